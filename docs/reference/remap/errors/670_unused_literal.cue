@@ -0,0 +1,32 @@
+package metadata
+
+remap: errors: "670": {
+	title: "Unused literal"
+	description: """
+		You've written a literal value that isn't the last expression of the program. This is a warning,
+		rather than an error, so the program still compiles.
+		"""
+
+	rationale: """
+		A program returns the value of its last expression. A literal anywhere else has no effect, which
+		usually means something is missing, such as the path or variable it was meant to be assigned to.
+		"""
+
+	resolution: """
+		Assign the literal to a path or variable, or remove it.
+		"""
+
+	examples: [
+		{
+			"title": "\(title)"
+			source: #"""
+				"the hills are alive"
+				.processed = true
+				"""#
+			diff: #"""
+				- 	"the hills are alive"
+				+# 	.movie_song_quote = "the hills are alive"
+				"""#
+		},
+	]
+}
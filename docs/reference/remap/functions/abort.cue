@@ -0,0 +1,39 @@
+package metadata
+
+remap: functions: abort: {
+	category: "Debug"
+	description: """
+		Aborts the program with the provided `message`. An optional `payload` object can be attached to the error,
+		which is made available to the embedding application (for example to route the event to a dead-letter
+		destination).
+		"""
+
+	arguments: [
+		{
+			name:        "message"
+			description: "The message describing why the program was aborted."
+			required:    true
+			type: ["string"]
+		},
+		{
+			name:        "payload"
+			description: "Structured data to attach to the resulting error."
+			required:    false
+			type: ["object"]
+		},
+	]
+	internal_failure_reasons: [
+		"this function always fails",
+	]
+	return: types: ["null"]
+
+	examples: [
+		{
+			title: "Abort with a payload"
+			source: #"""
+				ok, err = abort("invalid event", payload: { "reason": "missing field" })
+				"""#
+			return: #"function call error for "abort" at (10:72): invalid event"#
+		},
+	]
+}
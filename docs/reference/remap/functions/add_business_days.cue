@@ -0,0 +1,63 @@
+package metadata
+
+remap: functions: add_business_days: {
+	category: "Timestamp"
+	description: """
+		Moves the `value` by the given number of business `days`, meaning weekdays which aren't
+		`holidays`, backwards if `days` is negative. The time of day in the `timezone` is kept.
+
+		The date of the `value` itself isn't counted, so one business day after a Friday, or after a
+		Saturday, is the next Monday.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The timestamp to move."
+			required:    true
+			type: ["timestamp"]
+		},
+		{
+			name:        "days"
+			description: "The number of business days to move the timestamp by."
+			required:    true
+			type: ["integer"]
+		},
+		{
+			name:        "holidays"
+			description: "The days which aren't business days, as timestamps or dates formatted as `YYYY-MM-DD`."
+			required:    false
+			type: ["array"]
+		},
+		{
+			name:        "timezone"
+			description: "The time zone of the dates, such as `America/New_York`, or `local` for the local time zone."
+			required:    false
+			default:     "UTC"
+			type: ["string"]
+		},
+	]
+	internal_failure_reasons: [
+		"`timezone` isn't a known time zone",
+		"`holidays` contains a value which is neither a timestamp nor a date formatted as `YYYY-MM-DD`",
+		"The resulting timestamp is out of range",
+	]
+	return: types: ["timestamp"]
+
+	examples: [
+		{
+			title: "Add a business day over a weekend"
+			source: #"""
+				add_business_days!(t'2021-03-05T12:00:00Z', 1)
+				"""#
+			return: "2021-03-08T12:00:00Z"
+		},
+		{
+			title: "Subtract business days (with holidays)"
+			source: #"""
+				add_business_days!(t'2021-03-03T12:00:00Z', -3, holidays: ["2021-03-01"])
+				"""#
+			return: "2021-02-25T12:00:00Z"
+		},
+	]
+}
@@ -0,0 +1,45 @@
+package metadata
+
+remap: functions: basename: {
+	category: "String"
+	description: """
+		Returns the last component of the path `value`, ignoring trailing separators.
+
+		Paths are separated by forward slashes, whatever the platform Vector runs on, unless `windows` is set.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The path."
+			required:    true
+			type: ["string"]
+		},
+		{
+			name:        "windows"
+			description: "If set to `true`, backslashes also separate the path and it can start with a drive, such as `C:`."
+			required:    false
+			default:     false
+			type: ["boolean"]
+		},
+	]
+	internal_failure_reasons: []
+	return: types: ["string"]
+
+	examples: [
+		{
+			title: "Get the file name"
+			source: #"""
+				basename("/var/log/syslog.1")
+				"""#
+			return: "syslog.1"
+		},
+		{
+			title: "Get the file name of a Windows path"
+			source: #"""
+				basename(s'C:\Logs\app.log', windows: true)
+				"""#
+			return: "app.log"
+		},
+	]
+}
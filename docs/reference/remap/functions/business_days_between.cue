@@ -0,0 +1,62 @@
+package metadata
+
+remap: functions: business_days_between: {
+	category: "Timestamp"
+	description: """
+		Counts the business days, meaning the weekdays which aren't `holidays`, from the date of
+		`start`, included, to the date of `end`, excluded. The count is negative if `end` comes before
+		`start`.
+
+		Dates start and end at midnight in the `timezone`.
+		"""
+
+	arguments: [
+		{
+			name:        "start"
+			description: "The timestamp to count from."
+			required:    true
+			type: ["timestamp"]
+		},
+		{
+			name:        "end"
+			description: "The timestamp to count to."
+			required:    true
+			type: ["timestamp"]
+		},
+		{
+			name:        "holidays"
+			description: "The days which aren't business days, as timestamps or dates formatted as `YYYY-MM-DD`."
+			required:    false
+			type: ["array"]
+		},
+		{
+			name:        "timezone"
+			description: "The time zone of the dates, such as `America/New_York`, or `local` for the local time zone."
+			required:    false
+			default:     "UTC"
+			type: ["string"]
+		},
+	]
+	internal_failure_reasons: [
+		"`timezone` isn't a known time zone",
+		"`holidays` contains a value which is neither a timestamp nor a date formatted as `YYYY-MM-DD`",
+	]
+	return: types: ["integer"]
+
+	examples: [
+		{
+			title: "Count business days over a weekend"
+			source: #"""
+				business_days_between(t'2021-03-05T12:00:00Z', t'2021-03-08T12:00:00Z')
+				"""#
+			return: 1
+		},
+		{
+			title: "Count business days (with holidays)"
+			source: #"""
+				business_days_between!(t'2021-03-01T12:00:00Z', t'2021-03-08T12:00:00Z', holidays: ["2021-03-03"])
+				"""#
+			return: 4
+		},
+	]
+}
@@ -0,0 +1,49 @@
+package metadata
+
+remap: functions: camel_case_keys: {
+	category: "Object"
+	description: """
+		Recursively converts every key of the `value` object, including the keys of objects nested in
+		arrays, to `camelCase`. Values are left untouched.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The object or array containing the keys to convert."
+			required:    true
+			type: ["array", "object"]
+		},
+		{
+			name:        "overwrite"
+			description: "Whether keys that convert to the same key overwrite each other. If `false`, an error is returned instead."
+			required:    false
+			default:     true
+			type: ["boolean"]
+		},
+	]
+	internal_failure_reasons: [
+		"several keys of an object convert to the same key and `overwrite` is `false`",
+	]
+	return: {
+		types: ["array", "object"]
+		rules: [
+			"The return type matches the `value` type.",
+			"Keys are split into words at `_`, `-`, `.` and whitespace, and where the case changes.",
+			"When keys overwrite each other, the value of the key that sorts last is kept.",
+		]
+	}
+
+	examples: [
+		{
+			title: "Convert keys to `camelCase`"
+			source: #"""
+				camel_case_keys!({ "user_id": 1, "http_server": { "max_connections": 2 } })
+				"""#
+			return: {
+				httpServer: maxConnections: 2
+				userId: 1
+			}
+		},
+	]
+}
@@ -0,0 +1,52 @@
+package metadata
+
+remap: functions: canonicalize: {
+	category: "Codec"
+	description: """
+		Encodes the `value` to a deterministic string, so that logically equal values can be compared
+		or hashed, for example with [`sha2`](#sha2), to deduplicate events.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The value to canonicalize."
+			required:    true
+			type: ["any"]
+		},
+		{
+			name:        "unify_numbers"
+			description: "Whether floats without a fractional part are encoded as integers, so that `1` and `1.0` are equal."
+			required:    false
+			default:     false
+			type: ["boolean"]
+		},
+	]
+	internal_failure_reasons: []
+	return: {
+		types: ["string"]
+		rules: [
+			"Object keys are sorted, and no whitespace is added.",
+			"Strings are quoted, with quotes, backslashes, control characters and invalid UTF-8 bytes escaped.",
+			"Floats always include a fractional part or an exponent, unless `unify_numbers` is `true`.",
+			"Timestamps are encoded in RFC 3339 format, in UTC.",
+		]
+	}
+
+	examples: [
+		{
+			title: "Canonicalize an object"
+			source: #"""
+				canonicalize({ "b": 1.0, "a": [1, "1"] })
+				"""#
+			return: #"{"a":[1,"1"],"b":1.0}"#
+		},
+		{
+			title: "Hash an event for deduplication"
+			source: #"""
+				sha2(canonicalize({ "b": 2, "a": 1 })) == sha2(canonicalize({ "a": 1, "b": 2 }))
+				"""#
+			return: true
+		},
+	]
+}
@@ -0,0 +1,45 @@
+package metadata
+
+remap: functions: capture_error: {
+	category: "Debug"
+	description: """
+		Captures the outcome of the `value` expression, returning an object with its result as the `ok` field if it
+		succeeds, or its error `message` as the `error` field and the code of the error as the `code` field if it
+		fails, so that the program can branch on the details of the error instead of discarding them.
+
+		Unlike the arguments of other functions, `value` may be fallible.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The expression to capture the outcome of."
+			required:    true
+			type: ["any"]
+		},
+	]
+	internal_failure_reasons: []
+	return: types: ["object"]
+
+	examples: [
+		{
+			title: "Capture a result"
+			source: #"""
+				capture_error(parse_json("[1]"))
+				"""#
+			return: {
+				ok: [1]
+			}
+		},
+		{
+			title: "Capture an error"
+			source: #"""
+				capture_error(parse_json("{"))
+				"""#
+			return: {
+				error: #"function call error for "parse_json" at (14:29): unable to parse json: EOF while parsing an object at line 1 column 1"#
+				code:  705
+			}
+		},
+	]
+}
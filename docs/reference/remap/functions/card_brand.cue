@@ -0,0 +1,51 @@
+package metadata
+
+remap: functions: card_brand: {
+	category: "String"
+	description: """
+		Returns the likely brand of the card number in the `value`, based on its issuer prefix and
+		length.
+		"""
+	notices: [
+		"""
+			The brand is one of `visa`, `mastercard`, `amex`, `discover`, `jcb`, `diners_club`,
+			`unionpay`, or `maestro`. The number isn't validated, use [`luhn_check`](#luhn_check)
+			for that.
+			""",
+	]
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The card number. Spaces and dashes are ignored."
+			required:    true
+			type: ["string"]
+		},
+	]
+	internal_failure_reasons: [
+		"`value` contains characters other than digits, spaces, and dashes",
+	]
+	return: {
+		types: ["string", "null"]
+		rules: [
+			"Returns `null` if the number doesn't match any known brand.",
+		]
+	}
+
+	examples: [
+		{
+			title: "Visa card"
+			source: #"""
+				card_brand!("4111 1111 1111 1111")
+				"""#
+			return: "visa"
+		},
+		{
+			title: "Unknown brand"
+			source: #"""
+				card_brand!("1234 5678")
+				"""#
+			return: null
+		},
+	]
+}
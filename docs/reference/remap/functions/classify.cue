@@ -0,0 +1,50 @@
+package metadata
+
+remap: functions: classify: {
+	category: "String"
+	description: """
+		Returns the labels of all `categories` whose pattern matches the `value`.
+
+		All patterns are matched in a single pass. If `categories` is an object literal, it's compiled
+		once, when the program is compiled, and invalid patterns are reported as compile-time errors.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The value to classify."
+			required:    true
+			type: ["string"]
+		},
+		{
+			name:        "categories"
+			description: "An object mapping each label to a regular expression pattern, as a regex or a string."
+			required:    true
+			type: ["object"]
+		},
+	]
+	internal_failure_reasons: [
+		"`categories` isn't an object literal, and contains a value that isn't a valid regular expression",
+	]
+	return: {
+		types: ["array"]
+		rules: [
+			"Labels are returned in the order of the keys of `categories`, which are sorted alphabetically.",
+			"Returns an empty array if none of the patterns match.",
+		]
+	}
+
+	examples: [
+		{
+			title: "Classify a request"
+			source: #"""
+				classify("GET /api/health", {
+					"api": r'^\w+ /api/',
+					"health": r'/health$',
+					"static": r'\.css$',
+				})
+				"""#
+			return: ["api", "health"]
+		},
+	]
+}
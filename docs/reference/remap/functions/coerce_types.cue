@@ -0,0 +1,71 @@
+package metadata
+
+remap: functions: coerce_types: {
+	category: "Coerce"
+	description: """
+		Recursively converts the strings within `value` that look like integers, floats, booleans or
+		`null` into values of those types.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The object or array to coerce."
+			required:    true
+			type: ["array", "object"]
+		},
+		{
+			name:        "integer"
+			description: "Whether strings such as `\"5\"` are converted into integers."
+			required:    false
+			default:     true
+			type: ["boolean"]
+		},
+		{
+			name:        "float"
+			description: "Whether strings such as `\"5.0\"` or `\"1e3\"` are converted into floats."
+			required:    false
+			default:     true
+			type: ["boolean"]
+		},
+		{
+			name:        "boolean"
+			description: "Whether `\"true\"` and `\"false\"` are converted into booleans."
+			required:    false
+			default:     true
+			type: ["boolean"]
+		},
+		{
+			name:        "null"
+			description: "Whether `\"null\"` is converted into `null`."
+			required:    false
+			default:     true
+			type: ["boolean"]
+		},
+	]
+	internal_failure_reasons: []
+	return: {
+		types: ["array", "object"]
+		rules: [
+			"The return type matches the `value` type.",
+			"Numbers with leading zeros, such as `\"007\"`, are left as strings.",
+			"Integers that don't fit in 64 bits are left as strings.",
+			"Strings are only converted when they match exactly: surrounding whitespace, signs other than `-`, and other casings such as `\"True\"` are left as is.",
+		]
+	}
+	examples: [
+		{
+			title: "Coerce a key-value map"
+			source: #"""
+				coerce_types({"count": "5", "ratio": "0.5", "enabled": "true", "parent": "null", "zip": "02134"})
+				"""#
+			return: {
+				count:   5
+				ratio:   0.5
+				enabled: true
+				parent:  null
+				zip:     "02134"
+			}
+		},
+	]
+}
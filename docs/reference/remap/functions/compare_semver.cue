@@ -0,0 +1,58 @@
+package metadata
+
+remap: functions: compare_semver: {
+	category: "String"
+	description: """
+		Compares two [semantic versions](\(urls.semver)) following the semantic versioning precedence
+		rules.
+		"""
+	notices: [
+		"""
+			A prerelease version has a lower precedence than its associated release, and build
+			metadata is ignored.
+			""",
+	]
+
+	arguments: [
+		{
+			name:        "a"
+			description: "The first version to compare."
+			required:    true
+			type: ["string"]
+		},
+		{
+			name:        "b"
+			description: "The second version to compare."
+			required:    true
+			type: ["string"]
+		},
+	]
+	internal_failure_reasons: [
+		"`a` or `b` isn't a valid semantic version",
+	]
+	return: {
+		types: ["integer"]
+		rules: [
+			"Returns `-1` if `a` has a lower precedence than `b`.",
+			"Returns `0` if `a` and `b` have the same precedence.",
+			"Returns `1` if `a` has a higher precedence than `b`.",
+		]
+	}
+
+	examples: [
+		{
+			title: "Compare release versions"
+			source: #"""
+				compare_semver!("1.2.3", "1.10.0")
+				"""#
+			return: -1
+		},
+		{
+			title: "Compare a release with its prerelease"
+			source: #"""
+				compare_semver!("2.0.0", "2.0.0-rc.1")
+				"""#
+			return: 1
+		},
+	]
+}
@@ -0,0 +1,50 @@
+package metadata
+
+remap: functions: compile_template: {
+	category: "String"
+	description: """
+		Compiles the `template` once, to be rendered many times with [`render`](#render) without being
+		parsed again.
+
+		In the template, `{{ path }}` placeholders are replaced by the values of variables. Paths are field
+		names separated by dots, numbers indexing arrays, as in `{{ user.emails.0 }}`. A backslash before
+		`{{` makes it literal.
+
+		The compiled template is an object, which can be kept in a variable or a field like any other
+		value, and is meant to be passed to `render` as it is.
+		"""
+
+	arguments: [
+		{
+			name:        "template"
+			description: "The template to compile."
+			required:    true
+			type: ["string"]
+		},
+	]
+	internal_failure_reasons: [
+		"`template` has an unclosed placeholder",
+		"`template` has a placeholder with an invalid path",
+	]
+	return: types: ["object"]
+
+	examples: [
+		{
+			title: "Compile a template"
+			source: #"""
+				compile_template!("Hello, {{ user.name }}!")
+				"""#
+			return: {
+				template: "Hello, {{ user.name }}!"
+				parts: ["Hello, ", ["user", "name"], "!"]
+			}
+		},
+		{
+			title: "Render a compiled template"
+			source: #"""
+				render!(compile_template!("{{ status }} {{ path }}"), { "status": 200, "path": "/" })
+				"""#
+			return: "200 /"
+		},
+	]
+}
@@ -0,0 +1,51 @@
+package metadata
+
+remap: functions: concat: {
+	category: "String"
+	description: #"""
+		Concatenates the `parts` into a single string, with parts optionally separated from one another by a
+		`separator`. Unlike `join`, the parts don't have to be strings: integers, floats, booleans and timestamps
+		are coerced as by `to_string`, and `null` parts are empty.
+
+		The result is built in a single string, so this is cheaper than chaining `+` to build long strings.
+		"""#
+
+	arguments: [
+		{
+			name:        "parts"
+			description: "The array of values to concatenate."
+			required:    true
+			type: ["array"]
+		},
+		{
+			name:        "separator"
+			description: "The string separating each part."
+			required:    false
+			default:     ""
+			type: ["string"]
+		},
+	]
+	internal_failure_reasons: [
+		"A part of `parts` is an array, an object or a regular expression",
+	]
+	return: {
+		types: ["string"]
+	}
+
+	examples: [
+		{
+			title: "Concatenate values of mixed types"
+			source: #"""
+				concat!(["user-", 42, "-", true])
+				"""#
+			return: "user-42-true"
+		},
+		{
+			title: "Concatenate values with a separator"
+			source: #"""
+				concat!(["GET", "/api", 200, 1.5], separator: " ")
+				"""#
+			return: "GET /api 200 1.5"
+		},
+	]
+}
@@ -0,0 +1,32 @@
+package metadata
+
+remap: functions: concat_bytes: {
+	category: "String"
+	description: """
+		Concatenates the byte strings in the `parts` array. Unlike `join`, the parts aren't read as UTF-8, so binary
+		data is kept as it is.
+		"""
+
+	arguments: [
+		{
+			name:        "parts"
+			description: "The array of byte strings to concatenate."
+			required:    true
+			type: ["array"]
+		},
+	]
+	internal_failure_reasons: [
+		"`parts` contains a non-string item",
+	]
+	return: types: ["string"]
+
+	examples: [
+		{
+			title: "Concatenate bytes"
+			source: #"""
+				concat_bytes!(["foo", "bar"])
+				"""#
+			return: "foobar"
+		},
+	]
+}
@@ -0,0 +1,46 @@
+package metadata
+
+remap: functions: contrast_ratio: {
+	category: "Number"
+	description: #"""
+		Returns the [contrast ratio](\(urls.wcag_contrast_ratio)) of the colors `a` and `b`, from `1` for colors of
+		the same luminance to `21` for black and white, as defined by the Web Content Accessibility Guidelines. The
+		guidelines recommend a ratio of at least `4.5` between text and its background.
+		"""#
+
+	arguments: [
+		{
+			name:        "a"
+			description: "A color, as a string accepted by `parse_color` or an object returned by it."
+			required:    true
+			type: ["string", "object"]
+		},
+		{
+			name:        "b"
+			description: "The other color, as a string accepted by `parse_color` or an object returned by it."
+			required:    true
+			type: ["string", "object"]
+		},
+	]
+	internal_failure_reasons: [
+		"`a` or `b` isn't a valid color",
+	]
+	return: types: ["float"]
+
+	examples: [
+		{
+			title: "Contrast ratio of black and white"
+			source: #"""
+				contrast_ratio!("black", "white")
+				"""#
+			return: 21.0
+		},
+		{
+			title: "Contrast ratio of the same color"
+			source: #"""
+				contrast_ratio!("#3366cc", { "r": 51, "g": 102, "b": 204 })
+				"""#
+			return: 1.0
+		},
+	]
+}
@@ -0,0 +1,46 @@
+package metadata
+
+remap: functions: cron_matches: {
+	category: "Timestamp"
+	description: """
+		Determines whether the cron `expression` fires during the minute of the `timestamp`, in UTC.
+
+		See [`cron_next`](#cron_next) for the supported cron expressions.
+		"""
+
+	arguments: [
+		{
+			name:        "expression"
+			description: "The cron expression."
+			required:    true
+			type: ["string"]
+		},
+		{
+			name:        "timestamp"
+			description: "The timestamp to check."
+			required:    true
+			type: ["timestamp"]
+		},
+	]
+	internal_failure_reasons: [
+		"`expression` isn't a valid cron expression",
+	]
+	return: types: ["boolean"]
+
+	examples: [
+		{
+			title: "Matching timestamp"
+			source: #"""
+				cron_matches!("*/15 * * * *", t'2021-03-05T12:30:45Z')
+				"""#
+			return: true
+		},
+		{
+			title: "Timestamp on a weekend"
+			source: #"""
+				cron_matches!("0 9 * * mon-fri", t'2021-03-06T09:00:00Z')
+				"""#
+			return: false
+		},
+	]
+}
@@ -0,0 +1,54 @@
+package metadata
+
+remap: functions: cron_next: {
+	category: "Timestamp"
+	description: """
+		Returns the first time the cron `expression` fires strictly after the `after` timestamp, in UTC.
+
+		The `expression` is a standard 5-field cron expression: the minute, hour, day of the month,
+		month, and day of the week. Each field is a list of values, ranges such as `1-5`, and steps
+		over either of them or `*`, such as `*/15` or `0-30/10`. Months and days of the week can be
+		named by their first three letters, and Sunday is both `0` and `7`. If both the day of the
+		month and the day of the week are restricted, a day matches if either of them does.
+
+		The `@yearly`, `@annually`, `@monthly`, `@weekly`, `@daily`, `@midnight`, and `@hourly`
+		shorthands are supported too.
+		"""
+
+	arguments: [
+		{
+			name:        "expression"
+			description: "The cron expression."
+			required:    true
+			type: ["string"]
+		},
+		{
+			name:        "after"
+			description: "The timestamp after which to look for the next fire time."
+			required:    true
+			type: ["timestamp"]
+		},
+	]
+	internal_failure_reasons: [
+		"`expression` isn't a valid cron expression",
+		"`expression` doesn't fire within the next 10 years, such as on February 30th",
+	]
+	return: types: ["timestamp"]
+
+	examples: [
+		{
+			title: "Next daily run"
+			source: #"""
+				cron_next!("0 9 * * *", t'2021-03-05T12:00:00Z')
+				"""#
+			return: "2021-03-06T09:00:00Z"
+		},
+		{
+			title: "Next run every 15 minutes"
+			source: #"""
+				cron_next!("*/15 * * * *", t'2021-03-05T12:07:10Z')
+				"""#
+			return: "2021-03-05T12:15:00Z"
+		},
+	]
+}
@@ -0,0 +1,46 @@
+package metadata
+
+remap: functions: decode_cbor: {
+	category: "Codec"
+	description: """
+		Decodes the `value`, a single [CBOR](\(urls.cbor)) data item.
+
+		Both text and byte strings decode to strings, and map keys can be strings or integers, integer
+		keys becoming their string representation. Date and time strings (tag 0) and epoch-based
+		numbers (tag 1) decode to timestamps, and tagged regular expressions (tag 35) to regular
+		expressions. Other tags are ignored, keeping the value they enclose.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The CBOR data to decode."
+			required:    true
+			type: ["string"]
+		},
+	]
+	internal_failure_reasons: [
+		"`value` isn't a single well-formed CBOR data item",
+		"`value` contains an integer outside of the 64-bit signed integer range",
+		"`value` contains a NaN float",
+		"`value` contains a map key which is neither a string nor an integer",
+	]
+	return: types: ["any"]
+
+	examples: [
+		{
+			title: "Decode an object"
+			source: #"""
+				decode_cbor!(decode_base64!("oWFhggH1"))
+				"""#
+			return: a: [1, true]
+		},
+		{
+			title: "Decode an integer"
+			source: #"""
+				decode_cbor!(decode_base64!("GQPo"))
+				"""#
+			return: 1000
+		},
+	]
+}
@@ -0,0 +1,33 @@
+package metadata
+
+remap: functions: decode_deflate: {
+	category: "Codec"
+	description: """
+		Decompresses the raw [DEFLATE](\(urls.deflate)) stream `value`, without the zlib or gzip wrapper used by
+		most formats. Data in those formats is rejected.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The raw DEFLATE stream to decompress."
+			required:    true
+			type: ["string"]
+		},
+	]
+	internal_failure_reasons: [
+		"`value` isn't a valid raw DEFLATE stream",
+		"`value` ends before the end of the stream",
+	]
+	return: types: ["string"]
+
+	examples: [
+		{
+			title: "Decompress a string"
+			source: #"""
+				decode_deflate!(decode_base64!("y0jNyckHAA=="))
+				"""#
+			return: "hello"
+		},
+	]
+}
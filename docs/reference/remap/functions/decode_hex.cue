@@ -0,0 +1,42 @@
+package metadata
+
+remap: functions: decode_hex: {
+	category: "Codec"
+	description: """
+		Decodes the hexadecimal `value` into the bytes it encodes. Digits can be in either case.
+
+		Whitespace and the delimiters `:`, `-`, `,` and `.` between the digits are ignored, in any mix, so that the
+		output of `encode_hex` can be decoded whatever its delimiter.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The hexadecimal string to decode."
+			required:    true
+			type: ["string"]
+		},
+	]
+	internal_failure_reasons: [
+		"`value` has a character that is neither a hexadecimal digit, whitespace, nor a delimiter",
+		"`value` has an odd number of digits",
+	]
+	return: types: ["string"]
+
+	examples: [
+		{
+			title: "Decode hexadecimal"
+			source: #"""
+				decode_hex!("68656c6c6f")
+				"""#
+			return: "hello"
+		},
+		{
+			title: "Decode delimited hexadecimal"
+			source: #"""
+				decode_hex!("68:65:6C 6C:6F")
+				"""#
+			return: "hello"
+		},
+	]
+}
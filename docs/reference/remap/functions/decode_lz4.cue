@@ -0,0 +1,33 @@
+package metadata
+
+remap: functions: decode_lz4: {
+	category: "Codec"
+	description: """
+		Decompresses the [LZ4 frame](\(urls.lz4_frame_format)) `value`. The checksums of the frame, if it has any,
+		are verified.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The LZ4 frame to decompress."
+			required:    true
+			type: ["string"]
+		},
+	]
+	internal_failure_reasons: [
+		"`value` isn't a valid LZ4 frame",
+		"`value` has a checksum that doesn't match",
+	]
+	return: types: ["string"]
+
+	examples: [
+		{
+			title: "Decompress a string"
+			source: #"""
+				decode_lz4!(decode_base64!("BCJNGGBAggUAAIBoZWxsbwAAAAA="))
+				"""#
+			return: "hello"
+		},
+	]
+}
@@ -0,0 +1,31 @@
+package metadata
+
+remap: functions: decode_percent: {
+	category: "Codec"
+	description: """
+		Decodes the [percent-encoded](\(urls.percent_encoding)) `value`.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The string to decode."
+			required:    true
+			type: ["string"]
+		},
+	]
+	internal_failure_reasons: [
+		"`value` contains a `%` that isn't followed by two hexadecimal digits",
+	]
+	return: types: ["string"]
+
+	examples: [
+		{
+			title: "Decode a percent-encoded string"
+			source: #"""
+				decode_percent!("foo%20bar%3F")
+				"""#
+			return: "foo bar?"
+		},
+	]
+}
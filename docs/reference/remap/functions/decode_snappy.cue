@@ -0,0 +1,40 @@
+package metadata
+
+remap: functions: decode_snappy: {
+	category: "Codec"
+	description: """
+		Decompresses the [Snappy](\(urls.snappy)) `value`, either a single raw block, or a stream in the framing
+		format if `framed` is set, whose checksums are verified.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The Snappy data to decompress."
+			required:    true
+			type: ["string"]
+		},
+		{
+			name:        "framed"
+			description: "If set to `true`, the value is decompressed from the framing format."
+			required:    false
+			default:     false
+			type: ["boolean"]
+		},
+	]
+	internal_failure_reasons: [
+		"`value` isn't valid Snappy data in the expected format",
+		"`value` has a chunk whose checksum doesn't match",
+	]
+	return: types: ["string"]
+
+	examples: [
+		{
+			title: "Decompress a string"
+			source: #"""
+				decode_snappy!(decode_base64!("BRBoZWxsbw=="))
+				"""#
+			return: "hello"
+		},
+	]
+}
@@ -0,0 +1,62 @@
+package metadata
+
+remap: functions: deep_equal: {
+	category: "Type"
+	description: """
+		Determines whether the `left` and `right` values are equal, comparing nested objects and arrays
+		element by element.
+		"""
+
+	arguments: [
+		{
+			name:        "left"
+			description: "The first value to compare."
+			required:    true
+			type: ["any"]
+		},
+		{
+			name:        "right"
+			description: "The second value to compare."
+			required:    true
+			type: ["any"]
+		},
+		{
+			name:        "unify_numbers"
+			description: "Whether integers and floats with the same numeric value, such as `1` and `1.0`, are equal."
+			required:    false
+			default:     true
+			type: ["boolean"]
+		},
+		{
+			name:        "ignore_array_order"
+			description: "Whether arrays holding the same elements in a different order are equal."
+			required:    false
+			default:     false
+			type: ["boolean"]
+		},
+	]
+	internal_failure_reasons: []
+	return: {
+		types: ["boolean"]
+		rules: [
+			"Objects are equal when they have the same fields, regardless of the order of those fields.",
+		]
+	}
+
+	examples: [
+		{
+			title: "Compare nested numbers"
+			source: #"""
+				deep_equal({ "a": [1, 2] }, { "a": [1.0, 2.0] })
+				"""#
+			return: true
+		},
+		{
+			title: "Compare arrays regardless of order"
+			source: #"""
+				deep_equal([1, 2, 3], [3, 2, 1], ignore_array_order: true)
+				"""#
+			return: true
+		},
+	]
+}
@@ -0,0 +1,40 @@
+package metadata
+
+remap: functions: detect_content_type: {
+	category: "String"
+	description: """
+		Returns the media (MIME) type of the bytes `value`, identified by the magic bytes it starts with, or `null`
+		if the format isn't recognized.
+
+		The recognized formats are PNG, JPEG and GIF images, PDF documents, and gzip, zip, bzip2 and Zstandard
+		archives.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The bytes to identify."
+			required:    true
+			type: ["string"]
+		},
+	]
+	internal_failure_reasons: []
+	return: types: ["string", "null"]
+
+	examples: [
+		{
+			title: "Identify a PDF document"
+			source: #"""
+				detect_content_type("%PDF-1.7")
+				"""#
+			return: "application/pdf"
+		},
+		{
+			title: "Identify unknown bytes"
+			source: #"""
+				detect_content_type("hello")
+				"""#
+			return: null
+		},
+	]
+}
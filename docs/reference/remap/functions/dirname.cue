@@ -0,0 +1,46 @@
+package metadata
+
+remap: functions: dirname: {
+	category: "String"
+	description: """
+		Returns the path `value` without its last component, ignoring trailing separators. The directory of a
+		relative path without separators is `.`, and the directory of the root is the root.
+
+		Paths are separated by forward slashes, whatever the platform Vector runs on, unless `windows` is set.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The path."
+			required:    true
+			type: ["string"]
+		},
+		{
+			name:        "windows"
+			description: "If set to `true`, backslashes also separate the path and it can start with a drive, such as `C:`."
+			required:    false
+			default:     false
+			type: ["boolean"]
+		},
+	]
+	internal_failure_reasons: []
+	return: types: ["string"]
+
+	examples: [
+		{
+			title: "Get the directory"
+			source: #"""
+				dirname("/var/log/syslog.1")
+				"""#
+			return: "/var/log"
+		},
+		{
+			title: "Get the directory of a Windows path"
+			source: #"""
+				dirname(s'C:\Logs\app.log', windows: true)
+				"""#
+			return: #"C:\Logs"#
+		},
+	]
+}
@@ -0,0 +1,45 @@
+package metadata
+
+remap: functions: dot_product: {
+	category: "Number"
+	description: """
+		Returns the dot product of the `a` and `b` arrays of numbers, which is the sum of the
+		products of their elements.
+		"""
+
+	arguments: [
+		{
+			name:        "a"
+			description: "The first array of numbers."
+			required:    true
+			type: ["array"]
+		},
+		{
+			name:        "b"
+			description: "The second array of numbers."
+			required:    true
+			type: ["array"]
+		},
+	]
+	internal_failure_reasons: [
+		"`a` or `b` contains an element that isn't a number",
+		"`a` and `b` aren't of the same length",
+	]
+	return: {
+		types: ["integer", "float"]
+		rules: [
+			"Returns an integer if all elements are integers. Returns a float otherwise.",
+			"Returns `0` if both arrays are empty.",
+		]
+	}
+
+	examples: [
+		{
+			title: "Dot product"
+			source: #"""
+				dot_product!([1, 2, 3], [4, 5, 6])
+				"""#
+			return: 32
+		},
+	]
+}
@@ -0,0 +1,59 @@
+package metadata
+
+remap: functions: ellipsize_middle: {
+	category: "String"
+	description: #"""
+		Shortens the `value` to `max_width` characters by replacing its middle with the `ellipsis`, keeping its
+		start and its end. When the rest of the width is odd, the start gets the extra character.
+
+		Widths are counted in grapheme clusters, so that characters made of several code points, such as accented
+		letters or flags, are never split. If the `max_width` is smaller than the `ellipsis`, the result is the
+		`ellipsis` truncated to the `max_width`.
+		"""#
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The string to shorten."
+			required:    true
+			type: ["string"]
+		},
+		{
+			name:        "max_width"
+			description: "The maximum number of characters of the result."
+			required:    true
+			type: ["integer"]
+		},
+		{
+			name:        "ellipsis"
+			description: "The string replacing the middle of the `value`."
+			required:    false
+			default:     "..."
+			type: ["string"]
+		},
+	]
+	internal_failure_reasons: []
+	return: {
+		types: ["string"]
+		rules: [
+			"If the `value` is at most `max_width` characters long, it is returned unchanged.",
+		]
+	}
+
+	examples: [
+		{
+			title: "Shorten a path"
+			source: #"""
+				ellipsize_middle("/var/log/nginx/access.log", 16)
+				"""#
+			return: "/var/lo...ss.log"
+		},
+		{
+			title: "Shorten with a custom ellipsis"
+			source: #"""
+				ellipsize_middle("0123456789abcdef", 9, ellipsis: "…")
+				"""#
+			return: "0123…cdef"
+		},
+	]
+}
@@ -0,0 +1,54 @@
+package metadata
+
+remap: functions: encode_cbor: {
+	category: "Codec"
+	description: """
+		Encodes the `value` as [CBOR](\(urls.cbor)).
+
+		Strings are encoded as text strings if they're valid UTF-8, and as byte strings otherwise.
+		Floats are always encoded in double precision, so that they stay distinct from integers.
+		Timestamps are tagged date and time strings (tag 0) or epoch-based numbers (tag 1), depending
+		on the `timestamp_format`, and regular expressions are tagged strings (tag 35).
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The value to encode."
+			required:    true
+			type: ["any"]
+		},
+		{
+			name:        "timestamp_format"
+			description: "How timestamps are encoded."
+			required:    false
+			default:     "rfc3339"
+			type: ["string"]
+			enum: {
+				rfc3339: "An RFC 3339 string, tagged with tag 0."
+				epoch:   "The number of seconds since the Unix epoch, tagged with tag 1. The number is a float if the timestamp has fractional seconds, which keeps about microsecond precision."
+			}
+		},
+	]
+	internal_failure_reasons: [
+		"`timestamp_format` isn't one of the supported formats",
+	]
+	return: types: ["string"]
+
+	examples: [
+		{
+			title: "Encode an object"
+			source: #"""
+				encode_base64(encode_cbor({ "a": [1, true] }))
+				"""#
+			return: "oWFhggH1"
+		},
+		{
+			title: "Encode a timestamp (epoch)"
+			source: #"""
+				encode_base64(encode_cbor!(t'2013-03-21T20:04:00Z', timestamp_format: "epoch"))
+				"""#
+			return: "wRpRS2ew"
+		},
+	]
+}
@@ -0,0 +1,39 @@
+package metadata
+
+remap: functions: encode_deflate: {
+	category: "Codec"
+	description: """
+		Compresses the `value` into a raw [DEFLATE](\(urls.deflate)) stream, without the zlib or gzip wrapper used
+		by most formats.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The bytes to compress."
+			required:    true
+			type: ["string"]
+		},
+		{
+			name:        "level"
+			description: "The compression level, from `0`, which stores the value uncompressed, to `9`, the best and slowest compression."
+			required:    false
+			default:     6
+			type: ["integer"]
+		},
+	]
+	internal_failure_reasons: [
+		"`level` isn't between `0` and `9`",
+	]
+	return: types: ["string"]
+
+	examples: [
+		{
+			title: "Compress and decompress a string"
+			source: #"""
+				decode_deflate!(encode_deflate("hello hello hello"))
+				"""#
+			return: "hello hello hello"
+		},
+	]
+}
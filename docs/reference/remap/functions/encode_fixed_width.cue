@@ -0,0 +1,61 @@
+package metadata
+
+remap: functions: encode_fixed_width: {
+	category: "Codec"
+	description: """
+		Encodes the `value` object into a fixed-width record, using the columns defined by `spec`.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The object to encode."
+			required:    true
+			type: ["object"]
+		},
+		{
+			name: "spec"
+			description: """
+				The columns of the record, in the same format as for `parse_fixed_width`. Each column can
+				also have an `align` of `"left"` (the default) or `"right"`, which decides the side the
+				field is padded on.
+				"""
+			required: true
+			type: ["array"]
+		},
+		{
+			name:        "truncate"
+			description: "Whether fields longer than their column are truncated, rather than failing."
+			required:    false
+			default:     false
+			type: ["boolean"]
+		},
+	]
+	internal_failure_reasons: [
+		"`spec` contains an invalid column definition",
+		"a field is longer than its column, and `truncate` is `false`",
+		"a field isn't a string, number, boolean or `null`",
+	]
+	return: {
+		types: ["string"]
+		rules: [
+			"Fields are padded with spaces, as are the gaps between columns.",
+			"Missing and `null` fields are left blank.",
+			"Truncated fields keep their leftmost characters.",
+		]
+	}
+
+	examples: [
+		{
+			title: "Encode a fixed-width record"
+			source: #"""
+				encode_fixed_width!({ "id": 42, "first": "JOHN", "last": "SMITH" }, [
+					{ "name": "id", "width": 5, "align": "right" },
+					{ "name": "first", "width": 9 },
+					{ "name": "last", "width": 5 },
+				])
+				"""#
+			return: "   42JOHN     SMITH"
+		},
+	]
+}
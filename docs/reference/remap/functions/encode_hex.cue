@@ -0,0 +1,50 @@
+package metadata
+
+remap: functions: encode_hex: {
+	category: "Codec"
+	description: """
+		Encodes the bytes of `value` in hexadecimal, with two digits for each byte.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The bytes to encode."
+			required:    true
+			type: ["string"]
+		},
+		{
+			name:        "uppercase"
+			description: "If set to `true`, the digits `A` to `F` are written in uppercase."
+			required:    false
+			default:     false
+			type: ["boolean"]
+		},
+		{
+			name:        "delimiter"
+			description: "The string separating the digits of each byte from the next, such as `:` for `aa:bb:cc`."
+			required:    false
+			default:     ""
+			type: ["string"]
+		},
+	]
+	internal_failure_reasons: []
+	return: types: ["string"]
+
+	examples: [
+		{
+			title: "Encode bytes"
+			source: #"""
+				encode_hex("hello")
+				"""#
+			return: "68656c6c6f"
+		},
+		{
+			title: "Encode bytes in uppercase, with a delimiter"
+			source: #"""
+				encode_hex("hello", uppercase: true, delimiter: ":")
+				"""#
+			return: "68:65:6C:6C:6F"
+		},
+	]
+}
@@ -0,0 +1,52 @@
+package metadata
+
+remap: functions: encode_influx: {
+	category: "Codec"
+	description: """
+		Encodes the `value` as a line of the [InfluxDB line protocol](\(urls.influxdb_line_protocol)),
+		the reverse of `parse_influx`.
+
+		The `value` is an object with a `measurement` string, an optional `tags` object of strings, a
+		non-empty `fields` object, and an optional `timestamp`, either a timestamp or an integer number
+		of nanoseconds since the Unix epoch. Tags and fields are written in alphabetical order, integer
+		fields are suffixed with `i`, and string fields are quoted.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The line to encode."
+			required:    true
+			type: ["object"]
+		},
+	]
+	internal_failure_reasons: [
+		"`measurement` is missing or isn't a non-empty string",
+		"`fields` is missing or isn't a non-empty object",
+		"a tag value isn't a non-empty string",
+		"a field value isn't a string, integer, float, or boolean",
+	]
+	return: types: ["string"]
+
+	examples: [
+		{
+			title: "Encode a line"
+			source: #"""
+				encode_influx!({
+					"measurement": "cpu",
+					"tags": { "host": "a" },
+					"fields": { "usage": 0.5, "cores": 4 },
+					"timestamp": t'2021-03-06T03:06:40Z'
+				})
+				"""#
+			return: "cpu,host=a cores=4i,usage=0.5 1615000000000000000"
+		},
+		{
+			title: "Encode a line with escaped characters"
+			source: #"""
+				encode_influx!({ "measurement": "weather", "tags": { "city": "New York" }, "fields": { "description": "light rain" } })
+				"""#
+			return: #"weather,city=New\ York description="light rain""#
+		},
+	]
+}
@@ -0,0 +1,36 @@
+package metadata
+
+remap: functions: encode_lz4: {
+	category: "Codec"
+	description: """
+		Compresses the `value` into an [LZ4 frame](\(urls.lz4_frame_format)).
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The bytes to compress."
+			required:    true
+			type: ["string"]
+		},
+		{
+			name:        "content_checksum"
+			description: "If set to `true`, the frame ends with a checksum of the value, which `decode_lz4` verifies."
+			required:    false
+			default:     false
+			type: ["boolean"]
+		},
+	]
+	internal_failure_reasons: []
+	return: types: ["string"]
+
+	examples: [
+		{
+			title: "Compress and decompress a string"
+			source: #"""
+				decode_lz4!(encode_lz4("hello hello hello", content_checksum: true))
+				"""#
+			return: "hello hello hello"
+		},
+	]
+}
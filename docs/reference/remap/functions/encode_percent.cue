@@ -0,0 +1,56 @@
+package metadata
+
+remap: functions: encode_percent: {
+	category: "Codec"
+	description: """
+		Encodes the `value` with [percent-encoding](\(urls.percent_encoding)), escaping the characters
+		that are part of the given `ascii_set`, as well as all non-ASCII characters.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The string to encode."
+			required:    true
+			type: ["string"]
+		},
+		{
+			name:        "ascii_set"
+			description: "The set of ASCII characters to escape."
+			required:    false
+			type: ["string"]
+			default: "non_alphanumeric"
+			enum: {
+				non_alphanumeric: "All ASCII characters except letters and digits."
+				controls:         "ASCII control characters."
+				fragment:         "The characters escaped in URL fragments."
+				query:            "The characters escaped in URL queries."
+				special:          "The characters escaped in URL queries of special schemes, such as `http`."
+				path:             "The characters escaped in URL paths."
+				userinfo:         "The characters escaped in the userinfo part of URLs."
+				component:        "The characters escaped in URL components, like JavaScript's `encodeURIComponent`."
+			}
+		},
+	]
+	internal_failure_reasons: [
+		"`ascii_set` isn't one of the supported sets",
+	]
+	return: types: ["string"]
+
+	examples: [
+		{
+			title: "Percent-encode all non-alphanumeric characters"
+			source: #"""
+				encode_percent("foo bar?")
+				"""#
+			return: "foo%20bar%3F"
+		},
+		{
+			title: "Percent-encode a URL query"
+			source: #"""
+				encode_percent!("a b?c=d#e", ascii_set: "query")
+				"""#
+			return: "a%20b?c=d%23e"
+		},
+	]
+}
@@ -0,0 +1,46 @@
+package metadata
+
+remap: functions: encode_snappy: {
+	category: "Codec"
+	description: """
+		Compresses the `value` with [Snappy](\(urls.snappy)), either as a single raw block, or in the framing
+		format for streams if `framed` is set.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The bytes to compress."
+			required:    true
+			type: ["string"]
+		},
+		{
+			name:        "framed"
+			description: "If set to `true`, the value is compressed in the framing format, with checksums of its chunks."
+			required:    false
+			default:     false
+			type: ["boolean"]
+		},
+	]
+	internal_failure_reasons: [
+		"`value` is too large to be compressed as a raw block",
+	]
+	return: types: ["string"]
+
+	examples: [
+		{
+			title: "Compress a string"
+			source: #"""
+				encode_base64(encode_snappy!("hello"))
+				"""#
+			return: "BRBoZWxsbw=="
+		},
+		{
+			title: "Compress and decompress a string in the framing format"
+			source: #"""
+				decode_snappy!(encode_snappy!("hello", framed: true), framed: true)
+				"""#
+			return: "hello"
+		},
+	]
+}
@@ -0,0 +1,44 @@
+package metadata
+
+remap: functions: ewma: {
+	category: "Number"
+	description: """
+		Returns the exponentially weighted moving averages of the `value` array of numbers.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The array of numbers."
+			required:    true
+			type: ["array"]
+		},
+		{
+			name:        "alpha"
+			description: "The weight given to each new element, greater than `0` and at most `1`."
+			required:    true
+			type: ["integer", "float"]
+		},
+	]
+	internal_failure_reasons: [
+		"`value` contains an element that isn't a number",
+		"`alpha` isn't greater than `0` and at most `1`",
+	]
+	return: {
+		types: ["array"]
+		rules: [
+			"The first average is the first element of `value`.",
+			"Each next average is `alpha * element + (1 - alpha) * previous average`.",
+		]
+	}
+
+	examples: [
+		{
+			title: "Smooth a series"
+			source: #"""
+				ewma!([10, 20, 20], 0.5)
+				"""#
+			return: [10.0, 15.0, 17.5]
+		},
+	]
+}
@@ -0,0 +1,53 @@
+package metadata
+
+remap: functions: extname: {
+	category: "String"
+	description: """
+		Returns the extension of the last component of the path `value`, from its last dot, or an empty string if
+		it has none. The leading dots of hidden files, such as `.bashrc`, don't start an extension.
+
+		Paths are separated by forward slashes, whatever the platform Vector runs on, unless `windows` is set.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The path."
+			required:    true
+			type: ["string"]
+		},
+		{
+			name:        "include_dot"
+			description: "If set to `false`, the extension is returned without its dot."
+			required:    false
+			default:     true
+			type: ["boolean"]
+		},
+		{
+			name:        "windows"
+			description: "If set to `true`, backslashes also separate the path and it can start with a drive, such as `C:`."
+			required:    false
+			default:     false
+			type: ["boolean"]
+		},
+	]
+	internal_failure_reasons: []
+	return: types: ["string"]
+
+	examples: [
+		{
+			title: "Get the extension"
+			source: #"""
+				extname("/var/log/app.log")
+				"""#
+			return: ".log"
+		},
+		{
+			title: "Get the extension without the dot"
+			source: #"""
+				extname("archive.tar.gz", include_dot: false)
+				"""#
+			return: "gz"
+		},
+	]
+}
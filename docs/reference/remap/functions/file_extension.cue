@@ -0,0 +1,48 @@
+package metadata
+
+remap: functions: file_extension: {
+	category: "String"
+	description: """
+		Returns the file extension, without its leading dot, of the media (MIME) type `value`, or `null` if it's
+		unknown. This is the reverse of `mime_type`.
+
+		The extensions are looked up in a bundled table of common media types. Media types with several extensions,
+		such as `image/jpeg`, return the usual one. Parameters of the media type, as in a `Content-Type` header,
+		are ignored.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The media type."
+			required:    true
+			type: ["string"]
+		},
+	]
+	internal_failure_reasons: []
+	return: types: ["string", "null"]
+
+	examples: [
+		{
+			title: "Look up a media type"
+			source: #"""
+				file_extension("image/jpeg")
+				"""#
+			return: "jpg"
+		},
+		{
+			title: "Look up a content type"
+			source: #"""
+				file_extension("text/html; charset=utf-8")
+				"""#
+			return: "html"
+		},
+		{
+			title: "Look up an unknown media type"
+			source: #"""
+				file_extension("application/x-unknown")
+				"""#
+			return: null
+		},
+	]
+}
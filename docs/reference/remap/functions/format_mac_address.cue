@@ -0,0 +1,52 @@
+package metadata
+
+remap: functions: format_mac_address: {
+	category: "String"
+	description: """
+		Formats the MAC address `value` in the given `style`, with lowercase digits.
+
+		The `value` can be written in any of the forms accepted by `parse_mac_address`.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The MAC address to format."
+			required:    true
+			type: ["string"]
+		},
+		{
+			name:        "style"
+			description: "How the MAC address is written."
+			required:    true
+			type: ["string"]
+			enum: {
+				colon: "Pairs of digits separated by colons, such as `00:1b:63:84:45:e6`."
+				dash:  "Pairs of digits separated by dashes, such as `00-1b-63-84-45-e6`."
+				cisco: "Groups of four digits separated by dots, such as `001b.6384.45e6`."
+			}
+		},
+	]
+	internal_failure_reasons: [
+		"`value` isn't a valid MAC address",
+		"`style` isn't one of the supported styles",
+	]
+	return: types: ["string"]
+
+	examples: [
+		{
+			title: "Format a MAC address in the Cisco style"
+			source: #"""
+				format_mac_address!("00:1B:63:84:45:E6", style: "cisco")
+				"""#
+			return: "001b.6384.45e6"
+		},
+		{
+			title: "Format a MAC address with dashes"
+			source: #"""
+				format_mac_address!("001b.6384.45e6", style: "dash")
+				"""#
+			return: "00-1b-63-84-45-e6"
+		},
+	]
+}
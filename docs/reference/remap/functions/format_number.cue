@@ -15,7 +15,7 @@ remap: functions: format_number: {
 		},
 		{
 			name:        "scale"
-			description: "The number of decimal places to display."
+			description: "The number of decimal places to display. The number is rounded half away from zero."
 			required:    false
 			type: ["integer"]
 		},
@@ -28,10 +28,9 @@ remap: functions: format_number: {
 		},
 		{
 			name:        "grouping_separator"
-			description: "The character to use between each thousands part of the number."
+			description: "The character to use between each thousands part of the number. The digits aren't grouped if it's omitted."
 			required:    false
 			type: ["string"]
-			default: ","
 		},
 	]
 	internal_failure_reasons: []
@@ -45,5 +44,12 @@ remap: functions: format_number: {
 				"""#
 			return: "1,234,567.890"
 		},
+		{
+			title: "Format a number (European separators, rounded)"
+			source: #"""
+				format_number(1234567.891, 2, decimal_separator: ",", grouping_separator: ".")
+				"""#
+			return: "1.234.567,89"
+		},
 	]
 }
@@ -0,0 +1,35 @@
+package metadata
+
+remap: functions: from_hex_dump: {
+	category: "Debug"
+	description: """
+		Parses the hex dump `value`, in the layout of `hex_dump`, back into the bytes it renders.
+
+		The ASCII column of each line is ignored, and can be left out. Blank lines are skipped.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The hex dump to parse."
+			required:    true
+			type: ["string"]
+		},
+	]
+	internal_failure_reasons: [
+		"`value` has a line without a valid offset",
+		"`value` has a line whose offset doesn't follow on from the bytes before it",
+		"`value` has an invalid byte",
+	]
+	return: types: ["string"]
+
+	examples: [
+		{
+			title: "Parse a hex dump"
+			source: #"""
+				from_hex_dump!("00000000  68 65 6c 6c 6f  |hello|")
+				"""#
+			return: "hello"
+		},
+	]
+}
@@ -0,0 +1,63 @@
+package metadata
+
+remap: functions: glob_match: {
+	category: "String"
+	description: """
+		Determines whether the `value` matches the glob `pattern`. Paths are separated by forward slashes.
+
+		In the pattern, `?` matches any single character and `*` matches any sequence of characters, neither
+		matching a separator. `**` matches any number of whole path segments, and must form a segment of its own,
+		as in `/var/log/**/*.log`. `[...]` matches any of the enclosed characters or ranges of characters, such as
+		`[a-z]`, and `[!...]` any character not enclosed.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The string to match."
+			required:    true
+			type: ["string"]
+		},
+		{
+			name:        "pattern"
+			description: "The glob pattern to match against."
+			required:    true
+			type: ["string"]
+		},
+		{
+			name:        "case_sensitive"
+			description: "If set to `false`, letters match regardless of their case."
+			required:    false
+			default:     true
+			type: ["boolean"]
+		},
+	]
+	internal_failure_reasons: [
+		"`pattern` isn't a valid glob pattern",
+	]
+	return: types: ["boolean"]
+
+	examples: [
+		{
+			title: "Match path segments"
+			source: #"""
+				glob_match("/var/log/nginx/access.log", "/var/log/**/*.log")
+				"""#
+			return: true
+		},
+		{
+			title: "Match within a path segment"
+			source: #"""
+				glob_match("/var/log/nginx/access.log", "/var/log/*.log")
+				"""#
+			return: false
+		},
+		{
+			title: "Match regardless of case"
+			source: #"""
+				glob_match("APP.LOG", "*.log", case_sensitive: false)
+				"""#
+			return: true
+		},
+	]
+}
@@ -0,0 +1,63 @@
+package metadata
+
+remap: functions: hash_color: {
+	category: "String"
+	description: #"""
+		Maps the `value` to a color, so that the same `value` is always given the same color, such as to tell
+		services or hosts apart in a user interface.
+
+		The color is picked from a hash of the `value`, and is a hexadecimal color such as `#24ba4f`, or an item
+		of the `palette` if one is given.
+		"""#
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The string to map to a color."
+			required:    true
+			type: ["string"]
+		},
+		{
+			name:        "palette"
+			description: "The colors to pick from. It takes precedence over `pastel`."
+			required:    false
+			type: ["array"]
+		},
+		{
+			name:        "pastel"
+			description: "Whether to only pick light colors, halfway between a color and white."
+			required:    false
+			default:     false
+			type: ["boolean"]
+		},
+	]
+	internal_failure_reasons: [
+		"`palette` is empty",
+		"`palette` contains an item that isn't a string",
+	]
+	return: types: ["string"]
+
+	examples: [
+		{
+			title: "Map a string to a color"
+			source: #"""
+				hash_color("checkout-service")
+				"""#
+			return: "#24ba4f"
+		},
+		{
+			title: "Map a string to a pastel color"
+			source: #"""
+				hash_color("checkout-service", pastel: true)
+				"""#
+			return: "#91dca7"
+		},
+		{
+			title: "Map a string to a color of a palette"
+			source: #"""
+				hash_color!("checkout-service", palette: ["red", "green", "blue"])
+				"""#
+			return: "blue"
+		},
+	]
+}
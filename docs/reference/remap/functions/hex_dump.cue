@@ -0,0 +1,49 @@
+package metadata
+
+remap: functions: hex_dump: {
+	category: "Debug"
+	description: """
+		Renders the bytes of `value` as a hex dump, for inspecting binary data.
+
+		Each line of the dump holds `bytes_per_line` bytes: the offset of the first byte, in hexadecimal, the
+		bytes in hexadecimal, and the bytes as ASCII between `|` characters, with a dot for each unprintable
+		byte. Lines are separated by newlines. The dump can be turned back into bytes with `from_hex_dump`.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The bytes to dump."
+			required:    true
+			type: ["string"]
+		},
+		{
+			name:        "bytes_per_line"
+			description: "The number of bytes on each line of the dump."
+			required:    false
+			default:     16
+			type: ["integer"]
+		},
+	]
+	internal_failure_reasons: [
+		"`bytes_per_line` isn't greater than zero",
+	]
+	return: types: ["string"]
+
+	examples: [
+		{
+			title: "Dump bytes"
+			source: #"""
+				hex_dump("hello world")
+				"""#
+			return: "00000000  68 65 6c 6c 6f 20 77 6f 72 6c 64                 |hello world|"
+		},
+		{
+			title: "Dump bytes on short lines"
+			source: #"""
+				hex_dump("hello", bytes_per_line: 4)
+				"""#
+			return: "00000000  68 65 6c 6c  |hell|\n00000004  6f           |o|"
+		},
+	]
+}
@@ -0,0 +1,30 @@
+package metadata
+
+remap: functions: html_escape: {
+	category:    "Codec"
+	description: """
+		Escapes the `&`, `<`, `>`, `"`, and `'` characters in the `value`, making it safe to embed in
+		HTML text or attribute values.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The string to escape."
+			required:    true
+			type: ["string"]
+		},
+	]
+	internal_failure_reasons: []
+	return: types: ["string"]
+
+	examples: [
+		{
+			title: "Escape HTML"
+			source: #"""
+				html_escape(s'<a href="/">Fish & chips</a>')
+				"""#
+			return: "&lt;a href=&quot;/&quot;&gt;Fish &amp; chips&lt;/a&gt;"
+		},
+	]
+}
@@ -0,0 +1,35 @@
+package metadata
+
+remap: functions: html_unescape: {
+	category:    "Codec"
+	description: """
+		Decodes the named (such as `&amp;`) and numeric (such as `&#x27;`) HTML character references
+		in the `value`.
+		"""
+	notices: [
+		"""
+			Unknown or malformed character references are left untouched.
+			""",
+	]
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The string to unescape."
+			required:    true
+			type: ["string"]
+		},
+	]
+	internal_failure_reasons: []
+	return: types: ["string"]
+
+	examples: [
+		{
+			title: "Unescape HTML"
+			source: #"""
+				html_unescape("Fish &amp; chips &#x27;n&apos; peas")
+				"""#
+			return: "Fish & chips 'n' peas"
+		},
+	]
+}
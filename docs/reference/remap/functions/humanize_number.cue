@@ -0,0 +1,46 @@
+package metadata
+
+remap: functions: humanize_number: {
+	category: "Number"
+	description: """
+		Abbreviates the `value` using the largest of the `K` (thousand), `M` (million), `B` (billion)
+		and `T` (trillion) units it's at least one of, such as `1.2K` for `1234`.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The number to abbreviate."
+			required:    true
+			type: ["integer", "float"]
+		},
+		{
+			name:        "precision"
+			description: "The maximum number of decimal places to display. Trailing zeros are omitted."
+			required:    false
+			default:     1
+			type: ["integer"]
+		},
+	]
+	internal_failure_reasons: [
+		"`precision` isn't between 0 and 15.",
+	]
+	return: types: ["string"]
+
+	examples: [
+		{
+			title: "Abbreviate a number"
+			source: #"""
+				humanize_number(1234)
+				"""#
+			return: "1.2K"
+		},
+		{
+			title: "Abbreviate a number (with precision)"
+			source: #"""
+				humanize_number(3456789, precision: 2)
+				"""#
+			return: "3.46M"
+		},
+	]
+}
@@ -0,0 +1,67 @@
+package metadata
+
+remap: functions: infer_schema: {
+	category: "Type"
+	description: """
+		Infers a schema describing the structure of the `value`, in a subset of
+		[JSON Schema](\(urls.json_schema)). Merge the schemas of several events with
+		[`merge_schemas`](#merge_schemas) to describe them all.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The value to infer the schema of."
+			required:    true
+			type: ["any"]
+		},
+	]
+	internal_failure_reasons: []
+	return: {
+		types: ["object"]
+		rules: [
+			"Types are `null`, `boolean`, `integer`, `number`, `string`, `object` and `array`.",
+			"Timestamps are strings with a `date-time` `format`, and regular expressions are strings.",
+			"The schemas of objects have the `properties` of their fields, all of which are `required`.",
+			"The `items` of arrays are described by the merged schema of all of them, and left out of empty arrays.",
+			"Merged types are widened: `integer` and `number` become `number`, and other differing types become an array of them.",
+		]
+	}
+
+	examples: [
+		{
+			title: "Infer the schema of an object"
+			source: #"""
+				infer_schema({ "id": 1, "tags": ["a", "b"] })
+				"""#
+			return: {
+				type: "object"
+				properties: {
+					id: type: "integer"
+					tags: {
+						type: "array"
+						items: type: "string"
+					}
+				}
+				required: ["id", "tags"]
+			}
+		},
+		{
+			title: "Infer the schema of an array of objects"
+			source: #"""
+				infer_schema([{ "id": 1, "name": "foo" }, { "id": 2 }])
+				"""#
+			return: {
+				type: "array"
+				items: {
+					type: "object"
+					properties: {
+						id: type:   "integer"
+						name: type: "string"
+					}
+					required: ["id"]
+				}
+			}
+		},
+	]
+}
@@ -0,0 +1,42 @@
+package metadata
+
+remap: functions: ip_version: {
+	category: "IP"
+	description: """
+		Returns the version of the IP address in `value`.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The IP address to inspect."
+			required:    true
+			type: ["string"]
+		},
+	]
+	internal_failure_reasons: []
+	return: {
+		types: ["integer", "null"]
+		rules: [
+			"Returns `4` for IPv4 addresses and `6` for IPv6 addresses, including IPv4-mapped IPv6 addresses.",
+			"Returns `null` if `value` isn't a valid IP address.",
+		]
+	}
+
+	examples: [
+		{
+			title: "IPv6 address"
+			source: #"""
+				ip_version("2001:db8::1")
+				"""#
+			return: 6
+		},
+		{
+			title: "Not an IP address"
+			source: #"""
+				ip_version("vector.dev")
+				"""#
+			return: null
+		},
+	]
+}
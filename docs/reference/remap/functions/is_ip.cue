@@ -0,0 +1,42 @@
+package metadata
+
+remap: functions: is_ip: {
+	category: "IP"
+	description: """
+		Determines whether the `value` is a valid IPv4 or IPv6 address.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The string to check."
+			required:    true
+			type: ["string"]
+		},
+	]
+	internal_failure_reasons: []
+	return: {
+		types: ["boolean"]
+		rules: [
+			"Returns `true` if `value` is a valid IPv4 or IPv6 address.",
+			"Returns `false` otherwise, including for CIDR blocks and hostnames.",
+		]
+	}
+
+	examples: [
+		{
+			title: "Valid IP address"
+			source: #"""
+				is_ip("192.168.0.1")
+				"""#
+			return: true
+		},
+		{
+			title: "Hostname"
+			source: #"""
+				is_ip("vector.dev")
+				"""#
+			return: false
+		},
+	]
+}
@@ -0,0 +1,44 @@
+package metadata
+
+remap: functions: is_private_ip: {
+	category: "IP"
+	description: """
+		Determines whether the `value` is an IP address in a private or loopback range.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The IP address to check."
+			required:    true
+			type: ["string"]
+		},
+	]
+	internal_failure_reasons: []
+	return: {
+		types: ["boolean"]
+		rules: [
+			"Returns `true` for IPv4 addresses in `10.0.0.0/8`, `172.16.0.0/12`, `192.168.0.0/16` and `127.0.0.0/8`.",
+			"Returns `true` for IPv6 unique local addresses (`fc00::/7`) and the loopback address `::1`.",
+			"IPv4-mapped IPv6 addresses are checked against the IPv4 ranges.",
+			"Returns `false` for any other address, and if `value` isn't a valid IP address.",
+		]
+	}
+
+	examples: [
+		{
+			title: "Private IPv4 address"
+			source: #"""
+				is_private_ip("192.168.0.1")
+				"""#
+			return: true
+		},
+		{
+			title: "Public IPv4 address"
+			source: #"""
+				is_private_ip("8.8.8.8")
+				"""#
+			return: false
+		},
+	]
+}
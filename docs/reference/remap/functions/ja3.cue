@@ -0,0 +1,35 @@
+package metadata
+
+remap: functions: ja3: {
+	category: "Hash"
+	description: """
+		Computes the [JA3](\(urls.ja3)) fingerprint of the TLS ClientHello `value`: the MD5 hash of
+		its JA3 string, as returned by `ja3_raw`.
+
+		The `value` is either a TLS record holding the ClientHello or the bare handshake message.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The TLS ClientHello to fingerprint."
+			required:    true
+			type: ["string"]
+		},
+	]
+	internal_failure_reasons: [
+		"`value` isn't a TLS ClientHello",
+		"`value` is truncated",
+	]
+	return: types: ["string"]
+
+	examples: [
+		{
+			title: "Fingerprint a ClientHello"
+			source: #"""
+				ja3!(decode_base64!("FgMBAHEBAABtAwEAAQIDBAUGBwgJCgsMDQ4PEBESExQVFhcYGRobHB0eHwAAGgoKAC8ANQAFAArACcAKwBPAFAAyADgAEwAEAQAAKhoaAAAAAAAQAA4AAAtleGFtcGxlLmNvbQAKAAgABgAXABgAGQALAAIBAA=="))
+				"""#
+			return: "ada70206e40642a3e4461f35503241d5"
+		},
+	]
+}
@@ -0,0 +1,36 @@
+package metadata
+
+remap: functions: ja3_raw: {
+	category: "String"
+	description: """
+		Returns the [JA3](\(urls.ja3)) string of the TLS ClientHello `value`, which `ja3` hashes.
+
+		The string lists the TLS version, the cipher suites, the extensions, the elliptic curves, and
+		the elliptic curve point formats of the ClientHello, in decimal, leaving out GREASE values.
+		The `value` is either a TLS record holding the ClientHello or the bare handshake message.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The TLS ClientHello to fingerprint."
+			required:    true
+			type: ["string"]
+		},
+	]
+	internal_failure_reasons: [
+		"`value` isn't a TLS ClientHello",
+		"`value` is truncated",
+	]
+	return: types: ["string"]
+
+	examples: [
+		{
+			title: "Describe a ClientHello"
+			source: #"""
+				ja3_raw!(decode_base64!("FgMBAHEBAABtAwEAAQIDBAUGBwgJCgsMDQ4PEBESExQVFhcYGRobHB0eHwAAGgoKAC8ANQAFAArACcAKwBPAFAAyADgAEwAEAQAAKhoaAAAAAAAQAA4AAAtleGFtcGxlLmNvbQAKAAgABgAXABgAGQALAAIBAA=="))
+				"""#
+			return: "769,47-53-5-10-49161-49162-49171-49172-50-56-19-4,0-10-11,23-24-25,0"
+		},
+	]
+}
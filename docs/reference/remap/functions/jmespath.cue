@@ -0,0 +1,63 @@
+package metadata
+
+remap: functions: jmespath: {
+	category: "Object"
+	description: """
+		Evaluates the [JMESPath](\(urls.jmespath)) `expression` against the `value`, and returns
+		the result.
+
+		The whole grammar is supported: identifiers, indices and slices, wildcards, flattening,
+		filters, multi-select lists and hashes, pipes, and the built-in functions of the
+		specification.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The value to query."
+			required:    true
+			type: ["any"]
+		},
+		{
+			name:        "expression"
+			description: "The JMESPath expression."
+			required:    true
+			type: ["string"]
+		},
+	]
+	internal_failure_reasons: [
+		"`expression` isn't a valid JMESPath expression",
+		"a function of `expression` is called with arguments of the wrong type",
+	]
+	return: {
+		types: ["any"]
+		rules: [
+			"An `expression` that doesn't match returns `null`.",
+			"A literal `expression` is parsed when the program is compiled, so that an invalid one is reported then.",
+		]
+	}
+
+	examples: [
+		{
+			title: "Project the fields of an array of objects"
+			source: #"""
+				jmespath!({ "people": [{ "name": "foo" }, { "name": "bar" }] }, "people[*].name")
+				"""#
+			return: ["foo", "bar"]
+		},
+		{
+			title: "Filter an array of objects"
+			source: #"""
+				jmespath!({ "people": [{ "name": "foo", "age": 30 }, { "name": "bar", "age": 20 }] }, "people[?age > `25`].name | [0]")
+				"""#
+			return: "foo"
+		},
+		{
+			title: "Sort an array of objects"
+			source: #"""
+				jmespath!({ "people": [{ "name": "foo", "age": 30 }, { "name": "bar", "age": 20 }] }, "sort_by(people, &age)[*].name")
+				"""#
+			return: ["bar", "foo"]
+		},
+	]
+}
@@ -0,0 +1,46 @@
+package metadata
+
+remap: functions: join_path: {
+	category: "String"
+	description: """
+		Joins the strings in the `parts` array into a path, separated by forward slashes, or by backslashes if
+		`windows` is set. Separators are collapsed where the parts meet and empty parts are skipped.
+		"""
+
+	arguments: [
+		{
+			name:        "parts"
+			description: "The array of path components to join."
+			required:    true
+			type: ["array"]
+		},
+		{
+			name:        "windows"
+			description: "If set to `true`, the parts are joined with backslashes, which also count as separators."
+			required:    false
+			default:     false
+			type: ["boolean"]
+		},
+	]
+	internal_failure_reasons: [
+		"`parts` contains a non-string item",
+	]
+	return: types: ["string"]
+
+	examples: [
+		{
+			title: "Join a path"
+			source: #"""
+				join_path!(["/var/log/", "app", "current.log"])
+				"""#
+			return: "/var/log/app/current.log"
+		},
+		{
+			title: "Join a Windows path"
+			source: #"""
+				join_path!(["C:", "Logs", "app.log"], windows: true)
+				"""#
+			return: #"C:\Logs\app.log"#
+		},
+	]
+}
@@ -0,0 +1,76 @@
+package metadata
+
+remap: functions: like: {
+	category: "String"
+	description: """
+		Determines whether the `value` matches the SQL `LIKE` `pattern`, as a whole.
+
+		In the pattern, `%` matches any sequence of characters, including none, and `_` matches any single
+		character. Preceded by the `escape` character, any character, including `%`, `_` and the escape
+		character itself, matches only itself.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The string to match."
+			required:    true
+			type: ["string"]
+		},
+		{
+			name:        "pattern"
+			description: "The `LIKE` pattern to match against."
+			required:    true
+			type: ["string"]
+		},
+		{
+			name:        "escape"
+			description: "The character escaping wildcards in the `pattern`, a single character. It must be a literal string."
+			required:    false
+			default:     "\\"
+			type: ["string"]
+		},
+		{
+			name:        "case_sensitive"
+			description: "If set to `false`, letters match regardless of their case."
+			required:    false
+			default:     true
+			type: ["boolean"]
+		},
+	]
+	internal_failure_reasons: [
+		"`pattern` ends with the `escape` character",
+	]
+	return: types: ["boolean"]
+
+	examples: [
+		{
+			title: "Match any sequence of characters"
+			source: #"""
+				like("GET /api/users", "GET /api/%")
+				"""#
+			return: true
+		},
+		{
+			title: "Match single characters"
+			source: #"""
+				like("HTTP/1.1", "HTTP/_._")
+				"""#
+			return: true
+		},
+		{
+			title: "Match a literal percent sign"
+			source: #"""
+				like("50% off", "50!%%", escape: "!")
+				"""#
+			return: true
+		},
+		{
+			title: "Match regardless of case"
+			source: #"""
+				like("ERROR: disk full", "error:%", case_sensitive: false)
+				"""#
+			return: true
+		},
+	]
+}
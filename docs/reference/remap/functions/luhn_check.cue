@@ -0,0 +1,45 @@
+package metadata
+
+remap: functions: luhn_check: {
+	category: "String"
+	description: """
+		Determines whether the `value` passes the [Luhn checksum](\(urls.luhn)) used to validate
+		credit card numbers and other identification numbers.
+		"""
+	notices: [
+		"""
+			Spaces and dashes are ignored, making it possible to check formatted card numbers such
+			as `4111 1111 1111 1111`.
+			""",
+	]
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The number to check."
+			required:    true
+			type: ["string"]
+		},
+	]
+	internal_failure_reasons: [
+		"`value` contains characters other than digits, spaces, and dashes",
+	]
+	return: types: ["boolean"]
+
+	examples: [
+		{
+			title: "Valid number"
+			source: #"""
+				luhn_check!("4111 1111 1111 1111")
+				"""#
+			return: true
+		},
+		{
+			title: "Invalid number"
+			source: #"""
+				luhn_check!("4111 1111 1111 1112")
+				"""#
+			return: false
+		},
+	]
+}
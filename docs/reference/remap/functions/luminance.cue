@@ -0,0 +1,39 @@
+package metadata
+
+remap: functions: luminance: {
+	category: "Number"
+	description: #"""
+		Returns the [relative luminance](\(urls.wcag_relative_luminance)) of the `color`, from `0` for black to `1`
+		for white, as defined by the Web Content Accessibility Guidelines. The opacity of the `color` is ignored.
+		"""#
+
+	arguments: [
+		{
+			name:        "color"
+			description: "The color, as a string accepted by `parse_color` or an object returned by it."
+			required:    true
+			type: ["string", "object"]
+		},
+	]
+	internal_failure_reasons: [
+		"`color` isn't a valid color",
+	]
+	return: types: ["float"]
+
+	examples: [
+		{
+			title: "Luminance of a named color"
+			source: #"""
+				luminance!("red")
+				"""#
+			return: 0.2126
+		},
+		{
+			title: "Luminance of a parsed color"
+			source: #"""
+				luminance!({ "r": 255, "g": 255, "b": 255 })
+				"""#
+			return: 1.0
+		},
+	]
+}
@@ -0,0 +1,52 @@
+package metadata
+
+remap: functions: mac_vendor: {
+	category: "IP"
+	description: """
+		Returns the vendor of the MAC address `value`, found by its organizationally unique identifier
+		(OUI), the first three octets of the address.
+
+		The vendors are looked up in a bundled database of well-known vendors, or in the `database`
+		file if one is given. Unknown OUIs and locally administered addresses, which have no OUI,
+		return `null`. The `value` can be written in any of the forms accepted by `parse_mac_address`.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The MAC address to look up."
+			required:    true
+			type: ["string"]
+		},
+		{
+			name: "database"
+			description: """
+				The path of a file in the format of the IEEE registry's `oui.txt`, replacing the bundled
+				database. The file is read once, when the program is compiled.
+				"""
+			required: false
+			type: ["string"]
+		},
+	]
+	internal_failure_reasons: [
+		"`value` isn't a valid MAC address",
+	]
+	return: types: ["string", "null"]
+
+	examples: [
+		{
+			title: "Look up a known vendor"
+			source: #"""
+				mac_vendor!("00:1b:63:84:45:e6")
+				"""#
+			return: "Apple, Inc."
+		},
+		{
+			title: "Look up a locally administered address"
+			source: #"""
+				mac_vendor!("02:42:ac:11:00:02")
+				"""#
+			return: null
+		},
+	]
+}
@@ -0,0 +1,66 @@
+package metadata
+
+remap: functions: mask: {
+	category: "String"
+	description: """
+		Replaces the characters of the `value` string with `mask_char`, except for the first
+		`keep_start` and the last `keep_end` ones.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The string to mask."
+			required:    true
+			type: ["string"]
+		},
+		{
+			name:        "keep_start"
+			description: "The number of leading characters to leave unmasked."
+			required:    false
+			default:     0
+			type: ["integer"]
+		},
+		{
+			name:        "keep_end"
+			description: "The number of trailing characters to leave unmasked."
+			required:    false
+			default:     4
+			type: ["integer"]
+		},
+		{
+			name:        "mask_char"
+			description: "The character to mask with."
+			required:    false
+			default:     "*"
+			type: ["string"]
+		},
+	]
+	internal_failure_reasons: [
+		"`keep_start` or `keep_end` is negative",
+		"`mask_char` isn't a single character",
+	]
+	return: {
+		types: ["string"]
+		rules: [
+			"If `value` has no more than `keep_start + keep_end` characters, it's returned unchanged.",
+		]
+	}
+
+	examples: [
+		{
+			title: "Mask a card number"
+			source: #"""
+				mask!("4111111111111111", keep_start: 4)
+				"""#
+			return: "4111********1111"
+		},
+		{
+			title: "Mask with a custom character"
+			source: #"""
+				mask!("secret", keep_end: 0, mask_char: "#")
+				"""#
+			return: "######"
+		},
+	]
+}
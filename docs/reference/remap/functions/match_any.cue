@@ -0,0 +1,45 @@
+package metadata
+
+remap: functions: match_any: {
+	category: "String"
+	description: """
+		Determines whether the `value` matches any of the `patterns`.
+
+		All patterns are matched in a single pass. If `patterns` is an array literal, it's compiled
+		once, when the program is compiled, and invalid patterns are reported as compile-time errors.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The value to match."
+			required:    true
+			type: ["string"]
+		},
+		{
+			name:        "patterns"
+			description: "The array of regular expression patterns to match against, as regexes or strings."
+			required:    true
+			type: ["array"]
+		},
+	]
+	internal_failure_reasons: [
+		"`patterns` isn't an array literal, and contains a value that isn't a valid regular expression",
+	]
+	return: {
+		types: ["boolean"]
+		rules: [
+			"Returns `false` if `patterns` is empty.",
+		]
+	}
+
+	examples: [
+		{
+			title: "Match against a blocklist"
+			source: #"""
+				match_any("I'm a little teapot", [r'^kettle', r'teapot$'])
+				"""#
+			return: true
+		},
+	]
+}
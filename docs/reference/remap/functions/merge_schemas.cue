@@ -0,0 +1,53 @@
+package metadata
+
+remap: functions: merge_schemas: {
+	category: "Type"
+	description: """
+		Merges two schemas, such as those inferred by [`infer_schema`](#infer_schema), into one
+		that describes the values of both, to accumulate the schema of many events.
+		"""
+
+	arguments: [
+		{
+			name:        "a"
+			description: "The first schema."
+			required:    true
+			type: ["object"]
+		},
+		{
+			name:        "b"
+			description: "The second schema."
+			required:    true
+			type: ["object"]
+		},
+	]
+	internal_failure_reasons: [
+		"`a` or `b` isn't a valid schema",
+	]
+	return: {
+		types: ["object"]
+		rules: [
+			"Types are widened: `integer` and `number` become `number`, and other differing types become an array of them.",
+			"The `properties` of both schemas are merged, and only the fields `required` by both stay required.",
+			"The `items` of arrays are merged.",
+			"A `format` is kept only if both schemas of strings have it.",
+		]
+	}
+
+	examples: [
+		{
+			title: "Merge the schemas of two events"
+			source: #"""
+				merge_schemas!(infer_schema({ "id": 1, "name": "foo" }), infer_schema({ "id": "2" }))
+				"""#
+			return: {
+				type: "object"
+				properties: {
+					id: type: ["integer", "string"]
+					name: type: "string"
+				}
+				required: ["id"]
+			}
+		},
+	]
+}
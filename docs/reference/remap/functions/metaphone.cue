@@ -0,0 +1,46 @@
+package metadata
+
+remap: functions: metaphone: {
+	category: "String"
+	description: """
+		Returns the Metaphone code of the `value` word, so that words that sound alike can be
+		matched. It's more accurate than Soundex, as it takes the pronunciation of groups of letters
+		into account.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The word to encode."
+			required:    true
+			type: ["string"]
+		},
+		{
+			name:        "ignore_invalid"
+			description: "Whether to leave out characters that aren't ASCII letters, instead of failing."
+			required:    false
+			default:     false
+			type: ["boolean"]
+		},
+	]
+	internal_failure_reasons: [
+		"`value` contains a character that isn't an ASCII letter, and `ignore_invalid` isn't set",
+	]
+	return: {
+		types: ["string"]
+		rules: [
+			"The code uses uppercase letters, `0` stands for the \"th\" sound and `X` for the \"sh\" sound.",
+			"The length of the code isn't limited.",
+		]
+	}
+
+	examples: [
+		{
+			title: "Encode a word"
+			source: #"""
+				metaphone!("Knight")
+				"""#
+			return: "NT"
+		},
+	]
+}
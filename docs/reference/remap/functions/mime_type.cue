@@ -0,0 +1,47 @@
+package metadata
+
+remap: functions: mime_type: {
+	category: "String"
+	description: """
+		Returns the media (MIME) type of the file extension, file name, or path `value`, or `null` if it's unknown.
+
+		The types are looked up in a bundled table of common media types, by the longest known extension of the
+		file name, so that `site.tar.gz` is a compressed tarball rather than a gzip file. Extensions are matched
+		regardless of their case, and can be given with or without their leading dot.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The file extension, file name, or path."
+			required:    true
+			type: ["string"]
+		},
+	]
+	internal_failure_reasons: []
+	return: types: ["string", "null"]
+
+	examples: [
+		{
+			title: "Look up an extension"
+			source: #"""
+				mime_type("json")
+				"""#
+			return: "application/json"
+		},
+		{
+			title: "Look up a file with a compound extension"
+			source: #"""
+				mime_type("/backups/site.tar.gz")
+				"""#
+			return: "application/x-compressed-tar"
+		},
+		{
+			title: "Look up an unknown extension"
+			source: #"""
+				mime_type("data.unknown")
+				"""#
+			return: null
+		},
+	]
+}
@@ -0,0 +1,43 @@
+package metadata
+
+remap: functions: moving_average: {
+	category: "Number"
+	description: """
+		Returns the means of each `window` consecutive elements of the `value` array of numbers.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The array of numbers."
+			required:    true
+			type: ["array"]
+		},
+		{
+			name:        "window"
+			description: "The number of elements to average."
+			required:    true
+			type: ["integer"]
+		},
+	]
+	internal_failure_reasons: [
+		"`value` contains an element that isn't a number",
+		"`window` is less than `1` or greater than the length of `value`",
+	]
+	return: {
+		types: ["array"]
+		rules: [
+			"The first mean is that of the first `window` elements, so the result has `window - 1` elements less than `value`.",
+		]
+	}
+
+	examples: [
+		{
+			title: "Moving average"
+			source: #"""
+				moving_average!([1, 2, 3, 4], 2)
+				"""#
+			return: [1.5, 2.5, 3.5]
+		},
+	]
+}
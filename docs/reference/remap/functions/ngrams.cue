@@ -0,0 +1,51 @@
+package metadata
+
+remap: functions: ngrams: {
+	category: "Array"
+	description: """
+		Returns the sequences of `n` consecutive elements of the `value` array, or of `n`
+		consecutive characters of the `value` string.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The array or string to return the n-grams of."
+			required:    true
+			type: ["array", "string"]
+		},
+		{
+			name:        "n"
+			description: "The number of elements or characters in each n-gram."
+			required:    true
+			type: ["integer"]
+		},
+	]
+	internal_failure_reasons: [
+		"`n` is less than `1`",
+	]
+	return: {
+		types: ["array"]
+		rules: [
+			"Each n-gram of an array is an array, and each n-gram of a string is a string.",
+			"Returns an empty array if `value` has less than `n` elements or characters.",
+		]
+	}
+
+	examples: [
+		{
+			title: "Word bigrams"
+			source: #"""
+				ngrams!(tokenize!("The quick brown fox"), 2)
+				"""#
+			return: [["The", "quick"], ["quick", "brown"], ["brown", "fox"]]
+		},
+		{
+			title: "Character trigrams"
+			source: #"""
+				ngrams!("vector", 3)
+				"""#
+			return: ["vec", "ect", "cto", "tor"]
+		},
+	]
+}
@@ -0,0 +1,67 @@
+package metadata
+
+remap: functions: normalize_email: {
+	category: "String"
+	description: """
+		Normalizes the `value` email address, so that addresses delivered to the same mailbox can be
+		compared.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The email address to normalize."
+			required:    true
+			type: ["string"]
+		},
+		{
+			name:        "strip_dots"
+			description: "Whether to remove the dots from the local part, as Gmail ignores them."
+			required:    false
+			default:     false
+			type: ["boolean"]
+		},
+		{
+			name:        "strip_tags"
+			description: "Whether to remove the `+tag` suffix from the local part."
+			required:    false
+			default:     false
+			type: ["boolean"]
+		},
+	]
+	internal_failure_reasons: [
+		"`value` isn't a properly formatted email address",
+		"the domain of `value` isn't a valid internationalized domain name",
+	]
+	return: {
+		types: ["string"]
+		rules: [
+			"The domain is lowercased, and internationalized domains are converted to their ASCII (punycode) form.",
+			"The local part is otherwise left unchanged, as it may be case-sensitive.",
+		]
+	}
+
+	examples: [
+		{
+			title: "Lowercase the domain"
+			source: #"""
+				normalize_email!("First.Last+news@Gmail.COM")
+				"""#
+			return: "First.Last+news@gmail.com"
+		},
+		{
+			title: "Strip dots and tags"
+			source: #"""
+				normalize_email!("First.Last+news@Gmail.COM", strip_dots: true, strip_tags: true)
+				"""#
+			return: "FirstLast@gmail.com"
+		},
+		{
+			title: "Internationalized domain"
+			source: #"""
+				normalize_email!("info@Bücher.de")
+				"""#
+			return: "info@xn--bcher-kva.de"
+		},
+	]
+}
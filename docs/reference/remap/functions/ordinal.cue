@@ -0,0 +1,36 @@
+package metadata
+
+remap: functions: ordinal: {
+	category: "Number"
+	description: """
+		Formats the `value` as an English ordinal number, such as `1st`, `2nd`, `3rd` or `11th`.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The number to format."
+			required:    true
+			type: ["integer"]
+		},
+	]
+	internal_failure_reasons: []
+	return: types: ["string"]
+
+	examples: [
+		{
+			title: "Format an ordinal number"
+			source: #"""
+				ordinal(22)
+				"""#
+			return: "22nd"
+		},
+		{
+			title: "Format an ordinal number ending in 11, 12 or 13"
+			source: #"""
+				ordinal(112)
+				"""#
+			return: "112th"
+		},
+	]
+}
@@ -0,0 +1,70 @@
+package metadata
+
+remap: functions: parse_color: {
+	category: "Parse"
+	description: #"""
+		Parses the `value` into the red, green and blue channels and the opacity of a color.
+
+		The `value` can be a hexadecimal color, such as `#36c`, `#3366cc` or `#3366cc80`, an `rgb(51, 102, 204)`
+		or `rgba(51, 102, 204, 0.5)` color, or one of the [CSS named colors](\(urls.css_named_colors)), such as
+		`aliceblue`, or `transparent`. Case and surrounding whitespace are ignored.
+		"""#
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The string to parse."
+			required:    true
+			type: ["string"]
+		},
+	]
+	internal_failure_reasons: [
+		"`value` isn't a valid color",
+	]
+	return: {
+		types: ["object"]
+		rules: [
+			"The `r`, `g` and `b` channels are integers between `0` and `255`.",
+			"The `a` opacity is a float between `0`, for transparent, and `1`, for opaque.",
+		]
+	}
+
+	examples: [
+		{
+			title: "Parse a hexadecimal color"
+			source: #"""
+				parse_color!("#f0f8ff80")
+				"""#
+			return: {
+				r: 240
+				g: 248
+				b: 255
+				a: 0.5019607843137255
+			}
+		},
+		{
+			title: "Parse an rgba() color"
+			source: #"""
+				parse_color!("rgba(255, 0, 0, 0.5)")
+				"""#
+			return: {
+				r: 255
+				g: 0
+				b: 0
+				a: 0.5
+			}
+		},
+		{
+			title: "Parse a named color"
+			source: #"""
+				parse_color!("rebeccapurple")
+				"""#
+			return: {
+				r: 102
+				g: 51
+				b: 153
+				a: 1.0
+			}
+		},
+	]
+}
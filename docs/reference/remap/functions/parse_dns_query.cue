@@ -0,0 +1,65 @@
+package metadata
+
+remap: functions: parse_dns_query: {
+	category: "Parse"
+	description: """
+		Parses the `value` as a DNS message in the wire format, such as the queries and responses
+		captured by dnstap.
+
+		The result has the `id`, `opcode`, and `rcode` of the message, its header `flags`, its
+		`questions`, and its `answers`. The data of `A` and `AAAA` records is the IP address, the data of
+		`CNAME`, `NS`, and `PTR` records is the domain name, the data of `MX` records is an object with
+		the `preference` and the `exchange`, and the data of `TXT` records is an array of strings. The
+		data of other records is the raw bytes.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The DNS message to parse."
+			required:    true
+			type: ["string"]
+		},
+	]
+	internal_failure_reasons: [
+		"`value` is truncated",
+		"`value` has an invalid domain name compression pointer",
+		"an `A` or `AAAA` record has data of the wrong length",
+	]
+	return: types: ["object"]
+
+	examples: [
+		{
+			title: "Parse an A record response"
+			source: #"""
+				parse_dns_query!(decode_base64!("EjSBgAABAAEAAAAAB2V4YW1wbGUDY29tAAABAAHADAABAAEAAA4QAARduNgi"))
+				"""#
+			return: {
+				id:     4660
+				opcode: 0
+				rcode:  "NOERROR"
+				flags: {
+					qr: true
+					aa: false
+					tc: false
+					rd: true
+					ra: true
+					ad: false
+					cd: false
+				}
+				questions: [{
+					name:  "example.com"
+					type:  "A"
+					class: "IN"
+				}]
+				answers: [{
+					name:  "example.com"
+					type:  "A"
+					class: "IN"
+					ttl:   3600
+					data:  "93.184.216.34"
+				}]
+			}
+		},
+	]
+}
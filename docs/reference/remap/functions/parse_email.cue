@@ -0,0 +1,40 @@
+package metadata
+
+remap: functions: parse_email: {
+	category: "Parse"
+	description: """
+		Parses the `value` email address into its local part and domain.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The email address to parse."
+			required:    true
+			type: ["string"]
+		},
+	]
+	internal_failure_reasons: [
+		"`value` isn't a properly formatted email address",
+	]
+	return: {
+		types: ["map"]
+		rules: [
+			"The address is split at its last `@`.",
+			"Neither part is otherwise modified.",
+		]
+	}
+
+	examples: [
+		{
+			title: "Parse email address"
+			source: #"""
+				parse_email!("vector@timber.io")
+				"""#
+			return: {
+				local:  "vector"
+				domain: "timber.io"
+			}
+		},
+	]
+}
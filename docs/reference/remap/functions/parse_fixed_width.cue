@@ -0,0 +1,56 @@
+package metadata
+
+remap: functions: parse_fixed_width: {
+	category: "Parse"
+	description: """
+		Parses the `value` fixed-width record into an object, using the columns defined by `spec`.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The fixed-width record to parse."
+			required:    true
+			type: ["string"]
+		},
+		{
+			name: "spec"
+			description: """
+				The columns of the record, as an array of objects with a `name`, a `start` position and a
+				`length`. `width` can be used instead of `length`, and `start` defaults to the end of the
+				previous column, so that consecutive columns only need a `width`. Positions are counted in
+				characters, starting at `0`.
+				"""
+			required: true
+			type: ["array"]
+		},
+	]
+	internal_failure_reasons: [
+		"`spec` contains an invalid column definition",
+	]
+	return: {
+		types: ["object"]
+		rules: [
+			"The whitespace around each field is trimmed.",
+			"Columns starting past the end of `value` are `null`, and columns running past it are cut short.",
+		]
+	}
+
+	examples: [
+		{
+			title: "Parse a fixed-width record"
+			source: #"""
+				parse_fixed_width!("00042JOHN     SMITH", [
+					{ "name": "id", "width": 5 },
+					{ "name": "first", "width": 9 },
+					{ "name": "last", "width": 5 },
+				])
+				"""#
+			return: {
+				id:    "00042"
+				first: "JOHN"
+				last:  "SMITH"
+			}
+		},
+	]
+}
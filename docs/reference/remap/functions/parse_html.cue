@@ -0,0 +1,52 @@
+package metadata
+
+remap: functions: parse_html: {
+	category: "Parse"
+	description: """
+		Parses the `value` as HTML, and returns the text content of every element matching the
+		`selector`, in document order.
+		"""
+	notices: [
+		"""
+			Only a subset of CSS selectors is supported: a tag name, an `#id`, one or more
+			`.class` names, or a combination of those, such as `p.note`. Combinators (such as
+			`div p`) and attribute selectors aren't supported.
+			""",
+	]
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The HTML to parse."
+			required:    true
+			type: ["string"]
+		},
+		{
+			name:        "selector"
+			description: "The CSS selector matching the elements to extract."
+			required:    true
+			type: ["string"]
+		},
+	]
+	internal_failure_reasons: [
+		"`selector` isn't a supported CSS selector",
+	]
+	return: types: ["array"]
+
+	examples: [
+		{
+			title: "Extract text by class"
+			source: #"""
+				parse_html!(s'<p class="note">a &amp; b</p><p>c</p>', ".note")
+				"""#
+			return: ["a & b"]
+		},
+		{
+			title: "Extract text by tag"
+			source: #"""
+				parse_html!("<ul><li>one</li><li>two</li></ul>", "li")
+				"""#
+			return: ["one", "two"]
+		},
+	]
+}
@@ -0,0 +1,57 @@
+package metadata
+
+remap: functions: parse_influx: {
+	category: "Parse"
+	description: """
+		Parses the `value` as a line of the [InfluxDB line protocol](\(urls.influxdb_line_protocol)).
+
+		Escaped commas, spaces, and equal signs are unescaped in the measurement, tag keys, tag values,
+		and field keys. Field values suffixed with `i` (or `u`) are integers, quoted ones are strings,
+		`true` and `false` are booleans, and any other number is a float. The timestamp, in nanoseconds
+		since the Unix epoch, is optional and is `null` when missing.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The line to parse."
+			required:    true
+			type: ["string"]
+		},
+	]
+	internal_failure_reasons: [
+		"`value` isn't a properly formatted line protocol line",
+		"`value` has no fields",
+	]
+	return: types: ["object"]
+
+	examples: [
+		{
+			title: "Parse a line"
+			source: #"""
+				parse_influx!("cpu,host=a usage=0.5,cores=4i 1615000000000000000")
+				"""#
+			return: {
+				measurement: "cpu"
+				tags: host: "a"
+				fields: {
+					usage: 0.5
+					cores: 4
+				}
+				timestamp: "2021-03-06T03:06:40Z"
+			}
+		},
+		{
+			title: "Parse a line with escaped characters"
+			source: #"""
+				parse_influx!(s'weather,city=New\ York description="light rain"')
+				"""#
+			return: {
+				measurement: "weather"
+				tags: city: "New York"
+				fields: description: "light rain"
+				timestamp: null
+			}
+		},
+	]
+}
@@ -0,0 +1,61 @@
+package metadata
+
+remap: functions: parse_iso8601_duration: {
+	category: "Parse"
+	description: """
+		Parses the `value`, an ISO 8601 duration such as `P1DT2H`, into its total length in the `unit`.
+
+		Years and months don't have a fixed length, they're approximated as 365 and 30 days. Weeks are
+		7 days. Any component can be fractional, with either a `.` or a `,` as the decimal separator.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The ISO 8601 duration."
+			required:    true
+			type: ["string"]
+		},
+		{
+			name:        "unit"
+			description: "The output units for the duration."
+			required:    false
+			default:     "s"
+			type: ["string"]
+			enum: {
+				ns: "Nanoseconds (1 billion nanoseconds in a second)"
+				us: "Microseconds (1 million microseconds in a second)"
+				µs: "Microseconds (1 million microseconds in a second)"
+				ms: "Milliseconds (1 thousand microseconds in a second)"
+				cs: "Centiseconds (100 centiseconds in a second)"
+				ds: "Deciseconds (10 deciseconds in a second)"
+				s:  "Seconds"
+				m:  "Minutes (60 seconds in a minute)"
+				h:  "Hours (60 minutes in an hour)"
+				d:  "Days (24 hours in a day)"
+			}
+		},
+	]
+	internal_failure_reasons: [
+		"`value` isn't a properly formatted ISO 8601 duration",
+		"`unit` isn't one of the supported units",
+	]
+	return: types: ["float"]
+
+	examples: [
+		{
+			title: "Parse ISO 8601 duration (seconds)"
+			source: #"""
+				parse_iso8601_duration!("P1DT2H")
+				"""#
+			return: 93600.0
+		},
+		{
+			title: "Parse ISO 8601 duration (minutes)"
+			source: #"""
+				parse_iso8601_duration!("PT1H30M", unit: "m")
+				"""#
+			return: 90.0
+		},
+	]
+}
@@ -0,0 +1,43 @@
+package metadata
+
+remap: functions: parse_mac_address: {
+	category: "Parse"
+	description: """
+		Parses the `value` as a MAC address, returning it in its canonical form: lowercase pairs of
+		digits separated by colons, such as `00:1b:63:84:45:e6`.
+
+		The `value` can be written with colons (`00:1B:63:84:45:E6`), with dashes
+		(`00-1B-63-84-45-E6`), in the dotted Cisco style (`001b.6384.45e6`), or without separators
+		(`001B638445E6`), in either case.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The MAC address to parse."
+			required:    true
+			type: ["string"]
+		},
+	]
+	internal_failure_reasons: [
+		"`value` isn't a valid MAC address",
+	]
+	return: types: ["string"]
+
+	examples: [
+		{
+			title: "Parse a MAC address with dashes"
+			source: #"""
+				parse_mac_address!("00-1B-63-84-45-E6")
+				"""#
+			return: "00:1b:63:84:45:e6"
+		},
+		{
+			title: "Parse a Cisco MAC address"
+			source: #"""
+				parse_mac_address!("001b.6384.45e6")
+				"""#
+			return: "00:1b:63:84:45:e6"
+		},
+	]
+}
@@ -0,0 +1,51 @@
+package metadata
+
+remap: functions: parse_markdown: {
+	category: "Parse"
+	description: """
+		Parses the `value` as a [CommonMark](\(urls.commonmark)) document, extracting its headings,
+		paragraphs, code blocks, and links.
+		"""
+	notices: [
+		"""
+			Parsing is lenient: any valid UTF-8 string produces a result, even if it doesn't contain
+			any of the extracted elements.
+			""",
+	]
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The markdown document to parse."
+			required:    true
+			type: ["string"]
+		},
+	]
+	internal_failure_reasons: [
+		"`value` isn't valid UTF-8",
+	]
+	return: {
+		types: ["object"]
+		rules: [
+			"`headings` contains the `level` and `text` of each heading, in document order.",
+			"`paragraphs` contains the plain text of each paragraph.",
+			"`code_blocks` contains the `code` of each code block, and its `language` if it's a fenced block with an info string.",
+			"`links` contains the `text`, `url`, and `title` of each link.",
+		]
+	}
+
+	examples: [
+		{
+			title: "Parse a markdown document"
+			source: #"""
+				parse_markdown!("# Vector\nA [fast](https://vector.dev) pipeline.")
+				"""#
+			return: {
+				headings: [{level: 1, text: "Vector"}]
+				paragraphs: ["A fast pipeline."]
+				code_blocks: []
+				links: [{text: "fast", url: "https://vector.dev", title: null}]
+			}
+		},
+	]
+}
@@ -0,0 +1,67 @@
+package metadata
+
+remap: functions: parse_number_array: {
+	category:    "Parse"
+	description: """
+		Parses the `value` delimited list of numbers into an array of integers and floats.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The delimited list of numbers to parse."
+			required:    true
+			type: ["string"]
+		},
+		{
+			name:        "delimiter"
+			description: "The string separating the numbers."
+			required:    false
+			default:     ","
+			type: ["string"]
+		},
+		{
+			name:        "skip_invalid"
+			description: "Whether to leave out elements that aren't numbers, instead of failing."
+			required:    false
+			default:     false
+			type: ["boolean"]
+		},
+	]
+	internal_failure_reasons: [
+		"an element of `value` isn't a number, and `skip_invalid` isn't set",
+		"`delimiter` is empty",
+	]
+	return: {
+		types: ["array"]
+		rules: [
+			"Whitespace around each element is ignored.",
+			"Elements are parsed as integers if possible, and as floats otherwise, preserving their type.",
+			"An empty `value` returns an empty array.",
+		]
+	}
+
+	examples: [
+		{
+			title: "Parse a list of numbers"
+			source: #"""
+				parse_number_array!("1, 2.5, -3")
+				"""#
+			return: [1, 2.5, -3]
+		},
+		{
+			title: "Parse a list with a custom delimiter"
+			source: #"""
+				parse_number_array!("1;2;3", delimiter: ";")
+				"""#
+			return: [1, 2, 3]
+		},
+		{
+			title: "Skip invalid elements"
+			source: #"""
+				parse_number_array!("1,n/a,3", skip_invalid: true)
+				"""#
+			return: [1, 3]
+		},
+	]
+}
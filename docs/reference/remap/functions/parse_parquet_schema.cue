@@ -0,0 +1,48 @@
+package metadata
+
+remap: functions: parse_parquet_schema: {
+	category: "Parse"
+	description: """
+		Parses the schema and the number of rows of the Parquet file `value` from its
+		[metadata](\(urls.parquet_metadata)), in the footer of the file. The row data isn't read.
+
+		The schema is returned as its leaf columns, whose names are their paths through the groups of the schema,
+		separated by dots, such as `address.city`. Each column has its physical `type`, such as `INT64` or
+		`BYTE_ARRAY`, its `repetition`, `REQUIRED`, `OPTIONAL` or `REPEATED`, and its `converted_type`, such as
+		`UTF8`, or `null` if it has none.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The bytes of the Parquet file."
+			required:    true
+			type: ["string"]
+		},
+	]
+	internal_failure_reasons: [
+		"`value` isn't a Parquet file",
+		"`value` has invalid or truncated metadata",
+	]
+	return: types: ["object"]
+
+	examples: [
+		{
+			title: "Parse the schema of a Parquet file"
+			source: #"""
+				parse_parquet_schema!(decode_base64!("UEFSMRUCGSxIBnNjaGVtYRUCABUEJQAYAmlkABYAGQwAHQAAAFBBUjE="))
+				"""#
+			return: {
+				version:    1
+				num_rows:   0
+				created_by: null
+				columns: [{
+					name:           "id"
+					type:           "INT64"
+					repetition:     "REQUIRED"
+					converted_type: null
+				}]
+			}
+		},
+	]
+}
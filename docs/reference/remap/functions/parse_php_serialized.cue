@@ -0,0 +1,48 @@
+package metadata
+
+remap: functions: parse_php_serialized: {
+	category: "Parse"
+	description: """
+		Parses the `value` as the output of PHP's `serialize` function.
+		"""
+	notices: [
+		"""
+			Arrays with sequential integer keys starting at zero are returned as arrays, all other
+			arrays are returned as objects. Objects are returned as objects of their properties, the
+			class name is discarded.
+			""",
+	]
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The PHP serialized string to parse."
+			required:    true
+			type: ["string"]
+		},
+	]
+	internal_failure_reasons: [
+		"`value` isn't a valid PHP serialized string",
+	]
+	return: types: ["boolean", "integer", "float", "string", "object", "array", "null"]
+
+	examples: [
+		{
+			title: "Parse a PHP associative array"
+			source: #"""
+				parse_php_serialized!(s'a:2:{s:4:"name";s:6:"vector";s:5:"stars";i:9001;}')
+				"""#
+			return: {
+				name:  "vector"
+				stars: 9001
+			}
+		},
+		{
+			title: "Parse a PHP list"
+			source: #"""
+				parse_php_serialized!(s'a:2:{i:0;b:1;i:1;N;}')
+				"""#
+			return: [true, null]
+		},
+	]
+}
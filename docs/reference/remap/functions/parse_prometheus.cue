@@ -0,0 +1,62 @@
+package metadata
+
+remap: functions: parse_prometheus: {
+	category: "Parse"
+	description: """
+		Parses the `value` as metrics in the [Prometheus text exposition format](\(urls.prometheus_text_based_exposition_format)),
+		returning an array with one object per sample.
+
+		The `# TYPE` and `# HELP` comments of a metric are associated with its samples, including the
+		`_bucket`, `_sum`, and `_count` samples of histograms and summaries. Samples without a `# TYPE`
+		comment are `untyped`, and samples without a `# HELP` comment have a `null` help. The value of
+		`NaN` samples is `null`, and the optional timestamp, in milliseconds since the Unix epoch, is
+		`null` when missing.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The text to parse."
+			required:    true
+			type: ["string"]
+		},
+	]
+	internal_failure_reasons: [
+		"`value` isn't properly formatted Prometheus text",
+		"a `# TYPE` comment has an unknown metric type",
+	]
+	return: types: ["array"]
+
+	examples: [
+		{
+			title: "Parse a counter"
+			source: #"""
+				parse_prometheus!(s'# HELP http_requests_total The number of requests.
+				# TYPE http_requests_total counter
+				http_requests_total{code="200"} 1027 1395066363000')
+				"""#
+			return: [{
+				name: "http_requests_total"
+				labels: code: "200"
+				value:     1027.0
+				timestamp: "2014-03-17T14:26:03Z"
+				type:      "counter"
+				help:      "The number of requests."
+			}]
+		},
+		{
+			title: "Parse a sample without metadata"
+			source: #"""
+				parse_prometheus!("temperature 21.5")
+				"""#
+			return: [{
+				name: "temperature"
+				labels: {}
+				value:     21.5
+				timestamp: null
+				type:      "untyped"
+				help:      null
+			}]
+		},
+	]
+}
@@ -0,0 +1,48 @@
+package metadata
+
+remap: functions: parse_ruby_hash: {
+	category: "Parse"
+	description: """
+		Parses the `value` as the output of Ruby's `Hash#inspect`.
+
+		Both the `:key=>value` and the `key: value` syntax are supported. Symbols are
+		converted to strings and `nil` is converted to `null`.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The string representation of the Ruby hash to parse."
+			required:    true
+			type: ["string"]
+		},
+	]
+	internal_failure_reasons: [
+		"`value` isn't a valid Ruby hash",
+	]
+	return: types: ["object"]
+
+	examples: [
+		{
+			title: "Parse a Ruby hash with symbol keys"
+			source: #"""
+				parse_ruby_hash!(s'{:user=>"alice", :roles=>[:admin, :dev], :age=>42}')
+				"""#
+			return: {
+				user: "alice"
+				roles: ["admin", "dev"]
+				age: 42
+			}
+		},
+		{
+			title: "Parse a Ruby hash with shorthand keys"
+			source: #"""
+				parse_ruby_hash!(s'{status: "ok", retry: nil}')
+				"""#
+			return: {
+				status: "ok"
+				retry:  null
+			}
+		},
+	]
+}
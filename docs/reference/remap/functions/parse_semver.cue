@@ -0,0 +1,55 @@
+package metadata
+
+remap: functions: parse_semver: {
+	category: "Parse"
+	description: """
+		Parses the `value` as a [semantic version](\(urls.semver)).
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The semantic version to parse."
+			required:    true
+			type: ["string"]
+		},
+	]
+	internal_failure_reasons: [
+		"`value` isn't a valid semantic version",
+	]
+	return: {
+		types: ["object"]
+		rules: [
+			"`prerelease` and `build` are `null` if the version doesn't have them.",
+		]
+	}
+
+	examples: [
+		{
+			title: "Parse a release version"
+			source: #"""
+				parse_semver!("1.2.3")
+				"""#
+			return: {
+				major:      1
+				minor:      2
+				patch:      3
+				prerelease: null
+				build:      null
+			}
+		},
+		{
+			title: "Parse a prerelease version with build metadata"
+			source: #"""
+				parse_semver!("2.0.0-rc.1+build.5")
+				"""#
+			return: {
+				major:      2
+				minor:      0
+				patch:      0
+				prerelease: "rc.1"
+				build:      "build.5"
+			}
+		},
+	]
+}
@@ -0,0 +1,61 @@
+package metadata
+
+remap: functions: parse_statsd: {
+	category: "Parse"
+	description: """
+		Parses the `value` as a [StatsD](\(urls.statsd)) line, including the [DogStatsD](\(urls.dogstatsd))
+		tags extension.
+
+		The `type` is `counter` (`c`), `gauge` (`g`), `timer` (`ms`), `histogram` (`h`),
+		`distribution` (`d`), or `set` (`s`). The value of sets is a string, and the value of the
+		other metrics is a float. The `sample_rate` defaults to `1.0`, and tags without a value are
+		`"true"`.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The line to parse."
+			required:    true
+			type: ["string"]
+		},
+	]
+	internal_failure_reasons: [
+		"`value` isn't a properly formatted StatsD line",
+		"the metric type is unknown",
+		"the sample rate isn't greater than 0 and at most 1",
+	]
+	return: types: ["object"]
+
+	examples: [
+		{
+			title: "Parse a counter"
+			source: #"""
+				parse_statsd!("page.views:1|c|@0.5|#env:prod,canary")
+				"""#
+			return: {
+				metric:      "page.views"
+				value:       1.0
+				type:        "counter"
+				sample_rate: 0.5
+				tags: {
+					env:    "prod"
+					canary: "true"
+				}
+			}
+		},
+		{
+			title: "Parse a set"
+			source: #"""
+				parse_statsd!("users.unique:alice|s")
+				"""#
+			return: {
+				metric:      "users.unique"
+				value:       "alice"
+				type:        "set"
+				sample_rate: 1.0
+				tags: {}
+			}
+		},
+	]
+}
@@ -0,0 +1,44 @@
+package metadata
+
+remap: functions: percentile: {
+	category: "Number"
+	description: """
+		Returns the value at percentile `p` of the `value` array of numbers.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The array of numbers."
+			required:    true
+			type: ["array"]
+		},
+		{
+			name:        "p"
+			description: "The percentile to return, between `0` and `100`."
+			required:    true
+			type: ["integer", "float"]
+		},
+	]
+	internal_failure_reasons: [
+		"`value` is empty",
+		"`value` contains an element that isn't a number",
+		"`p` isn't between `0` and `100`",
+	]
+	return: {
+		types: ["float"]
+		rules: [
+			"If the percentile falls between two elements, it's linearly interpolated between them.",
+		]
+	}
+
+	examples: [
+		{
+			title: "90th percentile"
+			source: #"""
+				percentile!([1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11], 90)
+				"""#
+			return: 10.0
+		},
+	]
+}
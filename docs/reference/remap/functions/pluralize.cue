@@ -0,0 +1,52 @@
+package metadata
+
+remap: functions: pluralize: {
+	category: "String"
+	description: """
+		Returns the singular `value` if `count` is `1` or `-1`, or its plural otherwise.
+
+		The plural follows the regular English rules: `es` is appended to words ending in `s`,
+		`x`, `z`, `ch` or `sh`, a `y` following a consonant is replaced by `ies`, and `s` is
+		appended to any other word. Irregular plurals can be given with `plural`.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The singular form of the word."
+			required:    true
+			type: ["string"]
+		},
+		{
+			name:        "count"
+			description: "The number of items the word refers to."
+			required:    true
+			type: ["integer", "float"]
+		},
+		{
+			name:        "plural"
+			description: "The plural form of the word, for words that don't follow the regular rules."
+			required:    false
+			type: ["string"]
+		},
+	]
+	internal_failure_reasons: []
+	return: types: ["string"]
+
+	examples: [
+		{
+			title: "Pluralize a word"
+			source: #"""
+				pluralize("city", 3)
+				"""#
+			return: "cities"
+		},
+		{
+			title: "Pluralize an irregular word"
+			source: #"""
+				pluralize("person", 2, plural: "people")
+				"""#
+			return: "people"
+		},
+	]
+}
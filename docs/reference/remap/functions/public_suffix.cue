@@ -0,0 +1,47 @@
+package metadata
+
+remap: functions: public_suffix: {
+	category: "String"
+	description: """
+		Returns the public suffix of the domain in `value`, such as `co.uk` for `www.example.co.uk`.
+
+		Suffixes are looked up in a snapshot of the [Public Suffix List](\(urls.public_suffix_list))
+		bundled with Vector. Domains whose top-level domain isn't in the list are treated as having
+		a single-label suffix.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The domain name."
+			required:    true
+			type: ["string"]
+		},
+	]
+	internal_failure_reasons: [
+		"`value` isn't a valid domain name",
+	]
+	return: {
+		types: ["string"]
+		rules: [
+			"The domain is lowercased and a trailing dot is ignored before the lookup.",
+		]
+	}
+
+	examples: [
+		{
+			title: "Multi-label suffix"
+			source: #"""
+				public_suffix!("a.b.example.co.uk")
+				"""#
+			return: "co.uk"
+		},
+		{
+			title: "Single-label suffix"
+			source: #"""
+				public_suffix!("vector.dev")
+				"""#
+			return: "dev"
+		},
+	]
+}
@@ -0,0 +1,44 @@
+package metadata
+
+remap: functions: quantile: {
+	category: "Number"
+	description: """
+		Returns the value at quantile `q` of the `value` array of numbers.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The array of numbers."
+			required:    true
+			type: ["array"]
+		},
+		{
+			name:        "q"
+			description: "The quantile to return, between `0.0` and `1.0`."
+			required:    true
+			type: ["integer", "float"]
+		},
+	]
+	internal_failure_reasons: [
+		"`value` is empty",
+		"`value` contains an element that isn't a number",
+		"`q` isn't between `0.0` and `1.0`",
+	]
+	return: {
+		types: ["float"]
+		rules: [
+			"If the quantile falls between two elements, it's linearly interpolated between them.",
+		]
+	}
+
+	examples: [
+		{
+			title: "Median"
+			source: #"""
+				quantile!([3, 1, 4, 2], 0.5)
+				"""#
+			return: 2.5
+		},
+	]
+}
@@ -0,0 +1,37 @@
+package metadata
+
+remap: functions: random_int: {
+	category:    "Random"
+	description: """
+		Generates a random integer between `min` (inclusive) and `max` (exclusive).
+		"""
+
+	arguments: [
+		{
+			name:        "min"
+			description: "The lower bound of the range, inclusive."
+			required:    true
+			type: ["integer"]
+		},
+		{
+			name:        "max"
+			description: "The upper bound of the range, exclusive."
+			required:    true
+			type: ["integer"]
+		},
+	]
+	internal_failure_reasons: [
+		"`max` isn't greater than `min`",
+	]
+	return: types: ["integer"]
+
+	examples: [
+		{
+			title: "Generate a random integer"
+			source: #"""
+				random_int!(0, 10)
+				"""#
+			return: 7
+		},
+	]
+}
@@ -0,0 +1,48 @@
+package metadata
+
+remap: functions: registrable_domain: {
+	category: "String"
+	description: """
+		Returns the registrable domain in `value`, that is its public suffix and the label preceding
+		it, such as `example.co.uk` for `www.example.co.uk`.
+
+		Suffixes are looked up in a snapshot of the [Public Suffix List](\(urls.public_suffix_list))
+		bundled with Vector.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The domain name."
+			required:    true
+			type: ["string"]
+		},
+	]
+	internal_failure_reasons: [
+		"`value` isn't a valid domain name",
+		"`value` is a public suffix, and so has no registrable domain",
+	]
+	return: {
+		types: ["string"]
+		rules: [
+			"The domain is lowercased and a trailing dot is ignored before the lookup.",
+		]
+	}
+
+	examples: [
+		{
+			title: "Multi-label suffix"
+			source: #"""
+				registrable_domain!("a.b.example.co.uk")
+				"""#
+			return: "example.co.uk"
+		},
+		{
+			title: "Single-label suffix"
+			source: #"""
+				registrable_domain!("www.vector.dev")
+				"""#
+			return: "vector.dev"
+		},
+	]
+}
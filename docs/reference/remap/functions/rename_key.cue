@@ -0,0 +1,66 @@
+package metadata
+
+remap: functions: rename_key: {
+	category: "Object"
+	description: """
+		Renames the field at the `from` path of the `value` object to the `to` path.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The object containing the field to rename."
+			required:    true
+			type: ["object"]
+		},
+		{
+			name:        "from"
+			description: "The path of the field to rename (for example `foo.bar`)."
+			required:    true
+			type: ["string"]
+		},
+		{
+			name:        "to"
+			description: "The path the field is moved to."
+			required:    true
+			type: ["string"]
+		},
+		{
+			name:        "overwrite"
+			description: "Whether an existing field at the `to` path is overwritten. If `false`, an error is returned instead."
+			required:    false
+			default:     true
+			type: ["boolean"]
+		},
+	]
+	internal_failure_reasons: [
+		"`from` or `to` isn't a valid path",
+		"a field already exists at the `to` path and `overwrite` is `false`",
+	]
+	return: {
+		types: ["object"]
+		rules: [
+			#"If no field exists at the `from` path, `value` is returned unmodified."#,
+		]
+	}
+
+	examples: [
+		{
+			title: "Rename a field"
+			source: #"""
+				rename_key!({ "msg": "hello" }, "msg", "message")
+				"""#
+			return: message: "hello"
+		},
+		{
+			title: "Rename a nested field"
+			source: #"""
+				rename_key!({ "http": { "status": 200 } }, "http.status", "status_code")
+				"""#
+			return: {
+				http: {}
+				status_code: 200
+			}
+		},
+	]
+}
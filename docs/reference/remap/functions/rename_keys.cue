@@ -0,0 +1,54 @@
+package metadata
+
+remap: functions: rename_keys: {
+	category: "Object"
+	description: """
+		Renames multiple fields of the `value` object at once, using the `mapping` object of old to new paths.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The object containing the fields to rename."
+			required:    true
+			type: ["object"]
+		},
+		{
+			name:        "mapping"
+			description: "An object mapping the path of each field to rename to its new path."
+			required:    true
+			type: ["object"]
+		},
+		{
+			name:        "overwrite"
+			description: "Whether existing fields at the new paths are overwritten. If `false`, an error is returned instead."
+			required:    false
+			default:     true
+			type: ["boolean"]
+		},
+	]
+	internal_failure_reasons: [
+		"a path in `mapping` isn't a valid path",
+		"a value in `mapping` isn't a string",
+		"a field already exists at one of the new paths and `overwrite` is `false`",
+	]
+	return: {
+		types: ["object"]
+		rules: [
+			#"All fields are moved at once, so fields can be swapped."#,
+		]
+	}
+
+	examples: [
+		{
+			title: "Rename fields"
+			source: #"""
+				rename_keys!({ "msg": "hello", "lvl": "info" }, { "msg": "message", "lvl": "level" })
+				"""#
+			return: {
+				level:   "info"
+				message: "hello"
+			}
+		},
+	]
+}
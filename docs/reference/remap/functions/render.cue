@@ -0,0 +1,53 @@
+package metadata
+
+remap: functions: render: {
+	category: "String"
+	description: """
+		Renders the `template`, replacing its placeholders by the values of the `variables`. The template
+		is either compiled by [`compile_template`](#compile_template), to be rendered repeatedly without
+		being parsed again, or a string, compiled on every call.
+		"""
+
+	arguments: [
+		{
+			name:        "template"
+			description: "The compiled template, or the template string."
+			required:    true
+			type: ["object", "string"]
+		},
+		{
+			name:        "variables"
+			description: "The variables of the placeholders."
+			required:    true
+			type: ["object"]
+		},
+	]
+	internal_failure_reasons: [
+		"`template` is a string that isn't a valid template",
+		"`template` is an object that isn't a compiled template",
+	]
+	return: {
+		types: ["string"]
+		rules: [
+			"Missing variables and `null` render as empty strings.",
+			"Timestamps render in RFC 3339 format, and arrays and objects as JSON.",
+		]
+	}
+
+	examples: [
+		{
+			title: "Render a compiled template"
+			source: #"""
+				render!(compile_template!("{{ method }} {{ path }}"), { "method": "GET", "path": "/" })
+				"""#
+			return: "GET /"
+		},
+		{
+			title: "Render a template string"
+			source: #"""
+				render!("Hello, {{ user.name }}!", { "user": { "name": "foo" } })
+				"""#
+			return: "Hello, foo!"
+		},
+	]
+}
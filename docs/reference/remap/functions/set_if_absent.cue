@@ -0,0 +1,59 @@
+package metadata
+
+remap: functions: set_if_absent: {
+	category: "Object"
+	description: """
+		Sets the `new` value at the given `path` of the `value` object, but only if nothing exists at that path yet.
+		Any intermediate objects required by the path are created.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The object to update."
+			required:    true
+			type: ["object"]
+		},
+		{
+			name:        "path"
+			description: "The path to set, in the same format as a VRL path (for example `foo.bar[0]`)."
+			required:    true
+			type: ["string"]
+		},
+		{
+			name:        "new"
+			description: "The value to set at `path` if it's absent."
+			required:    true
+			type: ["any"]
+		},
+	]
+	internal_failure_reasons: [
+		"`path` isn't a valid path",
+	]
+	return: {
+		types: ["object"]
+		rules: [
+			#"If a value already exists at `path`, `value` is returned unmodified, even if the existing value is `null`."#,
+		]
+	}
+
+	examples: [
+		{
+			title: "Set an absent field"
+			source: #"""
+				set_if_absent!({ "message": "hello" }, "level", "info")
+				"""#
+			return: {
+				level:   "info"
+				message: "hello"
+			}
+		},
+		{
+			title: "Leave a present field untouched"
+			source: #"""
+				set_if_absent!({ "level": "error" }, "level", "info")
+				"""#
+			return: level: "error"
+		},
+	]
+}
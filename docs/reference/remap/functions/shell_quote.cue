@@ -0,0 +1,43 @@
+package metadata
+
+remap: functions: shell_quote: {
+	category:    "String"
+	description: """
+		Quotes the `value` so that a POSIX shell interprets it as a single word, making it safe to
+		embed in a command line.
+		"""
+	notices: [
+		"""
+			Values that only contain letters, digits, and the `_-+=%@:,./` characters are returned
+			as-is, all other values are wrapped in single quotes.
+			""",
+	]
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The string to quote."
+			required:    true
+			type: ["string"]
+		},
+	]
+	internal_failure_reasons: []
+	return: types: ["string"]
+
+	examples: [
+		{
+			title: "Quote a safe value"
+			source: #"""
+				shell_quote("/var/log/app.log")
+				"""#
+			return: "/var/log/app.log"
+		},
+		{
+			title: "Quote a value with spaces and quotes"
+			source: #"""
+				shell_quote("it's here")
+				"""#
+			return: #"'it'\''s here'"#
+		},
+	]
+}
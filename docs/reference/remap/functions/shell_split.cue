@@ -0,0 +1,32 @@
+package metadata
+
+remap: functions: shell_split: {
+	category: "String"
+	description: """
+		Splits the `value` into words the way a POSIX shell would, respecting single quotes, double
+		quotes, and backslash escapes.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The command line to split."
+			required:    true
+			type: ["string"]
+		},
+	]
+	internal_failure_reasons: [
+		"`value` contains an unterminated quote or ends with a backslash",
+	]
+	return: types: ["array"]
+
+	examples: [
+		{
+			title: "Split a command line"
+			source: #"""
+				shell_split!(s'grep -i "connection refused" /var/log/app\ 1.log')
+				"""#
+			return: ["grep", "-i", "connection refused", "/var/log/app 1.log"]
+		},
+	]
+}
@@ -0,0 +1,46 @@
+package metadata
+
+remap: functions: sizeof: {
+	category: "Debug"
+	description: """
+		Returns an estimate of the number of bytes the provided `value` occupies in memory.
+
+		The estimate accounts for the length of strings, the elements of arrays,
+		and the keys and values of objects, making it useful for comparing the
+		relative size of events rather than for exact accounting.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The value to measure."
+			required:    true
+			type: ["any"]
+		},
+	]
+	internal_failure_reasons: []
+	return: {
+		types: ["integer"]
+		rules: [
+			"Empty values, and scalars such as `null`, integers, and booleans, return the same small constant.",
+			"Larger strings and containers return proportionally larger estimates.",
+		]
+	}
+
+	examples: [
+		{
+			title: "Compare string sizes"
+			source: #"""
+				sizeof("foobar") > sizeof("foo")
+				"""#
+			return: true
+		},
+		{
+			title: "Compare object sizes"
+			source: #"""
+				sizeof({ "foo": [1, 2, 3] }) > sizeof({})
+				"""#
+			return: true
+		},
+	]
+}
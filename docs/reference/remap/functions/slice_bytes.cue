@@ -0,0 +1,51 @@
+package metadata
+
+remap: functions: slice_bytes: {
+	category: "String"
+	description: """
+		Returns the bytes of `value` from the `start` index up to, but not including, the `end` index.
+
+		Negative indices count from the end of `value`. Unlike `slice`, indices out of range are clamped to the
+		bounds of `value`, and an `end` before `start` returns an empty string, rather than failing.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The bytes to slice."
+			required:    true
+			type: ["string"]
+		},
+		{
+			name:        "start"
+			description: "The index of the first byte of the slice."
+			required:    true
+			type: ["integer"]
+		},
+		{
+			name:        "end"
+			description: "The index after the last byte of the slice. Defaults to the length of `value`."
+			required:    false
+			type: ["integer"]
+		},
+	]
+	internal_failure_reasons: []
+	return: types: ["string"]
+
+	examples: [
+		{
+			title: "Slice bytes"
+			source: #"""
+				slice_bytes("foobar", 2, 4)
+				"""#
+			return: "ob"
+		},
+		{
+			title: "Slice bytes from the end"
+			source: #"""
+				slice_bytes("foobar", -3)
+				"""#
+			return: "bar"
+		},
+	]
+}
@@ -0,0 +1,45 @@
+package metadata
+
+remap: functions: soundex: {
+	category: "String"
+	description: """
+		Returns the Soundex code of the `value` word, so that words that sound alike, such as names
+		with different spellings, can be matched.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The word to encode."
+			required:    true
+			type: ["string"]
+		},
+		{
+			name:        "ignore_invalid"
+			description: "Whether to leave out characters that aren't ASCII letters, instead of failing."
+			required:    false
+			default:     false
+			type: ["boolean"]
+		},
+	]
+	internal_failure_reasons: [
+		"`value` contains a character that isn't an ASCII letter, and `ignore_invalid` isn't set",
+	]
+	return: {
+		types: ["string"]
+		rules: [
+			"The code is the uppercased first letter followed by three digits, padded with zeroes.",
+			"Returns an empty string if `value` has no letters.",
+		]
+	}
+
+	examples: [
+		{
+			title: "Encode a name"
+			source: #"""
+				soundex!("Robert") == soundex!("Rupert")
+				"""#
+			return: true
+		},
+	]
+}
@@ -0,0 +1,65 @@
+package metadata
+
+remap: functions: sprintf: {
+	category: "String"
+	description: #"""
+		Formats the `args` according to the `format`, as C's `printf` does.
+
+		Each conversion of the `format` is replaced by the next argument, and `%%` is a literal `%`. A conversion
+		is written `%[flags][width][.precision]conversion`, where the conversion is one of:
+
+		* `d`: an integer, in decimal
+		* `x`: an integer, in lowercase hexadecimal
+		* `f`: a float or an integer, with `precision` decimal places, `6` by default
+		* `s`: a string, or a value coerced into a string as by `to_string`, truncated to `precision` characters
+
+		The result of the conversion is padded with spaces on the left to be at least `width` characters long. The
+		`-` flag pads it on the right instead, and the `0` flag pads numbers with zeros after their sign. Neither
+		`width` nor `precision` can be larger than `1024`.
+		"""#
+
+	arguments: [
+		{
+			name:        "format"
+			description: "The format string."
+			required:    true
+			type: ["string"]
+		},
+		{
+			name:        "args"
+			description: "The arguments of the conversions of the `format`, in order."
+			required:    true
+			type: ["array"]
+		},
+	]
+	internal_failure_reasons: [
+		"`format` contains an unknown or incomplete conversion, a precision for `%d` or `%x`, or a width or precision larger than `1024`",
+		"The number of `args` isn't the number of conversions of the `format`",
+		"An argument isn't of the type of its conversion",
+	]
+	return: types: ["string"]
+
+	examples: [
+		{
+			title: "Format a float"
+			source: #"""
+				sprintf!("took %.2fms", [12.3456])
+				"""#
+			return: "took 12.35ms"
+		},
+		{
+			title: "Pad values"
+			source: #"""
+				sprintf!("[%-5s|%5d|%05d]", ["ab", 42, -42])
+				"""#
+			return: "[ab   |   42|-0042]"
+		},
+		{
+			title: "Format an integer in hexadecimal"
+			source: #"""
+				sprintf!("0x%08x", [48879])
+				"""#
+			return: "0x0000beef"
+		},
+	]
+}
@@ -0,0 +1,64 @@
+package metadata
+
+remap: functions: stats: {
+	category: "Number"
+	description: """
+		Returns the `count`, `sum`, `min`, `max` and `mean` of the `value` array of numbers, computed in a single
+		pass over the array.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The array of numbers."
+			required:    true
+			type: ["array"]
+		},
+		{
+			name:        "skip_non_numeric"
+			description: "Whether to leave out the elements that aren't numbers, instead of failing."
+			required:    false
+			default:     false
+			type: ["boolean"]
+		},
+	]
+	internal_failure_reasons: [
+		"`value` contains an element that isn't a number, unless `skip_non_numeric` is set",
+	]
+	return: {
+		types: ["object"]
+		rules: [
+			"The `sum` is an integer if all of the numbers are integers, and a float otherwise.",
+			"The `min` and `max` are the elements themselves, and are `null`, as is the `mean`, if there are no numbers.",
+		]
+	}
+
+	examples: [
+		{
+			title: "Summarize an array"
+			source: #"""
+				stats!([3, 1, 4, 1, 5])
+				"""#
+			return: {
+				count: 5
+				sum:   14
+				min:   1
+				max:   5
+				mean:  2.8
+			}
+		},
+		{
+			title: "Skip the elements that aren't numbers"
+			source: #"""
+				stats!([3, "n/a", 1.5], skip_non_numeric: true)
+				"""#
+			return: {
+				count: 2
+				sum:   4.5
+				min:   1.5
+				max:   3
+				mean:  2.25
+			}
+		},
+	]
+}
@@ -0,0 +1,36 @@
+package metadata
+
+remap: functions: strip_html: {
+	category:    "String"
+	description: """
+		Strips the HTML tags from the `value`, and decodes its character references (such as `&amp;`)
+		to produce plain text.
+		"""
+	notices: [
+		"""
+			Comments, doctypes, and the contents of `<script>` and `<style>` elements are removed
+			entirely.
+			""",
+	]
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The HTML to strip."
+			required:    true
+			type: ["string"]
+		},
+	]
+	internal_failure_reasons: []
+	return: types: ["string"]
+
+	examples: [
+		{
+			title: "Strip HTML"
+			source: #"""
+				strip_html("<p>Fish &amp; <b>chips</b></p>")
+				"""#
+			return: "Fish & chips"
+		},
+	]
+}
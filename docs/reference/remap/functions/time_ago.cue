@@ -0,0 +1,46 @@
+package metadata
+
+remap: functions: time_ago: {
+	category: "Timestamp"
+	description: """
+		Describes the `value` relative to the current time, in the largest of the minute, hour, day,
+		month (30 days) and year (365 days) units it's at least one of, rounded down, such as
+		`3 minutes ago`, or `in 2 days` for a future `value`. Differences of less than a minute are
+		described as `just now`.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The timestamp to describe."
+			required:    true
+			type: ["timestamp"]
+		},
+		{
+			name:        "now"
+			description: "The timestamp to describe the `value` relative to."
+			required:    false
+			default:     "The current time."
+			type: ["timestamp"]
+		},
+	]
+	internal_failure_reasons: []
+	return: types: ["string"]
+
+	examples: [
+		{
+			title: "Describe a past timestamp"
+			source: #"""
+				time_ago(t'2021-02-03T04:02:00Z', now: t'2021-02-03T04:05:06Z')
+				"""#
+			return: "3 minutes ago"
+		},
+		{
+			title: "Describe a future timestamp"
+			source: #"""
+				time_ago(t'2021-02-05T04:05:06Z', now: t'2021-02-03T04:05:06Z')
+				"""#
+			return: "in 2 days"
+		},
+	]
+}
@@ -0,0 +1,76 @@
+package metadata
+
+remap: functions: token_bucket: {
+	category: "Object"
+	description: """
+		Decides whether an event is allowed by a token bucket rate limiter, without keeping any state
+		across events: the previous `state` of the bucket is given, such as a field carried on the
+		event, and the new one is returned alongside the decision.
+
+		The bucket holds up to `capacity` tokens, and is refilled by `refill_rate` tokens per second
+		since the `last_refill` of the `state`. An event is allowed if there's at least one token in
+		the bucket, which it takes. A `null` or empty `state` is a new bucket, which starts full.
+		"""
+
+	arguments: [
+		{
+			name:        "state"
+			description: "The previous state of the bucket, an object with the `tokens` and `last_refill` of the bucket."
+			required:    true
+			type: ["object", "null"]
+		},
+		{
+			name:        "capacity"
+			description: "The maximum number of tokens in the bucket."
+			required:    true
+			type: ["integer", "float"]
+		},
+		{
+			name:        "refill_rate"
+			description: "The number of tokens added to the bucket per second."
+			required:    true
+			type: ["integer", "float"]
+		},
+		{
+			name:        "now"
+			description: "The current time."
+			required:    true
+			type: ["timestamp"]
+		},
+	]
+	internal_failure_reasons: [
+		"`capacity` isn't greater than 0",
+		"`refill_rate` is negative",
+		"`state` doesn't have a numeric `tokens` field and a timestamp `last_refill` field",
+	]
+	return: types: ["object"]
+
+	examples: [
+		{
+			title: "New bucket"
+			source: #"""
+				token_bucket!(null, 10, 1, t'2021-03-05T12:00:00Z')
+				"""#
+			return: {
+				allowed: true
+				state: {
+					last_refill: "2021-03-05T12:00:00Z"
+					tokens:      9.0
+				}
+			}
+		},
+		{
+			title: "Refilled bucket"
+			source: #"""
+				token_bucket!({ "tokens": 0.0, "last_refill": t'2021-03-05T12:00:00Z' }, 10, 2, t'2021-03-05T12:00:02Z')
+				"""#
+			return: {
+				allowed: true
+				state: {
+					last_refill: "2021-03-05T12:00:02Z"
+					tokens:      3.0
+				}
+			}
+		},
+	]
+}
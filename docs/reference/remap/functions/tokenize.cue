@@ -0,0 +1,51 @@
+package metadata
+
+remap: functions: tokenize: {
+	category: "String"
+	description: """
+		Splits the `value` string into an array of words, on whitespace and punctuation.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The string to tokenize."
+			required:    true
+			type: ["string"]
+		},
+		{
+			name:        "lowercase"
+			description: "Whether to lowercase the words."
+			required:    false
+			default:     false
+			type: ["boolean"]
+		},
+		{
+			name:        "stopwords"
+			description: "The words to leave out, regardless of case."
+			required:    false
+			default:     []
+			type: ["array"]
+		},
+	]
+	internal_failure_reasons: [
+		"`stopwords` contains an element that isn't a string",
+	]
+	return: {
+		types: ["array"]
+		rules: [
+			"A word is a sequence of letters and digits, so `it's` is split into `it` and `s`.",
+			"Returns an empty array if `value` contains no words.",
+		]
+	}
+
+	examples: [
+		{
+			title: "Tokenize a sentence"
+			source: #"""
+				tokenize!("The quick, brown fox!", lowercase: true, stopwords: ["the"])
+				"""#
+			return: ["quick", "brown", "fox"]
+		},
+	]
+}
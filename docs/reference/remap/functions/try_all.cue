@@ -0,0 +1,39 @@
+package metadata
+
+remap: functions: try_all: {
+	category: "Debug"
+	description: """
+		Resolves the `expressions` one by one, in order, returning the value of the first one that succeeds. The
+		expressions after it aren't resolved.
+
+		If all of them fail, the error lists the error of each expression, and its payload holds them as the
+		`attempts` array, each with the `error` message and `code` of the error.
+
+		Unlike the arguments of other functions, the `expressions` may be fallible. The call can only fail if all of
+		them can.
+		"""
+
+	arguments: [
+		{
+			name:        "expressions"
+			description: "The array of expressions to try."
+			required:    true
+			type: ["array"]
+		},
+	]
+	internal_failure_reasons: [
+		"all of the `expressions` fail",
+		"`expressions` is empty",
+	]
+	return: types: ["any"]
+
+	examples: [
+		{
+			title: "Parse a value in one of several formats"
+			source: #"""
+				try_all!([parse_json("{"), to_int("12")])
+				"""#
+			return: 12
+		},
+	]
+}
@@ -0,0 +1,57 @@
+package metadata
+
+remap: functions: unique_bloom: {
+	category: "Array"
+	description: """
+		Removes the probable duplicates from the `value` array, using a bloom filter.
+
+		This uses a fixed amount of memory, regardless of the size of the elements, at the cost of
+		exactness: an element may be removed because it looks like a duplicate, even though it isn't
+		one. The probability of this happening grows with the number of distinct elements, and
+		shrinks as `bits` grows. Actual duplicates are always removed.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The array to remove the duplicates from."
+			required:    true
+			type: ["array"]
+		},
+		{
+			name:        "bits"
+			description: "The size of the bloom filter, in bits, up to `16777216`."
+			required:    false
+			default:     8192
+			type: ["integer"]
+		},
+		{
+			name:        "hashes"
+			description: "The number of bits set for each element, up to `64`."
+			required:    false
+			default:     4
+			type: ["integer"]
+		},
+	]
+	internal_failure_reasons: [
+		"`bits` isn't between `1` and `16777216`",
+		"`hashes` isn't between `1` and `64`",
+	]
+	return: {
+		types: ["array"]
+		rules: [
+			"The first occurrence of each element is kept, in order.",
+			"Elements are only duplicates if they're of the same type, so `1` and `1.0` are distinct.",
+		]
+	}
+
+	examples: [
+		{
+			title: "Remove duplicates"
+			source: #"""
+				unique_bloom!(["foo", "bar", "foo", 1, 1])
+				"""#
+			return: ["foo", "bar", 1]
+		},
+	]
+}
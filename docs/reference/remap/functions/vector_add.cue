@@ -0,0 +1,43 @@
+package metadata
+
+remap: functions: vector_add: {
+	category: "Number"
+	description: """
+		Adds the `a` and `b` arrays of numbers element by element.
+		"""
+
+	arguments: [
+		{
+			name:        "a"
+			description: "The first array of numbers."
+			required:    true
+			type: ["array"]
+		},
+		{
+			name:        "b"
+			description: "The second array of numbers."
+			required:    true
+			type: ["array"]
+		},
+	]
+	internal_failure_reasons: [
+		"`a` or `b` contains an element that isn't a number",
+		"`a` and `b` aren't of the same length",
+	]
+	return: {
+		types: ["array"]
+		rules: [
+			"Each element is an integer if both added elements are integers, and a float otherwise.",
+		]
+	}
+
+	examples: [
+		{
+			title: "Add two arrays"
+			source: #"""
+				vector_add!([1, 2.5], [3, 4])
+				"""#
+			return: [4, 6.5]
+		},
+	]
+}
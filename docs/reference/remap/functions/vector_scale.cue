@@ -0,0 +1,42 @@
+package metadata
+
+remap: functions: vector_scale: {
+	category: "Number"
+	description: """
+		Multiplies each element of the `value` array of numbers by `factor`.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The array of numbers to scale."
+			required:    true
+			type: ["array"]
+		},
+		{
+			name:        "factor"
+			description: "The number to multiply each element by."
+			required:    true
+			type: ["integer", "float"]
+		},
+	]
+	internal_failure_reasons: [
+		"`value` contains an element that isn't a number",
+	]
+	return: {
+		types: ["array"]
+		rules: [
+			"Each element is an integer if both it and `factor` are integers, and a float otherwise.",
+		]
+	}
+
+	examples: [
+		{
+			title: "Scale an array"
+			source: #"""
+				vector_scale!([1, 2.5], -2)
+				"""#
+			return: [-2, -5.0]
+		},
+	]
+}
@@ -0,0 +1,72 @@
+package metadata
+
+remap: functions: windows: {
+	category: "Array"
+	description: """
+		Returns the windows of `size` consecutive elements of the `value` array, starting every `step` elements.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The array."
+			required:    true
+			type: ["array"]
+		},
+		{
+			name:        "size"
+			description: "The number of elements in each window."
+			required:    true
+			type: ["integer"]
+		},
+		{
+			name:        "step"
+			description: "The number of elements between the starts of two windows."
+			required:    false
+			default:     1
+			type: ["integer"]
+		},
+		{
+			name:        "partial"
+			description: "Whether to keep the windows at the end of the array that are shorter than `size`."
+			required:    false
+			default:     false
+			type: ["boolean"]
+		},
+	]
+	internal_failure_reasons: [
+		"`size` is less than `1`",
+		"`step` is less than `1`",
+	]
+	return: {
+		types: ["array"]
+		rules: [
+			"Each window is an array of the elements of `value`, in order.",
+			"If `value` has fewer than `size` elements and `partial` isn't set, no windows are returned.",
+		]
+	}
+
+	examples: [
+		{
+			title: "Sliding windows"
+			source: #"""
+				windows!([1, 2, 3, 4], 2)
+				"""#
+			return: [[1, 2], [2, 3], [3, 4]]
+		},
+		{
+			title: "Windows with a step"
+			source: #"""
+				windows!([1, 2, 3, 4, 5], 2, step: 2)
+				"""#
+			return: [[1, 2], [3, 4]]
+		},
+		{
+			title: "Keep the partial windows"
+			source: #"""
+				windows!([1, 2, 3, 4, 5], 2, step: 2, partial: true)
+				"""#
+			return: [[1, 2], [3, 4], [5]]
+		},
+	]
+}
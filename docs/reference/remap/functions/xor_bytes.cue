@@ -0,0 +1,39 @@
+package metadata
+
+remap: functions: xor_bytes: {
+	category: "String"
+	description: """
+		XORs the bytes of `value` with the bytes of `key`. The shorter of the two is repeated over the longer one,
+		so that a short key can be applied to any value, and applying the same key again returns the original
+		value.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The bytes to XOR."
+			required:    true
+			type: ["string"]
+		},
+		{
+			name:        "key"
+			description: "The bytes to XOR them with."
+			required:    true
+			type: ["string"]
+		},
+	]
+	internal_failure_reasons: [
+		"`value` or `key` is empty",
+	]
+	return: types: ["string"]
+
+	examples: [
+		{
+			title: "XOR with a repeating key"
+			source: #"""
+				encode_base64(xor_bytes!("secret", "key"))
+				"""#
+			return: "GAAaGQAN"
+		},
+	]
+}
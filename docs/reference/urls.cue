@@ -88,6 +88,7 @@ urls: {
 	big_query_streaming:                                      "https://cloud.google.com/bigquery/streaming-data-into-bigquery"
 	b_tree_map:                                               "https://doc.rust-lang.org/std/collections/struct.BTreeMap.html"
 	cargo_audit:                                              "\(github)/RustSec/cargo-audit"
+	cbor:                                                     "https://cbor.io/"
 	centos:                                                   "https://www.centos.org/"
 	chrono_time_formats:                                      "https://docs.rs/chrono/latest/chrono/format/strftime/index.html#specifiers"
 	cgroups_limit_resources:                                  "https://the.binbashtheory.com/control-resources-cgroups/"
@@ -96,10 +97,12 @@ urls: {
 	cloudsmith:                                               "https://cloudsmith.io/~timber/repos/vector/packages/"
 	cloudsmith_apt:                                           "https://cloudsmith.io/~timber/repos/vector/setup/#formats-deb"
 	cloudsmith_yum:                                           "https://cloudsmith.io/~timber/repos/vector/setup/#formats-rpm"
+	commonmark:                                               "https://commonmark.org"
 	console:                                                  "\(wikipedia)/wiki/System_console"
 	conventional_commits:                                     "https://www.conventionalcommits.org"
 	contributing:                                             "\(vector_repo)/blob/master/CONTRIBUTING.md#setup"
 	crc:                                                      "\(wikipedia)/wiki/Cyclic_redundancy_check"
+	css_named_colors:                                         "https://www.w3.org/TR/css-color-4/#named-colors"
 	ctime:                                                    "https://www.cplusplus.com/reference/ctime"
 	cue:                                                      "https://cuelang.org/"
 	dag:                                                      "\(wikipedia)/wiki/Directed_acyclic_graph"
@@ -113,6 +116,7 @@ urls: {
 	date:                                                     "https://man7.org/linux/man-pages/man1/date.1.html"
 	debian:                                                   "https://www.debian.org/"
 	default_configuration:                                    "\(vector_repo)/blob/master/config/vector.toml"
+	deflate:                                                  "https://datatracker.ietf.org/doc/html/rfc1951"
 	docker:                                                   "https://www.docker.com/"
 	docker_alpine:                                            "\(docker_hub)/_/alpine"
 	docker_cli:                                               "\(docker_docs)/engine/reference/commandline/cli/"
@@ -220,9 +224,12 @@ urls: {
 	iso_8601:                                                 "\(wikipedia)/wiki/ISO_8601"
 	iso3166_2:                                                "\(wikipedia)/wiki/ISO_3166-2"
 	issue_1694:                                               "\(vector_repo)/issues/1694"
+	ja3:                                                      "\(github)/salesforce/ja3"
 	jemalloc:                                                 "\(github)/jemalloc/jemalloc"
+	jmespath:                                                 "https://jmespath.org/specification.html"
 	journald:                                                 "https://www.freedesktop.org/software/systemd/man/systemd-journald.service.html"
 	json:                                                     "\(wikipedia)/wiki/JSON"
+	json_schema:                                              "https://json-schema.org/"
 	json_types:                                               "\(wikipedia)/wiki/JSON#Data_types_and_syntax"
 	jsonnet:                                                  "https://jsonnet.org/"
 	kafka:                                                    "https://kafka.apache.org/"
@@ -273,7 +280,9 @@ urls: {
 	lua_sequence:                                             "\(lua)/pil/11.1.html"
 	lua_string:                                               "\(lua)/pil/2.4.html"
 	lua_tonumber:                                             "\(lua)/manual/5.3/manual.html#pdf-tonumber"
+	luhn:                                                     "\(wikipedia)/wiki/Luhn_algorithm"
 	lz4:                                                      "https://lz4.github.io/lz4/"
+	lz4_frame_format:                                         "https://github.com/lz4/lz4/blob/dev/doc/lz4_Frame_format.md"
 	macos:                                                    "\(wikipedia)/wiki/MacOS"
 	mailing_list:                                             "\(vector_website)/community/"
 	maxmind:                                                  "https://www.maxmind.com/en/home"
@@ -309,6 +318,8 @@ urls: {
 	order_of_ops:                                             "\(wikipedia)/wiki/Order_of_operations"
 	papertrail:                                               "https://www.papertrail.com/"
 	papertrail_syslog:                                        "https://help.papertrailapp.com/kb/how-it-works/http-api/#submitting-log-messages"
+	parquet_metadata:                                         "https://parquet.apache.org/docs/file-format/metadata/"
+	percent_encoding:                                         "\(wikipedia)/wiki/Percent-encoding"
 	perl_windows:                                             "https://www.perl.org/get.html#win32"
 	postgresql:                                               "https://www.postgresql.org/"
 	postgresql_csvlog:                                        "https://www.postgresql.org/docs/current/runtime-config-logging.html#RUNTIME-CONFIG-LOGGING-CSVLOG"
@@ -326,6 +337,7 @@ urls: {
 	prometheus_remote_integrations:                           "https://prometheus.io/docs/operating/integrations/#remote-endpoints-and-storage"
 	prometheus_remote_write:                                  "https://prometheus.io/docs/prometheus/latest/configuration/configuration/#remote_write"
 	protobuf:                                                 "https://developers.google.com/protocol-buffers"
+	public_suffix_list:                                       "https://publicsuffix.org/"
 	pulsar:                                                   "https://pulsar.apache.org/"
 	pulsar_protocol:                                          "https://pulsar.apache.org/docs/en/develop-binary-protocol/"
 	raspbian:                                                 "https://www.raspbian.org/"
@@ -505,6 +517,8 @@ urls: {
 	vote_feature:                                             "\(vector_repo)/issues?q=is%3Aissue+is%3Aopen+sort%3Areactions-%2B1-desc+label%3A%22Type%3A+New+Feature%22"
 	wasm:                                                     "https://webassembly.org/"
 	wasm_languages:                                           "\(github)/appcypher/awesome-wasm-langs"
+	wcag_contrast_ratio:                                      "https://www.w3.org/TR/WCAG21/#dfn-contrast-ratio"
+	wcag_relative_luminance:                                  "https://www.w3.org/TR/WCAG21/#dfn-relative-luminance"
 	wikipedia:                                                "https://en.wikipedia.org"
 	windows:                                                  "https://www.microsoft.com/en-us/windows"
 	windows_installer:                                        "\(wikipedia)/wiki/Windows_Installer"
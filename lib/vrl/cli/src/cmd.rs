@@ -1,11 +1,19 @@
-use super::{repl, Error};
-use std::collections::BTreeMap;
+use super::{complete, explain, hover, repl, Error};
+use std::collections::{BTreeMap, BTreeSet};
+use std::convert::TryFrom;
 use std::fs::File;
 use std::io::{self, Read};
 use std::iter::IntoIterator;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::thread;
 use structopt::StructOpt;
-use vrl::{diagnostic::Formatter, state, Runtime, Target, Value};
+use vrl::{
+    diagnostic::{Diagnostic, Formatter, Severity},
+    enrichment::EnrichmentCache,
+    hook::LogHook,
+    state, Abort, Cost, Optimization, Program, Runtime, RuntimeResult, Schema, Value,
+};
 
 #[derive(Debug, StructOpt)]
 #[structopt(name = "VRL", about = "Vector Remap Language CLI")]
@@ -27,6 +35,137 @@ pub struct Opts {
     /// this flag is equivalent to using `.` as the final expression.
     #[structopt(short = "o", long)]
     print_object: bool,
+
+    /// Print a JSON envelope for each event object instead of only the output: the output as
+    /// `output`, the values logged with `log` as `logs`, and whether the event was dropped because
+    /// the program aborted as `dropped`, along with the `error` of dropped events.
+    #[structopt(long)]
+    wrap_result: bool,
+
+    /// The number of threads used to process the event objects. Results are always printed in the
+    /// same order as the event objects they belong to.
+    #[structopt(long, default_value = "1")]
+    parallelism: usize,
+
+    /// The number of results of enrichment lookups to cache, such as looking up an IP address,
+    /// which are then shared by the event objects, and evicted least recently used first. Nothing
+    /// is cached by default.
+    #[structopt(long)]
+    enrichment_cache_size: Option<usize>,
+
+    /// The file containing the JSON schema each event object has to match before the program
+    /// runs. Event objects that don't match it are rejected with an error.
+    #[structopt(long, parse(from_os_str))]
+    input_schema: Option<PathBuf>,
+
+    /// The file containing the JSON schema each event object has to match after the program ran.
+    #[structopt(long, parse(from_os_str))]
+    output_schema: Option<PathBuf>,
+
+    /// The least severe diagnostics to print when compiling the program, one of `error`,
+    /// `warning` or `note`. Only errors stop the program from running.
+    #[structopt(long, default_value = "note", possible_values = &["error", "warning", "note"])]
+    min_severity: Severity,
+
+    /// Print the diagnostics of the program as JSON, one object per line, with the stable code,
+    /// severity, message, labels and notes of each of them, instead of rendering them.
+    #[structopt(long)]
+    json_diagnostics: bool,
+
+    /// The level of optimization of the program, from `0` to `2`. Level 1 resolves the operations
+    /// and pure function calls over only literals when compiling, and level 2 also shares the value
+    /// of identical pure function calls, as long as their arguments don't change. Optimizations
+    /// don't change the results, but are off by default for the diagnostics to match the program.
+    #[structopt(short = "O", long, default_value = "0", possible_values = &["0", "1", "2"])]
+    optimize: Optimization,
+
+    /// The only functions the program may call, separated by commas. The program isn't run if it
+    /// calls any other function.
+    #[structopt(long, use_delimiter = true, number_of_values = 1)]
+    allow_functions: Option<Vec<String>>,
+
+    /// The functions the program may not call, separated by commas. The program isn't run if it
+    /// calls any of them.
+    #[structopt(long, use_delimiter = true, number_of_values = 1)]
+    deny_functions: Vec<String>,
+
+    #[structopt(subcommand)]
+    command: Option<Command>,
+}
+
+#[derive(Debug, StructOpt)]
+enum Command {
+    /// Print the type of the expression at the given byte offset of the program as JSON, along
+    /// with the signature and documentation of the function, for the name of a function call.
+    Hover {
+        /// The file containing the VRL program.
+        #[structopt(short, long = "program", parse(from_os_str))]
+        program_file: PathBuf,
+
+        /// The byte offset in the program.
+        #[structopt(long)]
+        offset: usize,
+    },
+
+    /// Print the program, with the inferred type of each top-level expression, and whether it can
+    /// fail, as a trailing comment.
+    Explain {
+        /// The file containing the VRL program.
+        #[structopt(short, long = "program", parse(from_os_str))]
+        program_file: PathBuf,
+    },
+
+    /// Print the completion candidates at the given byte offset of the program as JSON: the
+    /// fields of the object before a `.`, or the variables and functions anywhere else.
+    Complete {
+        /// The file containing the VRL program.
+        #[structopt(short, long = "program", parse(from_os_str))]
+        program_file: PathBuf,
+
+        /// The byte offset in the program.
+        #[structopt(long)]
+        offset: usize,
+    },
+
+    /// Print a static estimate of the cost of running the program as JSON, to compare programs
+    /// before deploying them. The score is relative: only compare it with the score of other
+    /// programs.
+    Cost {
+        /// The file containing the VRL program.
+        #[structopt(short, long = "program", parse(from_os_str))]
+        program_file: PathBuf,
+    },
+
+    /// Print the names of the functions the program calls, one per line, for example to check
+    /// them against an allowlist.
+    Deps {
+        /// The file containing the VRL program.
+        #[structopt(short, long = "program", parse(from_os_str))]
+        program_file: PathBuf,
+    },
+
+    /// Print the paths of the event the program reads and writes as JSON. The paths aren't
+    /// `complete` if the program calls functions accessing the event directly, such as `del`.
+    Paths {
+        /// The file containing the VRL program.
+        #[structopt(short, long = "program", parse(from_os_str))]
+        program_file: PathBuf,
+    },
+}
+
+impl Opts {
+    /// The compiler state, configured by the options.
+    fn compiler_state(&self) -> state::Compiler {
+        let mut state = state::Compiler::default()
+            .with_optimization(self.optimize)
+            .with_denied_functions(self.deny_functions.iter().cloned());
+
+        if let Some(functions) = &self.allow_functions {
+            state = state.with_allowed_functions(functions.iter().cloned());
+        }
+
+        state
+    }
 }
 
 pub fn cmd(opts: &Opts) -> exitcode::ExitCode {
@@ -40,6 +179,10 @@ pub fn cmd(opts: &Opts) -> exitcode::ExitCode {
 }
 
 fn run(opts: &Opts) -> Result<(), Error> {
+    if let Some(command) = &opts.command {
+        return run_command(command);
+    }
+
     // Run the REPL if no program or program file is specified
     if should_open_repl(opts) {
         // If an input file is provided, use that for the REPL objects, otherwise provide a
@@ -52,17 +195,36 @@ fn run(opts: &Opts) -> Result<(), Error> {
         repl(repl_objects)
     } else {
         let objects = read_into_objects(opts.input_file.as_ref())?;
-        let program = read_program(opts.program.as_deref(), opts.program_file.as_ref())?;
+        let source = read_program(opts.program.as_deref(), opts.program_file.as_ref())?;
+        let state = opts.compiler_state();
+        let (mut program, warnings) = if opts.json_diagnostics {
+            compile_rendered(&source, opts.min_severity, state, diagnostics_to_json)?
+        } else {
+            compile(&source, opts.min_severity, state)?
+        };
 
-        for mut object in objects {
-            let result = execute(&mut object, program.clone()).map(|v| {
-                if opts.print_object {
-                    object.to_string()
-                } else {
-                    v.to_string()
-                }
-            });
+        if let Some(warnings) = warnings {
+            eprintln!("{}", warnings);
+        }
 
+        if let Some(path) = &opts.input_schema {
+            program = program.with_input_schema(read_schema(path)?);
+        }
+
+        if let Some(path) = &opts.output_schema {
+            program = program.with_output_schema(read_schema(path)?);
+        }
+
+        let results = process(
+            objects,
+            program,
+            opts.print_object,
+            opts.wrap_result,
+            opts.parallelism,
+            opts.enrichment_cache_size,
+        );
+
+        for result in results {
             match result {
                 Ok(ok) => println!("{}", ok),
                 Err(err) => eprintln!("{}", err),
@@ -73,6 +235,84 @@ fn run(opts: &Opts) -> Result<(), Error> {
     }
 }
 
+fn run_command(command: &Command) -> Result<(), Error> {
+    match command {
+        Command::Hover {
+            program_file,
+            offset,
+        } => {
+            let source = read(File::open(program_file)?)?;
+            println!("{}", hover::hover(&source, *offset, &stdlib::all()));
+        }
+        Command::Explain { program_file } => {
+            let source = read(File::open(program_file)?)?;
+            let mut state = state::Compiler::default();
+            let analysis = vrl::analyze(&source, &stdlib::all(), &mut state);
+
+            println!("{}", explain::explain(&source, &analysis));
+
+            let diagnostics = Formatter::new(&source, analysis.diagnostics.clone()).colored();
+            if analysis.diagnostics.has_errors() {
+                return Err(Error::Parse(diagnostics.to_string()));
+            } else if !diagnostics.is_empty() {
+                eprintln!("{}", diagnostics);
+            }
+        }
+        Command::Complete {
+            program_file,
+            offset,
+        } => {
+            let source = read(File::open(program_file)?)?;
+            println!("{}", complete::complete(&source, *offset, &stdlib::all()));
+        }
+        Command::Cost { program_file } => {
+            let source = read(File::open(program_file)?)?;
+            let (program, _) = compile(&source, Severity::Error, state::Compiler::default())?;
+
+            println!("{}", cost_to_json(program.estimate_cost()));
+        }
+        Command::Deps { program_file } => {
+            let source = read(File::open(program_file)?)?;
+            let (program, _) = compile(&source, Severity::Error, state::Compiler::default())?;
+
+            for function in program.functions_used() {
+                println!("{}", function);
+            }
+        }
+        Command::Paths { program_file } => {
+            let source = read(File::open(program_file)?)?;
+            let (program, _) = compile(&source, Severity::Error, state::Compiler::default())?;
+
+            println!("{}", paths_to_json(&program));
+        }
+    }
+
+    Ok(())
+}
+
+fn cost_to_json(cost: Cost) -> serde_json::Value {
+    serde_json::json!({
+        "score": cost.score,
+        "expressions": cost.expressions,
+        "function_calls": cost.function_calls,
+        "regexes": cost.regexes,
+        "conditions": cost.conditions,
+    })
+}
+
+fn paths_to_json(program: &Program) -> serde_json::Value {
+    let read = program.paths_read();
+    let written = program.paths_written();
+    let strings =
+        |paths: BTreeSet<vrl::Path>| paths.iter().map(ToString::to_string).collect::<Vec<_>>();
+
+    serde_json::json!({
+        "read": strings(read.paths),
+        "written": strings(written.paths),
+        "complete": read.complete && written.complete,
+    })
+}
+
 fn repl(objects: Vec<Value>) -> Result<(), Error> {
     if cfg!(feature = "repl") {
         repl::run(objects);
@@ -82,16 +322,239 @@ fn repl(objects: Vec<Value>) -> Result<(), Error> {
     }
 }
 
-fn execute(object: &mut impl Target, source: String) -> Result<Value, Error> {
-    let state = state::Runtime::default();
+/// Compiles the program, returning the rendered warnings alongside it, if any
+/// are at least as severe as `min_severity`.
+fn compile(
+    source: &str,
+    min_severity: Severity,
+    state: state::Compiler,
+) -> Result<(Program, Option<String>), Error> {
+    compile_rendered(source, min_severity, state, |formatter| {
+        formatter.colored().to_string()
+    })
+}
+
+/// Compiles the program like [`compile`], rendering the diagnostics with the
+/// given function.
+fn compile_rendered(
+    source: &str,
+    min_severity: Severity,
+    mut state: state::Compiler,
+    render: impl Fn(Formatter) -> String,
+) -> Result<(Program, Option<String>), Error> {
+    let (program, diagnostics) = vrl::compile_detailed(source, &stdlib::all(), &mut state);
+    let formatter = Formatter::new(source, diagnostics).with_min_severity(min_severity);
+
+    match program {
+        Some(program) if formatter.is_empty() => Ok((program, None)),
+        Some(program) => Ok((program, Some(render(formatter)))),
+        None => Err(Error::Parse(render(formatter))),
+    }
+}
+
+/// Renders the diagnostics as JSON, one object per line.
+fn diagnostics_to_json(formatter: Formatter) -> String {
+    formatter
+        .diagnostics()
+        .map(|diagnostic| diagnostic_to_json(diagnostic).to_string())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn diagnostic_to_json(diagnostic: &Diagnostic) -> serde_json::Value {
+    let labels = diagnostic
+        .labels()
+        .iter()
+        .map(|label| {
+            serde_json::json!({
+                "message": label.message,
+                "primary": label.primary,
+                "start": label.span.start(),
+                "end": label.span.end(),
+            })
+        })
+        .collect::<Vec<_>>();
+
+    serde_json::json!({
+        "code": format!("E{:03}", diagnostic.code()),
+        "severity": diagnostic.severity().to_string(),
+        "message": diagnostic.message(),
+        "labels": labels,
+        "notes": diagnostic.notes().iter().map(ToString::to_string).collect::<Vec<_>>(),
+    })
+}
+
+/// Runs the program against each of the objects, returning the printable
+/// result for each of them, in order.
+///
+/// With a `parallelism` above one, the objects are split into contiguous
+/// chunks, each processed on its own thread, and the results of the chunks are
+/// concatenated in turn, which preserves the order of the objects.
+#[allow(clippy::needless_collect)]
+fn process(
+    objects: Vec<Value>,
+    program: Program,
+    print_object: bool,
+    wrap_result: bool,
+    parallelism: usize,
+    enrichment_cache_size: Option<usize>,
+) -> Vec<Result<String, Error>> {
+    let parallelism = parallelism.max(1).min(objects.len().max(1));
+    if parallelism == 1 {
+        return process_chunk(
+            objects,
+            &program,
+            print_object,
+            wrap_result,
+            enrichment_cache_size,
+        );
+    }
+
+    let program = Arc::new(program);
+    let chunk_size = (objects.len() + parallelism - 1) / parallelism;
+    let mut objects = objects.into_iter();
+
+    // All of the workers are spawned before any of them is joined.
+    let workers = (0..parallelism)
+        .map(|_| {
+            let chunk = objects.by_ref().take(chunk_size).collect::<Vec<_>>();
+            let program = Arc::clone(&program);
+
+            thread::spawn(move || {
+                process_chunk(
+                    chunk,
+                    &program,
+                    print_object,
+                    wrap_result,
+                    enrichment_cache_size,
+                )
+            })
+        })
+        .collect::<Vec<_>>();
+
+    workers
+        .into_iter()
+        .flat_map(|worker| worker.join().expect("worker thread panicked"))
+        .collect()
+}
+
+fn process_chunk(
+    mut objects: Vec<Value>,
+    program: &Program,
+    print_object: bool,
+    wrap_result: bool,
+    enrichment_cache_size: Option<usize>,
+) -> Vec<Result<String, Error>> {
+    let state = runtime_state(enrichment_cache_size);
+    if wrap_result {
+        return process_chunk_wrapped(objects, program, print_object, state);
+    }
+
     let mut runtime = Runtime::new(state);
-    let program = vrl::compile(&source, &stdlib::all()).map_err(|diagnostics| {
-        Error::Parse(Formatter::new(&source, diagnostics).colored().to_string())
-    })?;
+    let results = runtime.resolve_batch(&mut objects, program, false);
+
+    objects
+        .iter()
+        .zip(results)
+        .map(|(object, result)| match result {
+            Ok(_) if print_object => Ok(object.to_string()),
+            Ok(value) => Ok(value.to_string()),
+            Err(err) => Err(runtime_error(err)),
+        })
+        .collect()
+}
+
+/// Like [`process_chunk`], but the result of each object is printed as a JSON
+/// envelope, along with the values the program logged for it.
+///
+/// The objects are resolved one at a time, to tell apart the values logged
+/// for each of them.
+fn process_chunk_wrapped(
+    mut objects: Vec<Value>,
+    program: &Program,
+    print_object: bool,
+    state: state::Runtime,
+) -> Vec<Result<String, Error>> {
+    let logs = Logs::default();
+    let mut runtime = Runtime::new(state.with_log_hook(logs.clone()));
+
+    objects
+        .iter_mut()
+        .map(|object| {
+            let result = runtime
+                .resolve_batch(std::slice::from_mut(object), program, false)
+                .remove(0);
+
+            Ok(wrap(object, result, logs.take(), print_object).to_string())
+        })
+        .collect()
+}
+
+/// The runtime state of each chunk of event objects, with an enrichment cache of
+/// the given size, if any.
+fn runtime_state(enrichment_cache_size: Option<usize>) -> state::Runtime {
+    let state = state::Runtime::default();
+
+    match enrichment_cache_size {
+        Some(size) => state.with_enrichment_cache(EnrichmentCache::new(size)),
+        None => state,
+    }
+}
+
+fn wrap(
+    object: &Value,
+    result: RuntimeResult,
+    logs: Vec<serde_json::Value>,
+    print_object: bool,
+) -> serde_json::Value {
+    match result {
+        Ok(_) if print_object => serde_json::json!({
+            "output": object,
+            "logs": logs,
+            "dropped": false,
+        }),
+        Ok(value) => serde_json::json!({
+            "output": value,
+            "logs": logs,
+            "dropped": false,
+        }),
+        Err(err) => serde_json::json!({
+            "output": null,
+            "logs": logs,
+            "dropped": true,
+            "error": abort_message(&err),
+        }),
+    }
+}
+
+/// Collects the values logged by the program, in order.
+#[derive(Debug, Clone, Default)]
+struct Logs(Arc<Mutex<Vec<serde_json::Value>>>);
 
-    runtime
-        .resolve(object, &program)
-        .map_err(|err| Error::Runtime(err.to_string()))
+impl Logs {
+    /// Returns the values logged so far, and forgets them.
+    fn take(&self) -> Vec<serde_json::Value> {
+        std::mem::take(&mut *self.0.lock().expect("lock poisoned"))
+    }
+}
+
+impl LogHook for Logs {
+    fn log(&self, level: &str, value: &Value) {
+        let entry = serde_json::json!({ "level": level, "message": value });
+
+        self.0.lock().expect("lock poisoned").push(entry);
+    }
+}
+
+fn runtime_error(err: Abort) -> Error {
+    Error::Runtime(abort_message(&err))
+}
+
+fn abort_message(err: &Abort) -> String {
+    match err.payload() {
+        Some(payload) => format!("{} (payload: {})", err, payload),
+        None => err.to_string(),
+    }
 }
 
 fn read_program(source: Option<&str>, file: Option<&PathBuf>) -> Result<String, Error> {
@@ -104,6 +567,12 @@ fn read_program(source: Option<&str>, file: Option<&PathBuf>) -> Result<String,
     }
 }
 
+fn read_schema(path: &Path) -> Result<Schema, Error> {
+    let schema = serde_to_vrl(serde_json::from_str(&read(File::open(path)?)?)?);
+
+    Schema::try_from(schema).map_err(|err| Error::Schema(format!("{}: {}", path.display(), err)))
+}
+
 fn read_into_objects(input: Option<&PathBuf>) -> Result<Vec<Value>, Error> {
     let input = match input {
         Some(path) => read(File::open(path)?),
@@ -151,3 +620,405 @@ fn should_open_repl(opts: &Opts) -> bool {
 fn default_objects() -> Vec<Value> {
     vec![Value::Object(BTreeMap::new())]
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_send_sync<T: Send + Sync>() {}
+
+    fn output(results: Vec<Result<String, Error>>) -> String {
+        results
+            .into_iter()
+            .map(|result| match result {
+                Ok(ok) => format!("ok: {}\n", ok),
+                Err(err @ Error::Runtime(_)) => format!("err: {}\n", err),
+                Err(err) => panic!("unexpected error: {}", err),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn program_is_send_and_sync() {
+        assert_send_sync::<Program>();
+    }
+
+    #[test]
+    fn parallel_output_matches_sequential() {
+        let source = ".doubled = to_int!(.id) * 2\n.";
+
+        // Every tenth object has an invalid id, to interleave errors with the
+        // results.
+        let objects = (0..101)
+            .map(|i| {
+                let id = if i % 10 == 0 {
+                    Value::from("invalid")
+                } else {
+                    Value::from(i)
+                };
+
+                let mut object = BTreeMap::new();
+                object.insert("id".to_owned(), id);
+                Value::from(object)
+            })
+            .collect::<Vec<_>>();
+
+        let sequential = output(process(
+            objects.clone(),
+            compile(source, Severity::Note, state::Compiler::default())
+                .unwrap()
+                .0,
+            false,
+            false,
+            1,
+            None,
+        ));
+
+        for parallelism in &[2, 3, 8, 200] {
+            let parallel = output(process(
+                objects.clone(),
+                compile(source, Severity::Note, state::Compiler::default())
+                    .unwrap()
+                    .0,
+                false,
+                false,
+                *parallelism,
+                None,
+            ));
+
+            assert_eq!(parallel, sequential);
+        }
+    }
+
+    #[test]
+    fn runtime_errors_print_their_message() {
+        let source = r#"abort!("invalid event", { "reason": "missing id" })"#;
+        let program = compile(source, Severity::Note, state::Compiler::default())
+            .unwrap()
+            .0;
+
+        let mut results = process(default_objects(), program, false, false, 1, None);
+
+        assert_eq!(
+            results.remove(0).unwrap_err().to_string(),
+            r#"function call error for "abort" at (0:51): invalid event (payload: { "reason": "missing id" })"#
+        );
+    }
+
+    #[test]
+    fn input_schema_rejects_before_running() {
+        let schema = serde_to_vrl(serde_json::json!({
+            "type": "object",
+            "required": ["id"],
+        }));
+        let program = compile(".id = 1\n.", Severity::Note, state::Compiler::default())
+            .unwrap()
+            .0
+            .with_input_schema(Schema::try_from(schema).unwrap());
+
+        let objects = vec![
+            serde_to_vrl(serde_json::json!({ "id": 2 })),
+            serde_to_vrl(serde_json::json!({ "message": "foo" })),
+        ];
+
+        assert_eq!(
+            output(process(objects, program, false, false, 1, None)),
+            "ok: { \"id\": 1 }\nerr: input schema violation: .: missing required field \"id\"\n"
+        );
+    }
+
+    #[test]
+    fn min_severity_filters_warnings() {
+        // The unused literal is a warning, which doesn't stop the program from
+        // compiling.
+        let source = "\"unused\"\n.";
+
+        let (_, warnings) = compile(source, Severity::Note, state::Compiler::default()).unwrap();
+        assert!(warnings.unwrap().contains("E670"));
+
+        let (_, warnings) = compile(source, Severity::Warning, state::Compiler::default()).unwrap();
+        assert!(warnings.unwrap().contains("E670"));
+
+        let (_, warnings) = compile(source, Severity::Error, state::Compiler::default()).unwrap();
+        assert!(warnings.is_none());
+    }
+
+    #[test]
+    fn min_severity_keeps_errors() {
+        let source = "\"unused\"\nfoo()";
+
+        match compile(source, Severity::Error, state::Compiler::default()) {
+            Err(Error::Parse(diagnostics)) => {
+                assert!(diagnostics.contains("E105"));
+                assert!(!diagnostics.contains("E670"));
+            }
+            result => panic!("expected a parse error, got {:?}", result.map(|_| ())),
+        }
+    }
+
+    #[test]
+    fn exits_non_zero_only_on_errors() {
+        let input = tempfile::NamedTempFile::new().unwrap();
+        std::fs::write(input.path(), "{}\n").unwrap();
+        let input = input.path().to_str().unwrap();
+
+        let exit = |program: &str| {
+            cmd(&Opts::from_iter(&[
+                "vrl",
+                "--input",
+                input,
+                "--min-severity",
+                "error",
+                program,
+            ]))
+        };
+
+        assert_eq!(exit("\"unused\"\n."), exitcode::OK);
+        assert_eq!(exit("foo()"), exitcode::SOFTWARE);
+    }
+
+    #[test]
+    fn parses_hover_command() {
+        let opts = Opts::from_iter(&["vrl", "hover", "--program", "foo.vrl", "--offset", "3"]);
+
+        match opts.command {
+            Some(Command::Hover {
+                program_file,
+                offset,
+            }) => {
+                assert_eq!(program_file, PathBuf::from("foo.vrl"));
+                assert_eq!(offset, 3);
+            }
+            command => panic!("expected the hover command, got {:?}", command),
+        }
+        assert!(opts.program.is_none());
+    }
+
+    #[test]
+    fn enrichment_cache_size() {
+        let opts = Opts::from_iter(&["vrl", "--enrichment-cache-size", "100", "."]);
+        assert_eq!(opts.enrichment_cache_size, Some(100));
+
+        let state = runtime_state(opts.enrichment_cache_size);
+        assert!(state.enrichment_cache().is_some());
+
+        let opts = Opts::from_iter(&["vrl", "."]);
+        assert!(runtime_state(opts.enrichment_cache_size)
+            .enrichment_cache()
+            .is_none());
+    }
+
+    #[test]
+    fn cost_of_regex_in_condition() {
+        let simple = compile(".foo = 1", Severity::Error, state::Compiler::default())
+            .unwrap()
+            .0;
+        let regex = compile(
+            r#"if match(string!(.message), r'^\d+$') { .kind = "number" }"#,
+            Severity::Error,
+            state::Compiler::default(),
+        )
+        .unwrap()
+        .0;
+
+        assert!(regex.estimate_cost().score > simple.estimate_cost().score);
+        assert_eq!(
+            cost_to_json(regex.estimate_cost()),
+            serde_json::json!({
+                "score": 7 + 2 * 10 + 50 + 2,
+                "expressions": 7,
+                "function_calls": 2,
+                "regexes": 1,
+                "conditions": 1,
+            })
+        );
+    }
+
+    #[test]
+    fn optimized_output_matches_unoptimized() {
+        let corpus = [
+            ".sum = 1 + 2 * 3\n.ratio = 10 / 4 ?? 0\n.",
+            ".remainder = 10 % 3\n.zero = 10 % 0\n.",
+            r#".message = upcase("a") + downcase(string!(.message))
+."#,
+            r#".first = parse_json!(.payload)
+.second = parse_json!(.payload)
+.payload = "[1]"
+.third = parse_json!(.payload)
+."#,
+            r#"if contains(string!(.message), "error") { .level = "error" } else { .level = "info" }
+."#,
+        ];
+
+        let objects = vec![
+            serde_to_vrl(serde_json::json!({ "message": "An error", "payload": "{\"a\": 1}" })),
+            serde_to_vrl(serde_json::json!({ "message": "ok", "payload": "invalid" })),
+            serde_to_vrl(serde_json::json!({})),
+        ];
+
+        for source in &corpus {
+            let run = |optimization| {
+                let state = state::Compiler::default().with_optimization(optimization);
+                let program = compile(source, Severity::Note, state).unwrap().0;
+                output(process(objects.clone(), program, false, false, 1, None))
+            };
+
+            assert_eq!(
+                run(Optimization::Full),
+                run(Optimization::Disabled),
+                "{}",
+                source
+            );
+        }
+    }
+
+    #[test]
+    fn functions_used() {
+        let source = r#"# parse_json(.message)
+.message = downcase(string!(.message))
+if contains(.message, "error") {
+    .level = upcase(string(.level) ?? "error")
+}"#;
+        let program = compile(source, Severity::Error, state::Compiler::default())
+            .unwrap()
+            .0;
+
+        assert_eq!(
+            program.functions_used().into_iter().collect::<Vec<_>>(),
+            vec!["contains", "downcase", "string", "upcase"]
+        );
+    }
+
+    #[test]
+    fn function_flags() {
+        let source = ".message = upcase(string!(.message))";
+        let opts = Opts::from_iter(&["vrl", source, "--deny-functions", "upcase,downcase"]);
+
+        match compile(source, Severity::Error, opts.compiler_state()) {
+            Err(Error::Parse(diagnostics)) => assert!(diagnostics.contains("E111")),
+            result => panic!("expected a parse error, got {:?}", result.map(|_| ())),
+        }
+
+        let opts = Opts::from_iter(&["vrl", source, "--allow-functions", "upcase,string"]);
+        assert!(compile(source, Severity::Error, opts.compiler_state()).is_ok());
+
+        let opts = Opts::from_iter(&["vrl", source, "--allow-functions", "string"]);
+        assert!(compile(source, Severity::Error, opts.compiler_state()).is_err());
+    }
+
+    #[test]
+    fn wrap_result() {
+        let source = r#".checked = true
+log(.id, level: "warn")
+if .id == 2 { abort!("invalid id") }"#;
+        let program = compile(source, Severity::Error, state::Compiler::default())
+            .unwrap()
+            .0;
+        let objects = vec![
+            serde_to_vrl(serde_json::json!({ "id": 1 })),
+            serde_to_vrl(serde_json::json!({ "id": 2 })),
+        ];
+
+        let envelopes = process(objects, program, true, true, 1, None)
+            .into_iter()
+            .map(|result| serde_json::from_str::<serde_json::Value>(&result.unwrap()).unwrap())
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            envelopes,
+            vec![
+                serde_json::json!({
+                    "output": { "id": 1, "checked": true },
+                    "logs": [{ "level": "warn", "message": 1 }],
+                    "dropped": false,
+                }),
+                serde_json::json!({
+                    "output": null,
+                    "logs": [{ "level": "warn", "message": 2 }],
+                    "dropped": true,
+                    "error": r#"function call error for "abort" at (54:74): invalid id"#,
+                }),
+            ]
+        );
+    }
+
+    #[test]
+    fn json_diagnostics() {
+        let diagnostics = |source: &str| match compile_rendered(
+            source,
+            Severity::Note,
+            state::Compiler::default(),
+            diagnostics_to_json,
+        ) {
+            Err(Error::Parse(json)) => json
+                .lines()
+                .map(|line| serde_json::from_str::<serde_json::Value>(line).unwrap())
+                .collect::<Vec<_>>(),
+            result => panic!("expected a parse error, got {:?}", result.map(|_| ())),
+        };
+
+        let upcase = diagnostics("upcase(1)");
+        assert_eq!(upcase.len(), 1);
+        assert_eq!(upcase[0]["code"], "E110");
+        assert_eq!(upcase[0]["severity"], "error");
+        assert_eq!(upcase[0]["message"], "invalid argument type");
+        assert_eq!(
+            upcase[0]["labels"][0],
+            serde_json::json!({
+                "message": r#"this expression resolves to the exact type "integer""#,
+                "primary": true,
+                "start": 7,
+                "end": 8,
+            })
+        );
+
+        // The code identifies the class of the error, not the function or
+        // the types involved.
+        let downcase = diagnostics(".message = downcase(true)");
+        assert_eq!(downcase[0]["code"], "E110");
+        assert_eq!(diagnostics("upcase(1)"), upcase);
+    }
+
+    #[test]
+    fn paths() {
+        let source = ".c = .a.b";
+        let program = compile(source, Severity::Error, state::Compiler::default())
+            .unwrap()
+            .0;
+
+        assert_eq!(
+            paths_to_json(&program),
+            serde_json::json!({ "read": [".a.b"], "written": [".c"], "complete": true })
+        );
+
+        let source = "del(.a)";
+        let program = compile(source, Severity::Error, state::Compiler::default())
+            .unwrap()
+            .0;
+
+        assert_eq!(
+            paths_to_json(&program),
+            serde_json::json!({ "read": [], "written": [".a"], "complete": false })
+        );
+
+        let source = "del(.a)\n.c = .a.b";
+        let program = compile(source, Severity::Error, state::Compiler::default())
+            .unwrap()
+            .0;
+
+        assert_eq!(
+            paths_to_json(&program),
+            serde_json::json!({ "read": [".a.b"], "written": [".a", ".c"], "complete": false })
+        );
+
+        let source = ".b = .a\ndel(.a)";
+        let program = compile(source, Severity::Error, state::Compiler::default())
+            .unwrap()
+            .0;
+
+        assert_eq!(
+            paths_to_json(&program),
+            serde_json::json!({ "read": [".a"], "written": [".a", ".b"], "complete": false })
+        );
+    }
+}
@@ -0,0 +1,212 @@
+use crate::hover::{signature, type_name};
+use serde_json::{json, Value as Json};
+use vrl::{state, Function};
+
+/// A completion candidate, as editors list them.
+struct Completion {
+    label: String,
+    kind: &'static str,
+    detail: String,
+}
+
+/// Returns the completion candidates at the byte offset of the source, as a
+/// JSON array.
+///
+/// After a `.`, the candidates are the known fields of the object before it.
+/// Anywhere else, they're the variables assigned before the offset, and the
+/// functions. Either way, only the candidates starting with the identifier
+/// being typed at the offset are returned.
+pub fn complete(source: &str, offset: usize, functions: &[Box<dyn Function>]) -> Json {
+    let offset = offset.min(source.len());
+    if !source.is_char_boundary(offset) {
+        return json!([]);
+    }
+
+    let prefix_start = scan_back(source, offset, is_ident);
+    let prefix = &source[prefix_start..offset];
+
+    let completions = match source[..prefix_start].strip_suffix('.') {
+        Some(before) => fields(source, before.len(), offset, functions),
+        None => scope(&source[..prefix_start], functions),
+    };
+
+    completions
+        .into_iter()
+        .filter(|completion| completion.label.starts_with(prefix))
+        .map(|completion| {
+            json!({
+                "label": completion.label,
+                "kind": completion.kind,
+                "detail": completion.detail,
+            })
+        })
+        .collect()
+}
+
+/// The known fields of the expression ending at the `.` found at the `dot`
+/// byte offset.
+fn fields(
+    source: &str,
+    dot: usize,
+    offset: usize,
+    functions: &[Box<dyn Function>],
+) -> Vec<Completion> {
+    let start = scan_back(source, dot, |c| is_ident(c) || matches!(c, '.' | '[' | ']'));
+
+    // The incomplete path doesn't parse, it's left out so that the expression
+    // before it can be typed. A `.` on its own is the event itself, and is
+    // kept.
+    let (source, end) = if start == dot {
+        (format!("{}{}", &source[..=dot], &source[offset..]), dot + 1)
+    } else {
+        (format!("{}{}", &source[..dot], &source[offset..]), dot)
+    };
+
+    let mut state = state::Compiler::default();
+    let analysis = vrl::analyze(&source, functions, &mut state);
+
+    analysis
+        .annotations
+        .iter()
+        .find(|annotation| annotation.span.start() == start && annotation.span.end() == end)
+        .map(|annotation| annotation.type_def.object_fields())
+        .unwrap_or_default()
+        .into_iter()
+        .map(|(field, type_def)| Completion {
+            label: field,
+            kind: "field",
+            detail: type_name(&type_def),
+        })
+        .collect()
+}
+
+/// The variables assigned in the source, and the functions.
+fn scope(source: &str, functions: &[Box<dyn Function>]) -> Vec<Completion> {
+    let mut state = state::Compiler::default();
+    vrl::analyze(source, functions, &mut state);
+
+    let mut variables = state
+        .variables()
+        .map(|(ident, type_def)| Completion {
+            label: ident.to_owned(),
+            kind: "variable",
+            detail: type_name(type_def),
+        })
+        .collect::<Vec<_>>();
+    variables.sort_by(|a, b| a.label.cmp(&b.label));
+
+    let mut functions = functions
+        .iter()
+        .map(|function| Completion {
+            label: function.identifier().to_owned(),
+            kind: "function",
+            detail: signature(function.as_ref()),
+        })
+        .collect::<Vec<_>>();
+    functions.sort_by(|a, b| a.label.cmp(&b.label));
+
+    variables.into_iter().chain(functions).collect()
+}
+
+/// Returns the byte offset at which the characters matching the predicate
+/// right before `end` start.
+fn scan_back(source: &str, end: usize, predicate: impl Fn(char) -> bool) -> usize {
+    source[..end]
+        .char_indices()
+        .rev()
+        .take_while(|(_, c)| predicate(*c))
+        .last()
+        .map_or(end, |(i, _)| i)
+}
+
+fn is_ident(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == '_'
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn labels(completions: &Json) -> Vec<&str> {
+        completions
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|completion| completion["label"].as_str().unwrap())
+            .collect()
+    }
+
+    const OBJECT: &str = "foo = { \"bar\": 1, \"baz\": { \"qux\": true } }\n";
+
+    #[test]
+    fn complete_object_fields() {
+        let source = format!("{}foo.", OBJECT);
+        let completions = complete(&source, source.len(), &stdlib::all());
+
+        assert_eq!(
+            completions,
+            json!([
+                { "label": "bar", "kind": "field", "detail": "integer" },
+                { "label": "baz", "kind": "field", "detail": "object" },
+            ])
+        );
+    }
+
+    #[test]
+    fn complete_object_fields_with_prefix() {
+        let source = format!("{}foo.baz.q\n.", OBJECT);
+        let completions = complete(&source, source.len() - 2, &stdlib::all());
+
+        assert_eq!(labels(&completions), vec!["qux"]);
+
+        let source = format!("{}foo.baz", OBJECT);
+        let completions = complete(&source, source.len(), &stdlib::all());
+
+        assert_eq!(labels(&completions), vec!["baz"]);
+    }
+
+    #[test]
+    fn complete_unknown_object_fields() {
+        let source = ".foo.";
+
+        assert_eq!(complete(source, source.len(), &stdlib::all()), json!([]));
+    }
+
+    #[test]
+    fn complete_top_level() {
+        let functions = stdlib::all();
+        let source = format!("{}bar = \"bar\"\n", OBJECT);
+        let completions = complete(&source, source.len(), &functions);
+        let labels = labels(&completions);
+
+        assert_eq!(labels.len(), functions.len() + 2);
+        assert_eq!(&labels[..2], &["bar", "foo"]);
+        assert!(labels.contains(&"parse_json"));
+        assert_eq!(completions[0]["kind"], "variable");
+        assert_eq!(completions[0]["detail"], "string");
+    }
+
+    #[test]
+    fn complete_top_level_with_prefix() {
+        let source = "parse_j";
+        let completions = complete(source, source.len(), &stdlib::all());
+
+        assert_eq!(
+            completions,
+            json!([{
+                "label": "parse_json",
+                "kind": "function",
+                "detail": "parse_json(value: string)",
+            }])
+        );
+    }
+
+    #[test]
+    fn complete_only_assigned_variables() {
+        let source = "foo = 1\nf\nfizz = 2";
+        let completions = complete(source, 9, &stdlib::all());
+
+        assert!(labels(&completions).contains(&"foo"));
+        assert!(!labels(&completions).contains(&"fizz"));
+    }
+}
@@ -0,0 +1,111 @@
+use crate::hover::type_name;
+use std::collections::BTreeMap;
+use vrl::{
+    analysis::{Annotation, Subject},
+    Analysis,
+};
+
+/// Returns the source, with the inferred type of each top-level expression,
+/// and whether it can fail, as a comment at the end of the line on which the
+/// expression ends.
+pub fn explain(source: &str, analysis: &Analysis) -> String {
+    let mut comments = BTreeMap::<usize, Vec<String>>::new();
+
+    for annotation in analysis.top_level() {
+        let line = source[..annotation.span.end()].matches('\n').count();
+
+        comments
+            .entry(line)
+            .or_default()
+            .push(comment(annotation, analysis));
+    }
+
+    source
+        .lines()
+        .enumerate()
+        .map(|(i, line)| match comments.get(&i) {
+            Some(comments) => format!("{}  # {}", line, comments.join("; ")),
+            None => line.to_owned(),
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn comment(annotation: &Annotation, analysis: &Analysis) -> String {
+    let aborts = analysis.annotations.iter().any(|call| {
+        matches!(
+            call.subject,
+            Subject::FunctionCall {
+                abort_on_error: true,
+                ..
+            }
+        ) && annotation.span.start() <= call.span.start()
+            && call.span.end() <= annotation.span.end()
+    });
+
+    // An expression calling a function with `!` is infallible, as the program
+    // aborts instead of returning the error.
+    let fallibility = if annotation.type_def.is_fallible() {
+        "fallible, unhandled"
+    } else if aborts {
+        "fallible, aborts on error"
+    } else {
+        "infallible"
+    };
+
+    format!("{}, {}", type_name(&annotation.type_def), fallibility)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use vrl::state;
+
+    fn explained(source: &str) -> String {
+        let mut state = state::Compiler::default();
+        let analysis = vrl::analyze(source, &stdlib::all(), &mut state);
+
+        explain(source, &analysis)
+    }
+
+    #[test]
+    fn explain_parse_json_assignment() {
+        let source = indoc::indoc! {r#"
+            .message = s'{ "level": "info" }'
+            .parsed = parse_json!(.message)
+            parsed, err = parse_json(.message)
+
+            if err == null {
+                .level = "info"
+            }
+        "#};
+
+        assert_eq!(
+            explained(source),
+            indoc::indoc! {r#"
+                .message = s'{ "level": "info" }'  # string, infallible
+                .parsed = parse_json!(.message)  # string | integer | float | boolean | object | array | null, fallible, aborts on error
+                parsed, err = parse_json(.message)  # string | integer | float | boolean | object | array | null, infallible
+
+                if err == null {
+                    .level = "info"
+                }  # string, infallible"#}
+        );
+    }
+
+    #[test]
+    fn explain_unhandled_error() {
+        assert_eq!(
+            explained("parse_json(.message)"),
+            "parse_json(.message)  # string | integer | float | boolean | object | array | null, fallible, unhandled"
+        );
+    }
+
+    #[test]
+    fn explain_expressions_on_the_same_line() {
+        assert_eq!(
+            explained("foo = 1; foo"),
+            "foo = 1; foo  # integer, infallible; integer, infallible"
+        );
+    }
+}
@@ -0,0 +1,141 @@
+use serde_json::{json, Value as Json};
+use vrl::{analysis::Subject, prelude::TypeDef, state, value::Kind, Function};
+
+/// Returns what editors show when hovering over the byte offset of the source:
+/// the type of the innermost expression at the offset and, for the identifier
+/// of a function call, the signature and documentation of the function.
+///
+/// Returns `null` if there's no expression at the offset.
+pub fn hover(source: &str, offset: usize, functions: &[Box<dyn Function>]) -> Json {
+    let mut state = state::Compiler::default();
+    let analysis = vrl::analyze(source, functions, &mut state);
+
+    let annotation = match analysis.annotation_at(offset) {
+        Some(annotation) => annotation,
+        None => return Json::Null,
+    };
+
+    let mut hover = json!({
+        "type": type_name(&annotation.type_def),
+        "fallible": annotation.type_def.is_fallible(),
+    });
+
+    match &annotation.subject {
+        Subject::Expression => {}
+        Subject::Variable(ident) => hover["variable"] = json!(ident),
+        Subject::FunctionCall { ident, .. } => {
+            if let Some(function) = functions.iter().find(|f| f.identifier() == ident) {
+                hover["function"] = json!(ident);
+                hover["signature"] = json!(signature(function.as_ref()));
+                hover["summary"] = documentation(function.summary());
+                hover["usage"] = documentation(function.usage());
+            }
+        }
+    }
+
+    hover
+}
+
+/// The signature of the function, such as `round(value: integer | float,
+/// precision?: integer)`, optional parameters being suffixed with a `?`.
+pub(crate) fn signature(function: &dyn Function) -> String {
+    let parameters = function
+        .parameters()
+        .iter()
+        .map(|parameter| {
+            let optional = if parameter.required { "" } else { "?" };
+
+            format!(
+                "{}{}: {}",
+                parameter.keyword,
+                optional,
+                kind_name(parameter.kind())
+            )
+        })
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    format!("{}({})", function.identifier(), parameters)
+}
+
+/// The kinds of the type definition, such as `string | null`.
+pub(crate) fn type_name(type_def: &TypeDef) -> String {
+    kind_name(type_def.kind())
+}
+
+fn kind_name(kind: Kind) -> String {
+    if kind.is_all() {
+        return "any".to_owned();
+    }
+
+    let kinds = [
+        Kind::Bytes,
+        Kind::Integer,
+        Kind::Float,
+        Kind::Boolean,
+        Kind::Object,
+        Kind::Array,
+        Kind::Timestamp,
+        Kind::Regex,
+        Kind::Null,
+    ];
+
+    kinds
+        .iter()
+        .filter(|k| kind.contains(**k))
+        .map(|k| k.as_str())
+        .collect::<Vec<_>>()
+        .join(" | ")
+}
+
+/// Functions without documentation return a "TODO" placeholder, which isn't
+/// worth showing.
+fn documentation(text: &'static str) -> Json {
+    match text.trim() {
+        "TODO" => Json::Null,
+        text => json!(text),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hover_function_call() {
+        let hover = hover(r#"parse_json!("{}")"#, 3, &stdlib::all());
+
+        assert_eq!(hover["function"], "parse_json");
+        assert_eq!(hover["signature"], "parse_json(value: string)");
+        assert_eq!(hover["summary"], "parse a string to a JSON type");
+        assert_eq!(
+            hover["type"],
+            "string | integer | float | boolean | object | array | null"
+        );
+    }
+
+    #[test]
+    fn hover_function_signature_with_optional_parameters() {
+        let hover = hover(r#"round(1.5, precision: 1)"#, 0, &stdlib::all());
+
+        assert_eq!(
+            hover["signature"],
+            "round(value: integer | float, precision?: integer)"
+        );
+    }
+
+    #[test]
+    fn hover_variable() {
+        let hover = hover("foo = \"bar\"\nfoo", 12, &stdlib::all());
+
+        assert_eq!(
+            hover,
+            json!({ "variable": "foo", "type": "string", "fallible": false })
+        );
+    }
+
+    #[test]
+    fn hover_outside_expressions() {
+        assert_eq!(hover("foo = 1\n\n", 8, &stdlib::all()), Json::Null);
+    }
+}
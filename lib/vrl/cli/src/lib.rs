@@ -1,4 +1,7 @@
 pub mod cmd;
+mod complete;
+mod explain;
+mod hover;
 #[cfg(feature = "repl")]
 mod repl;
 
@@ -9,12 +12,15 @@ pub enum Error {
     #[error("io error")]
     Io(#[from] std::io::Error),
 
-    #[error("parse error")]
+    #[error("{0}")]
     Parse(String),
 
-    #[error("runtime error")]
+    #[error("{0}")]
     Runtime(String),
 
+    #[error("schema error: {0}")]
+    Schema(String),
+
     #[error("json error")]
     Json(#[from] serde_json::Error),
 
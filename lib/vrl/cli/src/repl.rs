@@ -9,7 +9,8 @@ use rustyline::hint::{Hinter, HistoryHinter};
 use rustyline::validate::{self, MatchingBracketValidator, ValidationResult, Validator};
 use rustyline::{Context, Editor, Helper};
 use std::borrow::Cow::{self, Borrowed, Owned};
-use vrl::{diagnostic::Formatter, state, Runtime, Target, Value};
+use std::collections::VecDeque;
+use vrl::{diagnostic::Formatter, state, Program, Runtime, Target, Value};
 
 // Create a list of all possible error values for potential docs lookup
 lazy_static! {
@@ -23,11 +24,15 @@ lazy_static! {
     .collect();
 }
 
+/// The number of lines the `undo` command can revert.
+const UNDO_DEPTH: usize = 100;
+
 const DOCS_URL: &str = "https://vector.dev/docs/reference/vrl";
 const ERRORS_URL_ROOT: &str = "https://errors.vrl.dev";
 const RESERVED_TERMS: &[&str] = &[
     "next",
     "prev",
+    "undo",
     "exit",
     "quit",
     "help",
@@ -37,13 +42,106 @@ const RESERVED_TERMS: &[&str] = &[
     "help docs",
 ];
 
-pub(crate) fn run(mut objects: Vec<Value>) {
-    let mut index = 0;
+/// The state from before a line was resolved, which the `undo` command
+/// restores.
+struct Checkpoint {
+    compiler: state::CompilerCheckpoint,
+    runtime: state::RuntimeCheckpoint,
+    index: usize,
+    object: Option<Value>,
+}
+
+/// The objects and the state the lines of a session are resolved against.
+struct Session {
+    objects: Vec<Value>,
+    index: usize,
+    checkpoints: VecDeque<Checkpoint>,
+    compiler_state: state::Compiler,
+    runtime: Runtime,
+}
+
+impl Session {
+    fn new(objects: Vec<Value>) -> Self {
+        Self {
+            objects,
+            index: 0,
+            checkpoints: VecDeque::with_capacity(UNDO_DEPTH),
+            compiler_state: state::Compiler::default(),
+            runtime: Runtime::new(state::Runtime::default()),
+        }
+    }
+
+    /// Resolves the line against the current object, saving a checkpoint
+    /// to undo it if it compiles.
+    fn resolve_line(&mut self, line: &str) -> String {
+        let checkpoint = Checkpoint {
+            compiler: self.compiler_state.checkpoint(),
+            runtime: self.runtime.checkpoint(),
+            index: self.index,
+            object: self.objects.get(self.index).cloned(),
+        };
+
+        let program = match self.compile(line) {
+            Ok(program) => program,
+            Err(diagnostics) => return diagnostics,
+        };
+
+        // Only the last lines are kept, as every checkpoint holds a copy of
+        // the object.
+        if self.checkpoints.len() == UNDO_DEPTH {
+            self.checkpoints.pop_front();
+        }
+        self.checkpoints.push_back(checkpoint);
+
+        resolve(
+            self.objects.get_mut(self.index),
+            &mut self.runtime,
+            &program,
+        )
+    }
+
+    /// Shows the current object.
+    fn show(&mut self) -> String {
+        match self.compile(".") {
+            Ok(program) => resolve(
+                self.objects.get_mut(self.index),
+                &mut self.runtime,
+                &program,
+            ),
+            Err(diagnostics) => diagnostics,
+        }
+    }
+
+    fn compile(&mut self, source: &str) -> Result<Program, String> {
+        vrl::compile_with_state(source, &stdlib::all(), &mut self.compiler_state)
+            .map_err(|diagnostics| Formatter::new(source, diagnostics).colored().to_string())
+    }
+
+    /// Reverts the last line that compiled, returning whether there was one.
+    fn undo(&mut self) -> bool {
+        let checkpoint = match self.checkpoints.pop_back() {
+            Some(checkpoint) => checkpoint,
+            None => return false,
+        };
+
+        self.compiler_state.rollback_to(checkpoint.compiler);
+        self.runtime.rollback_to(checkpoint.runtime);
+
+        if let (Some(object), Some(previous)) =
+            (self.objects.get_mut(checkpoint.index), checkpoint.object)
+        {
+            *object = previous;
+        }
+
+        true
+    }
+}
+
+pub(crate) fn run(objects: Vec<Value>) {
+    let mut session = Session::new(objects);
     let func_docs_regex = Regex::new(r"^help\sdocs\s(\w{1,})$").unwrap();
     let error_docs_regex = Regex::new(r"^help\serror\s(\w{1,})$").unwrap();
 
-    let mut compiler_state = state::Compiler::default();
-    let mut rt = Runtime::new(state::Runtime::default());
     let mut rl = Editor::<Repl>::new();
     rl.set_helper(Some(Repl::new()));
 
@@ -65,40 +163,44 @@ pub(crate) fn run(mut objects: Vec<Value>) {
             Ok(line) => {
                 rl.add_history_entry(line);
 
-                let command = match line {
+                let objects = &mut session.objects;
+                let value = match line {
                     "next" => {
                         // allow adding one new object at a time
-                        if index < objects.len() && objects.last() != Some(&Value::Null) {
-                            index = index.saturating_add(1);
+                        if session.index < objects.len() && objects.last() != Some(&Value::Null) {
+                            session.index = session.index.saturating_add(1);
                         }
 
                         // add new object
-                        if index == objects.len() {
+                        if session.index == objects.len() {
                             objects.push(Value::Null)
                         }
 
-                        "."
+                        session.show()
                     }
                     "prev" => {
-                        index = index.saturating_sub(1);
+                        session.index = session.index.saturating_sub(1);
 
                         // remove empty last object
                         if objects.last() == Some(&Value::Null) {
                             let _ = objects.pop();
                         }
 
-                        "."
+                        session.show()
+                    }
+                    "undo" => {
+                        if session.undo() {
+                            println!("undid the last line\n");
+                        } else {
+                            println!("nothing to undo\n");
+                        }
+
+                        continue;
                     }
                     "" => continue,
-                    _ => line,
+                    line => session.resolve_line(line),
                 };
 
-                let value = resolve(
-                    objects.get_mut(index),
-                    &mut rt,
-                    command,
-                    &mut compiler_state,
-                );
                 println!("{}\n", value);
             }
             Err(ReadlineError::Interrupted) => break,
@@ -111,23 +213,13 @@ pub(crate) fn run(mut objects: Vec<Value>) {
     }
 }
 
-fn resolve(
-    object: Option<&mut impl Target>,
-    runtime: &mut Runtime,
-    program: &str,
-    state: &mut state::Compiler,
-) -> String {
+fn resolve(object: Option<&mut impl Target>, runtime: &mut Runtime, program: &Program) -> String {
     let object = match object {
         None => return Value::Null.to_string(),
         Some(object) => object,
     };
 
-    let program = match vrl::compile_with_state(program, &stdlib::all(), state) {
-        Ok(program) => program,
-        Err(diagnostics) => return Formatter::new(program, diagnostics).colored().to_string(),
-    };
-
-    match runtime.resolve(object, &program) {
+    match runtime.resolve(object, program) {
         Ok(value) => value.to_string(),
         Err(err) => err.to_string(),
     }
@@ -325,6 +417,7 @@ const HELP_TEXT: &str = indoc! {r#"
       help error <code>  Navigate to the docs for a specific error code
       next               Load the next object or create a new one
       prev               Load the previous object
+      undo               Revert the changes made by the last resolved line (up to 100 lines)
       exit               Terminate the program
 "#};
 
@@ -359,9 +452,76 @@ const BANNER_TEXT: &str = indoc! {r#"
     >   help              Learn more about VRL
     >   next              Load the next object or create a new one
     >   prev              Load the previous object
+    >   undo              Revert the last line
     >   exit              Terminate the program
     >
     > Any other value is resolved to a VRL expression.
     >
     > Try it out now by typing `.` and hitting [enter] to see the result.
 "#};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use vrl::value;
+
+    fn variables(session: &Session) -> Vec<&str> {
+        let mut variables = session
+            .compiler_state
+            .variables()
+            .map(|(ident, _)| ident)
+            .collect::<Vec<_>>();
+        variables.sort_unstable();
+        variables
+    }
+
+    #[test]
+    fn undo_line() {
+        let mut session = Session::new(vec![value!({})]);
+
+        assert_eq!(session.resolve_line("x = 1"), "1");
+        assert_eq!(session.resolve_line(".foo = x"), "1");
+        assert_eq!(session.resolve_line("y = 2"), "2");
+        assert_eq!(variables(&session), vec!["x", "y"]);
+
+        assert!(session.undo());
+        assert_eq!(variables(&session), vec!["x"]);
+        assert_eq!(session.resolve_line("y"), "null");
+        assert_eq!(session.show(), r#"{ "foo": 1 }"#);
+
+        // Undoes the `y` query, and the assignment to `.foo`.
+        assert!(session.undo());
+        assert!(session.undo());
+        assert_eq!(variables(&session), vec!["x"]);
+        assert_eq!(session.show(), "{  }");
+    }
+
+    #[test]
+    fn undo_skips_lines_that_fail_to_compile() {
+        let mut session = Session::new(vec![value!({})]);
+
+        assert_eq!(session.resolve_line("x = 1"), "1");
+        assert!(session.resolve_line("y = x +").contains("error"));
+
+        assert!(session.undo());
+        assert!(variables(&session).is_empty());
+        assert_eq!(session.resolve_line("x"), "null");
+        assert!(session.undo());
+        assert!(!session.undo());
+    }
+
+    #[test]
+    fn undo_depth() {
+        let mut session = Session::new(vec![value!({})]);
+
+        for i in 0..=UNDO_DEPTH {
+            session.resolve_line(&format!("x = {}", i));
+        }
+
+        for _ in 0..UNDO_DEPTH {
+            assert!(session.undo());
+        }
+        assert!(!session.undo());
+        assert_eq!(session.resolve_line("x"), "0");
+    }
+}
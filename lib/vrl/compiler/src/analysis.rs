@@ -0,0 +1,145 @@
+use crate::{Program, Span, TypeDef};
+use diagnostic::DiagnosticList;
+
+/// The outcome of compiling a program for tooling such as editors, which
+/// need to know more about the program than whether it compiles.
+///
+/// See [`analyze`](crate::analyze).
+#[derive(Debug)]
+pub struct Analysis {
+    /// The compiled program, or `None` if any of the diagnostics is an error.
+    pub program: Option<Program>,
+
+    /// The errors and warnings found while compiling.
+    pub diagnostics: DiagnosticList,
+
+    /// The annotations of the compiled expressions, in the order in which
+    /// their compilation finished, meaning nested expressions come before the
+    /// expressions containing them.
+    pub annotations: Vec<Annotation>,
+}
+
+impl Analysis {
+    /// Returns the innermost annotation spanning the given byte offset of the
+    /// source, if any.
+    pub fn annotation_at(&self, offset: usize) -> Option<&Annotation> {
+        self.annotations
+            .iter()
+            .filter(|annotation| annotation.contains(offset))
+            .min_by_key(|annotation| annotation.span.end() - annotation.span.start())
+    }
+
+    /// Returns the annotations of the top-level expressions of the program,
+    /// in order.
+    pub fn top_level(&self) -> Vec<&Annotation> {
+        let mut top_level = vec![];
+        let mut start = usize::MAX;
+
+        // Top-level expressions don't overlap, and each of them is annotated
+        // after the expressions they contain, so going backwards, the next
+        // top-level expression is the next annotation ending before the
+        // previous one starts.
+        for annotation in self.annotations.iter().rev() {
+            if annotation.span.end() <= start {
+                start = annotation.span.start();
+                top_level.push(annotation);
+            }
+        }
+
+        top_level.reverse();
+        top_level
+    }
+}
+
+/// An expression of the program, and the type definition the compiler
+/// inferred for it at that point of the program.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Annotation {
+    pub span: Span,
+    pub subject: Subject,
+    pub type_def: TypeDef,
+}
+
+impl Annotation {
+    /// Returns `true` if the annotated expression spans the given byte offset.
+    pub fn contains(&self, offset: usize) -> bool {
+        self.span.start() <= offset && offset < self.span.end()
+    }
+}
+
+/// What an [`Annotation`] is about.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Subject {
+    /// Any expression.
+    Expression,
+
+    /// A variable, which is also annotated as an expression.
+    Variable(String),
+
+    /// The identifier of a function call, annotated with the type definition
+    /// of the call.
+    FunctionCall {
+        ident: String,
+
+        /// Whether the call aborts the program on error, as in `foo!()`.
+        abort_on_error: bool,
+    },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{analyze, State};
+
+    fn analysis(source: &str) -> Analysis {
+        let ast = ::parser::parse(source).unwrap();
+
+        analyze(ast, &[], &mut State::default())
+    }
+
+    #[test]
+    fn annotation_at_variable() {
+        let analysis = analysis("foo = 1\nfoo");
+        let annotation = analysis.annotation_at(8).unwrap();
+
+        assert_eq!(annotation.subject, Subject::Variable("foo".to_owned()));
+        assert_eq!(annotation.type_def, TypeDef::new().integer());
+    }
+
+    #[test]
+    fn annotation_at_innermost_expression() {
+        let analysis = analysis(r#"[1, "foo"]"#);
+
+        assert_eq!(
+            analysis.annotation_at(4).unwrap().type_def,
+            TypeDef::new().bytes()
+        );
+        assert!(analysis.annotation_at(0).unwrap().type_def.is_array());
+        assert!(analysis.annotation_at(10).is_none());
+    }
+
+    #[test]
+    fn top_level() {
+        let analysis = analysis("foo = [1, 2]\nfoo\nif true { foo } else { 1 }");
+        let spans = analysis
+            .top_level()
+            .iter()
+            .map(|annotation| (annotation.span.start(), annotation.span.end()))
+            .collect::<Vec<_>>();
+
+        assert_eq!(spans, vec![(0, 12), (13, 16), (17, 43)]);
+        assert!(analysis
+            .top_level()
+            .iter()
+            .all(|annotation| annotation.subject == Subject::Expression));
+    }
+
+    #[test]
+    fn annotations_are_collected_despite_errors() {
+        let analysis = analysis("foo = 1\nbar()");
+
+        assert!(analysis.program.is_none());
+        assert!(analysis.diagnostics.has_errors());
+        assert!(analysis.annotation_at(0).is_some());
+    }
+}
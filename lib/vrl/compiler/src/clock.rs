@@ -0,0 +1,33 @@
+use chrono::{DateTime, Utc};
+use std::fmt;
+
+/// The source of the current time at runtime.
+///
+/// Functions read the time from the clock of the [runtime
+/// state](crate::state::Runtime), instead of from the system, so that the
+/// time can be controlled by the embedder of the runtime, for example to get
+/// deterministic results in tests.
+pub trait Clock: Send + Sync + fmt::Debug {
+    /// Returns the current time.
+    fn now(&self) -> DateTime<Utc>;
+}
+
+/// A [`Clock`] reading the system time, used by default.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+}
+
+/// A [`Clock`] that always returns the same time.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FixedClock(pub DateTime<Utc>);
+
+impl Clock for FixedClock {
+    fn now(&self) -> DateTime<Utc> {
+        self.0
+    }
+}
@@ -1,10 +1,14 @@
+use crate::analysis::{Annotation, Subject};
 use crate::expression::*;
-use crate::{Function, Program, State, Value};
+use crate::{Context, Cost, Function, Path, Program, Span, State, Value};
 use chrono::{TimeZone, Utc};
 use diagnostic::DiagnosticError;
 use ordered_float::NotNan;
 use parser::ast::{self, AssignmentOp, Node};
+use std::collections::{BTreeSet, HashMap, HashSet};
 use std::convert::TryFrom;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
 
 pub type Errors = Vec<Box<dyn DiagnosticError>>;
 
@@ -12,7 +16,30 @@ pub struct Compiler<'a> {
     fns: &'a [Box<dyn Function>],
     state: &'a mut State,
     errors: Errors,
+    warnings: Errors,
+    annotations: Option<Vec<Annotation>>,
     fallible: bool,
+    cost: Cost,
+    functions: BTreeSet<String>,
+    paths_read: BTreeSet<Path>,
+    paths_written: BTreeSet<Path>,
+    dynamic_paths: bool,
+
+    /// The variables assigned by the program, with the span of their first
+    /// assignment, and the variables it reads, to warn about unused ones.
+    assigned_variables: HashMap<ast::Ident, Span>,
+    read_variables: HashSet<ast::Ident>,
+
+    /// The slot of the value shared by the identical pure function calls,
+    /// by call, and whether the call is repeated.
+    calls: HashMap<String, (usize, Arc<AtomicBool>)>,
+
+    /// The depth of the expression being compiled.
+    depth: usize,
+
+    /// The index of the error of the first expression nested too deep, in the
+    /// statement being compiled.
+    nesting_error: Option<usize>,
 }
 
 impl<'a> Compiler<'a> {
@@ -21,24 +48,72 @@ impl<'a> Compiler<'a> {
             fns,
             state,
             errors: vec![],
+            warnings: vec![],
+            annotations: None,
             fallible: false,
+            cost: Cost::default(),
+            functions: BTreeSet::default(),
+            paths_read: BTreeSet::default(),
+            paths_written: BTreeSet::default(),
+            dynamic_paths: false,
+            assigned_variables: HashMap::default(),
+            read_variables: HashSet::default(),
+            calls: HashMap::default(),
+            depth: 0,
+            nesting_error: None,
         }
     }
 
-    pub(super) fn compile(mut self, ast: parser::Program) -> Result<Program, Errors> {
+    pub(super) fn compile(self, ast: parser::Program) -> Result<Program, Errors> {
+        self.compile_with_warnings(ast).0
+    }
+
+    /// Similar to [`Compiler::compile`], but also returns the warnings found
+    /// while compiling, whether or not compilation succeeded.
+    pub(super) fn compile_with_warnings(
+        mut self,
+        ast: parser::Program,
+    ) -> (Result<Program, Errors>, Errors) {
+        let result = self.compile_program(ast);
+
+        (result, self.warnings)
+    }
+
+    /// Similar to [`Compiler::compile_with_warnings`], but also annotates each
+    /// compiled expression with its type definition.
+    pub(super) fn compile_annotated(
+        mut self,
+        ast: parser::Program,
+    ) -> (Result<Program, Errors>, Errors, Vec<Annotation>) {
+        self.annotations = Some(vec![]);
+        let result = self.compile_program(ast);
+
+        (result, self.warnings, self.annotations.unwrap_or_default())
+    }
+
+    fn compile_program(&mut self, ast: parser::Program) -> Result<Program, Errors> {
         let expressions = self
             .compile_root_exprs(ast)
             .into_iter()
             .map(|expr| Box::new(expr) as _)
             .collect();
 
+        self.check_unused_variables();
+
         if !self.errors.is_empty() {
-            return Err(self.errors);
+            return Err(std::mem::take(&mut self.errors));
         }
 
         Ok(Program {
             expressions,
             fallible: self.fallible,
+            cost: self.cost,
+            functions: std::mem::take(&mut self.functions),
+            paths_read: std::mem::take(&mut self.paths_read),
+            paths_written: std::mem::take(&mut self.paths_written),
+            dynamic_paths: self.dynamic_paths,
+            input_schema: None,
+            output_schema: None,
         })
     }
 
@@ -48,9 +123,13 @@ impl<'a> Compiler<'a> {
     ) -> Vec<Expr> {
         use ast::RootExpr::*;
 
+        let nodes = nodes.into_iter().collect::<Vec<_>>();
+        let last = nodes.len().saturating_sub(1);
+
         nodes
             .into_iter()
-            .filter_map(|node| {
+            .enumerate()
+            .filter_map(|(i, node)| {
                 let span = node.span();
 
                 match node.into_inner() {
@@ -62,6 +141,11 @@ impl<'a> Compiler<'a> {
                             self.errors.push(Box::new(err));
                         }
 
+                        // Only the value of the last expression is returned.
+                        if i != last {
+                            self.check_discarded(&expr, span);
+                        }
+
                         Some(expr)
                     }
                     Error(err) => {
@@ -73,6 +157,37 @@ impl<'a> Compiler<'a> {
             .collect()
     }
 
+    /// Warns about an expression whose result is discarded, if resolving it
+    /// has no side effects, as it's then dead code.
+    fn check_discarded(&mut self, expr: &Expr, span: Span) {
+        let warning = match expr {
+            Expr::Literal(_) => Warning::UnusedLiteral { span },
+            Expr::Variable(_) => Warning::UnusedExpression { span },
+            Expr::Query(query) if query.expression_target().is_none() => {
+                Warning::UnusedExpression { span }
+            }
+            _ => return,
+        };
+
+        self.warnings.push(Box::new(warning));
+    }
+
+    /// Warns about the variables assigned but never read, once the whole
+    /// program is compiled.
+    fn check_unused_variables(&mut self) {
+        let read_variables = &self.read_variables;
+        let mut unused = std::mem::take(&mut self.assigned_variables)
+            .into_iter()
+            .filter(|(ident, _)| !read_variables.contains(ident))
+            .collect::<Vec<_>>();
+        unused.sort_by_key(|(_, span)| *span);
+
+        for (ident, span) in unused {
+            let warning = Warning::UnusedVariable { ident, span };
+            self.warnings.push(Box::new(warning));
+        }
+    }
+
     fn compile_exprs(&mut self, nodes: impl IntoIterator<Item = Node<ast::Expr>>) -> Vec<Expr> {
         nodes
             .into_iter()
@@ -83,7 +198,22 @@ impl<'a> Compiler<'a> {
     fn compile_expr(&mut self, node: Node<ast::Expr>) -> Expr {
         use ast::Expr::*;
 
-        match node.into_inner() {
+        let span = node.span();
+
+        // The expressions nested in one too deep aren't compiled, so that
+        // only the outermost of them is reported.
+        let max_depth = self.state.max_depth();
+        if self.depth >= max_depth {
+            self.nesting_error.get_or_insert(self.errors.len());
+            self.errors
+                .push(Box::new(Error::NestingDepth { span, max_depth }));
+            return Noop.into();
+        }
+
+        self.cost.add_expression();
+        self.depth += 1;
+
+        let expr = match node.into_inner() {
             Literal(node) => self.compile_literal(node).into(),
             Container(node) => self.compile_container(node).into(),
             IfStatement(node) => self.compile_if_statement(node).into(),
@@ -93,12 +223,71 @@ impl<'a> Compiler<'a> {
             FunctionCall(node) => self.compile_function_call(node).into(),
             Variable(node) => self.compile_variable(node).into(),
             Unary(node) => self.compile_unary(node).into(),
+        };
+        self.depth -= 1;
+
+        // The expressions around the one nested too deep would report errors
+        // about the placeholder it's compiled to, which aren't reported either.
+        if self.depth == 0 {
+            if let Some(index) = self.nesting_error.take() {
+                self.errors.truncate(index + 1);
+            }
+        }
+
+        let expr = self.fold_constant(expr);
+
+        self.annotate(span, &expr, || Subject::Expression);
+        expr
+    }
+
+    /// Replaces an operation or a pure function call over only literals with
+    /// its value, so that it isn't resolved again for each event.
+    ///
+    /// Fallible expressions are kept, as are the ones failing to resolve (e.g.
+    /// dividing by zero), so that they still fail at runtime.
+    fn fold_constant(&mut self, expr: Expr) -> Expr {
+        let constant = match &expr {
+            Expr::Op(op) => op.is_constant(),
+            Expr::FunctionCall(call) => call.is_constant(),
+            _ => false,
+        };
+
+        if !constant
+            || !self.state.optimization().folds_constants()
+            || expr.type_def(self.state).is_fallible()
+        {
+            return expr;
+        }
+
+        let mut target = Value::Null;
+        let mut state = crate::state::Runtime::default();
+        let mut ctx = Context::new(&mut target, &mut state);
+
+        match expr.resolve(&mut ctx).ok().and_then(Literal::from_value) {
+            Some(literal) => literal.into(),
+            None => expr,
+        }
+    }
+
+    /// Records the type definition of the expression, if annotations were
+    /// requested.
+    fn annotate(&mut self, span: Span, expr: &dyn Expression, subject: impl FnOnce() -> Subject) {
+        if let Some(annotations) = &mut self.annotations {
+            annotations.push(Annotation {
+                span,
+                subject: subject(),
+                type_def: expr.type_def(self.state),
+            });
         }
     }
 
     fn compile_literal(&mut self, node: Node<ast::Literal>) -> Literal {
         use literal::ErrorVariant::*;
 
+        if let ast::Literal::Regex(_) = node.inner() {
+            self.cost.add_regex();
+        }
+
         Literal::try_from(node).unwrap_or_else(|err| {
             let value = match &err.variant {
                 #[allow(clippy::trivial_regex)]
@@ -132,7 +321,24 @@ impl<'a> Compiler<'a> {
     }
 
     fn compile_block(&mut self, node: Node<ast::Block>) -> Block {
-        let exprs = self.compile_exprs(node.into_inner().into_iter());
+        let nodes = node.into_inner().into_iter().collect::<Vec<_>>();
+        let last = nodes.len().saturating_sub(1);
+
+        let exprs = nodes
+            .into_iter()
+            .enumerate()
+            .map(|(i, node)| {
+                let span = node.span();
+                let expr = self.compile_expr(node);
+
+                // Only the value of the last expression of a block is used.
+                if i != last {
+                    self.check_discarded(&expr, span);
+                }
+
+                expr
+            })
+            .collect();
 
         Block::new(exprs)
     }
@@ -162,6 +368,8 @@ impl<'a> Compiler<'a> {
             alternative,
         } = node.into_inner();
 
+        self.cost.add_condition();
+
         let predicate = match self.compile_predicate(predicate) {
             Ok(v) => v,
             Err(err) => {
@@ -239,6 +447,7 @@ impl<'a> Compiler<'a> {
         let node = match assignment {
             Single { target, op, expr } => {
                 let span = expr.span();
+                self.record_assignment(&target);
 
                 match op {
                     AssignmentOp::Assign => {
@@ -255,6 +464,8 @@ impl<'a> Compiler<'a> {
             }
             Infallible { ok, err, op, expr } => {
                 let span = expr.span();
+                self.record_assignment(&ok);
+                self.record_assignment(&err);
 
                 match op {
                     AssignmentOp::Assign => {
@@ -290,11 +501,54 @@ impl<'a> Compiler<'a> {
         })
     }
 
+    /// Records the variable or the path of the target assigned to.
+    fn record_assignment(&mut self, target: &Node<ast::AssignmentTarget>) {
+        match target.inner() {
+            ast::AssignmentTarget::Internal(ident, _) => {
+                self.assigned_variables
+                    .entry(ident.clone())
+                    .or_insert_with(|| target.span());
+            }
+            ast::AssignmentTarget::External(path) => {
+                let path = path.clone().map(Into::into).unwrap_or_else(Path::root);
+                self.paths_written.insert(path);
+            }
+            ast::AssignmentTarget::Query(ast::Query { target, path })
+                if matches!(target.inner(), ast::QueryTarget::External) =>
+            {
+                self.paths_written.insert(path.inner().clone().into());
+            }
+            _ => {}
+        }
+    }
+
+    /// Records the path of the target passed to a function writing to it,
+    /// returning it along with whether the program read it before.
+    fn record_write(&mut self, argument: &ast::FunctionArgument) -> Option<(Path, bool)> {
+        match argument.expr.inner() {
+            ast::Expr::Query(query)
+                if matches!(query.target.inner(), ast::QueryTarget::External) =>
+            {
+                let path: Path = query.path.inner().clone().into();
+                self.paths_written.insert(path.clone());
+
+                let read = self.paths_read.contains(&path);
+                Some((path, read))
+            }
+            _ => None,
+        }
+    }
+
     fn compile_query(&mut self, node: Node<ast::Query>) -> Query {
         let ast::Query { target, path } = node.into_inner();
         let target = self.compile_query_target(target);
+        let path: Path = path.into_inner().into();
+
+        if let Target::External = target {
+            self.paths_read.insert(path.clone());
+        }
 
-        Query::new(target, path.into_inner().into())
+        Query::new(target, path)
     }
 
     fn compile_query_target(&mut self, node: Node<ast::QueryTarget>) -> query::Target {
@@ -321,21 +575,63 @@ impl<'a> Compiler<'a> {
 
     fn compile_function_call(&mut self, node: Node<ast::FunctionCall>) -> FunctionCall {
         let call_span = node.span();
+        let cache_key = self.cache_key(node.inner());
         let ast::FunctionCall {
             ident,
             abort_on_error,
             arguments,
         } = node.into_inner();
 
+        let writes_target = self
+            .fns
+            .iter()
+            .any(|f| f.identifier() == ident.inner().as_ref() && f.writes_target());
+
         let arguments = arguments
             .into_iter()
-            .map(|node| Node::new(node.span(), self.compile_function_argument(node)))
-            .collect();
+            .map(|node| {
+                let written = if writes_target {
+                    self.record_write(node.inner())
+                } else {
+                    None
+                };
+
+                let argument = Node::new(node.span(), self.compile_function_argument(node));
+
+                // The paths written by the function are only read if the rest
+                // of the program reads them.
+                if let Some((path, false)) = written {
+                    self.paths_read.remove(&path);
+                }
+
+                argument
+            })
+            .collect::<Vec<_>>();
+
+        let cache = cache_key.map(|key| {
+            let slot = self.calls.len();
+            let (slot, repeated) = self
+                .calls
+                .entry(key)
+                .and_modify(|(_, repeated)| repeated.store(true, Ordering::Relaxed))
+                .or_insert_with(|| (slot, Arc::default()));
+            let arguments = arguments
+                .iter()
+                .map(|argument| argument.inner().inner().clone())
+                .collect();
+
+            function_call::Cache::new(*slot, arguments, repeated.clone())
+        });
+
+        let ident_span = ident.span();
+        let function = ident.inner().clone();
 
         if abort_on_error {
             self.fallible = true;
         }
 
+        self.cost.add_function_call();
+
         FunctionCall::new(
             call_span,
             ident,
@@ -344,12 +640,70 @@ impl<'a> Compiler<'a> {
             self.fns,
             self.state,
         )
+        .map(|call| {
+            self.functions.insert(function.as_ref().to_owned());
+            if self
+                .fns
+                .iter()
+                .any(|f| f.identifier() == function.as_ref() && f.accesses_target())
+            {
+                self.dynamic_paths = true;
+            }
+
+            self.annotate(ident_span, &call, || Subject::FunctionCall {
+                ident: function.into_inner(),
+                abort_on_error,
+            });
+
+            match cache {
+                Some(cache) => call.with_cache(cache),
+                None => call,
+            }
+        })
         .unwrap_or_else(|err| {
             self.errors.push(Box::new(err));
             FunctionCall::noop()
         })
     }
 
+    /// The source of a call to a pure function, identifying the identical
+    /// calls of the program, if its arguments are cheap to resolve and free
+    /// of side effects, so that its value can be shared.
+    fn cache_key(&self, call: &ast::FunctionCall) -> Option<String> {
+        use ast::{Expr, QueryTarget};
+
+        if !self.state.optimization().shares_function_calls() {
+            return None;
+        }
+
+        let ident = call.ident.inner().as_ref();
+        if !self
+            .fns
+            .iter()
+            .any(|f| f.identifier() == ident && f.is_pure())
+        {
+            return None;
+        }
+
+        let cheap = call
+            .arguments
+            .iter()
+            .all(|argument| match argument.inner().expr.inner() {
+                Expr::Literal(_) | Expr::Variable(_) => true,
+                Expr::Query(query) => matches!(
+                    query.inner().target.inner(),
+                    QueryTarget::External | QueryTarget::Internal(_)
+                ),
+                _ => false,
+            });
+
+        if !cheap || call.arguments.is_empty() {
+            return None;
+        }
+
+        Some(call.to_string())
+    }
+
     fn compile_function_argument(&mut self, node: Node<ast::FunctionArgument>) -> FunctionArgument {
         let ast::FunctionArgument { ident, expr } = node.into_inner();
         let expr = Node::new(expr.span(), self.compile_expr(expr));
@@ -357,7 +711,14 @@ impl<'a> Compiler<'a> {
     }
 
     fn compile_variable(&mut self, node: Node<ast::Ident>) -> Variable {
-        Variable::new(node.into_inner(), &self.state)
+        let (span, ident) = node.take();
+        let variable = Variable::new(ident, &self.state);
+        self.read_variables.insert(variable.ident().clone());
+
+        self.annotate(span, &variable, || {
+            Subject::Variable(variable.ident().to_string())
+        });
+        variable
     }
 
     fn compile_unary(&mut self, node: Node<ast::Unary>) -> Unary {
@@ -0,0 +1,100 @@
+/// A static estimate of the cost of running a program, see
+/// [`Program::estimate_cost`](crate::Program::estimate_cost).
+///
+/// The `score` weighs each expression of the program by how expensive it
+/// usually is to resolve. It's only meaningful relative to the score of other
+/// programs: it doesn't depend on the events the program runs against, so
+/// both branches of conditions are counted, and the cost of each function is
+/// assumed to be the same.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Cost {
+    /// The weighted sum of the expressions of the program.
+    pub score: usize,
+
+    /// The number of expressions, including nested ones.
+    pub expressions: usize,
+
+    /// The number of function calls.
+    pub function_calls: usize,
+
+    /// The number of regular expression literals.
+    pub regexes: usize,
+
+    /// The number of `if` statements.
+    pub conditions: usize,
+}
+
+impl Cost {
+    const EXPRESSION: usize = 1;
+    const FUNCTION_CALL: usize = 10;
+    const REGEX: usize = 50;
+    const CONDITION: usize = 2;
+
+    pub(crate) fn add_expression(&mut self) {
+        self.expressions += 1;
+        self.score += Self::EXPRESSION;
+    }
+
+    pub(crate) fn add_function_call(&mut self) {
+        self.function_calls += 1;
+        self.score += Self::FUNCTION_CALL;
+    }
+
+    /// Regular expressions are compiled once, but matching them is usually
+    /// the most expensive part of a program.
+    pub(crate) fn add_regex(&mut self) {
+        self.regexes += 1;
+        self.score += Self::REGEX;
+    }
+
+    pub(crate) fn add_condition(&mut self) {
+        self.conditions += 1;
+        self.score += Self::CONDITION;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn estimate(source: &str) -> Cost {
+        let ast = ::parser::parse(source).unwrap();
+        crate::compile(ast, &[]).unwrap().estimate_cost()
+    }
+
+    #[test]
+    fn assignment() {
+        assert_eq!(
+            estimate(".foo = 1"),
+            Cost {
+                score: 2,
+                expressions: 2,
+                ..Default::default()
+            }
+        );
+    }
+
+    #[test]
+    fn regexes_in_conditions() {
+        let cost = estimate(r#"if .foo == 1 { r'\d+' } else { [r'\w+', "bar"] }"#);
+
+        assert_eq!(
+            cost,
+            Cost {
+                score: 8 + 2 + 2 * 50,
+                expressions: 8,
+                function_calls: 0,
+                regexes: 2,
+                conditions: 1,
+            }
+        );
+        assert!(cost.score > estimate(".foo = 1").score);
+    }
+
+    #[test]
+    fn deterministic() {
+        let source = r#"if .foo == 1 { bar = r'\d+' } else { .baz = { "qux": [1, 2] } }"#;
+
+        assert_eq!(estimate(source), estimate(source));
+    }
+}
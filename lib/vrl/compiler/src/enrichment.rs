@@ -0,0 +1,201 @@
+use crate::Value;
+use chrono::{DateTime, Duration, Utc};
+use std::collections::{BTreeMap, HashMap};
+
+/// A cache of the results of enrichment lookups, such as looking up an IP
+/// address in a database, or resolving a hostname.
+///
+/// A cache installed on the [runtime state](crate::state::Runtime) is shared by
+/// the programs the runtime resolves, so that a lookup repeated across a batch
+/// of events is only done once. Results are keyed by the function and its
+/// arguments, and the least recently used one is evicted when the cache is
+/// full. With a TTL, results older than it are looked up again, so that the
+/// results of services such as DNS don't go stale.
+#[derive(Debug, Clone)]
+pub struct EnrichmentCache {
+    size: usize,
+    ttl: Option<Duration>,
+    entries: HashMap<Key, Entry>,
+
+    /// The keys of the entries, by the last time they were used.
+    used: BTreeMap<u64, Key>,
+    ticks: u64,
+}
+
+/// The function, and the canonical representation of its arguments.
+type Key = (String, String);
+
+#[derive(Debug, Clone)]
+struct Entry {
+    value: Value,
+    inserted_at: DateTime<Utc>,
+    used: u64,
+}
+
+impl EnrichmentCache {
+    /// Creates a cache holding the results of at most `size` lookups.
+    pub fn new(size: usize) -> Self {
+        Self {
+            size,
+            ttl: None,
+            entries: HashMap::default(),
+            used: BTreeMap::default(),
+            ticks: 0,
+        }
+    }
+
+    /// Look up the results again once they're older than `ttl`.
+    pub fn with_ttl(mut self, ttl: std::time::Duration) -> Self {
+        self.ttl = Some(Duration::from_std(ttl).unwrap_or_else(|_| Duration::max_value()));
+        self
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// The result of the lookup of the function with the given arguments, if
+    /// it's cached and hasn't expired at `now`.
+    pub fn get(
+        &mut self,
+        function: &str,
+        arguments: &[Value],
+        now: DateTime<Utc>,
+    ) -> Option<Value> {
+        let key = key(function, arguments);
+        let entry = self.entries.get_mut(&key)?;
+
+        if let Some(ttl) = self.ttl {
+            if now.signed_duration_since(entry.inserted_at) >= ttl {
+                self.used.remove(&entry.used);
+                self.entries.remove(&key);
+                return None;
+            }
+        }
+
+        self.ticks += 1;
+        self.used.remove(&entry.used);
+        self.used.insert(self.ticks, key);
+        entry.used = self.ticks;
+
+        Some(entry.value.clone())
+    }
+
+    /// Caches the result of the lookup of the function with the given
+    /// arguments, looked up at `now`, evicting the least recently used result
+    /// if the cache is full.
+    pub fn insert(
+        &mut self,
+        function: &str,
+        arguments: &[Value],
+        value: Value,
+        now: DateTime<Utc>,
+    ) {
+        if self.size == 0 {
+            return;
+        }
+
+        let key = key(function, arguments);
+        if let Some(entry) = self.entries.remove(&key) {
+            self.used.remove(&entry.used);
+        }
+
+        if self.entries.len() >= self.size {
+            let oldest = self.used.keys().next().copied();
+            if let Some(key) = oldest.and_then(|used| self.used.remove(&used)) {
+                self.entries.remove(&key);
+            }
+        }
+
+        self.ticks += 1;
+        self.used.insert(self.ticks, key.clone());
+        self.entries.insert(
+            key,
+            Entry {
+                value,
+                inserted_at: now,
+                used: self.ticks,
+            },
+        );
+    }
+}
+
+fn key(function: &str, arguments: &[Value]) -> Key {
+    let arguments = Value::Array(arguments.to_vec()).canonicalize(false);
+
+    (function.to_owned(), arguments)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn evicts_least_recently_used() {
+        let now = Utc.timestamp(0, 0);
+        let mut cache = EnrichmentCache::new(2);
+
+        cache.insert("lookup", &["a".into()], 1.into(), now);
+        cache.insert("lookup", &["b".into()], 2.into(), now);
+        assert_eq!(cache.get("lookup", &["a".into()], now), Some(1.into()));
+
+        // "b" is evicted, as "a" was used since it was cached.
+        cache.insert("lookup", &["c".into()], 3.into(), now);
+        assert_eq!(cache.len(), 2);
+        assert_eq!(cache.get("lookup", &["a".into()], now), Some(1.into()));
+        assert_eq!(cache.get("lookup", &["b".into()], now), None);
+        assert_eq!(cache.get("lookup", &["c".into()], now), Some(3.into()));
+    }
+
+    #[test]
+    fn keyed_by_function_and_arguments() {
+        let now = Utc.timestamp(0, 0);
+        let mut cache = EnrichmentCache::new(10);
+
+        cache.insert("lookup", &[1.into()], "integer".into(), now);
+        cache.insert("lookup", &[1.0.into()], "float".into(), now);
+        cache.insert("resolve", &[1.into()], "other".into(), now);
+
+        assert_eq!(
+            cache.get("lookup", &[1.into()], now),
+            Some("integer".into())
+        );
+        assert_eq!(
+            cache.get("lookup", &[1.0.into()], now),
+            Some("float".into())
+        );
+        assert_eq!(cache.get("resolve", &[1.into()], now), Some("other".into()));
+        assert_eq!(cache.get("lookup", &[1.into(), 2.into()], now), None);
+    }
+
+    #[test]
+    fn expires_after_ttl() {
+        let now = Utc.timestamp(0, 0);
+        let mut cache = EnrichmentCache::new(10).with_ttl(std::time::Duration::from_secs(60));
+
+        cache.insert("lookup", &["a".into()], 1.into(), now);
+        assert_eq!(
+            cache.get("lookup", &["a".into()], now + Duration::seconds(59)),
+            Some(1.into())
+        );
+        assert_eq!(
+            cache.get("lookup", &["a".into()], now + Duration::seconds(60)),
+            None
+        );
+        assert!(cache.is_empty());
+    }
+
+    #[test]
+    fn empty_cache_holds_nothing() {
+        let now = Utc.timestamp(0, 0);
+        let mut cache = EnrichmentCache::new(0);
+
+        cache.insert("lookup", &["a".into()], 1.into(), now);
+        assert_eq!(cache.get("lookup", &["a".into()], now), None);
+    }
+}
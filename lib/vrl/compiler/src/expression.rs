@@ -1,5 +1,5 @@
 use crate::{Context, Span, State, TypeDef, Value};
-use diagnostic::{DiagnosticError, Label, Note};
+use diagnostic::{DiagnosticError, Label, Note, Severity};
 use dyn_clone::{clone_trait_object, DynClone};
 use std::fmt;
 
@@ -56,6 +56,15 @@ pub trait Expression: Send + Sync + fmt::Debug + DynClone {
     /// This method is executed at compile-time.
     fn type_def(&self, state: &crate::State) -> TypeDef;
 
+    /// Resolve an expression to a value at compile-time, if possible.
+    ///
+    /// This returns `Some` for literals, and for arrays and objects made up
+    /// entirely of literals, allowing functions to validate or pre-compute
+    /// their arguments once, instead of on every resolution.
+    fn as_value(&self) -> Option<Value> {
+        None
+    }
+
     /// Format the expression into a consistent style.
     ///
     /// This defaults to not formatting, so that function implementations don't
@@ -141,6 +150,16 @@ impl Expression for Expr {
             Unary(v) => v.type_def(state),
         }
     }
+
+    fn as_value(&self) -> Option<Value> {
+        use Expr::*;
+
+        match self {
+            Literal(v) => v.as_value(),
+            Container(v) => v.as_value(),
+            _ => None,
+        }
+    }
 }
 
 impl fmt::Display for Expr {
@@ -230,6 +249,9 @@ impl From<Unary> for Expr {
 pub enum Error {
     #[error("unhandled error")]
     Fallible { span: Span },
+
+    #[error("expression nested too deeply")]
+    NestingDepth { span: Span, max_depth: usize },
 }
 
 impl DiagnosticError for Error {
@@ -238,6 +260,7 @@ impl DiagnosticError for Error {
 
         match self {
             Fallible { .. } => 100,
+            NestingDepth { .. } => 112,
         }
     }
 
@@ -249,6 +272,16 @@ impl DiagnosticError for Error {
                 Label::primary("expression can result in runtime error", span),
                 Label::context("handle the error case to ensure runtime success", span),
             ],
+            NestingDepth { span, max_depth } => vec![
+                Label::primary(
+                    format!(
+                        "this expression is nested more than {} levels deep",
+                        max_depth
+                    ),
+                    span,
+                ),
+                Label::context("assign parts of it to variables instead", span),
+            ],
         }
     }
 
@@ -257,6 +290,65 @@ impl DiagnosticError for Error {
 
         match self {
             Fallible { .. } => vec![Note::SeeErrorDocs],
+            NestingDepth { .. } => vec![],
+        }
+    }
+}
+
+// -----------------------------------------------------------------------------
+
+#[derive(thiserror::Error, Debug)]
+pub enum Warning {
+    #[error("unused literal")]
+    UnusedLiteral { span: Span },
+
+    #[error("unused expression")]
+    UnusedExpression { span: Span },
+
+    #[error("unused variable")]
+    UnusedVariable {
+        ident: crate::parser::Ident,
+        span: Span,
+    },
+}
+
+impl DiagnosticError for Warning {
+    fn code(&self) -> usize {
+        use Warning::*;
+
+        match self {
+            UnusedLiteral { .. } => 670,
+            UnusedExpression { .. } => 671,
+            UnusedVariable { .. } => 672,
+        }
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn labels(&self) -> Vec<Label> {
+        use Warning::*;
+
+        match self {
+            UnusedLiteral { span } => vec![
+                Label::primary("this literal has no effect", span),
+                Label::context(
+                    "only the last expression of a program or block is used as its result",
+                    span,
+                ),
+            ],
+            UnusedExpression { span } => vec![
+                Label::primary("this expression has no effect", span),
+                Label::context(
+                    "only the last expression of a program or block is used as its result",
+                    span,
+                ),
+            ],
+            UnusedVariable { ident, span } => vec![
+                Label::primary(format!("variable \"{}\" is never read", ident), span),
+                Label::context("assign to \"_\" to discard the value instead", span),
+            ],
         }
     }
 }
@@ -265,9 +357,16 @@ impl DiagnosticError for Error {
 
 #[derive(Debug, Default, PartialEq)]
 pub struct ExpressionError {
+    /// The stable code identifying the class of the error, or `0` if it has
+    /// none. Errors without a code returned by functions are given `705`.
+    pub code: usize,
     pub message: String,
     pub labels: Vec<Label>,
     pub notes: Vec<Note>,
+
+    /// Optional structured data attached to the error, to be inspected by the
+    /// embedder of the runtime (e.g. for dead-letter routing).
+    pub payload: Option<Value>,
 }
 
 impl std::fmt::Display for ExpressionError {
@@ -284,7 +383,7 @@ impl std::error::Error for ExpressionError {
 
 impl DiagnosticError for ExpressionError {
     fn code(&self) -> usize {
-        0
+        self.code
     }
 
     fn message(&self) -> String {
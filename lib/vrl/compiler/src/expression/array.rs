@@ -35,6 +35,14 @@ impl Expression for Array {
 
         TypeDef::new().array(type_defs).with_fallibility(fallible)
     }
+
+    fn as_value(&self) -> Option<Value> {
+        self.inner
+            .iter()
+            .map(Expression::as_value)
+            .collect::<Option<Vec<_>>>()
+            .map(Value::Array)
+    }
 }
 
 impl fmt::Display for Array {
@@ -56,9 +64,32 @@ impl From<Vec<Expr>> for Array {
     }
 }
 
+impl From<Array> for Vec<Expr> {
+    fn from(array: Array) -> Self {
+        array.inner
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::{expr, map, test_type_def, value::Kind, TypeDef};
+    use super::*;
+    use crate::expression::{Literal, Query, Target};
+    use crate::{expr, map, test_type_def, value, value::Kind, Path, TypeDef};
+    use std::str::FromStr;
+
+    #[test]
+    fn as_value() {
+        assert_eq!(
+            expr!([1, ["foo"], { "bar": null }]).as_value(),
+            Some(value!([1, ["foo"], { "bar": null }]))
+        );
+
+        let array = Array::new(vec![
+            Literal::from(1).into(),
+            Query::new(Target::External, Path::from_str(".foo").unwrap()).into(),
+        ]);
+        assert_eq!(array.as_value(), None);
+    }
 
     test_type_def![
         empty_array {
@@ -1,4 +1,4 @@
-use crate::expression::{Expr, Literal, Resolved};
+use crate::expression::{Expr, ExpressionError, Literal, Resolved};
 use crate::parser::{
     ast::{self, Ident},
     Node,
@@ -245,9 +245,41 @@ impl Target {
         }
     }
 
-    fn insert(&self, value: Value, ctx: &mut Context) {
+    /// Fails if the value would be nested deeper than the runtime allows once
+    /// assigned, see [`Runtime::with_max_value_depth`](crate::state::Runtime::with_max_value_depth).
+    fn check_depth(&self, value: &Value, ctx: &Context) -> Result<(), ExpressionError> {
         use Target::*;
 
+        let max_depth = match ctx.state().max_value_depth() {
+            Some(max_depth) => max_depth,
+            None => return Ok(()),
+        };
+
+        let path_depth = match self {
+            Noop => return Ok(()),
+            Internal(_, path) | External(path) => {
+                path.as_ref().map_or(0, |path| path.segments().len())
+            }
+        };
+
+        match max_depth.checked_sub(path_depth) {
+            Some(depth) if !value.exceeds_depth(depth) => Ok(()),
+            _ => Err(ExpressionError {
+                code: 704,
+                message: format!(
+                    "can't assign a value nested more than {} levels deep",
+                    max_depth
+                ),
+                ..Default::default()
+            }),
+        }
+    }
+
+    fn insert(&self, value: Value, ctx: &mut Context) -> Result<(), ExpressionError> {
+        use Target::*;
+
+        self.check_depth(&value, ctx)?;
+
         match self {
             Noop => {}
             Internal(ident, path) => {
@@ -255,7 +287,10 @@ impl Target {
                 // without any path appended and return early.
                 let path = match path {
                     Some(path) => path,
-                    None => return ctx.state_mut().insert_variable(ident.clone(), value),
+                    None => {
+                        ctx.state_mut().insert_variable(ident.clone(), value);
+                        return Ok(());
+                    }
                 };
 
                 // Update existing variable using the provided path, or create a
@@ -274,6 +309,8 @@ impl Target {
                     .insert(path.as_ref().unwrap_or(&Path::root()), value);
             }
         }
+
+        Ok(())
     }
 }
 
@@ -370,7 +407,7 @@ where
         let value = match self {
             Single { target, expr } => {
                 let value = expr.resolve(ctx)?;
-                target.insert(value.clone(), ctx);
+                target.insert(value.clone(), ctx)?;
                 value
             }
             Infallible {
@@ -380,14 +417,14 @@ where
                 default,
             } => match expr.resolve(ctx) {
                 Ok(value) => {
-                    ok.insert(value.clone(), ctx);
-                    err.insert(Value::Null, ctx);
+                    ok.insert(value.clone(), ctx)?;
+                    err.insert(Value::Null, ctx)?;
                     value
                 }
                 Err(error) => {
-                    ok.insert(default.clone(), ctx);
+                    ok.insert(default.clone(), ctx)?;
                     let value = Value::from(error.to_string());
-                    err.insert(value.clone(), ctx);
+                    err.insert(value.clone(), ctx)?;
                     value
                 }
             },
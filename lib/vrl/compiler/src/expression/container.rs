@@ -1,5 +1,5 @@
 use crate::expression::{Array, Block, Group, Object, Resolved};
-use crate::{Context, Expression, State, TypeDef};
+use crate::{Context, Expression, State, TypeDef, Value};
 use std::fmt;
 
 #[derive(Debug, Clone, PartialEq)]
@@ -43,6 +43,17 @@ impl Expression for Container {
             Object(v) => v.type_def(state),
         }
     }
+
+    fn as_value(&self) -> Option<Value> {
+        use Variant::*;
+
+        match &self.variant {
+            Group(v) => v.as_value(),
+            Array(v) => v.as_value(),
+            Object(v) => v.as_value(),
+            Block(_) => None,
+        }
+    }
 }
 
 impl fmt::Display for Container {
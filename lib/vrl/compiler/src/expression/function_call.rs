@@ -1,9 +1,12 @@
-use crate::expression::{FunctionArgument, Noop};
+use crate::expression::{Expr, FunctionArgument, Noop};
 use crate::function::{ArgumentList, Parameter};
 use crate::parser::{Ident, Node};
 use crate::{value::Kind, Context, Expression, Function, Resolved, Span, State, TypeDef};
 use diagnostic::{DiagnosticError, Label, Note, Urls};
 use std::fmt;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::Instant;
 use tracing::{span, Level};
 
 #[derive(Clone)]
@@ -12,6 +15,12 @@ pub struct FunctionCall {
     expr: Box<dyn Expression>,
     maybe_fallible_arguments: bool,
 
+    // Whether the function is pure, and all of the arguments are literals.
+    constant: bool,
+
+    // Set if identical calls to the pure function can share their value.
+    cache: Option<Cache>,
+
     // used for enhancing runtime error messages (using abort-instruction).
     //
     // TODO: have span store line/col details to further improve this.
@@ -55,6 +64,11 @@ impl FunctionCall {
             }
         };
 
+        // Check if the function may be called.
+        if !state.is_function_allowed(function.identifier()) {
+            return Err(Error::Disallowed { ident_span, ident });
+        }
+
         // Check function arity.
         if arguments.len() > function.parameters().len() {
             let arguments_span = {
@@ -89,6 +103,7 @@ impl FunctionCall {
             .collect::<Vec<_>>();
 
         let mut maybe_fallible_arguments = false;
+        let mut constant = function.is_pure();
         for node in arguments {
             let (argument_span, argument) = node.take();
 
@@ -137,13 +152,15 @@ impl FunctionCall {
                 maybe_fallible_arguments = true;
             }
 
-            // Check if the argument is infallible.
-            if argument.type_def(state).is_fallible() {
+            // Check if the argument is infallible, unless the function
+            // handles its errors.
+            if argument.type_def(state).is_fallible() && !function.handles_errors() {
                 return Err(Error::FallibleArgument {
                     expr_span: argument.span(),
                 });
             }
 
+            constant &= argument.as_value().is_some();
             list.insert(parameter.keyword, argument.into_inner());
         }
 
@@ -180,6 +197,8 @@ impl FunctionCall {
             abort_on_error,
             expr,
             maybe_fallible_arguments,
+            constant,
+            cache: None,
             span: call_span,
             arguments_fmt,
             arguments_dbg,
@@ -187,6 +206,43 @@ impl FunctionCall {
         })
     }
 
+    /// Whether the function is pure, and all of the arguments are literals.
+    pub(crate) fn is_constant(&self) -> bool {
+        self.constant
+    }
+
+    /// Share the value of the call with the identical calls of the program,
+    /// see [`Cache`].
+    pub(crate) fn with_cache(mut self, cache: Cache) -> Self {
+        self.cache = Some(cache);
+        self
+    }
+
+    /// Resolves the function, unless an identical call was already resolved
+    /// with the same arguments.
+    fn resolve_cached(&self, ctx: &mut Context) -> Resolved {
+        let cache = match &self.cache {
+            Some(cache) if cache.repeated.load(Ordering::Relaxed) => cache,
+            _ => return self.expr.resolve(ctx),
+        };
+
+        let arguments = cache
+            .arguments
+            .iter()
+            .map(|argument| argument.resolve(ctx))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        if let Some(value) = ctx.state().cached_call(cache.slot, &arguments) {
+            return Ok(value.clone());
+        }
+
+        let value = self.expr.resolve(ctx)?;
+        ctx.state_mut()
+            .cache_call(cache.slot, arguments, value.clone());
+
+        Ok(value)
+    }
+
     pub fn noop() -> Self {
         let expr = Box::new(Noop) as _;
 
@@ -194,6 +250,8 @@ impl FunctionCall {
             abort_on_error: false,
             expr,
             maybe_fallible_arguments: false,
+            constant: false,
+            cache: None,
             span: Span::default(),
             arguments_fmt: vec![],
             arguments_dbg: vec![],
@@ -204,19 +262,39 @@ impl FunctionCall {
 
 impl Expression for FunctionCall {
     fn resolve(&self, ctx: &mut Context) -> Resolved {
-        span!(Level::ERROR, "remap", vrl_position = &self.span.start()).in_scope(|| {
-            self.expr.resolve(ctx).map_err(|mut err| {
-                err.message = format!(
-                    r#"function call error for "{}" at ({}:{}): {}"#,
-                    self.ident,
-                    self.span.start(),
-                    self.span.end(),
-                    err.message
-                );
-
-                err
-            })
-        })
+        // The call is only timed if a hook is installed, so that it costs
+        // nothing otherwise.
+        let start = ctx.state().function_hook().map(|hook| {
+            hook.enter(self.ident);
+            Instant::now()
+        });
+
+        let result =
+            span!(Level::ERROR, "remap", vrl_position = &self.span.start()).in_scope(|| {
+                self.resolve_cached(ctx).map_err(|mut err| {
+                    // Errors returned by the function itself, rather than by
+                    // operations on values, share the same code.
+                    if err.code == 0 {
+                        err.code = 705;
+                    }
+
+                    err.message = format!(
+                        r#"function call error for "{}" at ({}:{}): {}"#,
+                        self.ident,
+                        self.span.start(),
+                        self.span.end(),
+                        err.message
+                    );
+
+                    err
+                })
+            });
+
+        if let (Some(start), Some(hook)) = (start, ctx.state().function_hook()) {
+            hook.exit(self.ident, start.elapsed());
+        }
+
+        result
     }
 
     fn type_def(&self, state: &State) -> TypeDef {
@@ -328,6 +406,32 @@ impl fmt::Debug for FunctionCall {
     }
 }
 
+/// The identity of the calls to a pure function with the same arguments, to
+/// resolve them once for each time their arguments change, instead of once
+/// for each call.
+///
+/// The arguments must be cheap to resolve, and free of side effects, as they
+/// are resolved again to find out whether the function must be.
+#[derive(Debug, Clone)]
+pub(crate) struct Cache {
+    slot: usize,
+    arguments: Vec<Expr>,
+
+    /// Set once a second identical call is compiled, as the value of a call
+    /// made only once isn't worth keeping.
+    repeated: Arc<AtomicBool>,
+}
+
+impl Cache {
+    pub(crate) fn new(slot: usize, arguments: Vec<Expr>, repeated: Arc<AtomicBool>) -> Self {
+        Self {
+            slot,
+            arguments,
+            repeated,
+        }
+    }
+}
+
 impl PartialEq for FunctionCall {
     fn eq(&self, other: &Self) -> bool {
         self.ident == other.ident
@@ -346,6 +450,9 @@ pub enum Error {
         idents: Vec<&'static str>,
     },
 
+    #[error("call to disallowed function")]
+    Disallowed { ident_span: Span, ident: Ident },
+
     #[error("wrong number of function arguments")]
     WrongNumberOfArgs { arguments_span: Span, max: usize },
 
@@ -393,6 +500,7 @@ impl DiagnosticError for Error {
 
         match self {
             Undefined { .. } => 105,
+            Disallowed { .. } => 111,
             WrongNumberOfArgs { .. } => 106,
             UnknownKeyword { .. } => 108,
             Compilation { .. } => 610,
@@ -433,6 +541,14 @@ impl DiagnosticError for Error {
                 vec
             }
 
+            Disallowed { ident_span, ident } => vec![
+                Label::primary("disallowed function", ident_span),
+                Label::context(
+                    format!(r#"programs aren't allowed to call "{}""#, ident),
+                    ident_span,
+                ),
+            ],
+
             WrongNumberOfArgs {
                 arguments_span,
                 max,
@@ -1,5 +1,5 @@
 use crate::expression::{Expr, Resolved};
-use crate::{Context, Expression, State, TypeDef};
+use crate::{Context, Expression, State, TypeDef, Value};
 use std::fmt;
 
 #[derive(Debug, Clone, PartialEq)]
@@ -23,6 +23,10 @@ impl Expression for Group {
     fn type_def(&self, state: &State) -> TypeDef {
         self.inner.type_def(state)
     }
+
+    fn as_value(&self) -> Option<Value> {
+        self.inner.as_value()
+    }
 }
 
 impl fmt::Display for Group {
@@ -34,6 +34,22 @@ impl Literal {
             Null => Value::Null,
         }
     }
+
+    /// The literal for the value, if the value can be written as one.
+    pub(crate) fn from_value(value: Value) -> Option<Self> {
+        let literal = match value {
+            Value::Bytes(v) => Literal::String(v),
+            Value::Integer(v) => Literal::Integer(v),
+            Value::Float(v) => Literal::Float(v),
+            Value::Boolean(v) => Literal::Boolean(v),
+            Value::Regex(v) => Literal::Regex(v),
+            Value::Timestamp(v) => Literal::Timestamp(v),
+            Value::Null => Literal::Null,
+            Value::Array(_) | Value::Object(_) => return None,
+        };
+
+        Some(literal)
+    }
 }
 
 impl TryFrom<Node<ast::Literal>> for Literal {
@@ -81,6 +97,10 @@ impl Expression for Literal {
 
         type_def.infallible()
     }
+
+    fn as_value(&self) -> Option<Value> {
+        Some(self.to_value())
+    }
 }
 
 impl fmt::Display for Literal {
@@ -36,6 +36,14 @@ impl Expression for Object {
 
         TypeDef::new().object(type_defs).with_fallibility(fallible)
     }
+
+    fn as_value(&self) -> Option<Value> {
+        self.inner
+            .iter()
+            .map(|(key, expr)| expr.as_value().map(|v| (key.to_owned(), v)))
+            .collect::<Option<BTreeMap<_, _>>>()
+            .map(Value::Object)
+    }
 }
 
 impl fmt::Display for Object {
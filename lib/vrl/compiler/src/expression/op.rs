@@ -12,6 +12,11 @@ pub struct Op {
 }
 
 impl Op {
+    /// Whether both operands are literals.
+    pub(crate) fn is_constant(&self) -> bool {
+        self.lhs.as_value().is_some() && self.rhs.as_value().is_some()
+    }
+
     pub fn new(
         lhs: Node<Expr>,
         opcode: Node<ast::Opcode>,
@@ -1,4 +1,4 @@
-use crate::expression::{Expr, Expression, FunctionArgument, Literal, Query};
+use crate::expression::{container, Container, Expr, Expression, FunctionArgument, Literal, Query};
 use crate::parser::Node;
 use crate::value::Kind;
 use crate::{Span, Value};
@@ -46,6 +46,41 @@ pub trait Function: Sync + fmt::Debug {
     fn parameters(&self) -> &'static [Parameter] {
         &[]
     }
+
+    /// Whether the function always returns the same value for the same
+    /// arguments, without any side effects.
+    ///
+    /// Calls to pure functions with only literal arguments are resolved once
+    /// at compile-time, instead of for each event.
+    fn is_pure(&self) -> bool {
+        false
+    }
+
+    /// Whether the function accesses the target directly, instead of only
+    /// through the values of its arguments, such as `del` removing the field
+    /// it's given.
+    ///
+    /// The paths a program reads and writes can't be fully known at
+    /// compile-time if it calls such a function.
+    fn accesses_target(&self) -> bool {
+        false
+    }
+
+    /// Whether the function writes to the paths of the target it's given as
+    /// arguments, such as `del` removing them.
+    ///
+    /// These paths are written by the program, rather than read.
+    fn writes_target(&self) -> bool {
+        false
+    }
+
+    /// Whether the function handles the errors of its arguments itself, such
+    /// as `capture_error` returning them as values.
+    ///
+    /// The arguments of other functions must be infallible.
+    fn handles_errors(&self) -> bool {
+        false
+    }
 }
 
 // -----------------------------------------------------------------------------
@@ -173,6 +208,27 @@ impl ArgumentList {
         Ok(required(self.optional_query(keyword)?))
     }
 
+    /// The expressions of an array argument, for functions resolving them
+    /// one by one, instead of the whole array at once.
+    pub fn optional_array(&mut self, keyword: &'static str) -> Result<Option<Vec<Expr>>, Error> {
+        self.optional_expr(keyword)
+            .map(|expr| match expr {
+                Expr::Container(Container {
+                    variant: container::Variant::Array(array),
+                }) => Ok(array.into()),
+                expr => Err(Error::UnexpectedExpression {
+                    keyword,
+                    expected: "array",
+                    expr,
+                }),
+            })
+            .transpose()
+    }
+
+    pub fn required_array(&mut self, keyword: &'static str) -> Result<Vec<Expr>, Error> {
+        Ok(required(self.optional_array(keyword)?))
+    }
+
     pub fn optional_regex(&mut self, keyword: &'static str) -> Result<Option<regex::Regex>, Error> {
         self.optional_expr(keyword)
             .map(|expr| match expr {
@@ -0,0 +1,31 @@
+use crate::Value;
+use std::fmt;
+use std::time::Duration;
+
+/// Instrumentation of the function calls of programs at runtime.
+///
+/// A hook installed on the [runtime state](crate::state::Runtime) is notified
+/// when each function call of a program is entered and exited, so that the
+/// embedder of the runtime can count or time the calls, for example to
+/// report them as metrics. When no hook is installed, function calls aren't
+/// timed.
+pub trait FunctionHook: Send + Sync + fmt::Debug {
+    /// Called before the function is called.
+    fn enter(&self, _function: &str) {}
+
+    /// Called after the function returned, or failed, with the time it took,
+    /// including the time taken by the function calls of its arguments.
+    fn exit(&self, function: &str, duration: Duration);
+}
+
+/// The destination of the messages programs log at runtime, using the `log`
+/// function.
+///
+/// A hook installed on the [runtime state](crate::state::Runtime) receives
+/// every message, regardless of its rate limit, in addition to it being
+/// emitted as a tracing event, so that the embedder of the runtime can show
+/// the messages alongside the output of the program.
+pub trait LogHook: Send + Sync + fmt::Debug {
+    /// Called with the level and the logged value.
+    fn log(&self, level: &str, value: &Value);
+}
@@ -4,21 +4,36 @@ mod program;
 mod target;
 mod test_util;
 
+pub mod analysis;
+pub mod clock;
+pub mod cost;
+pub mod enrichment;
 pub mod expression;
 pub mod function;
+pub mod hook;
+pub mod optimization;
 pub mod path;
+pub mod schema;
 pub mod state;
 pub mod type_def;
 pub mod value;
 
 pub(crate) use diagnostic::Span;
+use diagnostic::{Diagnostic, DiagnosticList};
 pub(crate) use state::Compiler as State;
 
+pub use analysis::{Analysis, Annotation};
+pub use clock::Clock;
 pub use context::Context;
+pub use cost::Cost;
+pub use enrichment::EnrichmentCache;
 pub use expression::{Expression, ExpressionError, Resolved};
 pub use function::{Function, Parameter};
-pub use path::Path;
-pub use program::Program;
+pub use hook::{FunctionHook, LogHook};
+pub use optimization::Optimization;
+pub use path::{Path, Segment as PathSegment};
+pub use program::{Program, TargetPaths};
+pub use schema::Schema;
 pub use target::Target;
 pub use type_def::TypeDef;
 pub use value::Value;
@@ -48,6 +63,49 @@ pub fn compile_with_state(
     compiler::Compiler::new(fns, state).compile(ast)
 }
 
+/// Similar to [`compile_with_state`], except that the warnings found while
+/// compiling are returned alongside any errors, and regardless of whether
+/// compilation succeeded.
+///
+/// The program is `None` if any of the diagnostics is an error.
+pub fn compile_detailed(
+    ast: parser::Program,
+    fns: &[Box<dyn Function>],
+    state: &mut State,
+) -> (Option<Program>, DiagnosticList) {
+    let (result, warnings) = compiler::Compiler::new(fns, state).compile_with_warnings(ast);
+
+    into_diagnostics(result, warnings)
+}
+
+/// Similar to [`compile_detailed`], except that each compiled expression is
+/// annotated with its type definition, for tools such as editors to show.
+pub fn analyze(ast: parser::Program, fns: &[Box<dyn Function>], state: &mut State) -> Analysis {
+    let (result, warnings, annotations) =
+        compiler::Compiler::new(fns, state).compile_annotated(ast);
+    let (program, diagnostics) = into_diagnostics(result, warnings);
+
+    Analysis {
+        program,
+        diagnostics,
+        annotations,
+    }
+}
+
+fn into_diagnostics(
+    result: std::result::Result<Program, compiler::Errors>,
+    warnings: compiler::Errors,
+) -> (Option<Program>, DiagnosticList) {
+    let (program, mut diagnostics) = match result {
+        Ok(program) => (Some(program), DiagnosticList::default()),
+        Err(errors) => (None, DiagnosticList::from(errors)),
+    };
+
+    diagnostics.extend(warnings.into_iter().map(Diagnostic::from));
+
+    (program, diagnostics)
+}
+
 /// re-export of commonly used parser types.
 pub(crate) mod parser {
     pub use ::parser::ast::{self, Ident, Node};
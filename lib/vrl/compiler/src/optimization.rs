@@ -0,0 +1,66 @@
+use std::fmt;
+use std::str::FromStr;
+
+/// How much the compiler optimizes programs, set on the [compiler
+/// state](crate::state::Compiler).
+///
+/// Optimizations don't change the results of programs, but they're off by
+/// default, so that the compiled program matches its source, for example for
+/// the diagnostics of the compiler to be predictable.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Optimization {
+    /// Level 0: the program isn't optimized.
+    Disabled,
+
+    /// Level 1: the operations and pure function calls over only literals
+    /// are resolved at compile-time (constant folding).
+    Basic,
+
+    /// Level 2: level 1, and the identical pure function calls of the
+    /// program share their value, as long as their arguments don't change
+    /// (common subexpression elimination).
+    Full,
+}
+
+impl Optimization {
+    pub fn folds_constants(self) -> bool {
+        self >= Optimization::Basic
+    }
+
+    pub fn shares_function_calls(self) -> bool {
+        self >= Optimization::Full
+    }
+}
+
+impl Default for Optimization {
+    fn default() -> Self {
+        Optimization::Disabled
+    }
+}
+
+impl FromStr for Optimization {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        use Optimization::*;
+
+        match s {
+            "0" => Ok(Disabled),
+            "1" => Ok(Basic),
+            "2" => Ok(Full),
+            _ => Err(format!(r#"unknown optimization level "{}""#, s)),
+        }
+    }
+}
+
+impl fmt::Display for Optimization {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use Optimization::*;
+
+        match self {
+            Disabled => f.write_str("0"),
+            Basic => f.write_str("1"),
+            Full => f.write_str("2"),
+        }
+    }
+}
@@ -1,4 +1,5 @@
-use crate::Expression;
+use crate::{Cost, Expression, Path, Schema};
+use std::collections::BTreeSet;
 use std::iter::IntoIterator;
 use std::ops::Deref;
 
@@ -6,6 +7,13 @@ use std::ops::Deref;
 pub struct Program {
     pub(crate) expressions: Vec<Box<dyn Expression>>,
     pub(crate) fallible: bool,
+    pub(crate) cost: Cost,
+    pub(crate) functions: BTreeSet<String>,
+    pub(crate) paths_read: BTreeSet<Path>,
+    pub(crate) paths_written: BTreeSet<Path>,
+    pub(crate) dynamic_paths: bool,
+    pub(crate) input_schema: Option<Schema>,
+    pub(crate) output_schema: Option<Schema>,
 }
 
 impl Program {
@@ -16,6 +24,73 @@ impl Program {
     pub fn is_fallible(&self) -> bool {
         self.fallible
     }
+
+    /// Returns a static estimate of the cost of running the program, to
+    /// compare programs before deploying them.
+    pub fn estimate_cost(&self) -> Cost {
+        self.cost
+    }
+
+    /// Returns the names of the functions the program calls, for example to
+    /// only allow some functions to be used.
+    pub fn functions_used(&self) -> BTreeSet<String> {
+        self.functions.clone()
+    }
+
+    /// Returns the paths of the target the program reads, for example to
+    /// track the lineage of the fields of events.
+    pub fn paths_read(&self) -> TargetPaths {
+        TargetPaths {
+            paths: self.paths_read.clone(),
+            complete: !self.dynamic_paths,
+        }
+    }
+
+    /// Returns the paths of the target the program assigns to, or that
+    /// functions such as `del` write to.
+    pub fn paths_written(&self) -> TargetPaths {
+        TargetPaths {
+            paths: self.paths_written.clone(),
+            complete: !self.dynamic_paths,
+        }
+    }
+
+    /// Attach a schema the target has to match before the program runs.
+    pub fn with_input_schema(mut self, schema: Schema) -> Self {
+        self.input_schema = Some(schema);
+        self
+    }
+
+    /// Attach a schema the target has to match after the program ran.
+    pub fn with_output_schema(mut self, schema: Schema) -> Self {
+        self.output_schema = Some(schema);
+        self
+    }
+
+    /// The schema the target is validated against before the program runs,
+    /// if any.
+    pub fn input_schema(&self) -> Option<&Schema> {
+        self.input_schema.as_ref()
+    }
+
+    /// The schema the target is validated against after the program ran, if
+    /// any.
+    pub fn output_schema(&self) -> Option<&Schema> {
+        self.output_schema.as_ref()
+    }
+}
+
+/// The paths of the target a program reads or writes, see
+/// [`Program::paths_read`] and [`Program::paths_written`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TargetPaths {
+    /// The paths known at compile-time.
+    pub paths: BTreeSet<Path>,
+
+    /// Whether the paths are all the paths the program may access, which
+    /// isn't the case if it calls a function accessing the target directly,
+    /// see [`Function::accesses_target`](crate::Function::accesses_target).
+    pub complete: bool,
 }
 
 impl IntoIterator for Program {
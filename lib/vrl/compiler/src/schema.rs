@@ -0,0 +1,417 @@
+use crate::Value;
+use std::collections::BTreeMap;
+use std::convert::TryFrom;
+use std::fmt;
+
+/// A schema to validate values against, such as the events going in and out
+/// of a [`Program`](crate::Program).
+///
+/// This supports a subset of [JSON Schema](https://json-schema.org): the
+/// `type`, `enum`, `properties`, `required`, `additionalProperties`, `items`,
+/// `minimum`, `maximum`, `minLength` and `maxLength` keywords. Any other
+/// keyword is ignored.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Schema {
+    types: Option<Vec<Type>>,
+    variants: Option<Vec<Value>>,
+    properties: BTreeMap<String, Schema>,
+    required: Vec<String>,
+    deny_additional_properties: bool,
+    items: Option<Box<Schema>>,
+    minimum: Option<f64>,
+    maximum: Option<f64>,
+    min_length: Option<usize>,
+    max_length: Option<usize>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Type {
+    Null,
+    Boolean,
+    Integer,
+    Number,
+    String,
+    Array,
+    Object,
+}
+
+impl Type {
+    /// Timestamps and regexes have no JSON equivalent, they match `"string"`,
+    /// which is what they're encoded as.
+    fn matches(self, value: &Value) -> bool {
+        match (self, value) {
+            (Type::Null, Value::Null)
+            | (Type::Boolean, Value::Boolean(_))
+            | (Type::Integer, Value::Integer(_))
+            | (Type::Number, Value::Integer(_))
+            | (Type::Number, Value::Float(_))
+            | (Type::String, Value::Bytes(_))
+            | (Type::String, Value::Timestamp(_))
+            | (Type::String, Value::Regex(_))
+            | (Type::Array, Value::Array(_))
+            | (Type::Object, Value::Object(_)) => true,
+            (Type::Integer, Value::Float(v)) => v.fract() == 0.0,
+            _ => false,
+        }
+    }
+}
+
+impl fmt::Display for Type {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Type::Null => "null",
+            Type::Boolean => "boolean",
+            Type::Integer => "integer",
+            Type::Number => "number",
+            Type::String => "string",
+            Type::Array => "array",
+            Type::Object => "object",
+        };
+
+        f.write_str(name)
+    }
+}
+
+impl Schema {
+    /// Validates the value against this schema, returning a description of
+    /// the first violation found, if any.
+    pub fn validate(&self, value: &Value) -> Result<(), String> {
+        self.validate_at(value, &mut vec![])
+    }
+
+    fn validate_at(&self, value: &Value, path: &mut Vec<String>) -> Result<(), String> {
+        let error = |path: &[String], message: String| {
+            if path.is_empty() {
+                Err(format!(".: {}", message))
+            } else {
+                Err(format!("{}: {}", path.concat(), message))
+            }
+        };
+
+        if let Some(types) = &self.types {
+            if !types.iter().any(|t| t.matches(value)) {
+                let expected = types
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+                    .join(" or ");
+
+                return error(path, format!("expected {}, got {}", expected, value.kind()));
+            }
+        }
+
+        if let Some(variants) = &self.variants {
+            if !variants.contains(value) {
+                return error(path, format!("{} isn't one of the allowed values", value));
+            }
+        }
+
+        match value {
+            Value::Integer(_) | Value::Float(_) => {
+                let number = match value {
+                    Value::Integer(v) => *v as f64,
+                    Value::Float(v) => v.into_inner(),
+                    _ => unreachable!("checked above"),
+                };
+
+                if let Some(minimum) = self.minimum {
+                    if number < minimum {
+                        return error(path, format!("{} is less than {}", value, minimum));
+                    }
+                }
+
+                if let Some(maximum) = self.maximum {
+                    if number > maximum {
+                        return error(path, format!("{} is greater than {}", value, maximum));
+                    }
+                }
+            }
+            Value::Bytes(v) => {
+                let length = String::from_utf8_lossy(v).chars().count();
+
+                if let Some(min_length) = self.min_length {
+                    if length < min_length {
+                        return error(
+                            path,
+                            format!("length {} is less than {}", length, min_length),
+                        );
+                    }
+                }
+
+                if let Some(max_length) = self.max_length {
+                    if length > max_length {
+                        return error(
+                            path,
+                            format!("length {} is greater than {}", length, max_length),
+                        );
+                    }
+                }
+            }
+            Value::Array(v) => {
+                if let Some(items) = &self.items {
+                    for (i, item) in v.iter().enumerate() {
+                        path.push(format!("[{}]", i));
+                        items.validate_at(item, path)?;
+                        path.pop();
+                    }
+                }
+            }
+            Value::Object(v) => {
+                for field in &self.required {
+                    if !v.contains_key(field) {
+                        return error(path, format!(r#"missing required field "{}""#, field));
+                    }
+                }
+
+                for (field, value) in v {
+                    match self.properties.get(field) {
+                        Some(schema) => {
+                            path.push(format!(".{}", field));
+                            schema.validate_at(value, path)?;
+                            path.pop();
+                        }
+                        None if self.deny_additional_properties => {
+                            return error(path, format!(r#"unexpected field "{}""#, field));
+                        }
+                        None => {}
+                    }
+                }
+            }
+            _ => {}
+        }
+
+        Ok(())
+    }
+}
+
+impl TryFrom<Value> for Schema {
+    type Error = String;
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        let mut object = match value {
+            Value::Object(object) => object,
+            value => return Err(format!("schema must be an object, got {}", value.kind())),
+        };
+
+        let mut schema = Schema::default();
+
+        if let Some(types) = object.remove("type") {
+            let types = match types {
+                Value::Array(types) => types,
+                value => vec![value],
+            };
+
+            schema.types = Some(
+                types
+                    .into_iter()
+                    .map(parse_type)
+                    .collect::<Result<Vec<_>, _>>()?,
+            );
+        }
+
+        if let Some(variants) = object.remove("enum") {
+            match variants {
+                Value::Array(variants) => schema.variants = Some(variants),
+                value => return Err(keyword_error("enum", "array", &value)),
+            }
+        }
+
+        if let Some(properties) = object.remove("properties") {
+            match properties {
+                Value::Object(properties) => {
+                    for (field, value) in properties {
+                        let property = Schema::try_from(value)
+                            .map_err(|err| format!(r#"property "{}": {}"#, field, err))?;
+
+                        schema.properties.insert(field, property);
+                    }
+                }
+                value => return Err(keyword_error("properties", "object", &value)),
+            }
+        }
+
+        if let Some(required) = object.remove("required") {
+            match required {
+                Value::Array(fields) => {
+                    for field in fields {
+                        match field {
+                            Value::Bytes(field) => schema
+                                .required
+                                .push(String::from_utf8_lossy(&field).into_owned()),
+                            value => return Err(keyword_error("required", "string", &value)),
+                        }
+                    }
+                }
+                value => return Err(keyword_error("required", "array", &value)),
+            }
+        }
+
+        if let Some(additional) = object.remove("additionalProperties") {
+            match additional {
+                Value::Boolean(allowed) => schema.deny_additional_properties = !allowed,
+                value => return Err(keyword_error("additionalProperties", "boolean", &value)),
+            }
+        }
+
+        if let Some(items) = object.remove("items") {
+            let items = Schema::try_from(items).map_err(|err| format!("items: {}", err))?;
+            schema.items = Some(Box::new(items));
+        }
+
+        schema.minimum = object
+            .remove("minimum")
+            .map(|v| parse_number("minimum", v))
+            .transpose()?;
+        schema.maximum = object
+            .remove("maximum")
+            .map(|v| parse_number("maximum", v))
+            .transpose()?;
+        schema.min_length = object
+            .remove("minLength")
+            .map(|v| parse_length("minLength", v))
+            .transpose()?;
+        schema.max_length = object
+            .remove("maxLength")
+            .map(|v| parse_length("maxLength", v))
+            .transpose()?;
+
+        Ok(schema)
+    }
+}
+
+fn keyword_error(keyword: &str, expected: &str, value: &Value) -> String {
+    format!(
+        r#"invalid "{}" keyword, expected {}, got {}"#,
+        keyword,
+        expected,
+        value.kind()
+    )
+}
+
+fn parse_type(value: Value) -> Result<Type, String> {
+    let name = match &value {
+        Value::Bytes(name) => String::from_utf8_lossy(name),
+        value => return Err(keyword_error("type", "string", value)),
+    };
+
+    match name.as_ref() {
+        "null" => Ok(Type::Null),
+        "boolean" => Ok(Type::Boolean),
+        "integer" => Ok(Type::Integer),
+        "number" => Ok(Type::Number),
+        "string" => Ok(Type::String),
+        "array" => Ok(Type::Array),
+        "object" => Ok(Type::Object),
+        name => Err(format!(r#"unknown type "{}""#, name)),
+    }
+}
+
+fn parse_number(keyword: &str, value: Value) -> Result<f64, String> {
+    match value {
+        Value::Integer(v) => Ok(v as f64),
+        Value::Float(v) => Ok(v.into_inner()),
+        value => Err(keyword_error(keyword, "number", &value)),
+    }
+}
+
+fn parse_length(keyword: &str, value: Value) -> Result<usize, String> {
+    match value {
+        Value::Integer(v) if v >= 0 => Ok(v as usize),
+        value => Err(keyword_error(keyword, "non-negative integer", &value)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::value;
+
+    fn schema(value: Value) -> Schema {
+        Schema::try_from(value).unwrap()
+    }
+
+    #[test]
+    fn validate_types() {
+        let schema = schema(value!({ "type": ["string", "null"] }));
+
+        assert_eq!(schema.validate(&value!("foo")), Ok(()));
+        assert_eq!(schema.validate(&Value::Null), Ok(()));
+        assert_eq!(
+            schema.validate(&value!(1)),
+            Err(r#".: expected string or null, got "integer""#.to_owned())
+        );
+    }
+
+    #[test]
+    fn validate_integer_accepts_integral_floats() {
+        let schema = schema(value!({ "type": "integer" }));
+
+        assert_eq!(schema.validate(&value!(1.0)), Ok(()));
+        assert!(schema.validate(&value!(1.5)).is_err());
+    }
+
+    #[test]
+    fn validate_object() {
+        let schema = schema(value!({
+            "type": "object",
+            "required": ["id"],
+            "additionalProperties": false,
+            "properties": {
+                "id": { "type": "integer", "minimum": 1 },
+                "tags": { "type": "array", "items": { "type": "string", "maxLength": 3 } },
+                "level": { "enum": ["info", "error"] },
+            },
+        }));
+
+        assert_eq!(
+            schema.validate(&value!({ "id": 1, "tags": ["foo"], "level": "info" })),
+            Ok(())
+        );
+        assert_eq!(
+            schema.validate(&value!({ "tags": [] })),
+            Err(r#".: missing required field "id""#.to_owned())
+        );
+        assert_eq!(
+            schema.validate(&value!({ "id": 0 })),
+            Err(".id: 0 is less than 1".to_owned())
+        );
+        assert_eq!(
+            schema.validate(&value!({ "id": 1, "tags": ["foo", "quux"] })),
+            Err(".tags[1]: length 4 is greater than 3".to_owned())
+        );
+        assert_eq!(
+            schema.validate(&value!({ "id": 1, "level": "debug" })),
+            Err(r#".level: "debug" isn't one of the allowed values"#.to_owned())
+        );
+        assert_eq!(
+            schema.validate(&value!({ "id": 1, "extra": true })),
+            Err(r#".: unexpected field "extra""#.to_owned())
+        );
+    }
+
+    #[test]
+    fn invalid_schema() {
+        assert_eq!(
+            Schema::try_from(value!("object")),
+            Err(r#"schema must be an object, got "string""#.to_owned())
+        );
+        assert_eq!(
+            Schema::try_from(value!({ "type": "date" })),
+            Err(r#"unknown type "date""#.to_owned())
+        );
+        assert_eq!(
+            Schema::try_from(value!({ "properties": { "id": { "minimum": "1" } } })),
+            Err(
+                r#"property "id": invalid "minimum" keyword, expected number, got "string""#
+                    .to_owned()
+            )
+        );
+    }
+
+    #[test]
+    fn ignores_unsupported_keywords() {
+        let schema = schema(value!({ "type": "string", "format": "email", "$id": "foo" }));
+
+        assert_eq!(schema.validate(&value!("foo")), Ok(()));
+    }
+}
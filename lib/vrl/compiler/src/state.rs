@@ -1,6 +1,12 @@
+use crate::clock::{Clock, SystemClock};
+use crate::enrichment::EnrichmentCache;
 use crate::expression::assignment;
-use crate::{parser::ast::Ident, Value};
-use std::collections::HashMap;
+use crate::hook::{FunctionHook, LogHook};
+use crate::optimization::Optimization;
+use crate::{parser::ast::Ident, TypeDef, Value};
+use rand::{rngs::StdRng, RngCore, SeedableRng};
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
 
 /// The state held by the compiler.
 ///
@@ -25,13 +31,86 @@ pub struct Compiler {
     /// forget any state it started tracking while parsing the old, defunct
     /// expression.
     snapshot: Option<Box<Self>>,
+
+    /// The optimizations applied to the compiled programs.
+    optimization: Optimization,
+
+    /// The only functions programs may call, if restricted, and the
+    /// functions they may not call.
+    allowed_functions: Option<Arc<HashSet<String>>>,
+    denied_functions: Arc<HashSet<String>>,
+
+    /// The maximum depth of nested expressions, if not the default.
+    max_depth: Option<usize>,
 }
 
 impl Compiler {
+    /// Apply the optimizations of the given level to the compiled programs.
+    pub fn with_optimization(mut self, optimization: Optimization) -> Self {
+        self.optimization = optimization;
+        self
+    }
+
+    pub fn optimization(&self) -> Optimization {
+        self.optimization
+    }
+
+    /// Only allow programs to call the given functions.
+    pub fn with_allowed_functions(
+        mut self,
+        functions: impl IntoIterator<Item = impl Into<String>>,
+    ) -> Self {
+        self.allowed_functions = Some(Arc::new(functions.into_iter().map(Into::into).collect()));
+        self
+    }
+
+    /// Prevent programs from calling the given functions.
+    pub fn with_denied_functions(
+        mut self,
+        functions: impl IntoIterator<Item = impl Into<String>>,
+    ) -> Self {
+        self.denied_functions = Arc::new(functions.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Whether programs may call the function, see
+    /// [`Compiler::with_allowed_functions`] and
+    /// [`Compiler::with_denied_functions`].
+    pub fn is_function_allowed(&self, ident: &str) -> bool {
+        let allowed = self
+            .allowed_functions
+            .as_ref()
+            .map_or(true, |functions| functions.contains(ident));
+
+        allowed && !self.denied_functions.contains(ident)
+    }
+
+    /// Reject programs with expressions nested more than `max_depth` levels
+    /// deep, beyond which the compiler, or the program at runtime, could
+    /// overflow the stack.
+    ///
+    /// Defaults to [`DEFAULT_MAX_DEPTH`](::parser::DEFAULT_MAX_DEPTH).
+    pub fn with_max_depth(mut self, max_depth: usize) -> Self {
+        self.max_depth = Some(max_depth);
+        self
+    }
+
+    pub fn max_depth(&self) -> usize {
+        self.max_depth.unwrap_or(::parser::DEFAULT_MAX_DEPTH)
+    }
+
     pub(crate) fn variable(&self, ident: &Ident) -> Option<&assignment::Details> {
         self.variables.get(ident)
     }
 
+    /// The variables assigned so far, with the type definition of their
+    /// value, in no particular order.
+    pub fn variables(&self) -> impl Iterator<Item = (&str, &TypeDef)> {
+        self.variables
+            .iter()
+            .map(|(ident, details)| (ident.as_ref(), &details.type_def))
+    }
+
     pub(crate) fn insert_variable(&mut self, ident: Ident, details: assignment::Details) {
         self.variables.insert(ident, details);
     }
@@ -55,6 +134,10 @@ impl Compiler {
             target,
             variables,
             snapshot: None,
+            optimization: self.optimization,
+            allowed_functions: self.allowed_functions.clone(),
+            denied_functions: self.denied_functions.clone(),
+            max_depth: self.max_depth,
         };
 
         self.snapshot = Some(Box::new(snapshot));
@@ -66,16 +149,173 @@ impl Compiler {
             *self = *snapshot;
         }
     }
+
+    /// Save the current state, so that the changes made by compiling
+    /// subsequent programs can be discarded using [`Compiler::rollback_to`].
+    ///
+    /// Unlike snapshots, any number of checkpoints can be held at once.
+    pub fn checkpoint(&self) -> CompilerCheckpoint {
+        let mut state = self.clone();
+        state.snapshot = None;
+
+        CompilerCheckpoint(state)
+    }
+
+    /// Restore the state saved by [`Compiler::checkpoint`], forgetting about
+    /// any variable introduced since.
+    pub fn rollback_to(&mut self, checkpoint: CompilerCheckpoint) {
+        *self = checkpoint.0;
+    }
 }
 
+/// The compiler state saved by [`Compiler::checkpoint`].
+#[derive(Clone)]
+pub struct CompilerCheckpoint(Compiler);
+
 /// The state used at runtime to track changes as they happen.
-#[derive(Debug, Default)]
+#[derive(Debug)]
 pub struct Runtime {
     /// The [`Value`] stored in each variable.
     variables: HashMap<Ident, Value>,
+
+    /// The clock from which functions read the current time.
+    clock: Box<dyn Clock>,
+
+    /// The source of randomness for functions. Unless seeded, it's seeded from
+    /// the operating system's entropy on first use, so that runtimes that
+    /// never need randomness don't pay for it.
+    rng: Option<StdRng>,
+
+    /// The hook notified of function calls, if any.
+    function_hook: Option<Box<dyn FunctionHook>>,
+
+    /// The hook receiving the messages logged by programs, if any.
+    log_hook: Option<Box<dyn LogHook>>,
+
+    /// The values of the repeated pure function calls of the running program,
+    /// with the arguments they were resolved with.
+    calls: Vec<Option<(Vec<Value>, Value)>>,
+
+    /// The maximum depth of the values assigned by programs, if limited.
+    max_value_depth: Option<usize>,
+
+    /// The cache of the results of enrichment lookups, if any, which is shared
+    /// by the programs resolved with this state.
+    enrichment_cache: Option<EnrichmentCache>,
+}
+
+impl Default for Runtime {
+    fn default() -> Self {
+        Self {
+            variables: HashMap::default(),
+            clock: Box::new(SystemClock),
+            rng: None,
+            function_hook: None,
+            log_hook: None,
+            calls: vec![],
+            max_value_depth: None,
+            enrichment_cache: None,
+        }
+    }
 }
 
 impl Runtime {
+    /// Read the current time from the given clock, instead of the system
+    /// clock.
+    pub fn with_clock(mut self, clock: impl Clock + 'static) -> Self {
+        self.clock = Box::new(clock);
+        self
+    }
+
+    /// Seed the source of randomness, so that functions produce the same
+    /// sequence of random values for each runtime seeded identically.
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.rng = Some(StdRng::seed_from_u64(seed));
+        self
+    }
+
+    /// Notify the given hook of each function call.
+    pub fn with_function_hook(mut self, hook: impl FunctionHook + 'static) -> Self {
+        self.function_hook = Some(Box::new(hook));
+        self
+    }
+
+    /// Send the messages logged by programs to the given hook.
+    pub fn with_log_hook(mut self, hook: impl LogHook + 'static) -> Self {
+        self.log_hook = Some(Box::new(hook));
+        self
+    }
+
+    /// Abort programs assigning values with objects and arrays nested more
+    /// than `max_depth` levels deep, including the depth of the path assigned
+    /// to, so that they can be displayed and encoded without overflowing the
+    /// stack.
+    ///
+    /// Unless set, the depth of values isn't limited.
+    pub fn with_max_value_depth(mut self, max_depth: usize) -> Self {
+        self.max_value_depth = Some(max_depth);
+        self
+    }
+
+    pub fn max_value_depth(&self) -> Option<usize> {
+        self.max_value_depth
+    }
+
+    /// Cache the results of the enrichment lookups of functions, see
+    /// [`Runtime::enrich`].
+    pub fn with_enrichment_cache(mut self, cache: EnrichmentCache) -> Self {
+        self.enrichment_cache = Some(cache);
+        self
+    }
+
+    pub fn enrichment_cache(&self) -> Option<&EnrichmentCache> {
+        self.enrichment_cache.as_ref()
+    }
+
+    /// The result of the enrichment lookup of the function with the given
+    /// arguments, from the enrichment cache if it's cached there, or returned
+    /// by `lookup` otherwise, in which case it's cached, unless the lookup
+    /// failed.
+    ///
+    /// Without an enrichment cache, the lookup is always done.
+    pub fn enrich<E>(
+        &mut self,
+        function: &str,
+        arguments: &[Value],
+        lookup: impl FnOnce() -> Result<Value, E>,
+    ) -> Result<Value, E> {
+        let now = self.clock.now();
+        let cache = match &mut self.enrichment_cache {
+            Some(cache) => cache,
+            None => return lookup(),
+        };
+
+        if let Some(value) = cache.get(function, arguments, now) {
+            return Ok(value);
+        }
+
+        let value = lookup()?;
+        cache.insert(function, arguments, value.clone(), now);
+
+        Ok(value)
+    }
+
+    pub fn clock(&self) -> &dyn Clock {
+        self.clock.as_ref()
+    }
+
+    pub fn rng(&mut self) -> &mut dyn RngCore {
+        self.rng.get_or_insert_with(StdRng::from_entropy)
+    }
+
+    pub fn function_hook(&self) -> Option<&dyn FunctionHook> {
+        self.function_hook.as_deref()
+    }
+
+    pub fn log_hook(&self) -> Option<&dyn LogHook> {
+        self.log_hook.as_deref()
+    }
+
     pub fn variable(&self, ident: &Ident) -> Option<&Value> {
         self.variables.get(&ident)
     }
@@ -87,4 +327,127 @@ impl Runtime {
     pub(crate) fn insert_variable(&mut self, ident: Ident, value: Value) {
         self.variables.insert(ident, value);
     }
+
+    /// The value of the repeated function call, if it was already resolved
+    /// with the same arguments.
+    pub(crate) fn cached_call(&self, slot: usize, arguments: &[Value]) -> Option<&Value> {
+        match self.calls.get(slot) {
+            Some(Some((cached, value))) if cached.as_slice() == arguments => Some(value),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn cache_call(&mut self, slot: usize, arguments: Vec<Value>, value: Value) {
+        if self.calls.len() <= slot {
+            self.calls.resize_with(slot + 1, Default::default);
+        }
+
+        self.calls[slot] = Some((arguments, value));
+    }
+
+    /// Forgets the values of the repeated function calls, which are only
+    /// valid for the program that resolved them.
+    ///
+    /// This must be called before resolving a program.
+    pub fn clear_calls(&mut self) {
+        self.calls.clear();
+    }
+
+    /// Removes all variables, keeping the allocated memory for reuse.
+    ///
+    /// The enrichment cache is kept, so that it can be shared by the programs
+    /// resolved next.
+    pub fn clear(&mut self) {
+        self.variables.clear();
+        self.calls.clear();
+    }
+
+    /// Save the current variables, so that the values assigned by subsequent
+    /// programs can be discarded using [`Runtime::rollback_to`].
+    pub fn checkpoint(&self) -> RuntimeCheckpoint {
+        RuntimeCheckpoint {
+            variables: self.variables.clone(),
+        }
+    }
+
+    /// Restore the variables saved by [`Runtime::checkpoint`].
+    ///
+    /// The clock, source of randomness, function hook and enrichment cache are
+    /// left as is.
+    pub fn rollback_to(&mut self, checkpoint: RuntimeCheckpoint) {
+        self.variables = checkpoint.variables;
+    }
+}
+
+/// The runtime variables saved by [`Runtime::checkpoint`].
+#[derive(Debug, Clone)]
+pub struct RuntimeCheckpoint {
+    variables: HashMap<Ident, Value>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn compile(source: &str, state: &mut Compiler) {
+        let ast = ::parser::parse(source).unwrap();
+        crate::compile_with_state(ast, &[], state).unwrap();
+    }
+
+    #[test]
+    fn compiler_rollback_to_checkpoint() {
+        let mut state = Compiler::default();
+        compile("foo = 1", &mut state);
+
+        let checkpoint = state.checkpoint();
+        compile("bar = 2\nfoo = \"foo\"", &mut state);
+
+        assert!(state.variable(&Ident::new("bar")).is_some());
+        assert!(state
+            .variable(&Ident::new("foo"))
+            .unwrap()
+            .type_def
+            .is_bytes());
+
+        state.rollback_to(checkpoint);
+
+        assert!(state.variable(&Ident::new("bar")).is_none());
+        assert!(state
+            .variable(&Ident::new("foo"))
+            .unwrap()
+            .type_def
+            .is_integer());
+    }
+
+    #[test]
+    fn compiler_variables() {
+        let mut state = Compiler::default();
+        compile("foo = 1\nbar = \"bar\"\nfoo = true", &mut state);
+
+        let mut variables = state.variables().collect::<Vec<_>>();
+        variables.sort_by_key(|(ident, _)| *ident);
+
+        assert_eq!(
+            variables,
+            vec![
+                ("bar", &TypeDef::new().bytes()),
+                ("foo", &TypeDef::new().boolean()),
+            ]
+        );
+    }
+
+    #[test]
+    fn runtime_rollback_to_checkpoint() {
+        let mut state = Runtime::default();
+        state.insert_variable(Ident::new("foo"), 1.into());
+
+        let checkpoint = state.checkpoint();
+        state.insert_variable(Ident::new("foo"), 2.into());
+        state.insert_variable(Ident::new("bar"), 3.into());
+
+        state.rollback_to(checkpoint);
+
+        assert_eq!(state.variable(&Ident::new("foo")), Some(&Value::from(1)));
+        assert_eq!(state.variable(&Ident::new("bar")), None);
+    }
 }
@@ -862,6 +862,28 @@ impl TypeDef {
         self.kind().intersects(kind.into())
     }
 
+    /// Returns the fields known to exist if this is an object, along with
+    /// their type definitions.
+    ///
+    /// Fields of objects of which only the inner kind is known are omitted.
+    pub fn object_fields(&self) -> BTreeMap<String, TypeDef> {
+        self.kind
+            .object()
+            .into_iter()
+            .flatten()
+            .filter_map(|(field, kind)| match field {
+                Field::Field(field) => Some((
+                    field.to_owned(),
+                    TypeDef {
+                        fallible: false,
+                        kind: kind.clone(),
+                    },
+                )),
+                Field::Any => None,
+            })
+            .collect()
+    }
+
     // -------------------------------------------------------------------------
 
     pub fn is_fallible(&self) -> bool {
@@ -1046,4 +1068,23 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn object_fields() {
+        let type_def = TypeDef::new()
+            .object::<&str, TypeDef>(map! {
+                "foo": TypeDef::new().integer(),
+                "bar": TypeDef::new().bytes().add_null(),
+            })
+            .add_integer();
+
+        let mut want = BTreeMap::new();
+        want.insert("foo".to_owned(), TypeDef::new().integer());
+        want.insert("bar".to_owned(), TypeDef::new().bytes().add_null());
+        assert_eq!(type_def.object_fields(), want);
+
+        let type_def = TypeDef::new().object::<(), Kind>(map! { (): Kind::all() });
+        assert!(type_def.object_fields().is_empty());
+        assert!(TypeDef::new().integer().object_fields().is_empty());
+    }
 }
@@ -1,4 +1,7 @@
 mod arithmetic;
+mod builder;
+mod canonical;
+mod compare;
 mod convert;
 mod error;
 pub mod kind;
@@ -6,6 +9,7 @@ mod r#macro;
 mod path;
 mod regex;
 mod serde;
+mod size;
 mod target;
 
 use bytes::Bytes;
@@ -15,6 +19,9 @@ use std::collections::BTreeMap;
 use std::fmt;
 
 pub use self::regex::Regex;
+pub use self::serde::Tagged;
+pub use builder::{BuildError, ValueBuilder};
+pub use compare::EqOptions;
 pub use error::Error;
 pub use kind::Kind;
 
@@ -8,12 +8,25 @@ impl Value {
     pub fn try_mul(self, rhs: Self) -> Result<Self, Error> {
         let err = || Error::Mul(self.kind(), rhs.kind());
 
+        // Bytes can't be repeated a negative number of times.
+        let count = |count: i64| usize::try_from(count).map_err(|_| err());
+
         let value = match self {
-            Value::Integer(lhv) if rhs.is_bytes() => rhs.try_bytes()?.repeat(lhv as usize).into(),
+            Value::Integer(lhv) if rhs.is_bytes() => {
+                let count = count(lhv)?;
+                rhs.try_bytes()?.repeat(count).into()
+            }
             Value::Integer(lhv) if rhs.is_float() => (lhv as f64 * rhs.try_float()?).into(),
-            Value::Integer(lhv) => (lhv * i64::try_from(&rhs).map_err(|_| err())?).into(),
-            Value::Float(lhv) => (lhv * f64::try_from(&rhs).map_err(|_| err())?).into(),
-            Value::Bytes(lhv) if rhs.is_integer() => lhv.repeat(rhs.try_integer()? as usize).into(),
+            Value::Integer(lhv) => lhv
+                .wrapping_mul(i64::try_from(&rhs).map_err(|_| err())?)
+                .into(),
+            Value::Float(lhv) => {
+                (lhv.into_inner() * f64::try_from(&rhs).map_err(|_| err())?).into()
+            }
+            Value::Bytes(ref lhv) if rhs.is_integer() => {
+                let count = count(i64::try_from(&rhs).map_err(|_| err())?)?;
+                lhv.repeat(count).into()
+            }
             _ => return Err(err()),
         };
 
@@ -45,8 +58,12 @@ impl Value {
 
         let value = match self {
             Value::Integer(lhv) if rhs.is_float() => (lhv as f64 + rhs.try_float()?).into(),
-            Value::Integer(lhv) => (lhv + i64::try_from(&rhs).map_err(|_| err())?).into(),
-            Value::Float(lhv) => (lhv + f64::try_from(&rhs).map_err(|_| err())?).into(),
+            Value::Integer(lhv) => lhv
+                .wrapping_add(i64::try_from(&rhs).map_err(|_| err())?)
+                .into(),
+            Value::Float(lhv) => {
+                (lhv.into_inner() + f64::try_from(&rhs).map_err(|_| err())?).into()
+            }
             Value::Bytes(_) if rhs.is_null() => self,
             Value::Bytes(_) if rhs.is_bytes() => format!(
                 "{}{}",
@@ -67,8 +84,12 @@ impl Value {
 
         let value = match self {
             Value::Integer(lhv) if rhs.is_float() => (lhv as f64 - rhs.try_float()?).into(),
-            Value::Integer(lhv) => (lhv - i64::try_from(&rhs).map_err(|_| err())?).into(),
-            Value::Float(lhv) => (lhv - f64::try_from(&rhs).map_err(|_| err())?).into(),
+            Value::Integer(lhv) => lhv
+                .wrapping_sub(i64::try_from(&rhs).map_err(|_| err())?)
+                .into(),
+            Value::Float(lhv) => {
+                (lhv.into_inner() - f64::try_from(&rhs).map_err(|_| err())?).into()
+            }
             _ => return Err(err()),
         };
 
@@ -116,10 +137,18 @@ impl Value {
     pub fn try_rem(self, rhs: Self) -> Result<Self, Error> {
         let err = || Error::Rem(self.kind(), rhs.kind());
 
+        if f64::try_from(&rhs).map_or(false, |rhv| rhv == 0.0) {
+            return Err(Error::DivideByZero);
+        }
+
         let value = match self {
             Value::Integer(lhv) if rhs.is_float() => (lhv as f64 % rhs.try_float()?).into(),
-            Value::Integer(lhv) => (lhv % i64::try_from(&rhs).map_err(|_| err())?).into(),
-            Value::Float(lhv) => (lhv % f64::try_from(&rhs).map_err(|_| err())?).into(),
+            Value::Integer(lhv) => lhv
+                .wrapping_rem(i64::try_from(&rhs).map_err(|_| err())?)
+                .into(),
+            Value::Float(lhv) => {
+                (lhv.into_inner() % f64::try_from(&rhs).map_err(|_| err())?).into()
+            }
             _ => return Err(err()),
         };
 
@@ -0,0 +1,184 @@
+use super::{Kind, Value};
+use crate::path::{Path, Segment};
+use std::collections::BTreeMap;
+use std::str::FromStr;
+
+/// Builds a [`Value`] by inserting values at paths, such as `"a.b[0].c"`, the
+/// way a program assigns to paths.
+///
+/// Missing objects and arrays along a path are created as needed, and arrays
+/// are padded with `null` up to the given index.
+///
+/// ```
+/// # use vrl_compiler::{value, value::ValueBuilder};
+/// let value = ValueBuilder::new()
+///     .insert("a.b[1]", 1)?
+///     .insert("a.c", "foo")?
+///     .build();
+///
+/// assert_eq!(value, value!({ "a": { "b": [null, 1], "c": "foo" } }));
+/// # Ok::<(), vrl_compiler::value::BuildError>(())
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct ValueBuilder {
+    value: Value,
+}
+
+#[derive(thiserror::Error, Clone, Debug, PartialEq)]
+pub enum BuildError {
+    #[error(r#"invalid path "{0}""#)]
+    InvalidPath(String),
+
+    #[error(r#"can't insert at "{path}": expected {expected} at "{parent}", got {got}"#)]
+    Conflict {
+        path: String,
+        parent: String,
+        expected: Kind,
+        got: Kind,
+    },
+}
+
+impl Default for ValueBuilder {
+    fn default() -> Self {
+        Self {
+            value: BTreeMap::default().into(),
+        }
+    }
+}
+
+impl ValueBuilder {
+    /// Creates a builder for an empty object.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Inserts the value at the given path, replacing any existing value.
+    ///
+    /// This fails if the path goes through an existing value that isn't an
+    /// object or array, as appropriate, rather than silently replacing it.
+    /// `null` values are replaced.
+    pub fn insert(mut self, path: &str, value: impl Into<Value>) -> Result<Self, BuildError> {
+        let path = Path::from_str(path).map_err(|_| BuildError::InvalidPath(path.to_owned()))?;
+        let segments = path.segments();
+
+        for (i, segment) in segments.iter().enumerate() {
+            let parent_path = Path::new_unchecked(segments[..i].to_vec());
+            let parent = match self.value.get_by_path(&parent_path) {
+                Some(parent) => parent,
+                None => break,
+            };
+
+            let expected = match segment {
+                Segment::Index(_) => Kind::Array,
+                Segment::Field(_) | Segment::Coalesce(_) => Kind::Object,
+            };
+
+            match parent {
+                Value::Null => break,
+                parent if parent.kind() != expected => {
+                    return Err(BuildError::Conflict {
+                        path: path.to_string(),
+                        parent: parent_path.to_string(),
+                        expected,
+                        got: parent.kind(),
+                    })
+                }
+                _ => {}
+            }
+        }
+
+        self.value.insert_by_path(&path, value.into());
+
+        Ok(self)
+    }
+
+    /// Returns the built value.
+    pub fn build(self) -> Value {
+        self.value
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::value;
+
+    #[test]
+    fn nested() {
+        let value = ValueBuilder::new()
+            .insert("a.b.c", 1)
+            .unwrap()
+            .insert(".a.b.d", "foo")
+            .unwrap()
+            .insert("a.e[2].f", true)
+            .unwrap()
+            .insert("a.e[0]", 1.5)
+            .unwrap()
+            .insert("g[0][1]", Value::Null)
+            .unwrap()
+            .build();
+
+        assert_eq!(
+            value,
+            value!({
+                "a": {
+                    "b": { "c": 1, "d": "foo" },
+                    "e": [1.5, null, { "f": true }],
+                },
+                "g": [[null, null]],
+            })
+        );
+    }
+
+    #[test]
+    fn replace() {
+        let value = ValueBuilder::new()
+            .insert("a.b", 1)
+            .unwrap()
+            .insert("a", "foo")
+            .unwrap()
+            .insert("c", Value::Null)
+            .unwrap()
+            .insert("c.d", 2)
+            .unwrap()
+            .build();
+
+        assert_eq!(value, value!({ "a": "foo", "c": { "d": 2 } }));
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(ValueBuilder::new().build(), value!({}));
+    }
+
+    #[test]
+    fn conflict() {
+        let builder = ValueBuilder::new().insert("a.b", 1).unwrap();
+
+        assert_eq!(
+            builder.clone().insert("a.b.c", 2),
+            Err(BuildError::Conflict {
+                path: ".a.b.c".to_owned(),
+                parent: ".a.b".to_owned(),
+                expected: Kind::Object,
+                got: Kind::Integer,
+            })
+        );
+        assert_eq!(
+            builder.clone().insert("a[0]", 2).unwrap_err().to_string(),
+            r#"can't insert at ".a[0]": expected "array" at ".a", got "object""#
+        );
+        assert_eq!(
+            builder.insert("[0]", 2).unwrap_err().to_string(),
+            r#"can't insert at ".[0]": expected "array" at ".", got "object""#
+        );
+    }
+
+    #[test]
+    fn invalid_path() {
+        assert_eq!(
+            ValueBuilder::new().insert("a..b", 1),
+            Err(BuildError::InvalidPath("a..b".to_owned()))
+        );
+    }
+}
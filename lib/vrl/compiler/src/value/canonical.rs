@@ -0,0 +1,162 @@
+use super::Value;
+use chrono::SecondsFormat;
+use std::fmt::Write;
+
+impl Value {
+    /// Returns a deterministic string representation of this value, suitable
+    /// for hashing.
+    ///
+    /// Values that are equal produce the same string, regardless of how they
+    /// were built. Strings are quoted, so they never collide with other kinds,
+    /// and floats are written with a fractional part or an exponent, so that
+    /// `1` and `1.0` remain distinct, unless `unify_numbers` is set, in which
+    /// case floats without a fractional part are written as integers.
+    pub fn canonicalize(&self, unify_numbers: bool) -> String {
+        let mut canonical = String::new();
+        write_canonical(self, unify_numbers, &mut canonical);
+        canonical
+    }
+}
+
+fn write_canonical(value: &Value, unify_numbers: bool, out: &mut String) {
+    match value {
+        Value::Bytes(v) => write_string(v, out),
+        Value::Integer(v) => write!(out, "{}", v).expect("write to string"),
+        Value::Float(v) => {
+            let v = v.into_inner();
+
+            // `i64::MAX as f64` rounds up to 2^63, which doesn't fit an `i64`.
+            if unify_numbers && v.fract() == 0.0 && v >= i64::MIN as f64 && v < i64::MAX as f64 {
+                write!(out, "{}", v as i64).expect("write to string")
+            } else {
+                write!(out, "{:?}", v).expect("write to string")
+            }
+        }
+        Value::Boolean(v) => write!(out, "{}", v).expect("write to string"),
+        Value::Object(v) => {
+            out.push('{');
+            for (i, (key, value)) in v.iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                write_string(key.as_bytes(), out);
+                out.push(':');
+                write_canonical(value, unify_numbers, out);
+            }
+            out.push('}');
+        }
+        Value::Array(v) => {
+            out.push('[');
+            for (i, value) in v.iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                write_canonical(value, unify_numbers, out);
+            }
+            out.push(']');
+        }
+        Value::Timestamp(v) => write!(out, "t'{}'", v.to_rfc3339_opts(SecondsFormat::AutoSi, true))
+            .expect("write to string"),
+        Value::Regex(v) => write!(out, "r'{}'", v.as_str()).expect("write to string"),
+        Value::Null => out.push_str("null"),
+    }
+}
+
+/// Writes a quoted string, escaping quotes, backslashes and control
+/// characters, as well as any bytes that aren't valid UTF-8.
+fn write_string(mut bytes: &[u8], out: &mut String) {
+    out.push('"');
+
+    while !bytes.is_empty() {
+        let (valid, invalid) = match std::str::from_utf8(bytes) {
+            Ok(valid) => (valid, 0),
+            Err(error) => {
+                let valid =
+                    std::str::from_utf8(&bytes[..error.valid_up_to()]).expect("valid UTF-8 prefix");
+                let invalid = error
+                    .error_len()
+                    .unwrap_or(bytes.len() - error.valid_up_to());
+
+                (valid, invalid)
+            }
+        };
+
+        for c in valid.chars() {
+            match c {
+                '"' => out.push_str("\\\""),
+                '\\' => out.push_str("\\\\"),
+                c if c.is_control() => {
+                    write!(out, "\\u{{{:x}}}", c as u32).expect("write to string")
+                }
+                c => out.push(c),
+            }
+        }
+
+        let start = valid.len();
+        for byte in &bytes[start..start + invalid] {
+            write!(out, "\\x{:02x}", byte).expect("write to string");
+        }
+
+        bytes = &bytes[start + invalid..];
+    }
+
+    out.push('"');
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::value;
+
+    #[test]
+    fn sorts_object_keys() {
+        let mut forward = value!({});
+        forward.insert_by_path(&"a".parse().unwrap(), 1.into());
+        forward.insert_by_path(&"b".parse().unwrap(), 2.into());
+
+        let mut backward = value!({});
+        backward.insert_by_path(&"b".parse().unwrap(), 2.into());
+        backward.insert_by_path(&"a".parse().unwrap(), 1.into());
+
+        assert_eq!(forward.canonicalize(false), r#"{"a":1,"b":2}"#);
+        assert_eq!(forward.canonicalize(false), backward.canonicalize(false));
+    }
+
+    #[test]
+    fn distinguishes_integers_and_floats() {
+        assert_eq!(value!(1).canonicalize(false), "1");
+        assert_eq!(value!(1.0).canonicalize(false), "1.0");
+        assert_eq!(value!(1.5).canonicalize(false), "1.5");
+    }
+
+    #[test]
+    fn unifies_integral_floats() {
+        assert_eq!(value!(1.0).canonicalize(true), "1");
+        assert_eq!(Value::from(-0.0).canonicalize(true), "0");
+        assert_eq!(value!(1.5).canonicalize(true), "1.5");
+
+        // Floats out of the range of integers are written as they are.
+        assert_eq!(value!(1e300).canonicalize(true), format!("{:?}", 1e300));
+    }
+
+    #[test]
+    fn quotes_strings() {
+        assert_eq!(value!("1").canonicalize(false), r#""1""#);
+        assert_eq!(
+            value!("a \"b\"\\\n").canonicalize(false),
+            r#""a \"b\"\\\u{a}""#
+        );
+        assert_eq!(
+            Value::from(&b"a\xffb"[..]).canonicalize(false),
+            r#""a\xffb""#
+        );
+    }
+
+    #[test]
+    fn nested() {
+        assert_eq!(
+            value!({ "foo": [null, true, { "bar": "baz" }] }).canonicalize(false),
+            r#"{"foo":[null,true,{"bar":"baz"}]}"#
+        );
+    }
+}
@@ -0,0 +1,139 @@
+use super::Value;
+
+/// The options of a [`Value::loose_eq`] comparison.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EqOptions {
+    /// Whether integers and floats with the same numeric value are equal, such
+    /// as `1` and `1.0`.
+    pub unify_numbers: bool,
+
+    /// Whether arrays holding the same elements in a different order are
+    /// equal.
+    pub ignore_array_order: bool,
+}
+
+impl Default for EqOptions {
+    fn default() -> Self {
+        Self {
+            unify_numbers: true,
+            ignore_array_order: false,
+        }
+    }
+}
+
+impl Value {
+    /// Compares two values recursively, relaxing the strict comparison of
+    /// [`PartialEq`] according to the given options.
+    ///
+    /// Objects are always compared regardless of the order their fields were
+    /// inserted in.
+    pub fn loose_eq(&self, other: &Self, options: EqOptions) -> bool {
+        match (self, other) {
+            (Value::Integer(lhs), Value::Float(rhs)) | (Value::Float(rhs), Value::Integer(lhs))
+                if options.unify_numbers =>
+            {
+                let rhs = rhs.into_inner();
+
+                // `i64::MAX as f64` rounds up to 2^63, which doesn't fit an `i64`.
+                rhs.fract() == 0.0
+                    && rhs >= i64::MIN as f64
+                    && rhs < i64::MAX as f64
+                    && rhs as i64 == *lhs
+            }
+            (Value::Object(lhs), Value::Object(rhs)) => {
+                lhs.len() == rhs.len()
+                    && lhs.iter().all(|(key, lhs)| {
+                        rhs.get(key).map_or(false, |rhs| lhs.loose_eq(rhs, options))
+                    })
+            }
+            (Value::Array(lhs), Value::Array(rhs)) if options.ignore_array_order => {
+                if lhs.len() != rhs.len() {
+                    return false;
+                }
+
+                // Each element is matched with the first unmatched equal
+                // element, which is enough as the comparison is an equivalence.
+                let mut matched = vec![false; rhs.len()];
+                lhs.iter().all(|lhs| {
+                    let index =
+                        (0..rhs.len()).position(|i| !matched[i] && lhs.loose_eq(&rhs[i], options));
+
+                    match index {
+                        Some(i) => {
+                            matched[i] = true;
+                            true
+                        }
+                        None => false,
+                    }
+                })
+            }
+            (Value::Array(lhs), Value::Array(rhs)) => {
+                lhs.len() == rhs.len()
+                    && lhs
+                        .iter()
+                        .zip(rhs)
+                        .all(|(lhs, rhs)| lhs.loose_eq(rhs, options))
+            }
+            _ => self == other,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::value;
+
+    #[test]
+    fn unify_numbers() {
+        let options = EqOptions::default();
+
+        assert!(value!(1).loose_eq(&value!(1.0), options));
+        assert!(value!(1.0).loose_eq(&value!(1), options));
+        assert!(!value!(1).loose_eq(&value!(1.5), options));
+        assert!(!value!(1).loose_eq(&value!("1"), options));
+        assert!(value!({ "a": [1, { "b": 2.0 }] })
+            .loose_eq(&value!({ "a": [1.0, { "b": 2 }] }), options));
+        assert!(!Value::Integer(i64::MAX).loose_eq(&value!(9223372036854775807.0), options));
+    }
+
+    #[test]
+    fn strict_numbers() {
+        let options = EqOptions {
+            unify_numbers: false,
+            ..Default::default()
+        };
+
+        assert!(!value!(1).loose_eq(&value!(1.0), options));
+        assert!(value!(1).loose_eq(&value!(1), options));
+    }
+
+    #[test]
+    fn strict_partial_eq_is_unchanged() {
+        assert_ne!(value!(1), value!(1.0));
+        assert_ne!(value!([1]), value!([1.0]));
+    }
+
+    #[test]
+    fn ignore_array_order() {
+        let options = EqOptions {
+            ignore_array_order: true,
+            ..Default::default()
+        };
+
+        assert!(value!([1, "a", [2, 3]]).loose_eq(&value!([[3, 2], "a", 1.0]), options));
+        assert!(value!([1, 1, 2]).loose_eq(&value!([2, 1, 1]), options));
+        assert!(!value!([1, 1, 2]).loose_eq(&value!([2, 2, 1]), options));
+        assert!(!value!([1, 2]).loose_eq(&value!([1, 2, 2]), options));
+        assert!(!value!([1, 2]).loose_eq(&value!([2, 1]), EqOptions::default()));
+    }
+
+    #[test]
+    fn objects() {
+        let options = EqOptions::default();
+
+        assert!(value!({ "a": 1, "b": 2 }).loose_eq(&value!({ "b": 2, "a": 1 }), options));
+        assert!(!value!({ "a": 1 }).loose_eq(&value!({ "a": 1, "b": 2 }), options));
+        assert!(!value!({ "a": 1 }).loose_eq(&value!({ "b": 1 }), options));
+    }
+}
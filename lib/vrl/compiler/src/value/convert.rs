@@ -5,7 +5,7 @@ use bytes::Bytes;
 use chrono::{DateTime, Utc};
 use ordered_float::NotNan;
 use std::borrow::Cow;
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, HashMap};
 use std::convert::TryFrom;
 use std::iter::FromIterator;
 
@@ -211,6 +211,12 @@ impl Value {
         }
     }
 
+    /// Returns the bytes as a string, if they're valid UTF-8.
+    pub fn as_str(&self) -> Option<&str> {
+        self.as_bytes()
+            .and_then(|bytes| std::str::from_utf8(bytes).ok())
+    }
+
     pub fn try_bytes(self) -> Result<Bytes, Error> {
         match self {
             Value::Bytes(v) => Ok(v),
@@ -397,6 +403,11 @@ impl Value {
         }
     }
 
+    /// Iterates over the elements of an array, without cloning them.
+    pub fn iter_array(&self) -> Option<impl Iterator<Item = &Value>> {
+        self.as_array().map(|array| array.iter())
+    }
+
     pub fn try_array(self) -> Result<Vec<Value>, Error> {
         match self {
             Value::Array(v) => Ok(v),
@@ -420,6 +431,14 @@ impl FromIterator<Value> for Value {
     }
 }
 
+impl TryFrom<Value> for Vec<Value> {
+    type Error = Error;
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        value.try_array()
+    }
+}
+
 // Value::Object ---------------------------------------------------------------
 
 impl Value {
@@ -441,6 +460,13 @@ impl Value {
         }
     }
 
+    /// Iterates over the fields of an object in key order, without cloning
+    /// them.
+    pub fn iter_object(&self) -> Option<impl Iterator<Item = (&str, &Value)>> {
+        self.as_object()
+            .map(|object| object.iter().map(|(key, value)| (key.as_str(), value)))
+    }
+
     pub fn try_object(self) -> Result<BTreeMap<String, Value>, Error> {
         match self {
             Value::Object(v) => Ok(v),
@@ -458,12 +484,34 @@ impl From<BTreeMap<String, Value>> for Value {
     }
 }
 
+impl From<HashMap<String, Value>> for Value {
+    fn from(value: HashMap<String, Value>) -> Self {
+        value.into_iter().collect()
+    }
+}
+
 impl FromIterator<(String, Value)> for Value {
     fn from_iter<I: IntoIterator<Item = (String, Value)>>(iter: I) -> Self {
         Value::Object(iter.into_iter().collect::<BTreeMap<_, _>>())
     }
 }
 
+impl TryFrom<Value> for BTreeMap<String, Value> {
+    type Error = Error;
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        value.try_object()
+    }
+}
+
+impl TryFrom<Value> for HashMap<String, Value> {
+    type Error = Error;
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        Ok(value.try_object()?.into_iter().collect())
+    }
+}
+
 // Value::Timestamp ------------------------------------------------------------
 
 impl Value {
@@ -494,3 +542,165 @@ impl From<DateTime<Utc>> for Value {
         Value::Timestamp(v)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::value;
+
+    #[test]
+    fn from_hash_map() {
+        let mut map = HashMap::new();
+        map.insert("a".to_owned(), value!([1, 2]));
+        map.insert("b".to_owned(), value!("x"));
+
+        assert_eq!(Value::from(map), value!({ "a": [1, 2], "b": "x" }));
+    }
+
+    #[test]
+    fn try_into_hash_map() {
+        let map =
+            HashMap::<String, Value>::try_from(value!({ "a": 1, "b": { "c": true } })).unwrap();
+
+        assert_eq!(map.len(), 2);
+        assert_eq!(map.get("a"), Some(&value!(1)));
+        assert_eq!(map.get("b"), Some(&value!({ "c": true })));
+
+        assert_eq!(
+            HashMap::<String, Value>::try_from(value!([1, 2])),
+            Err(Error::Expected {
+                got: Kind::Array,
+                expected: Kind::Object,
+            })
+        );
+    }
+
+    #[test]
+    fn try_into_btree_map() {
+        let map = BTreeMap::<String, Value>::try_from(value!({ "a": [1, 2], "b": "x" })).unwrap();
+
+        assert_eq!(map.get("a"), Some(&value!([1, 2])));
+        assert_eq!(map.get("b"), Some(&value!("x")));
+    }
+
+    #[test]
+    fn try_into_vec() {
+        assert_eq!(
+            Vec::<Value>::try_from(value!([1, "two", null])).unwrap(),
+            vec![value!(1), value!("two"), Value::Null]
+        );
+
+        assert!(Vec::<Value>::try_from(value!({})).is_err());
+    }
+
+    #[test]
+    fn accessors() {
+        let timestamp = Utc::now();
+        let values = vec![
+            value!("foo"),
+            value!(1),
+            value!(1.5),
+            value!(true),
+            value!([1]),
+            value!({ "a": 1 }),
+            Value::Timestamp(timestamp),
+            Value::Null,
+        ];
+
+        for (i, value) in values.iter().enumerate() {
+            assert_eq!(value.as_str().is_some(), i == 0);
+            assert_eq!(value.as_bytes().is_some(), i == 0);
+            assert_eq!(value.as_integer().is_some(), i == 1);
+            assert_eq!(value.as_float().is_some(), i == 2);
+            assert_eq!(value.as_boolean().is_some(), i == 3);
+            assert_eq!(value.as_array().is_some(), i == 4);
+            assert_eq!(value.as_object().is_some(), i == 5);
+            assert_eq!(value.as_timestamp().is_some(), i == 6);
+            assert_eq!(value.as_null().is_some(), i == 7);
+        }
+
+        assert_eq!(values[0].as_str(), Some("foo"));
+        assert_eq!(values[1].as_integer(), Some(1));
+        assert_eq!(values[2].as_float(), Some(1.5));
+        assert_eq!(values[3].as_boolean(), Some(true));
+        assert_eq!(values[4].as_array(), Some(&[value!(1)][..]));
+        assert_eq!(values[5].as_object().unwrap().get("a"), Some(&value!(1)));
+        assert_eq!(values[6].as_timestamp(), Some(&timestamp));
+    }
+
+    #[test]
+    fn as_str_invalid_utf8() {
+        let value = Value::Bytes(Bytes::from_static(b"\xff"));
+
+        assert_eq!(value.as_str(), None);
+        assert!(value.as_bytes().is_some());
+    }
+
+    #[test]
+    fn iter_array() {
+        let value = value!([1, "two", [3]]);
+
+        assert_eq!(
+            value.iter_array().unwrap().collect::<Vec<_>>(),
+            vec![&value!(1), &value!("two"), &value!([3])]
+        );
+        assert_eq!(value!([]).iter_array().unwrap().count(), 0);
+    }
+
+    #[test]
+    fn iter_object() {
+        let value = value!({ "b": 2, "a": { "c": 3 } });
+
+        assert_eq!(
+            value.iter_object().unwrap().collect::<Vec<_>>(),
+            vec![("a", &value!({ "c": 3 })), ("b", &value!(2))]
+        );
+        assert_eq!(value!({}).iter_object().unwrap().count(), 0);
+    }
+
+    #[test]
+    fn iter_scalars() {
+        for value in &[value!(1), value!("foo"), value!(true), Value::Null] {
+            assert!(value.iter_array().is_none());
+            assert!(value.iter_object().is_none());
+        }
+
+        assert!(value!([1]).iter_object().is_none());
+        assert!(value!({ "a": 1 }).iter_array().is_none());
+    }
+
+    #[test]
+    fn try_accessors() {
+        assert_eq!(value!("foo").try_bytes(), Ok(Bytes::from("foo")));
+        assert_eq!(value!(1).try_integer(), Ok(1));
+        assert_eq!(value!(1.5).try_float(), Ok(1.5));
+        assert_eq!(value!(true).try_boolean(), Ok(true));
+        assert_eq!(value!([1]).try_array(), Ok(vec![value!(1)]));
+        assert_eq!(Value::Null.try_null(), Ok(()));
+
+        assert_eq!(
+            value!(1).try_bytes(),
+            Err(Error::Expected {
+                got: Kind::Integer,
+                expected: Kind::Bytes,
+            })
+        );
+        assert!(value!("1").try_integer().is_err());
+        assert!(value!(1).try_float().is_err());
+        assert!(value!("true").try_boolean().is_err());
+        assert!(value!({}).try_array().is_err());
+        assert!(value!([]).try_object().is_err());
+        assert!(value!("2021-01-01").try_timestamp().is_err());
+    }
+
+    #[test]
+    fn value_macro_nested() {
+        let value = value!({ "a": [1, 2], "b": "x" });
+
+        let mut inner = BTreeMap::new();
+        inner.insert("a".to_owned(), Value::Array(vec![1.into(), 2.into()]));
+        inner.insert("b".to_owned(), Value::Bytes("x".into()));
+
+        assert_eq!(value, Value::Object(inner));
+    }
+}
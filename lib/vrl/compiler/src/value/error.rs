@@ -54,6 +54,9 @@ pub enum Error {
 
     #[error("can't merge type {1} into {0}")]
     Merge(Kind, Kind),
+
+    #[error("invalid JSON: {0}")]
+    Json(String),
 }
 
 impl DiagnosticError for Error {
@@ -77,6 +80,7 @@ impl DiagnosticError for Error {
             Lt(..) => 313,
             Le(..) => 314,
             Merge(..) => 315,
+            Json(..) => 316,
         }
     }
 }
@@ -84,6 +88,7 @@ impl DiagnosticError for Error {
 impl From<Error> for ExpressionError {
     fn from(err: Error) -> Self {
         ExpressionError {
+            code: err.code(),
             message: err.message(),
             ..Default::default()
         }
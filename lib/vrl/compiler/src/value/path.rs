@@ -1,5 +1,5 @@
 use super::Value;
-use crate::path::Segment::*;
+use crate::path::{self, Segment, Segment::*};
 use crate::Path;
 use std::collections::BTreeMap;
 
@@ -37,6 +37,54 @@ impl Value {
 
         self
     }
+
+    /// Visit every node of the value, depth-first, starting with the value
+    /// itself.
+    ///
+    /// The visitor receives the path segments leading to the node (relative
+    /// to the value on which `walk` is called), and the node itself. Object
+    /// fields are visited in key order.
+    pub fn walk<F>(&self, visitor: &mut F)
+    where
+        F: FnMut(&[Segment], &Value),
+    {
+        self.walk_segments(&mut vec![], visitor)
+    }
+
+    fn walk_segments<F>(&self, segments: &mut Vec<Segment>, visitor: &mut F)
+    where
+        F: FnMut(&[Segment], &Value),
+    {
+        visitor(segments, self);
+
+        match self {
+            Value::Object(map) => {
+                for (key, value) in map {
+                    segments.push(Field(field(key)));
+                    value.walk_segments(segments, visitor);
+                    segments.pop();
+                }
+            }
+            Value::Array(array) => {
+                for (index, value) in array.iter().enumerate() {
+                    segments.push(Index(index as i64));
+                    value.walk_segments(segments, visitor);
+                    segments.pop();
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Convert an object key into a path field, quoting it if it can't be used as
+/// a regular path field.
+fn field(key: &str) -> path::Field {
+    if !key.is_empty() && key.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
+        path::Field::Regular(key.to_owned())
+    } else {
+        path::Field::Quoted(key.to_owned())
+    }
 }
 
 #[cfg(test)]
@@ -94,4 +142,50 @@ mod tests {
 
         assert_eq!(value.at_path(&path), object);
     }
+
+    #[test]
+    fn test_walk() {
+        let value = value!({ "a": [1, { "b": true }], "c d": "x", "e": {} });
+
+        let mut visited = vec![];
+        value.walk(&mut |segments, value| {
+            let path = Path::new_unchecked(segments.to_vec()).to_string();
+            visited.push((path, value.clone()));
+        });
+
+        assert_eq!(
+            visited,
+            vec![
+                (".".to_owned(), value),
+                (".a".to_owned(), value!([1, { "b": true }])),
+                (".a[0]".to_owned(), value!(1)),
+                (".a[1]".to_owned(), value!({ "b": true })),
+                (".a[1].b".to_owned(), value!(true)),
+                (r#"."c d""#.to_owned(), value!("x")),
+                (".e".to_owned(), value!({})),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_walk_paths_resolve() {
+        let value = value!({ "a": [1, { "b": [null, "c"] }], "d-e": { "f": 1.5 } });
+
+        let mut count = 0;
+        value.walk(&mut |segments, node| {
+            let path = Path::new_unchecked(segments.to_vec());
+            assert_eq!(value.get_by_path(&path), Some(node));
+            count += 1;
+        });
+
+        assert_eq!(count, 9);
+    }
+
+    #[test]
+    fn test_walk_scalar() {
+        let mut visited = vec![];
+        value!("foo").walk(&mut |segments, value| visited.push((segments.len(), value.clone())));
+
+        assert_eq!(visited, vec![(0, value!("foo"))]);
+    }
 }
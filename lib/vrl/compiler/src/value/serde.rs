@@ -1,12 +1,24 @@
-use super::Value;
+use super::{Error as ValueError, Value};
 use bytes::Bytes;
-use chrono::SecondsFormat;
-use serde::de::{Error, MapAccess, SeqAccess, Visitor};
+use chrono::{DateTime, SecondsFormat, Utc};
+use serde::de::{EnumAccess, Error, MapAccess, SeqAccess, Unexpected, VariantAccess, Visitor};
 use serde::{Deserialize, Serialize, Serializer};
 use std::collections::BTreeMap;
 use std::convert::TryFrom;
 use std::fmt;
 
+impl Value {
+    /// Parses a JSON document into a value, without going through a program.
+    pub fn from_json_str(json: &str) -> Result<Self, ValueError> {
+        serde_json::from_str(json).map_err(|err| ValueError::Json(err.to_string()))
+    }
+
+    /// Encodes the value as a JSON document, without going through a program.
+    pub fn to_json_str(&self) -> String {
+        serde_json::to_string(self).expect("values always serialize to JSON")
+    }
+}
+
 impl Serialize for Value {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -56,8 +68,16 @@ impl<'de> Deserialize<'de> for Value {
             }
 
             #[inline]
-            fn visit_u64<E>(self, value: u64) -> Result<Value, E> {
-                Ok((value as i64).into())
+            fn visit_u64<E>(self, value: u64) -> Result<Value, E>
+            where
+                E: serde::de::Error,
+            {
+                // Integers too large for an `i64` would wrap around, they're
+                // kept as the closest float instead.
+                match i64::try_from(value) {
+                    Ok(value) => Ok(value.into()),
+                    Err(_) => self.visit_f64(value as f64),
+                }
             }
 
             #[inline]
@@ -129,3 +149,314 @@ impl<'de> Deserialize<'de> for Value {
         deserializer.deserialize_any(ValueVisitor)
     }
 }
+
+/// A lossless representation of a value, for passing values between services.
+///
+/// The plain representation of a value maps bytes, timestamps and regular
+/// expressions to strings, which can't be told apart once deserialized. Here
+/// every value is tagged with its kind instead, with bytes encoded as base64
+/// and timestamps as RFC 3339 strings, so that a value always deserializes
+/// back to an equal one.
+///
+/// In JSON, `{ "foo": [1, t'2021-02-03T04:05:06Z'] }` is represented as
+/// `{"object":{"foo":{"array":[{"integer":1},{"timestamp":"2021-02-03T04:05:06Z"}]}}}`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Tagged(pub Value);
+
+const KINDS: &[&str] = &[
+    "bytes",
+    "integer",
+    "float",
+    "boolean",
+    "object",
+    "array",
+    "timestamp",
+    "regex",
+    "null",
+];
+
+struct TaggedRef<'a>(&'a Value);
+
+impl Serialize for TaggedRef<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        use Value::*;
+
+        match &self.0 {
+            Bytes(v) => {
+                serializer.serialize_newtype_variant("Value", 0, "bytes", &base64::encode(v))
+            }
+            Integer(v) => serializer.serialize_newtype_variant("Value", 1, "integer", v),
+            Float(v) => serializer.serialize_newtype_variant("Value", 2, "float", &v.into_inner()),
+            Boolean(v) => serializer.serialize_newtype_variant("Value", 3, "boolean", v),
+            Object(v) => {
+                let object = v
+                    .iter()
+                    .map(|(key, value)| (key, TaggedRef(value)))
+                    .collect::<BTreeMap<_, _>>();
+
+                serializer.serialize_newtype_variant("Value", 4, "object", &object)
+            }
+            Array(v) => {
+                let array = v.iter().map(TaggedRef).collect::<Vec<_>>();
+
+                serializer.serialize_newtype_variant("Value", 5, "array", &array)
+            }
+            Timestamp(v) => serializer.serialize_newtype_variant(
+                "Value",
+                6,
+                "timestamp",
+                &v.to_rfc3339_opts(SecondsFormat::AutoSi, true),
+            ),
+            Regex(v) => serializer.serialize_newtype_variant("Value", 7, "regex", v.as_str()),
+            Null => serializer.serialize_unit_variant("Value", 8, "null"),
+        }
+    }
+}
+
+impl Serialize for Tagged {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        TaggedRef(&self.0).serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Tagged {
+    fn deserialize<D>(deserializer: D) -> Result<Tagged, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct TaggedVisitor;
+
+        impl<'de> Visitor<'de> for TaggedVisitor {
+            type Value = Tagged;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("a value tagged with its kind")
+            }
+
+            fn visit_enum<A>(self, data: A) -> Result<Tagged, A::Error>
+            where
+                A: EnumAccess<'de>,
+            {
+                let (kind, variant) = data.variant::<String>()?;
+
+                let value = match kind.as_str() {
+                    "bytes" => {
+                        let encoded = variant.newtype_variant::<String>()?;
+                        let bytes = base64::decode(&encoded).map_err(|_| {
+                            Error::invalid_value(Unexpected::Str(&encoded), &"base64 bytes")
+                        })?;
+
+                        Value::Bytes(bytes.into())
+                    }
+                    "integer" => Value::Integer(variant.newtype_variant()?),
+                    "float" => {
+                        let float = variant.newtype_variant::<f64>()?;
+
+                        Value::try_from(float).map_err(|_| {
+                            Error::invalid_value(Unexpected::Float(float), &"a number")
+                        })?
+                    }
+                    "boolean" => Value::Boolean(variant.newtype_variant()?),
+                    "object" => Value::Object(
+                        variant
+                            .newtype_variant::<BTreeMap<String, Tagged>>()?
+                            .into_iter()
+                            .map(|(key, value)| (key, value.0))
+                            .collect(),
+                    ),
+                    "array" => Value::Array(
+                        variant
+                            .newtype_variant::<Vec<Tagged>>()?
+                            .into_iter()
+                            .map(|value| value.0)
+                            .collect(),
+                    ),
+                    "timestamp" => {
+                        let timestamp = variant.newtype_variant::<String>()?;
+                        let timestamp = DateTime::parse_from_rfc3339(&timestamp).map_err(|_| {
+                            Error::invalid_value(
+                                Unexpected::Str(&timestamp),
+                                &"an RFC 3339 timestamp",
+                            )
+                        })?;
+
+                        Value::Timestamp(timestamp.with_timezone(&Utc))
+                    }
+                    "regex" => {
+                        let pattern = variant.newtype_variant::<String>()?;
+                        let regex = regex::Regex::new(&pattern).map_err(|_| {
+                            Error::invalid_value(Unexpected::Str(&pattern), &"a regular expression")
+                        })?;
+
+                        Value::Regex(regex.into())
+                    }
+                    "null" => {
+                        variant.unit_variant()?;
+
+                        Value::Null
+                    }
+                    kind => return Err(Error::unknown_variant(kind, KINDS)),
+                };
+
+                Ok(Tagged(value))
+            }
+        }
+
+        deserializer.deserialize_enum("Value", KINDS, TaggedVisitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::value;
+    use chrono::TimeZone;
+
+    #[test]
+    fn json_round_trip() {
+        let value = value!({
+            "bytes": "foo",
+            "integer": (-1),
+            "float": 1.0,
+            "boolean": true,
+            "null": null,
+            "array": [1, 2.5, "three", [null], {}],
+            "object": { "nested": { "deeply": [{ "a": 1 }] } },
+        });
+
+        let json = serde_json::to_string(&value).unwrap();
+        let decoded: Value = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(decoded, value);
+    }
+
+    #[test]
+    fn integers_and_floats_stay_distinct() {
+        let json = serde_json::to_string(&value!([1, 1.0])).unwrap();
+
+        assert_eq!(json, "[1,1.0]");
+        assert_eq!(
+            serde_json::from_str::<Value>(&json).unwrap(),
+            value!([1, 1.0])
+        );
+    }
+
+    #[test]
+    fn serialize_timestamp() {
+        let value = Value::Timestamp(Utc.ymd(2021, 2, 3).and_hms_milli(4, 5, 6, 789));
+
+        assert_eq!(
+            serde_json::to_string(&value).unwrap(),
+            r#""2021-02-03T04:05:06.789Z""#
+        );
+    }
+
+    #[test]
+    fn json_str_round_trip() {
+        let json = r#"{"a":[1,2.5,null,{"b":"c"}],"d":{"e":{"f":-1}},"g":true,"h":null}"#;
+        let value = Value::from_json_str(json).unwrap();
+
+        assert_eq!(
+            value,
+            value!({
+                "a": [1, 2.5, null, { "b": "c" }],
+                "d": { "e": { "f": (-1) } },
+                "g": true,
+                "h": null,
+            })
+        );
+        assert_eq!(value.to_json_str(), json);
+        assert_eq!(Value::from_json_str(&value.to_json_str()).unwrap(), value);
+    }
+
+    #[test]
+    fn from_json_str_scalars() {
+        assert_eq!(Value::from_json_str("null").unwrap(), Value::Null);
+        assert_eq!(Value::from_json_str("1").unwrap(), value!(1));
+        assert_eq!(Value::from_json_str("1.0").unwrap(), value!(1.0));
+        assert_eq!(Value::from_json_str(r#""foo""#).unwrap(), value!("foo"));
+    }
+
+    #[test]
+    fn from_json_str_malformed() {
+        for json in &["", "{", r#"{"a":}"#, "[1,]", "nul", r#"{"a":1} x"#] {
+            match Value::from_json_str(json) {
+                Err(ValueError::Json(_)) => {}
+                result => panic!("expected a JSON error for {:?}, got {:?}", json, result),
+            }
+        }
+    }
+
+    #[test]
+    fn deserialize_large_unsigned_integer() {
+        assert_eq!(
+            serde_json::from_str::<Value>("9223372036854775807").unwrap(),
+            Value::Integer(i64::MAX)
+        );
+        assert_eq!(
+            serde_json::from_str::<Value>("18446744073709551615").unwrap(),
+            value!(18446744073709551615.0)
+        );
+    }
+
+    #[test]
+    fn tagged_json_round_trip() {
+        let value = value!({
+            "bytes": "foo",
+            "integer": (-1),
+            "float": 1.0,
+            "boolean": true,
+            "null": null,
+            "array": [1, 2.5, "three", [null], {}],
+            "object": { "nested": { "deeply": [{ "a": 1 }] } },
+        });
+
+        let json = serde_json::to_string(&Tagged(value.clone())).unwrap();
+        let decoded: Tagged = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(decoded.0, value);
+    }
+
+    #[test]
+    fn tagged_bytes_round_trip() {
+        let value = Value::Bytes(vec![0, 159, 146, 150, 255].into());
+
+        let json = serde_json::to_string(&Tagged(value.clone())).unwrap();
+        assert_eq!(json, r#"{"bytes":"AJ+Slv8="}"#);
+        assert_eq!(serde_json::from_str::<Tagged>(&json).unwrap().0, value);
+    }
+
+    #[test]
+    fn tagged_timestamp_round_trip() {
+        let value = Value::Timestamp(Utc.ymd(2021, 2, 3).and_hms_nano(4, 5, 6, 789_012_345));
+
+        let json = serde_json::to_string(&Tagged(value.clone())).unwrap();
+        assert_eq!(json, r#"{"timestamp":"2021-02-03T04:05:06.789012345Z"}"#);
+        assert_eq!(serde_json::from_str::<Tagged>(&json).unwrap().0, value);
+    }
+
+    #[test]
+    fn tagged_kinds_stay_distinct() {
+        let value = value!(["2021-02-03T04:05:06Z", "AJ+Slv8=", 1, 1.0, null]);
+
+        let json = serde_json::to_string(&Tagged(value.clone())).unwrap();
+        assert_eq!(
+            json,
+            r#"{"array":[{"bytes":"MjAyMS0wMi0wM1QwNDowNTowNlo="},{"bytes":"QUorU2x2OD0="},{"integer":1},{"float":1.0},"null"]}"#
+        );
+        assert_eq!(serde_json::from_str::<Tagged>(&json).unwrap().0, value);
+    }
+
+    #[test]
+    fn tagged_unknown_kind() {
+        let error = serde_json::from_str::<Tagged>(r#"{"string":"foo"}"#).unwrap_err();
+
+        assert!(error.to_string().starts_with("unknown variant `string`"));
+    }
+}
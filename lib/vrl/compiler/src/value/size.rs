@@ -0,0 +1,116 @@
+use super::Value;
+use std::mem::size_of;
+
+impl Value {
+    /// Returns an approximation of the number of bytes used to store this
+    /// value in memory.
+    ///
+    /// Every value accounts for its own inline size, to which strings add
+    /// their byte length, and containers add the estimates of their
+    /// elements (and, for objects, the storage of their keys).
+    pub fn size_estimate(&self) -> usize {
+        let heap = match self {
+            Value::Bytes(v) => v.len(),
+            Value::Regex(v) => v.as_str().len(),
+            Value::Array(v) => v.iter().map(Value::size_estimate).sum(),
+            Value::Object(v) => v
+                .iter()
+                .map(|(k, v)| size_of::<String>() + k.len() + v.size_estimate())
+                .sum(),
+            Value::Integer(_)
+            | Value::Float(_)
+            | Value::Boolean(_)
+            | Value::Timestamp(_)
+            | Value::Null => 0,
+        };
+
+        size_of::<Value>() + heap
+    }
+
+    /// Returns whether objects and arrays are nested in this value more than
+    /// `max_depth` levels deep, with scalars nested zero levels deep.
+    ///
+    /// Containers aren't inspected deeper than the limit, so that this doesn't
+    /// recurse too deep on values nested arbitrarily deep.
+    pub fn exceeds_depth(&self, max_depth: usize) -> bool {
+        match self {
+            Value::Array(v) => max_depth == 0 || v.iter().any(|v| v.exceeds_depth(max_depth - 1)),
+            Value::Object(v) => {
+                max_depth == 0 || v.values().any(|v| v.exceeds_depth(max_depth - 1))
+            }
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeMap;
+
+    #[test]
+    fn empty_values_are_constant() {
+        let empty = size_of::<Value>();
+
+        assert_eq!(Value::Null.size_estimate(), empty);
+        assert_eq!(Value::from("").size_estimate(), empty);
+        assert_eq!(Value::Array(vec![]).size_estimate(), empty);
+        assert_eq!(Value::Object(BTreeMap::new()).size_estimate(), empty);
+    }
+
+    #[test]
+    fn scalars_share_inline_size() {
+        assert_eq!(Value::from(1).size_estimate(), Value::Null.size_estimate());
+        assert_eq!(
+            Value::from(true).size_estimate(),
+            Value::from(1.5).size_estimate()
+        );
+    }
+
+    #[test]
+    fn strings_grow_with_length() {
+        let short = Value::from("foo").size_estimate();
+        let long = Value::from("foobarbaz").size_estimate();
+
+        assert_eq!(long - short, 6);
+    }
+
+    #[test]
+    fn containers_grow_with_elements() {
+        let one = Value::Array(vec![Value::from("foo")]);
+        let ten = Value::Array(vec![Value::from("foo"); 10]);
+
+        assert_eq!(
+            ten.size_estimate() - size_of::<Value>(),
+            (one.size_estimate() - size_of::<Value>()) * 10
+        );
+
+        let mut map = BTreeMap::new();
+        map.insert("foo".to_owned(), Value::from(1));
+        let small = Value::Object(map.clone()).size_estimate();
+
+        map.insert("bar".to_owned(), Value::Array(vec![Value::from(1); 5]));
+        let large = Value::Object(map).size_estimate();
+
+        assert!(large > small);
+        assert_eq!(
+            large - small,
+            size_of::<String>() + 3 + size_of::<Value>() * 6
+        );
+    }
+
+    #[test]
+    fn depth() {
+        let scalar = Value::from("foo");
+        assert!(!scalar.exceeds_depth(0));
+
+        let nested = Value::Array(vec![Value::Array(vec![]), Value::Array(vec![scalar])]);
+        assert!(nested.exceeds_depth(1));
+        assert!(!nested.exceeds_depth(2));
+
+        let mut map = BTreeMap::new();
+        map.insert("foo".to_owned(), nested);
+        assert!(Value::Object(map.clone()).exceeds_depth(2));
+        assert!(!Value::Object(map).exceeds_depth(3));
+    }
+}
@@ -2,12 +2,16 @@ pub mod prelude;
 mod runtime;
 
 pub use compiler::{
-    function,
+    analysis::{self, Analysis, Annotation},
+    clock, enrichment, function, hook,
+    optimization::{self, Optimization},
     path::{self, Path},
-    state, value, Context, Expression, Function, Program, Target, Value,
+    state, value, Context, Cost, Expression, Function, PathSegment, Program, Schema, Target,
+    TargetPaths, Value,
 };
 pub use diagnostic;
-pub use runtime::{Runtime, RuntimeResult};
+use diagnostic::{Diagnostic, DiagnosticList};
+pub use runtime::{Abort, Runtime, RuntimeResult};
 
 /// Compile a given source into the final [`Program`].
 pub fn compile(source: &str, fns: &[Box<dyn Function>]) -> compiler::Result {
@@ -20,7 +24,172 @@ pub fn compile_with_state(
     fns: &[Box<dyn Function>],
     state: &mut state::Compiler,
 ) -> compiler::Result {
-    let ast = parser::parse(source).map_err(|err| vec![Box::new(err) as _])?;
+    let ast = parser::parse_with_max_depth(source, state.max_depth())
+        .map_err(|err| vec![Box::new(err) as _])?;
 
     compiler::compile_with_state(ast, fns, state)
 }
+
+/// Similar to [`compile_with_state`], except that warnings are returned as
+/// well as errors, even if the program compiles.
+///
+/// The program is `None` if the source has any errors.
+pub fn compile_detailed(
+    source: &str,
+    fns: &[Box<dyn Function>],
+    state: &mut state::Compiler,
+) -> (Option<Program>, DiagnosticList) {
+    match parser::parse_with_max_depth(source, state.max_depth()) {
+        Ok(ast) => compiler::compile_detailed(ast, fns, state),
+        Err(err) => (
+            None,
+            Diagnostic::from(Box::new(err) as Box<dyn diagnostic::DiagnosticError>).into(),
+        ),
+    }
+}
+
+/// Similar to [`compile_detailed`], except that each compiled expression is
+/// annotated with its type definition. See [`Analysis`].
+pub fn analyze(source: &str, fns: &[Box<dyn Function>], state: &mut state::Compiler) -> Analysis {
+    match parser::parse_with_max_depth(source, state.max_depth()) {
+        Ok(ast) => compiler::analyze(ast, fns, state),
+        Err(err) => Analysis {
+            program: None,
+            diagnostics: Diagnostic::from(Box::new(err) as Box<dyn diagnostic::DiagnosticError>)
+                .into(),
+            annotations: vec![],
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn compile_detailed_returns_warnings() {
+        let mut state = state::Compiler::default();
+        let (program, diagnostics) = compile_detailed("\"unused\"\n.foo = 1\n2", &[], &mut state);
+
+        assert!(program.is_some());
+        assert_eq!(diagnostics.len(), 1);
+        assert!(diagnostics[0].is_warning());
+        assert_eq!(diagnostics[0].message(), "unused literal");
+
+        // Warnings don't prevent the program from compiling.
+        assert!(compile("\"unused\"\n.foo = 1", &[]).is_ok());
+    }
+
+    #[test]
+    fn compile_detailed_returns_errors_and_warnings() {
+        let mut state = state::Compiler::default();
+        let (program, diagnostics) =
+            compile_detailed("\"unused\"\n.foo = undefined(1)", &[], &mut state);
+
+        assert!(program.is_none());
+        assert_eq!(diagnostics.errors().len(), 1);
+        assert_eq!(diagnostics.warnings().len(), 1);
+    }
+
+    #[test]
+    fn compile_detailed_unused_variable() {
+        let mut state = state::Compiler::default();
+        let (program, diagnostics) = compile_detailed("foo = 1\n.bar = 2", &[], &mut state);
+
+        assert!(program.is_some());
+        assert_eq!(diagnostics.len(), 1);
+        assert!(diagnostics[0].is_warning());
+        assert_eq!(diagnostics[0].message(), "unused variable");
+
+        let mut state = state::Compiler::default();
+        let (_, diagnostics) = compile_detailed("foo = 1\nfoo = 2\n.bar = 2", &[], &mut state);
+        assert_eq!(diagnostics.len(), 1);
+
+        let mut state = state::Compiler::default();
+        let (_, diagnostics) = compile_detailed("foo = 1\n.bar = foo", &[], &mut state);
+        assert!(diagnostics.is_empty());
+    }
+
+    #[test]
+    fn compile_detailed_unused_expression() {
+        let mut state = state::Compiler::default();
+        let (program, diagnostics) =
+            compile_detailed("if true { .foo\n.bar = 1 }\n.baz = 2", &[], &mut state);
+
+        assert!(program.is_some());
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].message(), "unused expression");
+    }
+
+    #[test]
+    fn compile_detailed_parse_error() {
+        let mut state = state::Compiler::default();
+        let (program, diagnostics) = compile_detailed(".foo = ", &[], &mut state);
+
+        assert!(program.is_none());
+        assert!(diagnostics.has_errors());
+        assert!(!diagnostics.has_warnings());
+    }
+
+    #[test]
+    fn compile_deeply_nested() {
+        let sources = vec![
+            format!("{}1{}", "[".repeat(10_000), "]".repeat(10_000)),
+            format!("{}1{}", "(".repeat(10_000), ")".repeat(10_000)),
+            format!("{}true", "!".repeat(1_000)),
+            vec!["1"; 1_000].join(" + "),
+        ];
+
+        for source in &sources {
+            let mut state = state::Compiler::default();
+            let (program, diagnostics) = compile_detailed(source, &[], &mut state);
+
+            assert!(program.is_none());
+            assert!(diagnostics.has_errors());
+        }
+
+        let nested = format!("{}1{}", "[".repeat(100), "]".repeat(100));
+        assert!(compile(&nested, &[]).is_ok());
+    }
+
+    #[test]
+    fn compile_truncated() {
+        let source = r#".a = .b[1 + "c"] ?? { "d": [t'2021-01-01T00:00:00Z', r'e', s'f'] }
+if .g { .h = !(.i || 5 % 0) } else { .j = -1 * "k" }"#;
+
+        // None of the prefixes of the program panics, or loops forever, even
+        // when constant operations are resolved at compile-time.
+        for (end, _) in source.char_indices() {
+            let mut state = state::Compiler::default().with_optimization(Optimization::Full);
+            let _ = compile_detailed(&source[..end], &[], &mut state);
+        }
+    }
+
+    #[test]
+    fn compile_max_depth() {
+        let compile = |source, max_depth| {
+            let mut state = state::Compiler::default().with_max_depth(max_depth);
+            compile_with_state(source, &[], &mut state)
+        };
+
+        assert!(compile("[[1]]", 3).is_ok());
+        assert!(compile("1 + 2 + 3", 3).is_ok());
+
+        // Too many delimiters are rejected by the parser, and expressions
+        // nested otherwise by the compiler.
+        let errors = compile("[[[[1]]]]", 3).unwrap_err();
+        assert_eq!(errors[0].code(), 211);
+        assert_eq!(
+            errors[0].labels()[0].message,
+            "more than 3 levels of nesting"
+        );
+
+        let errors = compile("!!!!true", 3).unwrap_err();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].code(), 112);
+        assert_eq!(
+            errors[0].labels()[0].message,
+            "this expression is nested more than 3 levels deep"
+        );
+    }
+}
@@ -1,4 +1,5 @@
 use crate::{state, Context, Path, Program, Target, Value};
+use compiler::ExpressionError;
 use std::{error::Error, fmt};
 
 pub type RuntimeResult = Result<Value, Abort>;
@@ -9,12 +10,62 @@ pub struct Runtime {
 }
 
 /// The error raised if the runtime is aborted.
-#[derive(Debug, Clone, PartialEq, Eq)]
-pub struct Abort(String);
+#[derive(Debug, Clone, PartialEq)]
+pub struct Abort {
+    code: usize,
+    message: String,
+    payload: Option<Value>,
+}
+
+impl Abort {
+    fn new(code: usize, message: impl Into<String>) -> Self {
+        Self {
+            code,
+            message: message.into(),
+            payload: None,
+        }
+    }
+
+    /// The stable code identifying why the runtime aborted:
+    ///
+    /// - `3xx` for errors of operations on values, e.g. `305` when dividing by
+    ///   zero
+    /// - `700` when the program is aborted using the `abort` function
+    /// - `701` when the target isn't an object
+    /// - `702` and `703` when the target violates the input or output schema
+    /// - `704` when assigning a value nested deeper than the runtime allows
+    /// - `705` for the other errors returned by functions
+    pub fn code(&self) -> usize {
+        self.code
+    }
+
+    /// The message describing why the runtime aborted.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// The structured payload attached to the error, if any.
+    ///
+    /// This is set when the program is aborted using the `abort` function
+    /// with a `payload` argument.
+    pub fn payload(&self) -> Option<&Value> {
+        self.payload.as_ref()
+    }
+}
+
+impl From<ExpressionError> for Abort {
+    fn from(err: ExpressionError) -> Self {
+        Self {
+            code: err.code,
+            message: err.message,
+            payload: err.payload,
+        }
+    }
+}
 
 impl fmt::Display for Abort {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.write_str(&self.0)
+        f.write_str(&self.message)
     }
 }
 
@@ -29,6 +80,22 @@ impl Runtime {
         Self { state }
     }
 
+    /// Save the variables of the runtime, see [`state::Runtime::checkpoint`].
+    pub fn checkpoint(&self) -> state::RuntimeCheckpoint {
+        self.state.checkpoint()
+    }
+
+    /// Restore the variables saved by [`Runtime::checkpoint`].
+    pub fn rollback_to(&mut self, checkpoint: state::RuntimeCheckpoint) {
+        self.state.rollback_to(checkpoint)
+    }
+
+    /// The value of the variable with the given name, as assigned by the
+    /// programs resolved so far, if any.
+    pub fn variable(&self, name: &str) -> Option<&Value> {
+        self.state.variable(&parser::ast::Ident::new(name))
+    }
+
     /// Given the provided [`Target`], resolve the provided [`Program`] to
     /// completion.
     pub fn resolve(&mut self, target: &mut dyn Target, program: &Program) -> RuntimeResult {
@@ -36,29 +103,702 @@ impl Runtime {
         //
         // VRL technically supports any `Value` object as the root, but the
         // assumption is people are expected to use it to query objects.
-        match target.get(&Path::root()) {
-            Ok(Some(Value::Object(_))) => {}
+        let root = match target.get(&Path::root()) {
+            Ok(Some(value @ Value::Object(_))) => value,
             Ok(Some(value)) => {
-                return Err(Abort(format!(
-                    "target must be a valid object, got {}: {}",
-                    value.kind(),
-                    value
-                )))
+                return Err(Abort::new(
+                    701,
+                    format!(
+                        "target must be a valid object, got {}: {}",
+                        value.kind(),
+                        value
+                    ),
+                ))
+            }
+            Ok(None) => return Err(Abort::new(701, "expected target object, got nothing")),
+            Err(err) => {
+                return Err(Abort::new(
+                    701,
+                    format!("error querying target object: {}", err),
+                ))
             }
-            Ok(None) => return Err(Abort("expected target object, got nothing".to_owned())),
-            Err(err) => return Err(Abort(format!("error querying target object: {}", err))),
         };
 
+        if let Some(schema) = program.input_schema() {
+            schema
+                .validate(&root)
+                .map_err(|err| Abort::new(702, format!("input schema violation: {}", err)))?;
+        }
+
+        // The values of the repeated function calls of another program can't
+        // be reused.
+        self.state.clear_calls();
+
         let mut context = Context::new(target, &mut self.state);
+        let mut value = Value::Null;
 
-        let mut values = program
+        for expr in program.iter() {
+            value = expr.resolve(&mut context)?;
+        }
+
+        if let Some(schema) = program.output_schema() {
+            let root = target
+                .get(&Path::root())
+                .map_err(|err| Abort::new(701, format!("error querying target object: {}", err)))?
+                .unwrap_or(Value::Null);
+
+            schema
+                .validate(&root)
+                .map_err(|err| Abort::new(703, format!("output schema violation: {}", err)))?;
+        }
+
+        Ok(value)
+    }
+
+    /// Resolve the provided [`Program`] against each of the provided
+    /// [`Target`]s, in order, returning the result for each of them.
+    ///
+    /// The runtime state is cleared between targets, so that variables
+    /// assigned for one target aren't visible to the next, while its memory,
+    /// and its enrichment cache, are reused. If `stop_on_abort` is `true`, no
+    /// more targets are resolved after the first one that aborts, and the
+    /// returned results end with its error.
+    pub fn resolve_batch<T: Target>(
+        &mut self,
+        targets: &mut [T],
+        program: &Program,
+        stop_on_abort: bool,
+    ) -> Vec<RuntimeResult> {
+        let mut results = Vec::with_capacity(targets.len());
+
+        for target in targets {
+            self.state.clear();
+
+            let result = self.resolve(target, program);
+            let aborted = result.is_err();
+            results.push(result);
+
+            if aborted && stop_on_abort {
+                break;
+            }
+        }
+
+        results
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::{kind, ArgumentList, Compiled, Example, Parameter, Resolved, TypeDef};
+    use crate::{
+        enrichment::EnrichmentCache, hook::FunctionHook, Expression, Function, Optimization,
+    };
+    use compiler::{value, Schema};
+    use std::convert::TryFrom;
+    use std::str::FromStr;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
+
+    fn resolve_each(targets: &[Value], program: &Program) -> Vec<RuntimeResult> {
+        targets
             .iter()
-            .map(|expr| {
-                expr.resolve(&mut context)
-                    .map_err(|err| Abort(err.to_string()))
+            .map(|target| Runtime::default().resolve(&mut target.clone(), program))
+            .collect()
+    }
+
+    #[test]
+    fn batch_matches_resolve() {
+        let program = crate::compile(".seen = true\n.id", &[]).unwrap();
+        let targets = vec![value!({ "id": 1 }), value!({ "id": "two" }), value!({})];
+
+        let want = resolve_each(&targets, &program);
+
+        let mut batch = targets;
+        let got = Runtime::default().resolve_batch(&mut batch, &program, false);
+
+        assert_eq!(got, want);
+        assert_eq!(got, vec![Ok(value!(1)), Ok(value!("two")), Ok(Value::Null)]);
+        assert_eq!(
+            batch,
+            vec![
+                value!({ "id": 1, "seen": true }),
+                value!({ "id": "two", "seen": true }),
+                value!({ "seen": true }),
+            ]
+        );
+    }
+
+    #[test]
+    fn batch_does_not_leak_variables() {
+        let program = crate::compile("if .first == true { x = \"set\" }\nx", &[]).unwrap();
+        let targets = vec![value!({ "first": true }), value!({ "first": false })];
+
+        let mut batch = targets.clone();
+        let got = Runtime::default().resolve_batch(&mut batch, &program, false);
+
+        assert_eq!(got, resolve_each(&targets, &program));
+        assert_eq!(got, vec![Ok(value!("set")), Ok(Value::Null)]);
+    }
+
+    #[test]
+    fn batch_stop_on_abort() {
+        let program = crate::compile(".", &[]).unwrap();
+        let mut targets = vec![value!({}), value!("not an object"), value!({})];
+
+        let got = Runtime::default().resolve_batch(&mut targets, &program, false);
+        assert_eq!(got.len(), 3);
+        assert!(got[1].is_err());
+
+        let got = Runtime::default().resolve_batch(&mut targets, &program, true);
+        assert_eq!(got.len(), 2);
+        assert!(got[1].is_err());
+    }
+
+    #[test]
+    fn input_schema_violation_aborts_before_running() {
+        let schema = Schema::try_from(value!({
+            "type": "object",
+            "required": ["id"],
+            "properties": { "id": { "type": "integer" } },
+        }))
+        .unwrap();
+        let program = crate::compile(".seen = true", &[])
+            .unwrap()
+            .with_input_schema(schema);
+
+        let mut target = value!({ "id": "one" });
+        let got = Runtime::default().resolve(&mut target, &program);
+
+        assert_eq!(
+            got,
+            Err(Abort::new(
+                702,
+                r#"input schema violation: .id: expected integer, got "string""#
+            ))
+        );
+        assert_eq!(target, value!({ "id": "one" }));
+
+        let mut target = value!({ "id": 1 });
+        assert!(Runtime::default().resolve(&mut target, &program).is_ok());
+    }
+
+    #[test]
+    fn output_schema_violation_aborts() {
+        let schema = Schema::try_from(value!({
+            "properties": { "message": { "type": "string" } },
+        }))
+        .unwrap();
+        let program = crate::compile(".message = 1", &[])
+            .unwrap()
+            .with_output_schema(schema);
+
+        let mut target = value!({ "message": "foo" });
+        let got = Runtime::default().resolve(&mut target, &program);
+
+        assert_eq!(
+            got,
+            Err(Abort::new(
+                703,
+                r#"output schema violation: .message: expected string, got "integer""#
+            ))
+        );
+    }
+
+    #[test]
+    fn max_value_depth() {
+        let runtime = || Runtime::new(state::Runtime::default().with_max_value_depth(3));
+
+        let program = crate::compile(".a = [[1]]\nx.b = [1]\n.c = x", &[]).unwrap();
+        let mut target = value!({});
+        let mut unlimited = value!({});
+
+        assert_eq!(
+            runtime().resolve(&mut target, &program),
+            Ok(value!({ "b": [1] }))
+        );
+        Runtime::default()
+            .resolve(&mut unlimited, &program)
+            .unwrap();
+        assert_eq!(target.to_string(), unlimited.to_string());
+
+        for source in &[".a.b = [[1]]", "x = [[[1]]]\n.a = x", "x.a.b = [[1]]"] {
+            let program = crate::compile(source, &[]).unwrap();
+            let mut target = value!({});
+
+            assert_eq!(
+                runtime().resolve(&mut target, &program),
+                Err(Abort::new(
+                    704,
+                    "can't assign a value nested more than 3 levels deep"
+                )),
+                "{}",
+                source
+            );
+            assert_eq!(target, value!({}), "{}", source);
+        }
+    }
+
+    #[test]
+    fn variables() {
+        let mut runtime = Runtime::default();
+        let program = crate::compile("answer = 42\n.answer = answer", &[]).unwrap();
+
+        runtime.resolve(&mut value!({}), &program).unwrap();
+        assert_eq!(runtime.variable("answer"), Some(&value!(42)));
+        assert_eq!(runtime.variable("question"), None);
+
+        runtime.resolve_batch(&mut [value!({})], &crate::compile(".", &[]).unwrap(), false);
+        assert_eq!(runtime.variable("answer"), None);
+    }
+
+    #[test]
+    fn error_codes() {
+        let code = |source: &str, mut target: Value| {
+            let program = crate::compile(source, &[]).unwrap();
+            Runtime::default()
+                .resolve(&mut target, &program)
+                .unwrap_err()
+                .code()
+        };
+
+        assert_eq!(code("10 % 0", value!({})), 305);
+        assert_eq!(code("x = 0\n.a = 1 % x", value!({})), 305);
+        assert_eq!(code(".", value!([])), 701);
+        assert_eq!(code(".", value!("foo")), 701);
+    }
+
+    /// A function returning its argument, under the given name.
+    #[derive(Debug)]
+    struct Identity(&'static str);
+
+    impl Function for Identity {
+        fn identifier(&self) -> &'static str {
+            self.0
+        }
+
+        fn examples(&self) -> &'static [Example] {
+            &[]
+        }
+
+        fn compile(&self, mut arguments: ArgumentList) -> Compiled {
+            Ok(Box::new(IdentityFn(arguments.required("value"))))
+        }
+
+        fn parameters(&self) -> &'static [Parameter] {
+            &[Parameter {
+                keyword: "value",
+                kind: kind::ANY,
+                required: true,
+            }]
+        }
+    }
+
+    #[derive(Debug, Clone)]
+    struct IdentityFn(Box<dyn Expression>);
+
+    impl Expression for IdentityFn {
+        fn resolve(&self, ctx: &mut Context) -> Resolved {
+            self.0.resolve(ctx)
+        }
+
+        fn type_def(&self, state: &state::Compiler) -> TypeDef {
+            self.0.type_def(state)
+        }
+    }
+
+    #[test]
+    fn disallowed_functions() {
+        use crate::diagnostic::Span;
+
+        let fns: Vec<Box<dyn Function>> =
+            vec![Box::new(Identity("first")), Box::new(Identity("second"))];
+        let source = ".a = first(1)\n.b = second(2)";
+
+        let mut state = state::Compiler::default().with_denied_functions(vec!["second"]);
+        let errors = crate::compile_with_state(source, &fns, &mut state).unwrap_err();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].code(), 111);
+        assert_eq!(errors[0].labels()[0].span, Span::new(19, 25));
+
+        let mut state = state::Compiler::default().with_allowed_functions(vec!["first"]);
+        let errors = crate::compile_with_state(source, &fns, &mut state).unwrap_err();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].labels()[0].span, Span::new(19, 25));
+
+        let mut state = state::Compiler::default().with_allowed_functions(vec!["first", "second"]);
+        assert!(crate::compile_with_state(source, &fns, &mut state).is_ok());
+    }
+
+    #[derive(Debug, Default)]
+    struct RecordingHook(Arc<Mutex<Vec<String>>>);
+
+    impl FunctionHook for RecordingHook {
+        fn enter(&self, function: &str) {
+            self.0.lock().unwrap().push(format!("enter {}", function));
+        }
+
+        fn exit(&self, function: &str, _: Duration) {
+            self.0.lock().unwrap().push(format!("exit {}", function));
+        }
+    }
+
+    #[test]
+    fn function_hook() {
+        let fns: Vec<Box<dyn Function>> =
+            vec![Box::new(Identity("first")), Box::new(Identity("second"))];
+        let program = crate::compile(".a = first(1)\nsecond(first(.a))", &fns).unwrap();
+
+        let hook = RecordingHook::default();
+        let calls = hook.0.clone();
+        let mut runtime = Runtime::new(state::Runtime::default().with_function_hook(hook));
+
+        let mut target = value!({});
+        let got = runtime.resolve(&mut target, &program);
+
+        assert_eq!(got, Ok(value!(1)));
+        assert_eq!(target, value!({ "a": 1 }));
+        assert_eq!(
+            *calls.lock().unwrap(),
+            vec![
+                "enter first",
+                "exit first",
+                "enter second",
+                "enter first",
+                "exit first",
+                "exit second",
+            ]
+        );
+
+        // The hook doesn't change the results.
+        let mut unhooked = value!({});
+        assert_eq!(Runtime::default().resolve(&mut unhooked, &program), got);
+        assert_eq!(unhooked, target);
+    }
+
+    /// A pure function returning its argument.
+    #[derive(Debug)]
+    struct Pure;
+
+    impl Function for Pure {
+        fn identifier(&self) -> &'static str {
+            "pure"
+        }
+
+        fn is_pure(&self) -> bool {
+            true
+        }
+
+        fn examples(&self) -> &'static [Example] {
+            &[]
+        }
+
+        fn compile(&self, arguments: ArgumentList) -> Compiled {
+            Identity("pure").compile(arguments)
+        }
+
+        fn parameters(&self) -> &'static [Parameter] {
+            Identity("pure").parameters()
+        }
+    }
+
+    fn resolve(program: &Program) -> RuntimeResult {
+        Runtime::default().resolve(&mut value!({}), program)
+    }
+
+    fn compile_optimized(source: &str, fns: &[Box<dyn Function>]) -> Program {
+        let mut state = state::Compiler::default().with_optimization(Optimization::Full);
+        crate::compile_with_state(source, fns, &mut state).unwrap()
+    }
+
+    #[test]
+    fn constant_folding() {
+        let folded = compile_optimized("1 + 2 * 3", &[]);
+        let unfolded = crate::compile("1 + 2 * 3", &[]).unwrap();
+
+        assert_eq!(folded[0].as_value(), Some(value!(7)));
+        assert_eq!(unfolded[0].as_value(), None);
+        assert_eq!(resolve(&folded), resolve(&unfolded));
+        assert_eq!(resolve(&folded), Ok(value!(7)));
+    }
+
+    #[test]
+    fn constant_folding_function_calls() {
+        let fns: Vec<Box<dyn Function>> = vec![Box::new(Pure), Box::new(Identity("impure"))];
+
+        let program = compile_optimized(r#"pure("a") + pure("b")"#, &fns);
+        assert_eq!(program[0].as_value(), Some(value!("ab")));
+
+        // Functions may have side effects, unless they're pure.
+        let program = compile_optimized(r#"impure("a")"#, &fns);
+        assert_eq!(program[0].as_value(), None);
+
+        let program = compile_optimized(r#"pure(.a)"#, &fns);
+        assert_eq!(program[0].as_value(), None);
+    }
+
+    #[test]
+    fn constant_folding_divide_by_zero() {
+        // The remainder of integers is infallible at compile-time, so the
+        // error can only be raised at runtime.
+        let program = compile_optimized("10 % 0", &[]);
+        assert_eq!(program[0].as_value(), None);
+        assert_eq!(
+            resolve(&program),
+            Err(Abort::new(305, "can't divide by zero"))
+        );
+
+        let program = compile_optimized("10 / 0 ?? 5", &[]);
+        assert_eq!(resolve(&program), Ok(value!(5)));
+
+        // Dividing is fallible, so it must still be handled.
+        let mut state = state::Compiler::default().with_optimization(Optimization::Full);
+        assert!(crate::compile_with_state("10 / 0", &[], &mut state).is_err());
+    }
+
+    /// A function returning its argument, counting the times it's resolved.
+    #[derive(Debug)]
+    struct Counting {
+        ident: &'static str,
+        pure: bool,
+        calls: Arc<AtomicUsize>,
+    }
+
+    impl Counting {
+        fn new(ident: &'static str, pure: bool) -> Self {
+            Self {
+                ident,
+                pure,
+                calls: Arc::default(),
+            }
+        }
+    }
+
+    impl Function for Counting {
+        fn identifier(&self) -> &'static str {
+            self.ident
+        }
+
+        fn is_pure(&self) -> bool {
+            self.pure
+        }
+
+        fn examples(&self) -> &'static [Example] {
+            &[]
+        }
+
+        fn compile(&self, mut arguments: ArgumentList) -> Compiled {
+            Ok(Box::new(CountingFn(
+                arguments.required("value"),
+                self.calls.clone(),
+            )))
+        }
+
+        fn parameters(&self) -> &'static [Parameter] {
+            Identity(self.ident).parameters()
+        }
+    }
+
+    #[derive(Debug, Clone)]
+    struct CountingFn(Box<dyn Expression>, Arc<AtomicUsize>);
+
+    impl Expression for CountingFn {
+        fn resolve(&self, ctx: &mut Context) -> Resolved {
+            self.1.fetch_add(1, Ordering::Relaxed);
+            self.0.resolve(ctx)
+        }
+
+        fn type_def(&self, state: &state::Compiler) -> TypeDef {
+            self.0.type_def(state)
+        }
+    }
+
+    #[test]
+    fn repeated_pure_calls() {
+        let parse = Counting::new("parse", true);
+        let calls = parse.calls.clone();
+        let fns: Vec<Box<dyn Function>> = vec![Box::new(parse)];
+        let program = compile_optimized(
+            ".a = parse(.msg)\n.b = parse(.msg)\n.msg = \"changed\"\n.c = parse(.msg)",
+            &fns,
+        );
+
+        let mut runtime = Runtime::default();
+        let mut target = value!({ "msg": "foo" });
+        runtime.resolve(&mut target, &program).unwrap();
+
+        assert_eq!(
+            target,
+            value!({ "msg": "changed", "a": "foo", "b": "foo", "c": "changed" })
+        );
+        // The second call shares the value of the first, but the third is
+        // resolved again, as its argument changed.
+        assert_eq!(calls.load(Ordering::Relaxed), 2);
+
+        // The values aren't shared between runs, even with the same arguments.
+        let mut target = value!({ "msg": "changed" });
+        runtime.resolve(&mut target, &program).unwrap();
+        assert_eq!(calls.load(Ordering::Relaxed), 3);
+    }
+
+    #[test]
+    fn repeated_impure_calls() {
+        let random = Counting::new("random", false);
+        let calls = random.calls.clone();
+        let fns: Vec<Box<dyn Function>> = vec![Box::new(random)];
+        let program = compile_optimized(".a = random(.msg)\n.b = random(.msg)", &fns);
+
+        let mut target = value!({ "msg": "foo" });
+        Runtime::default().resolve(&mut target, &program).unwrap();
+
+        assert_eq!(target, value!({ "msg": "foo", "a": "foo", "b": "foo" }));
+        assert_eq!(calls.load(Ordering::Relaxed), 2);
+    }
+
+    /// A stub of an enrichment function, returning its argument as the result
+    /// of a lookup, counting the lookups done.
+    #[derive(Debug, Clone)]
+    struct LookupFn(Box<dyn Expression>, Arc<AtomicUsize>);
+
+    impl Expression for LookupFn {
+        fn resolve(&self, ctx: &mut Context) -> Resolved {
+            let key = self.0.resolve(ctx)?;
+            let lookups = &self.1;
+
+            ctx.state_mut().enrich("lookup", &[key.clone()], || {
+                lookups.fetch_add(1, Ordering::Relaxed);
+                Ok(key)
             })
-            .collect::<Result<Vec<_>, _>>()?;
+        }
+
+        fn type_def(&self, state: &state::Compiler) -> TypeDef {
+            self.0.type_def(state)
+        }
+    }
+
+    #[derive(Debug, Default)]
+    struct Lookup(Arc<AtomicUsize>);
+
+    impl Function for Lookup {
+        fn identifier(&self) -> &'static str {
+            "lookup"
+        }
+
+        fn examples(&self) -> &'static [Example] {
+            &[]
+        }
+
+        fn compile(&self, mut arguments: ArgumentList) -> Compiled {
+            Ok(Box::new(LookupFn(
+                arguments.required("value"),
+                self.0.clone(),
+            )))
+        }
+
+        fn parameters(&self) -> &'static [Parameter] {
+            Identity("lookup").parameters()
+        }
+    }
+
+    #[test]
+    fn enrichment_cache() {
+        let lookup = Lookup::default();
+        let lookups = lookup.0.clone();
+        let fns: Vec<Box<dyn Function>> = vec![Box::new(lookup)];
+        let program = crate::compile(".a = lookup(.ip)\n.b = lookup(.ip)", &fns).unwrap();
+
+        let cache = EnrichmentCache::new(1);
+        let mut runtime = Runtime::new(state::Runtime::default().with_enrichment_cache(cache));
+
+        // The identical calls of the batch are only looked up once.
+        let mut targets = vec![value!({ "ip": "10.0.0.1" }), value!({ "ip": "10.0.0.1" })];
+        runtime.resolve_batch(&mut targets, &program, false);
+        assert_eq!(
+            targets[1],
+            value!({ "ip": "10.0.0.1", "a": "10.0.0.1", "b": "10.0.0.1" })
+        );
+        assert_eq!(lookups.load(Ordering::Relaxed), 1);
+
+        // The cache only holds one result, so looking up another one evicts
+        // the first.
+        let mut targets = vec![value!({ "ip": "10.0.0.2" }), value!({ "ip": "10.0.0.1" })];
+        runtime.resolve_batch(&mut targets, &program, false);
+        assert_eq!(lookups.load(Ordering::Relaxed), 3);
+
+        // Without a cache, every call is looked up.
+        let mut targets = vec![value!({ "ip": "10.0.0.1" })];
+        Runtime::default().resolve_batch(&mut targets, &program, false);
+        assert_eq!(lookups.load(Ordering::Relaxed), 5);
+    }
+
+    /// A function returning the field at the path given as a value, which
+    /// only resolves at runtime.
+    #[derive(Debug)]
+    struct Get;
+
+    impl Function for Get {
+        fn identifier(&self) -> &'static str {
+            "get"
+        }
+
+        fn accesses_target(&self) -> bool {
+            true
+        }
+
+        fn examples(&self) -> &'static [Example] {
+            &[]
+        }
+
+        fn compile(&self, mut arguments: ArgumentList) -> Compiled {
+            Ok(Box::new(IdentityFn(arguments.required("value"))))
+        }
+
+        fn parameters(&self) -> &'static [Parameter] {
+            &[
+                Parameter {
+                    keyword: "value",
+                    kind: kind::ANY,
+                    required: true,
+                },
+                Parameter {
+                    keyword: "path",
+                    kind: kind::ANY,
+                    required: true,
+                },
+            ]
+        }
+    }
+
+    #[test]
+    fn paths() {
+        let program = crate::compile(".c = .a.b", &[]).unwrap();
+
+        let read = program.paths_read();
+        assert_eq!(
+            read.paths,
+            vec![Path::from_str(".a.b").unwrap()].into_iter().collect()
+        );
+        assert!(read.complete);
+
+        let written = program.paths_written();
+        assert_eq!(
+            written.paths,
+            vec![Path::from_str(".c").unwrap()].into_iter().collect()
+        );
+        assert!(written.complete);
+    }
+
+    #[test]
+    fn dynamic_paths() {
+        let fns: Vec<Box<dyn Function>> = vec![Box::new(Get)];
+        let program = crate::compile("key = \"a\"\n.c = get(., key)", &fns).unwrap();
 
-        Ok(values.pop().unwrap_or(Value::Null))
+        assert_eq!(
+            program.paths_read().paths,
+            vec![Path::root()].into_iter().collect()
+        );
+        assert!(!program.paths_read().complete);
+        assert!(!program.paths_written().complete);
     }
 }
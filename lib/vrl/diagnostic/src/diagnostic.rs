@@ -58,6 +58,12 @@ impl Diagnostic {
         self.severity
     }
 
+    /// The stable code identifying the class of the diagnostic, rendered as
+    /// `E{code}`, e.g. `E110` for an argument of the wrong type.
+    pub fn code(&self) -> usize {
+        self.code
+    }
+
     pub fn message(&self) -> &str {
         &self.message
     }
@@ -106,7 +112,7 @@ impl Diagnostic {
 impl From<Box<dyn DiagnosticError>> for Diagnostic {
     fn from(error: Box<dyn DiagnosticError>) -> Self {
         Self {
-            severity: Severity::Error,
+            severity: error.severity(),
             code: error.code(),
             message: error.message(),
             labels: error.labels(),
@@ -1,4 +1,4 @@
-use crate::DiagnosticList;
+use crate::{Diagnostic, DiagnosticList, Severity};
 use std::fmt;
 
 /// A formatter to display diagnostics tied to a given source.
@@ -6,6 +6,7 @@ pub struct Formatter<'a> {
     source: &'a str,
     diagnostics: DiagnosticList,
     color: bool,
+    min_severity: Severity,
 }
 
 impl<'a> Formatter<'a> {
@@ -14,6 +15,7 @@ impl<'a> Formatter<'a> {
             source,
             diagnostics: diagnostics.into(),
             color: false,
+            min_severity: Severity::Note,
         }
     }
 
@@ -25,6 +27,27 @@ impl<'a> Formatter<'a> {
     pub fn enable_colors(&mut self, color: bool) {
         self.color = color
     }
+
+    /// Only render diagnostics that are at least as severe as the given
+    /// severity. All diagnostics are rendered by default.
+    pub fn with_min_severity(mut self, severity: Severity) -> Self {
+        self.min_severity = severity;
+        self
+    }
+
+    /// The diagnostics that are rendered, given the minimum severity.
+    pub fn diagnostics(&self) -> impl Iterator<Item = &Diagnostic> {
+        let min_severity = self.min_severity;
+
+        self.diagnostics
+            .iter()
+            .filter(move |diagnostic| diagnostic.severity().is_at_least(min_severity))
+    }
+
+    /// Returns `true` if none of the diagnostics are rendered.
+    pub fn is_empty(&self) -> bool {
+        self.diagnostics().next().is_none()
+    }
 }
 
 impl<'a> fmt::Display for Formatter<'a> {
@@ -44,7 +67,7 @@ impl<'a> fmt::Display for Formatter<'a> {
 
         f.write_str("\n")?;
 
-        for diagnostic in self.diagnostics.iter() {
+        for diagnostic in self.diagnostics() {
             term::emit(&mut buffer, &config, &file, &diagnostic.to_owned().into())
                 .map_err(|_| fmt::Error)?;
         }
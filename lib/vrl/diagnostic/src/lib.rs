@@ -21,6 +21,14 @@ const VRL_FUNCS_ROOT_URL: &str = "https://functions.vrl.dev";
 pub trait DiagnosticError: std::error::Error {
     fn code(&self) -> usize;
 
+    /// The severity of the diagnostic, such as whether it's an error or a
+    /// warning.
+    ///
+    /// Defaults to [`Severity::Error`].
+    fn severity(&self) -> Severity {
+        Severity::Error
+    }
+
     /// The subject message of the error.
     ///
     /// Defaults to the error message itself.
@@ -1,4 +1,6 @@
 use codespan_reporting::diagnostic;
+use std::fmt;
+use std::str::FromStr;
 
 #[derive(Debug, PartialEq, Clone, Copy)]
 pub enum Severity {
@@ -32,6 +34,54 @@ impl Severity {
     pub fn is_note(self) -> bool {
         matches!(self, Severity::Note)
     }
+
+    /// Returns `true` if the severity is the same as, or more severe than the
+    /// given severity, a [bug](Variant::Bug) being the most severe, and a
+    /// [note](Variant::Note) the least.
+    #[inline]
+    pub fn is_at_least(self, severity: Severity) -> bool {
+        self.rank() >= severity.rank()
+    }
+
+    fn rank(self) -> u8 {
+        use Severity::*;
+
+        match self {
+            Note => 0,
+            Warning => 1,
+            Error => 2,
+            Bug => 3,
+        }
+    }
+}
+
+impl FromStr for Severity {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        use Severity::*;
+
+        match s {
+            "bug" => Ok(Bug),
+            "error" => Ok(Error),
+            "warning" => Ok(Warning),
+            "note" => Ok(Note),
+            _ => Err(format!(r#"unknown severity "{}""#, s)),
+        }
+    }
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use Severity::*;
+
+        match self {
+            Bug => f.write_str("bug"),
+            Error => f.write_str("error"),
+            Warning => f.write_str("warning"),
+            Note => f.write_str("note"),
+        }
+    }
 }
 
 impl From<Severity> for diagnostic::Severity {
@@ -8,6 +8,10 @@ pub type Tok<'input> = Token<&'input str>;
 pub type SpannedResult<'input, Loc> = Result<Spanned<'input, Loc>, Error>;
 pub type Spanned<'input, Loc> = (Loc, Tok<'input>, Loc);
 
+/// The default maximum depth of nested expressions, see
+/// [`parse_with_max_depth`](crate::parse_with_max_depth).
+pub const DEFAULT_MAX_DEPTH: usize = 256;
+
 #[derive(thiserror::Error, Clone, Debug, PartialEq)]
 pub enum Error {
     #[error("syntax error")]
@@ -42,6 +46,9 @@ pub enum Error {
 
     #[error("unexpected parse error")]
     UnexpectedParseError(String),
+
+    #[error("expressions nested too deeply")]
+    NestingDepth { start: usize, max_depth: usize },
 }
 
 impl DiagnosticError for Error {
@@ -62,6 +69,7 @@ impl DiagnosticError for Error {
             Literal { .. } => 208,
             EscapeChar { .. } => 209,
             UnexpectedParseError(..) => 210,
+            NestingDepth { .. } => 211,
         }
     }
 
@@ -181,6 +189,11 @@ impl DiagnosticError for Error {
             )],
 
             UnexpectedParseError(string) => vec![Label::primary(string, Span::default())],
+
+            NestingDepth { start, max_depth } => vec![Label::primary(
+                format!("more than {} levels of nesting", max_depth),
+                Span::new(*start, *start + 1),
+            )],
         }
     }
 }
@@ -198,6 +211,7 @@ pub struct Lexer<'input> {
     open_brackets: usize,
     open_braces: usize,
     open_parens: usize,
+    max_depth: usize,
 
     /// Keep track of when the lexer is supposed to emit an `RQuery` token.
     ///
@@ -489,9 +503,9 @@ impl<'input> Iterator for Lexer<'input> {
                     '\n' => Some(Ok(self.token(start, Newline))),
                     '\\' => Some(Ok(self.token(start, Escape))),
 
-                    '(' => Some(Ok(self.open(start, LParen))),
-                    '[' => Some(Ok(self.open(start, LBracket))),
-                    '{' => Some(Ok(self.open(start, LBrace))),
+                    '(' => Some(self.open(start, LParen)),
+                    '[' => Some(self.open(start, LBracket)),
+                    '{' => Some(self.open(start, LBrace)),
                     '}' => Some(Ok(self.close(start, RBrace))),
                     ']' => Some(Ok(self.close(start, RBracket))),
                     ')' => Some(Ok(self.close(start, RParen))),
@@ -553,7 +567,7 @@ impl<'input> Iterator for Lexer<'input> {
 // -----------------------------------------------------------------------------
 
 impl<'input> Lexer<'input> {
-    fn open(&mut self, start: usize, token: Token<&'input str>) -> Spanned<'input, usize> {
+    fn open(&mut self, start: usize, token: Token<&'input str>) -> SpannedResult<'input, usize> {
         match &token {
             Token::LParen => self.open_parens += 1,
             Token::LBracket => self.open_brackets += 1,
@@ -561,7 +575,17 @@ impl<'input> Lexer<'input> {
             _ => {}
         };
 
-        self.token(start, token)
+        // Each open delimiter nests at least one expression, so programs
+        // rejected here would be rejected by the compiler as well, but this
+        // prevents the parser from building them at all.
+        if self.open_parens + self.open_brackets + self.open_braces > self.max_depth {
+            return Err(Error::NestingDepth {
+                start,
+                max_depth: self.max_depth,
+            });
+        }
+
+        Ok(self.token(start, token))
     }
 
     fn close(&mut self, start: usize, token: Token<&'input str>) -> Spanned<'input, usize> {
@@ -762,33 +786,35 @@ impl<'input> Lexer<'input> {
                             }
                         };
 
+                        // An invalid literal ends the region, since the
+                        // scan can't skip past it.
                         let ch = match &self.input[pos..] {
                             s if s.starts_with('"') => {
                                 let r = Lexer::new(&self.input[pos + 1..]).string_literal(0);
                                 match literal_check(r, &mut chars) {
                                     Ok(ch) => ch,
-                                    Err(_) => continue,
+                                    Err(_) => break,
                                 }
                             }
                             s if s.starts_with("s'") => {
                                 let r = Lexer::new(&self.input[pos + 1..]).raw_string_literal(0);
                                 match literal_check(r, &mut chars) {
                                     Ok(ch) => ch,
-                                    Err(_) => continue,
+                                    Err(_) => break,
                                 }
                             }
                             s if s.starts_with("r'") => {
                                 let r = Lexer::new(&self.input[pos + 1..]).regex_literal(0);
                                 match literal_check(r, &mut chars) {
                                     Ok(ch) => ch,
-                                    Err(_) => continue,
+                                    Err(_) => break,
                                 }
                             }
                             s if s.starts_with("t'") => {
                                 let r = Lexer::new(&self.input[pos + 1..]).timestamp_literal(0);
                                 match literal_check(r, &mut chars) {
                                     Ok(ch) => ch,
-                                    Err(_) => continue,
+                                    Err(_) => break,
                                 }
                             }
                             _ => *ch,
@@ -1004,10 +1030,16 @@ impl<'input> Lexer<'input> {
             open_braces: 0,
             open_brackets: 0,
             open_parens: 0,
+            max_depth: DEFAULT_MAX_DEPTH,
             rquery_indices: vec![],
         }
     }
 
+    pub(crate) fn with_max_depth(mut self, max_depth: usize) -> Self {
+        self.max_depth = max_depth;
+        self
+    }
+
     fn bump(&mut self) -> Option<(usize, char)> {
         self.chars.next()
     }
@@ -1795,4 +1827,35 @@ mod test {
             ],
         );
     }
+
+    #[test]
+    fn nesting_depth() {
+        let nested = "[".repeat(DEFAULT_MAX_DEPTH);
+        assert!(lexer(&nested).all(|token| token.is_ok()));
+
+        let nested = "([{".repeat(DEFAULT_MAX_DEPTH);
+        assert_eq!(
+            lexer(&nested).find_map(Result::err),
+            Some(Error::NestingDepth {
+                start: DEFAULT_MAX_DEPTH,
+                max_depth: DEFAULT_MAX_DEPTH,
+            })
+        );
+
+        let mut lexer = Lexer::new("[[[1]]]").with_max_depth(2);
+        assert_eq!(
+            lexer.find_map(Result::err),
+            Some(Error::NestingDepth {
+                start: 2,
+                max_depth: 2
+            })
+        );
+    }
+
+    #[test]
+    fn unterminated_literal_in_query() {
+        for source in &[r#".a[1 "b"#, ".a[1 s'b", ".a(1 r'b", ".a{1 t'b"] {
+            assert!(lexer(source).any(|token| token.is_err()), "{}", source);
+        }
+    }
 }
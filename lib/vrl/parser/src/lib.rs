@@ -10,19 +10,31 @@ mod lex;
 
 pub use ast::{Field, Literal, Path, PathSegment, Program};
 pub use diagnostic::Span;
-pub use lex::{Error, Token};
+pub use lex::{Error, Token, DEFAULT_MAX_DEPTH};
 
 pub fn parse(input: impl AsRef<str>) -> Result<Program, Error> {
-    let lexer = lex::Lexer::new(input.as_ref());
+    parse_with_max_depth(input, DEFAULT_MAX_DEPTH)
+}
+
+/// Similar to [`parse`], except that programs with more than `max_depth`
+/// parentheses, brackets or braces open at once are rejected, instead of
+/// being parsed into expressions too deeply nested to compile.
+pub fn parse_with_max_depth(input: impl AsRef<str>, max_depth: usize) -> Result<Program, Error> {
+    let lexer = lex::Lexer::new(input.as_ref()).with_max_depth(max_depth);
 
     parser::ProgramParser::new()
         .parse(input.as_ref(), lexer)
-        .map_err(|source| Error::ParseError {
-            span: Span::new(0, input.as_ref().len()),
-            source: source
-                .map_token(|t| t.map(|s| s.to_owned()))
-                .map_error(|err| err.to_string()),
-            dropped_tokens: vec![],
+        .map_err(|source| match source {
+            lalrpop_util::ParseError::User {
+                error: error @ Error::NestingDepth { .. },
+            } => error,
+            source => Error::ParseError {
+                span: Span::new(0, input.as_ref().len()),
+                source: source
+                    .map_token(|t| t.map(|s| s.to_owned()))
+                    .map_error(|err| err.to_string()),
+                dropped_tokens: vec![],
+            },
         })
 }
 
@@ -9,68 +9,168 @@ criterion_group!(
     // encapsulates CI noise we saw in
     // https://github.com/timberio/vector/pull/6408
     config = Criterion::default().noise_threshold(0.05);
-    targets = assert,
+    targets = abort,
+              add_business_days,
+              assert,
+              basename,
+              business_days_between,
+              camel_case_keys,
+              canonicalize,
+              capture_error,
+              card_brand,
               ceil,
+              classify,
+              coerce_types,
               compact,
+              compare_semver,
+              compile_template,
+              concat,
+              concat_bytes,
               contains,
+              contrast_ratio,
+              cron_matches,
+              cron_next,
               decode_base64,
               // TODO: Cannot pass a Path to bench_function
               //del,
+              decode_cbor,
+              decode_deflate,
+              decode_hex,
+              decode_lz4,
+              decode_percent,
+              decode_snappy,
+              deep_equal,
+              detect_content_type,
+              dirname,
+              dot_product,
               downcase,
+              ellipsize_middle,
               encode_base64,
+              encode_cbor,
+              encode_fixed_width,
+              encode_hex,
+              encode_influx,
               encode_json,
+              encode_percent,
+              encode_snappy,
               ends_with,
               // TODO: Cannot pass a Path to bench_function
               //exists
+              ewma,
+              extname,
+              file_extension,
               flatten,
               floor,
+              format_mac_address,
               format_number,
               format_timestamp,
+              from_hex_dump,
               get_env_var,
               get_hostname,
+              glob_match,
+              hash_color,
+              hex_dump,
+              html_escape,
+              html_unescape,
+              humanize_number,
               includes,
+              infer_schema,
               ip_cidr_contains,
               ip_subnet,
               ip_to_ipv6,
+              ip_version,
               ipv6_to_ipv4,
+              is_ip,
               is_nullish,
+              is_private_ip,
+              ja3,
+              jmespath,
               join,
+              join_path,
               length,
+              like,
               log,
+              luhn_check,
+              luminance,
+              mac_vendor,
+              mask,
               r#match,
+              match_any,
               md5,
               merge,
+              merge_schemas,
               // TODO: value is dynamic so we cannot assert equality
               //now,
+              metaphone,
+              mime_type,
+              moving_average,
+              ngrams,
+              normalize_email,
+              ordinal,
               parse_apache_log,
               parse_aws_alb_log,
               parse_aws_cloudwatch_log_subscription_message,
               parse_aws_vpc_flow_log,
+              parse_color,
               parse_common_log,
+              parse_dns_query,
               parse_duration,
+              parse_email,
+              parse_fixed_width,
               parse_glog,
               parse_grok,
+              parse_html,
+              parse_influx,
+              parse_iso8601_duration,
               parse_key_value,
               parse_json,
+              parse_mac_address,
+              parse_markdown,
+              parse_number_array,
+              parse_parquet_schema,
+              parse_php_serialized,
+              parse_prometheus,
               parse_regex,
               parse_regex_all,
+              parse_ruby_hash,
+              parse_semver,
+              parse_statsd,
               parse_syslog,
               parse_timestamp,
               parse_tokens,
               parse_url,
+              percentile,
+              pluralize,
+              public_suffix,
               push,
               // TODO: Has not been ported to vrl/stdlib yet
               //redact,
+              quantile,
+              registrable_domain,
+              rename_key,
+              rename_keys,
+              render,
               replace,
               round,
+              set_if_absent,
               sha1,
               sha2,
               sha3,
+              shell_quote,
+              shell_split,
+              sizeof,
               slice,
+              slice_bytes,
+              snake_case_keys,
+              soundex,
               split,
+              sprintf,
               starts_with,
+              stats,
               strip_ansi_escape_codes,
+              strip_html,
               strip_whitespace,
+              time_ago,
               to_bool,
               to_float,
               to_int,
@@ -80,13 +180,39 @@ criterion_group!(
               to_syslog_severity,
               to_timestamp,
               to_unix_timestamp,
+              token_bucket,
+              tokenize,
               truncate,
+              try_all,
               // TODO: value is dynamic so we cannot assert equality
               //uuidv4,
-              upcase
+              unique_bloom,
+              upcase,
+              vector_add,
+              vector_scale,
+              windows,
+              xor_bytes
 );
 criterion_main!(benches);
 
+bench_function! {
+    abort => vrl_stdlib::Abort;
+
+    payload {
+        args: func_args![message: "invalid event", payload: value!({ "reason": "missing field" })],
+        want: Err("invalid event"),
+    }
+}
+
+bench_function! {
+    add_business_days => vrl_stdlib::AddBusinessDays;
+
+    literal {
+        args: func_args![value: Utc.ymd(2021, 3, 5).and_hms(12, 0, 0), days: 10],
+        want: Ok(Utc.ymd(2021, 3, 19).and_hms(12, 0, 0)),
+    }
+}
+
 bench_function! {
     append => vrl_stdlib::Append;
 
@@ -105,6 +231,63 @@ bench_function! {
     }
 }
 
+bench_function! {
+    basename => vrl_stdlib::Basename;
+
+    file {
+        args: func_args![value: "/var/log/syslog.1"],
+        want: Ok("syslog.1"),
+    }
+}
+
+bench_function! {
+    business_days_between => vrl_stdlib::BusinessDaysBetween;
+
+    literal {
+        args: func_args![
+            start: Utc.ymd(2021, 3, 1).and_hms(0, 0, 0),
+            end: Utc.ymd(2021, 3, 31).and_hms(0, 0, 0),
+        ],
+        want: Ok(22),
+    }
+}
+
+bench_function! {
+    camel_case_keys => vrl_stdlib::CamelCaseKeys;
+
+    nested {
+        args: func_args![value: value!({ "user_id": 1, "http_server": { "max_connections": 2 } })],
+        want: Ok(value!({ "userId": 1, "httpServer": { "maxConnections": 2 } })),
+    }
+}
+
+bench_function! {
+    canonicalize => vrl_stdlib::Canonicalize;
+
+    object {
+        args: func_args![value: value!({ "b": { "d": 1.5, "c": null }, "a": [true, "foo"] })],
+        want: Ok(r#"{"a":[true,"foo"],"b":{"c":null,"d":1.5}}"#),
+    }
+}
+
+bench_function! {
+    capture_error => vrl_stdlib::CaptureError;
+
+    ok {
+        args: func_args![value: "foo"],
+        want: Ok(value!({ "ok": "foo" })),
+    }
+}
+
+bench_function! {
+    card_brand => vrl_stdlib::CardBrand;
+
+    literal {
+        args: func_args![value: "4111 1111 1111 1111"],
+        want: Ok("visa"),
+    }
+}
+
 bench_function! {
     ceil => vrl_stdlib::Ceil;
 
@@ -114,6 +297,27 @@ bench_function! {
     }
 }
 
+bench_function! {
+    classify => vrl_stdlib::Classify;
+
+    literal {
+        args: func_args![
+            value: "GET /api/health",
+            categories: value!({ "api": "^\\w+ /api/", "health": "/health$", "static": "\\.css$" }),
+        ],
+        want: Ok(value!(["api", "health"])),
+    }
+}
+
+bench_function! {
+    coerce_types => vrl_stdlib::CoerceTypes;
+
+    literal {
+        args: func_args![value: value!({ "a": "5", "b": "5.0", "c": "true", "d": "null", "e": "007" })],
+        want: Ok(value!({ "a": 5, "b": 5.0, "c": true, "d": null, "e": "007" })),
+    }
+}
+
 bench_function! {
     compact => vrl_stdlib::Compact;
 
@@ -132,6 +336,45 @@ bench_function! {
     }
 }
 
+bench_function! {
+    compare_semver => vrl_stdlib::CompareSemver;
+
+    prerelease {
+        args: func_args![a: "1.0.0-rc.1", b: "1.0.0"],
+        want: Ok(-1),
+    }
+}
+
+bench_function! {
+    compile_template => vrl_stdlib::CompileTemplate;
+
+    literal {
+        args: func_args![template: "{{ method }} {{ path }}"],
+        want: Ok(value!({ "template": "{{ method }} {{ path }}", "parts": [["method"], " ", ["path"]] })),
+    }
+}
+
+bench_function! {
+    concat => vrl_stdlib::Concat;
+
+    literal {
+        args: func_args![parts: value!(["user-", 42, "-", true]), separator: " "],
+        want: Ok("user- 42 - true"),
+    }
+}
+
+bench_function! {
+    concat_bytes => vrl_stdlib::ConcatBytes;
+
+    binary {
+        args: func_args![parts: vec![
+            Value::from(Bytes::from_static(b"\xff\x00")),
+            Value::from(Bytes::from_static(b"\x80\xfe")),
+        ]],
+        want: Ok(Bytes::from_static(b"\xff\x00\x80\xfe")),
+    }
+}
+
 bench_function! {
     contains => vrl_stdlib::Contains;
 
@@ -146,6 +389,33 @@ bench_function! {
     }
 }
 
+bench_function! {
+    contrast_ratio => vrl_stdlib::ContrastRatio;
+
+    literal {
+        args: func_args![a: "black", b: "white"],
+        want: Ok(21.0),
+    }
+}
+
+bench_function! {
+    cron_matches => vrl_stdlib::CronMatches;
+
+    literal {
+        args: func_args![expression: "*/15 9-17 * * mon-fri", timestamp: Utc.ymd(2021, 3, 5).and_hms(9, 30, 0)],
+        want: Ok(true),
+    }
+}
+
+bench_function! {
+    cron_next => vrl_stdlib::CronNext;
+
+    literal {
+        args: func_args![expression: "*/15 9-17 * * mon-fri", after: Utc.ymd(2021, 3, 5).and_hms(17, 50, 0)],
+        want: Ok(Utc.ymd(2021, 3, 8).and_hms(9, 0, 0)),
+    }
+}
+
 bench_function! {
     decode_base64 => vrl_stdlib::DecodeBase64;
 
@@ -155,6 +425,109 @@ bench_function! {
     }
 }
 
+bench_function! {
+    decode_cbor => vrl_stdlib::DecodeCbor;
+
+    object {
+        args: func_args![value: Bytes::from_static(&[0xa1, 0x61, b'a', 0x82, 0x01, 0xf5])],
+        want: Ok(value!({ "a": [1, true] })),
+    }
+}
+
+bench_function! {
+    decode_deflate => vrl_stdlib::DecodeDeflate;
+
+    stored {
+        args: func_args![value: Bytes::from_static(b"\x01\x05\x00\xfa\xffhello")],
+        want: Ok("hello"),
+    }
+}
+
+bench_function! {
+    decode_hex => vrl_stdlib::DecodeHex;
+
+    delimited {
+        args: func_args![value: "aa:bb:cc"],
+        want: Ok(Bytes::from_static(b"\xaa\xbb\xcc")),
+    }
+}
+
+bench_function! {
+    decode_lz4 => vrl_stdlib::DecodeLz4;
+
+    frame {
+        args: func_args![value: Bytes::from_static(b"\x04\x22\x4d\x18\x60\x40\x82\x05\x00\x00\x80hello\x00\x00\x00\x00")],
+        want: Ok("hello"),
+    }
+}
+
+bench_function! {
+    decode_percent => vrl_stdlib::DecodePercent;
+
+    literal {
+        args: func_args![value: "a%20b%2Fc%3Fd%3De%26f-g.h"],
+        want: Ok("a b/c?d=e&f-g.h"),
+    }
+}
+
+bench_function! {
+    decode_snappy => vrl_stdlib::DecodeSnappy;
+
+    raw {
+        args: func_args![value: Bytes::from_static(b"\x05\x10hello")],
+        want: Ok("hello"),
+    }
+
+    framed {
+        args: func_args![
+            value: Bytes::from_static(b"\xff\x06\x00\x00sNaPpY\x01\x09\x00\x00\xbb\x1f\x1c\x19hello"),
+            framed: true,
+        ],
+        want: Ok("hello"),
+    }
+}
+
+bench_function! {
+    deep_equal => vrl_stdlib::DeepEqual;
+
+    nested {
+        args: func_args![left: value!({ "a": [1, { "b": 2 }] }), right: value!({ "a": [1.0, { "b": 2.0 }] })],
+        want: Ok(true),
+    }
+
+    ignore_array_order {
+        args: func_args![left: value!([1, 2, 3, 4]), right: value!([4, 3, 2, 1]), ignore_array_order: true],
+        want: Ok(true),
+    }
+}
+
+bench_function! {
+    detect_content_type => vrl_stdlib::DetectContentType;
+
+    png {
+        args: func_args![value: Bytes::from_static(b"\x89PNG\r\n\x1a\n\x00\x00\x00\x0dIHDR")],
+        want: Ok("image/png"),
+    }
+}
+
+bench_function! {
+    dirname => vrl_stdlib::Dirname;
+
+    file {
+        args: func_args![value: "/var/log/syslog.1"],
+        want: Ok("/var/log"),
+    }
+}
+
+bench_function! {
+    dot_product => vrl_stdlib::DotProduct;
+
+    literal {
+        args: func_args![a: value!([1, 2, 3.5]), b: value!([4, 5, 6])],
+        want: Ok(35.0),
+    }
+}
+
 bench_function! {
     downcase => vrl_stdlib::Downcase;
 
@@ -164,6 +537,15 @@ bench_function! {
     }
 }
 
+bench_function! {
+    ellipsize_middle => vrl_stdlib::EllipsizeMiddle;
+
+    literal {
+        args: func_args![value: "/var/log/nginx/access.log", max_width: 16],
+        want: Ok("/var/lo...ss.log"),
+    }
+}
+
 bench_function! {
     encode_base64 => vrl_stdlib::EncodeBase64;
 
@@ -173,6 +555,53 @@ bench_function! {
     }
 }
 
+bench_function! {
+    encode_cbor => vrl_stdlib::EncodeCbor;
+
+    object {
+        args: func_args![value: value!({ "a": [1, true] })],
+        want: Ok(Bytes::from_static(&[0xa1, 0x61, b'a', 0x82, 0x01, 0xf5])),
+    }
+}
+
+bench_function! {
+    encode_fixed_width => vrl_stdlib::EncodeFixedWidth;
+
+    literal {
+        args: func_args![
+            value: value!({ "id": 42, "first": "JOHN", "last": "SMITH" }),
+            spec: value!([
+                { "name": "id", "width": 5, "align": "right" },
+                { "name": "first", "width": 9 },
+                { "name": "last", "width": 10 },
+            ]),
+        ],
+        want: Ok("   42JOHN     SMITH     "),
+    }
+}
+
+bench_function! {
+    encode_hex => vrl_stdlib::EncodeHex;
+
+    uppercase_delimiter {
+        args: func_args![value: Bytes::from_static(b"\xaa\xbb\xcc"), uppercase: true, delimiter: ":"],
+        want: Ok("AA:BB:CC"),
+    }
+}
+
+bench_function! {
+    encode_influx => vrl_stdlib::EncodeInflux;
+
+    line {
+        args: func_args![value: value!({
+            "measurement": "cpu",
+            "tags": { "host": "a" },
+            "fields": { "usage": 0.5, "cores": 4 },
+        })],
+        want: Ok("cpu,host=a cores=4i,usage=0.5"),
+    }
+}
+
 bench_function! {
     encode_json => vrl_stdlib::EncodeJson;
 
@@ -182,6 +611,34 @@ bench_function! {
     }
 }
 
+bench_function! {
+    encode_percent => vrl_stdlib::EncodePercent;
+
+    non_alphanumeric {
+        args: func_args![value: "a b/c?d=e&f-g.h"],
+        want: Ok("a%20b%2Fc%3Fd%3De%26f%2Dg%2Eh"),
+    }
+
+    component {
+        args: func_args![value: "a b/c?d=e&f-g.h", ascii_set: "component"],
+        want: Ok("a%20b%2Fc%3Fd%3De%26f-g.h"),
+    }
+}
+
+bench_function! {
+    encode_snappy => vrl_stdlib::EncodeSnappy;
+
+    raw {
+        args: func_args![value: "hello"],
+        want: Ok(Bytes::from_static(b"\x05\x10hello")),
+    }
+
+    framed {
+        args: func_args![value: "hello", framed: true],
+        want: Ok(Bytes::from_static(b"\xff\x06\x00\x00sNaPpY\x01\x09\x00\x00\xbb\x1f\x1c\x19hello")),
+    }
+}
+
 bench_function! {
     ends_with => vrl_stdlib::EndsWith;
 
@@ -196,6 +653,33 @@ bench_function! {
     }
 }
 
+bench_function! {
+    ewma => vrl_stdlib::Ewma;
+
+    literal {
+        args: func_args![value: value!([10, 20, 20, 0]), alpha: 0.5],
+        want: Ok(value!([10.0, 15.0, 17.5, 8.75])),
+    }
+}
+
+bench_function! {
+    extname => vrl_stdlib::Extname;
+
+    file {
+        args: func_args![value: "/var/log/app.log"],
+        want: Ok(".log"),
+    }
+}
+
+bench_function! {
+    file_extension => vrl_stdlib::FileExtension;
+
+    content_type {
+        args: func_args![value: "text/html; charset=utf-8"],
+        want: Ok("html"),
+    }
+}
+
 bench_function! {
     flatten => vrl_stdlib::Flatten;
 
@@ -235,6 +719,15 @@ bench_function! {
     }
 }
 
+bench_function! {
+    format_mac_address => vrl_stdlib::FormatMacAddress;
+
+    cisco {
+        args: func_args![value: "00:1B:63:84:45:E6", style: "cisco"],
+        want: Ok("001b.6384.45e6"),
+    }
+}
+
 bench_function! {
     format_number => vrl_stdlib::FormatNumber;
 
@@ -258,6 +751,15 @@ bench_function! {
     }
 }
 
+bench_function! {
+    from_hex_dump => vrl_stdlib::FromHexDump;
+
+    short {
+        args: func_args![value: "00000000  68 65 6c 6c 6f 20 77 6f 72 6c 64 0a              |hello world.|"],
+        want: Ok("hello world\n"),
+    }
+}
+
 bench_function! {
     get_env_var => vrl_stdlib::GetEnvVar;
 
@@ -277,6 +779,65 @@ bench_function! {
     }
 }
 
+bench_function! {
+    glob_match => vrl_stdlib::GlobMatch;
+
+    double_star {
+        args: func_args![value: "/var/log/nginx/access.log", pattern: "/var/log/**/*.log"],
+        want: Ok(true),
+    }
+}
+
+bench_function! {
+    hash_color => vrl_stdlib::HashColor;
+
+    color {
+        args: func_args![value: "checkout-service"],
+        want: Ok("#24ba4f"),
+    }
+
+    palette {
+        args: func_args![value: "checkout-service", palette: value!(["red", "green", "blue"])],
+        want: Ok("blue"),
+    }
+}
+
+bench_function! {
+    hex_dump => vrl_stdlib::HexDump;
+
+    short {
+        args: func_args![value: "hello world\n"],
+        want: Ok("00000000  68 65 6c 6c 6f 20 77 6f 72 6c 64 0a              |hello world.|"),
+    }
+}
+
+bench_function! {
+    html_escape => vrl_stdlib::HtmlEscape;
+
+    literal {
+        args: func_args![value: r#"<a href="/">Fish & chips</a>"#],
+        want: Ok("&lt;a href=&quot;/&quot;&gt;Fish &amp; chips&lt;/a&gt;"),
+    }
+}
+
+bench_function! {
+    html_unescape => vrl_stdlib::HtmlUnescape;
+
+    literal {
+        args: func_args![value: "&lt;a href=&quot;/&quot;&gt;Fish &amp; chips&lt;/a&gt;"],
+        want: Ok(r#"<a href="/">Fish & chips</a>"#),
+    }
+}
+
+bench_function! {
+    humanize_number => vrl_stdlib::HumanizeNumber;
+
+    literal {
+        args: func_args![value: 3_456_789, precision: 2],
+        want: Ok("3.46M"),
+    }
+}
+
 bench_function! {
     includes => vrl_stdlib::Includes;
 
@@ -287,6 +848,22 @@ bench_function! {
 
 }
 
+bench_function! {
+    infer_schema => vrl_stdlib::InferSchema;
+
+    object {
+        args: func_args![value: value!({ "id": 1, "tags": ["a", "b"] })],
+        want: Ok(value!({
+            "type": "object",
+            "properties": {
+                "id": { "type": "integer" },
+                "tags": { "type": "array", "items": { "type": "string" } }
+            },
+            "required": ["id", "tags"]
+        })),
+    }
+}
+
 bench_function! {
     ip_cidr_contains => vrl_stdlib::IpCidrContains;
 
@@ -339,6 +916,20 @@ bench_function! {
     }
 }
 
+bench_function! {
+    ip_version => vrl_stdlib::IpVersion;
+
+    ipv4 {
+        args: func_args![value: "192.168.0.1"],
+        want: Ok(4),
+    }
+
+    ipv6 {
+        args: func_args![value: "2001:db8::1"],
+        want: Ok(6),
+    }
+}
+
 bench_function! {
     ipv6_to_ipv4 => vrl_stdlib::Ipv6ToIpV4;
 
@@ -353,6 +944,20 @@ bench_function! {
     }
 }
 
+bench_function! {
+    is_ip => vrl_stdlib::IsIp;
+
+    ipv4 {
+        args: func_args![value: "192.168.0.1"],
+        want: Ok(true),
+    }
+
+    not_an_ip {
+        args: func_args![value: "vector.dev"],
+        want: Ok(false),
+    }
+}
+
 bench_function! {
     is_nullish => vrl_stdlib::IsNullish;
 
@@ -377,6 +982,41 @@ bench_function! {
     }
 }
 
+bench_function! {
+    is_private_ip => vrl_stdlib::IsPrivateIp;
+
+    ipv4 {
+        args: func_args![value: "192.168.0.1"],
+        want: Ok(true),
+    }
+
+    ipv6 {
+        args: func_args![value: "fd12:3456:789a::1"],
+        want: Ok(true),
+    }
+}
+
+bench_function! {
+    ja3 => vrl_stdlib::Ja3;
+
+    client_hello {
+        args: func_args![value: Bytes::from_static(b"\x16\x03\x01\x00\x71\x01\x00\x00\x6d\x03\x01\x00\x01\x02\x03\x04\x05\x06\x07\x08\x09\x0a\x0b\x0c\x0d\x0e\x0f\x10\x11\x12\x13\x14\x15\x16\x17\x18\x19\x1a\x1b\x1c\x1d\x1e\x1f\x00\x00\x1a\x0a\x0a\x00\x2f\x00\x35\x00\x05\x00\x0a\xc0\x09\xc0\x0a\xc0\x13\xc0\x14\x00\x32\x00\x38\x00\x13\x00\x04\x01\x00\x00\x2a\x1a\x1a\x00\x00\x00\x00\x00\x10\x00\x0e\x00\x00\x0bexample.com\x00\x0a\x00\x08\x00\x06\x00\x17\x00\x18\x00\x19\x00\x0b\x00\x02\x01\x00")],
+        want: Ok("ada70206e40642a3e4461f35503241d5"),
+    }
+}
+
+bench_function! {
+    jmespath => vrl_stdlib::Jmespath;
+
+    projection {
+        args: func_args![
+            value: value!({ "people": [{ "name": "foo", "age": 30 }, { "name": "bar", "age": 20 }] }),
+            expression: "people[?age > `25`].name",
+        ],
+        want: Ok(value!(["foo"])),
+    }
+}
+
 bench_function! {
     join => vrl_stdlib::Join;
 
@@ -386,6 +1026,15 @@ bench_function! {
     }
 }
 
+bench_function! {
+    join_path => vrl_stdlib::JoinPath;
+
+    parts {
+        args: func_args![parts: value!(["/var/log/", "app", "current.log"])],
+        want: Ok("/var/log/app/current.log"),
+    }
+}
+
 bench_function! {
     length => vrl_stdlib::Length;
 
@@ -406,6 +1055,20 @@ bench_function! {
 }
 
 // TODO: Ensure tracing is enabled
+bench_function! {
+    like => vrl_stdlib::Like;
+
+    literal_pattern {
+        args: func_args![value: "GET /api/users/42", pattern: "GET /api/%/__"],
+        want: Ok(true),
+    }
+
+    case_insensitive {
+        args: func_args![value: "ERROR: disk full", pattern: "error:%", case_sensitive: false],
+        want: Ok(true),
+    }
+}
+
 bench_function! {
     log => vrl_stdlib::Log;
 
@@ -415,6 +1078,42 @@ bench_function! {
     }
 }
 
+bench_function! {
+    luhn_check => vrl_stdlib::LuhnCheck;
+
+    literal {
+        args: func_args![value: "4111 1111 1111 1111"],
+        want: Ok(true),
+    }
+}
+
+bench_function! {
+    luminance => vrl_stdlib::Luminance;
+
+    literal {
+        args: func_args![color: "red"],
+        want: Ok(0.2126),
+    }
+}
+
+bench_function! {
+    mac_vendor => vrl_stdlib::MacVendor;
+
+    known_vendor {
+        args: func_args![value: "00:1B:63:84:45:E6"],
+        want: Ok("Apple, Inc."),
+    }
+}
+
+bench_function! {
+    mask => vrl_stdlib::Mask;
+
+    literal {
+        args: func_args![value: "4111111111111111", keep_start: 4],
+        want: Ok("4111********1111"),
+    }
+}
+
 bench_function! {
     r#match => vrl_stdlib::Match;
 
@@ -424,6 +1123,20 @@ bench_function! {
     }
 }
 
+bench_function! {
+    match_any => vrl_stdlib::MatchAny;
+
+    matches {
+        args: func_args![value: "I'm a little teapot", patterns: vec!["^kettle", "teapot$"]],
+        want: Ok(true),
+    }
+
+    no_match {
+        args: func_args![value: "I'm a little teapot", patterns: vec!["^kettle", "mug$"]],
+        want: Ok(false),
+    }
+}
+
 bench_function! {
     md5  => vrl_stdlib::Md5;
 
@@ -488,6 +1201,87 @@ bench_function! {
     }
 }
 
+bench_function! {
+    merge_schemas => vrl_stdlib::MergeSchemas;
+
+    object {
+        args: func_args![
+            a: value!({
+                "type": "object",
+                "properties": { "id": { "type": "integer" }, "name": { "type": "string" } },
+                "required": ["id", "name"]
+            }),
+            b: value!({
+                "type": "object",
+                "properties": { "id": { "type": "string" } },
+                "required": ["id"]
+            }),
+        ],
+        want: Ok(value!({
+            "type": "object",
+            "properties": {
+                "id": { "type": ["integer", "string"] },
+                "name": { "type": "string" }
+            },
+            "required": ["id"]
+        })),
+    }
+}
+
+bench_function! {
+    metaphone => vrl_stdlib::Metaphone;
+
+    literal {
+        args: func_args![value: "Knight"],
+        want: Ok("NT"),
+    }
+}
+
+bench_function! {
+    mime_type => vrl_stdlib::MimeType;
+
+    compound_extension {
+        args: func_args![value: "/backups/site.tar.gz"],
+        want: Ok("application/x-compressed-tar"),
+    }
+}
+
+bench_function! {
+    moving_average => vrl_stdlib::MovingAverage;
+
+    literal {
+        args: func_args![value: value!([1, 2, 3, 4]), window: 2],
+        want: Ok(value!([1.5, 2.5, 3.5])),
+    }
+}
+
+bench_function! {
+    ngrams => vrl_stdlib::Ngrams;
+
+    literal {
+        args: func_args![value: value!(["the", "quick", "brown", "fox"]), n: 2],
+        want: Ok(value!([["the", "quick"], ["quick", "brown"], ["brown", "fox"]])),
+    }
+}
+
+bench_function! {
+    normalize_email => vrl_stdlib::NormalizeEmail;
+
+    strip {
+        args: func_args![value: "first.last+news@Gmail.com", strip_dots: true, strip_tags: true],
+        want: Ok("firstlast@gmail.com"),
+    }
+}
+
+bench_function! {
+    ordinal => vrl_stdlib::Ordinal;
+
+    literal {
+        args: func_args![value: 112],
+        want: Ok("112th"),
+    }
+}
+
 bench_function! {
     parse_aws_alb_log => vrl_stdlib::ParseAwsAlbLog;
 
@@ -665,6 +1459,20 @@ bench_function! {
     }
 }
 
+bench_function! {
+    parse_color => vrl_stdlib::ParseColor;
+
+    hex {
+        args: func_args![value: "#3366cc"],
+        want: Ok(value!({ "r": 51, "g": 102, "b": 204, "a": 1.0 })),
+    }
+
+    named {
+        args: func_args![value: "aliceblue"],
+        want: Ok(value!({ "r": 240, "g": 248, "b": 255, "a": 1.0 })),
+    }
+}
+
 bench_function! {
     parse_common_log => vrl_stdlib::ParseCommonLog;
 
@@ -686,11 +1494,52 @@ bench_function! {
 }
 
 bench_function! {
-    parse_duration => vrl_stdlib::ParseDuration;
+    parse_dns_query => vrl_stdlib::ParseDnsQuery;
+
+    a_record_response {
+        args: func_args![value: Bytes::from_static(b"\x12\x34\x81\x80\x00\x01\x00\x01\x00\x00\x00\x00\x07example\x03com\x00\x00\x01\x00\x01\xc0\x0c\x00\x01\x00\x01\x00\x00\x0e\x10\x00\x04\x5d\xb8\xd8\x22")],
+        want: Ok(value!({
+            "id": 4660,
+            "opcode": 0,
+            "rcode": "NOERROR",
+            "flags": { "qr": true, "aa": false, "tc": false, "rd": true, "ra": true, "ad": false, "cd": false },
+            "questions": [{ "name": "example.com", "type": "A", "class": "IN" }],
+            "answers": [{ "name": "example.com", "type": "A", "class": "IN", "ttl": 3600, "data": "93.184.216.34" }],
+        })),
+    }
+}
+
+bench_function! {
+    parse_duration => vrl_stdlib::ParseDuration;
+
+    literal {
+        args: func_args![value: "1005ms", unit: "s"],
+        want: Ok(1.005),
+    }
+}
+
+bench_function! {
+    parse_email => vrl_stdlib::ParseEmail;
+
+    simple {
+        args: func_args![value: "vector@timber.io"],
+        want: Ok(value!({ "local": "vector", "domain": "timber.io" })),
+    }
+}
+
+bench_function! {
+    parse_fixed_width => vrl_stdlib::ParseFixedWidth;
 
     literal {
-        args: func_args![value: "1005ms", unit: "s"],
-        want: Ok(1.005),
+        args: func_args![
+            value: "00042JOHN     SMITH     ",
+            spec: value!([
+                { "name": "id", "width": 5 },
+                { "name": "first", "width": 9 },
+                { "name": "last", "width": 10 },
+            ]),
+        ],
+        want: Ok(value!({ "id": "00042", "first": "JOHN", "last": "SMITH" })),
     }
 }
 
@@ -727,6 +1576,38 @@ bench_function! {
     }
 }
 
+bench_function! {
+    parse_html => vrl_stdlib::ParseHtml;
+
+    class_selector {
+        args: func_args![value: r#"<ul><li class="a">one</li><li>two</li><li class="a">three</li></ul>"#, selector: ".a"],
+        want: Ok(value!(["one", "three"])),
+    }
+}
+
+bench_function! {
+    parse_influx => vrl_stdlib::ParseInflux;
+
+    line {
+        args: func_args![value: "cpu,host=a usage=0.5,cores=4i 1615000000000000000"],
+        want: Ok(value!({
+            "measurement": "cpu",
+            "tags": { "host": "a" },
+            "fields": { "usage": 0.5, "cores": 4 },
+            "timestamp": (Utc.timestamp(1_615_000_000, 0)),
+        })),
+    }
+}
+
+bench_function! {
+    parse_iso8601_duration => vrl_stdlib::ParseIso8601Duration;
+
+    literal {
+        args: func_args![value: "P1DT2H30M"],
+        want: Ok(95400.0),
+    }
+}
+
 bench_function! {
     parse_json => vrl_stdlib::ParseJson;
 
@@ -753,6 +1634,77 @@ bench_function! {
     }
 }
 
+bench_function! {
+    parse_mac_address => vrl_stdlib::ParseMacAddress;
+
+    dash {
+        args: func_args![value: "00-1B-63-84-45-E6"],
+        want: Ok("00:1b:63:84:45:e6"),
+    }
+}
+
+bench_function! {
+    parse_markdown => vrl_stdlib::ParseMarkdown;
+
+    literal {
+        args: func_args![value: "# Vector\n\nA [fast](https://vector.dev) pipeline.\n\n```sh\nvector --help\n```\n"],
+        want: Ok(value!({
+            "headings": [{ "level": 1, "text": "Vector" }],
+            "paragraphs": ["A fast pipeline."],
+            "code_blocks": [{ "language": "sh", "code": "vector --help\n" }],
+            "links": [{ "text": "fast", "url": "https://vector.dev", "title": null }],
+        })),
+    }
+}
+
+bench_function! {
+    parse_number_array => vrl_stdlib::ParseNumberArray;
+
+    literal {
+        args: func_args![value: "1,2.5,-3,1e3"],
+        want: Ok(value!([1, 2.5, (-3), 1000.0])),
+    }
+}
+
+bench_function! {
+    parse_parquet_schema => vrl_stdlib::ParseParquetSchema;
+
+    single_column {
+        args: func_args![value: Bytes::from_static(b"PAR1\x15\x02\x19\x2cH\x06schema\x15\x02\x00\x15\x04\x25\x00\x18\x02id\x00\x16\x00\x19\x0c\x00\x1d\x00\x00\x00PAR1")],
+        want: Ok(value!({
+            "version": 1,
+            "num_rows": 0,
+            "created_by": null,
+            "columns": [{ "name": "id", "type": "INT64", "repetition": "REQUIRED", "converted_type": null }],
+        })),
+    }
+}
+
+bench_function! {
+    parse_php_serialized => vrl_stdlib::ParsePhpSerialized;
+
+    literal {
+        args: func_args![value: r#"a:2:{s:4:"name";s:6:"vector";s:4:"tags";a:2:{i:0;s:4:"fast";i:1;N;}}"#],
+        want: Ok(value!({ "name": "vector", "tags": ["fast", null] })),
+    }
+}
+
+bench_function! {
+    parse_prometheus => vrl_stdlib::ParsePrometheus;
+
+    counter {
+        args: func_args![value: "# TYPE http_requests_total counter\nhttp_requests_total{code=\"200\"} 1027"],
+        want: Ok(value!([{
+            "name": "http_requests_total",
+            "labels": { "code": "200" },
+            "value": 1027.0,
+            "timestamp": null,
+            "type": "counter",
+            "help": null,
+        }])),
+    }
+}
+
 bench_function! {
     parse_regex => vrl_stdlib::ParseRegex;
 
@@ -822,6 +1774,45 @@ bench_function! {
     }
 }
 
+bench_function! {
+    parse_ruby_hash => vrl_stdlib::ParseRubyHash;
+
+    literal {
+        args: func_args![value: r#"{:name=>"vector", :tags=>[:fast, nil]}"#],
+        want: Ok(value!({ "name": "vector", "tags": ["fast", null] })),
+    }
+}
+
+bench_function! {
+    parse_semver => vrl_stdlib::ParseSemver;
+
+    literal {
+        args: func_args![value: "2.0.0-rc.1+build.5"],
+        want: Ok(value!({
+            "major": 2,
+            "minor": 0,
+            "patch": 0,
+            "prerelease": "rc.1",
+            "build": "build.5",
+        })),
+    }
+}
+
+bench_function! {
+    parse_statsd => vrl_stdlib::ParseStatsd;
+
+    counter {
+        args: func_args![value: "page.views:1|c|@0.5|#env:prod"],
+        want: Ok(value!({
+            "metric": "page.views",
+            "value": 1.0,
+            "type": "counter",
+            "sample_rate": 0.5,
+            "tags": { "env": "prod" },
+        })),
+    }
+}
+
 bench_function! {
     parse_syslog => vrl_stdlib::ParseSyslog;
 
@@ -907,6 +1898,38 @@ bench_function! {
     }
 }
 
+bench_function! {
+    percentile => vrl_stdlib::Percentile;
+
+    literal {
+        args: func_args![value: value!([3, 1, 4, 1, 5, 9, 2, 6]), p: 75],
+        want: Ok(5.25),
+    }
+}
+
+bench_function! {
+    pluralize => vrl_stdlib::Pluralize;
+
+    regular {
+        args: func_args![value: "city", count: 2],
+        want: Ok("cities"),
+    }
+
+    irregular {
+        args: func_args![value: "person", count: 2, plural: "people"],
+        want: Ok("people"),
+    }
+}
+
+bench_function! {
+    public_suffix => vrl_stdlib::PublicSuffix;
+
+    multi_label {
+        args: func_args![value: "a.b.example.co.uk"],
+        want: Ok("co.uk"),
+    }
+}
+
 bench_function! {
     push => vrl_stdlib::Push;
 
@@ -930,6 +1953,65 @@ bench_function! {
 //}
 //}
 
+bench_function! {
+    quantile => vrl_stdlib::Quantile;
+
+    literal {
+        args: func_args![value: value!([3, 1, 4, 1, 5, 9, 2, 6]), q: 0.75],
+        want: Ok(5.25),
+    }
+}
+
+bench_function! {
+    registrable_domain => vrl_stdlib::RegistrableDomain;
+
+    multi_label {
+        args: func_args![value: "a.b.example.co.uk"],
+        want: Ok("example.co.uk"),
+    }
+}
+
+bench_function! {
+    rename_key => vrl_stdlib::RenameKey;
+
+    nested {
+        args: func_args![value: value!({ "foo": { "bar": 1 } }), from: "foo.bar", to: "baz"],
+        want: Ok(value!({ "foo": {}, "baz": 1 })),
+    }
+}
+
+bench_function! {
+    rename_keys => vrl_stdlib::RenameKeys;
+
+    bulk {
+        args: func_args![
+            value: value!({ "foo": 1, "bar": 2 }),
+            mapping: value!({ "foo": "baz", "bar": "qux" }),
+        ],
+        want: Ok(value!({ "baz": 1, "qux": 2 })),
+    }
+}
+
+bench_function! {
+    render => vrl_stdlib::Render;
+
+    compiled {
+        args: func_args![
+            template: value!({ "template": "{{ method }} {{ path }}", "parts": [["method"], " ", ["path"]] }),
+            variables: value!({ "method": "GET", "path": "/" }),
+        ],
+        want: Ok("GET /"),
+    }
+
+    string {
+        args: func_args![
+            template: "{{ method }} {{ path }}",
+            variables: value!({ "method": "GET", "path": "/" }),
+        ],
+        want: Ok("GET /"),
+    }
+}
+
 bench_function! {
     replace => vrl_stdlib::Replace;
 
@@ -966,6 +2048,20 @@ bench_function! {
     }
 }
 
+bench_function! {
+    set_if_absent => vrl_stdlib::SetIfAbsent;
+
+    absent {
+        args: func_args![value: value!({ "foo": 1 }), path: "bar.baz", new: 2],
+        want: Ok(value!({ "foo": 1, "bar": { "baz": 2 } })),
+    }
+
+    present {
+        args: func_args![value: value!({ "foo": 1 }), path: "foo", new: 2],
+        want: Ok(value!({ "foo": 1 })),
+    }
+}
+
 bench_function! {
     sha1 => vrl_stdlib::Sha1;
 
@@ -993,6 +2089,33 @@ bench_function! {
     }
 }
 
+bench_function! {
+    shell_quote => vrl_stdlib::ShellQuote;
+
+    literal {
+        args: func_args![value: "it's here"],
+        want: Ok(r#"'it'\''s here'"#),
+    }
+}
+
+bench_function! {
+    shell_split => vrl_stdlib::ShellSplit;
+
+    literal {
+        args: func_args![value: r#"grep -i "connection refused" /var/log/app\ 1.log"#],
+        want: Ok(value!(["grep", "-i", "connection refused", "/var/log/app 1.log"])),
+    }
+}
+
+bench_function! {
+    sizeof => vrl_stdlib::Sizeof;
+
+    object {
+        args: func_args![value: value!({ "foo": "bar", "baz": [1, 2, 3] })],
+        want: Ok(value!({ "foo": "bar", "baz": [1, 2, 3] }).size_estimate() as i64),
+    }
+}
+
 bench_function! {
     slice => vrl_stdlib::Slice;
 
@@ -1006,6 +2129,33 @@ bench_function! {
     }
 }
 
+bench_function! {
+    slice_bytes => vrl_stdlib::SliceBytes;
+
+    negative_indices {
+        args: func_args![value: "foobar", start: -4, end: -2],
+        want: Ok("ob"),
+    }
+}
+
+bench_function! {
+    snake_case_keys => vrl_stdlib::SnakeCaseKeys;
+
+    nested {
+        args: func_args![value: value!({ "userId": 1, "HTTPServer": { "maxConnections": 2 } })],
+        want: Ok(value!({ "user_id": 1, "http_server": { "max_connections": 2 } })),
+    }
+}
+
+bench_function! {
+    soundex => vrl_stdlib::Soundex;
+
+    literal {
+        args: func_args![value: "Robert"],
+        want: Ok("R163"),
+    }
+}
+
 bench_function! {
     split => vrl_stdlib::Split;
 
@@ -1020,6 +2170,15 @@ bench_function! {
     }
 }
 
+bench_function! {
+    sprintf => vrl_stdlib::Sprintf;
+
+    literal {
+        args: func_args![format: "%s took %.2fms (%d%%)", args: value!(["GET /", 12.3456, 50])],
+        want: Ok("GET / took 12.35ms (50%)"),
+    }
+}
+
 bench_function! {
     starts_with  => vrl_stdlib::StartsWith;
 
@@ -1034,6 +2193,15 @@ bench_function! {
     }
 }
 
+bench_function! {
+    stats => vrl_stdlib::Stats;
+
+    literal {
+        args: func_args![value: value!([3, 1, 4, 1, 5, 9, 2, 6])],
+        want: Ok(value!({ "count": 8, "sum": 31, "min": 1, "max": 9, "mean": 3.875 })),
+    }
+}
+
 bench_function! {
     strip_ansi_escape_codes => vrl_stdlib::StripAnsiEscapeCodes;
 
@@ -1043,6 +2211,15 @@ bench_function! {
     }
 }
 
+bench_function! {
+    strip_html => vrl_stdlib::StripHtml;
+
+    literal {
+        args: func_args![value: "<p>Fish &amp; <b>chips</b></p>"],
+        want: Ok("Fish & chips"),
+    }
+}
+
 bench_function! {
     strip_whitespace => vrl_stdlib::StripWhitespace;
 
@@ -1123,6 +2300,18 @@ bench_function! {
     }
 }
 
+bench_function! {
+    time_ago => vrl_stdlib::TimeAgo;
+
+    past {
+        args: func_args![
+            value: Utc.ymd(2021, 2, 3).and_hms(4, 2, 0),
+            now: Utc.ymd(2021, 2, 3).and_hms(4, 5, 6),
+        ],
+        want: Ok("3 minutes ago"),
+    }
+}
+
 bench_function! {
     to_bool => vrl_stdlib::ToBool;
 
@@ -1274,6 +2463,32 @@ bench_function! {
     }
 }
 
+bench_function! {
+    token_bucket => vrl_stdlib::TokenBucket;
+
+    refill {
+        args: func_args![
+            state: value!({ "tokens": 0.0, "last_refill": (Utc.ymd(2021, 3, 5).and_hms(12, 0, 0)) }),
+            capacity: 10,
+            refill_rate: 2,
+            now: Utc.ymd(2021, 3, 5).and_hms(12, 0, 2),
+        ],
+        want: Ok(value!({
+            "allowed": true,
+            "state": { "tokens": 3.0, "last_refill": (Utc.ymd(2021, 3, 5).and_hms(12, 0, 2)) },
+        })),
+    }
+}
+
+bench_function! {
+    tokenize => vrl_stdlib::Tokenize;
+
+    literal {
+        args: func_args![value: "The quick, brown fox!", lowercase: true, stopwords: value!(["the"])],
+        want: Ok(value!(["quick", "brown", "fox"])),
+    }
+}
+
 bench_function! {
     truncate => vrl_stdlib::Truncate;
 
@@ -1296,6 +2511,24 @@ bench_function! {
     }
 }
 
+bench_function! {
+    try_all => vrl_stdlib::TryAll;
+
+    first {
+        args: func_args![expressions: value!([1, "foo"])],
+        want: Ok(1),
+    }
+}
+
+bench_function! {
+    unique_bloom => vrl_stdlib::UniqueBloom;
+
+    literal {
+        args: func_args![value: value!(["foo", "bar", "foo", 1, 1, { "baz": true }])],
+        want: Ok(value!(["foo", "bar", 1, { "baz": true }])),
+    }
+}
+
 bench_function! {
     upcase => vrl_stdlib::Upcase;
 
@@ -1304,3 +2537,44 @@ bench_function! {
         want: Ok("FOO")
     }
 }
+
+bench_function! {
+    vector_add => vrl_stdlib::VectorAdd;
+
+    literal {
+        args: func_args![a: value!([1, 2, 3.5]), b: value!([4, 5, 6])],
+        want: Ok(value!([5, 7, 9.5])),
+    }
+}
+
+bench_function! {
+    vector_scale => vrl_stdlib::VectorScale;
+
+    literal {
+        args: func_args![value: value!([1, 2, 3.5]), factor: 2],
+        want: Ok(value!([2, 4, 7.0])),
+    }
+}
+
+bench_function! {
+    windows => vrl_stdlib::Windows;
+
+    sliding {
+        args: func_args![value: value!([1, 2, 3, 4]), size: 2],
+        want: Ok(value!([[1, 2], [2, 3], [3, 4]])),
+    }
+
+    partial {
+        args: func_args![value: value!([1, 2, 3, 4, 5]), size: 2, step: 2, partial: true],
+        want: Ok(value!([[1, 2], [3, 4], [5]])),
+    }
+}
+
+bench_function! {
+    xor_bytes => vrl_stdlib::XorBytes;
+
+    repeating_key {
+        args: func_args![value: "secret", key: "key"],
+        want: Ok(Bytes::from_static(b"\x18\x00\x1a\x19\x00\x0d")),
+    }
+}
@@ -0,0 +1,134 @@
+use vrl::prelude::*;
+
+#[derive(Clone, Copy, Debug)]
+pub struct Abort;
+
+impl Function for Abort {
+    fn identifier(&self) -> &'static str {
+        "abort"
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[
+            Parameter {
+                keyword: "message",
+                kind: kind::BYTES,
+                required: true,
+            },
+            Parameter {
+                keyword: "payload",
+                kind: kind::OBJECT,
+                required: false,
+            },
+        ]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "message",
+                source: r#"abort!("invalid event")"#,
+                result: Err(r#"function call error for "abort" at (0:23): invalid event"#),
+            },
+            Example {
+                title: "structured payload",
+                source: r#"abort!("invalid event", { "reason": "missing field" })"#,
+                result: Err(r#"function call error for "abort" at (0:54): invalid event"#),
+            },
+        ]
+    }
+
+    fn compile(&self, mut arguments: ArgumentList) -> Compiled {
+        let message = arguments.required("message");
+        let payload = arguments.optional("payload");
+
+        Ok(Box::new(AbortFn { message, payload }))
+    }
+}
+
+#[derive(Debug, Clone)]
+struct AbortFn {
+    message: Box<dyn Expression>,
+    payload: Option<Box<dyn Expression>>,
+}
+
+impl Expression for AbortFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let message = self.message.resolve(ctx)?;
+        let message = message.try_bytes_utf8_lossy()?.into_owned();
+
+        let payload = self
+            .payload
+            .as_ref()
+            .map(|payload| -> Result<Value> { Ok(payload.resolve(ctx)?.try_object()?.into()) })
+            .transpose()?;
+
+        Err(ExpressionError {
+            code: 700,
+            message,
+            payload,
+            ..Default::default()
+        })
+    }
+
+    fn type_def(&self, _: &state::Compiler) -> TypeDef {
+        TypeDef::new().fallible().null()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    test_function![
+        abort => Abort;
+
+        message {
+            args: func_args![message: "invalid event"],
+            want: Err("invalid event"),
+            tdef: TypeDef::new().fallible().null(),
+        }
+
+        payload {
+            args: func_args![
+                message: "invalid event",
+                payload: value!({ "reason": "missing field" }),
+            ],
+            want: Err("invalid event"),
+            tdef: TypeDef::new().fallible().null(),
+        }
+    ];
+
+    #[test]
+    fn payload_survives_runtime() {
+        let source = r#"abort!("invalid event", { "reason": "missing field", "code": 42 })"#;
+        let program = vrl::compile(source, &[Box::new(Abort)]).unwrap();
+
+        let mut target = value!({});
+        let err = vrl::Runtime::default()
+            .resolve(&mut target, &program)
+            .unwrap_err();
+
+        assert_eq!(
+            err.message(),
+            r#"function call error for "abort" at (0:66): invalid event"#
+        );
+        assert_eq!(err.code(), 700);
+        assert_eq!(
+            err.payload(),
+            Some(&value!({ "reason": "missing field", "code": 42 }))
+        );
+    }
+
+    #[test]
+    fn no_payload() {
+        let program = vrl::compile(r#"abort!("invalid event")"#, &[Box::new(Abort)]).unwrap();
+
+        let mut target = value!({});
+        let err = vrl::Runtime::default()
+            .resolve(&mut target, &program)
+            .unwrap_err();
+
+        assert_eq!(err.payload(), None);
+    }
+}
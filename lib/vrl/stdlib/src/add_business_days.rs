@@ -0,0 +1,191 @@
+use crate::calendar_util::BusinessCalendar;
+use vrl::prelude::*;
+
+#[derive(Clone, Copy, Debug)]
+pub struct AddBusinessDays;
+
+impl Function for AddBusinessDays {
+    fn identifier(&self) -> &'static str {
+        "add_business_days"
+    }
+
+    fn is_pure(&self) -> bool {
+        true
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[
+            Parameter {
+                keyword: "value",
+                kind: kind::TIMESTAMP,
+                required: true,
+            },
+            Parameter {
+                keyword: "days",
+                kind: kind::INTEGER,
+                required: true,
+            },
+            Parameter {
+                keyword: "holidays",
+                kind: kind::ARRAY,
+                required: false,
+            },
+            Parameter {
+                keyword: "timezone",
+                kind: kind::BYTES,
+                required: false,
+            },
+        ]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "weekend",
+                source: r#"add_business_days!(t'2021-03-05T12:00:00Z', 1)"#,
+                result: Ok("t'2021-03-08T12:00:00Z'"),
+            },
+            Example {
+                title: "backwards",
+                source: r#"add_business_days!(t'2021-03-08T12:00:00Z', -1)"#,
+                result: Ok("t'2021-03-05T12:00:00Z'"),
+            },
+        ]
+    }
+
+    fn compile(&self, mut arguments: ArgumentList) -> Compiled {
+        let value = arguments.required("value");
+        let days = arguments.required("days");
+        let holidays = arguments.optional("holidays");
+        let timezone = arguments.optional("timezone");
+
+        Ok(Box::new(AddBusinessDaysFn {
+            value,
+            days,
+            holidays,
+            timezone,
+        }))
+    }
+}
+
+#[derive(Debug, Clone)]
+struct AddBusinessDaysFn {
+    value: Box<dyn Expression>,
+    days: Box<dyn Expression>,
+    holidays: Option<Box<dyn Expression>>,
+    timezone: Option<Box<dyn Expression>>,
+}
+
+impl Expression for AddBusinessDaysFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?.try_timestamp()?;
+        let days = self.days.resolve(ctx)?.try_integer()?;
+
+        let holidays = match &self.holidays {
+            Some(holidays) => holidays.resolve(ctx)?.try_array()?,
+            None => vec![],
+        };
+
+        let timezone = match &self.timezone {
+            Some(timezone) => {
+                String::from_utf8_lossy(&timezone.resolve(ctx)?.try_bytes()?).into_owned()
+            }
+            None => "UTC".to_owned(),
+        };
+
+        let calendar = BusinessCalendar::new(&timezone, holidays)?;
+
+        calendar
+            .add_days(value, days)
+            .map(Into::into)
+            .ok_or_else(|| "timestamp out of range".into())
+    }
+
+    fn type_def(&self, _: &state::Compiler) -> TypeDef {
+        TypeDef::new().fallible().timestamp()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{TimeZone, Utc};
+
+    test_function![
+        add_business_days => AddBusinessDays;
+
+        over_weekend {
+            args: func_args![value: Utc.ymd(2021, 3, 5).and_hms(12, 0, 0), days: 1],
+            want: Ok(Utc.ymd(2021, 3, 8).and_hms(12, 0, 0)),
+            tdef: TypeDef::new().fallible().timestamp(),
+        }
+
+        from_weekend {
+            args: func_args![value: Utc.ymd(2021, 3, 6).and_hms(12, 0, 0), days: 1],
+            want: Ok(Utc.ymd(2021, 3, 8).and_hms(12, 0, 0)),
+            tdef: TypeDef::new().fallible().timestamp(),
+        }
+
+        zero {
+            args: func_args![value: Utc.ymd(2021, 3, 6).and_hms(12, 0, 0), days: 0],
+            want: Ok(Utc.ymd(2021, 3, 6).and_hms(12, 0, 0)),
+            tdef: TypeDef::new().fallible().timestamp(),
+        }
+
+        negative {
+            args: func_args![value: Utc.ymd(2021, 3, 8).and_hms(12, 0, 0), days: -1],
+            want: Ok(Utc.ymd(2021, 3, 5).and_hms(12, 0, 0)),
+            tdef: TypeDef::new().fallible().timestamp(),
+        }
+
+        negative_with_holiday {
+            args: func_args![
+                value: Utc.ymd(2021, 3, 3).and_hms(12, 0, 0),
+                days: -3,
+                holidays: value!(["2021-03-01"]),
+            ],
+            want: Ok(Utc.ymd(2021, 2, 25).and_hms(12, 0, 0)),
+            tdef: TypeDef::new().fallible().timestamp(),
+        }
+
+        with_holiday {
+            args: func_args![
+                value: Utc.ymd(2021, 3, 1).and_hms(12, 0, 0),
+                days: 5,
+                holidays: value!(["2021-03-03"]),
+            ],
+            want: Ok(Utc.ymd(2021, 3, 9).and_hms(12, 0, 0)),
+            tdef: TypeDef::new().fallible().timestamp(),
+        }
+
+        timezone {
+            args: func_args![
+                value: Utc.ymd(2021, 3, 6).and_hms(2, 0, 0),
+                days: 1,
+                timezone: "America/New_York",
+            ],
+            want: Ok(Utc.ymd(2021, 3, 9).and_hms(2, 0, 0)),
+            tdef: TypeDef::new().fallible().timestamp(),
+        }
+
+        timezone_keeps_local_time_over_dst {
+            args: func_args![
+                value: Utc.ymd(2021, 3, 12).and_hms(17, 0, 0),
+                days: 1,
+                timezone: "America/New_York",
+            ],
+            want: Ok(Utc.ymd(2021, 3, 15).and_hms(16, 0, 0)),
+            tdef: TypeDef::new().fallible().timestamp(),
+        }
+
+        error_timezone {
+            args: func_args![
+                value: Utc.ymd(2021, 3, 5).and_hms(12, 0, 0),
+                days: 1,
+                timezone: "Mars/Olympus_Mons",
+            ],
+            want: Err(r#"unknown timezone "Mars/Olympus_Mons""#),
+            tdef: TypeDef::new().fallible().timestamp(),
+        }
+    ];
+}
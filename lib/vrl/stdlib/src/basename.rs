@@ -0,0 +1,128 @@
+use crate::path_util;
+use vrl::prelude::*;
+
+#[derive(Clone, Copy, Debug)]
+pub struct Basename;
+
+impl Function for Basename {
+    fn identifier(&self) -> &'static str {
+        "basename"
+    }
+
+    fn is_pure(&self) -> bool {
+        true
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[
+            Parameter {
+                keyword: "value",
+                kind: kind::BYTES,
+                required: true,
+            },
+            Parameter {
+                keyword: "windows",
+                kind: kind::BOOLEAN,
+                required: false,
+            },
+        ]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "file",
+                source: r#"basename("/var/log/syslog.1")"#,
+                result: Ok("syslog.1"),
+            },
+            Example {
+                title: "windows",
+                source: r#"basename(s'C:\Logs\app.log', windows: true)"#,
+                result: Ok("app.log"),
+            },
+        ]
+    }
+
+    fn compile(&self, mut arguments: ArgumentList) -> Compiled {
+        let value = arguments.required("value");
+        let windows = arguments.optional("windows").unwrap_or(expr!(false));
+
+        Ok(Box::new(BasenameFn { value, windows }))
+    }
+}
+
+#[derive(Debug, Clone)]
+struct BasenameFn {
+    value: Box<dyn Expression>,
+    windows: Box<dyn Expression>,
+}
+
+impl Expression for BasenameFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?.try_bytes()?;
+        let windows = self.windows.resolve(ctx)?.try_boolean()?;
+
+        Ok(path_util::basename(&String::from_utf8_lossy(&value), windows).into())
+    }
+
+    fn type_def(&self, _: &state::Compiler) -> TypeDef {
+        TypeDef::new().infallible().bytes()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    test_function![
+        basename => Basename;
+
+        file {
+            args: func_args![value: "/var/log/syslog.1"],
+            want: Ok("syslog.1"),
+            tdef: TypeDef::new().infallible().bytes(),
+        }
+
+        relative {
+            args: func_args![value: "syslog"],
+            want: Ok("syslog"),
+            tdef: TypeDef::new().infallible().bytes(),
+        }
+
+        hidden_file {
+            args: func_args![value: "/home/user/.bashrc"],
+            want: Ok(".bashrc"),
+            tdef: TypeDef::new().infallible().bytes(),
+        }
+
+        trailing_slashes {
+            args: func_args![value: "/var/log//"],
+            want: Ok("log"),
+            tdef: TypeDef::new().infallible().bytes(),
+        }
+
+        root {
+            args: func_args![value: "/"],
+            want: Ok(""),
+            tdef: TypeDef::new().infallible().bytes(),
+        }
+
+        backslashes_without_windows {
+            args: func_args![value: r#"C:\Logs\app.log"#],
+            want: Ok(r#"C:\Logs\app.log"#),
+            tdef: TypeDef::new().infallible().bytes(),
+        }
+
+        windows {
+            args: func_args![value: r#"C:\Logs/app.log"#, windows: true],
+            want: Ok("app.log"),
+            tdef: TypeDef::new().infallible().bytes(),
+        }
+
+        windows_drive {
+            args: func_args![value: r#"C:\"#, windows: true],
+            want: Ok(""),
+            tdef: TypeDef::new().infallible().bytes(),
+        }
+    ];
+}
@@ -0,0 +1,235 @@
+use crate::calendar_util::BusinessCalendar;
+use vrl::prelude::*;
+
+#[derive(Clone, Copy, Debug)]
+pub struct BusinessDaysBetween;
+
+impl Function for BusinessDaysBetween {
+    fn identifier(&self) -> &'static str {
+        "business_days_between"
+    }
+
+    fn is_pure(&self) -> bool {
+        true
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[
+            Parameter {
+                keyword: "start",
+                kind: kind::TIMESTAMP,
+                required: true,
+            },
+            Parameter {
+                keyword: "end",
+                kind: kind::TIMESTAMP,
+                required: true,
+            },
+            Parameter {
+                keyword: "holidays",
+                kind: kind::ARRAY,
+                required: false,
+            },
+            Parameter {
+                keyword: "timezone",
+                kind: kind::BYTES,
+                required: false,
+            },
+        ]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "weekend",
+                source: r#"business_days_between(t'2021-03-05T12:00:00Z', t'2021-03-08T12:00:00Z')"#,
+                result: Ok("1"),
+            },
+            Example {
+                title: "holidays",
+                source: r#"business_days_between!(t'2021-03-01T12:00:00Z', t'2021-03-08T12:00:00Z', holidays: ["2021-03-03"])"#,
+                result: Ok("4"),
+            },
+        ]
+    }
+
+    fn compile(&self, mut arguments: ArgumentList) -> Compiled {
+        let start = arguments.required("start");
+        let end = arguments.required("end");
+        let holidays = arguments.optional("holidays");
+        let timezone = arguments.optional("timezone");
+
+        Ok(Box::new(BusinessDaysBetweenFn {
+            start,
+            end,
+            holidays,
+            timezone,
+        }))
+    }
+}
+
+#[derive(Debug, Clone)]
+struct BusinessDaysBetweenFn {
+    start: Box<dyn Expression>,
+    end: Box<dyn Expression>,
+    holidays: Option<Box<dyn Expression>>,
+    timezone: Option<Box<dyn Expression>>,
+}
+
+impl Expression for BusinessDaysBetweenFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let start = self.start.resolve(ctx)?.try_timestamp()?;
+        let end = self.end.resolve(ctx)?.try_timestamp()?;
+
+        let holidays = match &self.holidays {
+            Some(holidays) => holidays.resolve(ctx)?.try_array()?,
+            None => vec![],
+        };
+
+        let timezone = match &self.timezone {
+            Some(timezone) => {
+                String::from_utf8_lossy(&timezone.resolve(ctx)?.try_bytes()?).into_owned()
+            }
+            None => "UTC".to_owned(),
+        };
+
+        let calendar = BusinessCalendar::new(&timezone, holidays)?;
+
+        Ok(calendar.days_between(start, end).into())
+    }
+
+    fn type_def(&self, _: &state::Compiler) -> TypeDef {
+        TypeDef::new()
+            .integer()
+            .with_fallibility(self.holidays.is_some() || self.timezone.is_some())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{TimeZone, Utc};
+
+    test_function![
+        business_days_between => BusinessDaysBetween;
+
+        weekend {
+            args: func_args![
+                start: Utc.ymd(2021, 3, 5).and_hms(12, 0, 0),
+                end: Utc.ymd(2021, 3, 8).and_hms(12, 0, 0),
+            ],
+            want: Ok(1),
+            tdef: TypeDef::new().infallible().integer(),
+        }
+
+        week {
+            args: func_args![
+                start: Utc.ymd(2021, 3, 1).and_hms(12, 0, 0),
+                end: Utc.ymd(2021, 3, 8).and_hms(9, 0, 0),
+            ],
+            want: Ok(5),
+            tdef: TypeDef::new().infallible().integer(),
+        }
+
+        month {
+            args: func_args![
+                start: Utc.ymd(2021, 3, 1).and_hms(0, 0, 0),
+                end: Utc.ymd(2021, 3, 31).and_hms(0, 0, 0),
+            ],
+            want: Ok(22),
+            tdef: TypeDef::new().infallible().integer(),
+        }
+
+        same_day {
+            args: func_args![
+                start: Utc.ymd(2021, 3, 1).and_hms(9, 0, 0),
+                end: Utc.ymd(2021, 3, 1).and_hms(17, 0, 0),
+            ],
+            want: Ok(0),
+            tdef: TypeDef::new().infallible().integer(),
+        }
+
+        reversed {
+            args: func_args![
+                start: Utc.ymd(2021, 3, 8).and_hms(12, 0, 0),
+                end: Utc.ymd(2021, 3, 1).and_hms(12, 0, 0),
+            ],
+            want: Ok(-5),
+            tdef: TypeDef::new().infallible().integer(),
+        }
+
+        holiday {
+            args: func_args![
+                start: Utc.ymd(2021, 3, 1).and_hms(12, 0, 0),
+                end: Utc.ymd(2021, 3, 8).and_hms(12, 0, 0),
+                holidays: value!(["2021-03-03"]),
+            ],
+            want: Ok(4),
+            tdef: TypeDef::new().fallible().integer(),
+        }
+
+        holiday_timestamps_and_weekends {
+            args: func_args![
+                start: Utc.ymd(2021, 3, 1).and_hms(12, 0, 0),
+                end: Utc.ymd(2021, 3, 8).and_hms(12, 0, 0),
+                holidays: vec![
+                    Value::from(Utc.ymd(2021, 3, 3).and_hms(0, 0, 0)),
+                    Value::from("2021-03-06"),
+                    Value::from("2021-03-08"),
+                ],
+            ],
+            want: Ok(4),
+            tdef: TypeDef::new().fallible().integer(),
+        }
+
+        timezone_utc {
+            args: func_args![
+                start: Utc.ymd(2021, 3, 6).and_hms(2, 0, 0),
+                end: Utc.ymd(2021, 3, 8).and_hms(12, 0, 0),
+                timezone: "UTC",
+            ],
+            want: Ok(0),
+            tdef: TypeDef::new().fallible().integer(),
+        }
+
+        timezone_named {
+            args: func_args![
+                start: Utc.ymd(2021, 3, 6).and_hms(2, 0, 0),
+                end: Utc.ymd(2021, 3, 8).and_hms(12, 0, 0),
+                timezone: "America/New_York",
+            ],
+            want: Ok(1),
+            tdef: TypeDef::new().fallible().integer(),
+        }
+
+        error_timezone {
+            args: func_args![
+                start: Utc.ymd(2021, 3, 1).and_hms(12, 0, 0),
+                end: Utc.ymd(2021, 3, 8).and_hms(12, 0, 0),
+                timezone: "Mars/Olympus_Mons",
+            ],
+            want: Err(r#"unknown timezone "Mars/Olympus_Mons""#),
+            tdef: TypeDef::new().fallible().integer(),
+        }
+
+        error_holiday_format {
+            args: func_args![
+                start: Utc.ymd(2021, 3, 1).and_hms(12, 0, 0),
+                end: Utc.ymd(2021, 3, 8).and_hms(12, 0, 0),
+                holidays: value!(["March 3rd"]),
+            ],
+            want: Err(r#"invalid holiday "March 3rd", expected YYYY-MM-DD"#),
+            tdef: TypeDef::new().fallible().integer(),
+        }
+
+        error_holiday_kind {
+            args: func_args![
+                start: Utc.ymd(2021, 3, 1).and_hms(12, 0, 0),
+                end: Utc.ymd(2021, 3, 8).and_hms(12, 0, 0),
+                holidays: value!([1]),
+            ],
+            want: Err("expected holidays to be timestamps or strings, got integer"),
+            tdef: TypeDef::new().fallible().integer(),
+        }
+    ];
+}
@@ -0,0 +1,153 @@
+use chrono::{DateTime, Datelike, Local, NaiveDate, Utc, Weekday};
+use vrl::Value;
+
+/// The business days of a calendar: the weekdays which aren't holidays, the
+/// days starting and ending at midnight in the calendar's time zone.
+#[derive(Debug)]
+pub(crate) struct BusinessCalendar {
+    timezone: shared::TimeZone,
+    holidays: std::collections::BTreeSet<NaiveDate>,
+}
+
+impl BusinessCalendar {
+    /// Creates the calendar of the given time zone, such as `local` or
+    /// `America/New_York`, and holidays, which are either timestamps or dates
+    /// formatted as `YYYY-MM-DD`.
+    pub(crate) fn new(timezone: &str, holidays: Vec<Value>) -> std::result::Result<Self, String> {
+        let timezone = shared::TimeZone::parse(timezone)
+            .ok_or_else(|| format!(r#"unknown timezone "{}""#, timezone))?;
+
+        let mut calendar = Self {
+            timezone,
+            holidays: Default::default(),
+        };
+
+        for holiday in holidays {
+            let date = match holiday {
+                Value::Timestamp(timestamp) => calendar.date(timestamp),
+                Value::Bytes(bytes) => {
+                    let date = String::from_utf8_lossy(&bytes);
+
+                    NaiveDate::parse_from_str(&date, "%Y-%m-%d").map_err(|_| {
+                        format!(r#"invalid holiday "{}", expected YYYY-MM-DD"#, date)
+                    })?
+                }
+                value => {
+                    return Err(format!(
+                        "expected holidays to be timestamps or strings, got {}",
+                        value.kind().as_str()
+                    ))
+                }
+            };
+
+            calendar.holidays.insert(date);
+        }
+
+        Ok(calendar)
+    }
+
+    /// The date of the timestamp in the calendar's time zone.
+    pub(crate) fn date(&self, timestamp: DateTime<Utc>) -> NaiveDate {
+        match self.timezone {
+            shared::TimeZone::Local => timestamp.with_timezone(&Local).naive_local().date(),
+            shared::TimeZone::Named(tz) => timestamp.with_timezone(&tz).naive_local().date(),
+        }
+    }
+
+    /// The number of business days from the date of `start`, included, to the
+    /// date of `end`, excluded, or minus the number of business days from the
+    /// date of `end` to the date of `start` if `end` comes first.
+    #[cfg(feature = "business_days_between")]
+    pub(crate) fn days_between(&self, start: DateTime<Utc>, end: DateTime<Utc>) -> i64 {
+        let (start, end) = (self.date(start), self.date(end));
+
+        if end < start {
+            -self.count_days(end, start)
+        } else {
+            self.count_days(start, end)
+        }
+    }
+
+    #[cfg(feature = "business_days_between")]
+    fn count_days(&self, start: NaiveDate, end: NaiveDate) -> i64 {
+        // Every full week has five weekdays, only the remaining days are
+        // checked one by one.
+        let days = end.signed_duration_since(start).num_days();
+        let mut weekdays = days / 7 * 5;
+
+        let mut date = start + chrono::Duration::days(days / 7 * 7);
+        while date < end {
+            if !is_weekend(date) {
+                weekdays += 1;
+            }
+            date = date.succ();
+        }
+
+        let holidays = self
+            .holidays
+            .range(start..end)
+            .filter(|date| !is_weekend(**date))
+            .count() as i64;
+
+        weekdays - holidays
+    }
+
+    /// Moves the timestamp by the given number of business days, backwards if
+    /// negative, keeping its time of day in the calendar's time zone.
+    ///
+    /// The date of the timestamp itself isn't counted, so one business day
+    /// after a Friday is the next Monday, whether the timestamp is on a
+    /// business day or not.
+    ///
+    /// Returns `None` if the resulting timestamp is out of range.
+    #[cfg(feature = "add_business_days")]
+    pub(crate) fn add_days(&self, timestamp: DateTime<Utc>, days: i64) -> Option<DateTime<Utc>> {
+        let mut date = self.date(timestamp);
+        let mut remaining = days.checked_abs()?;
+
+        while remaining > 0 {
+            date = if days > 0 {
+                date.succ_opt()?
+            } else {
+                date.pred_opt()?
+            };
+
+            if !is_weekend(date) && !self.holidays.contains(&date) {
+                remaining -= 1;
+            }
+        }
+
+        match self.timezone {
+            shared::TimeZone::Local => with_date(&Local, timestamp, date),
+            shared::TimeZone::Named(tz) => with_date(&tz, timestamp, date),
+        }
+    }
+}
+
+fn is_weekend(date: NaiveDate) -> bool {
+    matches!(date.weekday(), Weekday::Sat | Weekday::Sun)
+}
+
+/// Moves the timestamp to the given date in the time zone, at the same time of
+/// day.
+#[cfg(feature = "add_business_days")]
+fn with_date<T: chrono::TimeZone>(
+    tz: &T,
+    timestamp: DateTime<Utc>,
+    date: NaiveDate,
+) -> Option<DateTime<Utc>> {
+    let local = timestamp.with_timezone(tz);
+
+    match tz
+        .from_local_datetime(&date.and_time(local.time()))
+        .earliest()
+    {
+        Some(moved) => Some(moved.with_timezone(&Utc)),
+
+        // The time of day is skipped on that date by a daylight saving time
+        // transition, the timestamp is moved by whole days instead.
+        None => {
+            timestamp.checked_add_signed(date.signed_duration_since(local.naive_local().date()))
+        }
+    }
+}
@@ -0,0 +1,160 @@
+use crate::util;
+use vrl::prelude::*;
+
+#[derive(Clone, Copy, Debug)]
+pub struct CamelCaseKeys;
+
+impl Function for CamelCaseKeys {
+    fn identifier(&self) -> &'static str {
+        "camel_case_keys"
+    }
+
+    fn is_pure(&self) -> bool {
+        true
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[
+            Parameter {
+                keyword: "value",
+                kind: kind::OBJECT | kind::ARRAY,
+                required: true,
+            },
+            Parameter {
+                keyword: "overwrite",
+                kind: kind::BOOLEAN,
+                required: false,
+            },
+        ]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "nested object",
+                source: r#"camel_case_keys!({ "user_id": 1, "http_server": { "max_connections": 2 } })"#,
+                result: Ok(r#"{ "httpServer": { "maxConnections": 2 }, "userId": 1 }"#),
+            },
+            Example {
+                title: "collision",
+                source: r#"camel_case_keys!({ "userId": 1, "user_id": 2 }, overwrite: false)"#,
+                result: Err(
+                    r#"function call error for "camel_case_keys" at (0:65): keys "userId" and "user_id" both convert to "userId""#,
+                ),
+            },
+        ]
+    }
+
+    fn compile(&self, mut arguments: ArgumentList) -> Compiled {
+        let value = arguments.required("value");
+        let overwrite = arguments
+            .optional("overwrite")
+            .unwrap_or_else(|| expr!(true));
+
+        Ok(Box::new(CamelCaseKeysFn { value, overwrite }))
+    }
+}
+
+#[derive(Debug, Clone)]
+struct CamelCaseKeysFn {
+    value: Box<dyn Expression>,
+    overwrite: Box<dyn Expression>,
+}
+
+impl Expression for CamelCaseKeysFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?;
+        let overwrite = self.overwrite.resolve(ctx)?.try_boolean()?;
+
+        Ok(util::convert_keys(value, &camel_case, overwrite)?)
+    }
+
+    fn type_def(&self, state: &state::Compiler) -> TypeDef {
+        let td = self.value.type_def(state);
+
+        if td.is_array() {
+            TypeDef::new()
+                .fallible()
+                .array_mapped::<(), Kind>(map! { (): Kind::all() })
+        } else {
+            TypeDef::new()
+                .fallible()
+                .object::<(), Kind>(map! { (): Kind::all() })
+        }
+    }
+}
+
+/// Converts the key to `camelCase`, leaving keys without any word untouched.
+fn camel_case(key: &str) -> String {
+    let words = util::identifier_words(key);
+
+    if words.is_empty() {
+        return key.to_owned();
+    }
+
+    let mut camel = words[0].to_lowercase();
+    for word in &words[1..] {
+        let mut chars = word.chars();
+        if let Some(first) = chars.next() {
+            camel.extend(first.to_uppercase());
+            camel.push_str(&chars.as_str().to_lowercase());
+        }
+    }
+
+    camel
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    test_function![
+        camel_case_keys => CamelCaseKeys;
+
+        flat {
+            args: func_args![value: value!({
+                "user_id": 1,
+                "UserName": 2,
+                "HTTPServer": 3,
+                "user-agent": 4,
+                "alreadyCamel": 5,
+                "version_2_count": 6,
+                "_": 7,
+            })],
+            want: Ok(value!({
+                "userId": 1,
+                "userName": 2,
+                "httpServer": 3,
+                "userAgent": 4,
+                "alreadyCamel": 5,
+                "version2Count": 6,
+                "_": 7,
+            })),
+            tdef: TypeDef::new().fallible().object::<(), Kind>(map! { (): Kind::all() }),
+        }
+
+        deeply_nested {
+            args: func_args![value: value!({ "outer_key": { "middle_key": { "inner_key": "snake_case" } } })],
+            want: Ok(value!({ "outerKey": { "middleKey": { "innerKey": "snake_case" } } })),
+            tdef: TypeDef::new().fallible().object::<(), Kind>(map! { (): Kind::all() }),
+        }
+
+        array_of_objects {
+            args: func_args![value: value!([{ "first_name": "a" }, [{ "last_name": "b" }], "other_value"])],
+            want: Ok(value!([{ "firstName": "a" }, [{ "lastName": "b" }], "other_value"])),
+            tdef: TypeDef::new().fallible().array_mapped::<(), Kind>(map! { (): Kind::all() }),
+        }
+
+        collision_overwrite {
+            args: func_args![value: value!({ "userId": 1, "user_id": 2 })],
+            want: Ok(value!({ "userId": 2 })),
+            tdef: TypeDef::new().fallible().object::<(), Kind>(map! { (): Kind::all() }),
+        }
+
+        collision_error {
+            args: func_args![value: value!({ "userId": 1, "user_id": 2 }), overwrite: false],
+            want: Err(r#"keys "userId" and "user_id" both convert to "userId""#),
+            tdef: TypeDef::new().fallible().object::<(), Kind>(map! { (): Kind::all() }),
+        }
+    ];
+}
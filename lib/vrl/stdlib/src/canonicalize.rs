@@ -0,0 +1,119 @@
+use vrl::prelude::*;
+
+#[derive(Clone, Copy, Debug)]
+pub struct Canonicalize;
+
+impl Function for Canonicalize {
+    fn identifier(&self) -> &'static str {
+        "canonicalize"
+    }
+
+    fn is_pure(&self) -> bool {
+        true
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[
+            Parameter {
+                keyword: "value",
+                kind: kind::ANY,
+                required: true,
+            },
+            Parameter {
+                keyword: "unify_numbers",
+                kind: kind::BOOLEAN,
+                required: false,
+            },
+        ]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "object",
+                source: r#"canonicalize({ "b": 1.0, "a": [1, "1"] })"#,
+                result: Ok(r#"s'{"a":[1,"1"],"b":1.0}'"#),
+            },
+            Example {
+                title: "unify numbers",
+                source: r#"canonicalize({ "b": 1.0, "a": [1, "1"] }, unify_numbers: true)"#,
+                result: Ok(r#"s'{"a":[1,"1"],"b":1}'"#),
+            },
+            Example {
+                title: "hash",
+                source: r#"sha2(canonicalize({ "b": 2, "a": 1 })) == sha2(canonicalize({ "a": 1, "b": 2 }))"#,
+                result: Ok("true"),
+            },
+        ]
+    }
+
+    fn compile(&self, mut arguments: ArgumentList) -> Compiled {
+        let value = arguments.required("value");
+        let unify_numbers = arguments
+            .optional("unify_numbers")
+            .unwrap_or_else(|| expr!(false));
+
+        Ok(Box::new(CanonicalizeFn {
+            value,
+            unify_numbers,
+        }))
+    }
+}
+
+#[derive(Debug, Clone)]
+struct CanonicalizeFn {
+    value: Box<dyn Expression>,
+    unify_numbers: Box<dyn Expression>,
+}
+
+impl Expression for CanonicalizeFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?;
+        let unify_numbers = self.unify_numbers.resolve(ctx)?.try_boolean()?;
+
+        Ok(value.canonicalize(unify_numbers).into())
+    }
+
+    fn type_def(&self, _: &state::Compiler) -> TypeDef {
+        TypeDef::new().infallible().bytes()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    test_function![
+        canonicalize => Canonicalize;
+
+        key_order {
+            args: func_args![value: value!({ "b": { "d": 1, "c": null }, "a": [true] })],
+            want: Ok(r#"{"a":[true],"b":{"c":null,"d":1}}"#),
+            tdef: TypeDef::new().infallible().bytes(),
+        }
+
+        integer {
+            args: func_args![value: 1],
+            want: Ok("1"),
+            tdef: TypeDef::new().infallible().bytes(),
+        }
+
+        float {
+            args: func_args![value: 1.0],
+            want: Ok("1.0"),
+            tdef: TypeDef::new().infallible().bytes(),
+        }
+
+        unified_float {
+            args: func_args![value: 1.0, unify_numbers: true],
+            want: Ok("1"),
+            tdef: TypeDef::new().infallible().bytes(),
+        }
+
+        string {
+            args: func_args![value: "1"],
+            want: Ok(r#""1""#),
+            tdef: TypeDef::new().infallible().bytes(),
+        }
+    ];
+}
@@ -0,0 +1,161 @@
+use std::collections::BTreeMap;
+use vrl::prelude::*;
+
+#[derive(Clone, Copy, Debug)]
+pub struct CaptureError;
+
+impl Function for CaptureError {
+    fn identifier(&self) -> &'static str {
+        "capture_error"
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[Parameter {
+            keyword: "value",
+            kind: kind::ANY,
+            required: true,
+        }]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "success",
+                source: r#"capture_error(parse_json("[1]"))"#,
+                result: Ok(r#"{ "ok": [1] }"#),
+            },
+            Example {
+                title: "error",
+                source: r#"capture_error(parse_json("{"))"#,
+                result: Ok(indoc! {r#"
+                    {
+                        "code": 705,
+                        "error": "function call error for \"parse_json\" at (14:29): unable to parse json: EOF while parsing an object at line 1 column 1"
+                    }
+                "#}),
+            },
+        ]
+    }
+
+    fn compile(&self, mut arguments: ArgumentList) -> Compiled {
+        let value = arguments.required("value");
+
+        Ok(Box::new(CaptureErrorFn { value }))
+    }
+
+    fn handles_errors(&self) -> bool {
+        true
+    }
+}
+
+#[derive(Debug, Clone)]
+struct CaptureErrorFn {
+    value: Box<dyn Expression>,
+}
+
+impl Expression for CaptureErrorFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let captured: BTreeMap<String, Value> = match self.value.resolve(ctx) {
+            Ok(value) => map!["ok": value],
+            Err(err) => map!["error": err.message, "code": err.code as i64],
+        };
+
+        Ok(captured.into())
+    }
+
+    fn type_def(&self, state: &state::Compiler) -> TypeDef {
+        let value = self.value.type_def(state);
+
+        // Only the fields of the outcome that can happen are known to exist.
+        let mut fields = BTreeMap::<&str, TypeDef>::new();
+        fields.insert("ok", value.clone().infallible());
+        if value.is_fallible() {
+            fields.insert("error", Kind::Bytes.into());
+            fields.insert("code", Kind::Integer.into());
+        }
+
+        TypeDef::new().infallible().object(fields)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    test_function![
+        capture_error => CaptureError;
+
+        ok {
+            args: func_args![value: "foo"],
+            want: Ok(value!({ "ok": "foo" })),
+            tdef: TypeDef::new().infallible().object::<&str, TypeDef>(map! {
+                "ok": Kind::Bytes,
+            }),
+        }
+    ];
+
+    fn resolve(source: &str) -> Value {
+        let program = vrl::compile(source, &crate::all()).unwrap();
+        let mut target = value!({});
+
+        vrl::Runtime::default()
+            .resolve(&mut target, &program)
+            .unwrap()
+    }
+
+    #[test]
+    fn captures_error() {
+        assert_eq!(
+            resolve(r#"capture_error(parse_json("{"))"#),
+            value!({
+                "error": r#"function call error for "parse_json" at (14:29): unable to parse json: EOF while parsing an object at line 1 column 1"#,
+                "code": 705,
+            })
+        );
+        assert_eq!(
+            resolve(r#"capture_error(to_int("foo"))"#),
+            value!({
+                "error": r#"function call error for "to_int" at (14:27): Invalid integer "foo": invalid digit found in string"#,
+                "code": 705,
+            })
+        );
+        assert_eq!(
+            resolve(r#"capture_error(abort!("invalid event"))"#),
+            value!({
+                "error": r#"function call error for "abort" at (14:37): invalid event"#,
+                "code": 700,
+            })
+        );
+    }
+
+    #[test]
+    fn captures_value() {
+        assert_eq!(
+            resolve(r#"capture_error(parse_json("[1]"))"#),
+            value!({ "ok": [1] })
+        );
+        assert_eq!(
+            resolve("result = capture_error(parse_json(\"{\"))\nexists(result.error)"),
+            value!(true)
+        );
+    }
+
+    #[test]
+    fn branches_on_error() {
+        let source = r#"
+            result = capture_error(parse_json(.message))
+            if exists(result.error) { .code = result.code } else { .parsed = result.ok }
+            .
+        "#;
+
+        assert_eq!(
+            vrl::Runtime::default()
+                .resolve(
+                    &mut value!({ "message": "{" }),
+                    &vrl::compile(source, &crate::all()).unwrap()
+                )
+                .unwrap(),
+            value!({ "message": "{", "code": 705 })
+        );
+    }
+}
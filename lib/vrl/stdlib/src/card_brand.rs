@@ -0,0 +1,160 @@
+use crate::util;
+use vrl::prelude::*;
+
+#[derive(Clone, Copy, Debug)]
+pub struct CardBrand;
+
+impl Function for CardBrand {
+    fn identifier(&self) -> &'static str {
+        "card_brand"
+    }
+
+    fn is_pure(&self) -> bool {
+        true
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[Parameter {
+            keyword: "value",
+            kind: kind::BYTES,
+            required: true,
+        }]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "visa",
+                source: r#"card_brand!("4111 1111 1111 1111")"#,
+                result: Ok("visa"),
+            },
+            Example {
+                title: "unknown",
+                source: r#"card_brand!("1234 5678")"#,
+                result: Ok("null"),
+            },
+        ]
+    }
+
+    fn compile(&self, mut arguments: ArgumentList) -> Compiled {
+        let value = arguments.required("value");
+
+        Ok(Box::new(CardBrandFn { value }))
+    }
+}
+
+#[derive(Debug, Clone)]
+struct CardBrandFn {
+    value: Box<dyn Expression>,
+}
+
+impl Expression for CardBrandFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let bytes = self.value.resolve(ctx)?.try_bytes()?;
+        let digits = util::card_digits(&String::from_utf8_lossy(&bytes))?;
+
+        Ok(brand(&digits).into())
+    }
+
+    fn type_def(&self, _: &state::Compiler) -> TypeDef {
+        TypeDef::new().fallible().bytes().add_null()
+    }
+}
+
+/// Returns the brand matching the issuer prefix and length of the card number.
+///
+/// This only reflects the ranges assigned to each brand, the number isn't
+/// guaranteed to belong to an actual card.
+fn brand(digits: &str) -> Option<&'static str> {
+    let len = digits.len();
+    let prefix = |n: usize| digits.get(..n).and_then(|p| p.parse::<u32>().ok());
+    let starts_in =
+        |n: usize, start: u32, end: u32| prefix(n).map_or(false, |p| (start..=end).contains(&p));
+
+    let brand = if starts_in(1, 4, 4) && (len == 13 || len == 16 || len == 19) {
+        "visa"
+    } else if (starts_in(2, 51, 55) || starts_in(4, 2221, 2720)) && len == 16 {
+        "mastercard"
+    } else if (starts_in(2, 34, 34) || starts_in(2, 37, 37)) && len == 15 {
+        "amex"
+    } else if (starts_in(4, 6011, 6011)
+        || starts_in(2, 65, 65)
+        || starts_in(3, 644, 649)
+        || starts_in(6, 622_126, 622_925))
+        && (16..=19).contains(&len)
+    {
+        "discover"
+    } else if starts_in(4, 3528, 3589) && (16..=19).contains(&len) {
+        "jcb"
+    } else if (starts_in(3, 300, 305) || starts_in(2, 36, 36) || starts_in(2, 38, 39))
+        && (14..=19).contains(&len)
+    {
+        "diners_club"
+    } else if starts_in(2, 62, 62) && (16..=19).contains(&len) {
+        "unionpay"
+    } else if (starts_in(2, 50, 50) || starts_in(2, 56, 69)) && (12..=19).contains(&len) {
+        "maestro"
+    } else {
+        return None;
+    };
+
+    Some(brand)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_brand() {
+        let cases = [
+            ("4111111111111111", Some("visa")),
+            ("4222222222222", Some("visa")),
+            ("5555555555554444", Some("mastercard")),
+            ("2223003122003222", Some("mastercard")),
+            ("378282246310005", Some("amex")),
+            ("6011111111111117", Some("discover")),
+            ("6221260000000000", Some("discover")),
+            ("3530111333300000", Some("jcb")),
+            ("36227206271667", Some("diners_club")),
+            ("6200000000000005", Some("unionpay")),
+            ("6759649826438453", Some("maestro")),
+            ("411111111111", None),
+            ("5555555555", None),
+            ("9999999999999999", None),
+            ("1", None),
+        ];
+
+        for (digits, want) in &cases {
+            assert_eq!(brand(digits), *want, "{}", digits);
+        }
+    }
+
+    test_function![
+        card_brand => CardBrand;
+
+        visa {
+            args: func_args![value: "4111 1111 1111 1111"],
+            want: Ok("visa"),
+            tdef: TypeDef::new().fallible().bytes().add_null(),
+        }
+
+        amex {
+            args: func_args![value: "3782-822463-10005"],
+            want: Ok("amex"),
+            tdef: TypeDef::new().fallible().bytes().add_null(),
+        }
+
+        unknown {
+            args: func_args![value: "1234 5678 9012 3456"],
+            want: Ok(Value::Null),
+            tdef: TypeDef::new().fallible().bytes().add_null(),
+        }
+
+        non_numeric {
+            args: func_args![value: "visa"],
+            want: Err("value must only contain digits, spaces and dashes"),
+            tdef: TypeDef::new().fallible().bytes().add_null(),
+        }
+    ];
+}
@@ -0,0 +1,540 @@
+//! A CBOR ([RFC 8949](https://www.rfc-editor.org/rfc/rfc8949.html)) encoder
+//! and decoder for values.
+//!
+//! Strings are encoded as text strings if they're valid UTF-8, and as byte
+//! strings otherwise, both decoding to strings. Timestamps are tagged date and
+//! time strings (tag 0) or epoch-based numbers (tag 1), and regular
+//! expressions are tagged strings (tag 35).
+
+#[cfg(feature = "encode_cbor")]
+use chrono::SecondsFormat;
+#[cfg(feature = "decode_cbor")]
+use chrono::{DateTime, TimeZone, Utc};
+#[cfg(feature = "decode_cbor")]
+use std::convert::TryFrom;
+#[cfg(feature = "encode_cbor")]
+use std::str::FromStr;
+use vrl::Value;
+
+const UNSIGNED: u8 = 0;
+const NEGATIVE: u8 = 1;
+const BYTES: u8 = 2;
+const TEXT: u8 = 3;
+const ARRAY: u8 = 4;
+const MAP: u8 = 5;
+const TAG: u8 = 6;
+const SIMPLE: u8 = 7;
+
+const TAG_DATETIME: u64 = 0;
+const TAG_EPOCH: u64 = 1;
+const TAG_REGEX: u64 = 35;
+
+#[cfg(feature = "decode_cbor")]
+const BREAK: u8 = 0xff;
+
+/// How deeply arrays, maps and tags can be nested in decoded data.
+#[cfg(feature = "decode_cbor")]
+const MAX_DEPTH: usize = 128;
+
+/// How timestamps are encoded.
+#[cfg(feature = "encode_cbor")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum TimestampFormat {
+    /// A tagged RFC 3339 string, such as `2021-03-05T12:00:00Z`.
+    Rfc3339,
+
+    /// A tagged number of seconds since the Unix epoch, which is a float if
+    /// the timestamp has fractional seconds.
+    Epoch,
+}
+
+#[cfg(feature = "encode_cbor")]
+impl Default for TimestampFormat {
+    fn default() -> Self {
+        Self::Rfc3339
+    }
+}
+
+#[cfg(feature = "encode_cbor")]
+impl FromStr for TimestampFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "rfc3339" => Ok(Self::Rfc3339),
+            "epoch" => Ok(Self::Epoch),
+            _ => Err(format!(r#"unknown timestamp format "{}""#, s)),
+        }
+    }
+}
+
+#[cfg(feature = "encode_cbor")]
+pub(crate) fn encode(value: &Value, timestamps: TimestampFormat, output: &mut Vec<u8>) {
+    match value {
+        Value::Bytes(bytes) => match std::str::from_utf8(bytes) {
+            Ok(text) => encode_text(text, output),
+            Err(_) => {
+                encode_head(BYTES, bytes.len() as u64, output);
+                output.extend_from_slice(bytes);
+            }
+        },
+        Value::Integer(integer) => encode_integer(*integer, output),
+        Value::Float(float) => encode_float(float.into_inner(), output),
+        Value::Boolean(false) => output.push(0xf4),
+        Value::Boolean(true) => output.push(0xf5),
+        Value::Null => output.push(0xf6),
+        Value::Array(items) => {
+            encode_head(ARRAY, items.len() as u64, output);
+            for item in items {
+                encode(item, timestamps, output);
+            }
+        }
+        Value::Object(fields) => {
+            encode_head(MAP, fields.len() as u64, output);
+            for (key, value) in fields {
+                encode_text(key, output);
+                encode(value, timestamps, output);
+            }
+        }
+        Value::Timestamp(timestamp) => match timestamps {
+            TimestampFormat::Rfc3339 => {
+                encode_head(TAG, TAG_DATETIME, output);
+                encode_text(
+                    &timestamp.to_rfc3339_opts(SecondsFormat::AutoSi, true),
+                    output,
+                );
+            }
+            TimestampFormat::Epoch => {
+                encode_head(TAG, TAG_EPOCH, output);
+                match timestamp.timestamp_subsec_nanos() {
+                    0 => encode_integer(timestamp.timestamp(), output),
+                    nanos => encode_float(
+                        timestamp.timestamp() as f64 + f64::from(nanos) / 1e9,
+                        output,
+                    ),
+                }
+            }
+        },
+        Value::Regex(regex) => {
+            encode_head(TAG, TAG_REGEX, output);
+            encode_text(regex.as_str(), output);
+        }
+    }
+}
+
+/// Encodes the major type and its argument, in as few bytes as possible.
+#[cfg(feature = "encode_cbor")]
+fn encode_head(major: u8, argument: u64, output: &mut Vec<u8>) {
+    let major = major << 5;
+
+    if argument < 24 {
+        output.push(major | argument as u8);
+    } else if argument <= u64::from(u8::MAX) {
+        output.extend_from_slice(&[major | 24, argument as u8]);
+    } else if argument <= u64::from(u16::MAX) {
+        output.push(major | 25);
+        output.extend_from_slice(&(argument as u16).to_be_bytes());
+    } else if argument <= u64::from(u32::MAX) {
+        output.push(major | 26);
+        output.extend_from_slice(&(argument as u32).to_be_bytes());
+    } else {
+        output.push(major | 27);
+        output.extend_from_slice(&argument.to_be_bytes());
+    }
+}
+
+#[cfg(feature = "encode_cbor")]
+fn encode_integer(integer: i64, output: &mut Vec<u8>) {
+    if integer < 0 {
+        // Negative integers are encoded as `-1 - n`.
+        encode_head(NEGATIVE, !integer as u64, output);
+    } else {
+        encode_head(UNSIGNED, integer as u64, output);
+    }
+}
+
+/// Floats are always encoded in double precision, so that they decode to the
+/// exact same value.
+#[cfg(feature = "encode_cbor")]
+fn encode_float(float: f64, output: &mut Vec<u8>) {
+    output.push(SIMPLE << 5 | 27);
+    output.extend_from_slice(&float.to_be_bytes());
+}
+
+#[cfg(feature = "encode_cbor")]
+fn encode_text(text: &str, output: &mut Vec<u8>) {
+    encode_head(TEXT, text.len() as u64, output);
+    output.extend_from_slice(text.as_bytes());
+}
+
+/// Decodes a single CBOR data item, spanning the whole input.
+#[cfg(feature = "decode_cbor")]
+pub(crate) fn decode(input: &[u8]) -> Result<Value, String> {
+    let mut decoder = Decoder { input, position: 0 };
+    let value = decoder.value(0)?;
+
+    if decoder.position < input.len() {
+        return Err(format!(
+            "unexpected data after the CBOR value at byte {}",
+            decoder.position
+        ));
+    }
+
+    Ok(value)
+}
+
+#[cfg(feature = "decode_cbor")]
+struct Decoder<'a> {
+    input: &'a [u8],
+    position: usize,
+}
+
+#[cfg(feature = "decode_cbor")]
+impl<'a> Decoder<'a> {
+    fn value(&mut self, depth: usize) -> Result<Value, String> {
+        if depth > MAX_DEPTH {
+            return Err("CBOR data is nested too deeply".to_owned());
+        }
+
+        let initial = self.byte()?;
+        let (major, info) = (initial >> 5, initial & 0x1f);
+
+        match major {
+            UNSIGNED => {
+                let n = self.definite(info)?;
+                i64::try_from(n)
+                    .map(Into::into)
+                    .map_err(|_| format!("CBOR integer {} is out of range", n))
+            }
+            NEGATIVE => {
+                let n = self.definite(info)?;
+                i64::try_from(n)
+                    .map(|n| (-1 - n).into())
+                    .map_err(|_| format!("CBOR integer -1-{} is out of range", n))
+            }
+            BYTES => Ok(Value::Bytes(self.string(major, info)?.into())),
+            TEXT => {
+                let text = String::from_utf8(self.string(major, info)?)
+                    .map_err(|_| "invalid UTF-8 in CBOR text string")?;
+                Ok(text.into())
+            }
+            ARRAY => {
+                let mut items = vec![];
+                match self.argument(info)? {
+                    Some(len) => {
+                        for _ in 0..len {
+                            items.push(self.value(depth + 1)?);
+                        }
+                    }
+                    None => {
+                        while !self.is_break()? {
+                            items.push(self.value(depth + 1)?);
+                        }
+                    }
+                }
+                Ok(items.into())
+            }
+            MAP => {
+                let mut fields = std::collections::BTreeMap::new();
+                match self.argument(info)? {
+                    Some(len) => {
+                        for _ in 0..len {
+                            let key = self.key(depth + 1)?;
+                            fields.insert(key, self.value(depth + 1)?);
+                        }
+                    }
+                    None => {
+                        while !self.is_break()? {
+                            let key = self.key(depth + 1)?;
+                            fields.insert(key, self.value(depth + 1)?);
+                        }
+                    }
+                }
+                Ok(fields.into())
+            }
+            TAG => {
+                let tag = self.definite(info)?;
+                let value = self.value(depth + 1)?;
+
+                match tag {
+                    TAG_DATETIME => tagged_datetime(value),
+                    TAG_EPOCH => tagged_epoch(value),
+                    TAG_REGEX => tagged_regex(value),
+                    // Other tags only add meaning to the value, which is kept
+                    // as is.
+                    _ => Ok(value),
+                }
+            }
+            SIMPLE => self.simple(info),
+            _ => unreachable!("major types are 3 bits"),
+        }
+    }
+
+    fn simple(&mut self, info: u8) -> Result<Value, String> {
+        let float = match info {
+            20 => return Ok(false.into()),
+            21 => return Ok(true.into()),
+            22 | 23 => return Ok(Value::Null),
+            25 => f16_to_f64(u16::from_be_bytes(self.array2()?)),
+            26 => f64::from(f32::from_be_bytes(self.array4()?)),
+            27 => f64::from_be_bytes(self.array8()?),
+            31 => return Err("unexpected CBOR break".to_owned()),
+            _ => return Err(format!("unsupported CBOR simple value {}", info)),
+        };
+
+        if float.is_nan() {
+            return Err("NaN floats aren't supported".to_owned());
+        }
+
+        Ok(float.into())
+    }
+
+    /// Decodes a map key, which is either a string or an integer.
+    fn key(&mut self, depth: usize) -> Result<String, String> {
+        match self.value(depth)? {
+            Value::Bytes(bytes) => Ok(String::from_utf8_lossy(&bytes).into_owned()),
+            Value::Integer(integer) => Ok(integer.to_string()),
+            value => Err(format!(
+                "unsupported CBOR map key of type {}",
+                value.kind().as_str()
+            )),
+        }
+    }
+
+    /// Decodes the contents of a byte or text string, which is either given
+    /// whole or in chunks of the same major type.
+    fn string(&mut self, major: u8, info: u8) -> Result<Vec<u8>, String> {
+        if let Some(len) = self.argument(info)? {
+            return Ok(self.take(len)?.to_vec());
+        }
+
+        let mut string = vec![];
+        while !self.is_break()? {
+            let initial = self.byte()?;
+            let len = match self.argument(initial & 0x1f)? {
+                Some(len) if initial >> 5 == major => len,
+                _ => return Err("invalid chunk in CBOR string".to_owned()),
+            };
+
+            string.extend_from_slice(self.take(len)?);
+        }
+
+        Ok(string)
+    }
+
+    /// Decodes the argument of the initial byte, `None` meaning its length is
+    /// indefinite.
+    fn argument(&mut self, info: u8) -> Result<Option<u64>, String> {
+        let argument = match info {
+            0..=23 => u64::from(info),
+            24 => u64::from(self.byte()?),
+            25 => u64::from(u16::from_be_bytes(self.array2()?)),
+            26 => u64::from(u32::from_be_bytes(self.array4()?)),
+            27 => u64::from_be_bytes(self.array8()?),
+            31 => return Ok(None),
+            _ => return Err(format!("invalid CBOR additional information {}", info)),
+        };
+
+        Ok(Some(argument))
+    }
+
+    fn definite(&mut self, info: u8) -> Result<u64, String> {
+        self.argument(info)?
+            .ok_or_else(|| "unexpected indefinite length in CBOR data".to_owned())
+    }
+
+    /// Consumes the next byte if it's a break, ending an indefinite-length
+    /// item.
+    fn is_break(&mut self) -> Result<bool, String> {
+        let is_break = self.peek()? == BREAK;
+        if is_break {
+            self.position += 1;
+        }
+
+        Ok(is_break)
+    }
+
+    fn peek(&self) -> Result<u8, String> {
+        self.input
+            .get(self.position)
+            .copied()
+            .ok_or_else(|| "unexpected end of CBOR data".to_owned())
+    }
+
+    fn byte(&mut self) -> Result<u8, String> {
+        let byte = self.peek()?;
+        self.position += 1;
+
+        Ok(byte)
+    }
+
+    fn take(&mut self, len: u64) -> Result<&'a [u8], String> {
+        let len = usize::try_from(len)
+            .ok()
+            .filter(|len| *len <= self.input.len() - self.position)
+            .ok_or_else(|| "unexpected end of CBOR data".to_owned())?;
+
+        let bytes = &self.input[self.position..self.position + len];
+        self.position += len;
+
+        Ok(bytes)
+    }
+
+    fn array2(&mut self) -> Result<[u8; 2], String> {
+        Ok(<[u8; 2]>::try_from(self.take(2)?).expect("length is checked"))
+    }
+
+    fn array4(&mut self) -> Result<[u8; 4], String> {
+        Ok(<[u8; 4]>::try_from(self.take(4)?).expect("length is checked"))
+    }
+
+    fn array8(&mut self) -> Result<[u8; 8], String> {
+        Ok(<[u8; 8]>::try_from(self.take(8)?).expect("length is checked"))
+    }
+}
+
+#[cfg(feature = "decode_cbor")]
+fn tagged_datetime(value: Value) -> Result<Value, String> {
+    let text = match &value {
+        Value::Bytes(bytes) => String::from_utf8_lossy(bytes),
+        _ => return Err("expected a string in CBOR date and time".to_owned()),
+    };
+
+    DateTime::parse_from_rfc3339(&text)
+        .map(|datetime| datetime.with_timezone(&Utc).into())
+        .map_err(|err| format!("invalid CBOR date and time {:?}: {}", text, err))
+}
+
+#[cfg(feature = "decode_cbor")]
+fn tagged_epoch(value: Value) -> Result<Value, String> {
+    let timestamp = match value {
+        Value::Integer(seconds) => Utc.timestamp_opt(seconds, 0).single(),
+        Value::Float(seconds) => {
+            let seconds = seconds.into_inner();
+            let whole = seconds.floor();
+            let nanos = ((seconds - whole) * 1e9).round().min(999_999_999.0);
+
+            if whole.abs() < i64::MAX as f64 {
+                Utc.timestamp_opt(whole as i64, nanos as u32).single()
+            } else {
+                None
+            }
+        }
+        _ => return Err("expected a number in CBOR epoch-based date and time".to_owned()),
+    };
+
+    timestamp
+        .map(Into::into)
+        .ok_or_else(|| "CBOR epoch-based date and time is out of range".to_owned())
+}
+
+#[cfg(feature = "decode_cbor")]
+fn tagged_regex(value: Value) -> Result<Value, String> {
+    let pattern = match &value {
+        Value::Bytes(bytes) => String::from_utf8_lossy(bytes),
+        _ => return Err("expected a string in CBOR regular expression".to_owned()),
+    };
+
+    regex::Regex::new(&pattern)
+        .map(|regex| Value::Regex(regex.into()))
+        .map_err(|err| format!("invalid CBOR regular expression: {}", err))
+}
+
+/// Converts the bits of a half-precision float.
+#[cfg(feature = "decode_cbor")]
+fn f16_to_f64(bits: u16) -> f64 {
+    let sign = if bits & 0x8000 == 0 { 1.0 } else { -1.0 };
+    let exponent = i32::from((bits >> 10) & 0x1f);
+    let fraction = f64::from(bits & 0x3ff);
+
+    sign * match exponent {
+        0 => fraction * 2_f64.powi(-24),
+        31 if fraction == 0.0 => f64::INFINITY,
+        31 => f64::NAN,
+        _ => (1024.0 + fraction) * 2_f64.powi(exponent - 25),
+    }
+}
+
+#[cfg(all(test, feature = "decode_cbor", feature = "encode_cbor"))]
+mod tests {
+    use super::*;
+    use bytes::Bytes;
+    use vrl::value;
+
+    fn round_trip(value: &Value, timestamps: TimestampFormat) -> Value {
+        let mut output = vec![];
+        encode(value, timestamps, &mut output);
+
+        decode(&output).unwrap()
+    }
+
+    #[test]
+    fn round_trip_nested() {
+        let value = value!({
+            "string": "foo",
+            "array": [1, [2, [3, { "a": null }]], {}],
+            "object": { "nested": { "deeply": [true, false] } },
+            "timestamp": (Utc.ymd(2021, 3, 5).and_hms_nano(12, 0, 0, 123_456_789)),
+        });
+
+        assert_eq!(round_trip(&value, TimestampFormat::Rfc3339), value);
+    }
+
+    #[test]
+    fn round_trip_integers_and_floats() {
+        let value = value!([
+            0,
+            1,
+            23,
+            24,
+            255,
+            256,
+            65_536,
+            4_294_967_296_i64,
+            (i64::MAX),
+            (-1),
+            (-25),
+            (i64::MIN),
+            0.0,
+            1.0,
+            (-1.5),
+            1.0e300,
+        ]);
+
+        let decoded = round_trip(&value, TimestampFormat::Rfc3339);
+
+        assert_eq!(decoded, value);
+        assert!(decoded.as_array().unwrap()[1].is_integer());
+        assert!(decoded.as_array().unwrap()[13].is_float());
+    }
+
+    #[test]
+    fn round_trip_binary() {
+        let value = Value::from(vec![
+            Value::Bytes(Bytes::from_static(&[0x00, 0xff, 0xc3, 0x28])),
+            Value::Bytes(Bytes::from(vec![0x80; 300])),
+            Value::from("é".repeat(100)),
+        ]);
+
+        assert_eq!(round_trip(&value, TimestampFormat::Rfc3339), value);
+    }
+
+    #[test]
+    fn round_trip_epoch_timestamps() {
+        let value = value!([
+            (Utc.ymd(2021, 3, 5).and_hms(12, 0, 0)),
+            (Utc.ymd(2021, 3, 5).and_hms_milli(12, 0, 0, 250)),
+            (Utc.ymd(1960, 1, 1).and_hms(0, 0, 0)),
+        ]);
+
+        assert_eq!(round_trip(&value, TimestampFormat::Epoch), value);
+    }
+
+    #[test]
+    fn decode_nested_too_deeply() {
+        assert_eq!(
+            decode(&[0x81; MAX_DEPTH + 2]),
+            Err("CBOR data is nested too deeply".to_owned())
+        );
+    }
+}
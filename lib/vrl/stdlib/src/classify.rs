@@ -0,0 +1,184 @@
+use crate::util;
+use regex::RegexSet;
+use std::collections::BTreeMap;
+use vrl::prelude::*;
+
+#[derive(Clone, Copy, Debug)]
+pub struct Classify;
+
+impl Function for Classify {
+    fn identifier(&self) -> &'static str {
+        "classify"
+    }
+
+    fn is_pure(&self) -> bool {
+        true
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[
+            Parameter {
+                keyword: "value",
+                kind: kind::BYTES,
+                required: true,
+            },
+            Parameter {
+                keyword: "categories",
+                kind: kind::OBJECT,
+                required: true,
+            },
+        ]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "multiple categories",
+                source: r#"classify("GET /api/health", { "api": r'^\w+ /api/', "health": r'/health$', "static": r'\.css$' })"#,
+                result: Ok(r#"["api", "health"]"#),
+            },
+            Example {
+                title: "no category",
+                source: r#"classify("GET /index.html", { "api": r'^\w+ /api/' })"#,
+                result: Ok("[]"),
+            },
+        ]
+    }
+
+    fn compile(&self, mut arguments: ArgumentList) -> Compiled {
+        let value = arguments.required("value");
+        let categories = arguments.required("categories");
+
+        // A literal object is compiled once, here, so that invalid patterns
+        // are reported at compile-time.
+        let categories = match categories.as_value() {
+            Some(object) => {
+                let (labels, sources) = object
+                    .try_object()
+                    .and_then(split_categories)
+                    .map_err(|e| Box::new(e) as Box<dyn DiagnosticError>)?;
+
+                let set = util::regex_set(&sources)
+                    .map_err(|e| Box::new(e.with_labels(&labels)) as Box<dyn DiagnosticError>)?;
+
+                Categories::Compiled { labels, set }
+            }
+            None => Categories::Dynamic(categories),
+        };
+
+        Ok(Box::new(ClassifyFn { value, categories }))
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Categories {
+    Compiled { labels: Vec<String>, set: RegexSet },
+    Dynamic(Box<dyn Expression>),
+}
+
+#[derive(Debug, Clone)]
+struct ClassifyFn {
+    value: Box<dyn Expression>,
+    categories: Categories,
+}
+
+impl Expression for ClassifyFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?;
+        let string = value.try_bytes_utf8_lossy()?;
+
+        let labels = match &self.categories {
+            Categories::Compiled { labels, set } => matching_labels(labels, set, &string),
+            Categories::Dynamic(categories) => {
+                let (labels, sources) = split_categories(categories.resolve(ctx)?.try_object()?)?;
+                let set =
+                    util::regex_set(&sources).map_err(|e| e.with_labels(&labels).to_string())?;
+
+                matching_labels(&labels, &set, &string)
+            }
+        };
+
+        Ok(labels.into())
+    }
+
+    fn type_def(&self, _: &state::Compiler) -> TypeDef {
+        TypeDef::new()
+            .array_mapped::<(), Kind>(map! { (): Kind::Bytes })
+            .with_fallibility(matches!(self.categories, Categories::Dynamic(_)))
+    }
+}
+
+/// Splits the categories into their labels and the sources of their patterns,
+/// in the same order.
+fn split_categories(
+    categories: BTreeMap<String, Value>,
+) -> std::result::Result<(Vec<String>, Vec<String>), value::Error> {
+    let mut labels = Vec::with_capacity(categories.len());
+    let mut sources = Vec::with_capacity(categories.len());
+
+    for (label, pattern) in categories {
+        labels.push(label);
+        sources.push(util::pattern_source(pattern)?);
+    }
+
+    Ok((labels, sources))
+}
+
+fn matching_labels(labels: &[String], set: &RegexSet, string: &str) -> Vec<Value> {
+    set.matches(string)
+        .into_iter()
+        .map(|index| labels[index].clone().into())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use shared::btreemap;
+
+    test_function![
+        classify => Classify;
+
+        multiple_matches {
+            args: func_args![
+                value: "GET /api/health",
+                categories: value!({ "api": "^\\w+ /api/", "health": "/health$", "static": "\\.css$" }),
+            ],
+            want: Ok(value!(["api", "health"])),
+            tdef: TypeDef::new().infallible().array_mapped::<(), Kind>(map! { (): Kind::Bytes }),
+        }
+
+        no_match {
+            args: func_args![
+                value: "GET /index.html",
+                categories: value!({ "api": "^\\w+ /api/", "static": "\\.css$" }),
+            ],
+            want: Ok(value!([])),
+            tdef: TypeDef::new().infallible().array_mapped::<(), Kind>(map! { (): Kind::Bytes }),
+        }
+
+        overlapping_patterns {
+            args: func_args![
+                value: "error: disk full",
+                categories: value!({ "errors": "^error", "disk": "disk", "full_message": "^error: disk full$", "warnings": "^warn" }),
+            ],
+            want: Ok(value!(["disk", "errors", "full_message"])),
+            tdef: TypeDef::new().infallible().array_mapped::<(), Kind>(map! { (): Kind::Bytes }),
+        }
+
+        regex_pattern {
+            args: func_args![
+                value: "foobar",
+                categories: Value::from(btreemap! { "foo" => regex::Regex::new("^fo+").unwrap() }),
+            ],
+            want: Ok(value!(["foo"])),
+            tdef: TypeDef::new().infallible().array_mapped::<(), Kind>(map! { (): Kind::Bytes }),
+        }
+
+        invalid_pattern {
+            args: func_args![value: "foobar", categories: value!({ "bar": "(bar", "foo": "^foo" })],
+            want: Err(r#"invalid pattern "(bar" for label "bar""#),
+            tdef: TypeDef::new().infallible().array_mapped::<(), Kind>(map! { (): Kind::Bytes }),
+        }
+    ];
+}
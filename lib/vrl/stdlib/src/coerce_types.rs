@@ -0,0 +1,292 @@
+use std::collections::BTreeMap;
+use vrl::prelude::*;
+
+#[derive(Clone, Copy, Debug)]
+pub struct CoerceTypes;
+
+impl Function for CoerceTypes {
+    fn identifier(&self) -> &'static str {
+        "coerce_types"
+    }
+
+    fn is_pure(&self) -> bool {
+        true
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[
+            Parameter {
+                keyword: "value",
+                kind: kind::OBJECT | kind::ARRAY,
+                required: true,
+            },
+            Parameter {
+                keyword: "integer",
+                kind: kind::BOOLEAN,
+                required: false,
+            },
+            Parameter {
+                keyword: "float",
+                kind: kind::BOOLEAN,
+                required: false,
+            },
+            Parameter {
+                keyword: "boolean",
+                kind: kind::BOOLEAN,
+                required: false,
+            },
+            Parameter {
+                keyword: "null",
+                kind: kind::BOOLEAN,
+                required: false,
+            },
+        ]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "object",
+                source: r#"coerce_types({ "a": "5", "b": "5.0", "c": "true", "d": "null", "e": "007" })"#,
+                result: Ok(r#"{ "a": 5, "b": 5.0, "c": true, "d": null, "e": "007" }"#),
+            },
+            Example {
+                title: "numbers only",
+                source: r#"coerce_types(["5", "true"], boolean: false)"#,
+                result: Ok(r#"[5, "true"]"#),
+            },
+        ]
+    }
+
+    fn compile(&self, mut arguments: ArgumentList) -> Compiled {
+        let value = arguments.required("value");
+        let integer = arguments.optional("integer").unwrap_or(expr!(true));
+        let float = arguments.optional("float").unwrap_or(expr!(true));
+        let boolean = arguments.optional("boolean").unwrap_or(expr!(true));
+        let null = arguments.optional("null").unwrap_or(expr!(true));
+
+        Ok(Box::new(CoerceTypesFn {
+            value,
+            integer,
+            float,
+            boolean,
+            null,
+        }))
+    }
+}
+
+#[derive(Debug, Clone)]
+struct CoerceTypesFn {
+    value: Box<dyn Expression>,
+    integer: Box<dyn Expression>,
+    float: Box<dyn Expression>,
+    boolean: Box<dyn Expression>,
+    null: Box<dyn Expression>,
+}
+
+#[derive(Debug)]
+struct CoerceOptions {
+    integer: bool,
+    float: bool,
+    boolean: bool,
+    null: bool,
+}
+
+impl CoerceOptions {
+    /// Converts the string into the value it unambiguously represents, if any
+    /// of the enabled coercions applies.
+    ///
+    /// Numbers with leading zeros, such as zip codes and identifiers, aren't
+    /// coerced, as the zeros would be lost.
+    fn coerce_str(&self, s: &str) -> Option<Value> {
+        match s {
+            "null" if self.null => return Some(Value::Null),
+            "true" if self.boolean => return Some(true.into()),
+            "false" if self.boolean => return Some(false.into()),
+            _ => {}
+        }
+
+        let (integer, fraction, exponent) = split_number(s)?;
+
+        let digits = integer.strip_prefix('-').unwrap_or(integer);
+        if digits.is_empty() || (digits.len() > 1 && digits.starts_with('0')) {
+            return None;
+        }
+
+        match (fraction, exponent) {
+            (None, None) if self.integer => s.parse::<i64>().ok().map(Into::into),
+            (None, None) => None,
+            _ if self.float => s
+                .parse::<f64>()
+                .ok()
+                .filter(|float| float.is_finite())
+                .map(Into::into),
+            _ => None,
+        }
+    }
+}
+
+/// Splits a string made of an optionally signed integer part, followed by an
+/// optional `.` fraction and `e` exponent, returning `None` if the string
+/// doesn't have that shape.
+fn split_number(s: &str) -> Option<(&str, Option<&str>, Option<&str>)> {
+    let is_digits = |s: &str| !s.is_empty() && s.bytes().all(|c| c.is_ascii_digit());
+
+    let (mantissa, exponent) = match s.find(|c| c == 'e' || c == 'E') {
+        Some(i) => (&s[..i], Some(&s[i + 1..])),
+        None => (s, None),
+    };
+
+    let (integer, fraction) = match mantissa.find('.') {
+        Some(i) => (&mantissa[..i], Some(&mantissa[i + 1..])),
+        None => (mantissa, None),
+    };
+
+    let digits = integer.strip_prefix('-').unwrap_or(integer);
+    if !is_digits(digits) || !fraction.map_or(true, is_digits) {
+        return None;
+    }
+
+    if let Some(exponent) = exponent {
+        let exponent = exponent
+            .strip_prefix(|c| c == '-' || c == '+')
+            .unwrap_or(exponent);
+
+        if !is_digits(exponent) {
+            return None;
+        }
+    }
+
+    Some((integer, fraction, exponent))
+}
+
+impl Expression for CoerceTypesFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let options = CoerceOptions {
+            integer: self.integer.resolve(ctx)?.try_boolean()?,
+            float: self.float.resolve(ctx)?.try_boolean()?,
+            boolean: self.boolean.resolve(ctx)?.try_boolean()?,
+            null: self.null.resolve(ctx)?.try_boolean()?,
+        };
+
+        match self.value.resolve(ctx)? {
+            Value::Object(map) => Ok(coerce_map(map, &options).into()),
+            Value::Array(array) => Ok(coerce_array(array, &options).into()),
+            value => Err(value::Error::Expected {
+                got: value.kind(),
+                expected: Kind::Array | Kind::Object,
+            }
+            .into()),
+        }
+    }
+
+    fn type_def(&self, state: &state::Compiler) -> TypeDef {
+        let td = self.value.type_def(state);
+
+        if td.is_array() {
+            TypeDef::new().array_mapped::<(), Kind>(map! { (): Kind::all() })
+        } else {
+            TypeDef::new().object::<(), Kind>(map! { (): Kind::all() })
+        }
+    }
+}
+
+fn coerce(value: Value, options: &CoerceOptions) -> Value {
+    match value {
+        Value::Bytes(bytes) => std::str::from_utf8(&bytes)
+            .ok()
+            .and_then(|s| options.coerce_str(s))
+            .unwrap_or(Value::Bytes(bytes)),
+        Value::Object(map) => coerce_map(map, options).into(),
+        Value::Array(array) => coerce_array(array, options).into(),
+        value => value,
+    }
+}
+
+fn coerce_map(map: BTreeMap<String, Value>, options: &CoerceOptions) -> BTreeMap<String, Value> {
+    map.into_iter()
+        .map(|(key, value)| (key, coerce(value, options)))
+        .collect()
+}
+
+fn coerce_array(array: Vec<Value>, options: &CoerceOptions) -> Vec<Value> {
+    array
+        .into_iter()
+        .map(|value| coerce(value, options))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    test_function![
+        coerce_types => CoerceTypes;
+
+        object {
+            args: func_args![value: value!({
+                "integer": "5",
+                "float": "5.0",
+                "boolean": "true",
+                "null": "null",
+                "leading_zero": "007",
+                "string": "foo",
+            })],
+            want: Ok(value!({
+                "integer": 5,
+                "float": 5.0,
+                "boolean": true,
+                "null": null,
+                "leading_zero": "007",
+                "string": "foo",
+            })),
+            tdef: TypeDef::new().object::<(), Kind>(map! { (): Kind::all() }),
+        }
+
+        nested {
+            args: func_args![value: value!({ "a": { "b": ["1", "false", ["-2.5e3"]] }, "c": 1 })],
+            want: Ok(value!({ "a": { "b": [1, false, [(-2500.0)]] }, "c": 1 })),
+            tdef: TypeDef::new().object::<(), Kind>(map! { (): Kind::all() }),
+        }
+
+        array {
+            args: func_args![value: value!(["0", "0.5", "-0", "1e3"])],
+            want: Ok(value!([0, 0.5, 0, 1000.0])),
+            tdef: TypeDef::new().array_mapped::<(), Kind>(map! { (): Kind::all() }),
+        }
+
+        ambiguous {
+            args: func_args![value: value!([
+                "007", "00.5", ".5", "5.", "+5", " 5", "1_000", "True", "NULL", "NaN", "inf",
+                "1e", "99999999999999999999", ""
+            ])],
+            want: Ok(value!([
+                "007", "00.5", ".5", "5.", "+5", " 5", "1_000", "True", "NULL", "NaN", "inf",
+                "1e", "99999999999999999999", ""
+            ])),
+            tdef: TypeDef::new().array_mapped::<(), Kind>(map! { (): Kind::all() }),
+        }
+
+        disabled {
+            args: func_args![
+                value: value!(["5", "5.0", "true", "null"]),
+                integer: false,
+                boolean: false,
+            ],
+            want: Ok(value!(["5", 5.0, "true", null])),
+            tdef: TypeDef::new().array_mapped::<(), Kind>(map! { (): Kind::all() }),
+        }
+
+        all_disabled {
+            args: func_args![
+                value: value!(["5", "5.0", "true", "null"]),
+                integer: false,
+                float: false,
+                boolean: false,
+                null: false,
+            ],
+            want: Ok(value!(["5", "5.0", "true", "null"])),
+            tdef: TypeDef::new().array_mapped::<(), Kind>(map! { (): Kind::all() }),
+        }
+    ];
+}
@@ -0,0 +1,334 @@
+//! Colors, written as hexadecimal colors such as `#f0f8ff` or `#f0f8ff80`,
+//! as `rgb(240, 248, 255)` or `rgba(240, 248, 255, 0.5)`, or as CSS named
+//! colors such as `aliceblue`, or as objects with `r`, `g`, `b` and `a`
+//! fields.
+
+use vrl::Value;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct Color {
+    pub(crate) r: u8,
+    pub(crate) g: u8,
+    pub(crate) b: u8,
+
+    /// The opacity, from `0` for transparent to `1` for opaque.
+    pub(crate) a: f64,
+}
+
+impl Color {
+    /// Parses the color from its string or its object.
+    #[cfg(any(feature = "contrast_ratio", feature = "luminance"))]
+    pub(crate) fn from_value(value: &Value) -> Result<Self, String> {
+        match value {
+            Value::Bytes(bytes) => parse(&String::from_utf8_lossy(bytes)),
+            Value::Object(object) => {
+                let channel = |name: &str| {
+                    object
+                        .get(name)
+                        .and_then(Value::as_integer)
+                        .filter(|channel| (0..=255).contains(channel))
+                        .map(|channel| channel as u8)
+                        .ok_or_else(|| {
+                            format!(
+                                r#"invalid color: "{}" must be an integer between 0 and 255"#,
+                                name
+                            )
+                        })
+                };
+
+                let a = match object.get("a") {
+                    None => 1.0,
+                    Some(Value::Integer(a)) => *a as f64,
+                    Some(Value::Float(a)) => a.into_inner(),
+                    Some(_) => f64::NAN,
+                };
+                if !(0.0..=1.0).contains(&a) {
+                    return Err(r#"invalid color: "a" must be a number between 0 and 1"#.to_owned());
+                }
+
+                Ok(Color {
+                    r: channel("r")?,
+                    g: channel("g")?,
+                    b: channel("b")?,
+                    a,
+                })
+            }
+            value => Err(format!(
+                "invalid color: expected a string or an object, got {}",
+                value.kind()
+            )),
+        }
+    }
+
+    #[cfg(feature = "parse_color")]
+    pub(crate) fn into_value(self) -> Value {
+        let mut map = std::collections::BTreeMap::<String, Value>::new();
+        map.insert("r".to_owned(), i64::from(self.r).into());
+        map.insert("g".to_owned(), i64::from(self.g).into());
+        map.insert("b".to_owned(), i64::from(self.b).into());
+        map.insert("a".to_owned(), self.a.into());
+
+        map.into()
+    }
+
+    /// The relative luminance of the color, from `0` for black to `1` for
+    /// white, as defined by the Web Content Accessibility Guidelines. The
+    /// opacity is ignored.
+    #[cfg(any(feature = "contrast_ratio", feature = "luminance"))]
+    pub(crate) fn luminance(&self) -> f64 {
+        let linear = |channel: u8| {
+            let channel = f64::from(channel) / 255.0;
+            if channel <= 0.03928 {
+                channel / 12.92
+            } else {
+                ((channel + 0.055) / 1.055).powf(2.4)
+            }
+        };
+
+        0.2126 * linear(self.r) + 0.7152 * linear(self.g) + 0.0722 * linear(self.b)
+    }
+}
+
+/// The contrast ratio of the colors, from `1` for the same luminance to `21`
+/// for black and white, as defined by the Web Content Accessibility
+/// Guidelines.
+#[cfg(feature = "contrast_ratio")]
+pub(crate) fn contrast_ratio(a: &Color, b: &Color) -> f64 {
+    let (a, b) = (a.luminance(), b.luminance());
+    let (lighter, darker) = if a > b { (a, b) } else { (b, a) };
+
+    (lighter + 0.05) / (darker + 0.05)
+}
+
+pub(crate) fn parse(color: &str) -> Result<Color, String> {
+    let invalid = || format!("unable to parse color {:?}", color);
+    let trimmed = color.trim().to_ascii_lowercase();
+
+    if let Some(hex) = trimmed.strip_prefix('#') {
+        return parse_hex(hex).ok_or_else(invalid);
+    }
+
+    if let Some(arguments) = trimmed
+        .strip_prefix("rgba(")
+        .or_else(|| trimmed.strip_prefix("rgb("))
+    {
+        return parse_rgb(arguments).ok_or_else(invalid);
+    }
+
+    if trimmed == "transparent" {
+        return Ok(Color {
+            r: 0,
+            g: 0,
+            b: 0,
+            a: 0.0,
+        });
+    }
+
+    NAMED_COLORS
+        .binary_search_by_key(&trimmed.as_str(), |&(name, _)| name)
+        .map(|index| {
+            let (_, (r, g, b)) = NAMED_COLORS[index];
+            Color { r, g, b, a: 1.0 }
+        })
+        .map_err(|_| invalid())
+}
+
+/// Parses `rgb`, `rgba`, `rrggbb` or `rrggbbaa`.
+fn parse_hex(hex: &str) -> Option<Color> {
+    if !hex.bytes().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+
+    let channels = match hex.len() {
+        3 | 4 => hex
+            .chars()
+            .map(|c| c.to_digit(16).map(|c| c as u8 * 17))
+            .collect::<Option<Vec<_>>>()?,
+        6 | 8 => (0..hex.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).ok())
+            .collect::<Option<Vec<_>>>()?,
+        _ => return None,
+    };
+
+    Some(Color {
+        r: channels[0],
+        g: channels[1],
+        b: channels[2],
+        a: channels.get(3).map_or(1.0, |a| f64::from(*a) / 255.0),
+    })
+}
+
+/// Parses the `r, g, b)` or `r, g, b, a)` after `rgb(` or `rgba(`.
+fn parse_rgb(arguments: &str) -> Option<Color> {
+    let arguments = arguments.strip_suffix(')')?;
+    let arguments = arguments.split(',').map(str::trim).collect::<Vec<_>>();
+
+    let a = match arguments.len() {
+        3 => 1.0,
+        4 => arguments[3]
+            .parse::<f64>()
+            .ok()
+            .filter(|a| (0.0..=1.0).contains(a))?,
+        _ => return None,
+    };
+
+    Some(Color {
+        r: arguments[0].parse().ok()?,
+        g: arguments[1].parse().ok()?,
+        b: arguments[2].parse().ok()?,
+        a,
+    })
+}
+
+/// The CSS named colors, sorted by name.
+const NAMED_COLORS: &[(&str, (u8, u8, u8))] = &[
+    ("aliceblue", (240, 248, 255)),
+    ("antiquewhite", (250, 235, 215)),
+    ("aqua", (0, 255, 255)),
+    ("aquamarine", (127, 255, 212)),
+    ("azure", (240, 255, 255)),
+    ("beige", (245, 245, 220)),
+    ("bisque", (255, 228, 196)),
+    ("black", (0, 0, 0)),
+    ("blanchedalmond", (255, 235, 205)),
+    ("blue", (0, 0, 255)),
+    ("blueviolet", (138, 43, 226)),
+    ("brown", (165, 42, 42)),
+    ("burlywood", (222, 184, 135)),
+    ("cadetblue", (95, 158, 160)),
+    ("chartreuse", (127, 255, 0)),
+    ("chocolate", (210, 105, 30)),
+    ("coral", (255, 127, 80)),
+    ("cornflowerblue", (100, 149, 237)),
+    ("cornsilk", (255, 248, 220)),
+    ("crimson", (220, 20, 60)),
+    ("cyan", (0, 255, 255)),
+    ("darkblue", (0, 0, 139)),
+    ("darkcyan", (0, 139, 139)),
+    ("darkgoldenrod", (184, 134, 11)),
+    ("darkgray", (169, 169, 169)),
+    ("darkgreen", (0, 100, 0)),
+    ("darkgrey", (169, 169, 169)),
+    ("darkkhaki", (189, 183, 107)),
+    ("darkmagenta", (139, 0, 139)),
+    ("darkolivegreen", (85, 107, 47)),
+    ("darkorange", (255, 140, 0)),
+    ("darkorchid", (153, 50, 204)),
+    ("darkred", (139, 0, 0)),
+    ("darksalmon", (233, 150, 122)),
+    ("darkseagreen", (143, 188, 143)),
+    ("darkslateblue", (72, 61, 139)),
+    ("darkslategray", (47, 79, 79)),
+    ("darkslategrey", (47, 79, 79)),
+    ("darkturquoise", (0, 206, 209)),
+    ("darkviolet", (148, 0, 211)),
+    ("deeppink", (255, 20, 147)),
+    ("deepskyblue", (0, 191, 255)),
+    ("dimgray", (105, 105, 105)),
+    ("dimgrey", (105, 105, 105)),
+    ("dodgerblue", (30, 144, 255)),
+    ("firebrick", (178, 34, 34)),
+    ("floralwhite", (255, 250, 240)),
+    ("forestgreen", (34, 139, 34)),
+    ("fuchsia", (255, 0, 255)),
+    ("gainsboro", (220, 220, 220)),
+    ("ghostwhite", (248, 248, 255)),
+    ("gold", (255, 215, 0)),
+    ("goldenrod", (218, 165, 32)),
+    ("gray", (128, 128, 128)),
+    ("green", (0, 128, 0)),
+    ("greenyellow", (173, 255, 47)),
+    ("grey", (128, 128, 128)),
+    ("honeydew", (240, 255, 240)),
+    ("hotpink", (255, 105, 180)),
+    ("indianred", (205, 92, 92)),
+    ("indigo", (75, 0, 130)),
+    ("ivory", (255, 255, 240)),
+    ("khaki", (240, 230, 140)),
+    ("lavender", (230, 230, 250)),
+    ("lavenderblush", (255, 240, 245)),
+    ("lawngreen", (124, 252, 0)),
+    ("lemonchiffon", (255, 250, 205)),
+    ("lightblue", (173, 216, 230)),
+    ("lightcoral", (240, 128, 128)),
+    ("lightcyan", (224, 255, 255)),
+    ("lightgoldenrodyellow", (250, 250, 210)),
+    ("lightgray", (211, 211, 211)),
+    ("lightgreen", (144, 238, 144)),
+    ("lightgrey", (211, 211, 211)),
+    ("lightpink", (255, 182, 193)),
+    ("lightsalmon", (255, 160, 122)),
+    ("lightseagreen", (32, 178, 170)),
+    ("lightskyblue", (135, 206, 250)),
+    ("lightslategray", (119, 136, 153)),
+    ("lightslategrey", (119, 136, 153)),
+    ("lightsteelblue", (176, 196, 222)),
+    ("lightyellow", (255, 255, 224)),
+    ("lime", (0, 255, 0)),
+    ("limegreen", (50, 205, 50)),
+    ("linen", (250, 240, 230)),
+    ("magenta", (255, 0, 255)),
+    ("maroon", (128, 0, 0)),
+    ("mediumaquamarine", (102, 205, 170)),
+    ("mediumblue", (0, 0, 205)),
+    ("mediumorchid", (186, 85, 211)),
+    ("mediumpurple", (147, 112, 219)),
+    ("mediumseagreen", (60, 179, 113)),
+    ("mediumslateblue", (123, 104, 238)),
+    ("mediumspringgreen", (0, 250, 154)),
+    ("mediumturquoise", (72, 209, 204)),
+    ("mediumvioletred", (199, 21, 133)),
+    ("midnightblue", (25, 25, 112)),
+    ("mintcream", (245, 255, 250)),
+    ("mistyrose", (255, 228, 225)),
+    ("moccasin", (255, 228, 181)),
+    ("navajowhite", (255, 222, 173)),
+    ("navy", (0, 0, 128)),
+    ("oldlace", (253, 245, 230)),
+    ("olive", (128, 128, 0)),
+    ("olivedrab", (107, 142, 35)),
+    ("orange", (255, 165, 0)),
+    ("orangered", (255, 69, 0)),
+    ("orchid", (218, 112, 214)),
+    ("palegoldenrod", (238, 232, 170)),
+    ("palegreen", (152, 251, 152)),
+    ("paleturquoise", (175, 238, 238)),
+    ("palevioletred", (219, 112, 147)),
+    ("papayawhip", (255, 239, 213)),
+    ("peachpuff", (255, 218, 185)),
+    ("peru", (205, 133, 63)),
+    ("pink", (255, 192, 203)),
+    ("plum", (221, 160, 221)),
+    ("powderblue", (176, 224, 230)),
+    ("purple", (128, 0, 128)),
+    ("rebeccapurple", (102, 51, 153)),
+    ("red", (255, 0, 0)),
+    ("rosybrown", (188, 143, 143)),
+    ("royalblue", (65, 105, 225)),
+    ("saddlebrown", (139, 69, 19)),
+    ("salmon", (250, 128, 114)),
+    ("sandybrown", (244, 164, 96)),
+    ("seagreen", (46, 139, 87)),
+    ("seashell", (255, 245, 238)),
+    ("sienna", (160, 82, 45)),
+    ("silver", (192, 192, 192)),
+    ("skyblue", (135, 206, 235)),
+    ("slateblue", (106, 90, 205)),
+    ("slategray", (112, 128, 144)),
+    ("slategrey", (112, 128, 144)),
+    ("snow", (255, 250, 250)),
+    ("springgreen", (0, 255, 127)),
+    ("steelblue", (70, 130, 180)),
+    ("tan", (210, 180, 140)),
+    ("teal", (0, 128, 128)),
+    ("thistle", (216, 191, 216)),
+    ("tomato", (255, 99, 71)),
+    ("turquoise", (64, 224, 208)),
+    ("violet", (238, 130, 238)),
+    ("wheat", (245, 222, 179)),
+    ("white", (255, 255, 255)),
+    ("whitesmoke", (245, 245, 245)),
+    ("yellow", (255, 255, 0)),
+    ("yellowgreen", (154, 205, 50)),
+];
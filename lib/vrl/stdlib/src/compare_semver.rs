@@ -0,0 +1,179 @@
+use crate::util;
+use std::cmp::Ordering;
+use vrl::prelude::*;
+
+#[derive(Clone, Copy, Debug)]
+pub struct CompareSemver;
+
+impl Function for CompareSemver {
+    fn identifier(&self) -> &'static str {
+        "compare_semver"
+    }
+
+    fn is_pure(&self) -> bool {
+        true
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[
+            Parameter {
+                keyword: "a",
+                kind: kind::BYTES,
+                required: true,
+            },
+            Parameter {
+                keyword: "b",
+                kind: kind::BYTES,
+                required: true,
+            },
+        ]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "older",
+                source: r#"compare_semver!("1.2.3", "1.10.0")"#,
+                result: Ok("-1"),
+            },
+            Example {
+                title: "prerelease",
+                source: r#"compare_semver!("2.0.0", "2.0.0-rc.1")"#,
+                result: Ok("1"),
+            },
+        ]
+    }
+
+    fn compile(&self, mut arguments: ArgumentList) -> Compiled {
+        let a = arguments.required("a");
+        let b = arguments.required("b");
+
+        Ok(Box::new(CompareSemverFn { a, b }))
+    }
+}
+
+#[derive(Debug, Clone)]
+struct CompareSemverFn {
+    a: Box<dyn Expression>,
+    b: Box<dyn Expression>,
+}
+
+impl Expression for CompareSemverFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let a = self.a.resolve(ctx)?.try_bytes()?;
+        let a = util::parse_semver(&String::from_utf8_lossy(&a))?;
+
+        let b = self.b.resolve(ctx)?.try_bytes()?;
+        let b = util::parse_semver(&String::from_utf8_lossy(&b))?;
+
+        let ordering = match precedence(&a, &b) {
+            Ordering::Less => -1,
+            Ordering::Equal => 0,
+            Ordering::Greater => 1,
+        };
+
+        Ok(ordering.into())
+    }
+
+    fn type_def(&self, _: &state::Compiler) -> TypeDef {
+        TypeDef::new().fallible().integer()
+    }
+}
+
+/// Compares two versions following the semantic versioning precedence rules.
+///
+/// A prerelease version has a lower precedence than the associated release,
+/// and build metadata is ignored.
+fn precedence(a: &semver::Version, b: &semver::Version) -> Ordering {
+    (a.major, a.minor, a.patch)
+        .cmp(&(b.major, b.minor, b.patch))
+        .then_with(|| match (a.pre.is_empty(), b.pre.is_empty()) {
+            (true, true) => Ordering::Equal,
+            (true, false) => Ordering::Greater,
+            (false, true) => Ordering::Less,
+            (false, false) => a.pre.cmp(&b.pre),
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_precedence() {
+        // The example ordering from the semantic versioning specification.
+        let versions = [
+            "1.0.0-alpha",
+            "1.0.0-alpha.1",
+            "1.0.0-alpha.beta",
+            "1.0.0-beta",
+            "1.0.0-beta.2",
+            "1.0.0-beta.11",
+            "1.0.0-rc.1",
+            "1.0.0",
+            "1.0.1",
+            "1.1.0",
+            "2.0.0",
+        ]
+        .iter()
+        .map(|v| util::parse_semver(v).unwrap())
+        .collect::<Vec<_>>();
+
+        for pair in versions.windows(2) {
+            assert_eq!(precedence(&pair[0], &pair[1]), Ordering::Less);
+            assert_eq!(precedence(&pair[1], &pair[0]), Ordering::Greater);
+        }
+    }
+
+    test_function![
+        compare_semver => CompareSemver;
+
+        equal {
+            args: func_args![a: "1.2.3", b: "1.2.3"],
+            want: Ok(0),
+            tdef: TypeDef::new().fallible().integer(),
+        }
+
+        numeric_ordering {
+            args: func_args![a: "1.10.0", b: "1.9.0"],
+            want: Ok(1),
+            tdef: TypeDef::new().fallible().integer(),
+        }
+
+        prerelease_before_release {
+            args: func_args![a: "1.0.0-rc.1", b: "1.0.0"],
+            want: Ok(-1),
+            tdef: TypeDef::new().fallible().integer(),
+        }
+
+        prerelease_ordering {
+            args: func_args![a: "1.0.0-beta.11", b: "1.0.0-beta.2"],
+            want: Ok(1),
+            tdef: TypeDef::new().fallible().integer(),
+        }
+
+        build_metadata_ignored {
+            args: func_args![a: "1.0.0+build.1", b: "1.0.0+build.2"],
+            want: Ok(0),
+            tdef: TypeDef::new().fallible().integer(),
+        }
+
+        build_metadata_ignored_prerelease {
+            args: func_args![a: "1.0.0-rc.1+zzz", b: "1.0.0-rc.1"],
+            want: Ok(0),
+            tdef: TypeDef::new().fallible().integer(),
+        }
+
+        invalid_a {
+            args: func_args![a: "1.0", b: "1.0.0"],
+            want: Err(r#"invalid semantic version "1.0""#),
+            tdef: TypeDef::new().fallible().integer(),
+        }
+
+        invalid_b {
+            args: func_args![a: "1.0.0", b: "latest"],
+            want: Err(r#"invalid semantic version "latest""#),
+            tdef: TypeDef::new().fallible().integer(),
+        }
+    ];
+}
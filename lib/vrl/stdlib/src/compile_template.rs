@@ -0,0 +1,132 @@
+use crate::template_util;
+use std::collections::BTreeMap;
+use vrl::prelude::*;
+
+#[derive(Clone, Copy, Debug)]
+pub struct CompileTemplate;
+
+impl Function for CompileTemplate {
+    fn identifier(&self) -> &'static str {
+        "compile_template"
+    }
+
+    fn is_pure(&self) -> bool {
+        true
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[Parameter {
+            keyword: "template",
+            kind: kind::BYTES,
+            required: true,
+        }]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "compile",
+                source: r#"compile_template!("Hello, {{ user.name }}!")"#,
+                result: Ok(
+                    r#"{ "template": "Hello, {{ user.name }}!", "parts": ["Hello, ", ["user", "name"], "!"] }"#,
+                ),
+            },
+            Example {
+                title: "render",
+                source: r#"render!(compile_template!("{{ status }} {{ path }}"), { "status": 200, "path": "/" })"#,
+                result: Ok("200 /"),
+            },
+            Example {
+                title: "unclosed placeholder",
+                source: r#"compile_template!("Hello, {{ name")"#,
+                result: Err(
+                    r#"function call error for "compile_template" at (0:35): unable to compile template: unclosed placeholder at position 7"#,
+                ),
+            },
+        ]
+    }
+
+    fn compile(&self, mut arguments: ArgumentList) -> Compiled {
+        let template = arguments.required("template");
+
+        Ok(Box::new(CompileTemplateFn { template }))
+    }
+}
+
+#[derive(Debug, Clone)]
+struct CompileTemplateFn {
+    template: Box<dyn Expression>,
+}
+
+impl Expression for CompileTemplateFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let template = self.template.resolve(ctx)?;
+        let template = template.try_bytes_utf8_lossy()?;
+
+        Ok(template_util::compile(&template)?)
+    }
+
+    fn type_def(&self, _: &state::Compiler) -> TypeDef {
+        TypeDef::new().fallible().object(type_def())
+    }
+}
+
+fn type_def() -> BTreeMap<&'static str, TypeDef> {
+    map! {
+        "template": Kind::Bytes,
+        "parts": TypeDef::new().array_mapped::<(), Kind>(map! {
+            (): Kind::Bytes | Kind::Array,
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    test_function![
+        compile_template => CompileTemplate;
+
+        placeholders {
+            args: func_args![template: "Hello, {{ user.name }}! You have {{count}} messages."],
+            want: Ok(value!({
+                "template": "Hello, {{ user.name }}! You have {{count}} messages.",
+                "parts": ["Hello, ", ["user", "name"], "! You have ", ["count"], " messages."]
+            })),
+            tdef: TypeDef::new().fallible().object(type_def()),
+        }
+
+        adjacent_placeholders {
+            args: func_args![template: "{{ a }}{{ b.0 }}"],
+            want: Ok(value!({ "template": "{{ a }}{{ b.0 }}", "parts": [["a"], ["b", "0"]] })),
+            tdef: TypeDef::new().fallible().object(type_def()),
+        }
+
+        escaped_braces {
+            args: func_args![template: r#"\{{ not a placeholder }}"#],
+            want: Ok(value!({
+                "template": r#"\{{ not a placeholder }}"#,
+                "parts": ["{{ not a placeholder }}"]
+            })),
+            tdef: TypeDef::new().fallible().object(type_def()),
+        }
+
+        unclosed_placeholder {
+            args: func_args![template: "Hello, {{ name"],
+            want: Err("unable to compile template: unclosed placeholder at position 7"),
+            tdef: TypeDef::new().fallible().object(type_def()),
+        }
+
+        invalid_placeholder {
+            args: func_args![template: "Hello, {{ user..name }}"],
+            want: Err(r#"unable to compile template: invalid placeholder "{{ user..name }}" at position 7"#),
+            tdef: TypeDef::new().fallible().object(type_def()),
+        }
+
+        empty_placeholder {
+            args: func_args![template: "{{ }}"],
+            want: Err(r#"unable to compile template: invalid placeholder "{{ }}" at position 0"#),
+            tdef: TypeDef::new().fallible().object(type_def()),
+        }
+    ];
+}
@@ -0,0 +1,171 @@
+use chrono::SecondsFormat;
+use vrl::prelude::*;
+
+#[derive(Clone, Copy, Debug)]
+pub struct Concat;
+
+impl Function for Concat {
+    fn identifier(&self) -> &'static str {
+        "concat"
+    }
+
+    fn is_pure(&self) -> bool {
+        true
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[
+            Parameter {
+                keyword: "parts",
+                kind: kind::ARRAY,
+                required: true,
+            },
+            Parameter {
+                keyword: "separator",
+                kind: kind::BYTES,
+                required: false,
+            },
+        ]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "mixed types",
+                source: r#"concat!(["user-", 42, "-", true])"#,
+                result: Ok("user-42-true"),
+            },
+            Example {
+                title: "separator",
+                source: r#"concat!(["GET", "/api", 200, 1.5], separator: " ")"#,
+                result: Ok("GET /api 200 1.5"),
+            },
+            Example {
+                title: "invalid part",
+                source: r#"concat!(["a", { "b": 1 }])"#,
+                result: Err(
+                    r#"function call error for "concat" at (0:26): unable to coerce "object" at index 1 into "string""#,
+                ),
+            },
+        ]
+    }
+
+    fn compile(&self, mut arguments: ArgumentList) -> Compiled {
+        let parts = arguments.required("parts");
+        let separator = arguments.optional("separator");
+
+        Ok(Box::new(ConcatFn { parts, separator }))
+    }
+}
+
+#[derive(Debug, Clone)]
+struct ConcatFn {
+    parts: Box<dyn Expression>,
+    separator: Option<Box<dyn Expression>>,
+}
+
+impl Expression for ConcatFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let parts = self.parts.resolve(ctx)?.try_array()?;
+        let separator = match &self.separator {
+            Some(expr) => expr.resolve(ctx)?.try_bytes()?,
+            None => Bytes::new(),
+        };
+        let separator = String::from_utf8_lossy(&separator);
+
+        // Strings are usually most of the parts, so their length is a good
+        // estimate of the length of the result.
+        let capacity = parts
+            .iter()
+            .map(|part| match part {
+                Value::Bytes(bytes) => bytes.len(),
+                _ => 0,
+            })
+            .sum::<usize>()
+            + separator.len() * parts.len().saturating_sub(1);
+        let mut concatenated = String::with_capacity(capacity);
+
+        for (index, part) in parts.iter().enumerate() {
+            if index > 0 {
+                concatenated.push_str(&separator);
+            }
+
+            match part {
+                Value::Bytes(bytes) => concatenated.push_str(&String::from_utf8_lossy(bytes)),
+                Value::Integer(integer) => concatenated.push_str(&integer.to_string()),
+                Value::Float(float) => concatenated.push_str(&float.to_string()),
+                Value::Boolean(boolean) => concatenated.push_str(&boolean.to_string()),
+                Value::Timestamp(timestamp) => {
+                    concatenated.push_str(&timestamp.to_rfc3339_opts(SecondsFormat::AutoSi, true))
+                }
+                Value::Null => {}
+                part => {
+                    return Err(format!(
+                        r#"unable to coerce {} at index {} into "string""#,
+                        part.kind(),
+                        index
+                    )
+                    .into())
+                }
+            }
+        }
+
+        Ok(concatenated.into())
+    }
+
+    fn type_def(&self, _: &state::Compiler) -> TypeDef {
+        TypeDef::new().fallible().bytes()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{TimeZone, Utc};
+
+    test_function![
+        concat => Concat;
+
+        strings {
+            args: func_args![parts: value!(["foo", "bar", "baz"])],
+            want: Ok("foobarbaz"),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+
+        mixed_types {
+            args: func_args![parts: value!(["user-", 42, "-", 1.5, "-", true, "-", null, "-"])],
+            want: Ok("user-42-1.5-true--"),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+
+        timestamp {
+            args: func_args![parts: Value::Array(vec!["at ".into(), Utc.ymd(2021, 2, 3).and_hms(4, 5, 6).into()])],
+            want: Ok("at 2021-02-03T04:05:06Z"),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+
+        separator {
+            args: func_args![parts: value!(["GET", "/api", 200]), separator: " "],
+            want: Ok("GET /api 200"),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+
+        single_part_separator {
+            args: func_args![parts: value!([1]), separator: ", "],
+            want: Ok("1"),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+
+        empty {
+            args: func_args![parts: value!([]), separator: ", "],
+            want: Ok(""),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+
+        invalid_part {
+            args: func_args![parts: value!(["a", ["b"]])],
+            want: Err(r#"unable to coerce "array" at index 1 into "string""#),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+    ];
+}
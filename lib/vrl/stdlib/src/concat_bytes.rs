@@ -0,0 +1,99 @@
+use vrl::prelude::*;
+
+#[derive(Clone, Copy, Debug)]
+pub struct ConcatBytes;
+
+impl Function for ConcatBytes {
+    fn identifier(&self) -> &'static str {
+        "concat_bytes"
+    }
+
+    fn is_pure(&self) -> bool {
+        true
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[Parameter {
+            keyword: "parts",
+            kind: kind::ARRAY,
+            required: true,
+        }]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[Example {
+            title: "concatenate",
+            source: r#"concat_bytes!(["foo", "bar"])"#,
+            result: Ok("foobar"),
+        }]
+    }
+
+    fn compile(&self, mut arguments: ArgumentList) -> Compiled {
+        let parts = arguments.required("parts");
+
+        Ok(Box::new(ConcatBytesFn { parts }))
+    }
+}
+
+#[derive(Debug, Clone)]
+struct ConcatBytesFn {
+    parts: Box<dyn Expression>,
+}
+
+impl Expression for ConcatBytesFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let parts = self
+            .parts
+            .resolve(ctx)?
+            .try_array()?
+            .into_iter()
+            .map(Value::try_bytes)
+            .collect::<std::result::Result<Vec<_>, _>>()
+            .map_err(|_| "all array items must be strings")?;
+
+        // Unlike `join`, the parts aren't read as UTF-8, so that binary data
+        // is kept as it is.
+        Ok(Bytes::from(parts.concat()).into())
+    }
+
+    fn type_def(&self, _: &state::Compiler) -> TypeDef {
+        TypeDef::new().fallible().bytes()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    test_function![
+        concat_bytes => ConcatBytes;
+
+        strings {
+            args: func_args![parts: value!(["foo", "bar", "baz"])],
+            want: Ok("foobarbaz"),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+
+        binary {
+            args: func_args![parts: vec![
+                Value::from(Bytes::from_static(b"\xff\x00")),
+                Value::from(""),
+                Value::from(Bytes::from_static(b"\x80\xfe")),
+            ]],
+            want: Ok(Bytes::from_static(b"\xff\x00\x80\xfe")),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+
+        empty {
+            args: func_args![parts: value!([])],
+            want: Ok(""),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+
+        non_string_part {
+            args: func_args![parts: value!(["foo", 1])],
+            want: Err("all array items must be strings"),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+    ];
+}
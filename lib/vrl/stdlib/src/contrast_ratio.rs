@@ -0,0 +1,110 @@
+use crate::color_util::{self, Color};
+use vrl::prelude::*;
+
+#[derive(Clone, Copy, Debug)]
+pub struct ContrastRatio;
+
+impl Function for ContrastRatio {
+    fn identifier(&self) -> &'static str {
+        "contrast_ratio"
+    }
+
+    fn is_pure(&self) -> bool {
+        true
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[
+            Parameter {
+                keyword: "a",
+                kind: kind::BYTES | kind::OBJECT,
+                required: true,
+            },
+            Parameter {
+                keyword: "b",
+                kind: kind::BYTES | kind::OBJECT,
+                required: true,
+            },
+        ]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "black and white",
+                source: r#"contrast_ratio!("black", "white")"#,
+                result: Ok("21.0"),
+            },
+            Example {
+                title: "same color",
+                source: r##"contrast_ratio!("#3366cc", { "r": 51, "g": 102, "b": 204 })"##,
+                result: Ok("1.0"),
+            },
+        ]
+    }
+
+    fn compile(&self, mut arguments: ArgumentList) -> Compiled {
+        let a = arguments.required("a");
+        let b = arguments.required("b");
+
+        Ok(Box::new(ContrastRatioFn { a, b }))
+    }
+}
+
+#[derive(Debug, Clone)]
+struct ContrastRatioFn {
+    a: Box<dyn Expression>,
+    b: Box<dyn Expression>,
+}
+
+impl Expression for ContrastRatioFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let a = Color::from_value(&self.a.resolve(ctx)?)?;
+        let b = Color::from_value(&self.b.resolve(ctx)?)?;
+
+        Ok(color_util::contrast_ratio(&a, &b).into())
+    }
+
+    fn type_def(&self, _: &state::Compiler) -> TypeDef {
+        TypeDef::new().fallible().float()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    test_function![
+        contrast_ratio => ContrastRatio;
+
+        black_and_white {
+            args: func_args![a: "black", b: "white"],
+            want: Ok(21.0),
+            tdef: TypeDef::new().fallible().float(),
+        }
+
+        white_and_black {
+            args: func_args![a: "#ffffff", b: "#000"],
+            want: Ok(21.0),
+            tdef: TypeDef::new().fallible().float(),
+        }
+
+        same_color {
+            args: func_args![a: "#3366cc", b: value!({ "r": 51, "g": 102, "b": 204 })],
+            want: Ok(1.0),
+            tdef: TypeDef::new().fallible().float(),
+        }
+
+        white_and_red {
+            args: func_args![a: "white", b: "red"],
+            want: Ok(1.05 / 0.2626),
+            tdef: TypeDef::new().fallible().float(),
+        }
+
+        invalid_color {
+            args: func_args![a: "black", b: "blurple"],
+            want: Err(r#"unable to parse color "blurple""#),
+            tdef: TypeDef::new().fallible().float(),
+        }
+    ];
+}
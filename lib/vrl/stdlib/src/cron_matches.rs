@@ -0,0 +1,141 @@
+use crate::cron_util::Schedule;
+use vrl::prelude::*;
+
+#[derive(Clone, Copy, Debug)]
+pub struct CronMatches;
+
+impl Function for CronMatches {
+    fn identifier(&self) -> &'static str {
+        "cron_matches"
+    }
+
+    fn is_pure(&self) -> bool {
+        true
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[
+            Parameter {
+                keyword: "expression",
+                kind: kind::BYTES,
+                required: true,
+            },
+            Parameter {
+                keyword: "timestamp",
+                kind: kind::TIMESTAMP,
+                required: true,
+            },
+        ]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "match",
+                source: r#"cron_matches!("*/15 * * * *", t'2021-03-05T12:30:45Z')"#,
+                result: Ok("true"),
+            },
+            Example {
+                title: "mismatch",
+                source: r#"cron_matches!("0 9 * * mon-fri", t'2021-03-06T09:00:00Z')"#,
+                result: Ok("false"),
+            },
+        ]
+    }
+
+    fn compile(&self, mut arguments: ArgumentList) -> Compiled {
+        let expression = arguments.required("expression");
+        let timestamp = arguments.required("timestamp");
+
+        Ok(Box::new(CronMatchesFn {
+            expression,
+            timestamp,
+        }))
+    }
+}
+
+#[derive(Debug, Clone)]
+struct CronMatchesFn {
+    expression: Box<dyn Expression>,
+    timestamp: Box<dyn Expression>,
+}
+
+impl Expression for CronMatchesFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let bytes = self.expression.resolve(ctx)?.try_bytes()?;
+        let expression = String::from_utf8_lossy(&bytes);
+        let timestamp = self.timestamp.resolve(ctx)?.try_timestamp()?;
+
+        let schedule = Schedule::parse(&expression)?;
+
+        Ok(schedule.matches(timestamp).into())
+    }
+
+    fn type_def(&self, _: &state::Compiler) -> TypeDef {
+        TypeDef::new().fallible().boolean()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{TimeZone, Utc};
+
+    test_function![
+        cron_matches => CronMatches;
+
+        daily {
+            args: func_args![expression: "0 9 * * *", timestamp: Utc.ymd(2021, 3, 5).and_hms(9, 0, 59)],
+            want: Ok(true),
+            tdef: TypeDef::new().fallible().boolean(),
+        }
+
+        daily_mismatch {
+            args: func_args![expression: "0 9 * * *", timestamp: Utc.ymd(2021, 3, 5).and_hms(9, 1, 0)],
+            want: Ok(false),
+            tdef: TypeDef::new().fallible().boolean(),
+        }
+
+        step {
+            args: func_args![expression: "*/15 * * * *", timestamp: Utc.ymd(2021, 3, 5).and_hms(12, 30, 45)],
+            want: Ok(true),
+            tdef: TypeDef::new().fallible().boolean(),
+        }
+
+        step_mismatch {
+            args: func_args![expression: "*/15 * * * *", timestamp: Utc.ymd(2021, 3, 5).and_hms(12, 31, 0)],
+            want: Ok(false),
+            tdef: TypeDef::new().fallible().boolean(),
+        }
+
+        step_from_value {
+            args: func_args![expression: "5/20 * * * *", timestamp: Utc.ymd(2021, 3, 5).and_hms(12, 45, 0)],
+            want: Ok(true),
+            tdef: TypeDef::new().fallible().boolean(),
+        }
+
+        weekdays {
+            args: func_args![expression: "0 9 * * mon-fri", timestamp: Utc.ymd(2021, 3, 6).and_hms(9, 0, 0)],
+            want: Ok(false),
+            tdef: TypeDef::new().fallible().boolean(),
+        }
+
+        sunday_as_seven {
+            args: func_args![expression: "0 9 * * 7", timestamp: Utc.ymd(2021, 3, 7).and_hms(9, 0, 0)],
+            want: Ok(true),
+            tdef: TypeDef::new().fallible().boolean(),
+        }
+
+        invalid_range {
+            args: func_args![expression: "0 9 * * fri-mon", timestamp: Utc.ymd(2021, 3, 5).and_hms(9, 0, 0)],
+            want: Err(r#"invalid cron expression "0 9 * * fri-mon": invalid day of the week field "fri-mon""#),
+            tdef: TypeDef::new().fallible().boolean(),
+        }
+
+        invalid_name {
+            args: func_args![expression: "0 9 * foo *", timestamp: Utc.ymd(2021, 3, 5).and_hms(9, 0, 0)],
+            want: Err(r#"invalid cron expression "0 9 * foo *": invalid month field "foo""#),
+            tdef: TypeDef::new().fallible().boolean(),
+        }
+    ];
+}
@@ -0,0 +1,174 @@
+use crate::cron_util::{Schedule, MAX_YEARS};
+use vrl::prelude::*;
+
+#[derive(Clone, Copy, Debug)]
+pub struct CronNext;
+
+impl Function for CronNext {
+    fn identifier(&self) -> &'static str {
+        "cron_next"
+    }
+
+    fn is_pure(&self) -> bool {
+        true
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[
+            Parameter {
+                keyword: "expression",
+                kind: kind::BYTES,
+                required: true,
+            },
+            Parameter {
+                keyword: "after",
+                kind: kind::TIMESTAMP,
+                required: true,
+            },
+        ]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "daily",
+                source: r#"cron_next!("0 9 * * *", t'2021-03-05T12:00:00Z')"#,
+                result: Ok("t'2021-03-06T09:00:00Z'"),
+            },
+            Example {
+                title: "step",
+                source: r#"cron_next!("*/15 * * * *", t'2021-03-05T12:07:10Z')"#,
+                result: Ok("t'2021-03-05T12:15:00Z'"),
+            },
+        ]
+    }
+
+    fn compile(&self, mut arguments: ArgumentList) -> Compiled {
+        let expression = arguments.required("expression");
+        let after = arguments.required("after");
+
+        Ok(Box::new(CronNextFn { expression, after }))
+    }
+}
+
+#[derive(Debug, Clone)]
+struct CronNextFn {
+    expression: Box<dyn Expression>,
+    after: Box<dyn Expression>,
+}
+
+impl Expression for CronNextFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let bytes = self.expression.resolve(ctx)?.try_bytes()?;
+        let expression = String::from_utf8_lossy(&bytes);
+        let after = self.after.resolve(ctx)?.try_timestamp()?;
+
+        let schedule = Schedule::parse(&expression)?;
+
+        schedule.next_after(after).map(Into::into).ok_or_else(|| {
+            format!(
+                r#"cron expression "{}" doesn't fire within {} years"#,
+                expression, MAX_YEARS
+            )
+            .into()
+        })
+    }
+
+    fn type_def(&self, _: &state::Compiler) -> TypeDef {
+        TypeDef::new().fallible().timestamp()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{TimeZone, Utc};
+
+    test_function![
+        cron_next => CronNext;
+
+        daily {
+            args: func_args![expression: "0 9 * * *", after: Utc.ymd(2021, 3, 5).and_hms(12, 0, 0)],
+            want: Ok(Utc.ymd(2021, 3, 6).and_hms(9, 0, 0)),
+            tdef: TypeDef::new().fallible().timestamp(),
+        }
+
+        daily_same_day {
+            args: func_args![expression: "0 9 * * *", after: Utc.ymd(2021, 3, 5).and_hms(8, 59, 30)],
+            want: Ok(Utc.ymd(2021, 3, 5).and_hms(9, 0, 0)),
+            tdef: TypeDef::new().fallible().timestamp(),
+        }
+
+        strictly_after {
+            args: func_args![expression: "0 9 * * *", after: Utc.ymd(2021, 3, 5).and_hms(9, 0, 0)],
+            want: Ok(Utc.ymd(2021, 3, 6).and_hms(9, 0, 0)),
+            tdef: TypeDef::new().fallible().timestamp(),
+        }
+
+        daily_macro {
+            args: func_args![expression: "@daily", after: Utc.ymd(2021, 3, 5).and_hms(12, 0, 0)],
+            want: Ok(Utc.ymd(2021, 3, 6).and_hms(0, 0, 0)),
+            tdef: TypeDef::new().fallible().timestamp(),
+        }
+
+        step {
+            args: func_args![expression: "*/15 * * * *", after: Utc.ymd(2021, 3, 5).and_hms(12, 7, 10)],
+            want: Ok(Utc.ymd(2021, 3, 5).and_hms(12, 15, 0)),
+            tdef: TypeDef::new().fallible().timestamp(),
+        }
+
+        step_next_hour {
+            args: func_args![expression: "*/15 * * * *", after: Utc.ymd(2021, 3, 5).and_hms(23, 45, 0)],
+            want: Ok(Utc.ymd(2021, 3, 6).and_hms(0, 0, 0)),
+            tdef: TypeDef::new().fallible().timestamp(),
+        }
+
+        ranges_and_lists {
+            args: func_args![expression: "0,30 9-17/4 * * mon-fri", after: Utc.ymd(2021, 3, 5).and_hms(17, 30, 0)],
+            want: Ok(Utc.ymd(2021, 3, 8).and_hms(9, 0, 0)),
+            tdef: TypeDef::new().fallible().timestamp(),
+        }
+
+        day_of_month_or_week {
+            args: func_args![expression: "0 0 13 * 5", after: Utc.ymd(2021, 3, 6).and_hms(0, 0, 0)],
+            want: Ok(Utc.ymd(2021, 3, 12).and_hms(0, 0, 0)),
+            tdef: TypeDef::new().fallible().timestamp(),
+        }
+
+        named_month {
+            args: func_args![expression: "0 0 1 JAN *", after: Utc.ymd(2021, 3, 5).and_hms(12, 0, 0)],
+            want: Ok(Utc.ymd(2022, 1, 1).and_hms(0, 0, 0)),
+            tdef: TypeDef::new().fallible().timestamp(),
+        }
+
+        leap_day {
+            args: func_args![expression: "0 0 29 2 *", after: Utc.ymd(2021, 3, 5).and_hms(12, 0, 0)],
+            want: Ok(Utc.ymd(2024, 2, 29).and_hms(0, 0, 0)),
+            tdef: TypeDef::new().fallible().timestamp(),
+        }
+
+        never {
+            args: func_args![expression: "0 0 30 2 *", after: Utc.ymd(2021, 3, 5).and_hms(12, 0, 0)],
+            want: Err(r#"cron expression "0 0 30 2 *" doesn't fire within 10 years"#),
+            tdef: TypeDef::new().fallible().timestamp(),
+        }
+
+        invalid_value {
+            args: func_args![expression: "60 * * * *", after: Utc.ymd(2021, 3, 5).and_hms(12, 0, 0)],
+            want: Err(r#"invalid cron expression "60 * * * *": invalid minute field "60""#),
+            tdef: TypeDef::new().fallible().timestamp(),
+        }
+
+        invalid_step {
+            args: func_args![expression: "*/0 * * * *", after: Utc.ymd(2021, 3, 5).and_hms(12, 0, 0)],
+            want: Err(r#"invalid cron expression "*/0 * * * *": invalid minute field "*/0""#),
+            tdef: TypeDef::new().fallible().timestamp(),
+        }
+
+        invalid_fields {
+            args: func_args![expression: "* * *", after: Utc.ymd(2021, 3, 5).and_hms(12, 0, 0)],
+            want: Err(r#"invalid cron expression "* * *": expected 5 fields, got 3"#),
+            tdef: TypeDef::new().fallible().timestamp(),
+        }
+    ];
+}
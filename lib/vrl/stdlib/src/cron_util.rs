@@ -0,0 +1,178 @@
+use chrono::{DateTime, Datelike, Duration, NaiveDate, Timelike, Utc};
+
+const MONTHS: &[&str] = &[
+    "jan", "feb", "mar", "apr", "may", "jun", "jul", "aug", "sep", "oct", "nov", "dec",
+];
+
+const WEEKDAYS: &[&str] = &["sun", "mon", "tue", "wed", "thu", "fri", "sat"];
+
+/// How many years [`Schedule::next_after`] looks ahead before giving up, which
+/// is enough for a schedule firing on February 29th only.
+pub(crate) const MAX_YEARS: i32 = 10;
+
+/// A standard 5-field cron schedule, such as `*/15 9-17 * * mon-fri`, in UTC.
+///
+/// Each field is a list of values, ranges (`1-5`), and steps over either of
+/// them or `*` (`*/15`, `0-30/10`, or `5/10`, which starts at `5` and goes to
+/// the end of the range). Months and weekdays can be named by their first
+/// three letters, and Sunday is both `0` and `7`.
+///
+/// As in most cron implementations, if both the day of the month and the day
+/// of the week are restricted, a day matches if either of them does.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Schedule {
+    minutes: u64,
+    hours: u64,
+    days: u64,
+    months: u64,
+    weekdays: u64,
+    any_day: bool,
+    any_weekday: bool,
+}
+
+impl Schedule {
+    pub(crate) fn parse(expression: &str) -> Result<Self, String> {
+        let error =
+            |reason: String| format!(r#"invalid cron expression "{}": {}"#, expression, reason);
+
+        let expanded = match expression.trim() {
+            "@yearly" | "@annually" => "0 0 1 1 *",
+            "@monthly" => "0 0 1 * *",
+            "@weekly" => "0 0 * * 0",
+            "@daily" | "@midnight" => "0 0 * * *",
+            "@hourly" => "0 * * * *",
+            expression => expression,
+        };
+
+        let fields = expanded.split_whitespace().collect::<Vec<_>>();
+        if fields.len() != 5 {
+            return Err(error(format!("expected 5 fields, got {}", fields.len())));
+        }
+
+        let field = |i: usize, name: &str, min: u32, max: u32, names: &[&str]| {
+            parse_field(fields[i], min, max, names)
+                .ok_or_else(|| error(format!(r#"invalid {} field "{}""#, name, fields[i])))
+        };
+
+        let mut weekdays = field(4, "day of the week", 0, 7, WEEKDAYS)?;
+        if contains(weekdays, 7) {
+            weekdays = (weekdays | 1) & !(1 << 7);
+        }
+
+        Ok(Self {
+            minutes: field(0, "minute", 0, 59, &[])?,
+            hours: field(1, "hour", 0, 23, &[])?,
+            days: field(2, "day of the month", 1, 31, &[])?,
+            months: field(3, "month", 1, 12, MONTHS)?,
+            weekdays,
+            any_day: fields[2].starts_with('*'),
+            any_weekday: fields[4].starts_with('*'),
+        })
+    }
+
+    /// Returns `true` if the schedule fires during the minute of the
+    /// timestamp.
+    pub(crate) fn matches(&self, timestamp: DateTime<Utc>) -> bool {
+        self.matches_date(timestamp.date().naive_utc())
+            && contains(self.hours, timestamp.hour())
+            && contains(self.minutes, timestamp.minute())
+    }
+
+    /// Returns the first time the schedule fires strictly after the
+    /// timestamp, or `None` if it doesn't within [`MAX_YEARS`] years.
+    pub(crate) fn next_after(&self, timestamp: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        let start = timestamp
+            .naive_utc()
+            .with_second(0)?
+            .with_nanosecond(0)?
+            .checked_add_signed(Duration::minutes(1))?;
+
+        let mut date = start.date();
+        let (mut hour, mut minute) = (start.hour(), start.minute());
+
+        while date.year() <= start.year() + MAX_YEARS {
+            if self.matches_date(date) {
+                let time = (hour..24)
+                    .filter(|h| contains(self.hours, *h))
+                    .find_map(|h| {
+                        let from = if h == hour { minute } else { 0 };
+                        (from..60)
+                            .find(|m| contains(self.minutes, *m))
+                            .map(|m| (h, m))
+                    });
+
+                if let Some((hour, minute)) = time {
+                    return Some(DateTime::from_utc(date.and_hms(hour, minute, 0), Utc));
+                }
+            }
+
+            date = date.succ_opt()?;
+            hour = 0;
+            minute = 0;
+        }
+
+        None
+    }
+
+    fn matches_date(&self, date: NaiveDate) -> bool {
+        let day = contains(self.days, date.day());
+        let weekday = contains(self.weekdays, date.weekday().num_days_from_sunday());
+
+        let day = if self.any_day || self.any_weekday {
+            day && weekday
+        } else {
+            day || weekday
+        };
+
+        day && contains(self.months, date.month())
+    }
+}
+
+fn contains(set: u64, value: u32) -> bool {
+    set & (1 << value) != 0
+}
+
+/// Parses a field into the set of its values, as a bit set.
+fn parse_field(field: &str, min: u32, max: u32, names: &[&str]) -> Option<u64> {
+    let value = |value: &str| -> Option<u32> {
+        match names
+            .iter()
+            .position(|name| name.eq_ignore_ascii_case(value))
+        {
+            Some(i) => Some(min + i as u32),
+            None => value
+                .parse()
+                .ok()
+                .filter(|value| (min..=max).contains(value)),
+        }
+    };
+
+    let mut set = 0_u64;
+
+    for part in field.split(',') {
+        let mut split = part.splitn(2, '/');
+        let range = split.next()?;
+        let step = match split.next() {
+            Some(step) => Some(step.parse::<usize>().ok().filter(|step| *step > 0)?),
+            None => None,
+        };
+
+        let mut bounds = range.splitn(2, '-');
+        let (start, end) = match (bounds.next()?, bounds.next()) {
+            ("*", None) => (min, max),
+            (start, Some(end)) => (value(start)?, value(end)?),
+            (start, None) if step.is_some() => (value(start)?, max),
+            (start, None) => (value(start)?, value(start)?),
+        };
+
+        if start > end {
+            return None;
+        }
+
+        for value in (start..=end).step_by(step.unwrap_or(1)) {
+            set |= 1 << value;
+        }
+    }
+
+    Some(set)
+}
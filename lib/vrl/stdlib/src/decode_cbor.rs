@@ -0,0 +1,167 @@
+use crate::cbor_util;
+use vrl::prelude::*;
+
+#[derive(Clone, Copy, Debug)]
+pub struct DecodeCbor;
+
+impl Function for DecodeCbor {
+    fn identifier(&self) -> &'static str {
+        "decode_cbor"
+    }
+
+    fn is_pure(&self) -> bool {
+        true
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[Parameter {
+            keyword: "value",
+            kind: kind::BYTES,
+            required: true,
+        }]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "object",
+                source: r#"decode_cbor!(decode_base64!("oWFhggH1"))"#,
+                result: Ok(r#"{ "a": [1, true] }"#),
+            },
+            Example {
+                title: "integer",
+                source: r#"decode_cbor!(decode_base64!("GQPo"))"#,
+                result: Ok("1000"),
+            },
+        ]
+    }
+
+    fn compile(&self, mut arguments: ArgumentList) -> Compiled {
+        let value = arguments.required("value");
+
+        Ok(Box::new(DecodeCborFn { value }))
+    }
+}
+
+#[derive(Debug, Clone)]
+struct DecodeCborFn {
+    value: Box<dyn Expression>,
+}
+
+impl Expression for DecodeCborFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?.try_bytes()?;
+
+        Ok(cbor_util::decode(&value)?)
+    }
+
+    fn type_def(&self, _: &state::Compiler) -> TypeDef {
+        TypeDef::new().fallible().unknown()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{TimeZone, Utc};
+
+    test_function![
+        decode_cbor => DecodeCbor;
+
+        integer {
+            args: func_args![value: Bytes::from_static(&[0x1a, 0x00, 0x0f, 0x42, 0x40])],
+            want: Ok(1_000_000),
+            tdef: TypeDef::new().fallible().unknown(),
+        }
+
+        negative_integer {
+            args: func_args![value: Bytes::from_static(&[0x39, 0x03, 0xe7])],
+            want: Ok(-1000),
+            tdef: TypeDef::new().fallible().unknown(),
+        }
+
+        half_float {
+            args: func_args![value: Bytes::from_static(&[0xf9, 0xc4, 0x00])],
+            want: Ok(-4.0),
+            tdef: TypeDef::new().fallible().unknown(),
+        }
+
+        single_float {
+            args: func_args![value: Bytes::from_static(&[0xfa, 0x47, 0xc3, 0x50, 0x00])],
+            want: Ok(100_000.0),
+            tdef: TypeDef::new().fallible().unknown(),
+        }
+
+        binary {
+            args: func_args![value: Bytes::from_static(&[0x42, 0xff, 0x00])],
+            want: Ok(Bytes::from_static(&[0xff, 0x00])),
+            tdef: TypeDef::new().fallible().unknown(),
+        }
+
+        indefinite_lengths {
+            args: func_args![value: Bytes::from_static(b"\xbf\x61a\x01\x61b\x9f\x02\x7f\x63str\x61s\xff\xff\xff")],
+            want: Ok(value!({ "a": 1, "b": [2, "strs"] })),
+            tdef: TypeDef::new().fallible().unknown(),
+        }
+
+        integer_keys {
+            args: func_args![value: Bytes::from_static(b"\xa2\x01\x61a\x20\x61b")],
+            want: Ok(value!({ "1": "a", "-1": "b" })),
+            tdef: TypeDef::new().fallible().unknown(),
+        }
+
+        timestamp {
+            args: func_args![value: Bytes::from_static(b"\xc0\x742013-03-21T20:04:00Z")],
+            want: Ok(Utc.ymd(2013, 3, 21).and_hms(20, 4, 0)),
+            tdef: TypeDef::new().fallible().unknown(),
+        }
+
+        timestamp_epoch {
+            args: func_args![value: Bytes::from_static(&[0xc1, 0xfb, 0x41, 0xd4, 0x52, 0xd9, 0xec, 0x20, 0x00, 0x00])],
+            want: Ok(Utc.ymd(2013, 3, 21).and_hms_milli(20, 4, 0, 500)),
+            tdef: TypeDef::new().fallible().unknown(),
+        }
+
+        regular_expression {
+            args: func_args![value: Bytes::from_static(b"\xd8\x23\x63a+b")],
+            want: Ok(Value::Regex(regex::Regex::new("a+b").unwrap().into())),
+            tdef: TypeDef::new().fallible().unknown(),
+        }
+
+        unknown_tag {
+            args: func_args![value: Bytes::from_static(&[0xd8, 0x20, 0x01])],
+            want: Ok(1),
+            tdef: TypeDef::new().fallible().unknown(),
+        }
+
+        truncated {
+            args: func_args![value: Bytes::from_static(&[0x19, 0x03])],
+            want: Err("unexpected end of CBOR data"),
+            tdef: TypeDef::new().fallible().unknown(),
+        }
+
+        trailing_data {
+            args: func_args![value: Bytes::from_static(&[0x01, 0x02])],
+            want: Err("unexpected data after the CBOR value at byte 1"),
+            tdef: TypeDef::new().fallible().unknown(),
+        }
+
+        integer_out_of_range {
+            args: func_args![value: Bytes::from_static(&[0x1b, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff])],
+            want: Err("CBOR integer 18446744073709551615 is out of range"),
+            tdef: TypeDef::new().fallible().unknown(),
+        }
+
+        nan {
+            args: func_args![value: Bytes::from_static(&[0xf9, 0x7e, 0x00])],
+            want: Err("NaN floats aren't supported"),
+            tdef: TypeDef::new().fallible().unknown(),
+        }
+
+        invalid_text {
+            args: func_args![value: Bytes::from_static(&[0x61, 0xff])],
+            want: Err("invalid UTF-8 in CBOR text string"),
+            tdef: TypeDef::new().fallible().unknown(),
+        }
+    ];
+}
@@ -0,0 +1,147 @@
+use flate2::{Decompress, FlushDecompress, Status};
+use vrl::prelude::*;
+
+#[derive(Clone, Copy, Debug)]
+pub struct DecodeDeflate;
+
+impl Function for DecodeDeflate {
+    fn identifier(&self) -> &'static str {
+        "decode_deflate"
+    }
+
+    fn is_pure(&self) -> bool {
+        true
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[Parameter {
+            keyword: "value",
+            kind: kind::BYTES,
+            required: true,
+        }]
+    }
+
+    fn compile(&self, mut arguments: ArgumentList) -> Compiled {
+        let value = arguments.required("value");
+
+        Ok(Box::new(DecodeDeflateFn { value }))
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[Example {
+            title: "demo string",
+            source: r#"decode_deflate!(decode_base64!("y0jNyckHAA=="))"#,
+            result: Ok("hello"),
+        }]
+    }
+}
+
+#[derive(Clone, Debug)]
+struct DecodeDeflateFn {
+    value: Box<dyn Expression>,
+}
+
+impl Expression for DecodeDeflateFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?.try_bytes()?;
+
+        Ok(inflate(&value)
+            .ok_or("unable to decode value from raw DEFLATE")?
+            .into())
+    }
+
+    fn type_def(&self, _: &state::Compiler) -> TypeDef {
+        TypeDef::new().bytes().fallible()
+    }
+}
+
+/// Inflates a raw DEFLATE stream, without a zlib or gzip wrapper. Streams
+/// that are corrupt, or that end before their final block, fail.
+fn inflate(input: &[u8]) -> Option<Bytes> {
+    let mut decompress = Decompress::new(false);
+    let mut output = Vec::with_capacity(input.len() * 2);
+
+    loop {
+        let consumed = decompress.total_in() as usize;
+        let status = decompress
+            .decompress_vec(&input[consumed..], &mut output, FlushDecompress::None)
+            .ok()?;
+
+        match status {
+            Status::StreamEnd => return Some(output.into()),
+            // The stream goes on for as long as there's room for its output.
+            _ if output.len() == output.capacity() => output.reserve(output.len().max(64)),
+            _ => return None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use flate2::{
+        write::{DeflateEncoder, GzEncoder},
+        Compression,
+    };
+    use std::io::Write;
+
+    fn deflate(value: &[u8]) -> Bytes {
+        let mut encoder = DeflateEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(value).unwrap();
+
+        encoder.finish().unwrap().into()
+    }
+
+    fn gzip(value: &[u8]) -> Bytes {
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(value).unwrap();
+
+        encoder.finish().unwrap().into()
+    }
+
+    test_function![
+        decode_deflate => DecodeDeflate;
+
+        round_trip {
+            args: func_args![value: deflate(b"hello hello hello hello")],
+            want: Ok("hello hello hello hello"),
+            tdef: TypeDef::new().bytes().fallible(),
+        }
+
+        round_trip_binary {
+            args: func_args![value: deflate(&[0, 255, 128, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1])],
+            want: Ok(Bytes::from_static(&[0, 255, 128, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1])),
+            tdef: TypeDef::new().bytes().fallible(),
+        }
+
+        round_trip_large {
+            args: func_args![value: deflate("0123456789".repeat(1000).as_bytes())],
+            want: Ok("0123456789".repeat(1000)),
+            tdef: TypeDef::new().bytes().fallible(),
+        }
+
+        stored {
+            args: func_args![value: Bytes::from_static(b"\x01\x05\x00\xfa\xffhello")],
+            want: Ok("hello"),
+            tdef: TypeDef::new().bytes().fallible(),
+        }
+
+        gzip_rejected {
+            args: func_args![value: gzip(b"hello")],
+            want: Err("unable to decode value from raw DEFLATE"),
+            tdef: TypeDef::new().bytes().fallible(),
+        }
+
+        truncated {
+            args: func_args![value: deflate(b"hello hello hello hello").slice(..4)],
+            want: Err("unable to decode value from raw DEFLATE"),
+            tdef: TypeDef::new().bytes().fallible(),
+        }
+
+        empty {
+            args: func_args![value: ""],
+            want: Err("unable to decode value from raw DEFLATE"),
+            tdef: TypeDef::new().bytes().fallible(),
+        }
+    ];
+}
@@ -0,0 +1,134 @@
+use vrl::prelude::*;
+
+#[derive(Clone, Copy, Debug)]
+pub struct DecodeHex;
+
+impl Function for DecodeHex {
+    fn identifier(&self) -> &'static str {
+        "decode_hex"
+    }
+
+    fn is_pure(&self) -> bool {
+        true
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[Parameter {
+            keyword: "value",
+            kind: kind::BYTES,
+            required: true,
+        }]
+    }
+
+    fn compile(&self, mut arguments: ArgumentList) -> Compiled {
+        let value = arguments.required("value");
+
+        Ok(Box::new(DecodeHexFn { value }))
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "bare",
+                source: r#"decode_hex!("68656c6c6f")"#,
+                result: Ok("hello"),
+            },
+            Example {
+                title: "delimited",
+                source: r#"decode_hex!("68:65:6C:6C:6F")"#,
+                result: Ok("hello"),
+            },
+        ]
+    }
+}
+
+#[derive(Clone, Debug)]
+struct DecodeHexFn {
+    value: Box<dyn Expression>,
+}
+
+impl Expression for DecodeHexFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?;
+        let value = value.try_bytes_utf8_lossy()?;
+
+        // Digits can be separated by whitespace and the usual delimiters, in
+        // any mix, as long as each byte is written with two digits.
+        let mut digits = vec![];
+        for c in value.chars() {
+            match c.to_digit(16) {
+                Some(digit) => digits.push(digit as u8),
+                None if c.is_whitespace() || matches!(c, ':' | '-' | ',' | '.') => {}
+                None => {
+                    return Err(format!("unable to decode hex: invalid character {:?}", c).into())
+                }
+            }
+        }
+
+        if digits.len() % 2 != 0 {
+            return Err("unable to decode hex: odd number of digits".into());
+        }
+
+        let bytes = digits
+            .chunks(2)
+            .map(|pair| (pair[0] << 4) | pair[1])
+            .collect::<Vec<_>>();
+
+        Ok(Bytes::from(bytes).into())
+    }
+
+    fn type_def(&self, _: &state::Compiler) -> TypeDef {
+        TypeDef::new().fallible().bytes()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    test_function![
+        decode_hex => DecodeHex;
+
+        bare {
+            args: func_args![value: "deadbeef"],
+            want: Ok(Bytes::from_static(b"\xde\xad\xbe\xef")),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+
+        uppercase {
+            args: func_args![value: "DEADbeef"],
+            want: Ok(Bytes::from_static(b"\xde\xad\xbe\xef")),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+
+        delimited {
+            args: func_args![value: "aa:bb:cc"],
+            want: Ok(Bytes::from_static(b"\xaa\xbb\xcc")),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+
+        mixed_whitespace_and_delimiters {
+            args: func_args![value: " 68 65:6c-6c\n\t6f, 21 "],
+            want: Ok("hello!"),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+
+        empty {
+            args: func_args![value: ""],
+            want: Ok(""),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+
+        odd_digits {
+            args: func_args![value: "abc"],
+            want: Err("unable to decode hex: odd number of digits"),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+
+        invalid_character {
+            args: func_args![value: "ab:cg"],
+            want: Err(r#"unable to decode hex: invalid character 'g'"#),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+    ];
+}
@@ -0,0 +1,116 @@
+use crate::lz4_util;
+use vrl::prelude::*;
+
+#[derive(Clone, Copy, Debug)]
+pub struct DecodeLz4;
+
+impl Function for DecodeLz4 {
+    fn identifier(&self) -> &'static str {
+        "decode_lz4"
+    }
+
+    fn is_pure(&self) -> bool {
+        true
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[Parameter {
+            keyword: "value",
+            kind: kind::BYTES,
+            required: true,
+        }]
+    }
+
+    fn compile(&self, mut arguments: ArgumentList) -> Compiled {
+        let value = arguments.required("value");
+
+        Ok(Box::new(DecodeLz4Fn { value }))
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[Example {
+            title: "demo string",
+            source: r#"decode_lz4!(decode_base64!("BCJNGGBAggUAAIBoZWxsbwAAAAA="))"#,
+            result: Ok("hello"),
+        }]
+    }
+}
+
+#[derive(Clone, Debug)]
+struct DecodeLz4Fn {
+    value: Box<dyn Expression>,
+}
+
+impl Expression for DecodeLz4Fn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?.try_bytes()?;
+
+        // Checksums of the content and of blocks are verified when the frame
+        // has them.
+        match lz4_util::decode(&value) {
+            Ok(decoded) => Ok(Bytes::from(decoded).into()),
+            Err(err) => Err(format!("unable to decode value from LZ4: {}", err).into()),
+        }
+    }
+
+    fn type_def(&self, _: &state::Compiler) -> TypeDef {
+        TypeDef::new().bytes().fallible()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    test_function![
+        decode_lz4 => DecodeLz4;
+
+        frame {
+            args: func_args![value: Bytes::from_static(b"\x04\x22\x4d\x18\x60\x40\x82\x05\x00\x00\x80hello\x00\x00\x00\x00")],
+            want: Ok("hello"),
+            tdef: TypeDef::new().bytes().fallible(),
+        }
+
+        content_checksum {
+            args: func_args![value: Bytes::from_static(b"\x04\x22\x4d\x18\x64\x40\xa7\x05\x00\x00\x80hello\x00\x00\x00\x00\xf9\x77\x00\xfb")],
+            want: Ok("hello"),
+            tdef: TypeDef::new().bytes().fallible(),
+        }
+
+        invalid_content_checksum {
+            args: func_args![value: Bytes::from_static(b"\x04\x22\x4d\x18\x64\x40\xa7\x05\x00\x00\x80hello\x00\x00\x00\x00\x00\x00\x00\x00")],
+            want: Err("unable to decode value from LZ4: invalid content checksum"),
+            tdef: TypeDef::new().bytes().fallible(),
+        }
+
+        invalid_magic_number {
+            args: func_args![value: "hello"],
+            want: Err("unable to decode value from LZ4: not an LZ4 frame"),
+            tdef: TypeDef::new().bytes().fallible(),
+        }
+
+        invalid_header_checksum {
+            args: func_args![value: Bytes::from_static(b"\x04\x22\x4d\x18\x60\x40\x00\x05\x00\x00\x80hello\x00\x00\x00\x00")],
+            want: Err("unable to decode value from LZ4: invalid header checksum"),
+            tdef: TypeDef::new().bytes().fallible(),
+        }
+
+        truncated {
+            args: func_args![value: Bytes::from_static(b"\x04\x22\x4d\x18\x60\x40\x82\x05\x00\x00\x80hel")],
+            want: Err("unable to decode value from LZ4: unexpected end of frame"),
+            tdef: TypeDef::new().bytes().fallible(),
+        }
+
+        invalid_block {
+            args: func_args![value: Bytes::from_static(b"\x04\x22\x4d\x18\x60\x40\x82\x03\x00\x00\x00\xf0\x00\x00\x00\x00\x00")],
+            want: Err("unable to decode value from LZ4: invalid block"),
+            tdef: TypeDef::new().bytes().fallible(),
+        }
+
+        trailing_data {
+            args: func_args![value: Bytes::from_static(b"\x04\x22\x4d\x18\x60\x40\x82\x05\x00\x00\x80hello\x00\x00\x00\x00\x00")],
+            want: Err("unable to decode value from LZ4: unexpected data after the frame"),
+            tdef: TypeDef::new().bytes().fallible(),
+        }
+    ];
+}
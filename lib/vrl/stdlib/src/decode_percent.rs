@@ -0,0 +1,135 @@
+use percent_encoding::percent_decode;
+use vrl::prelude::*;
+
+#[derive(Clone, Copy, Debug)]
+pub struct DecodePercent;
+
+impl Function for DecodePercent {
+    fn identifier(&self) -> &'static str {
+        "decode_percent"
+    }
+
+    fn is_pure(&self) -> bool {
+        true
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[Parameter {
+            keyword: "value",
+            kind: kind::BYTES,
+            required: true,
+        }]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "percent-encoded",
+                source: r#"decode_percent!("foo%20bar%3F")"#,
+                result: Ok("foo bar?"),
+            },
+            Example {
+                title: "malformed",
+                source: r#"decode_percent!("100%")"#,
+                result: Err(
+                    r#"function call error for "decode_percent" at (0:23): invalid percent-encoded sequence at position 3"#,
+                ),
+            },
+        ]
+    }
+
+    fn compile(&self, mut arguments: ArgumentList) -> Compiled {
+        let value = arguments.required("value");
+
+        Ok(Box::new(DecodePercentFn { value }))
+    }
+}
+
+#[derive(Debug, Clone)]
+struct DecodePercentFn {
+    value: Box<dyn Expression>,
+}
+
+impl Expression for DecodePercentFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?.try_bytes()?;
+
+        // `percent_decode` leaves malformed sequences untouched, reject them
+        // instead so they don't go unnoticed.
+        let mut bytes = value.iter().enumerate();
+        while let Some((pos, &byte)) = bytes.next() {
+            if byte != b'%' {
+                continue;
+            }
+
+            let valid = value
+                .get(pos + 1..pos + 3)
+                .map_or(false, |hex| hex.iter().all(u8::is_ascii_hexdigit));
+
+            if !valid {
+                return Err(format!("invalid percent-encoded sequence at position {}", pos).into());
+            }
+
+            bytes.nth(1);
+        }
+
+        let decoded: Vec<u8> = percent_decode(&value).collect();
+
+        Ok(Bytes::from(decoded).into())
+    }
+
+    fn type_def(&self, _: &state::Compiler) -> TypeDef {
+        TypeDef::new().fallible().bytes()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    test_function![
+        decode_percent => DecodePercent;
+
+        plain {
+            args: func_args![value: "foo bar"],
+            want: Ok("foo bar"),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+
+        reserved {
+            args: func_args![value: "a%20b%2Fc%3fd%3De%26f-g.h+i"],
+            want: Ok("a b/c?d=e&f-g.h+i"),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+
+        non_ascii {
+            args: func_args![value: "caf%C3%A9"],
+            want: Ok("caf\u{e9}"),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+
+        encoded_percent {
+            args: func_args![value: "100%25%2541"],
+            want: Ok("100%%41"),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+
+        trailing_percent {
+            args: func_args![value: "100%"],
+            want: Err("invalid percent-encoded sequence at position 3"),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+
+        truncated_sequence {
+            args: func_args![value: "foo%2"],
+            want: Err("invalid percent-encoded sequence at position 3"),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+
+        invalid_hex {
+            args: func_args![value: "ok%20then%zzz"],
+            want: Err("invalid percent-encoded sequence at position 9"),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+    ];
+}
@@ -0,0 +1,126 @@
+use snap::{raw::Decoder, read::FrameDecoder};
+use std::io::Read;
+use vrl::prelude::*;
+
+#[derive(Clone, Copy, Debug)]
+pub struct DecodeSnappy;
+
+impl Function for DecodeSnappy {
+    fn identifier(&self) -> &'static str {
+        "decode_snappy"
+    }
+
+    fn is_pure(&self) -> bool {
+        true
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[
+            Parameter {
+                keyword: "value",
+                kind: kind::BYTES,
+                required: true,
+            },
+            Parameter {
+                keyword: "framed",
+                kind: kind::BOOLEAN,
+                required: false,
+            },
+        ]
+    }
+
+    fn compile(&self, mut arguments: ArgumentList) -> Compiled {
+        let value = arguments.required("value");
+        let framed = arguments.optional("framed").unwrap_or(expr!(false));
+
+        Ok(Box::new(DecodeSnappyFn { value, framed }))
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[Example {
+            title: "raw",
+            source: r#"decode_snappy!(decode_base64!("BRBoZWxsbw=="))"#,
+            result: Ok("hello"),
+        }]
+    }
+}
+
+#[derive(Clone, Debug)]
+struct DecodeSnappyFn {
+    value: Box<dyn Expression>,
+    framed: Box<dyn Expression>,
+}
+
+impl Expression for DecodeSnappyFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?.try_bytes()?;
+        let framed = self.framed.resolve(ctx)?.try_boolean()?;
+
+        let decoded = if framed {
+            let mut decoded = vec![];
+            match FrameDecoder::new(&value[..]).read_to_end(&mut decoded) {
+                Ok(_) => Some(decoded),
+                Err(_) => None,
+            }
+        } else {
+            Decoder::new().decompress_vec(&value).ok()
+        };
+
+        match decoded {
+            Some(decoded) => Ok(Bytes::from(decoded).into()),
+            None => Err("unable to decode value from snappy".into()),
+        }
+    }
+
+    fn type_def(&self, _: &state::Compiler) -> TypeDef {
+        TypeDef::new().bytes().fallible()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    test_function![
+        decode_snappy => DecodeSnappy;
+
+        raw {
+            args: func_args![value: Bytes::from_static(b"\x05\x10hello")],
+            want: Ok("hello"),
+            tdef: TypeDef::new().bytes().fallible(),
+        }
+
+        framed {
+            args: func_args![
+                value: Bytes::from_static(b"\xff\x06\x00\x00sNaPpY\x01\x09\x00\x00\xbb\x1f\x1c\x19hello"),
+                framed: true,
+            ],
+            want: Ok("hello"),
+            tdef: TypeDef::new().bytes().fallible(),
+        }
+
+        truncated_raw {
+            args: func_args![value: Bytes::from_static(b"\x05\x10hel")],
+            want: Err("unable to decode value from snappy"),
+            tdef: TypeDef::new().bytes().fallible(),
+        }
+
+        invalid_stream_identifier {
+            args: func_args![
+                value: Bytes::from_static(b"\xff\x06\x00\x00sNaPpZ\x01\x09\x00\x00\xbb\x1f\x1c\x19hello"),
+                framed: true,
+            ],
+            want: Err("unable to decode value from snappy"),
+            tdef: TypeDef::new().bytes().fallible(),
+        }
+
+        invalid_checksum {
+            args: func_args![
+                value: Bytes::from_static(b"\xff\x06\x00\x00sNaPpY\x01\x09\x00\x00\x00\x00\x00\x00hello"),
+                framed: true,
+            ],
+            want: Err("unable to decode value from snappy"),
+            tdef: TypeDef::new().bytes().fallible(),
+        }
+    ];
+}
@@ -0,0 +1,141 @@
+use vrl::prelude::*;
+use vrl::value::EqOptions;
+
+#[derive(Clone, Copy, Debug)]
+pub struct DeepEqual;
+
+impl Function for DeepEqual {
+    fn identifier(&self) -> &'static str {
+        "deep_equal"
+    }
+
+    fn is_pure(&self) -> bool {
+        true
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[
+            Parameter {
+                keyword: "left",
+                kind: kind::ANY,
+                required: true,
+            },
+            Parameter {
+                keyword: "right",
+                kind: kind::ANY,
+                required: true,
+            },
+            Parameter {
+                keyword: "unify_numbers",
+                kind: kind::BOOLEAN,
+                required: false,
+            },
+            Parameter {
+                keyword: "ignore_array_order",
+                kind: kind::BOOLEAN,
+                required: false,
+            },
+        ]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "nested numbers",
+                source: r#"deep_equal({ "a": [1, 2] }, { "a": [1.0, 2.0] })"#,
+                result: Ok("true"),
+            },
+            Example {
+                title: "array order",
+                source: r#"deep_equal([1, 2], [2, 1], ignore_array_order: true)"#,
+                result: Ok("true"),
+            },
+        ]
+    }
+
+    fn compile(&self, mut arguments: ArgumentList) -> Compiled {
+        let left = arguments.required("left");
+        let right = arguments.required("right");
+        let unify_numbers = arguments.optional("unify_numbers").unwrap_or(expr!(true));
+        let ignore_array_order = arguments
+            .optional("ignore_array_order")
+            .unwrap_or(expr!(false));
+
+        Ok(Box::new(DeepEqualFn {
+            left,
+            right,
+            unify_numbers,
+            ignore_array_order,
+        }))
+    }
+}
+
+#[derive(Debug, Clone)]
+struct DeepEqualFn {
+    left: Box<dyn Expression>,
+    right: Box<dyn Expression>,
+    unify_numbers: Box<dyn Expression>,
+    ignore_array_order: Box<dyn Expression>,
+}
+
+impl Expression for DeepEqualFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let left = self.left.resolve(ctx)?;
+        let right = self.right.resolve(ctx)?;
+        let options = EqOptions {
+            unify_numbers: self.unify_numbers.resolve(ctx)?.try_boolean()?,
+            ignore_array_order: self.ignore_array_order.resolve(ctx)?.try_boolean()?,
+        };
+
+        Ok(left.loose_eq(&right, options).into())
+    }
+
+    fn type_def(&self, _: &state::Compiler) -> TypeDef {
+        TypeDef::new().infallible().boolean()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    test_function![
+        deep_equal => DeepEqual;
+
+        int_float_equivalence {
+            args: func_args![left: value!({ "a": [1, { "b": 2 }] }), right: value!({ "a": [1.0, { "b": 2.0 }] })],
+            want: Ok(true),
+            tdef: TypeDef::new().infallible().boolean(),
+        }
+
+        strict_numbers {
+            args: func_args![left: 1, right: 1.0, unify_numbers: false],
+            want: Ok(false),
+            tdef: TypeDef::new().infallible().boolean(),
+        }
+
+        different_values {
+            args: func_args![left: value!({ "a": 1 }), right: value!({ "a": 1.5 })],
+            want: Ok(false),
+            tdef: TypeDef::new().infallible().boolean(),
+        }
+
+        different_kinds {
+            args: func_args![left: 1, right: "1"],
+            want: Ok(false),
+            tdef: TypeDef::new().infallible().boolean(),
+        }
+
+        array_order {
+            args: func_args![left: value!([1, 2, [3, 4]]), right: value!([[4, 3], 2, 1])],
+            want: Ok(false),
+            tdef: TypeDef::new().infallible().boolean(),
+        }
+
+        ignore_array_order {
+            args: func_args![left: value!([1, 2, [3, 4]]), right: value!([[4, 3], 2, 1]), ignore_array_order: true],
+            want: Ok(true),
+            tdef: TypeDef::new().infallible().boolean(),
+        }
+    ];
+}
@@ -49,6 +49,14 @@ impl Function for Del {
         ]
     }
 
+    fn accesses_target(&self) -> bool {
+        true
+    }
+
+    fn writes_target(&self) -> bool {
+        true
+    }
+
     fn compile(&self, mut arguments: ArgumentList) -> Compiled {
         let query = arguments.required_query("target")?;
 
@@ -0,0 +1,144 @@
+use vrl::prelude::*;
+
+/// The leading bytes, or magic numbers, of the formats that are recognized,
+/// and their media types.
+const SIGNATURES: &[(&[u8], &str)] = &[
+    (b"\x89PNG\r\n\x1a\n", "image/png"),
+    (b"\xff\xd8\xff", "image/jpeg"),
+    (b"GIF87a", "image/gif"),
+    (b"GIF89a", "image/gif"),
+    (b"\x1f\x8b", "application/gzip"),
+    (b"%PDF-", "application/pdf"),
+    // Local file headers, and the end of central directory record of an
+    // empty archive.
+    (b"PK\x03\x04", "application/zip"),
+    (b"PK\x05\x06", "application/zip"),
+    (b"BZh", "application/x-bzip2"),
+    (b"\x28\xb5\x2f\xfd", "application/zstd"),
+];
+
+#[derive(Clone, Copy, Debug)]
+pub struct DetectContentType;
+
+impl Function for DetectContentType {
+    fn identifier(&self) -> &'static str {
+        "detect_content_type"
+    }
+
+    fn is_pure(&self) -> bool {
+        true
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[Parameter {
+            keyword: "value",
+            kind: kind::BYTES,
+            required: true,
+        }]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "pdf",
+                source: r#"detect_content_type("%PDF-1.7")"#,
+                result: Ok("application/pdf"),
+            },
+            Example {
+                title: "unknown",
+                source: r#"detect_content_type("hello")"#,
+                result: Ok("null"),
+            },
+        ]
+    }
+
+    fn compile(&self, mut arguments: ArgumentList) -> Compiled {
+        let value = arguments.required("value");
+
+        Ok(Box::new(DetectContentTypeFn { value }))
+    }
+}
+
+#[derive(Debug, Clone)]
+struct DetectContentTypeFn {
+    value: Box<dyn Expression>,
+}
+
+impl Expression for DetectContentTypeFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?.try_bytes()?;
+
+        Ok(SIGNATURES
+            .iter()
+            .find(|(magic, _)| value.starts_with(magic))
+            .map(|(_, media_type)| *media_type)
+            .into())
+    }
+
+    fn type_def(&self, _: &state::Compiler) -> TypeDef {
+        TypeDef::new().infallible().bytes().add_null()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    test_function![
+        detect_content_type => DetectContentType;
+
+        png {
+            args: func_args![value: Bytes::from_static(b"\x89PNG\r\n\x1a\n\x00\x00\x00\x0dIHDR")],
+            want: Ok("image/png"),
+            tdef: TypeDef::new().infallible().bytes().add_null(),
+        }
+
+        jpeg {
+            args: func_args![value: Bytes::from_static(b"\xff\xd8\xff\xe0\x00\x10JFIF\x00")],
+            want: Ok("image/jpeg"),
+            tdef: TypeDef::new().infallible().bytes().add_null(),
+        }
+
+        gzip {
+            args: func_args![value: Bytes::from_static(b"\x1f\x8b\x08\x00\x00\x00\x00\x00")],
+            want: Ok("application/gzip"),
+            tdef: TypeDef::new().infallible().bytes().add_null(),
+        }
+
+        pdf {
+            args: func_args![value: "%PDF-1.7\n"],
+            want: Ok("application/pdf"),
+            tdef: TypeDef::new().infallible().bytes().add_null(),
+        }
+
+        zip {
+            args: func_args![value: Bytes::from_static(b"PK\x03\x04\x14\x00\x00\x00")],
+            want: Ok("application/zip"),
+            tdef: TypeDef::new().infallible().bytes().add_null(),
+        }
+
+        empty_zip {
+            args: func_args![value: Bytes::from_static(b"PK\x05\x06\x00\x00\x00\x00")],
+            want: Ok("application/zip"),
+            tdef: TypeDef::new().infallible().bytes().add_null(),
+        }
+
+        unknown {
+            args: func_args![value: Bytes::from_static(b"\x00\x01\x02\x03plain")],
+            want: Ok(Value::Null),
+            tdef: TypeDef::new().infallible().bytes().add_null(),
+        }
+
+        truncated_signature {
+            args: func_args![value: Bytes::from_static(b"\x89PNG")],
+            want: Ok(Value::Null),
+            tdef: TypeDef::new().infallible().bytes().add_null(),
+        }
+
+        empty {
+            args: func_args![value: ""],
+            want: Ok(Value::Null),
+            tdef: TypeDef::new().infallible().bytes().add_null(),
+        }
+    ];
+}
@@ -0,0 +1,128 @@
+use crate::path_util;
+use vrl::prelude::*;
+
+#[derive(Clone, Copy, Debug)]
+pub struct Dirname;
+
+impl Function for Dirname {
+    fn identifier(&self) -> &'static str {
+        "dirname"
+    }
+
+    fn is_pure(&self) -> bool {
+        true
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[
+            Parameter {
+                keyword: "value",
+                kind: kind::BYTES,
+                required: true,
+            },
+            Parameter {
+                keyword: "windows",
+                kind: kind::BOOLEAN,
+                required: false,
+            },
+        ]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "file",
+                source: r#"dirname("/var/log/syslog.1")"#,
+                result: Ok("/var/log"),
+            },
+            Example {
+                title: "windows",
+                source: r#"dirname(s'C:\Logs\app.log', windows: true)"#,
+                result: Ok(r#"C:\Logs"#),
+            },
+        ]
+    }
+
+    fn compile(&self, mut arguments: ArgumentList) -> Compiled {
+        let value = arguments.required("value");
+        let windows = arguments.optional("windows").unwrap_or(expr!(false));
+
+        Ok(Box::new(DirnameFn { value, windows }))
+    }
+}
+
+#[derive(Debug, Clone)]
+struct DirnameFn {
+    value: Box<dyn Expression>,
+    windows: Box<dyn Expression>,
+}
+
+impl Expression for DirnameFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?.try_bytes()?;
+        let windows = self.windows.resolve(ctx)?.try_boolean()?;
+
+        Ok(path_util::dirname(&String::from_utf8_lossy(&value), windows).into())
+    }
+
+    fn type_def(&self, _: &state::Compiler) -> TypeDef {
+        TypeDef::new().infallible().bytes()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    test_function![
+        dirname => Dirname;
+
+        file {
+            args: func_args![value: "/var/log/syslog.1"],
+            want: Ok("/var/log"),
+            tdef: TypeDef::new().infallible().bytes(),
+        }
+
+        relative {
+            args: func_args![value: "log/syslog"],
+            want: Ok("log"),
+            tdef: TypeDef::new().infallible().bytes(),
+        }
+
+        without_directory {
+            args: func_args![value: ".bashrc"],
+            want: Ok("."),
+            tdef: TypeDef::new().infallible().bytes(),
+        }
+
+        trailing_slashes {
+            args: func_args![value: "/var//log/"],
+            want: Ok("/var"),
+            tdef: TypeDef::new().infallible().bytes(),
+        }
+
+        top_level {
+            args: func_args![value: "/var"],
+            want: Ok("/"),
+            tdef: TypeDef::new().infallible().bytes(),
+        }
+
+        root {
+            args: func_args![value: "/"],
+            want: Ok("/"),
+            tdef: TypeDef::new().infallible().bytes(),
+        }
+
+        windows {
+            args: func_args![value: r#"C:\Logs\app.log"#, windows: true],
+            want: Ok(r#"C:\Logs"#),
+            tdef: TypeDef::new().infallible().bytes(),
+        }
+
+        windows_drive {
+            args: func_args![value: r#"C:\Logs"#, windows: true],
+            want: Ok(r#"C:\"#),
+            tdef: TypeDef::new().infallible().bytes(),
+        }
+    ];
+}
@@ -0,0 +1,113 @@
+use crate::util;
+use vrl::prelude::*;
+
+#[derive(Clone, Copy, Debug)]
+pub struct DotProduct;
+
+impl Function for DotProduct {
+    fn identifier(&self) -> &'static str {
+        "dot_product"
+    }
+
+    fn is_pure(&self) -> bool {
+        true
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[
+            Parameter {
+                keyword: "a",
+                kind: kind::ARRAY,
+                required: true,
+            },
+            Parameter {
+                keyword: "b",
+                kind: kind::ARRAY,
+                required: true,
+            },
+        ]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "integers",
+                source: r#"dot_product!([1, 2, 3], [4, 5, 6])"#,
+                result: Ok("32"),
+            },
+            Example {
+                title: "floats",
+                source: r#"dot_product!([0.5, 1], [2, 3])"#,
+                result: Ok("4.0"),
+            },
+        ]
+    }
+
+    fn compile(&self, mut arguments: ArgumentList) -> Compiled {
+        let a = arguments.required("a");
+        let b = arguments.required("b");
+
+        Ok(Box::new(DotProductFn { a, b }))
+    }
+}
+
+#[derive(Debug, Clone)]
+struct DotProductFn {
+    a: Box<dyn Expression>,
+    b: Box<dyn Expression>,
+}
+
+impl Expression for DotProductFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let a = self.a.resolve(ctx)?;
+        let b = self.b.resolve(ctx)?;
+
+        util::number_array_pairs(a, b)?
+            .into_iter()
+            .try_fold(Value::Integer(0), |sum, (a, b)| sum.try_add(a.try_mul(b)?))
+            .map_err(Into::into)
+    }
+
+    fn type_def(&self, _: &state::Compiler) -> TypeDef {
+        TypeDef::new().fallible().integer().add_float()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    test_function![
+        dot_product => DotProduct;
+
+        integers {
+            args: func_args![a: value!([1, 2, 3]), b: value!([4, (-5), 6])],
+            want: Ok(12),
+            tdef: TypeDef::new().fallible().integer().add_float(),
+        }
+
+        floats {
+            args: func_args![a: value!([0.5, 1]), b: value!([2, 3])],
+            want: Ok(4.0),
+            tdef: TypeDef::new().fallible().integer().add_float(),
+        }
+
+        empty {
+            args: func_args![a: value!([]), b: value!([])],
+            want: Ok(0),
+            tdef: TypeDef::new().fallible().integer().add_float(),
+        }
+
+        length_mismatch {
+            args: func_args![a: value!([1, 2]), b: value!([1, 2, 3])],
+            want: Err("arrays must have the same length, got 2 and 3"),
+            tdef: TypeDef::new().fallible().integer().add_float(),
+        }
+
+        not_a_number {
+            args: func_args![a: value!([1, "2"]), b: value!([1, 2])],
+            want: Err(r#"expected array of numbers, got "string" at index 1"#),
+            tdef: TypeDef::new().fallible().integer().add_float(),
+        }
+    ];
+}
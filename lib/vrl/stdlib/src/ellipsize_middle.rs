@@ -0,0 +1,186 @@
+use unicode_segmentation::UnicodeSegmentation;
+use vrl::prelude::*;
+
+#[derive(Clone, Copy, Debug)]
+pub struct EllipsizeMiddle;
+
+impl Function for EllipsizeMiddle {
+    fn identifier(&self) -> &'static str {
+        "ellipsize_middle"
+    }
+
+    fn is_pure(&self) -> bool {
+        true
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[
+            Parameter {
+                keyword: "value",
+                kind: kind::BYTES,
+                required: true,
+            },
+            Parameter {
+                keyword: "max_width",
+                kind: kind::INTEGER,
+                required: true,
+            },
+            Parameter {
+                keyword: "ellipsis",
+                kind: kind::BYTES,
+                required: false,
+            },
+        ]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "ellipsize",
+                source: r#"ellipsize_middle("/var/log/nginx/access.log", 16)"#,
+                result: Ok("/var/lo...ss.log"),
+            },
+            Example {
+                title: "within the limit",
+                source: r#"ellipsize_middle("/var/log", 16)"#,
+                result: Ok("/var/log"),
+            },
+            Example {
+                title: "custom ellipsis",
+                source: r#"ellipsize_middle("0123456789abcdef", 9, ellipsis: "…")"#,
+                result: Ok("0123…cdef"),
+            },
+        ]
+    }
+
+    fn compile(&self, mut arguments: ArgumentList) -> Compiled {
+        let value = arguments.required("value");
+        let max_width = arguments.required("max_width");
+        let ellipsis = arguments.optional("ellipsis").unwrap_or(expr!("..."));
+
+        Ok(Box::new(EllipsizeMiddleFn {
+            value,
+            max_width,
+            ellipsis,
+        }))
+    }
+}
+
+#[derive(Debug, Clone)]
+struct EllipsizeMiddleFn {
+    value: Box<dyn Expression>,
+    max_width: Box<dyn Expression>,
+    ellipsis: Box<dyn Expression>,
+}
+
+impl Expression for EllipsizeMiddleFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?;
+        let value = value.try_bytes_utf8_lossy()?;
+
+        let max_width = self.max_width.resolve(ctx)?.try_integer()?;
+        let max_width = if max_width < 0 { 0 } else { max_width as usize };
+
+        let ellipsis = self.ellipsis.resolve(ctx)?;
+        let ellipsis = ellipsis.try_bytes_utf8_lossy()?;
+
+        Ok(ellipsize_middle(&value, max_width, &ellipsis).into())
+    }
+
+    fn type_def(&self, _: &state::Compiler) -> TypeDef {
+        TypeDef::new().infallible().bytes()
+    }
+}
+
+/// Widths are counted in grapheme clusters, so that characters made of
+/// several code points, such as accented letters or flags, are never split.
+fn ellipsize_middle(value: &str, max_width: usize, ellipsis: &str) -> String {
+    let graphemes = value.graphemes(true).collect::<Vec<_>>();
+    if graphemes.len() <= max_width {
+        return value.to_owned();
+    }
+
+    // When even the ellipsis doesn't fit, it is truncated to the width.
+    let ellipsis = ellipsis.graphemes(true).collect::<Vec<_>>();
+    if ellipsis.len() >= max_width {
+        return ellipsis[..max_width].concat();
+    }
+
+    // The start gets the extra grapheme when the rest of the width is odd.
+    let kept = max_width - ellipsis.len();
+    let start = kept - kept / 2;
+    let end = graphemes.len() - kept / 2;
+
+    [&graphemes[..start], ellipsis.as_slice(), &graphemes[end..]]
+        .concat()
+        .concat()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    test_function![
+        ellipsize_middle => EllipsizeMiddle;
+
+        long_path {
+            args: func_args![value: "/var/log/nginx/access.log", max_width: 16],
+            want: Ok("/var/lo...ss.log"),
+            tdef: TypeDef::new().infallible().bytes(),
+        }
+
+        even_width {
+            args: func_args![value: "0123456789", max_width: 7],
+            want: Ok("01...89"),
+            tdef: TypeDef::new().infallible().bytes(),
+        }
+
+        within_limit {
+            args: func_args![value: "/var/log", max_width: 16],
+            want: Ok("/var/log"),
+            tdef: TypeDef::new().infallible().bytes(),
+        }
+
+        at_limit {
+            args: func_args![value: "0123456789", max_width: 10],
+            want: Ok("0123456789"),
+            tdef: TypeDef::new().infallible().bytes(),
+        }
+
+        custom_ellipsis {
+            args: func_args![value: "0123456789abcdef", max_width: 9, ellipsis: "…"],
+            want: Ok("0123…cdef"),
+            tdef: TypeDef::new().infallible().bytes(),
+        }
+
+        empty_ellipsis {
+            args: func_args![value: "0123456789", max_width: 4, ellipsis: ""],
+            want: Ok("0189"),
+            tdef: TypeDef::new().infallible().bytes(),
+        }
+
+        graphemes {
+            args: func_args![value: "e\u{301}e\u{301}e\u{301}e\u{301}e\u{301}e\u{301}", max_width: 5],
+            want: Ok("e\u{301}...e\u{301}"),
+            tdef: TypeDef::new().infallible().bytes(),
+        }
+
+        limit_smaller_than_ellipsis {
+            args: func_args![value: "0123456789", max_width: 2],
+            want: Ok(".."),
+            tdef: TypeDef::new().infallible().bytes(),
+        }
+
+        limit_of_ellipsis {
+            args: func_args![value: "0123456789", max_width: 3],
+            want: Ok("..."),
+            tdef: TypeDef::new().infallible().bytes(),
+        }
+
+        negative_limit {
+            args: func_args![value: "0123456789", max_width: -1],
+            want: Ok(""),
+            tdef: TypeDef::new().infallible().bytes(),
+        }
+    ];
+}
@@ -0,0 +1,167 @@
+use crate::cbor_util::{self, TimestampFormat};
+use std::str::FromStr;
+use vrl::prelude::*;
+
+#[derive(Clone, Copy, Debug)]
+pub struct EncodeCbor;
+
+impl Function for EncodeCbor {
+    fn identifier(&self) -> &'static str {
+        "encode_cbor"
+    }
+
+    fn is_pure(&self) -> bool {
+        true
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[
+            Parameter {
+                keyword: "value",
+                kind: kind::ANY,
+                required: true,
+            },
+            Parameter {
+                keyword: "timestamp_format",
+                kind: kind::BYTES,
+                required: false,
+            },
+        ]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "object",
+                source: r#"encode_base64(encode_cbor({ "a": [1, true] }))"#,
+                result: Ok("oWFhggH1"),
+            },
+            Example {
+                title: "epoch timestamp",
+                source: r#"encode_base64(encode_cbor!(t'2013-03-21T20:04:00Z', timestamp_format: "epoch"))"#,
+                result: Ok("wRpRS2ew"),
+            },
+        ]
+    }
+
+    fn compile(&self, mut arguments: ArgumentList) -> Compiled {
+        let value = arguments.required("value");
+        let timestamp_format = arguments.optional("timestamp_format");
+
+        Ok(Box::new(EncodeCborFn {
+            value,
+            timestamp_format,
+        }))
+    }
+}
+
+#[derive(Debug, Clone)]
+struct EncodeCborFn {
+    value: Box<dyn Expression>,
+    timestamp_format: Option<Box<dyn Expression>>,
+}
+
+impl Expression for EncodeCborFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?;
+
+        let timestamp_format = self
+            .timestamp_format
+            .as_ref()
+            .map(|format| -> Result<_> {
+                let format = format.resolve(ctx)?.try_bytes()?;
+                Ok(TimestampFormat::from_str(&String::from_utf8_lossy(
+                    &format,
+                ))?)
+            })
+            .transpose()?
+            .unwrap_or_default();
+
+        let mut output = vec![];
+        cbor_util::encode(&value, timestamp_format, &mut output);
+
+        Ok(Bytes::from(output).into())
+    }
+
+    fn type_def(&self, _: &state::Compiler) -> TypeDef {
+        TypeDef::new()
+            .bytes()
+            .with_fallibility(self.timestamp_format.is_some())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{TimeZone, Utc};
+
+    test_function![
+        encode_cbor => EncodeCbor;
+
+        integer {
+            args: func_args![value: 1000],
+            want: Ok(Bytes::from_static(&[0x19, 0x03, 0xe8])),
+            tdef: TypeDef::new().infallible().bytes(),
+        }
+
+        negative_integer {
+            args: func_args![value: -1000],
+            want: Ok(Bytes::from_static(&[0x39, 0x03, 0xe7])),
+            tdef: TypeDef::new().infallible().bytes(),
+        }
+
+        float {
+            args: func_args![value: 1.0],
+            want: Ok(Bytes::from_static(&[0xfb, 0x3f, 0xf0, 0, 0, 0, 0, 0, 0])),
+            tdef: TypeDef::new().infallible().bytes(),
+        }
+
+        text {
+            args: func_args![value: "IETF"],
+            want: Ok(Bytes::from_static(b"\x64IETF")),
+            tdef: TypeDef::new().infallible().bytes(),
+        }
+
+        binary {
+            args: func_args![value: Bytes::from_static(&[0xff, 0x00])],
+            want: Ok(Bytes::from_static(&[0x42, 0xff, 0x00])),
+            tdef: TypeDef::new().infallible().bytes(),
+        }
+
+        nested {
+            args: func_args![value: value!({ "a": [1, null, true] })],
+            want: Ok(Bytes::from_static(&[0xa1, 0x61, b'a', 0x83, 0x01, 0xf6, 0xf5])),
+            tdef: TypeDef::new().infallible().bytes(),
+        }
+
+        timestamp {
+            args: func_args![value: Utc.ymd(2013, 3, 21).and_hms(20, 4, 0)],
+            want: Ok(Bytes::from_static(b"\xc0\x742013-03-21T20:04:00Z")),
+            tdef: TypeDef::new().infallible().bytes(),
+        }
+
+        timestamp_epoch {
+            args: func_args![
+                value: Utc.ymd(2013, 3, 21).and_hms(20, 4, 0),
+                timestamp_format: "epoch",
+            ],
+            want: Ok(Bytes::from_static(&[0xc1, 0x1a, 0x51, 0x4b, 0x67, 0xb0])),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+
+        timestamp_epoch_fractional {
+            args: func_args![
+                value: Utc.ymd(2013, 3, 21).and_hms_milli(20, 4, 0, 500),
+                timestamp_format: "epoch",
+            ],
+            want: Ok(Bytes::from_static(&[0xc1, 0xfb, 0x41, 0xd4, 0x52, 0xd9, 0xec, 0x20, 0x00, 0x00])),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+
+        unknown_timestamp_format {
+            args: func_args![value: 1, timestamp_format: "unix"],
+            want: Err(r#"unknown timestamp format "unix""#),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+    ];
+}
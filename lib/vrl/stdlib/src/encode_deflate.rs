@@ -0,0 +1,142 @@
+use flate2::{write::DeflateEncoder, Compression};
+use std::io::Write;
+use vrl::prelude::*;
+
+#[derive(Clone, Copy, Debug)]
+pub struct EncodeDeflate;
+
+impl Function for EncodeDeflate {
+    fn identifier(&self) -> &'static str {
+        "encode_deflate"
+    }
+
+    fn is_pure(&self) -> bool {
+        true
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[
+            Parameter {
+                keyword: "value",
+                kind: kind::BYTES,
+                required: true,
+            },
+            Parameter {
+                keyword: "level",
+                kind: kind::INTEGER,
+                required: false,
+            },
+        ]
+    }
+
+    fn compile(&self, mut arguments: ArgumentList) -> Compiled {
+        let value = arguments.required("value");
+        let level = arguments.optional("level");
+
+        Ok(Box::new(EncodeDeflateFn { value, level }))
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[Example {
+            title: "round trip",
+            source: r#"decode_deflate!(encode_deflate("hello hello hello"))"#,
+            result: Ok("hello hello hello"),
+        }]
+    }
+}
+
+#[derive(Clone, Debug)]
+struct EncodeDeflateFn {
+    value: Box<dyn Expression>,
+    level: Option<Box<dyn Expression>>,
+}
+
+impl Expression for EncodeDeflateFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?.try_bytes()?;
+
+        let level = match &self.level {
+            Some(expr) => match expr.resolve(ctx)?.try_integer()? {
+                level @ 0..=9 => Compression::new(level as u32),
+                _ => return Err(r#""level" must be between 0 and 9"#.into()),
+            },
+            None => Compression::default(),
+        };
+
+        let mut encoder = DeflateEncoder::new(Vec::new(), level);
+        encoder.write_all(&value).expect("write to vec");
+        let encoded = encoder.finish().expect("write to vec");
+
+        Ok(Bytes::from(encoded).into())
+    }
+
+    fn type_def(&self, _: &state::Compiler) -> TypeDef {
+        TypeDef::new()
+            .bytes()
+            .with_fallibility(self.level.is_some())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use flate2::read::DeflateDecoder;
+    use std::io::Read;
+
+    fn inflate(value: Value) -> Vec<u8> {
+        let mut output = vec![];
+        DeflateDecoder::new(&value.try_bytes().unwrap()[..])
+            .read_to_end(&mut output)
+            .unwrap();
+
+        output
+    }
+
+    fn encode(value: &str, level: Option<i64>) -> Value {
+        let mut target = value!({});
+        let mut runtime_state = vrl::state::Runtime::default();
+        let mut ctx = Context::new(&mut target, &mut runtime_state);
+
+        EncodeDeflateFn {
+            value: expr!(value),
+            level: level.map(|level| expr!(level)),
+        }
+        .resolve(&mut ctx)
+        .unwrap()
+    }
+
+    test_function![
+        encode_deflate => EncodeDeflate;
+
+        invalid_level {
+            args: func_args![value: "hello", level: 10],
+            want: Err(r#""level" must be between 0 and 9"#),
+            tdef: TypeDef::new().bytes().fallible(),
+        }
+    ];
+
+    #[test]
+    fn round_trip() {
+        let value = "hello hello hello hello";
+
+        assert_eq!(inflate(encode(value, None)), value.as_bytes());
+    }
+
+    #[test]
+    fn levels() {
+        let value = "hello ".repeat(100);
+
+        let stored = encode(&value, Some(0));
+        let fastest = encode(&value, Some(1));
+        let best = encode(&value, Some(9));
+
+        // Level 0 stores the value uncompressed, in a block with a header.
+        assert!(stored.as_bytes().unwrap().len() > value.len());
+        assert!(fastest.as_bytes().unwrap().len() < value.len());
+        assert!(best.as_bytes().unwrap().len() <= fastest.as_bytes().unwrap().len());
+
+        for encoded in vec![stored, fastest, best] {
+            assert_eq!(inflate(encoded), value.as_bytes());
+        }
+    }
+}
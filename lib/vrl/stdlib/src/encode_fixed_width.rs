@@ -0,0 +1,250 @@
+use crate::fixed_width_util;
+use vrl::prelude::*;
+
+#[derive(Clone, Copy, Debug)]
+pub struct EncodeFixedWidth;
+
+impl Function for EncodeFixedWidth {
+    fn identifier(&self) -> &'static str {
+        "encode_fixed_width"
+    }
+
+    fn is_pure(&self) -> bool {
+        true
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[
+            Parameter {
+                keyword: "value",
+                kind: kind::OBJECT,
+                required: true,
+            },
+            Parameter {
+                keyword: "spec",
+                kind: kind::ARRAY,
+                required: true,
+            },
+            Parameter {
+                keyword: "truncate",
+                kind: kind::BOOLEAN,
+                required: false,
+            },
+        ]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "record",
+                source: r#"encode_fixed_width!({ "id": 42, "name": "JOHN" }, [{ "name": "id", "width": 5, "align": "right" }, { "name": "name", "width": 6 }])"#,
+                result: Ok(r#""   42JOHN  ""#),
+            },
+            Example {
+                title: "truncate",
+                source: r#"encode_fixed_width!({ "name": "JOHNATHAN" }, [{ "name": "name", "width": 4 }], truncate: true)"#,
+                result: Ok(r#""JOHN""#),
+            },
+        ]
+    }
+
+    fn compile(&self, mut arguments: ArgumentList) -> Compiled {
+        let value = arguments.required("value");
+        let spec = arguments.required("spec");
+        let truncate = arguments.optional("truncate").unwrap_or(expr!(false));
+
+        Ok(Box::new(EncodeFixedWidthFn {
+            value,
+            spec,
+            truncate,
+        }))
+    }
+}
+
+#[derive(Debug, Clone)]
+struct EncodeFixedWidthFn {
+    value: Box<dyn Expression>,
+    spec: Box<dyn Expression>,
+    truncate: Box<dyn Expression>,
+}
+
+impl Expression for EncodeFixedWidthFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?.try_object()?;
+        let spec = fixed_width_util::fixed_width_spec(self.spec.resolve(ctx)?)?;
+        let truncate = self.truncate.resolve(ctx)?.try_boolean()?;
+
+        // The line is as long as the column ending last, with any gaps between
+        // columns filled with spaces.
+        let len = spec
+            .iter()
+            .map(|field| field.start + field.length)
+            .max()
+            .unwrap_or(0);
+        let mut line = vec![' '; len];
+
+        for field in spec {
+            let text = match value.get(&field.name) {
+                Some(Value::Bytes(v)) => String::from_utf8_lossy(v).into_owned(),
+                Some(v @ Value::Integer(_))
+                | Some(v @ Value::Float(_))
+                | Some(v @ Value::Boolean(_)) => v.to_string(),
+                Some(Value::Null) | None => String::new(),
+                Some(v) => {
+                    return Err(format!(
+                        r#"unable to encode field "{}": expected string, integer, float, boolean or null, got {}"#,
+                        field.name,
+                        v.kind()
+                    )
+                    .into())
+                }
+            };
+
+            let mut chars = text.chars().collect::<Vec<_>>();
+            if chars.len() > field.length {
+                if !truncate {
+                    return Err(format!(
+                        r#"field "{}" is longer than its width of {}"#,
+                        field.name, field.length
+                    )
+                    .into());
+                }
+
+                chars.truncate(field.length);
+            }
+
+            let offset = if field.align_right {
+                field.length - chars.len()
+            } else {
+                0
+            };
+
+            let start = field.start + offset;
+            line[start..start + chars.len()].copy_from_slice(&chars);
+        }
+
+        Ok(line.into_iter().collect::<String>().into())
+    }
+
+    fn type_def(&self, _: &state::Compiler) -> TypeDef {
+        TypeDef::new().fallible().bytes()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    test_function![
+        encode_fixed_width => EncodeFixedWidth;
+
+        right_justified_numbers {
+            args: func_args![
+                value: value!({ "id": 42, "amount": 1.5, "flag": true }),
+                spec: value!([
+                    { "name": "id", "width": 5, "align": "right" },
+                    { "name": "amount", "width": 6, "align": "right" },
+                    { "name": "flag", "width": 5, "align": "right" },
+                ]),
+            ],
+            want: Ok("   42   1.5 true"),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+
+        left_justified_strings {
+            args: func_args![
+                value: value!({ "first": "JOHN", "last": "SMITH" }),
+                spec: value!([
+                    { "name": "first", "width": 6 },
+                    { "name": "last", "width": 6, "align": "left" },
+                ]),
+            ],
+            want: Ok("JOHN  SMITH "),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+
+        gaps_and_missing_fields {
+            args: func_args![
+                value: value!({ "b": "x", "c": null }),
+                spec: value!([
+                    { "name": "a", "start": 0, "length": 2 },
+                    { "name": "b", "start": 4, "length": 1 },
+                    { "name": "c", "start": 5, "length": 2 },
+                ]),
+            ],
+            want: Ok("    x  "),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+
+        overflow {
+            args: func_args![
+                value: value!({ "name": "JOHNATHAN" }),
+                spec: value!([{ "name": "name", "width": 4 }]),
+            ],
+            want: Err(r#"field "name" is longer than its width of 4"#),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+
+        overflow_truncated {
+            args: func_args![
+                value: value!({ "name": "JOHNATHAN", "id": 123456 }),
+                spec: value!([
+                    { "name": "name", "width": 4 },
+                    { "name": "id", "width": 3, "align": "right" },
+                ]),
+                truncate: true,
+            ],
+            want: Ok("JOHN123"),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+
+        unsupported_value {
+            args: func_args![
+                value: value!({ "tags": ["a"] }),
+                spec: value!([{ "name": "tags", "width": 4 }]),
+            ],
+            want: Err(r#"unable to encode field "tags": expected string, integer, float, boolean or null, got "array""#),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+
+        invalid_align {
+            args: func_args![
+                value: value!({}),
+                spec: value!([{ "name": "id", "width": 4, "align": "center" }]),
+            ],
+            want: Err(r#"invalid field 0 in spec: "align" must be "left" or "right""#),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+    ];
+
+    #[cfg(feature = "parse_fixed_width")]
+    #[test]
+    fn round_trip() {
+        let record = value!({ "id": "42", "first": "JOHN", "last": "SMITH" });
+        let spec = value!([
+            { "name": "id", "width": 5, "align": "right" },
+            { "name": "first", "width": 8 },
+            { "name": "last", "width": 8 },
+        ]);
+
+        let mut runtime_state = vrl::state::Runtime::default();
+        let mut target: Value = map![].into();
+        let mut ctx = Context::new(&mut target, &mut runtime_state);
+
+        let encoded = EncodeFixedWidth
+            .compile(func_args![value: record.clone(), spec: spec.clone()].into())
+            .unwrap()
+            .resolve(&mut ctx)
+            .unwrap();
+
+        assert_eq!(encoded, value!("   42JOHN    SMITH   "));
+
+        let decoded = crate::ParseFixedWidth
+            .compile(func_args![value: encoded, spec: spec].into())
+            .unwrap()
+            .resolve(&mut ctx)
+            .unwrap();
+
+        assert_eq!(decoded, record);
+    }
+}
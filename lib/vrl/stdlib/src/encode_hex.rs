@@ -0,0 +1,141 @@
+use vrl::prelude::*;
+
+#[derive(Clone, Copy, Debug)]
+pub struct EncodeHex;
+
+impl Function for EncodeHex {
+    fn identifier(&self) -> &'static str {
+        "encode_hex"
+    }
+
+    fn is_pure(&self) -> bool {
+        true
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[
+            Parameter {
+                keyword: "value",
+                kind: kind::BYTES,
+                required: true,
+            },
+            Parameter {
+                keyword: "uppercase",
+                kind: kind::BOOLEAN,
+                required: false,
+            },
+            Parameter {
+                keyword: "delimiter",
+                kind: kind::BYTES,
+                required: false,
+            },
+        ]
+    }
+
+    fn compile(&self, mut arguments: ArgumentList) -> Compiled {
+        let value = arguments.required("value");
+        let uppercase = arguments.optional("uppercase");
+        let delimiter = arguments.optional("delimiter");
+
+        Ok(Box::new(EncodeHexFn {
+            value,
+            uppercase,
+            delimiter,
+        }))
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "lowercase",
+                source: r#"encode_hex("hello")"#,
+                result: Ok("68656c6c6f"),
+            },
+            Example {
+                title: "uppercase with delimiter",
+                source: r#"encode_hex("hello", uppercase: true, delimiter: ":")"#,
+                result: Ok("68:65:6C:6C:6F"),
+            },
+        ]
+    }
+}
+
+#[derive(Clone, Debug)]
+struct EncodeHexFn {
+    value: Box<dyn Expression>,
+    uppercase: Option<Box<dyn Expression>>,
+    delimiter: Option<Box<dyn Expression>>,
+}
+
+impl Expression for EncodeHexFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?.try_bytes()?;
+
+        let uppercase = match &self.uppercase {
+            Some(expr) => expr.resolve(ctx)?.try_boolean()?,
+            None => false,
+        };
+
+        let delimiter = match &self.delimiter {
+            Some(expr) => {
+                let bytes = expr.resolve(ctx)?.try_bytes()?;
+                String::from_utf8_lossy(&bytes).into_owned()
+            }
+            None => String::new(),
+        };
+
+        let encoded = value
+            .iter()
+            .map(|byte| match uppercase {
+                true => format!("{:02X}", byte),
+                false => format!("{:02x}", byte),
+            })
+            .collect::<Vec<_>>()
+            .join(&delimiter);
+
+        Ok(encoded.into())
+    }
+
+    fn type_def(&self, _: &state::Compiler) -> TypeDef {
+        TypeDef::new().infallible().bytes()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    test_function![
+        encode_hex => EncodeHex;
+
+        lowercase {
+            args: func_args![value: Bytes::from_static(b"\xde\xad\xbe\xef")],
+            want: Ok("deadbeef"),
+            tdef: TypeDef::new().infallible().bytes(),
+        }
+
+        uppercase {
+            args: func_args![value: Bytes::from_static(b"\xde\xad\xbe\xef"), uppercase: true],
+            want: Ok("DEADBEEF"),
+            tdef: TypeDef::new().infallible().bytes(),
+        }
+
+        delimiter {
+            args: func_args![value: Bytes::from_static(b"\xaa\xbb\xcc"), delimiter: ":"],
+            want: Ok("aa:bb:cc"),
+            tdef: TypeDef::new().infallible().bytes(),
+        }
+
+        uppercase_delimiter {
+            args: func_args![value: Bytes::from_static(b"\x00\x1b\x63"), uppercase: true, delimiter: " "],
+            want: Ok("00 1B 63"),
+            tdef: TypeDef::new().infallible().bytes(),
+        }
+
+        empty {
+            args: func_args![value: "", delimiter: ":"],
+            want: Ok(""),
+            tdef: TypeDef::new().infallible().bytes(),
+        }
+    ];
+}
@@ -0,0 +1,234 @@
+use std::collections::BTreeMap;
+use vrl::prelude::*;
+
+#[derive(Clone, Copy, Debug)]
+pub struct EncodeInflux;
+
+impl Function for EncodeInflux {
+    fn identifier(&self) -> &'static str {
+        "encode_influx"
+    }
+
+    fn is_pure(&self) -> bool {
+        true
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[Parameter {
+            keyword: "value",
+            kind: kind::OBJECT,
+            required: true,
+        }]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "line",
+                source: r#"encode_influx!({ "measurement": "cpu", "tags": { "host": "a" }, "fields": { "usage": 0.5, "cores": 4 }, "timestamp": t'2021-03-06T03:06:40Z' })"#,
+                result: Ok("s'cpu,host=a cores=4i,usage=0.5 1615000000000000000'"),
+            },
+            Example {
+                title: "without timestamp",
+                source: r#"encode_influx!({ "measurement": "weather", "fields": { "city": "Paris, France" } })"#,
+                result: Ok(r#"s'weather city="Paris, France"'"#),
+            },
+        ]
+    }
+
+    fn compile(&self, mut arguments: ArgumentList) -> Compiled {
+        let value = arguments.required("value");
+
+        Ok(Box::new(EncodeInfluxFn { value }))
+    }
+}
+
+#[derive(Debug, Clone)]
+struct EncodeInfluxFn {
+    value: Box<dyn Expression>,
+}
+
+impl Expression for EncodeInfluxFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let line = self.value.resolve(ctx)?.try_object()?;
+
+        Ok(encode_line(&line)?.into())
+    }
+
+    fn type_def(&self, _: &state::Compiler) -> TypeDef {
+        TypeDef::new().fallible().bytes()
+    }
+}
+
+fn encode_line(line: &BTreeMap<String, Value>) -> std::result::Result<String, String> {
+    let measurement = match line.get("measurement") {
+        Some(Value::Bytes(measurement)) if !measurement.is_empty() => {
+            String::from_utf8_lossy(measurement)
+        }
+        _ => return Err("measurement must be a non-empty string".to_owned()),
+    };
+
+    let mut output = escape(&measurement, b", ");
+
+    match line.get("tags") {
+        Some(Value::Object(tags)) => {
+            for (key, value) in tags {
+                let value = match value {
+                    Value::Bytes(value) if !value.is_empty() => String::from_utf8_lossy(value),
+                    _ => return Err(format!("tag {:?} must be a non-empty string", key)),
+                };
+
+                output.push(',');
+                output.push_str(&escape(key, b",= "));
+                output.push('=');
+                output.push_str(&escape(&value, b",= "));
+            }
+        }
+        None | Some(Value::Null) => {}
+        Some(_) => return Err("tags must be an object".to_owned()),
+    }
+
+    let fields = match line.get("fields") {
+        Some(Value::Object(fields)) if !fields.is_empty() => fields,
+        _ => return Err("fields must be a non-empty object".to_owned()),
+    };
+
+    for (i, (key, value)) in fields.iter().enumerate() {
+        output.push(if i == 0 { ' ' } else { ',' });
+        output.push_str(&escape(key, b",= "));
+        output.push('=');
+
+        match value {
+            Value::Integer(integer) => output.push_str(&format!("{}i", integer)),
+            Value::Float(float) => output.push_str(&float.to_string()),
+            Value::Boolean(boolean) => output.push_str(&boolean.to_string()),
+            Value::Bytes(string) => {
+                output.push('"');
+                output.push_str(&escape(&String::from_utf8_lossy(string), b"\"\\"));
+                output.push('"');
+            }
+            value => {
+                return Err(format!(
+                    "field {:?} must be a string, integer, float or boolean, got {}",
+                    key,
+                    value.kind().as_str()
+                ))
+            }
+        }
+    }
+
+    match line.get("timestamp") {
+        Some(Value::Timestamp(timestamp)) => {
+            let nanos = timestamp
+                .timestamp()
+                .checked_mul(1_000_000_000)
+                .and_then(|nanos| nanos.checked_add(i64::from(timestamp.timestamp_subsec_nanos())))
+                .ok_or("timestamp is out of range")?;
+
+            output.push_str(&format!(" {}", nanos));
+        }
+        Some(Value::Integer(nanos)) => output.push_str(&format!(" {}", nanos)),
+        None | Some(Value::Null) => {}
+        Some(_) => return Err("timestamp must be a timestamp or an integer".to_owned()),
+    }
+
+    Ok(output)
+}
+
+/// Escapes the given characters with a backslash.
+fn escape(value: &str, escapes: &[u8]) -> String {
+    let mut escaped = String::with_capacity(value.len());
+
+    for c in value.chars() {
+        if c.is_ascii() && escapes.contains(&(c as u8)) {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{TimeZone, Utc};
+
+    test_function![
+        encode_influx => EncodeInflux;
+
+        line {
+            args: func_args![value: value!({
+                "measurement": "cpu",
+                "tags": { "region": "eu", "host": "a" },
+                "fields": { "usage": 0.5, "cores": 4, "up": true },
+                "timestamp": (Utc.timestamp(1_615_000_000, 0)),
+            })],
+            want: Ok("cpu,host=a,region=eu cores=4i,up=true,usage=0.5 1615000000000000000"),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+
+        without_timestamp {
+            args: func_args![value: value!({
+                "measurement": "weather",
+                "fields": { "temperature": 21.5 },
+            })],
+            want: Ok("weather temperature=21.5"),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+
+        integers_and_floats {
+            args: func_args![value: value!({
+                "measurement": "m",
+                "fields": { "a": 1.0, "b": 1, "c": (-2), "d": (-1500.25) },
+                "timestamp": null,
+            })],
+            want: Ok("m a=1,b=1i,c=-2i,d=-1500.25"),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+
+        integer_timestamp {
+            args: func_args![value: value!({
+                "measurement": "m",
+                "fields": { "a": 1 },
+                "timestamp": 1,
+            })],
+            want: Ok("m a=1i 1"),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+
+        escaping {
+            args: func_args![value: value!({
+                "measurement": "my measure,ment",
+                "tags": { "tag key,=": "tag value,=" },
+                "fields": { "field key=": r#"string "quoted" \ value"# },
+            })],
+            want: Ok(r#"my\ measure\,ment,tag\ key\,\==tag\ value\,\= field\ key\=="string \"quoted\" \\ value""#),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+
+        missing_measurement {
+            args: func_args![value: value!({ "fields": { "a": 1 } })],
+            want: Err("measurement must be a non-empty string"),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+
+        missing_fields {
+            args: func_args![value: value!({ "measurement": "m", "fields": {} })],
+            want: Err("fields must be a non-empty object"),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+
+        invalid_tag {
+            args: func_args![value: value!({ "measurement": "m", "tags": { "a": 1 }, "fields": { "a": 1 } })],
+            want: Err(r#"tag "a" must be a non-empty string"#),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+
+        invalid_field {
+            args: func_args![value: value!({ "measurement": "m", "fields": { "a": [1] } })],
+            want: Err(r#"field "a" must be a string, integer, float or boolean, got array"#),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+    ];
+}
@@ -119,5 +119,31 @@ mod tests {
             want: Ok("null"),
             tdef: TypeDef::new().infallible().bytes(),
         }
+
+        nested {
+            args: func_args![value: value!({ "a": [1, 2], "b": "x" })],
+            want: Ok(r#"{"a":[1,2],"b":"x"}"#),
+            tdef: TypeDef::new().infallible().bytes(),
+        }
     ];
+
+    #[test]
+    fn round_trip() {
+        let value = value!({ "a": [1, 2], "b": "x" });
+
+        let mut runtime_state = vrl::state::Runtime::default();
+        let mut target: Value = map![].into();
+        let mut ctx = Context::new(&mut target, &mut runtime_state);
+
+        let encoded = EncodeJson
+            .compile(func_args![value: value.clone()].into())
+            .unwrap()
+            .resolve(&mut ctx)
+            .unwrap();
+
+        let decoded: Value =
+            serde_json::from_str(&encoded.try_bytes_utf8_lossy().unwrap()).unwrap();
+
+        assert_eq!(decoded, value);
+    }
 }
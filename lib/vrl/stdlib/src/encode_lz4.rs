@@ -0,0 +1,148 @@
+use crate::lz4_util;
+use vrl::prelude::*;
+
+#[derive(Clone, Copy, Debug)]
+pub struct EncodeLz4;
+
+impl Function for EncodeLz4 {
+    fn identifier(&self) -> &'static str {
+        "encode_lz4"
+    }
+
+    fn is_pure(&self) -> bool {
+        true
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[
+            Parameter {
+                keyword: "value",
+                kind: kind::BYTES,
+                required: true,
+            },
+            Parameter {
+                keyword: "content_checksum",
+                kind: kind::BOOLEAN,
+                required: false,
+            },
+        ]
+    }
+
+    fn compile(&self, mut arguments: ArgumentList) -> Compiled {
+        let value = arguments.required("value");
+        let content_checksum = arguments
+            .optional("content_checksum")
+            .unwrap_or(expr!(false));
+
+        Ok(Box::new(EncodeLz4Fn {
+            value,
+            content_checksum,
+        }))
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[Example {
+            title: "round trip",
+            source: r#"decode_lz4!(encode_lz4("hello hello hello", content_checksum: true))"#,
+            result: Ok("hello hello hello"),
+        }]
+    }
+}
+
+#[derive(Clone, Debug)]
+struct EncodeLz4Fn {
+    value: Box<dyn Expression>,
+    content_checksum: Box<dyn Expression>,
+}
+
+impl Expression for EncodeLz4Fn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?.try_bytes()?;
+        let content_checksum = self.content_checksum.resolve(ctx)?.try_boolean()?;
+
+        Ok(Bytes::from(lz4_util::encode(&value, content_checksum)).into())
+    }
+
+    fn type_def(&self, _: &state::Compiler) -> TypeDef {
+        TypeDef::new().bytes().infallible()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// The flag of frame descriptors for frames with a checksum of their
+    /// content.
+    const CONTENT_CHECKSUM_FLAG: u8 = 0x04;
+
+    fn encode(value: &str, content_checksum: bool) -> Bytes {
+        let mut target = value!({});
+        let mut runtime_state = vrl::state::Runtime::default();
+        let mut ctx = Context::new(&mut target, &mut runtime_state);
+
+        EncodeLz4Fn {
+            value: expr!(value),
+            content_checksum: expr!(content_checksum),
+        }
+        .resolve(&mut ctx)
+        .unwrap()
+        .try_bytes()
+        .unwrap()
+    }
+
+    fn decode(encoded: &[u8]) -> Vec<u8> {
+        lz4_util::decode(encoded).unwrap()
+    }
+
+    #[test]
+    fn round_trip() {
+        let value = "hello ".repeat(100);
+        let encoded = encode(&value, false);
+
+        assert_eq!(&encoded[..4], b"\x04\x22\x4d\x18");
+        assert_eq!(encoded[4] & CONTENT_CHECKSUM_FLAG, 0);
+        assert!(encoded.len() < value.len());
+        assert_eq!(decode(&encoded), value.as_bytes());
+    }
+
+    #[test]
+    fn content_checksum() {
+        let value = "hello ".repeat(100);
+        let without = encode(&value, false);
+        let with = encode(&value, true);
+
+        assert_eq!(with[4] & CONTENT_CHECKSUM_FLAG, CONTENT_CHECKSUM_FLAG);
+        assert_eq!(with.len(), without.len() + 4);
+        assert_eq!(decode(&with), value.as_bytes());
+    }
+
+    #[test]
+    fn uncompressible() {
+        // Blocks too short to compress are stored as they are.
+        assert_eq!(
+            encode("hello", false),
+            Bytes::from_static(
+                b"\x04\x22\x4d\x18\x60\x40\x82\x05\x00\x00\x80hello\x00\x00\x00\x00"
+            )
+        );
+        assert_eq!(
+            encode("hello", true),
+            Bytes::from_static(b"\x04\x22\x4d\x18\x64\x40\xa7\x05\x00\x00\x80hello\x00\x00\x00\x00\xf9\x77\x00\xfb")
+        );
+    }
+
+    #[test]
+    fn multiple_blocks() {
+        let value = "hello ".repeat(50_000);
+        let encoded = encode(&value, true);
+
+        assert!(encoded.len() < value.len());
+        assert_eq!(decode(&encoded), value.as_bytes());
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(decode(&encode("", true)), b"");
+    }
+}
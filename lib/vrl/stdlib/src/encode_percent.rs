@@ -0,0 +1,230 @@
+use percent_encoding::{percent_encode, AsciiSet, CONTROLS, NON_ALPHANUMERIC};
+use std::str::FromStr;
+use vrl::prelude::*;
+
+/// The characters encoded in URL fragments.
+const FRAGMENT: &AsciiSet = &CONTROLS.add(b' ').add(b'"').add(b'<').add(b'>').add(b'`');
+
+/// The characters encoded in URL queries.
+const QUERY: &AsciiSet = &CONTROLS.add(b' ').add(b'"').add(b'#').add(b'<').add(b'>');
+
+/// The characters encoded in URL queries of special schemes, such as `http`.
+const SPECIAL: &AsciiSet = &QUERY.add(b'\'');
+
+/// The characters encoded in URL paths.
+const PATH: &AsciiSet = &QUERY.add(b'?').add(b'`').add(b'{').add(b'}');
+
+/// The characters encoded in the userinfo part of URLs.
+const USERINFO: &AsciiSet = &PATH
+    .add(b'/')
+    .add(b':')
+    .add(b';')
+    .add(b'=')
+    .add(b'@')
+    .add(b'[')
+    .add(b'\\')
+    .add(b']')
+    .add(b'^')
+    .add(b'|');
+
+/// The characters encoded in URL components, matching JavaScript's
+/// `encodeURIComponent`.
+const COMPONENT: &AsciiSet = &USERINFO.add(b'$').add(b'%').add(b'&').add(b'+').add(b',');
+
+#[derive(Clone, Copy, Debug)]
+pub struct EncodePercent;
+
+impl Function for EncodePercent {
+    fn identifier(&self) -> &'static str {
+        "encode_percent"
+    }
+
+    fn is_pure(&self) -> bool {
+        true
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[
+            Parameter {
+                keyword: "value",
+                kind: kind::BYTES,
+                required: true,
+            },
+            Parameter {
+                keyword: "ascii_set",
+                kind: kind::BYTES,
+                required: false,
+            },
+        ]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "default",
+                source: r#"encode_percent("foo bar?")"#,
+                result: Ok("foo%20bar%3F"),
+            },
+            Example {
+                title: "query",
+                source: r#"encode_percent!("a b?c=d#e", ascii_set: "query")"#,
+                result: Ok("a%20b?c=d%23e"),
+            },
+        ]
+    }
+
+    fn compile(&self, mut arguments: ArgumentList) -> Compiled {
+        let value = arguments.required("value");
+        let ascii_set = arguments.optional("ascii_set");
+
+        Ok(Box::new(EncodePercentFn { value, ascii_set }))
+    }
+}
+
+#[derive(Debug, Clone)]
+struct EncodePercentFn {
+    value: Box<dyn Expression>,
+    ascii_set: Option<Box<dyn Expression>>,
+}
+
+impl Expression for EncodePercentFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?.try_bytes()?;
+
+        let set = self
+            .ascii_set
+            .as_ref()
+            .map(|set| -> Result<_> {
+                let set = set.resolve(ctx)?.try_bytes()?;
+                Ok(EncodeSet::from_str(&String::from_utf8_lossy(&set))?)
+            })
+            .transpose()?
+            .unwrap_or_default();
+
+        Ok(percent_encode(&value, set.ascii_set()).to_string().into())
+    }
+
+    fn type_def(&self, _: &state::Compiler) -> TypeDef {
+        TypeDef::new()
+            .bytes()
+            .with_fallibility(self.ascii_set.is_some())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum EncodeSet {
+    NonAlphanumeric,
+    Controls,
+    Fragment,
+    Query,
+    Special,
+    Path,
+    Userinfo,
+    Component,
+}
+
+impl Default for EncodeSet {
+    fn default() -> Self {
+        Self::NonAlphanumeric
+    }
+}
+
+impl EncodeSet {
+    fn ascii_set(self) -> &'static AsciiSet {
+        use EncodeSet::*;
+
+        match self {
+            NonAlphanumeric => NON_ALPHANUMERIC,
+            Controls => CONTROLS,
+            Fragment => FRAGMENT,
+            Query => QUERY,
+            Special => SPECIAL,
+            Path => PATH,
+            Userinfo => USERINFO,
+            Component => COMPONENT,
+        }
+    }
+}
+
+impl FromStr for EncodeSet {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        use EncodeSet::*;
+
+        match s {
+            "non_alphanumeric" => Ok(NonAlphanumeric),
+            "controls" => Ok(Controls),
+            "fragment" => Ok(Fragment),
+            "query" => Ok(Query),
+            "special" => Ok(Special),
+            "path" => Ok(Path),
+            "userinfo" => Ok(Userinfo),
+            "component" => Ok(Component),
+            _ => Err(format!(r#"unknown ascii set "{}""#, s)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    test_function![
+        encode_percent => EncodePercent;
+
+        default {
+            args: func_args![value: "a b/c?d=e&f-g.h"],
+            want: Ok("a%20b%2Fc%3Fd%3De%26f%2Dg%2Eh"),
+            tdef: TypeDef::new().bytes().infallible(),
+        }
+
+        non_ascii {
+            args: func_args![value: "caf\u{e9}"],
+            want: Ok("caf%C3%A9"),
+            tdef: TypeDef::new().bytes().infallible(),
+        }
+
+        controls {
+            args: func_args![value: "a b/c\n", ascii_set: "controls"],
+            want: Ok("a b/c%0A"),
+            tdef: TypeDef::new().bytes().fallible(),
+        }
+
+        query {
+            args: func_args![value: "a b/c?d=e&f-g.h#i", ascii_set: "query"],
+            want: Ok("a%20b/c?d=e&f-g.h%23i"),
+            tdef: TypeDef::new().bytes().fallible(),
+        }
+
+        special {
+            args: func_args![value: "it's here", ascii_set: "special"],
+            want: Ok("it%27s%20here"),
+            tdef: TypeDef::new().bytes().fallible(),
+        }
+
+        path {
+            args: func_args![value: "a b/c?d=e&f-g.h", ascii_set: "path"],
+            want: Ok("a%20b/c%3Fd=e&f-g.h"),
+            tdef: TypeDef::new().bytes().fallible(),
+        }
+
+        userinfo {
+            args: func_args![value: "user:p@ss&w", ascii_set: "userinfo"],
+            want: Ok("user%3Ap%40ss&w"),
+            tdef: TypeDef::new().bytes().fallible(),
+        }
+
+        component {
+            args: func_args![value: "a b/c?d=e&f-g.h", ascii_set: "component"],
+            want: Ok("a%20b%2Fc%3Fd%3De%26f-g.h"),
+            tdef: TypeDef::new().bytes().fallible(),
+        }
+
+        unknown_set {
+            args: func_args![value: "foo", ascii_set: "bogus"],
+            want: Err(r#"unknown ascii set "bogus""#),
+            tdef: TypeDef::new().bytes().fallible(),
+        }
+    ];
+}
@@ -0,0 +1,147 @@
+use snap::{raw::Encoder, write::FrameEncoder};
+use std::io::Write;
+use vrl::prelude::*;
+
+#[derive(Clone, Copy, Debug)]
+pub struct EncodeSnappy;
+
+impl Function for EncodeSnappy {
+    fn identifier(&self) -> &'static str {
+        "encode_snappy"
+    }
+
+    fn is_pure(&self) -> bool {
+        true
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[
+            Parameter {
+                keyword: "value",
+                kind: kind::BYTES,
+                required: true,
+            },
+            Parameter {
+                keyword: "framed",
+                kind: kind::BOOLEAN,
+                required: false,
+            },
+        ]
+    }
+
+    fn compile(&self, mut arguments: ArgumentList) -> Compiled {
+        let value = arguments.required("value");
+        let framed = arguments.optional("framed").unwrap_or(expr!(false));
+
+        Ok(Box::new(EncodeSnappyFn { value, framed }))
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "raw",
+                source: r#"encode_base64(encode_snappy!("hello"))"#,
+                result: Ok("BRBoZWxsbw=="),
+            },
+            Example {
+                title: "framed",
+                source: r#"decode_snappy!(encode_snappy!("hello", framed: true), framed: true)"#,
+                result: Ok("hello"),
+            },
+        ]
+    }
+}
+
+#[derive(Clone, Debug)]
+struct EncodeSnappyFn {
+    value: Box<dyn Expression>,
+    framed: Box<dyn Expression>,
+}
+
+impl Expression for EncodeSnappyFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?.try_bytes()?;
+        let framed = self.framed.resolve(ctx)?.try_boolean()?;
+
+        let encoded = if framed {
+            let mut encoder = FrameEncoder::new(Vec::new());
+            encoder.write_all(&value).expect("write to vec");
+            encoder.into_inner().expect("write to vec")
+        } else {
+            // Only values too large for the format fail.
+            Encoder::new()
+                .compress_vec(&value)
+                .map_err(|_| "unable to encode value to snappy")?
+        };
+
+        Ok(Bytes::from(encoded).into())
+    }
+
+    fn type_def(&self, _: &state::Compiler) -> TypeDef {
+        TypeDef::new().bytes().fallible()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use snap::{raw::Decoder, read::FrameDecoder};
+    use std::io::Read;
+
+    fn encode(value: &str, framed: bool) -> Bytes {
+        let mut target = value!({});
+        let mut runtime_state = vrl::state::Runtime::default();
+        let mut ctx = Context::new(&mut target, &mut runtime_state);
+
+        EncodeSnappyFn {
+            value: expr!(value),
+            framed: expr!(framed),
+        }
+        .resolve(&mut ctx)
+        .unwrap()
+        .try_bytes()
+        .unwrap()
+    }
+
+    test_function![
+        encode_snappy => EncodeSnappy;
+
+        raw {
+            args: func_args![value: "hello"],
+            want: Ok(Bytes::from_static(b"\x05\x10hello")),
+            tdef: TypeDef::new().bytes().fallible(),
+        }
+
+        framed {
+            args: func_args![value: "hello", framed: true],
+            want: Ok(Bytes::from_static(b"\xff\x06\x00\x00sNaPpY\x01\x09\x00\x00\xbb\x1f\x1c\x19hello")),
+            tdef: TypeDef::new().bytes().fallible(),
+        }
+    ];
+
+    #[test]
+    fn round_trip_raw() {
+        let value = "hello ".repeat(100);
+        let encoded = encode(&value, false);
+
+        assert!(encoded.len() < value.len());
+        assert_eq!(
+            Decoder::new().decompress_vec(&encoded).unwrap(),
+            value.as_bytes()
+        );
+    }
+
+    #[test]
+    fn round_trip_framed() {
+        let value = "hello ".repeat(100);
+        let encoded = encode(&value, true);
+
+        let mut decoded = vec![];
+        FrameDecoder::new(&encoded[..])
+            .read_to_end(&mut decoded)
+            .unwrap();
+
+        assert!(encoded.len() < value.len());
+        assert_eq!(decoded, value.as_bytes());
+    }
+}
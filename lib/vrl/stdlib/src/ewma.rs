@@ -0,0 +1,137 @@
+use crate::util;
+use std::convert::TryFrom;
+use vrl::prelude::*;
+
+#[derive(Clone, Copy, Debug)]
+pub struct Ewma;
+
+impl Function for Ewma {
+    fn identifier(&self) -> &'static str {
+        "ewma"
+    }
+
+    fn is_pure(&self) -> bool {
+        true
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[
+            Parameter {
+                keyword: "value",
+                kind: kind::ARRAY,
+                required: true,
+            },
+            Parameter {
+                keyword: "alpha",
+                kind: kind::INTEGER | kind::FLOAT,
+                required: true,
+            },
+        ]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[Example {
+            title: "smoothing",
+            source: r#"ewma!([10, 20, 20], 0.5)"#,
+            result: Ok("[10.0, 15.0, 17.5]"),
+        }]
+    }
+
+    fn compile(&self, mut arguments: ArgumentList) -> Compiled {
+        let value = arguments.required("value");
+        let alpha = arguments.required("alpha");
+
+        Ok(Box::new(EwmaFn { value, alpha }))
+    }
+}
+
+#[derive(Debug, Clone)]
+struct EwmaFn {
+    value: Box<dyn Expression>,
+    alpha: Box<dyn Expression>,
+}
+
+impl Expression for EwmaFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let numbers = util::float_array(self.value.resolve(ctx)?)?;
+        let alpha = f64::try_from(&self.alpha.resolve(ctx)?)?;
+
+        if alpha <= 0.0 || alpha > 1.0 {
+            return Err(
+                format!("alpha must be greater than 0 and at most 1, got {}", alpha).into(),
+            );
+        }
+
+        // The series is seeded with its first element.
+        let mut average = None;
+        let averages = numbers
+            .into_iter()
+            .map(|number| {
+                let next = match average {
+                    Some(average) => alpha * number + (1.0 - alpha) * average,
+                    None => number,
+                };
+
+                average = Some(next);
+                next.into()
+            })
+            .collect::<Vec<Value>>();
+
+        Ok(averages.into())
+    }
+
+    fn type_def(&self, _: &state::Compiler) -> TypeDef {
+        TypeDef::new().fallible().array_mapped::<(), Kind>(map! {
+            (): Kind::Float,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    test_function![
+        ewma => Ewma;
+
+        series {
+            args: func_args![value: value!([10, 20, 20, 0]), alpha: 0.5],
+            want: Ok(value!([10.0, 15.0, 17.5, 8.75])),
+            tdef: TypeDef::new().fallible().array_mapped::<(), Kind>(map! {
+                (): Kind::Float,
+            }),
+        }
+
+        alpha_one {
+            args: func_args![value: value!([1, 5, 2]), alpha: 1],
+            want: Ok(value!([1.0, 5.0, 2.0])),
+            tdef: TypeDef::new().fallible().array_mapped::<(), Kind>(map! {
+                (): Kind::Float,
+            }),
+        }
+
+        empty {
+            args: func_args![value: value!([]), alpha: 0.3],
+            want: Ok(value!([])),
+            tdef: TypeDef::new().fallible().array_mapped::<(), Kind>(map! {
+                (): Kind::Float,
+            }),
+        }
+
+        alpha_zero {
+            args: func_args![value: value!([1, 2]), alpha: 0],
+            want: Err("alpha must be greater than 0 and at most 1, got 0"),
+            tdef: TypeDef::new().fallible().array_mapped::<(), Kind>(map! {
+                (): Kind::Float,
+            }),
+        }
+
+        alpha_too_large {
+            args: func_args![value: value!([1, 2]), alpha: 1.5],
+            want: Err("alpha must be greater than 0 and at most 1, got 1.5"),
+            tdef: TypeDef::new().fallible().array_mapped::<(), Kind>(map! {
+                (): Kind::Float,
+            }),
+        }
+    ];
+}
@@ -31,6 +31,10 @@ impl Function for Exists {
         ]
     }
 
+    fn accesses_target(&self) -> bool {
+        true
+    }
+
     fn compile(&self, mut arguments: ArgumentList) -> Compiled {
         let query = arguments.required_query("field")?;
 
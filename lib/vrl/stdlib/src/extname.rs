@@ -0,0 +1,158 @@
+use crate::path_util;
+use vrl::prelude::*;
+
+#[derive(Clone, Copy, Debug)]
+pub struct Extname;
+
+impl Function for Extname {
+    fn identifier(&self) -> &'static str {
+        "extname"
+    }
+
+    fn is_pure(&self) -> bool {
+        true
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[
+            Parameter {
+                keyword: "value",
+                kind: kind::BYTES,
+                required: true,
+            },
+            Parameter {
+                keyword: "include_dot",
+                kind: kind::BOOLEAN,
+                required: false,
+            },
+            Parameter {
+                keyword: "windows",
+                kind: kind::BOOLEAN,
+                required: false,
+            },
+        ]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "extension",
+                source: r#"extname("/var/log/app.log")"#,
+                result: Ok(".log"),
+            },
+            Example {
+                title: "without the dot",
+                source: r#"extname("archive.tar.gz", include_dot: false)"#,
+                result: Ok("gz"),
+            },
+        ]
+    }
+
+    fn compile(&self, mut arguments: ArgumentList) -> Compiled {
+        let value = arguments.required("value");
+        let include_dot = arguments.optional("include_dot").unwrap_or(expr!(true));
+        let windows = arguments.optional("windows").unwrap_or(expr!(false));
+
+        Ok(Box::new(ExtnameFn {
+            value,
+            include_dot,
+            windows,
+        }))
+    }
+}
+
+#[derive(Debug, Clone)]
+struct ExtnameFn {
+    value: Box<dyn Expression>,
+    include_dot: Box<dyn Expression>,
+    windows: Box<dyn Expression>,
+}
+
+impl Expression for ExtnameFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?.try_bytes()?;
+        let include_dot = self.include_dot.resolve(ctx)?.try_boolean()?;
+        let windows = self.windows.resolve(ctx)?.try_boolean()?;
+
+        let path = String::from_utf8_lossy(&value);
+        let basename = path_util::basename(&path, windows);
+
+        // The leading dots of hidden files, such as `.bashrc`, don't start an
+        // extension.
+        let name = basename.trim_start_matches('.');
+        let extension = match name.rfind('.') {
+            Some(i) if include_dot => &name[i..],
+            Some(i) => &name[i + 1..],
+            None => "",
+        };
+
+        Ok(extension.into())
+    }
+
+    fn type_def(&self, _: &state::Compiler) -> TypeDef {
+        TypeDef::new().infallible().bytes()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    test_function![
+        extname => Extname;
+
+        extension {
+            args: func_args![value: "/var/log/app.log"],
+            want: Ok(".log"),
+            tdef: TypeDef::new().infallible().bytes(),
+        }
+
+        without_dot {
+            args: func_args![value: "/var/log/app.log", include_dot: false],
+            want: Ok("log"),
+            tdef: TypeDef::new().infallible().bytes(),
+        }
+
+        last_extension {
+            args: func_args![value: "archive.tar.gz"],
+            want: Ok(".gz"),
+            tdef: TypeDef::new().infallible().bytes(),
+        }
+
+        no_extension {
+            args: func_args![value: "/usr/bin/env"],
+            want: Ok(""),
+            tdef: TypeDef::new().infallible().bytes(),
+        }
+
+        hidden_file {
+            args: func_args![value: "/home/user/.bashrc"],
+            want: Ok(""),
+            tdef: TypeDef::new().infallible().bytes(),
+        }
+
+        hidden_file_with_extension {
+            args: func_args![value: ".config.yaml"],
+            want: Ok(".yaml"),
+            tdef: TypeDef::new().infallible().bytes(),
+        }
+
+        dot_in_directory {
+            args: func_args![value: "/etc/conf.d/app"],
+            want: Ok(""),
+            tdef: TypeDef::new().infallible().bytes(),
+        }
+
+        trailing_slash {
+            args: func_args![value: "/srv/site.old/"],
+            want: Ok(".old"),
+            tdef: TypeDef::new().infallible().bytes(),
+        }
+
+        windows {
+            args: func_args![value: r#"C:\Logs.d\app"#, windows: true],
+            want: Ok(""),
+            tdef: TypeDef::new().infallible().bytes(),
+        }
+    ];
+}
@@ -0,0 +1,106 @@
+use crate::mime_util;
+use vrl::prelude::*;
+
+#[derive(Clone, Copy, Debug)]
+pub struct FileExtension;
+
+impl Function for FileExtension {
+    fn identifier(&self) -> &'static str {
+        "file_extension"
+    }
+
+    fn is_pure(&self) -> bool {
+        true
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[Parameter {
+            keyword: "value",
+            kind: kind::BYTES,
+            required: true,
+        }]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "media type",
+                source: r#"file_extension("image/jpeg")"#,
+                result: Ok("jpg"),
+            },
+            Example {
+                title: "content type",
+                source: r#"file_extension("text/html; charset=utf-8")"#,
+                result: Ok("html"),
+            },
+            Example {
+                title: "unknown",
+                source: r#"file_extension("application/x-unknown")"#,
+                result: Ok("null"),
+            },
+        ]
+    }
+
+    fn compile(&self, mut arguments: ArgumentList) -> Compiled {
+        let value = arguments.required("value");
+
+        Ok(Box::new(FileExtensionFn { value }))
+    }
+}
+
+#[derive(Debug, Clone)]
+struct FileExtensionFn {
+    value: Box<dyn Expression>,
+}
+
+impl Expression for FileExtensionFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?;
+        let value = value.try_bytes_utf8_lossy()?;
+
+        Ok(mime_util::extension(&value).into())
+    }
+
+    fn type_def(&self, _: &state::Compiler) -> TypeDef {
+        TypeDef::new().infallible().bytes().add_null()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    test_function![
+        file_extension => FileExtension;
+
+        media_type {
+            args: func_args![value: "application/json"],
+            want: Ok("json"),
+            tdef: TypeDef::new().infallible().bytes().add_null(),
+        }
+
+        preferred_extension {
+            args: func_args![value: "image/jpeg"],
+            want: Ok("jpg"),
+            tdef: TypeDef::new().infallible().bytes().add_null(),
+        }
+
+        compound_extension {
+            args: func_args![value: "application/x-compressed-tar"],
+            want: Ok("tar.gz"),
+            tdef: TypeDef::new().infallible().bytes().add_null(),
+        }
+
+        parameters_and_case {
+            args: func_args![value: "Text/HTML; charset=utf-8"],
+            want: Ok("html"),
+            tdef: TypeDef::new().infallible().bytes().add_null(),
+        }
+
+        unknown_media_type {
+            args: func_args![value: "application/x-unknown"],
+            want: Ok(Value::Null),
+            tdef: TypeDef::new().infallible().bytes().add_null(),
+        }
+    ];
+}
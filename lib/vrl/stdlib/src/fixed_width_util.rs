@@ -0,0 +1,79 @@
+use vrl::Value;
+
+/// A column of a fixed-width record.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct FixedWidthField {
+    pub(crate) name: String,
+    pub(crate) start: usize,
+    pub(crate) length: usize,
+    pub(crate) align_right: bool,
+}
+
+/// Parses the spec of a fixed-width record, an array of `{ name, start,
+/// length }` objects.
+///
+/// `width` can be used instead of `length`, and `start` defaults to the end of
+/// the previous field, so that consecutive fields only need a width. Positions
+/// are counted in characters, starting at zero.
+///
+/// Fields can also have an `align` of `"left"` (the default) or `"right"`,
+/// which is only used when encoding records.
+pub(crate) fn fixed_width_spec(spec: Value) -> std::result::Result<Vec<FixedWidthField>, String> {
+    type Field = std::collections::BTreeMap<String, Value>;
+
+    fn position(field: &Field, key: &str) -> std::result::Result<Option<usize>, String> {
+        match field.get(key) {
+            Some(Value::Integer(v)) if *v >= 0 => Ok(Some(*v as usize)),
+            Some(_) => Err(format!(r#""{}" must be a non-negative integer"#, key)),
+            None => Ok(None),
+        }
+    }
+
+    let fields = spec.try_array().map_err(|err| err.to_string())?;
+    let mut spec = Vec::with_capacity(fields.len());
+    let mut end = 0;
+
+    for (index, field) in fields.iter().enumerate() {
+        let error = |message: String| format!("invalid field {} in spec: {}", index, message);
+
+        let field = match field {
+            Value::Object(field) => field,
+            value => return Err(error(format!("expected object, got {}", value.kind()))),
+        };
+
+        let name = match field.get("name") {
+            Some(Value::Bytes(name)) => String::from_utf8_lossy(name).into_owned(),
+            Some(_) => return Err(error(r#""name" must be a string"#.to_owned())),
+            None => return Err(error(r#"missing "name""#.to_owned())),
+        };
+
+        let start = position(field, "start").map_err(error)?.unwrap_or(end);
+        let length = position(field, "length").map_err(error)?;
+        let width = position(field, "width").map_err(error)?;
+
+        let length = match (length, width) {
+            (Some(length), None) | (None, Some(length)) => length,
+            (Some(_), Some(_)) => {
+                return Err(error(r#"both "length" and "width" are set"#.to_owned()))
+            }
+            (None, None) => return Err(error(r#"missing "length" or "width""#.to_owned())),
+        };
+
+        let align_right = match field.get("align") {
+            Some(Value::Bytes(align)) if align.as_ref() == b"left" => false,
+            Some(Value::Bytes(align)) if align.as_ref() == b"right" => true,
+            Some(_) => return Err(error(r#""align" must be "left" or "right""#.to_owned())),
+            None => false,
+        };
+
+        end = start.saturating_add(length);
+        spec.push(FixedWidthField {
+            name,
+            start,
+            length,
+            align_right,
+        });
+    }
+
+    Ok(spec)
+}
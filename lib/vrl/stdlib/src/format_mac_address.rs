@@ -0,0 +1,120 @@
+use crate::mac_util::{self, Style};
+use std::str::FromStr;
+use vrl::prelude::*;
+
+#[derive(Clone, Copy, Debug)]
+pub struct FormatMacAddress;
+
+impl Function for FormatMacAddress {
+    fn identifier(&self) -> &'static str {
+        "format_mac_address"
+    }
+
+    fn is_pure(&self) -> bool {
+        true
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[
+            Parameter {
+                keyword: "value",
+                kind: kind::BYTES,
+                required: true,
+            },
+            Parameter {
+                keyword: "style",
+                kind: kind::BYTES,
+                required: true,
+            },
+        ]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "cisco",
+                source: r#"format_mac_address!("00:1B:63:84:45:E6", style: "cisco")"#,
+                result: Ok("001b.6384.45e6"),
+            },
+            Example {
+                title: "dash",
+                source: r#"format_mac_address!("001b.6384.45e6", style: "dash")"#,
+                result: Ok("00-1b-63-84-45-e6"),
+            },
+        ]
+    }
+
+    fn compile(&self, mut arguments: ArgumentList) -> Compiled {
+        let value = arguments.required("value");
+        let style = arguments.required("style");
+
+        Ok(Box::new(FormatMacAddressFn { value, style }))
+    }
+}
+
+#[derive(Debug, Clone)]
+struct FormatMacAddressFn {
+    value: Box<dyn Expression>,
+    style: Box<dyn Expression>,
+}
+
+impl Expression for FormatMacAddressFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?.try_bytes()?;
+        let style = self.style.resolve(ctx)?.try_bytes()?;
+        let style = Style::from_str(&String::from_utf8_lossy(&style))?;
+
+        let octets = mac_util::parse(String::from_utf8_lossy(&value).trim())?;
+
+        Ok(mac_util::format(&octets, style).into())
+    }
+
+    fn type_def(&self, _: &state::Compiler) -> TypeDef {
+        TypeDef::new().fallible().bytes()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    test_function![
+        format_mac_address => FormatMacAddress;
+
+        colon_to_cisco {
+            args: func_args![value: "00:1B:63:84:45:E6", style: "cisco"],
+            want: Ok("001b.6384.45e6"),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+
+        cisco_to_dash {
+            args: func_args![value: "001b.6384.45e6", style: "dash"],
+            want: Ok("00-1b-63-84-45-e6"),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+
+        dash_to_colon {
+            args: func_args![value: "00-1b-63-84-45-e6", style: "colon"],
+            want: Ok("00:1b:63:84:45:e6"),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+
+        bare_to_cisco {
+            args: func_args![value: "001B638445E6", style: "cisco"],
+            want: Ok("001b.6384.45e6"),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+
+        unknown_style {
+            args: func_args![value: "00:1b:63:84:45:e6", style: "dots"],
+            want: Err(r#"unknown style "dots", expected "colon", "dash" or "cisco""#),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+
+        invalid_address {
+            args: func_args![value: "00:1b:63:84:45:e6:00", style: "colon"],
+            want: Err(r#"invalid MAC address "00:1b:63:84:45:e6:00""#),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+    ];
+}
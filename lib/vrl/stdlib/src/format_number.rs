@@ -1,4 +1,7 @@
-use rust_decimal::{prelude::FromPrimitive, Decimal};
+use rust_decimal::{
+    prelude::{FromPrimitive, Zero},
+    Decimal, RoundingStrategy,
+};
 use vrl::prelude::*;
 
 #[derive(Clone, Copy, Debug)]
@@ -84,6 +87,19 @@ impl Expression for FormatNumberFn {
             None => None,
         };
 
+        // Round half away from zero before padding the fractional part.
+        let mut value = match scale {
+            Some(i) if i >= 0 => {
+                value.round_dp_with_strategy(i as u32, RoundingStrategy::MidpointAwayFromZero)
+            }
+            _ => value,
+        };
+
+        // Values rounding to zero mustn't keep their negative sign.
+        if value.is_zero() {
+            value.set_sign_positive(true);
+        }
+
         let grouping_separator = match &self.grouping_separator {
             Some(expr) => Some(expr.resolve(ctx)?.try_bytes()?),
             None => None,
@@ -127,19 +143,21 @@ impl Expression for FormatNumberFn {
         // Manipulate integral part based on configuration.
         if let Some(sep) = grouping_separator.as_deref() {
             let sep = String::from_utf8_lossy(sep);
-            let start = parts[0].len() % 3;
-
-            let positions: Vec<usize> = parts[0]
-                .chars()
-                .skip(start)
-                .enumerate()
-                .map(|(i, _)| i)
-                .filter(|i| i % 3 == 0)
-                .collect();
-
-            for (i, pos) in positions.iter().enumerate() {
-                parts[0].insert_str(pos + (i * sep.len()) + start, &sep);
+            let (sign, digits) = match parts[0].strip_prefix('-') {
+                Some(digits) => ("-", digits),
+                None => ("", parts[0].as_str()),
+            };
+
+            let mut grouped = sign.to_owned();
+            for (i, digit) in digits.chars().enumerate() {
+                if i > 0 && (digits.len() - i) % 3 == 0 {
+                    grouped.push_str(&sep);
+                }
+
+                grouped.push(digit);
             }
+
+            parts[0] = grouped;
         }
 
         // Join results, using configured decimal separator.
@@ -169,7 +187,7 @@ mod tests {
         precision {
             args: func_args![value: 1234.567,
                              scale: 2],
-            want: Ok(value!("1234.56")),
+            want: Ok(value!("1234.57")),
             tdef: TypeDef::new().infallible().bytes(),
         }
 
@@ -178,7 +196,7 @@ mod tests {
             args: func_args![value: 1234.567,
                              scale: 2,
                              decimal_separator: ","],
-            want: Ok(value!("1234,56")),
+            want: Ok(value!("1234,57")),
             tdef: TypeDef::new().infallible().bytes(),
         }
 
@@ -187,7 +205,7 @@ mod tests {
                              scale: 2,
                              decimal_separator: ",",
                              grouping_separator: " "],
-            want: Ok(value!("1 234,56")),
+            want: Ok(value!("1 234,57")),
             tdef: TypeDef::new().infallible().bytes(),
         }
 
@@ -196,7 +214,7 @@ mod tests {
                              scale: 3,
                              decimal_separator: ",",
                              grouping_separator: "."],
-            want: Ok(value!("11.222.333.444,567")),
+            want: Ok(value!("11.222.333.444,568")),
             tdef: TypeDef::new().infallible().bytes(),
         }
 
@@ -226,5 +244,64 @@ mod tests {
             want: Ok(value!("12345.00")),
             tdef: TypeDef::new().infallible().bytes(),
         }
+
+        group_large_integer {
+            args: func_args![value: 1234567890,
+                             grouping_separator: ","],
+            want: Ok(value!("1,234,567,890")),
+            tdef: TypeDef::new().infallible().bytes(),
+        }
+
+        group_short_integer {
+            args: func_args![value: 123,
+                             grouping_separator: ","],
+            want: Ok(value!("123")),
+            tdef: TypeDef::new().infallible().bytes(),
+        }
+
+        group_negative {
+            args: func_args![value: -123456.5,
+                             grouping_separator: ","],
+            want: Ok(value!("-123,456.5")),
+            tdef: TypeDef::new().infallible().bytes(),
+        }
+
+        european_separators {
+            args: func_args![value: 1234567.891,
+                             scale: 2,
+                             decimal_separator: ",",
+                             grouping_separator: "."],
+            want: Ok(value!("1.234.567,89")),
+            tdef: TypeDef::new().infallible().bytes(),
+        }
+
+        round_half_away_from_zero {
+            args: func_args![value: 0.125,
+                             scale: 2],
+            want: Ok(value!("0.13")),
+            tdef: TypeDef::new().infallible().bytes(),
+        }
+
+        round_to_zero {
+            args: func_args![value: -0.4,
+                             scale: 0],
+            want: Ok(value!("0")),
+            tdef: TypeDef::new().infallible().bytes(),
+        }
+
+        round_to_zero_fractional {
+            args: func_args![value: -0.004,
+                             scale: 2],
+            want: Ok(value!("0.00")),
+            tdef: TypeDef::new().infallible().bytes(),
+        }
+
+        round_up_to_next_integer {
+            args: func_args![value: 1999.999,
+                             scale: 2,
+                             grouping_separator: ","],
+            want: Ok(value!("2,000.00")),
+            tdef: TypeDef::new().infallible().bytes(),
+        }
     ];
 }
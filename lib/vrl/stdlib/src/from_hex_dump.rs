@@ -0,0 +1,154 @@
+use vrl::prelude::*;
+
+#[derive(Clone, Copy, Debug)]
+pub struct FromHexDump;
+
+impl Function for FromHexDump {
+    fn identifier(&self) -> &'static str {
+        "from_hex_dump"
+    }
+
+    fn is_pure(&self) -> bool {
+        true
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[Parameter {
+            keyword: "value",
+            kind: kind::BYTES,
+            required: true,
+        }]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[Example {
+            title: "dump",
+            source: r#"from_hex_dump!("00000000  68 65 6c 6c 6f  |hello|")"#,
+            result: Ok("hello"),
+        }]
+    }
+
+    fn compile(&self, mut arguments: ArgumentList) -> Compiled {
+        let value = arguments.required("value");
+
+        Ok(Box::new(FromHexDumpFn { value }))
+    }
+}
+
+#[derive(Debug, Clone)]
+struct FromHexDumpFn {
+    value: Box<dyn Expression>,
+}
+
+impl Expression for FromHexDumpFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?;
+        let dump = value.try_bytes_utf8_lossy()?;
+
+        Ok(parse(&dump)
+            .map_err(|err| format!("unable to parse hex dump: {}", err))?
+            .into())
+    }
+
+    fn type_def(&self, _: &state::Compiler) -> TypeDef {
+        TypeDef::new().fallible().bytes()
+    }
+}
+
+/// Parses a dump in the layout of `hex_dump`. The ASCII column, after the
+/// first `|` of a line, is optional and ignored, and the offset of each line
+/// must follow on from the bytes of the lines before it.
+fn parse(dump: &str) -> std::result::Result<Bytes, String> {
+    let mut bytes = vec![];
+
+    for (i, line) in dump
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+    {
+        let line_number = i + 1;
+        let hex = line.splitn(2, '|').next().unwrap_or_default();
+        let mut fields = hex.split_whitespace();
+
+        let offset = fields.next().unwrap_or_default();
+        match usize::from_str_radix(offset, 16) {
+            Ok(offset) if offset == bytes.len() => {}
+            Ok(offset) => {
+                return Err(format!(
+                    "line {}: expected offset {:08x}, got {:08x}",
+                    line_number,
+                    bytes.len(),
+                    offset
+                ))
+            }
+            Err(_) => return Err(format!("line {}: invalid offset {:?}", line_number, offset)),
+        }
+
+        for field in fields {
+            if field.len() != 2 || !field.bytes().all(|b| b.is_ascii_hexdigit()) {
+                return Err(format!("line {}: invalid byte {:?}", line_number, field));
+            }
+
+            bytes.push(u8::from_str_radix(field, 16).expect("valid hex digits"));
+        }
+    }
+
+    Ok(bytes.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    test_function![
+        from_hex_dump => FromHexDump;
+
+        short {
+            args: func_args![value: "00000000  68 65 6c 6c 6f 20 77 6f 72 6c 64 0a              |hello world.|"],
+            want: Ok("hello world\n"),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+
+        lines {
+            args: func_args![value: "00000000  68 65 6c 6c  |hell|\n00000004  6f 20 77 6f  |o wo|\n00000008  72 6c 64 0a  |rld.|"],
+            want: Ok("hello world\n"),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+
+        binary {
+            args: func_args![value: "00000000  00 ff 41 7f  |..A.|\n00000004  20           | |"],
+            want: Ok(Bytes::from_static(b"\x00\xffA\x7f ")),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+
+        without_ascii {
+            args: func_args![value: "00000000  68 69\n\n00000002  21\n"],
+            want: Ok("hi!"),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+
+        empty {
+            args: func_args![value: ""],
+            want: Ok(""),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+
+        missing_line {
+            args: func_args![value: "00000000  68 65 6c 6c  |hell|\n00000008  72 6c 64 0a  |rld.|"],
+            want: Err("unable to parse hex dump: line 2: expected offset 00000004, got 00000008"),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+
+        invalid_byte {
+            args: func_args![value: "00000000  68 6g  |h.|"],
+            want: Err(r#"unable to parse hex dump: line 1: invalid byte "6g""#),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+
+        invalid_offset {
+            args: func_args![value: "hello world"],
+            want: Err(r#"unable to parse hex dump: line 1: invalid offset "hello""#),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+    ];
+}
@@ -0,0 +1,279 @@
+use glob::{MatchOptions, Pattern};
+use std::fmt;
+use vrl::{
+    diagnostic::{Label, Span},
+    prelude::*,
+};
+
+#[derive(Debug)]
+pub enum Error {
+    InvalidPattern {
+        pattern: String,
+        error: glob::PatternError,
+    },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::InvalidPattern { pattern, error } => {
+                write!(f, r#"invalid pattern "{}": {}"#, pattern, error.msg)
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl DiagnosticError for Error {
+    fn code(&self) -> usize {
+        109
+    }
+
+    fn labels(&self) -> Vec<Label> {
+        vec![Label::primary(self.to_string(), Span::default())]
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct GlobMatch;
+
+impl Function for GlobMatch {
+    fn identifier(&self) -> &'static str {
+        "glob_match"
+    }
+
+    fn is_pure(&self) -> bool {
+        true
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[
+            Parameter {
+                keyword: "value",
+                kind: kind::BYTES,
+                required: true,
+            },
+            Parameter {
+                keyword: "pattern",
+                kind: kind::BYTES,
+                required: true,
+            },
+            Parameter {
+                keyword: "case_sensitive",
+                kind: kind::BOOLEAN,
+                required: false,
+            },
+        ]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "match",
+                source: r#"glob_match("/var/log/nginx/access.log", "/var/log/**/*.log")"#,
+                result: Ok("true"),
+            },
+            Example {
+                title: "mismatch",
+                source: r#"glob_match("/var/log/nginx/access.log", "/var/log/*.log")"#,
+                result: Ok("false"),
+            },
+            Example {
+                title: "case insensitive",
+                source: r#"glob_match("APP.LOG", "*.log", case_sensitive: false)"#,
+                result: Ok("true"),
+            },
+        ]
+    }
+
+    fn compile(&self, mut arguments: ArgumentList) -> Compiled {
+        let value = arguments.required("value");
+        let pattern = arguments.required("pattern");
+        let case_sensitive = arguments.optional("case_sensitive");
+
+        // A literal pattern is compiled once, here, so that an invalid one is
+        // reported at compile-time.
+        let pattern = match pattern.as_value() {
+            Some(pattern) => {
+                let pattern = pattern
+                    .try_bytes_utf8_lossy()
+                    .map_err(|e| Box::new(e) as Box<dyn DiagnosticError>)?;
+                let pattern = compile_pattern(&pattern)
+                    .map_err(|e| Box::new(e) as Box<dyn DiagnosticError>)?;
+
+                GlobPattern::Compiled(pattern)
+            }
+            None => GlobPattern::Dynamic(pattern),
+        };
+
+        Ok(Box::new(GlobMatchFn {
+            value,
+            pattern,
+            case_sensitive,
+        }))
+    }
+}
+
+fn compile_pattern(pattern: &str) -> std::result::Result<Pattern, Error> {
+    Pattern::new(pattern).map_err(|error| Error::InvalidPattern {
+        pattern: pattern.to_owned(),
+        error,
+    })
+}
+
+#[derive(Debug, Clone)]
+enum GlobPattern {
+    Compiled(Pattern),
+    Dynamic(Box<dyn Expression>),
+}
+
+#[derive(Debug, Clone)]
+struct GlobMatchFn {
+    value: Box<dyn Expression>,
+    pattern: GlobPattern,
+    case_sensitive: Option<Box<dyn Expression>>,
+}
+
+impl Expression for GlobMatchFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?;
+        let string = value.try_bytes_utf8_lossy()?;
+
+        let case_sensitive = match &self.case_sensitive {
+            Some(expr) => expr.resolve(ctx)?.try_boolean()?,
+            None => true,
+        };
+
+        // Wildcards don't match separators, apart from `**`, which matches
+        // whole path segments.
+        let options = MatchOptions {
+            case_sensitive,
+            require_literal_separator: true,
+            require_literal_leading_dot: false,
+        };
+
+        let is_match = match &self.pattern {
+            GlobPattern::Compiled(pattern) => pattern.matches_with(&string, options),
+            GlobPattern::Dynamic(pattern) => {
+                let pattern = pattern.resolve(ctx)?;
+                let pattern = pattern.try_bytes_utf8_lossy()?;
+
+                compile_pattern(&pattern)
+                    .map_err(|e| e.to_string())?
+                    .matches_with(&string, options)
+            }
+        };
+
+        Ok(is_match.into())
+    }
+
+    fn type_def(&self, _: &state::Compiler) -> TypeDef {
+        TypeDef::new()
+            .boolean()
+            .with_fallibility(matches!(self.pattern, GlobPattern::Dynamic(_)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    test_function![
+        glob_match => GlobMatch;
+
+        star_within_segment {
+            args: func_args![value: "src/lib.rs", pattern: "src/*.rs"],
+            want: Ok(true),
+            tdef: TypeDef::new().infallible().boolean(),
+        }
+
+        star_does_not_cross_segments {
+            args: func_args![value: "src/util/mod.rs", pattern: "src/*.rs"],
+            want: Ok(false),
+            tdef: TypeDef::new().infallible().boolean(),
+        }
+
+        double_star_crosses_segments {
+            args: func_args![value: "src/util/mod.rs", pattern: "src/**/*.rs"],
+            want: Ok(true),
+            tdef: TypeDef::new().infallible().boolean(),
+        }
+
+        double_star_matches_no_segments {
+            args: func_args![value: "src/lib.rs", pattern: "src/**/*.rs"],
+            want: Ok(true),
+            tdef: TypeDef::new().infallible().boolean(),
+        }
+
+        question_mark {
+            args: func_args![value: "app1.log", pattern: "app?.log"],
+            want: Ok(true),
+            tdef: TypeDef::new().infallible().boolean(),
+        }
+
+        character_class {
+            args: func_args![value: "syslog.3", pattern: "syslog.[0-9]"],
+            want: Ok(true),
+            tdef: TypeDef::new().infallible().boolean(),
+        }
+
+        negated_character_class {
+            args: func_args![value: "bat", pattern: "[!a-c]at"],
+            want: Ok(false),
+            tdef: TypeDef::new().infallible().boolean(),
+        }
+
+        no_match {
+            args: func_args![value: "/var/log/app.txt", pattern: "/var/log/*.log"],
+            want: Ok(false),
+            tdef: TypeDef::new().infallible().boolean(),
+        }
+
+        case_sensitive {
+            args: func_args![value: "APP.LOG", pattern: "*.log"],
+            want: Ok(false),
+            tdef: TypeDef::new().infallible().boolean(),
+        }
+
+        case_insensitive {
+            args: func_args![value: "APP.LOG", pattern: "*.log", case_sensitive: false],
+            want: Ok(true),
+            tdef: TypeDef::new().infallible().boolean(),
+        }
+
+        invalid_pattern {
+            args: func_args![value: "app.log", pattern: "[a"],
+            want: Err(r#"invalid pattern "[a": invalid range pattern"#),
+            tdef: TypeDef::new().infallible().boolean(),
+        }
+    ];
+
+    #[test]
+    fn dynamic_pattern() {
+        let mut target = value!({});
+        let mut runtime_state = vrl::state::Runtime::default();
+        let mut ctx = Context::new(&mut target, &mut runtime_state);
+
+        let func = GlobMatchFn {
+            value: expr!("src/lib.rs"),
+            pattern: GlobPattern::Dynamic(expr!("src/*.rs")),
+            case_sensitive: None,
+        };
+        assert_eq!(func.resolve(&mut ctx), Ok(true.into()));
+        assert!(func.type_def(&state::Compiler::default()).is_fallible());
+
+        let func = GlobMatchFn {
+            value: expr!("src/lib.rs"),
+            pattern: GlobPattern::Dynamic(expr!("src/a**b")),
+            case_sensitive: None,
+        };
+        assert_eq!(
+            func.resolve(&mut ctx).map_err(|e| e.to_string()),
+            Err(
+                r#"invalid pattern "src/a**b": recursive wildcards must form a single path component"#
+                    .to_owned()
+            )
+        );
+    }
+}
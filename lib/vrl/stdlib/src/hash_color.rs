@@ -0,0 +1,216 @@
+use vrl::prelude::*;
+
+#[derive(Clone, Copy, Debug)]
+pub struct HashColor;
+
+impl Function for HashColor {
+    fn identifier(&self) -> &'static str {
+        "hash_color"
+    }
+
+    fn is_pure(&self) -> bool {
+        true
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[
+            Parameter {
+                keyword: "value",
+                kind: kind::BYTES,
+                required: true,
+            },
+            Parameter {
+                keyword: "palette",
+                kind: kind::ARRAY,
+                required: false,
+            },
+            Parameter {
+                keyword: "pastel",
+                kind: kind::BOOLEAN,
+                required: false,
+            },
+        ]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "color",
+                source: r#"hash_color("checkout-service")"#,
+                result: Ok("#24ba4f"),
+            },
+            Example {
+                title: "pastel",
+                source: r#"hash_color("checkout-service", pastel: true)"#,
+                result: Ok("#91dca7"),
+            },
+            Example {
+                title: "palette",
+                source: r#"hash_color!("checkout-service", palette: ["red", "green", "blue"])"#,
+                result: Ok("blue"),
+            },
+        ]
+    }
+
+    fn compile(&self, mut arguments: ArgumentList) -> Compiled {
+        let value = arguments.required("value");
+        let palette = arguments.optional("palette");
+        let pastel = arguments.optional("pastel").unwrap_or(expr!(false));
+
+        Ok(Box::new(HashColorFn {
+            value,
+            palette,
+            pastel,
+        }))
+    }
+}
+
+/// The 64-bit FNV-1a hash, which unlike the hashers of the standard library
+/// is guaranteed to stay the same, so that keys keep their colors.
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ u64::from(*byte)).wrapping_mul(0x0000_0100_0000_01b3)
+    })
+}
+
+#[derive(Debug, Clone)]
+struct HashColorFn {
+    value: Box<dyn Expression>,
+    palette: Option<Box<dyn Expression>>,
+    pastel: Box<dyn Expression>,
+}
+
+impl Expression for HashColorFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let hash = fnv1a(&self.value.resolve(ctx)?.try_bytes()?);
+
+        if let Some(palette) = &self.palette {
+            let palette = palette.resolve(ctx)?.try_array()?;
+            if palette.is_empty() {
+                return Err("palette must not be empty".into());
+            }
+            if palette.iter().any(|color| !color.is_bytes()) {
+                return Err("all palette items must be strings".into());
+            }
+
+            return Ok(palette[(hash % palette.len() as u64) as usize].clone());
+        }
+
+        let mut channels = [(hash >> 16) as u8, (hash >> 8) as u8, hash as u8];
+
+        // Pastel colors are halfway between the color and white.
+        if self.pastel.resolve(ctx)?.try_boolean()? {
+            for channel in channels.iter_mut() {
+                *channel = ((u16::from(*channel) + 255) / 2) as u8;
+            }
+        }
+
+        Ok(format!("#{:02x}{:02x}{:02x}", channels[0], channels[1], channels[2]).into())
+    }
+
+    fn type_def(&self, _: &state::Compiler) -> TypeDef {
+        TypeDef::new()
+            .bytes()
+            .with_fallibility(self.palette.is_some())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    test_function![
+        hash_color => HashColor;
+
+        color {
+            args: func_args![value: "checkout-service"],
+            want: Ok("#24ba4f"),
+            tdef: TypeDef::new().infallible().bytes(),
+        }
+
+        distinct_keys {
+            args: func_args![value: "api"],
+            want: Ok("#6aae07"),
+            tdef: TypeDef::new().infallible().bytes(),
+        }
+
+        empty {
+            args: func_args![value: ""],
+            want: Ok("#222325"),
+            tdef: TypeDef::new().infallible().bytes(),
+        }
+
+        pastel {
+            args: func_args![value: "checkout-service", pastel: true],
+            want: Ok("#91dca7"),
+            tdef: TypeDef::new().infallible().bytes(),
+        }
+
+        palette {
+            args: func_args![value: "checkout-service", palette: value!(["red", "green", "blue"])],
+            want: Ok("blue"),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+
+        palette_ignores_pastel {
+            args: func_args![value: "api", palette: value!(["red", "green", "blue"]), pastel: true],
+            want: Ok("red"),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+
+        empty_palette {
+            args: func_args![value: "api", palette: value!([])],
+            want: Err("palette must not be empty"),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+
+        invalid_palette {
+            args: func_args![value: "api", palette: value!(["red", 1])],
+            want: Err("all palette items must be strings"),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+    ];
+
+    fn resolve(value: &str, palette: Option<Value>) -> Value {
+        let mut target = value!({});
+        let mut runtime_state = vrl::state::Runtime::default();
+        let mut ctx = Context::new(&mut target, &mut runtime_state);
+
+        HashColorFn {
+            value: Value::from(value).into_expression(),
+            palette: palette.map(|palette| palette.into_expression()),
+            pastel: expr!(false),
+        }
+        .resolve(&mut ctx)
+        .unwrap()
+    }
+
+    #[test]
+    fn deterministic() {
+        for key in &["api", "web", "db"] {
+            assert_eq!(resolve(key, None), resolve(key, None));
+        }
+    }
+
+    #[test]
+    fn distinct() {
+        let colors = ["api", "web", "db", "checkout-service"]
+            .iter()
+            .map(|key| resolve(key, None))
+            .collect::<Vec<_>>();
+
+        for (i, color) in colors.iter().enumerate() {
+            assert!(!colors[i + 1..].contains(color), "{} repeated", color);
+        }
+    }
+
+    #[test]
+    fn palette_member() {
+        let palette = value!(["#ff0000", "#00ff00", "#0000ff"]);
+
+        for i in 0..100 {
+            let color = resolve(&format!("key-{}", i), Some(palette.clone()));
+            assert!(palette.as_array().unwrap().contains(&color));
+        }
+    }
+}
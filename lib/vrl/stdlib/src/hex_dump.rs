@@ -0,0 +1,148 @@
+use std::fmt::Write;
+use vrl::prelude::*;
+
+#[derive(Clone, Copy, Debug)]
+pub struct HexDump;
+
+impl Function for HexDump {
+    fn identifier(&self) -> &'static str {
+        "hex_dump"
+    }
+
+    fn is_pure(&self) -> bool {
+        true
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[
+            Parameter {
+                keyword: "value",
+                kind: kind::BYTES,
+                required: true,
+            },
+            Parameter {
+                keyword: "bytes_per_line",
+                kind: kind::INTEGER,
+                required: false,
+            },
+        ]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[Example {
+            title: "dump",
+            source: r#"hex_dump("hello world")"#,
+            result: Ok("00000000  68 65 6c 6c 6f 20 77 6f 72 6c 64                 |hello world|"),
+        }]
+    }
+
+    fn compile(&self, mut arguments: ArgumentList) -> Compiled {
+        let value = arguments.required("value");
+        let bytes_per_line = arguments.optional("bytes_per_line");
+
+        Ok(Box::new(HexDumpFn {
+            value,
+            bytes_per_line,
+        }))
+    }
+}
+
+#[derive(Debug, Clone)]
+struct HexDumpFn {
+    value: Box<dyn Expression>,
+    bytes_per_line: Option<Box<dyn Expression>>,
+}
+
+impl Expression for HexDumpFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?.try_bytes()?;
+        let bytes_per_line = match &self.bytes_per_line {
+            Some(expr) => match expr.resolve(ctx)?.try_integer()? {
+                n if n > 0 => n as usize,
+                _ => return Err(r#""bytes_per_line" must be greater than zero"#.into()),
+            },
+            None => 16,
+        };
+
+        // Each line is the offset of its first byte, the bytes in hexadecimal,
+        // padded to the width of a full line, and the bytes as ASCII, with a
+        // dot for each unprintable byte.
+        let mut dump = String::new();
+        for (i, chunk) in value.chunks(bytes_per_line).enumerate() {
+            if i > 0 {
+                dump.push('\n');
+            }
+
+            let hex = chunk
+                .iter()
+                .map(|byte| format!("{:02x}", byte))
+                .collect::<Vec<_>>()
+                .join(" ");
+
+            let ascii = chunk
+                .iter()
+                .map(|&byte| match byte {
+                    0x20..=0x7e => byte as char,
+                    _ => '.',
+                })
+                .collect::<String>();
+
+            write!(
+                dump,
+                "{:08x}  {:width$}  |{}|",
+                i * bytes_per_line,
+                hex,
+                ascii,
+                width = bytes_per_line * 3 - 1
+            )
+            .expect("write to string");
+        }
+
+        Ok(dump.into())
+    }
+
+    fn type_def(&self, _: &state::Compiler) -> TypeDef {
+        TypeDef::new()
+            .bytes()
+            .with_fallibility(self.bytes_per_line.is_some())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    test_function![
+        hex_dump => HexDump;
+
+        short {
+            args: func_args![value: "hello world\n"],
+            want: Ok("00000000  68 65 6c 6c 6f 20 77 6f 72 6c 64 0a              |hello world.|"),
+            tdef: TypeDef::new().infallible().bytes(),
+        }
+
+        bytes_per_line {
+            args: func_args![value: "hello world\n", bytes_per_line: 4],
+            want: Ok("00000000  68 65 6c 6c  |hell|\n00000004  6f 20 77 6f  |o wo|\n00000008  72 6c 64 0a  |rld.|"),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+
+        binary {
+            args: func_args![value: Bytes::from_static(b"\x00\xffA\x7f "), bytes_per_line: 4],
+            want: Ok("00000000  00 ff 41 7f  |..A.|\n00000004  20           | |"),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+
+        empty {
+            args: func_args![value: ""],
+            want: Ok(""),
+            tdef: TypeDef::new().infallible().bytes(),
+        }
+
+        invalid_bytes_per_line {
+            args: func_args![value: "hello", bytes_per_line: 0],
+            want: Err(r#""bytes_per_line" must be greater than zero"#),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+    ];
+}
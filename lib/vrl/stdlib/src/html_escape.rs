@@ -0,0 +1,93 @@
+use vrl::prelude::*;
+
+#[derive(Clone, Copy, Debug)]
+pub struct HtmlEscape;
+
+impl Function for HtmlEscape {
+    fn identifier(&self) -> &'static str {
+        "html_escape"
+    }
+
+    fn is_pure(&self) -> bool {
+        true
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[Parameter {
+            keyword: "value",
+            kind: kind::BYTES,
+            required: true,
+        }]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[Example {
+            title: "escape",
+            source: r#"html_escape(s'<a href="/">Fish & chips</a>')"#,
+            result: Ok(r#"s'&lt;a href=&quot;/&quot;&gt;Fish &amp; chips&lt;/a&gt;'"#),
+        }]
+    }
+
+    fn compile(&self, mut arguments: ArgumentList) -> Compiled {
+        let value = arguments.required("value");
+
+        Ok(Box::new(HtmlEscapeFn { value }))
+    }
+}
+
+#[derive(Debug, Clone)]
+struct HtmlEscapeFn {
+    value: Box<dyn Expression>,
+}
+
+impl Expression for HtmlEscapeFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let bytes = self.value.resolve(ctx)?.try_bytes()?;
+        let input = String::from_utf8_lossy(&bytes);
+
+        let mut escaped = String::with_capacity(input.len());
+        for c in input.chars() {
+            match c {
+                '&' => escaped.push_str("&amp;"),
+                '<' => escaped.push_str("&lt;"),
+                '>' => escaped.push_str("&gt;"),
+                '"' => escaped.push_str("&quot;"),
+                '\'' => escaped.push_str("&#x27;"),
+                c => escaped.push(c),
+            }
+        }
+
+        Ok(escaped.into())
+    }
+
+    fn type_def(&self, _: &state::Compiler) -> TypeDef {
+        TypeDef::new().infallible().bytes()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    test_function![
+        html_escape => HtmlEscape;
+
+        plain {
+            args: func_args![value: "foo bar"],
+            want: Ok("foo bar"),
+            tdef: TypeDef::new().infallible().bytes(),
+        }
+
+        special_characters {
+            args: func_args![value: r#"<b class="x">Tom & Jerry's</b>"#],
+            want: Ok("&lt;b class=&quot;x&quot;&gt;Tom &amp; Jerry&#x27;s&lt;/b&gt;"),
+            tdef: TypeDef::new().infallible().bytes(),
+        }
+
+        already_escaped {
+            args: func_args![value: "&amp;"],
+            want: Ok("&amp;amp;"),
+            tdef: TypeDef::new().infallible().bytes(),
+        }
+    ];
+}
@@ -0,0 +1,94 @@
+use crate::html_util;
+use vrl::prelude::*;
+
+#[derive(Clone, Copy, Debug)]
+pub struct HtmlUnescape;
+
+impl Function for HtmlUnescape {
+    fn identifier(&self) -> &'static str {
+        "html_unescape"
+    }
+
+    fn is_pure(&self) -> bool {
+        true
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[Parameter {
+            keyword: "value",
+            kind: kind::BYTES,
+            required: true,
+        }]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[Example {
+            title: "unescape",
+            source: r#"html_unescape("Fish &amp; chips &#x27;n&apos; peas")"#,
+            result: Ok(r#""Fish & chips 'n' peas""#),
+        }]
+    }
+
+    fn compile(&self, mut arguments: ArgumentList) -> Compiled {
+        let value = arguments.required("value");
+
+        Ok(Box::new(HtmlUnescapeFn { value }))
+    }
+}
+
+#[derive(Debug, Clone)]
+struct HtmlUnescapeFn {
+    value: Box<dyn Expression>,
+}
+
+impl Expression for HtmlUnescapeFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let bytes = self.value.resolve(ctx)?.try_bytes()?;
+        let input = String::from_utf8_lossy(&bytes);
+
+        Ok(html_util::decode_html_entities(&input).into())
+    }
+
+    fn type_def(&self, _: &state::Compiler) -> TypeDef {
+        TypeDef::new().infallible().bytes()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    test_function![
+        html_unescape => HtmlUnescape;
+
+        special_characters {
+            args: func_args![value: "&lt;b class=&quot;x&quot;&gt;Tom &amp; Jerry&#x27;s&lt;/b&gt;"],
+            want: Ok(r#"<b class="x">Tom & Jerry's</b>"#),
+            tdef: TypeDef::new().infallible().bytes(),
+        }
+
+        apostrophes {
+            args: func_args![value: "&#x27; &#X27; &#39; &apos;"],
+            want: Ok("' ' ' '"),
+            tdef: TypeDef::new().infallible().bytes(),
+        }
+
+        named_and_numeric {
+            args: func_args![value: "&copy; &mdash; &#8364; &#x1f600;"],
+            want: Ok("\u{a9} \u{2014} \u{20ac} \u{1f600}"),
+            tdef: TypeDef::new().infallible().bytes(),
+        }
+
+        unknown_entities {
+            args: func_args![value: "AT&T &bogus; &#xzz; & &amp"],
+            want: Ok("AT&T &bogus; &#xzz; & &amp"),
+            tdef: TypeDef::new().infallible().bytes(),
+        }
+
+        escaped_entity {
+            args: func_args![value: "&amp;amp;"],
+            want: Ok("&amp;"),
+            tdef: TypeDef::new().infallible().bytes(),
+        }
+    ];
+}
@@ -0,0 +1,239 @@
+/// Decodes the HTML character references in the given string, such as `&amp;`
+/// or `&#x27;`.
+///
+/// Unknown or malformed references are left untouched.
+pub(crate) fn decode_html_entities(input: &str) -> String {
+    let mut output = String::with_capacity(input.len());
+    let mut rest = input;
+
+    while let Some(start) = rest.find('&') {
+        output.push_str(&rest[..start]);
+
+        let reference = &rest[start + 1..];
+        let decoded = reference
+            .find(';')
+            .filter(|&end| end <= 32)
+            .and_then(|end| decode_html_entity(&reference[..end]).map(|c| (c, end + 1)));
+
+        match decoded {
+            Some((c, len)) => {
+                output.push(c);
+                rest = &reference[len..];
+            }
+            None => {
+                output.push('&');
+                rest = reference;
+            }
+        }
+    }
+
+    output.push_str(rest);
+    output
+}
+
+/// Decodes a single character reference, without its leading `&` and
+/// trailing `;`.
+fn decode_html_entity(reference: &str) -> Option<char> {
+    if let Some(number) = reference.strip_prefix('#') {
+        let code = match number
+            .strip_prefix('x')
+            .or_else(|| number.strip_prefix('X'))
+        {
+            Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+            None => number.parse().ok()?,
+        };
+
+        return std::char::from_u32(code);
+    }
+
+    let c = match reference {
+        "amp" => '&',
+        "lt" => '<',
+        "gt" => '>',
+        "quot" => '"',
+        "apos" => '\'',
+        "nbsp" => '\u{a0}',
+        "copy" => '\u{a9}',
+        "reg" => '\u{ae}',
+        "trade" => '\u{2122}',
+        "euro" => '\u{20ac}',
+        "pound" => '\u{a3}',
+        "yen" => '\u{a5}',
+        "cent" => '\u{a2}',
+        "sect" => '\u{a7}',
+        "deg" => '\u{b0}',
+        "middot" => '\u{b7}',
+        "laquo" => '\u{ab}',
+        "raquo" => '\u{bb}',
+        "lsquo" => '\u{2018}',
+        "rsquo" => '\u{2019}',
+        "ldquo" => '\u{201c}',
+        "rdquo" => '\u{201d}',
+        "ndash" => '\u{2013}',
+        "mdash" => '\u{2014}',
+        "hellip" => '\u{2026}',
+        "bull" => '\u{2022}',
+        "times" => '\u{d7}',
+        "divide" => '\u{f7}',
+        _ => return None,
+    };
+
+    Some(c)
+}
+
+/// A token produced by [`tokenize_html`].
+#[cfg(any(feature = "parse_html", feature = "strip_html"))]
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum HtmlToken<'a> {
+    /// An opening tag, with its lowercased name and its decoded attributes.
+    Start {
+        name: String,
+        attributes: Vec<(String, String)>,
+        self_closing: bool,
+    },
+
+    /// A closing tag, with its lowercased name.
+    End(String),
+
+    /// The text between two tags, with its character references still encoded.
+    Text(&'a str),
+}
+
+/// Splits an HTML document into its tags and the text between them.
+///
+/// This is a lenient tokenizer, not a spec-compliant parser: comments,
+/// doctypes, and the contents of `<script>` and `<style>` elements are
+/// skipped, and a `<` that doesn't start a tag is treated as text.
+#[cfg(any(feature = "parse_html", feature = "strip_html"))]
+pub(crate) fn tokenize_html(input: &str) -> Vec<HtmlToken<'_>> {
+    let mut tokens = vec![];
+    let mut pos = 0;
+    let mut text_start = 0;
+
+    while let Some(offset) = input[pos..].find('<') {
+        let start = pos + offset;
+        let tail = &input[start..];
+
+        let (token, end) = if let Some(comment) = tail.strip_prefix("<!--") {
+            let end = comment
+                .find("-->")
+                .map_or(input.len(), |i| start + 4 + i + 3);
+            (None, end)
+        } else if tail.starts_with("<!") || tail.starts_with("<?") {
+            let end = tail.find('>').map_or(input.len(), |i| start + i + 1);
+            (None, end)
+        } else if let Some((token, len)) = parse_html_tag(tail) {
+            (Some(token), start + len)
+        } else {
+            pos = start + 1;
+            continue;
+        };
+
+        if text_start < start {
+            tokens.push(HtmlToken::Text(&input[text_start..start]));
+        }
+
+        pos = end;
+        text_start = end;
+
+        if let Some(token) = token {
+            if let HtmlToken::Start { name, .. } = &token {
+                if name == "script" || name == "style" {
+                    let close = format!("</{}", name);
+                    pos = input[pos..]
+                        .to_ascii_lowercase()
+                        .find(&close)
+                        .map_or(input.len(), |i| pos + i);
+                    text_start = pos;
+                }
+            }
+
+            tokens.push(token);
+        }
+    }
+
+    if text_start < input.len() {
+        tokens.push(HtmlToken::Text(&input[text_start..]));
+    }
+
+    tokens
+}
+
+/// Parses the opening or closing tag at the start of `input`, returning the
+/// token and the number of bytes it spans.
+#[cfg(any(feature = "parse_html", feature = "strip_html"))]
+fn parse_html_tag<'a>(input: &str) -> Option<(HtmlToken<'a>, usize)> {
+    let closing = input.starts_with("</");
+    let body = &input[if closing { 2 } else { 1 }..];
+
+    if !body.starts_with(|c: char| c.is_ascii_alphabetic()) {
+        return None;
+    }
+
+    // Find the closing `>`, ignoring any that are part of a quoted value.
+    let mut quote = None;
+    let end = body.find(|c: char| {
+        match quote {
+            Some(q) if c == q => quote = None,
+            Some(_) => {}
+            None if c == '"' || c == '\'' => quote = Some(c),
+            None => return c == '>',
+        }
+        false
+    })?;
+
+    let inner = &body[..end];
+    let len = input.len() - body.len() + end + 1;
+
+    let name_len = inner
+        .find(|c: char| c.is_whitespace() || c == '/')
+        .unwrap_or_else(|| inner.len());
+    let name = inner[..name_len].to_ascii_lowercase();
+
+    if closing {
+        return Some((HtmlToken::End(name), len));
+    }
+
+    let token = HtmlToken::Start {
+        name,
+        attributes: parse_html_attributes(&inner[name_len..]),
+        self_closing: inner.ends_with('/'),
+    };
+
+    Some((token, len))
+}
+
+#[cfg(any(feature = "parse_html", feature = "strip_html"))]
+fn parse_html_attributes(mut input: &str) -> Vec<(String, String)> {
+    let mut attributes = vec![];
+
+    loop {
+        input = input.trim_start_matches(|c: char| c.is_whitespace() || c == '/');
+        if input.is_empty() {
+            return attributes;
+        }
+
+        let name_len = input
+            .find(|c: char| c.is_whitespace() || c == '=' || c == '/')
+            .unwrap_or_else(|| input.len());
+        let name = input[..name_len].to_ascii_lowercase();
+        input = input[name_len..].trim_start();
+
+        let value = match input.strip_prefix('=').map(str::trim_start) {
+            Some(rest) if rest.starts_with('"') || rest.starts_with('\'') => {
+                let quote = &rest[..1];
+                let end = rest[1..].find(quote).map_or(rest.len(), |i| i + 1);
+                input = rest.get(end + 1..).unwrap_or("");
+                &rest[1..end]
+            }
+            Some(rest) => {
+                let end = rest.find(char::is_whitespace).unwrap_or_else(|| rest.len());
+                input = &rest[end..];
+                &rest[..end]
+            }
+            None => "",
+        };
+
+        attributes.push((name, decode_html_entities(value)));
+    }
+}
@@ -0,0 +1,222 @@
+use vrl::prelude::*;
+
+/// The suffixes of each power of a thousand, using the short scale.
+const UNITS: &[&str] = &["", "K", "M", "B", "T"];
+
+#[derive(Clone, Copy, Debug)]
+pub struct HumanizeNumber;
+
+impl Function for HumanizeNumber {
+    fn identifier(&self) -> &'static str {
+        "humanize_number"
+    }
+
+    fn is_pure(&self) -> bool {
+        true
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[
+            Parameter {
+                keyword: "value",
+                kind: kind::INTEGER | kind::FLOAT,
+                required: true,
+            },
+            Parameter {
+                keyword: "precision",
+                kind: kind::INTEGER,
+                required: false,
+            },
+        ]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "thousands",
+                source: r#"humanize_number(1234)"#,
+                result: Ok("1.2K"),
+            },
+            Example {
+                title: "precision",
+                source: r#"humanize_number(3456789, precision: 2)"#,
+                result: Ok("3.46M"),
+            },
+        ]
+    }
+
+    fn compile(&self, mut arguments: ArgumentList) -> Compiled {
+        let value = arguments.required("value");
+        let precision = arguments.optional("precision").unwrap_or(expr!(1));
+
+        Ok(Box::new(HumanizeNumberFn { value, precision }))
+    }
+}
+
+#[derive(Debug, Clone)]
+struct HumanizeNumberFn {
+    value: Box<dyn Expression>,
+    precision: Box<dyn Expression>,
+}
+
+impl Expression for HumanizeNumberFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = match self.value.resolve(ctx)? {
+            Value::Integer(v) => v as f64,
+            Value::Float(v) => v.into_inner(),
+            value => {
+                return Err(value::Error::Expected {
+                    got: value.kind(),
+                    expected: Kind::Integer | Kind::Float,
+                }
+                .into())
+            }
+        };
+        let precision = self.precision.resolve(ctx)?.try_integer()?;
+
+        if !(0..=15).contains(&precision) {
+            return Err(format!("precision must be between 0 and 15, got {}", precision).into());
+        }
+
+        Ok(humanize(value, precision as usize).into())
+    }
+
+    fn type_def(&self, _: &state::Compiler) -> TypeDef {
+        TypeDef::new().fallible().bytes()
+    }
+}
+
+/// Abbreviates the number using the largest unit it's at least one of, with
+/// at most `precision` decimals, such as `1.2K` for `1234`.
+fn humanize(number: f64, precision: usize) -> String {
+    let mut value = number.abs();
+    let mut unit = 0;
+
+    while value >= 1000.0 && unit < UNITS.len() - 1 {
+        value /= 1000.0;
+        unit += 1;
+    }
+
+    let mut digits = format!("{:.*}", precision, value);
+
+    // Rounding can reach the next unit, as with `999.96K`, which is shown as
+    // `1M` rather than `1000K`.
+    if digits.parse::<f64>().map_or(false, |v| v >= 1000.0) && unit < UNITS.len() - 1 {
+        unit += 1;
+        digits = format!("{:.*}", precision, value / 1000.0);
+    }
+
+    if digits.contains('.') {
+        digits = digits
+            .trim_end_matches('0')
+            .trim_end_matches('.')
+            .to_owned();
+    }
+
+    let sign = if number < 0.0 && digits != "0" {
+        "-"
+    } else {
+        ""
+    };
+
+    format!("{}{}{}", sign, digits, UNITS[unit])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    test_function![
+        humanize_number => HumanizeNumber;
+
+        below_thousand {
+            args: func_args![value: 999],
+            want: Ok("999"),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+
+        thousand {
+            args: func_args![value: 1000],
+            want: Ok("1K"),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+
+        thousands {
+            args: func_args![value: 1234],
+            want: Ok("1.2K"),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+
+        below_million {
+            args: func_args![value: 999_949],
+            want: Ok("999.9K"),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+
+        rounded_to_million {
+            args: func_args![value: 999_950],
+            want: Ok("1M"),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+
+        millions {
+            args: func_args![value: 3_400_000],
+            want: Ok("3.4M"),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+
+        billions {
+            args: func_args![value: 1_500_000_000],
+            want: Ok("1.5B"),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+
+        trillions {
+            args: func_args![value: 2_000_000_000_000_i64],
+            want: Ok("2T"),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+
+        beyond_largest_unit {
+            args: func_args![value: 5_000_000_000_000_000_i64],
+            want: Ok("5000T"),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+
+        negative {
+            args: func_args![value: -1234],
+            want: Ok("-1.2K"),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+
+        negative_rounded_to_zero {
+            args: func_args![value: -0.04],
+            want: Ok("0"),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+
+        float {
+            args: func_args![value: 12.345],
+            want: Ok("12.3"),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+
+        precision {
+            args: func_args![value: 3_456_789, precision: 2],
+            want: Ok("3.46M"),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+
+        no_decimals {
+            args: func_args![value: 1_567, precision: 0],
+            want: Ok("2K"),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+
+        invalid_precision {
+            args: func_args![value: 1, precision: -1],
+            want: Err("precision must be between 0 and 15, got -1"),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+    ];
+}
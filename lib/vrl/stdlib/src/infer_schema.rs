@@ -0,0 +1,159 @@
+use crate::schema_util;
+use vrl::prelude::*;
+
+#[derive(Clone, Copy, Debug)]
+pub struct InferSchema;
+
+impl Function for InferSchema {
+    fn identifier(&self) -> &'static str {
+        "infer_schema"
+    }
+
+    fn is_pure(&self) -> bool {
+        true
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[Parameter {
+            keyword: "value",
+            kind: kind::ANY,
+            required: true,
+        }]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "object",
+                source: r#"infer_schema({ "id": 1, "tags": ["a", "b"] })"#,
+                result: Ok(
+                    r#"{ "type": "object", "properties": { "id": { "type": "integer" }, "tags": { "type": "array", "items": { "type": "string" } } }, "required": ["id", "tags"] }"#,
+                ),
+            },
+            Example {
+                title: "optional field",
+                source: r#"infer_schema([{ "id": 1, "name": "foo" }, { "id": 2 }])"#,
+                result: Ok(
+                    r#"{ "type": "array", "items": { "type": "object", "properties": { "id": { "type": "integer" }, "name": { "type": "string" } }, "required": ["id"] } }"#,
+                ),
+            },
+        ]
+    }
+
+    fn compile(&self, mut arguments: ArgumentList) -> Compiled {
+        let value = arguments.required("value");
+
+        Ok(Box::new(InferSchemaFn { value }))
+    }
+}
+
+#[derive(Debug, Clone)]
+struct InferSchemaFn {
+    value: Box<dyn Expression>,
+}
+
+impl Expression for InferSchemaFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?;
+
+        Ok(schema_util::infer(&value).into())
+    }
+
+    fn type_def(&self, _: &state::Compiler) -> TypeDef {
+        TypeDef::new()
+            .infallible()
+            .object::<(), Kind>(map! { (): Kind::all() })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{TimeZone, Utc};
+
+    test_function![
+        infer_schema => InferSchema;
+
+        nested_object {
+            args: func_args![value: value!({ "user": { "name": "foo", "age": 42 }, "active": true })],
+            want: Ok(value!({
+                "type": "object",
+                "properties": {
+                    "active": { "type": "boolean" },
+                    "user": {
+                        "type": "object",
+                        "properties": {
+                            "age": { "type": "integer" },
+                            "name": { "type": "string" }
+                        },
+                        "required": ["age", "name"]
+                    }
+                },
+                "required": ["active", "user"]
+            })),
+            tdef: TypeDef::new().infallible().object::<(), Kind>(map! { (): Kind::all() }),
+        }
+
+        heterogeneous_objects {
+            args: func_args![value: value!([{ "a": 1, "b": "foo" }, { "a": 2.5, "c": true }])],
+            want: Ok(value!({
+                "type": "array",
+                "items": {
+                    "type": "object",
+                    "properties": {
+                        "a": { "type": "number" },
+                        "b": { "type": "string" },
+                        "c": { "type": "boolean" }
+                    },
+                    "required": ["a"]
+                }
+            })),
+            tdef: TypeDef::new().infallible().object::<(), Kind>(map! { (): Kind::all() }),
+        }
+
+        mixed_array {
+            args: func_args![value: value!([1, "foo", null])],
+            want: Ok(value!({
+                "type": "array",
+                "items": { "type": ["integer", "null", "string"] }
+            })),
+            tdef: TypeDef::new().infallible().object::<(), Kind>(map! { (): Kind::all() }),
+        }
+
+        empty_array {
+            args: func_args![value: value!([])],
+            want: Ok(value!({ "type": "array" })),
+            tdef: TypeDef::new().infallible().object::<(), Kind>(map! { (): Kind::all() }),
+        }
+
+        string {
+            args: func_args![value: "foo"],
+            want: Ok(value!({ "type": "string" })),
+            tdef: TypeDef::new().infallible().object::<(), Kind>(map! { (): Kind::all() }),
+        }
+
+        integer {
+            args: func_args![value: 42],
+            want: Ok(value!({ "type": "integer" })),
+            tdef: TypeDef::new().infallible().object::<(), Kind>(map! { (): Kind::all() }),
+        }
+
+        float {
+            args: func_args![value: 4.2],
+            want: Ok(value!({ "type": "number" })),
+            tdef: TypeDef::new().infallible().object::<(), Kind>(map! { (): Kind::all() }),
+        }
+
+        null {
+            args: func_args![value: value!(null)],
+            want: Ok(value!({ "type": "null" })),
+            tdef: TypeDef::new().infallible().object::<(), Kind>(map! { (): Kind::all() }),
+        }
+
+        timestamp {
+            args: func_args![value: Utc.ymd(2021, 1, 1).and_hms(0, 0, 0)],
+            want: Ok(value!({ "type": "string", "format": "date-time" })),
+            tdef: TypeDef::new().infallible().object::<(), Kind>(map! { (): Kind::all() }),
+        }
+    ];
+}
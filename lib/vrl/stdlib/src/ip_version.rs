@@ -0,0 +1,100 @@
+use std::net::IpAddr;
+use vrl::prelude::*;
+
+#[derive(Clone, Copy, Debug)]
+pub struct IpVersion;
+
+impl Function for IpVersion {
+    fn identifier(&self) -> &'static str {
+        "ip_version"
+    }
+
+    fn is_pure(&self) -> bool {
+        true
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[Parameter {
+            keyword: "value",
+            kind: kind::BYTES,
+            required: true,
+        }]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "IPv6",
+                source: r#"ip_version("2001:db8::1")"#,
+                result: Ok("6"),
+            },
+            Example {
+                title: "not an IP",
+                source: r#"ip_version("vector.dev")"#,
+                result: Ok("null"),
+            },
+        ]
+    }
+
+    fn compile(&self, mut arguments: ArgumentList) -> Compiled {
+        let value = arguments.required("value");
+
+        Ok(Box::new(IpVersionFn { value }))
+    }
+}
+
+#[derive(Debug, Clone)]
+struct IpVersionFn {
+    value: Box<dyn Expression>,
+}
+
+impl Expression for IpVersionFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let bytes = self.value.resolve(ctx)?.try_bytes()?;
+
+        let version = match String::from_utf8_lossy(&bytes).parse::<IpAddr>() {
+            Ok(IpAddr::V4(_)) => 4.into(),
+            Ok(IpAddr::V6(_)) => 6.into(),
+            Err(_) => Value::Null,
+        };
+
+        Ok(version)
+    }
+
+    fn type_def(&self, _: &state::Compiler) -> TypeDef {
+        TypeDef::new().infallible().integer().add_null()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    test_function![
+        ip_version => IpVersion;
+
+        ipv4 {
+            args: func_args![value: "192.168.0.1"],
+            want: Ok(4),
+            tdef: TypeDef::new().infallible().integer().add_null(),
+        }
+
+        ipv6 {
+            args: func_args![value: "fd12:3456:789a::1"],
+            want: Ok(6),
+            tdef: TypeDef::new().infallible().integer().add_null(),
+        }
+
+        ipv4_mapped {
+            args: func_args![value: "::ffff:192.168.0.1"],
+            want: Ok(6),
+            tdef: TypeDef::new().infallible().integer().add_null(),
+        }
+
+        not_an_ip {
+            args: func_args![value: "localhost"],
+            want: Ok(Value::Null),
+            tdef: TypeDef::new().infallible().integer().add_null(),
+        }
+    ];
+}
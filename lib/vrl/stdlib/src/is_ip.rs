@@ -0,0 +1,109 @@
+use std::net::IpAddr;
+use vrl::prelude::*;
+
+#[derive(Clone, Copy, Debug)]
+pub struct IsIp;
+
+impl Function for IsIp {
+    fn identifier(&self) -> &'static str {
+        "is_ip"
+    }
+
+    fn is_pure(&self) -> bool {
+        true
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[Parameter {
+            keyword: "value",
+            kind: kind::BYTES,
+            required: true,
+        }]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "IPv4",
+                source: r#"is_ip("192.168.0.1")"#,
+                result: Ok("true"),
+            },
+            Example {
+                title: "not an IP",
+                source: r#"is_ip("vector.dev")"#,
+                result: Ok("false"),
+            },
+        ]
+    }
+
+    fn compile(&self, mut arguments: ArgumentList) -> Compiled {
+        let value = arguments.required("value");
+
+        Ok(Box::new(IsIpFn { value }))
+    }
+}
+
+#[derive(Debug, Clone)]
+struct IsIpFn {
+    value: Box<dyn Expression>,
+}
+
+impl Expression for IsIpFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let bytes = self.value.resolve(ctx)?.try_bytes()?;
+
+        Ok(String::from_utf8_lossy(&bytes)
+            .parse::<IpAddr>()
+            .is_ok()
+            .into())
+    }
+
+    fn type_def(&self, _: &state::Compiler) -> TypeDef {
+        TypeDef::new().infallible().boolean()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    test_function![
+        is_ip => IsIp;
+
+        ipv4 {
+            args: func_args![value: "8.8.8.8"],
+            want: Ok(true),
+            tdef: TypeDef::new().infallible().boolean(),
+        }
+
+        ipv6 {
+            args: func_args![value: "2001:db8::1"],
+            want: Ok(true),
+            tdef: TypeDef::new().infallible().boolean(),
+        }
+
+        ipv4_mapped {
+            args: func_args![value: "::ffff:192.168.0.1"],
+            want: Ok(true),
+            tdef: TypeDef::new().infallible().boolean(),
+        }
+
+        hostname {
+            args: func_args![value: "vector.dev"],
+            want: Ok(false),
+            tdef: TypeDef::new().infallible().boolean(),
+        }
+
+        out_of_range {
+            args: func_args![value: "256.0.0.1"],
+            want: Ok(false),
+            tdef: TypeDef::new().infallible().boolean(),
+        }
+
+        cidr {
+            args: func_args![value: "10.0.0.0/8"],
+            want: Ok(false),
+            tdef: TypeDef::new().infallible().boolean(),
+        }
+    ];
+}
@@ -0,0 +1,166 @@
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+use vrl::prelude::*;
+
+#[derive(Clone, Copy, Debug)]
+pub struct IsPrivateIp;
+
+impl Function for IsPrivateIp {
+    fn identifier(&self) -> &'static str {
+        "is_private_ip"
+    }
+
+    fn is_pure(&self) -> bool {
+        true
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[Parameter {
+            keyword: "value",
+            kind: kind::BYTES,
+            required: true,
+        }]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "private IPv4",
+                source: r#"is_private_ip("192.168.0.1")"#,
+                result: Ok("true"),
+            },
+            Example {
+                title: "public IPv4",
+                source: r#"is_private_ip("8.8.8.8")"#,
+                result: Ok("false"),
+            },
+        ]
+    }
+
+    fn compile(&self, mut arguments: ArgumentList) -> Compiled {
+        let value = arguments.required("value");
+
+        Ok(Box::new(IsPrivateIpFn { value }))
+    }
+}
+
+#[derive(Debug, Clone)]
+struct IsPrivateIpFn {
+    value: Box<dyn Expression>,
+}
+
+impl Expression for IsPrivateIpFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let bytes = self.value.resolve(ctx)?.try_bytes()?;
+
+        let private = match String::from_utf8_lossy(&bytes).parse::<IpAddr>() {
+            Ok(IpAddr::V4(addr)) => is_private_ipv4(&addr),
+            Ok(IpAddr::V6(addr)) => is_private_ipv6(&addr),
+            Err(_) => false,
+        };
+
+        Ok(private.into())
+    }
+
+    fn type_def(&self, _: &state::Compiler) -> TypeDef {
+        TypeDef::new().infallible().boolean()
+    }
+}
+
+/// Returns `true` for the RFC 1918 private ranges and the loopback range.
+fn is_private_ipv4(addr: &Ipv4Addr) -> bool {
+    addr.is_private() || addr.is_loopback()
+}
+
+/// Returns `true` for unique local addresses (`fc00::/7`), the loopback
+/// address, and IPv4-mapped addresses of private IPv4 addresses.
+fn is_private_ipv6(addr: &Ipv6Addr) -> bool {
+    let segments = addr.segments();
+
+    if let [0, 0, 0, 0, 0, 0xffff, _, _] = segments {
+        let [.., a, b, c, d] = addr.octets();
+        return is_private_ipv4(&Ipv4Addr::new(a, b, c, d));
+    }
+
+    (segments[0] & 0xfe00) == 0xfc00 || addr.is_loopback()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    test_function![
+        is_private_ip => IsPrivateIp;
+
+        rfc1918_10 {
+            args: func_args![value: "10.1.2.3"],
+            want: Ok(true),
+            tdef: TypeDef::new().infallible().boolean(),
+        }
+
+        rfc1918_172 {
+            args: func_args![value: "172.31.255.255"],
+            want: Ok(true),
+            tdef: TypeDef::new().infallible().boolean(),
+        }
+
+        outside_rfc1918_172 {
+            args: func_args![value: "172.32.0.1"],
+            want: Ok(false),
+            tdef: TypeDef::new().infallible().boolean(),
+        }
+
+        rfc1918_192 {
+            args: func_args![value: "192.168.0.1"],
+            want: Ok(true),
+            tdef: TypeDef::new().infallible().boolean(),
+        }
+
+        public_ipv4 {
+            args: func_args![value: "8.8.8.8"],
+            want: Ok(false),
+            tdef: TypeDef::new().infallible().boolean(),
+        }
+
+        loopback_ipv4 {
+            args: func_args![value: "127.0.0.1"],
+            want: Ok(true),
+            tdef: TypeDef::new().infallible().boolean(),
+        }
+
+        ula {
+            args: func_args![value: "fd12:3456:789a::1"],
+            want: Ok(true),
+            tdef: TypeDef::new().infallible().boolean(),
+        }
+
+        loopback_ipv6 {
+            args: func_args![value: "::1"],
+            want: Ok(true),
+            tdef: TypeDef::new().infallible().boolean(),
+        }
+
+        public_ipv6 {
+            args: func_args![value: "2606:4700:4700::1111"],
+            want: Ok(false),
+            tdef: TypeDef::new().infallible().boolean(),
+        }
+
+        mapped_private_ipv4 {
+            args: func_args![value: "::ffff:10.0.0.1"],
+            want: Ok(true),
+            tdef: TypeDef::new().infallible().boolean(),
+        }
+
+        mapped_public_ipv4 {
+            args: func_args![value: "::ffff:8.8.8.8"],
+            want: Ok(false),
+            tdef: TypeDef::new().infallible().boolean(),
+        }
+
+        not_an_ip {
+            args: func_args![value: "intranet.local"],
+            want: Ok(false),
+            tdef: TypeDef::new().infallible().boolean(),
+        }
+    ];
+}
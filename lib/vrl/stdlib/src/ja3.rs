@@ -0,0 +1,84 @@
+use crate::ja3_util;
+use md5::Digest;
+use vrl::prelude::*;
+
+#[derive(Clone, Copy, Debug)]
+pub struct Ja3;
+
+impl Function for Ja3 {
+    fn identifier(&self) -> &'static str {
+        "ja3"
+    }
+
+    fn is_pure(&self) -> bool {
+        true
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[Parameter {
+            keyword: "value",
+            kind: kind::BYTES,
+            required: true,
+        }]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[Example {
+            title: "ClientHello",
+            source: r#"ja3!(decode_base64!("FgMBAHEBAABtAwEAAQIDBAUGBwgJCgsMDQ4PEBESExQVFhcYGRobHB0eHwAAGgoKAC8ANQAFAArACcAKwBPAFAAyADgAEwAEAQAAKhoaAAAAAAAQAA4AAAtleGFtcGxlLmNvbQAKAAgABgAXABgAGQALAAIBAA=="))"#,
+            result: Ok("ada70206e40642a3e4461f35503241d5"),
+        }]
+    }
+
+    fn compile(&self, mut arguments: ArgumentList) -> Compiled {
+        let value = arguments.required("value");
+
+        Ok(Box::new(Ja3Fn { value }))
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Ja3Fn {
+    value: Box<dyn Expression>,
+}
+
+impl Expression for Ja3Fn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?.try_bytes()?;
+        let ja3 = ja3_util::ja3_string(&value)?;
+
+        Ok(hex::encode(md5::Md5::digest(ja3.as_bytes())).into())
+    }
+
+    fn type_def(&self, _: &state::Compiler) -> TypeDef {
+        TypeDef::new().fallible().bytes()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ja3_util::CLIENT_HELLO;
+
+    test_function![
+        ja3 => Ja3;
+
+        client_hello {
+            args: func_args![value: Bytes::from_static(CLIENT_HELLO)],
+            want: Ok("ada70206e40642a3e4461f35503241d5"),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+
+        truncated {
+            args: func_args![value: Bytes::copy_from_slice(&CLIENT_HELLO[..60])],
+            want: Err("invalid TLS ClientHello: truncated message"),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+
+        not_client_hello {
+            args: func_args![value: Bytes::from_static(b"\x16\x03\x03\x00\x04\x02\x00\x00\x00")],
+            want: Err("invalid TLS ClientHello: expected handshake type 1, got 2"),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+    ];
+}
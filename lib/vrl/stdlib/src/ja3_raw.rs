@@ -0,0 +1,76 @@
+use crate::ja3_util;
+use vrl::prelude::*;
+
+#[derive(Clone, Copy, Debug)]
+pub struct Ja3Raw;
+
+impl Function for Ja3Raw {
+    fn identifier(&self) -> &'static str {
+        "ja3_raw"
+    }
+
+    fn is_pure(&self) -> bool {
+        true
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[Parameter {
+            keyword: "value",
+            kind: kind::BYTES,
+            required: true,
+        }]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[Example {
+            title: "ClientHello",
+            source: r#"ja3_raw!(decode_base64!("FgMBAHEBAABtAwEAAQIDBAUGBwgJCgsMDQ4PEBESExQVFhcYGRobHB0eHwAAGgoKAC8ANQAFAArACcAKwBPAFAAyADgAEwAEAQAAKhoaAAAAAAAQAA4AAAtleGFtcGxlLmNvbQAKAAgABgAXABgAGQALAAIBAA=="))"#,
+            result: Ok("769,47-53-5-10-49161-49162-49171-49172-50-56-19-4,0-10-11,23-24-25,0"),
+        }]
+    }
+
+    fn compile(&self, mut arguments: ArgumentList) -> Compiled {
+        let value = arguments.required("value");
+
+        Ok(Box::new(Ja3RawFn { value }))
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Ja3RawFn {
+    value: Box<dyn Expression>,
+}
+
+impl Expression for Ja3RawFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?.try_bytes()?;
+
+        Ok(ja3_util::ja3_string(&value)?.into())
+    }
+
+    fn type_def(&self, _: &state::Compiler) -> TypeDef {
+        TypeDef::new().fallible().bytes()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ja3_util::CLIENT_HELLO;
+
+    test_function![
+        ja3_raw => Ja3Raw;
+
+        client_hello {
+            args: func_args![value: Bytes::from_static(CLIENT_HELLO)],
+            want: Ok("769,47-53-5-10-49161-49162-49171-49172-50-56-19-4,0-10-11,23-24-25,0"),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+
+        malformed {
+            args: func_args![value: "GET / HTTP/1.1"],
+            want: Err("invalid TLS ClientHello: expected handshake type 1, got 71"),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+    ];
+}
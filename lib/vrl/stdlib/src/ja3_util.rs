@@ -0,0 +1,198 @@
+//! The [JA3](https://github.com/salesforce/ja3) fingerprint of TLS
+//! ClientHello messages.
+
+/// A ClientHello in a TLS record, whose JA3 string is
+/// `769,47-53-5-10-49161-49162-49171-49172-50-56-19-4,0-10-11,23-24-25,0`,
+/// the example of the JA3 documentation. It also has a GREASE cipher suite and
+/// extension, which are left out of the fingerprint.
+#[cfg(test)]
+pub(crate) const CLIENT_HELLO: &[u8] = b"\x16\x03\x01\x00\x71\x01\x00\x00\x6d\x03\x01\
+    \x00\x01\x02\x03\x04\x05\x06\x07\x08\x09\x0a\x0b\x0c\x0d\x0e\x0f\
+    \x10\x11\x12\x13\x14\x15\x16\x17\x18\x19\x1a\x1b\x1c\x1d\x1e\x1f\x00\
+    \x00\x1a\x0a\x0a\x00\x2f\x00\x35\x00\x05\x00\x0a\xc0\x09\xc0\x0a\xc0\x13\xc0\x14\
+    \x00\x32\x00\x38\x00\x13\x00\x04\x01\x00\
+    \x00\x2a\x1a\x1a\x00\x00\
+    \x00\x00\x00\x10\x00\x0e\x00\x00\x0bexample.com\
+    \x00\x0a\x00\x08\x00\x06\x00\x17\x00\x18\x00\x19\
+    \x00\x0b\x00\x02\x01\x00";
+
+const RECORD_HANDSHAKE: u8 = 0x16;
+const HANDSHAKE_CLIENT_HELLO: u8 = 0x01;
+
+const EXTENSION_SUPPORTED_GROUPS: u16 = 10;
+const EXTENSION_EC_POINT_FORMATS: u16 = 11;
+
+/// Returns the JA3 string of the ClientHello, either in a TLS record or as a
+/// bare handshake message:
+/// `SSLVersion,Ciphers,Extensions,EllipticCurves,EllipticCurvePointFormats`,
+/// the values of each field being separated by dashes.
+pub(crate) fn ja3_string(message: &[u8]) -> Result<String, String> {
+    client_hello(message).map_err(|err| format!("invalid TLS ClientHello: {}", err))
+}
+
+fn client_hello(message: &[u8]) -> Result<String, String> {
+    let mut reader = Reader::new(message);
+
+    // The record header is the type, the version and the length of the
+    // record, which can hold only part of the handshake message, so only the
+    // length of the handshake message is relied upon.
+    if message.first() == Some(&RECORD_HANDSHAKE) {
+        reader.bytes(5)?;
+    }
+
+    match reader.u8()? {
+        HANDSHAKE_CLIENT_HELLO => {}
+        kind => return Err(format!("expected handshake type 1, got {}", kind)),
+    }
+
+    let len = reader.u24()?;
+    let mut hello = Reader::new(reader.bytes(len)?);
+
+    let version = hello.u16()?;
+    hello.bytes(32)?;
+
+    let session_id_len = usize::from(hello.u8()?);
+    hello.bytes(session_id_len)?;
+
+    let cipher_suites_len = usize::from(hello.u16()?);
+    let ciphers = Reader::new(hello.bytes(cipher_suites_len)?).u16s()?;
+
+    let compression_methods_len = usize::from(hello.u8()?);
+    hello.bytes(compression_methods_len)?;
+
+    let mut extensions = vec![];
+    let mut groups = vec![];
+    let mut point_formats = vec![];
+
+    // Extensions are optional.
+    if !hello.is_empty() {
+        let extensions_len = usize::from(hello.u16()?);
+        let mut reader = Reader::new(hello.bytes(extensions_len)?);
+
+        while !reader.is_empty() {
+            let kind = reader.u16()?;
+            let len = usize::from(reader.u16()?);
+            let mut data = Reader::new(reader.bytes(len)?);
+
+            match kind {
+                EXTENSION_SUPPORTED_GROUPS => {
+                    let len = usize::from(data.u16()?);
+                    groups = Reader::new(data.bytes(len)?).u16s()?;
+                }
+                EXTENSION_EC_POINT_FORMATS => {
+                    let len = usize::from(data.u8()?);
+                    point_formats = data.bytes(len)?.iter().map(|f| u16::from(*f)).collect();
+                }
+                _ => {}
+            }
+
+            extensions.push(kind);
+        }
+    }
+
+    Ok(format!(
+        "{},{},{},{},{}",
+        version,
+        join(&ciphers),
+        join(&extensions),
+        join(&groups),
+        join(&point_formats),
+    ))
+}
+
+/// Joins the values with dashes, leaving out the GREASE values of RFC 8701,
+/// which clients pick at random.
+fn join(values: &[u16]) -> String {
+    values
+        .iter()
+        .filter(|value| !is_grease(**value))
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join("-")
+}
+
+fn is_grease(value: u16) -> bool {
+    value & 0x0f0f == 0x0a0a && value >> 8 == value & 0xff
+}
+
+struct Reader<'a> {
+    input: &'a [u8],
+    position: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn new(input: &'a [u8]) -> Self {
+        Self { input, position: 0 }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.position >= self.input.len()
+    }
+
+    fn bytes(&mut self, len: usize) -> Result<&'a [u8], String> {
+        let bytes = self
+            .input
+            .get(self.position..self.position + len)
+            .ok_or("truncated message")?;
+        self.position += len;
+
+        Ok(bytes)
+    }
+
+    fn u8(&mut self) -> Result<u8, String> {
+        Ok(self.bytes(1)?[0])
+    }
+
+    fn u16(&mut self) -> Result<u16, String> {
+        let bytes = self.bytes(2)?;
+
+        Ok(u16::from_be_bytes([bytes[0], bytes[1]]))
+    }
+
+    fn u24(&mut self) -> Result<usize, String> {
+        let bytes = self.bytes(3)?;
+
+        Ok((usize::from(bytes[0]) << 16) | (usize::from(bytes[1]) << 8) | usize::from(bytes[2]))
+    }
+
+    /// Reads the rest of the input as a list of 16-bit values.
+    fn u16s(&mut self) -> Result<Vec<u16>, String> {
+        let mut values = vec![];
+        while !self.is_empty() {
+            values.push(self.u16()?);
+        }
+
+        Ok(values)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn grease() {
+        assert!(is_grease(0x0a0a));
+        assert!(is_grease(0xfafa));
+        assert!(!is_grease(0x0a1a));
+        assert!(!is_grease(0x002f));
+    }
+
+    #[test]
+    fn bare_handshake() {
+        assert_eq!(
+            ja3_string(&CLIENT_HELLO[5..]).unwrap(),
+            "769,47-53-5-10-49161-49162-49171-49172-50-56-19-4,0-10-11,23-24-25,0"
+        );
+    }
+
+    #[test]
+    fn without_extensions() {
+        let hello = b"\x01\x00\x00\x29\x03\x03\
+            \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+            \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+            \x00\x00\x02\x00\x2f\x01\x00";
+
+        assert_eq!(ja3_string(hello).unwrap(), "771,47,,,");
+    }
+}
@@ -0,0 +1,259 @@
+use crate::jmespath_util::{self, Ast, ParseError};
+use std::fmt;
+use vrl::{
+    diagnostic::{Label, Span},
+    prelude::*,
+};
+
+#[derive(Debug)]
+pub enum Error {
+    InvalidExpression {
+        expression: String,
+        error: ParseError,
+    },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::InvalidExpression { expression, error } => {
+                write!(f, "invalid expression {:?}: {}", expression, error)
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl DiagnosticError for Error {
+    fn code(&self) -> usize {
+        109
+    }
+
+    fn labels(&self) -> Vec<Label> {
+        vec![Label::primary(self.to_string(), Span::default())]
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Jmespath;
+
+impl Function for Jmespath {
+    fn identifier(&self) -> &'static str {
+        "jmespath"
+    }
+
+    fn is_pure(&self) -> bool {
+        true
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[
+            Parameter {
+                keyword: "value",
+                kind: kind::ANY,
+                required: true,
+            },
+            Parameter {
+                keyword: "expression",
+                kind: kind::BYTES,
+                required: true,
+            },
+        ]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "projection",
+                source: r#"jmespath!({ "people": [{ "name": "foo" }, { "name": "bar" }] }, "people[*].name")"#,
+                result: Ok(r#"["foo", "bar"]"#),
+            },
+            Example {
+                title: "filter",
+                source: r#"jmespath!({ "people": [{ "name": "foo", "age": 30 }, { "name": "bar", "age": 20 }] }, "people[?age > `25`].name | [0]")"#,
+                result: Ok("foo"),
+            },
+            Example {
+                title: "function",
+                source: r#"jmespath!({ "people": [{ "name": "foo", "age": 30 }, { "name": "bar", "age": 20 }] }, "sort_by(people, &age)[*].name")"#,
+                result: Ok(r#"["bar", "foo"]"#),
+            },
+            Example {
+                title: "invalid function argument",
+                source: r#"jmespath!({ "foo": 1 }, "length(foo)")"#,
+                result: Err(
+                    r#"function call error for "jmespath" at (0:38): argument 1 of function "length" must be an array, an object or a string, got number"#,
+                ),
+            },
+        ]
+    }
+
+    fn compile(&self, mut arguments: ArgumentList) -> Compiled {
+        let value = arguments.required("value");
+        let expression = arguments.required("expression");
+
+        // A literal expression is parsed once, here, so that an invalid one
+        // is reported at compile-time.
+        let expression = match expression.as_value() {
+            Some(expression) => {
+                let expression = expression
+                    .try_bytes_utf8_lossy()
+                    .map_err(|e| Box::new(e) as Box<dyn DiagnosticError>)?;
+                let ast = parse_expression(&expression)
+                    .map_err(|e| Box::new(e) as Box<dyn DiagnosticError>)?;
+
+                JmespathExpression::Parsed(ast)
+            }
+            None => JmespathExpression::Dynamic(expression),
+        };
+
+        Ok(Box::new(JmespathFn { value, expression }))
+    }
+}
+
+fn parse_expression(expression: &str) -> std::result::Result<Ast, Error> {
+    jmespath_util::parse(expression).map_err(|error| Error::InvalidExpression {
+        expression: expression.to_owned(),
+        error,
+    })
+}
+
+#[derive(Debug, Clone)]
+enum JmespathExpression {
+    Parsed(Ast),
+    Dynamic(Box<dyn Expression>),
+}
+
+#[derive(Debug, Clone)]
+struct JmespathFn {
+    value: Box<dyn Expression>,
+    expression: JmespathExpression,
+}
+
+impl Expression for JmespathFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?;
+
+        let result = match &self.expression {
+            JmespathExpression::Parsed(ast) => jmespath_util::search(ast, &value)?,
+            JmespathExpression::Dynamic(expression) => {
+                let expression = expression.resolve(ctx)?;
+                let expression = expression.try_bytes_utf8_lossy()?;
+                let ast = parse_expression(&expression).map_err(|e| e.to_string())?;
+
+                jmespath_util::search(&ast, &value)?
+            }
+        };
+
+        Ok(result)
+    }
+
+    fn type_def(&self, _: &state::Compiler) -> TypeDef {
+        // Functions of expressions fail on arguments of the wrong type.
+        TypeDef::new().fallible().unknown()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    test_function![
+        jmespath => Jmespath;
+
+        projection {
+            args: func_args![
+                value: value!({ "people": [{ "name": "foo" }, { "name": "bar" }, { "age": 42 }] }),
+                expression: "people[*].name",
+            ],
+            want: Ok(value!(["foo", "bar"])),
+            tdef: TypeDef::new().fallible().unknown(),
+        }
+
+        filter {
+            args: func_args![
+                value: value!({ "people": [{ "name": "foo", "age": 30 }, { "name": "bar", "age": 20 }, { "name": "baz", "age": 40 }] }),
+                expression: "people[?age >= `30` && name != 'baz'].name",
+            ],
+            want: Ok(value!(["foo"])),
+            tdef: TypeDef::new().fallible().unknown(),
+        }
+
+        index_and_slice {
+            args: func_args![value: value!({ "items": [0, 1, 2, 3, 4] }), expression: "[items[-1], items[1:4:2]]"],
+            want: Ok(value!([4, [1, 3]])),
+            tdef: TypeDef::new().fallible().unknown(),
+        }
+
+        wildcard_and_flatten {
+            args: func_args![
+                value: value!({ "a": { "tags": ["x", "y"] }, "b": { "tags": ["z"] } }),
+                expression: "*.tags[]",
+            ],
+            want: Ok(value!(["x", "y", "z"])),
+            tdef: TypeDef::new().fallible().unknown(),
+        }
+
+        multiselect_hash {
+            args: func_args![
+                value: value!({ "user": { "name": "foo", "id": 1 } }),
+                expression: "user.{ name: name, key: to_string(id) }",
+            ],
+            want: Ok(value!({ "name": "foo", "key": "1" })),
+            tdef: TypeDef::new().fallible().unknown(),
+        }
+
+        pipe_and_functions {
+            args: func_args![
+                value: value!({ "people": [{ "name": "foo", "age": 30 }, { "name": "bar", "age": 20 }] }),
+                expression: "sort_by(people, &age)[*].name | join(', ', @)",
+            ],
+            want: Ok("bar, foo"),
+            tdef: TypeDef::new().fallible().unknown(),
+        }
+
+        missing {
+            args: func_args![value: value!({ "foo": 1 }), expression: "bar.baz[0]"],
+            want: Ok(value!(null)),
+            tdef: TypeDef::new().fallible().unknown(),
+        }
+
+        invalid_function_argument {
+            args: func_args![value: value!({ "foo": 1 }), expression: "length(foo)"],
+            want: Err(r#"argument 1 of function "length" must be an array, an object or a string, got number"#),
+            tdef: TypeDef::new().fallible().unknown(),
+        }
+
+        malformed_expression {
+            args: func_args![value: value!({ "people": [] }), expression: "people[?age > `25`"],
+            want: Err(r#"invalid expression "people[?age > `25`": expected token "]", got end of expression at position 18"#),
+            tdef: TypeDef::new().fallible().unknown(),
+        }
+    ];
+
+    #[test]
+    fn dynamic_expression() {
+        let mut target = value!({});
+        let mut runtime_state = vrl::state::Runtime::default();
+        let mut ctx = Context::new(&mut target, &mut runtime_state);
+
+        let func = JmespathFn {
+            value: expr!({ "foo": { "bar": 1 } }),
+            expression: JmespathExpression::Dynamic(expr!("foo.bar")),
+        };
+        assert_eq!(func.resolve(&mut ctx), Ok(1.into()));
+
+        let func = JmespathFn {
+            value: expr!({ "foo": { "bar": 1 } }),
+            expression: JmespathExpression::Dynamic(expr!("foo.")),
+        };
+        assert_eq!(
+            func.resolve(&mut ctx).map_err(|e| e.to_string()),
+            Err(
+                r#"invalid expression "foo.": expected an identifier, "*", "[" or "{", got end of expression at position 4"#
+                    .to_owned()
+            )
+        );
+    }
+}
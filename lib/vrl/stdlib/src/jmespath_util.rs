@@ -0,0 +1,1375 @@
+//! Parsing and evaluation of [JMESPath](https://jmespath.org/specification.html)
+//! expressions, a query language for JSON.
+//!
+//! The whole grammar is supported, along with the built-in functions of the
+//! specification. Values that aren't JSON, timestamps and regular
+//! expressions, are only ever passed through.
+
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, BTreeSet};
+use std::convert::TryFrom;
+use std::fmt;
+use vrl::Value;
+
+/// A parsed expression.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum Ast {
+    /// `@`, the current value.
+    Identity,
+    Field(String),
+    Index(i64),
+    Slice {
+        start: Option<i64>,
+        stop: Option<i64>,
+        step: Option<i64>,
+    },
+    Literal(Value),
+
+    /// Evaluates the right-hand side against the result of the left-hand
+    /// side.
+    Subexpr(Box<Ast>, Box<Ast>),
+
+    /// Evaluates the right-hand side against each element of the array the
+    /// left-hand side evaluates to, leaving out null results.
+    Projection(Box<Ast>, Box<Ast>),
+
+    /// Like a projection, of the values of the object the left-hand side
+    /// evaluates to.
+    ValueProjection(Box<Ast>, Box<Ast>),
+
+    /// Like a projection, of the elements for which the condition is truthy.
+    FilterProjection {
+        lhs: Box<Ast>,
+        condition: Box<Ast>,
+        rhs: Box<Ast>,
+    },
+
+    /// Flattens the array by one level.
+    Flatten(Box<Ast>),
+    MultiList(Vec<Ast>),
+    MultiHash(Vec<(String, Ast)>),
+    Pipe(Box<Ast>, Box<Ast>),
+    Or(Box<Ast>, Box<Ast>),
+    And(Box<Ast>, Box<Ast>),
+    Not(Box<Ast>),
+    Comparison(Comparator, Box<Ast>, Box<Ast>),
+    Function(String, Vec<Ast>),
+
+    /// `&expression`, an expression passed unevaluated to a function.
+    Expref(Box<Ast>),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Comparator {
+    Equal,
+    NotEqual,
+    Less,
+    LessEqual,
+    Greater,
+    GreaterEqual,
+}
+
+/// An error parsing an expression, at the byte offset of the token at which
+/// it was found.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParseError {
+    message: String,
+    position: usize,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at position {}", self.message, self.position)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+pub(crate) fn parse(expression: &str) -> Result<Ast, ParseError> {
+    let mut parser = Parser {
+        tokens: tokenize(expression)?,
+        index: 0,
+        depth: 0,
+    };
+
+    let ast = parser.expression(0)?;
+    match parser.peek() {
+        Token::Eof => Ok(ast),
+        token => Err(parser.error(format!("unexpected {}", token))),
+    }
+}
+
+// Lexing ----------------------------------------------------------------------
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Identifier(String),
+    QuotedIdentifier(String),
+    Number(i64),
+    Literal(Value),
+    Dot,
+    Star,
+    Flatten,
+    Filter,
+    LeftBracket,
+    RightBracket,
+    LeftBrace,
+    RightBrace,
+    LeftParen,
+    RightParen,
+    Comma,
+    Colon,
+    Pipe,
+    Or,
+    And,
+    Not,
+    At,
+    Ampersand,
+    Comparator(Comparator),
+    Eof,
+}
+
+impl Token {
+    /// The binding power of the token as an infix operator, how tightly it
+    /// binds to the expression on its left.
+    fn binding_power(&self) -> u8 {
+        match self {
+            Token::Pipe => 1,
+            Token::Or => 2,
+            Token::And => 3,
+            Token::Comparator(_) => 5,
+            Token::Flatten => 9,
+            Token::Star => 20,
+            Token::Filter => 21,
+            Token::Dot => 40,
+            Token::Not => 45,
+            Token::LeftBrace => 50,
+            Token::LeftBracket => 55,
+            Token::LeftParen => 60,
+            _ => 0,
+        }
+    }
+}
+
+impl fmt::Display for Token {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let token = match self {
+            Token::Identifier(identifier) => return write!(f, "identifier {:?}", identifier),
+            Token::QuotedIdentifier(identifier) => {
+                return write!(f, "quoted identifier {:?}", identifier)
+            }
+            Token::Number(number) => return write!(f, "number {}", number),
+            Token::Literal(_) => return f.write_str("literal"),
+            Token::Eof => return f.write_str("end of expression"),
+            Token::Dot => ".",
+            Token::Star => "*",
+            Token::Flatten => "[]",
+            Token::Filter => "[?",
+            Token::LeftBracket => "[",
+            Token::RightBracket => "]",
+            Token::LeftBrace => "{",
+            Token::RightBrace => "}",
+            Token::LeftParen => "(",
+            Token::RightParen => ")",
+            Token::Comma => ",",
+            Token::Colon => ":",
+            Token::Pipe => "|",
+            Token::Or => "||",
+            Token::And => "&&",
+            Token::Not => "!",
+            Token::At => "@",
+            Token::Ampersand => "&",
+            Token::Comparator(Comparator::Equal) => "==",
+            Token::Comparator(Comparator::NotEqual) => "!=",
+            Token::Comparator(Comparator::Less) => "<",
+            Token::Comparator(Comparator::LessEqual) => "<=",
+            Token::Comparator(Comparator::Greater) => ">",
+            Token::Comparator(Comparator::GreaterEqual) => ">=",
+        };
+
+        write!(f, "token {:?}", token)
+    }
+}
+
+/// Splits the expression into tokens, each with its byte offset. The last
+/// token is always [`Token::Eof`].
+fn tokenize(expression: &str) -> Result<Vec<(usize, Token)>, ParseError> {
+    let error = |message: &str, position| ParseError {
+        message: message.to_owned(),
+        position,
+    };
+
+    let chars = expression.char_indices().collect::<Vec<_>>();
+    let offset = |i: usize| {
+        chars
+            .get(i)
+            .map_or(expression.len(), |(position, _)| *position)
+    };
+
+    let mut tokens = vec![];
+    let mut i = 0;
+
+    while let Some(&(position, c)) = chars.get(i) {
+        i += 1;
+
+        let token = match c {
+            ' ' | '\t' | '\n' | '\r' => continue,
+            '.' => Token::Dot,
+            '*' => Token::Star,
+            ']' => Token::RightBracket,
+            '{' => Token::LeftBrace,
+            '}' => Token::RightBrace,
+            '(' => Token::LeftParen,
+            ')' => Token::RightParen,
+            ',' => Token::Comma,
+            ':' => Token::Colon,
+            '@' => Token::At,
+            '[' | '|' | '&' | '!' | '<' | '>' | '=' => {
+                let next = chars.get(i).map(|(_, c)| *c);
+                let (token, len) = match (c, next) {
+                    ('[', Some(']')) => (Token::Flatten, 1),
+                    ('[', Some('?')) => (Token::Filter, 1),
+                    ('[', _) => (Token::LeftBracket, 0),
+                    ('|', Some('|')) => (Token::Or, 1),
+                    ('|', _) => (Token::Pipe, 0),
+                    ('&', Some('&')) => (Token::And, 1),
+                    ('&', _) => (Token::Ampersand, 0),
+                    ('!', Some('=')) => (Token::Comparator(Comparator::NotEqual), 1),
+                    ('!', _) => (Token::Not, 0),
+                    ('<', Some('=')) => (Token::Comparator(Comparator::LessEqual), 1),
+                    ('<', _) => (Token::Comparator(Comparator::Less), 0),
+                    ('>', Some('=')) => (Token::Comparator(Comparator::GreaterEqual), 1),
+                    ('>', _) => (Token::Comparator(Comparator::Greater), 0),
+                    ('=', Some('=')) => (Token::Comparator(Comparator::Equal), 1),
+                    _ => return Err(error(r#"expected "==""#, position)),
+                };
+                i += len;
+
+                token
+            }
+            c if c.is_ascii_alphabetic() || c == '_' => {
+                while chars
+                    .get(i)
+                    .map_or(false, |(_, c)| c.is_ascii_alphanumeric() || *c == '_')
+                {
+                    i += 1;
+                }
+
+                Token::Identifier(expression[position..offset(i)].to_owned())
+            }
+            c if c.is_ascii_digit() || c == '-' => {
+                while chars.get(i).map_or(false, |(_, c)| c.is_ascii_digit()) {
+                    i += 1;
+                }
+
+                Token::Number(
+                    expression[position..offset(i)]
+                        .parse()
+                        .map_err(|_| error("invalid number", position))?,
+                )
+            }
+            '"' | '\'' | '`' => {
+                let (contents, end) = delimited(&chars, i, c).ok_or_else(|| match c {
+                    '"' => error("unterminated quoted identifier", position),
+                    '\'' => error("unterminated raw string", position),
+                    _ => error("unterminated literal", position),
+                })?;
+                i = end;
+
+                match c {
+                    '"' => Token::QuotedIdentifier(
+                        unescape(&contents).map_err(|message| error(&message, position))?,
+                    ),
+                    '\'' => Token::Literal(contents.replace("\\'", "'").into()),
+                    _ => Token::Literal(parse_json(&contents.replace("\\`", "`")).map_err(
+                        |message| error(&format!("invalid literal: {}", message), position),
+                    )?),
+                }
+            }
+            c => return Err(error(&format!("unexpected character {:?}", c), position)),
+        };
+
+        tokens.push((position, token));
+    }
+
+    tokens.push((expression.len(), Token::Eof));
+
+    Ok(tokens)
+}
+
+/// Scans the contents of a string delimited by the quote, from after the
+/// opening quote, returning them with their backslashes and the index after
+/// the closing quote.
+fn delimited(chars: &[(usize, char)], mut i: usize, quote: char) -> Option<(String, usize)> {
+    let mut contents = String::new();
+
+    while let Some(&(_, c)) = chars.get(i) {
+        i += 1;
+        if c == quote {
+            return Some((contents, i));
+        }
+
+        contents.push(c);
+        if c == '\\' {
+            let &(_, escaped) = chars.get(i)?;
+            contents.push(escaped);
+            i += 1;
+        }
+    }
+
+    None
+}
+
+/// Unescapes the contents of a quoted identifier, which are escaped as JSON
+/// strings are.
+fn unescape(raw: &str) -> Result<String, String> {
+    let mut string = String::new();
+    let mut chars = raw.chars();
+
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            string.push(c);
+            continue;
+        }
+
+        let c = match chars.next() {
+            Some('"') => '"',
+            Some('\\') => '\\',
+            Some('/') => '/',
+            Some('b') => '\u{08}',
+            Some('f') => '\u{0c}',
+            Some('n') => '\n',
+            Some('r') => '\r',
+            Some('t') => '\t',
+            Some('u') => {
+                let mut code = hex4(&mut chars)?;
+
+                // Characters outside of the basic multilingual plane are
+                // escaped as surrogate pairs.
+                if (0xd800..0xdc00).contains(&code) {
+                    if chars.next() != Some('\\') || chars.next() != Some('u') {
+                        return Err("invalid surrogate pair".to_owned());
+                    }
+                    let low = hex4(&mut chars)?;
+                    if !(0xdc00..0xe000).contains(&low) {
+                        return Err("invalid surrogate pair".to_owned());
+                    }
+                    code = 0x10000 + ((code - 0xd800) << 10) + (low - 0xdc00);
+                }
+
+                std::char::from_u32(code).ok_or("invalid unicode escape")?
+            }
+            _ => return Err("invalid escape".to_owned()),
+        };
+
+        string.push(c);
+    }
+
+    Ok(string)
+}
+
+fn hex4(chars: &mut std::str::Chars<'_>) -> Result<u32, String> {
+    let digits = chars.take(4).collect::<String>();
+    if digits.len() != 4 {
+        return Err("invalid unicode escape".to_owned());
+    }
+
+    u32::from_str_radix(&digits, 16).map_err(|_| "invalid unicode escape".to_owned())
+}
+
+fn parse_json(json: &str) -> Result<Value, String> {
+    serde_json::from_str(json).map_err(|err| err.to_string())
+}
+
+fn to_json(value: &Value) -> String {
+    serde_json::to_string(value).expect("valid JSON")
+}
+
+// Parsing ---------------------------------------------------------------------
+
+/// A Pratt parser, every token has a binding power, and can start an
+/// expression, continue one, or both.
+struct Parser {
+    tokens: Vec<(usize, Token)>,
+    index: usize,
+    depth: usize,
+}
+
+/// Tokens binding less than this end the right-hand side of a projection.
+const PROJECTION_STOP: u8 = 10;
+
+/// How deeply expressions can be nested.
+const MAX_DEPTH: usize = 128;
+
+impl Parser {
+    fn peek(&self) -> &Token {
+        &self.tokens[self.index].1
+    }
+
+    fn peek_at(&self, offset: usize) -> &Token {
+        let index = (self.index + offset).min(self.tokens.len() - 1);
+        &self.tokens[index].1
+    }
+
+    fn next(&mut self) -> Token {
+        let token = self.tokens[self.index].1.clone();
+        if self.index < self.tokens.len() - 1 {
+            self.index += 1;
+        }
+
+        token
+    }
+
+    fn error(&self, message: String) -> ParseError {
+        ParseError {
+            message,
+            position: self.tokens[self.index].0,
+        }
+    }
+
+    fn expect(&mut self, expected: Token) -> Result<(), ParseError> {
+        if *self.peek() == expected {
+            self.next();
+            Ok(())
+        } else {
+            Err(self.error(format!("expected {}, got {}", expected, self.peek())))
+        }
+    }
+
+    fn expression(&mut self, binding_power: u8) -> Result<Ast, ParseError> {
+        self.depth += 1;
+        if self.depth > MAX_DEPTH {
+            return Err(self.error("expression nested too deeply".to_owned()));
+        }
+
+        let mut lhs = self.prefix()?;
+        while binding_power < self.peek().binding_power() {
+            lhs = self.infix(lhs)?;
+        }
+
+        self.depth -= 1;
+
+        Ok(lhs)
+    }
+
+    /// Parses an expression starting with the next token.
+    fn prefix(&mut self) -> Result<Ast, ParseError> {
+        let ast = match self.peek().clone() {
+            Token::Identifier(name) | Token::QuotedIdentifier(name) => {
+                self.next();
+                Ast::Field(name)
+            }
+            Token::Literal(value) => {
+                self.next();
+                Ast::Literal(value)
+            }
+            Token::At => {
+                self.next();
+                Ast::Identity
+            }
+            Token::Star => {
+                self.next();
+                let rhs = self.projection_rhs(Token::Star.binding_power())?;
+                Ast::ValueProjection(Box::new(Ast::Identity), Box::new(rhs))
+            }
+            Token::Flatten => {
+                self.next();
+                let rhs = self.projection_rhs(Token::Flatten.binding_power())?;
+                Ast::Projection(
+                    Box::new(Ast::Flatten(Box::new(Ast::Identity))),
+                    Box::new(rhs),
+                )
+            }
+            Token::Filter => {
+                self.next();
+                self.filter(Ast::Identity)?
+            }
+            Token::LeftBracket => {
+                self.next();
+                match self.peek() {
+                    Token::Number(_) | Token::Colon => self.index(Ast::Identity)?,
+                    Token::Star if *self.peek_at(1) == Token::RightBracket => {
+                        self.next();
+                        self.next();
+                        let rhs = self.projection_rhs(Token::Star.binding_power())?;
+                        Ast::Projection(Box::new(Ast::Identity), Box::new(rhs))
+                    }
+                    _ => self.multi_list()?,
+                }
+            }
+            Token::LeftBrace => {
+                self.next();
+                self.multi_hash()?
+            }
+            Token::Not => {
+                self.next();
+                Ast::Not(Box::new(self.expression(Token::Not.binding_power())?))
+            }
+            Token::LeftParen => {
+                self.next();
+                let ast = self.expression(0)?;
+                self.expect(Token::RightParen)?;
+                ast
+            }
+            Token::Ampersand => {
+                self.next();
+                Ast::Expref(Box::new(self.expression(0)?))
+            }
+            token => return Err(self.error(format!("unexpected {}", token))),
+        };
+
+        Ok(ast)
+    }
+
+    /// Parses the continuation of the expression on the left, starting with
+    /// the next token.
+    fn infix(&mut self, lhs: Ast) -> Result<Ast, ParseError> {
+        let token = self.next();
+        let binding_power = token.binding_power();
+
+        let ast = match token {
+            Token::Dot => Ast::Subexpr(Box::new(lhs), Box::new(self.dot_rhs(binding_power)?)),
+            Token::Pipe => Ast::Pipe(Box::new(lhs), Box::new(self.expression(binding_power)?)),
+            Token::Or => Ast::Or(Box::new(lhs), Box::new(self.expression(binding_power)?)),
+            Token::And => Ast::And(Box::new(lhs), Box::new(self.expression(binding_power)?)),
+            Token::Comparator(comparator) => Ast::Comparison(
+                comparator,
+                Box::new(lhs),
+                Box::new(self.expression(binding_power)?),
+            ),
+            Token::Flatten => {
+                let rhs = self.projection_rhs(binding_power)?;
+                Ast::Projection(Box::new(Ast::Flatten(Box::new(lhs))), Box::new(rhs))
+            }
+            Token::Filter => self.filter(lhs)?,
+            Token::LeftBracket => match self.peek() {
+                Token::Number(_) | Token::Colon => self.index(lhs)?,
+                Token::Star if *self.peek_at(1) == Token::RightBracket => {
+                    self.next();
+                    self.next();
+                    let rhs = self.projection_rhs(Token::Star.binding_power())?;
+                    Ast::Projection(Box::new(lhs), Box::new(rhs))
+                }
+                token => return Err(self.error(format!("unexpected {}", token))),
+            },
+            Token::LeftParen => match lhs {
+                Ast::Field(name) => {
+                    let mut args = vec![];
+                    while *self.peek() != Token::RightParen {
+                        if !args.is_empty() {
+                            self.expect(Token::Comma)?;
+                        }
+                        args.push(self.expression(0)?);
+                    }
+                    self.next();
+
+                    Ast::Function(name, args)
+                }
+                _ => return Err(self.error("expected a function name".to_owned())),
+            },
+            token => return Err(self.error(format!("unexpected {}", token))),
+        };
+
+        Ok(ast)
+    }
+
+    /// Parses an index, `[0]`, or a slice, `[0:10:2]`, after the opening
+    /// bracket.
+    fn index(&mut self, lhs: Ast) -> Result<Ast, ParseError> {
+        let mut parts = [None; 3];
+        let mut colons = 0;
+
+        loop {
+            match self.next() {
+                Token::Number(number) if parts[colons].is_none() => parts[colons] = Some(number),
+                Token::Colon if colons < 2 => colons += 1,
+                Token::RightBracket => break,
+                token => return Err(self.error(format!("unexpected {}", token))),
+            }
+        }
+
+        match (colons, parts[0]) {
+            (0, Some(index)) => Ok(Ast::Subexpr(Box::new(lhs), Box::new(Ast::Index(index)))),
+            (0, None) => Err(self.error("expected an index".to_owned())),
+            _ => {
+                if parts[2] == Some(0) {
+                    return Err(self.error("slice step can't be zero".to_owned()));
+                }
+
+                let slice = Ast::Slice {
+                    start: parts[0],
+                    stop: parts[1],
+                    step: parts[2],
+                };
+                let rhs = self.projection_rhs(Token::Star.binding_power())?;
+
+                Ok(Ast::Projection(
+                    Box::new(Ast::Subexpr(Box::new(lhs), Box::new(slice))),
+                    Box::new(rhs),
+                ))
+            }
+        }
+    }
+
+    /// Parses a filter, after its opening `[?`.
+    fn filter(&mut self, lhs: Ast) -> Result<Ast, ParseError> {
+        let condition = self.expression(0)?;
+        self.expect(Token::RightBracket)?;
+        let rhs = self.projection_rhs(Token::Filter.binding_power())?;
+
+        Ok(Ast::FilterProjection {
+            lhs: Box::new(lhs),
+            condition: Box::new(condition),
+            rhs: Box::new(rhs),
+        })
+    }
+
+    /// Parses the right-hand side of a dot.
+    fn dot_rhs(&mut self, binding_power: u8) -> Result<Ast, ParseError> {
+        match self.peek() {
+            Token::Identifier(_) | Token::QuotedIdentifier(_) | Token::Star => {
+                self.expression(binding_power)
+            }
+            Token::LeftBracket => {
+                self.next();
+                self.multi_list()
+            }
+            Token::LeftBrace => {
+                self.next();
+                self.multi_hash()
+            }
+            token => Err(self.error(format!(
+                r#"expected an identifier, "*", "[" or "{{", got {}"#,
+                token
+            ))),
+        }
+    }
+
+    /// Parses what is projected, the rest of the expression up to a token
+    /// that ends the projection.
+    fn projection_rhs(&mut self, binding_power: u8) -> Result<Ast, ParseError> {
+        match self.peek() {
+            token if token.binding_power() < PROJECTION_STOP => Ok(Ast::Identity),
+            Token::LeftBracket | Token::Filter => self.expression(binding_power),
+            Token::Dot => {
+                self.next();
+                self.dot_rhs(binding_power)
+            }
+            token => Err(self.error(format!("unexpected {}", token))),
+        }
+    }
+
+    /// Parses a multi-select list, `[a, b]`, after the opening bracket.
+    fn multi_list(&mut self) -> Result<Ast, ParseError> {
+        let mut items = vec![self.expression(0)?];
+        while *self.peek() == Token::Comma {
+            self.next();
+            items.push(self.expression(0)?);
+        }
+        self.expect(Token::RightBracket)?;
+
+        Ok(Ast::MultiList(items))
+    }
+
+    /// Parses a multi-select hash, `{a: a, b: b}`, after the opening brace.
+    fn multi_hash(&mut self) -> Result<Ast, ParseError> {
+        let mut entries = vec![];
+        loop {
+            let key = match self.next() {
+                Token::Identifier(key) | Token::QuotedIdentifier(key) => key,
+                token => return Err(self.error(format!("expected a key, got {}", token))),
+            };
+            self.expect(Token::Colon)?;
+            entries.push((key, self.expression(0)?));
+
+            match self.next() {
+                Token::Comma => {}
+                Token::RightBrace => break,
+                token => return Err(self.error(format!(r#"expected "," or "}}", got {}"#, token))),
+            }
+        }
+
+        Ok(Ast::MultiHash(entries))
+    }
+}
+
+// Evaluation ------------------------------------------------------------------
+
+/// Evaluates the expression against the value.
+pub(crate) fn search(ast: &Ast, value: &Value) -> Result<Value, String> {
+    let result = match ast {
+        Ast::Identity => value.clone(),
+        Ast::Field(name) => match value {
+            Value::Object(object) => object.get(name).cloned().unwrap_or(Value::Null),
+            _ => Value::Null,
+        },
+        Ast::Index(index) => match value {
+            Value::Array(array) => {
+                let index = if *index < 0 {
+                    array.len() as i64 + index
+                } else {
+                    *index
+                };
+
+                usize::try_from(index)
+                    .ok()
+                    .and_then(|index| array.get(index))
+                    .cloned()
+                    .unwrap_or(Value::Null)
+            }
+            _ => Value::Null,
+        },
+        Ast::Slice { start, stop, step } => match value {
+            Value::Array(array) => slice(array, *start, *stop, *step).into(),
+            _ => Value::Null,
+        },
+        Ast::Literal(literal) => literal.clone(),
+        Ast::Subexpr(lhs, rhs) | Ast::Pipe(lhs, rhs) => search(rhs, &search(lhs, value)?)?,
+        Ast::Projection(lhs, rhs) => match search(lhs, value)? {
+            Value::Array(array) => project(array.iter(), rhs)?,
+            _ => Value::Null,
+        },
+        Ast::ValueProjection(lhs, rhs) => match search(lhs, value)? {
+            Value::Object(object) => project(object.values(), rhs)?,
+            _ => Value::Null,
+        },
+        Ast::FilterProjection {
+            lhs,
+            condition,
+            rhs,
+        } => match search(lhs, value)? {
+            Value::Array(array) => {
+                let mut kept = vec![];
+                for item in array {
+                    if is_truthy(&search(condition, &item)?) {
+                        kept.push(item);
+                    }
+                }
+
+                project(kept.iter(), rhs)?
+            }
+            _ => Value::Null,
+        },
+        Ast::Flatten(ast) => match search(ast, value)? {
+            Value::Array(array) => {
+                let mut flattened = vec![];
+                for item in array {
+                    match item {
+                        Value::Array(items) => flattened.extend(items),
+                        item => flattened.push(item),
+                    }
+                }
+
+                flattened.into()
+            }
+            _ => Value::Null,
+        },
+        Ast::MultiList(items) => match value {
+            Value::Null => Value::Null,
+            _ => items
+                .iter()
+                .map(|item| search(item, value))
+                .collect::<Result<Vec<_>, _>>()?
+                .into(),
+        },
+        Ast::MultiHash(entries) => match value {
+            Value::Null => Value::Null,
+            _ => entries
+                .iter()
+                .map(|(key, ast)| Ok((key.clone(), search(ast, value)?)))
+                .collect::<Result<BTreeMap<_, _>, String>>()?
+                .into(),
+        },
+        Ast::Or(lhs, rhs) => match search(lhs, value)? {
+            lhs if is_truthy(&lhs) => lhs,
+            _ => search(rhs, value)?,
+        },
+        Ast::And(lhs, rhs) => match search(lhs, value)? {
+            lhs if !is_truthy(&lhs) => lhs,
+            _ => search(rhs, value)?,
+        },
+        Ast::Not(ast) => (!is_truthy(&search(ast, value)?)).into(),
+        Ast::Comparison(comparator, lhs, rhs) => {
+            let lhs = search(lhs, value)?;
+            let rhs = search(rhs, value)?;
+
+            match comparator {
+                Comparator::Equal => equal(&lhs, &rhs).into(),
+                Comparator::NotEqual => (!equal(&lhs, &rhs)).into(),
+                // Only numbers are ordered.
+                _ => match (number(&lhs), number(&rhs)) {
+                    (Some(lhs), Some(rhs)) => match comparator {
+                        Comparator::Less => lhs < rhs,
+                        Comparator::LessEqual => lhs <= rhs,
+                        Comparator::Greater => lhs > rhs,
+                        _ => lhs >= rhs,
+                    }
+                    .into(),
+                    _ => Value::Null,
+                },
+            }
+        }
+        Ast::Function(name, args) => call(name, args, value)?,
+        Ast::Expref(_) => {
+            return Err("expression references can only be arguments of functions".to_owned())
+        }
+    };
+
+    Ok(result)
+}
+
+fn project<'a>(items: impl Iterator<Item = &'a Value>, rhs: &Ast) -> Result<Value, String> {
+    let mut projected = vec![];
+    for item in items {
+        match search(rhs, item)? {
+            Value::Null => {}
+            value => projected.push(value),
+        }
+    }
+
+    Ok(projected.into())
+}
+
+/// Slices the array as Python does, negative indices counting from its end.
+fn slice(array: &[Value], start: Option<i64>, stop: Option<i64>, step: Option<i64>) -> Vec<Value> {
+    let len = array.len() as i64;
+    let step = step.unwrap_or(1);
+
+    // The bounds are clamped to the array, the lower one to -1 when stepping
+    // backwards so that the first element can be included.
+    let bound = |index: i64, lower: i64, upper: i64| {
+        let index = if index < 0 { index + len } else { index };
+        index.max(lower).min(upper)
+    };
+
+    let mut sliced = vec![];
+    if step > 0 {
+        let mut i = start.map_or(0, |start| bound(start, 0, len));
+        let stop = stop.map_or(len, |stop| bound(stop, 0, len));
+        while i < stop {
+            sliced.push(array[i as usize].clone());
+            i = match i.checked_add(step) {
+                Some(i) => i,
+                None => break,
+            };
+        }
+    } else {
+        let mut i = start.map_or(len - 1, |start| bound(start, -1, len - 1));
+        let stop = stop.map_or(-1, |stop| bound(stop, -1, len - 1));
+        while i > stop {
+            sliced.push(array[i as usize].clone());
+            i = match i.checked_add(step) {
+                Some(i) => i,
+                None => break,
+            };
+        }
+    }
+
+    sliced
+}
+
+/// Empty strings, arrays and objects, `false` and `null` are false, other
+/// values are true.
+fn is_truthy(value: &Value) -> bool {
+    match value {
+        Value::Null => false,
+        Value::Boolean(boolean) => *boolean,
+        Value::Bytes(bytes) => !bytes.is_empty(),
+        Value::Array(array) => !array.is_empty(),
+        Value::Object(object) => !object.is_empty(),
+        _ => true,
+    }
+}
+
+fn number(value: &Value) -> Option<f64> {
+    match value {
+        Value::Integer(integer) => Some(*integer as f64),
+        Value::Float(float) => Some(float.into_inner()),
+        _ => None,
+    }
+}
+
+/// Compares the values as JSON, in which integers and floats are both
+/// numbers.
+#[allow(clippy::float_cmp)]
+fn equal(lhs: &Value, rhs: &Value) -> bool {
+    match (lhs, rhs) {
+        (Value::Array(lhs), Value::Array(rhs)) => {
+            lhs.len() == rhs.len() && lhs.iter().zip(rhs).all(|(lhs, rhs)| equal(lhs, rhs))
+        }
+        (Value::Object(lhs), Value::Object(rhs)) => {
+            lhs.len() == rhs.len()
+                && lhs
+                    .iter()
+                    .all(|(key, lhs)| rhs.get(key).map_or(false, |rhs| equal(lhs, rhs)))
+        }
+        _ => match (number(lhs), number(rhs)) {
+            (Some(lhs), Some(rhs)) => lhs == rhs,
+            _ => lhs == rhs,
+        },
+    }
+}
+
+fn type_name(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Boolean(_) => "boolean",
+        Value::Integer(_) | Value::Float(_) => "number",
+        Value::Bytes(_) => "string",
+        Value::Array(_) => "array",
+        Value::Object(_) => "object",
+        Value::Timestamp(_) => "timestamp",
+        Value::Regex(_) => "regex",
+    }
+}
+
+// Functions -------------------------------------------------------------------
+
+/// An argument of a function, either evaluated or an expression reference.
+enum Arg<'a> {
+    Value(Value),
+    Expref(&'a Ast),
+}
+
+fn call(name: &str, args: &[Ast], value: &Value) -> Result<Value, String> {
+    let args = args
+        .iter()
+        .map(|arg| match arg {
+            Ast::Expref(ast) => Ok(Arg::Expref(ast)),
+            arg => Ok(Arg::Value(search(arg, value)?)),
+        })
+        .collect::<Result<Vec<_>, String>>()?;
+    let args = Args { name, args };
+
+    let result = match name {
+        "abs" => {
+            args.arity(1)?;
+            match args.value(0)? {
+                Value::Integer(integer) => match integer.checked_abs() {
+                    Some(abs) => abs.into(),
+                    None => (*integer as f64).abs().into(),
+                },
+                Value::Float(float) => float.into_inner().abs().into(),
+                _ => return Err(args.invalid_type(0, "a number")),
+            }
+        }
+        "avg" => {
+            args.arity(1)?;
+            let numbers = args.numbers(0)?;
+
+            if numbers.is_empty() {
+                Value::Null
+            } else {
+                (numbers.iter().sum::<f64>() / numbers.len() as f64).into()
+            }
+        }
+        "ceil" | "floor" => {
+            args.arity(1)?;
+            match args.value(0)? {
+                Value::Integer(integer) => (*integer).into(),
+                Value::Float(float) if name == "ceil" => integral(float.into_inner().ceil()),
+                Value::Float(float) => integral(float.into_inner().floor()),
+                _ => return Err(args.invalid_type(0, "a number")),
+            }
+        }
+        "contains" => {
+            args.arity(2)?;
+            let needle = args.value(1)?;
+
+            match args.value(0)? {
+                Value::Array(array) => array.iter().any(|item| equal(item, needle)).into(),
+                Value::Bytes(bytes) => match needle {
+                    Value::Bytes(needle) => String::from_utf8_lossy(bytes)
+                        .contains(String::from_utf8_lossy(needle).as_ref())
+                        .into(),
+                    _ => false.into(),
+                },
+                _ => return Err(args.invalid_type(0, "an array or a string")),
+            }
+        }
+        "ends_with" | "starts_with" => {
+            args.arity(2)?;
+            let value = args.string(0)?;
+            let affix = args.string(1)?;
+
+            if name == "ends_with" {
+                value.ends_with(affix.as_ref()).into()
+            } else {
+                value.starts_with(affix.as_ref()).into()
+            }
+        }
+        "join" => {
+            args.arity(2)?;
+            let glue = args.string(0)?;
+            let strings = args
+                .array(1)?
+                .iter()
+                .map(|item| match item {
+                    Value::Bytes(bytes) => Ok(String::from_utf8_lossy(bytes)),
+                    _ => Err(args.invalid_type(1, "an array of strings")),
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+
+            strings.join(glue.as_ref()).into()
+        }
+        "keys" => {
+            args.arity(1)?;
+            match args.value(0)? {
+                Value::Object(object) => object
+                    .keys()
+                    .cloned()
+                    .map(Value::from)
+                    .collect::<Vec<_>>()
+                    .into(),
+                _ => return Err(args.invalid_type(0, "an object")),
+            }
+        }
+        "length" => {
+            args.arity(1)?;
+            match args.value(0)? {
+                Value::Array(array) => array.len().into(),
+                Value::Object(object) => object.len().into(),
+                Value::Bytes(bytes) => String::from_utf8_lossy(bytes).chars().count().into(),
+                _ => return Err(args.invalid_type(0, "an array, an object or a string")),
+            }
+        }
+        "map" => {
+            args.arity(2)?;
+            let ast = args.expref(0)?;
+
+            args.array(1)?
+                .iter()
+                .map(|item| search(ast, item))
+                .collect::<Result<Vec<_>, _>>()?
+                .into()
+        }
+        "max" | "min" | "sort" => {
+            args.arity(1)?;
+            let sorted = sort(args.array(0)?, |item| Ok(item.clone()))?
+                .ok_or_else(|| args.invalid_type(0, "an array of numbers or of strings"))?;
+
+            match name {
+                "max" => sorted.last().cloned().unwrap_or(Value::Null),
+                "min" => sorted.first().cloned().unwrap_or(Value::Null),
+                _ => sorted.into(),
+            }
+        }
+        "max_by" | "min_by" | "sort_by" => {
+            args.arity(2)?;
+            let ast = args.expref(1)?;
+            let sorted = sort(args.array(0)?, |item| search(ast, item))?.ok_or_else(|| {
+                format!(
+                    r#"expression of function "{}" must evaluate to all numbers or all strings"#,
+                    name
+                )
+            })?;
+
+            match name {
+                "max_by" => sorted.last().cloned().unwrap_or(Value::Null),
+                "min_by" => sorted.first().cloned().unwrap_or(Value::Null),
+                _ => sorted.into(),
+            }
+        }
+        "merge" => {
+            args.arity_at_least(1)?;
+            let mut merged = BTreeMap::new();
+            for i in 0..args.args.len() {
+                match args.value(i)? {
+                    Value::Object(object) => merged.extend(object.clone()),
+                    _ => return Err(args.invalid_type(i, "an object")),
+                }
+            }
+
+            merged.into()
+        }
+        "not_null" => {
+            args.arity_at_least(1)?;
+            let mut found = Value::Null;
+            for i in 0..args.args.len() {
+                match args.value(i)? {
+                    Value::Null => {}
+                    value => {
+                        found = value.clone();
+                        break;
+                    }
+                }
+            }
+
+            found
+        }
+        "reverse" => {
+            args.arity(1)?;
+            match args.value(0)? {
+                Value::Array(array) => array.iter().rev().cloned().collect::<Vec<_>>().into(),
+                Value::Bytes(bytes) => String::from_utf8_lossy(bytes)
+                    .chars()
+                    .rev()
+                    .collect::<String>()
+                    .into(),
+                _ => return Err(args.invalid_type(0, "an array or a string")),
+            }
+        }
+        "sum" => {
+            args.arity(1)?;
+            let numbers = args.numbers(0)?;
+
+            // The sum of integers is an integer, unless it overflows.
+            let integers = args
+                .array(0)?
+                .iter()
+                .try_fold(0i64, |sum, item| match item {
+                    Value::Integer(integer) => sum.checked_add(*integer),
+                    _ => None,
+                });
+
+            match integers {
+                Some(sum) => sum.into(),
+                None => numbers.iter().sum::<f64>().into(),
+            }
+        }
+        "to_array" => {
+            args.arity(1)?;
+            match args.value(0)? {
+                Value::Array(array) => array.clone().into(),
+                value => vec![value.clone()].into(),
+            }
+        }
+        "to_number" => {
+            args.arity(1)?;
+            match args.value(0)? {
+                value @ Value::Integer(_) | value @ Value::Float(_) => value.clone(),
+                Value::Bytes(bytes) => {
+                    let string = String::from_utf8_lossy(bytes);
+                    match string.parse::<i64>() {
+                        Ok(integer) => integer.into(),
+                        Err(_) => match string.parse::<f64>() {
+                            Ok(float) if float.is_finite() => float.into(),
+                            _ => Value::Null,
+                        },
+                    }
+                }
+                _ => Value::Null,
+            }
+        }
+        "to_string" => {
+            args.arity(1)?;
+            match args.value(0)? {
+                value @ Value::Bytes(_) => value.clone(),
+                value => to_json(value).into(),
+            }
+        }
+        "type" => {
+            args.arity(1)?;
+            type_name(args.value(0)?).into()
+        }
+        "values" => {
+            args.arity(1)?;
+            match args.value(0)? {
+                Value::Object(object) => object.values().cloned().collect::<Vec<_>>().into(),
+                _ => return Err(args.invalid_type(0, "an object")),
+            }
+        }
+        _ => return Err(format!(r#"unknown function "{}""#, name)),
+    };
+
+    Ok(result)
+}
+
+/// Returns the float as an integer when it fits in one.
+fn integral(float: f64) -> Value {
+    if float >= i64::MIN as f64 && float < i64::MAX as f64 {
+        (float as i64).into()
+    } else {
+        float.into()
+    }
+}
+
+/// Sorts the values by their keys, or returns `None` unless the keys are all
+/// numbers or all strings.
+fn sort(
+    values: &[Value],
+    key: impl Fn(&Value) -> Result<Value, String>,
+) -> Result<Option<Vec<Value>>, String> {
+    let mut keyed = values
+        .iter()
+        .map(|value| Ok((key(value)?, value)))
+        .collect::<Result<Vec<_>, String>>()?;
+
+    let kinds = keyed
+        .iter()
+        .map(|(key, _)| type_name(key))
+        .collect::<BTreeSet<_>>();
+    if kinds.len() > 1
+        || kinds
+            .iter()
+            .any(|kind| *kind != "number" && *kind != "string")
+    {
+        return Ok(None);
+    }
+
+    keyed.sort_by(|(lhs, _), (rhs, _)| match (lhs, rhs) {
+        (Value::Bytes(lhs), Value::Bytes(rhs)) => lhs.cmp(rhs),
+        _ => number(lhs)
+            .partial_cmp(&number(rhs))
+            .unwrap_or(Ordering::Equal),
+    });
+
+    Ok(Some(
+        keyed.into_iter().map(|(_, value)| value.clone()).collect(),
+    ))
+}
+
+/// The arguments of a function call, checked as they are used.
+struct Args<'a> {
+    name: &'a str,
+    args: Vec<Arg<'a>>,
+}
+
+impl<'a> Args<'a> {
+    fn arity(&self, expected: usize) -> Result<(), String> {
+        if self.args.len() == expected {
+            Ok(())
+        } else {
+            Err(format!(
+                r#"function "{}" expects {} argument{}, got {}"#,
+                self.name,
+                expected,
+                if expected == 1 { "" } else { "s" },
+                self.args.len()
+            ))
+        }
+    }
+
+    fn arity_at_least(&self, expected: usize) -> Result<(), String> {
+        if self.args.len() >= expected {
+            Ok(())
+        } else {
+            Err(format!(
+                r#"function "{}" expects at least {} argument{}, got {}"#,
+                self.name,
+                expected,
+                if expected == 1 { "" } else { "s" },
+                self.args.len()
+            ))
+        }
+    }
+
+    fn invalid_type(&self, index: usize, expected: &str) -> String {
+        let got = match &self.args[index] {
+            Arg::Value(value) => type_name(value),
+            Arg::Expref(_) => "expression reference",
+        };
+
+        format!(
+            r#"argument {} of function "{}" must be {}, got {}"#,
+            index + 1,
+            self.name,
+            expected,
+            got
+        )
+    }
+
+    /// Returns the evaluated argument, an expression reference being an
+    /// error.
+    fn value(&self, index: usize) -> Result<&Value, String> {
+        match &self.args[index] {
+            Arg::Value(value) => Ok(value),
+            Arg::Expref(_) => Err(self.invalid_type(index, "a value")),
+        }
+    }
+
+    fn expref(&self, index: usize) -> Result<&'a Ast, String> {
+        match self.args[index] {
+            Arg::Expref(ast) => Ok(ast),
+            Arg::Value(_) => Err(self.invalid_type(index, "an expression reference")),
+        }
+    }
+
+    fn string(&self, index: usize) -> Result<std::borrow::Cow<'_, str>, String> {
+        match self.value(index)? {
+            Value::Bytes(bytes) => Ok(String::from_utf8_lossy(bytes)),
+            _ => Err(self.invalid_type(index, "a string")),
+        }
+    }
+
+    fn array(&self, index: usize) -> Result<&[Value], String> {
+        match self.value(index)? {
+            Value::Array(array) => Ok(array),
+            _ => Err(self.invalid_type(index, "an array")),
+        }
+    }
+
+    fn numbers(&self, index: usize) -> Result<Vec<f64>, String> {
+        self.array(index)?
+            .iter()
+            .map(number)
+            .collect::<Option<Vec<_>>>()
+            .ok_or_else(|| self.invalid_type(index, "an array of numbers"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn precedence() {
+        assert_eq!(
+            parse("a || b && c").unwrap(),
+            Ast::Or(
+                Box::new(Ast::Field("a".to_owned())),
+                Box::new(Ast::And(
+                    Box::new(Ast::Field("b".to_owned())),
+                    Box::new(Ast::Field("c".to_owned()))
+                ))
+            )
+        );
+    }
+
+    #[test]
+    fn slices() {
+        let array = (0..5).map(Value::from).collect::<Vec<_>>();
+        let slice = |start, stop, step| {
+            slice(&array, start, stop, step)
+                .into_iter()
+                .map(|value| value.try_integer().unwrap())
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(slice(None, None, None), vec![0, 1, 2, 3, 4]);
+        assert_eq!(slice(Some(1), Some(3), None), vec![1, 2]);
+        assert_eq!(slice(Some(-2), None, None), vec![3, 4]);
+        assert_eq!(slice(None, None, Some(2)), vec![0, 2, 4]);
+        assert_eq!(slice(None, None, Some(-1)), vec![4, 3, 2, 1, 0]);
+        assert_eq!(slice(Some(3), Some(0), Some(-2)), vec![3, 1]);
+        assert_eq!(slice(Some(10), Some(20), None), Vec::<i64>::new());
+        assert_eq!(slice(Some(1), None, Some(i64::MAX)), vec![1]);
+        assert_eq!(slice(Some(3), None, Some(i64::MIN)), vec![3]);
+    }
+
+    #[test]
+    fn parse_errors() {
+        let error = |expression| parse(expression).unwrap_err().to_string();
+
+        assert_eq!(
+            error("foo."),
+            r#"expected an identifier, "*", "[" or "{", got end of expression at position 4"#
+        );
+        assert_eq!(error("foo[0"), "unexpected end of expression at position 5");
+        assert_eq!(error("a = b"), r#"expected "==" at position 2"#);
+        assert_eq!(error("[0::0]"), "slice step can't be zero at position 6");
+        assert_eq!(
+            error("foo bar"),
+            r#"unexpected identifier "bar" at position 4"#
+        );
+        assert_eq!(
+            parse(&"(".repeat(5000)).unwrap_err().to_string(),
+            format!("expression nested too deeply at position {}", MAX_DEPTH)
+        );
+        assert_eq!(
+            parse(&"!".repeat(5000)).unwrap_err().to_string(),
+            format!("expression nested too deeply at position {}", MAX_DEPTH)
+        );
+        assert!(parse(&("(".repeat(MAX_DEPTH - 1) + "a" + &")".repeat(MAX_DEPTH - 1))).is_ok());
+    }
+}
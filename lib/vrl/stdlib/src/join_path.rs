@@ -0,0 +1,145 @@
+use crate::path_util;
+use vrl::prelude::*;
+
+#[derive(Clone, Copy, Debug)]
+pub struct JoinPath;
+
+impl Function for JoinPath {
+    fn identifier(&self) -> &'static str {
+        "join_path"
+    }
+
+    fn is_pure(&self) -> bool {
+        true
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[
+            Parameter {
+                keyword: "parts",
+                kind: kind::ARRAY,
+                required: true,
+            },
+            Parameter {
+                keyword: "windows",
+                kind: kind::BOOLEAN,
+                required: false,
+            },
+        ]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "join",
+                source: r#"join_path!(["/var/log/", "app", "current.log"])"#,
+                result: Ok("/var/log/app/current.log"),
+            },
+            Example {
+                title: "windows",
+                source: r#"join_path!(["C:", "Logs", "app.log"], windows: true)"#,
+                result: Ok(r#"C:\Logs\app.log"#),
+            },
+        ]
+    }
+
+    fn compile(&self, mut arguments: ArgumentList) -> Compiled {
+        let parts = arguments.required("parts");
+        let windows = arguments.optional("windows").unwrap_or(expr!(false));
+
+        Ok(Box::new(JoinPathFn { parts, windows }))
+    }
+}
+
+#[derive(Debug, Clone)]
+struct JoinPathFn {
+    parts: Box<dyn Expression>,
+    windows: Box<dyn Expression>,
+}
+
+impl Expression for JoinPathFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let parts = self.parts.resolve(ctx)?.try_array()?;
+        let windows = self.windows.resolve(ctx)?.try_boolean()?;
+        let separator = if windows { '\\' } else { '/' };
+
+        let mut path = String::new();
+        for part in parts {
+            let part = part.try_bytes()?;
+            let part = String::from_utf8_lossy(&part);
+
+            if part.is_empty() {
+                continue;
+            }
+
+            // Separators are collapsed where the parts meet.
+            if path.is_empty() {
+                path.push_str(&part);
+            } else {
+                let len = path
+                    .trim_end_matches(|c| path_util::is_separator(c, windows))
+                    .len();
+                path.truncate(len);
+                path.push(separator);
+                path.push_str(part.trim_start_matches(|c| path_util::is_separator(c, windows)));
+            }
+        }
+
+        Ok(path.into())
+    }
+
+    fn type_def(&self, _: &state::Compiler) -> TypeDef {
+        TypeDef::new().fallible().bytes()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    test_function![
+        join_path => JoinPath;
+
+        join {
+            args: func_args![parts: value!(["var", "log", "app.log"])],
+            want: Ok("var/log/app.log"),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+
+        absolute {
+            args: func_args![parts: value!(["/", "var", "log"])],
+            want: Ok("/var/log"),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+
+        collapses_separators {
+            args: func_args![parts: value!(["/var/log/", "/app/", "", "current.log"])],
+            want: Ok("/var/log/app/current.log"),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+
+        keeps_trailing_separator {
+            args: func_args![parts: value!(["var", "log/"])],
+            want: Ok("var/log/"),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+
+        empty {
+            args: func_args![parts: value!([])],
+            want: Ok(""),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+
+        windows {
+            args: func_args![parts: value!(["C:", r#"Logs\"#, "app.log"]), windows: true],
+            want: Ok(r#"C:\Logs\app.log"#),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+
+        non_string_part {
+            args: func_args![parts: value!(["var", 1])],
+            want: Err(r#"expected "string", got "integer""#),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+    ];
+}
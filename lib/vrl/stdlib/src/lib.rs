@@ -1,49 +1,165 @@
 mod util;
 
+#[cfg(feature = "abort")]
+mod abort;
+#[cfg(feature = "add_business_days")]
+mod add_business_days;
 #[cfg(feature = "append")]
 mod append;
 #[cfg(feature = "array")]
 mod array;
 #[cfg(feature = "assert")]
 mod assert;
+#[cfg(feature = "basename")]
+mod basename;
 #[cfg(feature = "boolean")]
 mod boolean;
+#[cfg(feature = "business_days_between")]
+mod business_days_between;
+#[cfg(any(feature = "add_business_days", feature = "business_days_between"))]
+mod calendar_util;
+#[cfg(feature = "camel_case_keys")]
+mod camel_case_keys;
+#[cfg(feature = "canonicalize")]
+mod canonicalize;
+#[cfg(feature = "capture_error")]
+mod capture_error;
+#[cfg(feature = "card_brand")]
+mod card_brand;
+#[cfg(any(feature = "decode_cbor", feature = "encode_cbor"))]
+mod cbor_util;
 #[cfg(feature = "ceil")]
 mod ceil;
+#[cfg(feature = "classify")]
+mod classify;
+#[cfg(feature = "coerce_types")]
+mod coerce_types;
+#[cfg(any(
+    feature = "contrast_ratio",
+    feature = "luminance",
+    feature = "parse_color"
+))]
+mod color_util;
 #[cfg(feature = "compact")]
 mod compact;
+#[cfg(feature = "compare_semver")]
+mod compare_semver;
+#[cfg(feature = "compile_template")]
+mod compile_template;
+#[cfg(feature = "concat")]
+mod concat;
+#[cfg(feature = "concat_bytes")]
+mod concat_bytes;
 #[cfg(feature = "contains")]
 mod contains;
+#[cfg(feature = "contrast_ratio")]
+mod contrast_ratio;
+#[cfg(feature = "cron_matches")]
+mod cron_matches;
+#[cfg(feature = "cron_next")]
+mod cron_next;
+#[cfg(any(feature = "cron_matches", feature = "cron_next"))]
+mod cron_util;
 #[cfg(feature = "decode_base64")]
 mod decode_base64;
+#[cfg(feature = "decode_cbor")]
+mod decode_cbor;
+#[cfg(feature = "decode_deflate")]
+mod decode_deflate;
+#[cfg(feature = "decode_hex")]
+mod decode_hex;
+#[cfg(feature = "decode_lz4")]
+mod decode_lz4;
+#[cfg(feature = "decode_percent")]
+mod decode_percent;
+#[cfg(feature = "decode_snappy")]
+mod decode_snappy;
+#[cfg(feature = "deep_equal")]
+mod deep_equal;
 #[cfg(feature = "del")]
 mod del;
+#[cfg(feature = "detect_content_type")]
+mod detect_content_type;
+#[cfg(feature = "dirname")]
+mod dirname;
+#[cfg(feature = "dot_product")]
+mod dot_product;
 #[cfg(feature = "downcase")]
 mod downcase;
+#[cfg(feature = "ellipsize_middle")]
+mod ellipsize_middle;
 #[cfg(feature = "encode_base64")]
 mod encode_base64;
+#[cfg(feature = "encode_cbor")]
+mod encode_cbor;
+#[cfg(feature = "encode_deflate")]
+mod encode_deflate;
+#[cfg(feature = "encode_fixed_width")]
+mod encode_fixed_width;
+#[cfg(feature = "encode_hex")]
+mod encode_hex;
+#[cfg(feature = "encode_influx")]
+mod encode_influx;
 #[cfg(feature = "encode_json")]
 mod encode_json;
+#[cfg(feature = "encode_lz4")]
+mod encode_lz4;
+#[cfg(feature = "encode_percent")]
+mod encode_percent;
+#[cfg(feature = "encode_snappy")]
+mod encode_snappy;
 #[cfg(feature = "ends_with")]
 mod ends_with;
+#[cfg(feature = "ewma")]
+mod ewma;
 #[cfg(feature = "exists")]
 mod exists;
+#[cfg(feature = "extname")]
+mod extname;
+#[cfg(feature = "file_extension")]
+mod file_extension;
+#[cfg(any(feature = "encode_fixed_width", feature = "parse_fixed_width"))]
+mod fixed_width_util;
 #[cfg(feature = "flatten")]
 mod flatten;
 #[cfg(feature = "float")]
 mod float;
 #[cfg(feature = "floor")]
 mod floor;
+#[cfg(feature = "format_mac_address")]
+mod format_mac_address;
 #[cfg(feature = "format_number")]
 mod format_number;
 #[cfg(feature = "format_timestamp")]
 mod format_timestamp;
+#[cfg(feature = "from_hex_dump")]
+mod from_hex_dump;
 #[cfg(feature = "get_env_var")]
 mod get_env_var;
 #[cfg(feature = "get_hostname")]
 mod get_hostname;
+#[cfg(feature = "glob_match")]
+mod glob_match;
+#[cfg(feature = "hash_color")]
+mod hash_color;
+#[cfg(feature = "hex_dump")]
+mod hex_dump;
+#[cfg(feature = "html_escape")]
+mod html_escape;
+#[cfg(feature = "html_unescape")]
+mod html_unescape;
+#[cfg(any(
+    feature = "html_unescape",
+    feature = "parse_html",
+    feature = "strip_html"
+))]
+mod html_util;
+#[cfg(feature = "humanize_number")]
+mod humanize_number;
 #[cfg(feature = "includes")]
 mod includes;
+#[cfg(feature = "infer_schema")]
+mod infer_schema;
 #[cfg(feature = "integer")]
 mod integer;
 #[cfg(feature = "ip_cidr_contains")]
@@ -52,30 +168,84 @@ mod ip_cidr_contains;
 mod ip_subnet;
 #[cfg(feature = "ip_to_ipv6")]
 mod ip_to_ipv6;
+#[cfg(feature = "ip_version")]
+mod ip_version;
 #[cfg(feature = "ipv6_to_ipv4")]
 mod ipv6_to_ipv4;
+#[cfg(feature = "is_ip")]
+mod is_ip;
 #[cfg(feature = "is_nullish")]
 mod is_nullish;
+#[cfg(feature = "is_private_ip")]
+mod is_private_ip;
+#[cfg(feature = "ja3")]
+mod ja3;
+#[cfg(feature = "ja3_raw")]
+mod ja3_raw;
+#[cfg(any(feature = "ja3", feature = "ja3_raw"))]
+mod ja3_util;
+#[cfg(feature = "jmespath")]
+mod jmespath;
+#[cfg(feature = "jmespath")]
+mod jmespath_util;
 #[cfg(feature = "join")]
 mod join;
+#[cfg(feature = "join_path")]
+mod join_path;
 #[cfg(feature = "length")]
 mod length;
+#[cfg(feature = "like")]
+mod like;
 #[cfg(feature = "log")]
 mod log;
 #[cfg(any(feature = "parse_common_log", feature = "parse_apache_log"))]
 mod log_util;
+#[cfg(feature = "luhn_check")]
+mod luhn_check;
+#[cfg(feature = "luminance")]
+mod luminance;
+#[cfg(any(feature = "decode_lz4", feature = "encode_lz4"))]
+mod lz4_util;
+#[cfg(any(
+    feature = "format_mac_address",
+    feature = "mac_vendor",
+    feature = "parse_mac_address"
+))]
+mod mac_util;
+#[cfg(feature = "mac_vendor")]
+mod mac_vendor;
+#[cfg(feature = "mask")]
+mod mask;
 #[cfg(feature = "match")]
 mod r#match;
+#[cfg(feature = "match_any")]
+mod match_any;
 #[cfg(feature = "md5")]
 mod md5;
 #[cfg(feature = "merge")]
 mod merge;
+#[cfg(feature = "merge_schemas")]
+mod merge_schemas;
+#[cfg(feature = "metaphone")]
+mod metaphone;
+#[cfg(feature = "mime_type")]
+mod mime_type;
+#[cfg(any(feature = "file_extension", feature = "mime_type"))]
+mod mime_util;
+#[cfg(feature = "moving_average")]
+mod moving_average;
+#[cfg(feature = "ngrams")]
+mod ngrams;
+#[cfg(feature = "normalize_email")]
+mod normalize_email;
 #[cfg(feature = "now")]
 mod now;
 #[cfg(feature = "object")]
 mod object;
 #[cfg(feature = "only_fields")]
 mod only_fields;
+#[cfg(feature = "ordinal")]
+mod ordinal;
 #[cfg(feature = "parse_apache_log")]
 mod parse_apache_log;
 #[cfg(feature = "parse_aws_alb_log")]
@@ -84,24 +254,56 @@ mod parse_aws_alb_log;
 mod parse_aws_cloudwatch_log_subscription_message;
 #[cfg(feature = "parse_aws_vpc_flow_log")]
 mod parse_aws_vpc_flow_log;
+#[cfg(feature = "parse_color")]
+mod parse_color;
 #[cfg(feature = "parse_common_log")]
 mod parse_common_log;
+#[cfg(feature = "parse_dns_query")]
+mod parse_dns_query;
 #[cfg(feature = "parse_duration")]
 mod parse_duration;
+#[cfg(feature = "parse_email")]
+mod parse_email;
+#[cfg(feature = "parse_fixed_width")]
+mod parse_fixed_width;
 #[cfg(feature = "parse_glog")]
 mod parse_glog;
 #[cfg(feature = "parse_grok")]
 mod parse_grok;
+#[cfg(feature = "parse_html")]
+mod parse_html;
+#[cfg(feature = "parse_influx")]
+mod parse_influx;
+#[cfg(feature = "parse_iso8601_duration")]
+mod parse_iso8601_duration;
 #[cfg(feature = "parse_json")]
 mod parse_json;
 #[cfg(feature = "parse_key_value")]
 mod parse_key_value;
 #[cfg(feature = "parse_logfmt")]
 mod parse_logfmt;
+#[cfg(feature = "parse_mac_address")]
+mod parse_mac_address;
+#[cfg(feature = "parse_markdown")]
+mod parse_markdown;
+#[cfg(feature = "parse_number_array")]
+mod parse_number_array;
+#[cfg(feature = "parse_parquet_schema")]
+mod parse_parquet_schema;
+#[cfg(feature = "parse_php_serialized")]
+mod parse_php_serialized;
+#[cfg(feature = "parse_prometheus")]
+mod parse_prometheus;
 #[cfg(feature = "parse_regex")]
 mod parse_regex;
 #[cfg(feature = "parse_regex_all")]
 mod parse_regex_all;
+#[cfg(feature = "parse_ruby_hash")]
+mod parse_ruby_hash;
+#[cfg(feature = "parse_semver")]
+mod parse_semver;
+#[cfg(feature = "parse_statsd")]
+mod parse_statsd;
 #[cfg(feature = "parse_syslog")]
 mod parse_syslog;
 #[cfg(feature = "parse_timestamp")]
@@ -110,34 +312,85 @@ mod parse_timestamp;
 mod parse_tokens;
 #[cfg(feature = "parse_url")]
 mod parse_url;
+#[cfg(any(
+    feature = "basename",
+    feature = "dirname",
+    feature = "extname",
+    feature = "join_path"
+))]
+mod path_util;
+#[cfg(feature = "percentile")]
+mod percentile;
+#[cfg(feature = "pluralize")]
+mod pluralize;
+#[cfg(feature = "public_suffix")]
+mod public_suffix;
 #[cfg(feature = "push")]
 mod push;
+#[cfg(feature = "quantile")]
+mod quantile;
+#[cfg(feature = "random_int")]
+mod random_int;
 //#[cfg(feature = "redact")]
 //mod redact;
+#[cfg(feature = "registrable_domain")]
+mod registrable_domain;
+#[cfg(feature = "rename_key")]
+mod rename_key;
+#[cfg(feature = "rename_keys")]
+mod rename_keys;
+#[cfg(feature = "render")]
+mod render;
 #[cfg(feature = "replace")]
 mod replace;
 #[cfg(feature = "round")]
 mod round;
+#[cfg(any(feature = "infer_schema", feature = "merge_schemas"))]
+mod schema_util;
+#[cfg(feature = "set_if_absent")]
+mod set_if_absent;
 #[cfg(feature = "sha1")]
 mod sha1;
 #[cfg(feature = "sha2")]
 mod sha2;
 #[cfg(feature = "sha3")]
 mod sha3;
+#[cfg(feature = "shell_quote")]
+mod shell_quote;
+#[cfg(feature = "shell_split")]
+mod shell_split;
+#[cfg(feature = "sizeof")]
+mod sizeof;
 #[cfg(feature = "slice")]
 mod slice;
+#[cfg(feature = "slice_bytes")]
+mod slice_bytes;
+#[cfg(feature = "snake_case_keys")]
+mod snake_case_keys;
+#[cfg(feature = "soundex")]
+mod soundex;
 #[cfg(feature = "split")]
 mod split;
+#[cfg(feature = "sprintf")]
+mod sprintf;
 #[cfg(feature = "starts_with")]
 mod starts_with;
+#[cfg(feature = "stats")]
+mod stats;
 #[cfg(feature = "string")]
 mod string;
 #[cfg(feature = "strip_ansi_escape_codes")]
 mod strip_ansi_escape_codes;
+#[cfg(feature = "strip_html")]
+mod strip_html;
 #[cfg(feature = "strip_whitespace")]
 mod strip_whitespace;
 #[cfg(feature = "tag_types_externally")]
 mod tag_types_externally;
+#[cfg(any(feature = "compile_template", feature = "render"))]
+mod template_util;
+#[cfg(feature = "time_ago")]
+mod time_ago;
 #[cfg(feature = "timestamp")]
 mod timestamp;
 #[cfg(feature = "to_bool")]
@@ -158,12 +411,28 @@ mod to_syslog_severity;
 mod to_timestamp;
 #[cfg(feature = "to_unix_timestamp")]
 mod to_unix_timestamp;
+#[cfg(feature = "token_bucket")]
+mod token_bucket;
+#[cfg(feature = "tokenize")]
+mod tokenize;
 #[cfg(feature = "truncate")]
 mod truncate;
+#[cfg(feature = "try_all")]
+mod try_all;
+#[cfg(feature = "unique_bloom")]
+mod unique_bloom;
 #[cfg(feature = "upcase")]
 mod upcase;
 #[cfg(feature = "uuid_v4")]
 mod uuid_v4;
+#[cfg(feature = "vector_add")]
+mod vector_add;
+#[cfg(feature = "vector_scale")]
+mod vector_scale;
+#[cfg(feature = "windows")]
+mod windows;
+#[cfg(feature = "xor_bytes")]
+mod xor_bytes;
 
 // -----------------------------------------------------------------------------
 
@@ -173,48 +442,144 @@ pub use crate::array::Array;
 pub use crate::md5::Md5;
 #[cfg(feature = "sha1")]
 pub use crate::sha1::Sha1;
+#[cfg(feature = "abort")]
+pub use abort::Abort;
+#[cfg(feature = "add_business_days")]
+pub use add_business_days::AddBusinessDays;
 #[cfg(feature = "append")]
 pub use append::Append;
 #[cfg(feature = "assert")]
 pub use assert::Assert;
+#[cfg(feature = "basename")]
+pub use basename::Basename;
 #[cfg(feature = "boolean")]
 pub use boolean::Boolean;
+#[cfg(feature = "business_days_between")]
+pub use business_days_between::BusinessDaysBetween;
+#[cfg(feature = "camel_case_keys")]
+pub use camel_case_keys::CamelCaseKeys;
+#[cfg(feature = "canonicalize")]
+pub use canonicalize::Canonicalize;
+#[cfg(feature = "capture_error")]
+pub use capture_error::CaptureError;
+#[cfg(feature = "card_brand")]
+pub use card_brand::CardBrand;
 #[cfg(feature = "ceil")]
 pub use ceil::Ceil;
+#[cfg(feature = "classify")]
+pub use classify::Classify;
+#[cfg(feature = "coerce_types")]
+pub use coerce_types::CoerceTypes;
 #[cfg(feature = "compact")]
 pub use compact::Compact;
+#[cfg(feature = "compare_semver")]
+pub use compare_semver::CompareSemver;
+#[cfg(feature = "compile_template")]
+pub use compile_template::CompileTemplate;
+#[cfg(feature = "concat")]
+pub use concat::Concat;
+#[cfg(feature = "concat_bytes")]
+pub use concat_bytes::ConcatBytes;
 #[cfg(feature = "contains")]
 pub use contains::Contains;
+#[cfg(feature = "contrast_ratio")]
+pub use contrast_ratio::ContrastRatio;
+#[cfg(feature = "cron_matches")]
+pub use cron_matches::CronMatches;
+#[cfg(feature = "cron_next")]
+pub use cron_next::CronNext;
 #[cfg(feature = "decode_base64")]
 pub use decode_base64::DecodeBase64;
+#[cfg(feature = "decode_cbor")]
+pub use decode_cbor::DecodeCbor;
+#[cfg(feature = "decode_deflate")]
+pub use decode_deflate::DecodeDeflate;
+#[cfg(feature = "decode_hex")]
+pub use decode_hex::DecodeHex;
+#[cfg(feature = "decode_lz4")]
+pub use decode_lz4::DecodeLz4;
+#[cfg(feature = "decode_percent")]
+pub use decode_percent::DecodePercent;
+#[cfg(feature = "decode_snappy")]
+pub use decode_snappy::DecodeSnappy;
+#[cfg(feature = "deep_equal")]
+pub use deep_equal::DeepEqual;
 #[cfg(feature = "del")]
 pub use del::Del;
+#[cfg(feature = "detect_content_type")]
+pub use detect_content_type::DetectContentType;
+#[cfg(feature = "dirname")]
+pub use dirname::Dirname;
+#[cfg(feature = "dot_product")]
+pub use dot_product::DotProduct;
 #[cfg(feature = "downcase")]
 pub use downcase::Downcase;
+#[cfg(feature = "ellipsize_middle")]
+pub use ellipsize_middle::EllipsizeMiddle;
 #[cfg(feature = "encode_base64")]
 pub use encode_base64::EncodeBase64;
+#[cfg(feature = "encode_cbor")]
+pub use encode_cbor::EncodeCbor;
+#[cfg(feature = "encode_deflate")]
+pub use encode_deflate::EncodeDeflate;
+#[cfg(feature = "encode_fixed_width")]
+pub use encode_fixed_width::EncodeFixedWidth;
+#[cfg(feature = "encode_hex")]
+pub use encode_hex::EncodeHex;
+#[cfg(feature = "encode_influx")]
+pub use encode_influx::EncodeInflux;
 #[cfg(feature = "encode_json")]
 pub use encode_json::EncodeJson;
+#[cfg(feature = "encode_lz4")]
+pub use encode_lz4::EncodeLz4;
+#[cfg(feature = "encode_percent")]
+pub use encode_percent::EncodePercent;
+#[cfg(feature = "encode_snappy")]
+pub use encode_snappy::EncodeSnappy;
 #[cfg(feature = "ends_with")]
 pub use ends_with::EndsWith;
+#[cfg(feature = "ewma")]
+pub use ewma::Ewma;
 #[cfg(feature = "exists")]
 pub use exists::Exists;
+#[cfg(feature = "extname")]
+pub use extname::Extname;
+#[cfg(feature = "file_extension")]
+pub use file_extension::FileExtension;
 #[cfg(feature = "flatten")]
 pub use flatten::Flatten;
 #[cfg(feature = "float")]
 pub use float::Float;
 #[cfg(feature = "floor")]
 pub use floor::Floor;
+#[cfg(feature = "format_mac_address")]
+pub use format_mac_address::FormatMacAddress;
 #[cfg(feature = "format_number")]
 pub use format_number::FormatNumber;
 #[cfg(feature = "format_timestamp")]
 pub use format_timestamp::FormatTimestamp;
+#[cfg(feature = "from_hex_dump")]
+pub use from_hex_dump::FromHexDump;
 #[cfg(feature = "get_env_var")]
 pub use get_env_var::GetEnvVar;
 #[cfg(feature = "get_hostname")]
 pub use get_hostname::GetHostname;
+#[cfg(feature = "glob_match")]
+pub use glob_match::GlobMatch;
+#[cfg(feature = "hash_color")]
+pub use hash_color::HashColor;
+#[cfg(feature = "hex_dump")]
+pub use hex_dump::HexDump;
+#[cfg(feature = "html_escape")]
+pub use html_escape::HtmlEscape;
+#[cfg(feature = "html_unescape")]
+pub use html_unescape::HtmlUnescape;
+#[cfg(feature = "humanize_number")]
+pub use humanize_number::HumanizeNumber;
 #[cfg(feature = "includes")]
 pub use includes::Includes;
+#[cfg(feature = "infer_schema")]
+pub use infer_schema::InferSchema;
 #[cfg(feature = "integer")]
 pub use integer::Integer;
 #[cfg(feature = "ip_cidr_contains")]
@@ -223,24 +588,64 @@ pub use ip_cidr_contains::IpCidrContains;
 pub use ip_subnet::IpSubnet;
 #[cfg(feature = "ip_to_ipv6")]
 pub use ip_to_ipv6::IpToIpv6;
+#[cfg(feature = "ip_version")]
+pub use ip_version::IpVersion;
 #[cfg(feature = "ipv6_to_ipv4")]
 pub use ipv6_to_ipv4::Ipv6ToIpV4;
+#[cfg(feature = "is_ip")]
+pub use is_ip::IsIp;
 #[cfg(feature = "is_nullish")]
 pub use is_nullish::IsNullish;
+#[cfg(feature = "is_private_ip")]
+pub use is_private_ip::IsPrivateIp;
+#[cfg(feature = "ja3")]
+pub use ja3::Ja3;
+#[cfg(feature = "ja3_raw")]
+pub use ja3_raw::Ja3Raw;
+#[cfg(feature = "jmespath")]
+pub use jmespath::Jmespath;
 #[cfg(feature = "join")]
 pub use join::Join;
+#[cfg(feature = "join_path")]
+pub use join_path::JoinPath;
 #[cfg(feature = "length")]
 pub use length::Length;
+#[cfg(feature = "like")]
+pub use like::Like;
 #[cfg(feature = "log")]
 pub use log::Log;
+#[cfg(feature = "luhn_check")]
+pub use luhn_check::LuhnCheck;
+#[cfg(feature = "luminance")]
+pub use luminance::Luminance;
+#[cfg(feature = "mac_vendor")]
+pub use mac_vendor::MacVendor;
+#[cfg(feature = "mask")]
+pub use mask::Mask;
+#[cfg(feature = "match_any")]
+pub use match_any::MatchAny;
 #[cfg(feature = "merge")]
 pub use merge::Merge;
+#[cfg(feature = "merge_schemas")]
+pub use merge_schemas::MergeSchemas;
+#[cfg(feature = "metaphone")]
+pub use metaphone::Metaphone;
+#[cfg(feature = "mime_type")]
+pub use mime_type::MimeType;
+#[cfg(feature = "moving_average")]
+pub use moving_average::MovingAverage;
+#[cfg(feature = "ngrams")]
+pub use ngrams::Ngrams;
+#[cfg(feature = "normalize_email")]
+pub use normalize_email::NormalizeEmail;
 #[cfg(feature = "now")]
 pub use now::Now;
 #[cfg(feature = "object")]
 pub use object::Object;
 #[cfg(feature = "only_fields")]
 pub use only_fields::OnlyFields;
+#[cfg(feature = "ordinal")]
+pub use ordinal::Ordinal;
 #[cfg(feature = "parse_apache_log")]
 pub use parse_apache_log::ParseApacheLog;
 #[cfg(feature = "parse_aws_alb_log")]
@@ -249,24 +654,56 @@ pub use parse_aws_alb_log::ParseAwsAlbLog;
 pub use parse_aws_cloudwatch_log_subscription_message::ParseAwsCloudWatchLogSubscriptionMessage;
 #[cfg(feature = "parse_aws_vpc_flow_log")]
 pub use parse_aws_vpc_flow_log::ParseAwsVpcFlowLog;
+#[cfg(feature = "parse_color")]
+pub use parse_color::ParseColor;
 #[cfg(feature = "parse_common_log")]
 pub use parse_common_log::ParseCommonLog;
+#[cfg(feature = "parse_dns_query")]
+pub use parse_dns_query::ParseDnsQuery;
 #[cfg(feature = "parse_duration")]
 pub use parse_duration::ParseDuration;
+#[cfg(feature = "parse_email")]
+pub use parse_email::ParseEmail;
+#[cfg(feature = "parse_fixed_width")]
+pub use parse_fixed_width::ParseFixedWidth;
 #[cfg(feature = "parse_glog")]
 pub use parse_glog::ParseGlog;
 #[cfg(feature = "parse_grok")]
 pub use parse_grok::ParseGrok;
+#[cfg(feature = "parse_html")]
+pub use parse_html::ParseHtml;
+#[cfg(feature = "parse_influx")]
+pub use parse_influx::ParseInflux;
+#[cfg(feature = "parse_iso8601_duration")]
+pub use parse_iso8601_duration::ParseIso8601Duration;
 #[cfg(feature = "parse_json")]
 pub use parse_json::ParseJson;
 #[cfg(feature = "parse_key_value")]
 pub use parse_key_value::ParseKeyValue;
 #[cfg(feature = "parse_logfmt")]
 pub use parse_logfmt::ParseLogFmt;
+#[cfg(feature = "parse_mac_address")]
+pub use parse_mac_address::ParseMacAddress;
+#[cfg(feature = "parse_markdown")]
+pub use parse_markdown::ParseMarkdown;
+#[cfg(feature = "parse_number_array")]
+pub use parse_number_array::ParseNumberArray;
+#[cfg(feature = "parse_parquet_schema")]
+pub use parse_parquet_schema::ParseParquetSchema;
+#[cfg(feature = "parse_php_serialized")]
+pub use parse_php_serialized::ParsePhpSerialized;
+#[cfg(feature = "parse_prometheus")]
+pub use parse_prometheus::ParsePrometheus;
 #[cfg(feature = "parse_regex")]
 pub use parse_regex::ParseRegex;
 #[cfg(feature = "parse_regex_all")]
 pub use parse_regex_all::ParseRegexAll;
+#[cfg(feature = "parse_ruby_hash")]
+pub use parse_ruby_hash::ParseRubyHash;
+#[cfg(feature = "parse_semver")]
+pub use parse_semver::ParseSemver;
+#[cfg(feature = "parse_statsd")]
+pub use parse_statsd::ParseStatsd;
 #[cfg(feature = "parse_syslog")]
 pub use parse_syslog::ParseSyslog;
 #[cfg(feature = "parse_timestamp")]
@@ -275,34 +712,74 @@ pub use parse_timestamp::ParseTimestamp;
 pub use parse_tokens::ParseTokens;
 #[cfg(feature = "parse_url")]
 pub use parse_url::ParseUrl;
+#[cfg(feature = "percentile")]
+pub use percentile::Percentile;
+#[cfg(feature = "pluralize")]
+pub use pluralize::Pluralize;
+#[cfg(feature = "public_suffix")]
+pub use public_suffix::PublicSuffix;
 #[cfg(feature = "push")]
 pub use push::Push;
+#[cfg(feature = "quantile")]
+pub use quantile::Quantile;
 #[cfg(feature = "match")]
 pub use r#match::Match;
+#[cfg(feature = "random_int")]
+pub use random_int::RandomInt;
 //#[cfg(feature = "redact")]
 //pub use redact::Redact;
+#[cfg(feature = "registrable_domain")]
+pub use registrable_domain::RegistrableDomain;
+#[cfg(feature = "rename_key")]
+pub use rename_key::RenameKey;
+#[cfg(feature = "rename_keys")]
+pub use rename_keys::RenameKeys;
+#[cfg(feature = "render")]
+pub use render::Render;
 #[cfg(feature = "replace")]
 pub use replace::Replace;
 #[cfg(feature = "round")]
 pub use round::Round;
+#[cfg(feature = "set_if_absent")]
+pub use set_if_absent::SetIfAbsent;
 #[cfg(feature = "sha2")]
 pub use sha2::Sha2;
 #[cfg(feature = "sha3")]
 pub use sha3::Sha3;
+#[cfg(feature = "shell_quote")]
+pub use shell_quote::ShellQuote;
+#[cfg(feature = "shell_split")]
+pub use shell_split::ShellSplit;
+#[cfg(feature = "sizeof")]
+pub use sizeof::Sizeof;
 #[cfg(feature = "slice")]
 pub use slice::Slice;
+#[cfg(feature = "slice_bytes")]
+pub use slice_bytes::SliceBytes;
+#[cfg(feature = "snake_case_keys")]
+pub use snake_case_keys::SnakeCaseKeys;
+#[cfg(feature = "soundex")]
+pub use soundex::Soundex;
 #[cfg(feature = "split")]
 pub use split::Split;
+#[cfg(feature = "sprintf")]
+pub use sprintf::Sprintf;
 #[cfg(feature = "starts_with")]
 pub use starts_with::StartsWith;
+#[cfg(feature = "stats")]
+pub use stats::Stats;
 #[cfg(feature = "string")]
 pub use string::String;
 #[cfg(feature = "strip_ansi_escape_codes")]
 pub use strip_ansi_escape_codes::StripAnsiEscapeCodes;
+#[cfg(feature = "strip_html")]
+pub use strip_html::StripHtml;
 #[cfg(feature = "strip_whitespace")]
 pub use strip_whitespace::StripWhitespace;
 #[cfg(feature = "tag_types_externally")]
 pub use tag_types_externally::TagTypesExternally;
+#[cfg(feature = "time_ago")]
+pub use time_ago::TimeAgo;
 #[cfg(feature = "timestamp")]
 pub use timestamp::Timestamp;
 #[cfg(feature = "to_bool")]
@@ -323,59 +800,171 @@ pub use to_syslog_severity::ToSyslogSeverity;
 pub use to_timestamp::ToTimestamp;
 #[cfg(feature = "to_unix_timestamp")]
 pub use to_unix_timestamp::ToUnixTimestamp;
+#[cfg(feature = "token_bucket")]
+pub use token_bucket::TokenBucket;
+#[cfg(feature = "tokenize")]
+pub use tokenize::Tokenize;
 #[cfg(feature = "truncate")]
 pub use truncate::Truncate;
+#[cfg(feature = "try_all")]
+pub use try_all::TryAll;
+#[cfg(feature = "unique_bloom")]
+pub use unique_bloom::UniqueBloom;
 #[cfg(feature = "upcase")]
 pub use upcase::Upcase;
 #[cfg(feature = "uuid_v4")]
 pub use uuid_v4::UuidV4;
+#[cfg(feature = "vector_add")]
+pub use vector_add::VectorAdd;
+#[cfg(feature = "vector_scale")]
+pub use vector_scale::VectorScale;
+#[cfg(feature = "windows")]
+pub use windows::Windows;
+#[cfg(feature = "xor_bytes")]
+pub use xor_bytes::XorBytes;
 
 pub fn all() -> Vec<Box<dyn vrl::Function>> {
     vec![
+        #[cfg(feature = "abort")]
+        Box::new(Abort),
+        #[cfg(feature = "add_business_days")]
+        Box::new(AddBusinessDays),
         #[cfg(feature = "array")]
         Box::new(Array),
         #[cfg(feature = "append")]
         Box::new(Append),
         #[cfg(feature = "assert")]
         Box::new(Assert),
+        #[cfg(feature = "basename")]
+        Box::new(Basename),
         #[cfg(feature = "boolean")]
         Box::new(Boolean),
+        #[cfg(feature = "business_days_between")]
+        Box::new(BusinessDaysBetween),
+        #[cfg(feature = "camel_case_keys")]
+        Box::new(CamelCaseKeys),
+        #[cfg(feature = "canonicalize")]
+        Box::new(Canonicalize),
+        #[cfg(feature = "capture_error")]
+        Box::new(CaptureError),
+        #[cfg(feature = "card_brand")]
+        Box::new(CardBrand),
         #[cfg(feature = "ceil")]
         Box::new(Ceil),
+        #[cfg(feature = "classify")]
+        Box::new(Classify),
+        #[cfg(feature = "coerce_types")]
+        Box::new(CoerceTypes),
         #[cfg(feature = "compact")]
         Box::new(Compact),
+        #[cfg(feature = "compare_semver")]
+        Box::new(CompareSemver),
+        #[cfg(feature = "compile_template")]
+        Box::new(CompileTemplate),
+        #[cfg(feature = "concat")]
+        Box::new(Concat),
+        #[cfg(feature = "concat_bytes")]
+        Box::new(ConcatBytes),
         #[cfg(feature = "contains")]
         Box::new(Contains),
+        #[cfg(feature = "contrast_ratio")]
+        Box::new(ContrastRatio),
+        #[cfg(feature = "cron_matches")]
+        Box::new(CronMatches),
+        #[cfg(feature = "cron_next")]
+        Box::new(CronNext),
         #[cfg(feature = "decode_base64")]
         Box::new(DecodeBase64),
+        #[cfg(feature = "decode_cbor")]
+        Box::new(DecodeCbor),
+        #[cfg(feature = "decode_deflate")]
+        Box::new(DecodeDeflate),
+        #[cfg(feature = "decode_hex")]
+        Box::new(DecodeHex),
+        #[cfg(feature = "decode_lz4")]
+        Box::new(DecodeLz4),
+        #[cfg(feature = "decode_percent")]
+        Box::new(DecodePercent),
+        #[cfg(feature = "decode_snappy")]
+        Box::new(DecodeSnappy),
+        #[cfg(feature = "deep_equal")]
+        Box::new(DeepEqual),
         #[cfg(feature = "del")]
         Box::new(Del),
+        #[cfg(feature = "detect_content_type")]
+        Box::new(DetectContentType),
+        #[cfg(feature = "dirname")]
+        Box::new(Dirname),
+        #[cfg(feature = "dot_product")]
+        Box::new(DotProduct),
         #[cfg(feature = "downcase")]
         Box::new(Downcase),
+        #[cfg(feature = "ellipsize_middle")]
+        Box::new(EllipsizeMiddle),
         #[cfg(feature = "encode_base64")]
         Box::new(EncodeBase64),
+        #[cfg(feature = "encode_cbor")]
+        Box::new(EncodeCbor),
+        #[cfg(feature = "encode_deflate")]
+        Box::new(EncodeDeflate),
+        #[cfg(feature = "encode_fixed_width")]
+        Box::new(EncodeFixedWidth),
+        #[cfg(feature = "encode_hex")]
+        Box::new(EncodeHex),
+        #[cfg(feature = "encode_influx")]
+        Box::new(EncodeInflux),
         #[cfg(feature = "encode_json")]
         Box::new(EncodeJson),
+        #[cfg(feature = "encode_lz4")]
+        Box::new(EncodeLz4),
+        #[cfg(feature = "encode_percent")]
+        Box::new(EncodePercent),
+        #[cfg(feature = "encode_snappy")]
+        Box::new(EncodeSnappy),
         #[cfg(feature = "ends_with")]
         Box::new(EndsWith),
+        #[cfg(feature = "ewma")]
+        Box::new(Ewma),
         #[cfg(feature = "exists")]
         Box::new(Exists),
+        #[cfg(feature = "extname")]
+        Box::new(Extname),
+        #[cfg(feature = "file_extension")]
+        Box::new(FileExtension),
         #[cfg(feature = "flatten")]
         Box::new(Flatten),
         #[cfg(feature = "float")]
         Box::new(Float),
         #[cfg(feature = "floor")]
         Box::new(Floor),
+        #[cfg(feature = "format_mac_address")]
+        Box::new(FormatMacAddress),
         #[cfg(feature = "format_number")]
         Box::new(FormatNumber),
         #[cfg(feature = "format_timestamp")]
         Box::new(FormatTimestamp),
+        #[cfg(feature = "from_hex_dump")]
+        Box::new(FromHexDump),
         #[cfg(feature = "get_env_var")]
         Box::new(GetEnvVar),
         #[cfg(feature = "get_hostname")]
         Box::new(GetHostname),
+        #[cfg(feature = "glob_match")]
+        Box::new(GlobMatch),
+        #[cfg(feature = "hash_color")]
+        Box::new(HashColor),
+        #[cfg(feature = "hex_dump")]
+        Box::new(HexDump),
+        #[cfg(feature = "html_escape")]
+        Box::new(HtmlEscape),
+        #[cfg(feature = "html_unescape")]
+        Box::new(HtmlUnescape),
+        #[cfg(feature = "humanize_number")]
+        Box::new(HumanizeNumber),
         #[cfg(feature = "includes")]
         Box::new(Includes),
+        #[cfg(feature = "infer_schema")]
+        Box::new(InferSchema),
         #[cfg(feature = "integer")]
         Box::new(Integer),
         #[cfg(feature = "ip_cidr_contains")]
@@ -384,22 +973,60 @@ pub fn all() -> Vec<Box<dyn vrl::Function>> {
         Box::new(IpSubnet),
         #[cfg(feature = "ip_to_ipv6")]
         Box::new(IpToIpv6),
+        #[cfg(feature = "ip_version")]
+        Box::new(IpVersion),
         #[cfg(feature = "ipv6_to_ipv4")]
         Box::new(Ipv6ToIpV4),
+        #[cfg(feature = "is_ip")]
+        Box::new(IsIp),
         #[cfg(feature = "is_nullish")]
         Box::new(IsNullish),
+        #[cfg(feature = "is_private_ip")]
+        Box::new(IsPrivateIp),
+        #[cfg(feature = "ja3")]
+        Box::new(Ja3),
+        #[cfg(feature = "ja3_raw")]
+        Box::new(Ja3Raw),
+        #[cfg(feature = "jmespath")]
+        Box::new(Jmespath),
         #[cfg(feature = "join")]
         Box::new(Join),
+        #[cfg(feature = "join_path")]
+        Box::new(JoinPath),
         #[cfg(feature = "length")]
         Box::new(Length),
+        #[cfg(feature = "like")]
+        Box::new(Like),
         #[cfg(feature = "log")]
         Box::new(Log),
+        #[cfg(feature = "luhn_check")]
+        Box::new(LuhnCheck),
+        #[cfg(feature = "luminance")]
+        Box::new(Luminance),
+        #[cfg(feature = "mac_vendor")]
+        Box::new(MacVendor),
+        #[cfg(feature = "mask")]
+        Box::new(Mask),
         #[cfg(feature = "match")]
         Box::new(Match),
+        #[cfg(feature = "match_any")]
+        Box::new(MatchAny),
         #[cfg(feature = "md5")]
         Box::new(Md5),
         #[cfg(feature = "merge")]
         Box::new(Merge),
+        #[cfg(feature = "merge_schemas")]
+        Box::new(MergeSchemas),
+        #[cfg(feature = "metaphone")]
+        Box::new(Metaphone),
+        #[cfg(feature = "mime_type")]
+        Box::new(MimeType),
+        #[cfg(feature = "moving_average")]
+        Box::new(MovingAverage),
+        #[cfg(feature = "ngrams")]
+        Box::new(Ngrams),
+        #[cfg(feature = "normalize_email")]
+        Box::new(NormalizeEmail),
         #[cfg(feature = "now")]
         Box::new(Now),
         // We are not sure if this is the way we want to expose this functionality yet
@@ -408,18 +1035,34 @@ pub fn all() -> Vec<Box<dyn vrl::Function>> {
         //Box::new(OnlyFields),
         #[cfg(feature = "object")]
         Box::new(Object),
+        #[cfg(feature = "ordinal")]
+        Box::new(Ordinal),
         #[cfg(feature = "parse_aws_alb_log")]
         Box::new(ParseAwsAlbLog),
         #[cfg(feature = "parse_aws_cloudwatch_log_subscription_message")]
         Box::new(ParseAwsCloudWatchLogSubscriptionMessage),
         #[cfg(feature = "parse_aws_vpc_flow_log")]
         Box::new(ParseAwsVpcFlowLog),
+        #[cfg(feature = "parse_color")]
+        Box::new(ParseColor),
+        #[cfg(feature = "parse_dns_query")]
+        Box::new(ParseDnsQuery),
         #[cfg(feature = "parse_duration")]
         Box::new(ParseDuration),
+        #[cfg(feature = "parse_email")]
+        Box::new(ParseEmail),
+        #[cfg(feature = "parse_fixed_width")]
+        Box::new(ParseFixedWidth),
         #[cfg(feature = "parse_glog")]
         Box::new(ParseGlog),
         #[cfg(feature = "parse_grok")]
         Box::new(ParseGrok),
+        #[cfg(feature = "parse_html")]
+        Box::new(ParseHtml),
+        #[cfg(feature = "parse_influx")]
+        Box::new(ParseInflux),
+        #[cfg(feature = "parse_iso8601_duration")]
+        Box::new(ParseIso8601Duration),
         #[cfg(feature = "parse_json")]
         Box::new(ParseJson),
         #[cfg(feature = "parse_apache_log")]
@@ -430,10 +1073,28 @@ pub fn all() -> Vec<Box<dyn vrl::Function>> {
         Box::new(ParseKeyValue),
         #[cfg(feature = "parse_logfmt")]
         Box::new(ParseLogFmt),
+        #[cfg(feature = "parse_mac_address")]
+        Box::new(ParseMacAddress),
+        #[cfg(feature = "parse_markdown")]
+        Box::new(ParseMarkdown),
+        #[cfg(feature = "parse_number_array")]
+        Box::new(ParseNumberArray),
+        #[cfg(feature = "parse_parquet_schema")]
+        Box::new(ParseParquetSchema),
+        #[cfg(feature = "parse_php_serialized")]
+        Box::new(ParsePhpSerialized),
+        #[cfg(feature = "parse_prometheus")]
+        Box::new(ParsePrometheus),
         #[cfg(feature = "parse_regex")]
         Box::new(ParseRegex),
         #[cfg(feature = "parse_regex_all")]
         Box::new(ParseRegexAll),
+        #[cfg(feature = "parse_ruby_hash")]
+        Box::new(ParseRubyHash),
+        #[cfg(feature = "parse_semver")]
+        Box::new(ParseSemver),
+        #[cfg(feature = "parse_statsd")]
+        Box::new(ParseStatsd),
         #[cfg(feature = "parse_syslog")]
         Box::new(ParseSyslog),
         #[cfg(feature = "parse_timestamp")]
@@ -442,36 +1103,76 @@ pub fn all() -> Vec<Box<dyn vrl::Function>> {
         Box::new(ParseTokens),
         #[cfg(feature = "parse_url")]
         Box::new(ParseUrl),
+        #[cfg(feature = "percentile")]
+        Box::new(Percentile),
+        #[cfg(feature = "pluralize")]
+        Box::new(Pluralize),
+        #[cfg(feature = "public_suffix")]
+        Box::new(PublicSuffix),
         #[cfg(feature = "push")]
         Box::new(Push),
         #[cfg(feature = "match")]
         Box::new(Match),
+        #[cfg(feature = "quantile")]
+        Box::new(Quantile),
+        #[cfg(feature = "random_int")]
+        Box::new(RandomInt),
         //#[cfg(feature = "redact")]
         //Box::new(Redact),
+        #[cfg(feature = "registrable_domain")]
+        Box::new(RegistrableDomain),
+        #[cfg(feature = "rename_key")]
+        Box::new(RenameKey),
+        #[cfg(feature = "rename_keys")]
+        Box::new(RenameKeys),
+        #[cfg(feature = "render")]
+        Box::new(Render),
         #[cfg(feature = "replace")]
         Box::new(Replace),
         #[cfg(feature = "round")]
         Box::new(Round),
+        #[cfg(feature = "set_if_absent")]
+        Box::new(SetIfAbsent),
         #[cfg(feature = "sha1")]
         Box::new(Sha1),
         #[cfg(feature = "sha2")]
         Box::new(Sha2),
         #[cfg(feature = "sha3")]
         Box::new(Sha3),
+        #[cfg(feature = "shell_quote")]
+        Box::new(ShellQuote),
+        #[cfg(feature = "shell_split")]
+        Box::new(ShellSplit),
+        #[cfg(feature = "sizeof")]
+        Box::new(Sizeof),
         #[cfg(feature = "slice")]
         Box::new(Slice),
+        #[cfg(feature = "slice_bytes")]
+        Box::new(SliceBytes),
+        #[cfg(feature = "snake_case_keys")]
+        Box::new(SnakeCaseKeys),
+        #[cfg(feature = "soundex")]
+        Box::new(Soundex),
         #[cfg(feature = "split")]
         Box::new(Split),
+        #[cfg(feature = "sprintf")]
+        Box::new(Sprintf),
         #[cfg(feature = "starts_with")]
         Box::new(StartsWith),
+        #[cfg(feature = "stats")]
+        Box::new(Stats),
         #[cfg(feature = "string")]
         Box::new(String),
         #[cfg(feature = "strip_ansi_escape_codes")]
         Box::new(StripAnsiEscapeCodes),
+        #[cfg(feature = "strip_html")]
+        Box::new(StripHtml),
         #[cfg(feature = "strip_whitespace")]
         Box::new(StripWhitespace),
         #[cfg(feature = "tag_types_externally")]
         Box::new(TagTypesExternally),
+        #[cfg(feature = "time_ago")]
+        Box::new(TimeAgo),
         #[cfg(feature = "timestamp")]
         Box::new(Timestamp),
         #[cfg(feature = "to_bool")]
@@ -492,11 +1193,27 @@ pub fn all() -> Vec<Box<dyn vrl::Function>> {
         Box::new(ToTimestamp),
         #[cfg(feature = "to_unix_timestamp")]
         Box::new(ToUnixTimestamp),
+        #[cfg(feature = "token_bucket")]
+        Box::new(TokenBucket),
+        #[cfg(feature = "tokenize")]
+        Box::new(Tokenize),
         #[cfg(feature = "truncate")]
         Box::new(Truncate),
+        #[cfg(feature = "try_all")]
+        Box::new(TryAll),
+        #[cfg(feature = "unique_bloom")]
+        Box::new(UniqueBloom),
         #[cfg(feature = "upcase")]
         Box::new(Upcase),
         #[cfg(feature = "uuid_v4")]
         Box::new(UuidV4),
+        #[cfg(feature = "vector_add")]
+        Box::new(VectorAdd),
+        #[cfg(feature = "vector_scale")]
+        Box::new(VectorScale),
+        #[cfg(feature = "windows")]
+        Box::new(Windows),
+        #[cfg(feature = "xor_bytes")]
+        Box::new(XorBytes),
     ]
 }
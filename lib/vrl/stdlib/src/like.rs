@@ -0,0 +1,410 @@
+use std::fmt;
+use vrl::{
+    diagnostic::{Label, Span},
+    prelude::*,
+};
+
+#[derive(Debug)]
+pub enum Error {
+    InvalidEscape { escape: String },
+    InvalidPattern { pattern: String, escape: char },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::InvalidEscape { escape } => {
+                write!(
+                    f,
+                    r#"invalid escape "{}": must be a single character"#,
+                    escape
+                )
+            }
+            Error::InvalidPattern { pattern, escape } => write!(
+                f,
+                r#"invalid pattern "{}": ends with the escape character "{}""#,
+                pattern, escape
+            ),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl DiagnosticError for Error {
+    fn code(&self) -> usize {
+        109
+    }
+
+    fn labels(&self) -> Vec<Label> {
+        vec![Label::primary(self.to_string(), Span::default())]
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Like;
+
+impl Function for Like {
+    fn identifier(&self) -> &'static str {
+        "like"
+    }
+
+    fn is_pure(&self) -> bool {
+        true
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[
+            Parameter {
+                keyword: "value",
+                kind: kind::BYTES,
+                required: true,
+            },
+            Parameter {
+                keyword: "pattern",
+                kind: kind::BYTES,
+                required: true,
+            },
+            Parameter {
+                keyword: "escape",
+                kind: kind::BYTES,
+                required: false,
+            },
+            Parameter {
+                keyword: "case_sensitive",
+                kind: kind::BOOLEAN,
+                required: false,
+            },
+        ]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "match",
+                source: r#"like("GET /api/users", "GET /api/%")"#,
+                result: Ok("true"),
+            },
+            Example {
+                title: "single character",
+                source: r#"like("HTTP/1.1", "HTTP/_._")"#,
+                result: Ok("true"),
+            },
+            Example {
+                title: "escaped wildcard",
+                source: r#"like("50% off", "50!%%", escape: "!")"#,
+                result: Ok("true"),
+            },
+            Example {
+                title: "case insensitive",
+                source: r#"like("ERROR: disk full", "error:%", case_sensitive: false)"#,
+                result: Ok("true"),
+            },
+        ]
+    }
+
+    fn compile(&self, mut arguments: ArgumentList) -> Compiled {
+        let value = arguments.required("value");
+        let pattern = arguments.required("pattern");
+        let case_sensitive = arguments.optional("case_sensitive");
+
+        let escape = match arguments.optional_literal("escape")? {
+            Some(escape) => {
+                let escape = escape
+                    .to_value()
+                    .try_bytes_utf8_lossy()
+                    .expect("escape not bytes")
+                    .into_owned();
+
+                let mut chars = escape.chars();
+                match (chars.next(), chars.next()) {
+                    (Some(c), None) => c,
+                    _ => return Err(Box::new(Error::InvalidEscape { escape })),
+                }
+            }
+            None => '\\',
+        };
+
+        // A literal pattern is compiled once, here, so that an invalid one is
+        // reported at compile-time.
+        let pattern = match pattern.as_value() {
+            Some(pattern) => {
+                let pattern = pattern
+                    .try_bytes_utf8_lossy()
+                    .map_err(|e| Box::new(e) as Box<dyn DiagnosticError>)?;
+                let pattern = compile_pattern(&pattern, escape)
+                    .map_err(|e| Box::new(e) as Box<dyn DiagnosticError>)?;
+
+                LikePattern::Compiled(pattern)
+            }
+            None => LikePattern::Dynamic(pattern),
+        };
+
+        Ok(Box::new(LikeFn {
+            value,
+            pattern,
+            escape,
+            case_sensitive,
+        }))
+    }
+}
+
+/// A part of a pattern.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Token {
+    /// `%`, any sequence of characters, including none.
+    Any,
+
+    /// `_`, exactly one character.
+    One,
+    Char(char),
+}
+
+fn compile_pattern(pattern: &str, escape: char) -> std::result::Result<Vec<Token>, Error> {
+    let mut tokens = vec![];
+    let mut chars = pattern.chars();
+
+    while let Some(c) = chars.next() {
+        let token = match c {
+            // Any character can be escaped, not only the wildcards.
+            c if c == escape => match chars.next() {
+                Some(c) => Token::Char(c),
+                None => {
+                    return Err(Error::InvalidPattern {
+                        pattern: pattern.to_owned(),
+                        escape,
+                    })
+                }
+            },
+            '%' => Token::Any,
+            '_' => Token::One,
+            c => Token::Char(c),
+        };
+
+        tokens.push(token);
+    }
+
+    Ok(tokens)
+}
+
+/// Matches the whole value against the pattern. When a character doesn't
+/// match, the last `%` is made to match one more character, and matching
+/// starts over after it.
+fn is_match(pattern: &[Token], value: &str, case_sensitive: bool) -> bool {
+    let value = value.chars().collect::<Vec<_>>();
+    let eq =
+        |a: char, b: char| a == b || (!case_sensitive && a.to_lowercase().eq(b.to_lowercase()));
+
+    let mut token = 0;
+    let mut position = 0;
+    let mut backtrack = None;
+
+    while position < value.len() {
+        match pattern.get(token) {
+            Some(Token::Any) => {
+                token += 1;
+                backtrack = Some((token, position));
+                continue;
+            }
+            Some(Token::One) => {
+                token += 1;
+                position += 1;
+                continue;
+            }
+            Some(Token::Char(c)) if eq(*c, value[position]) => {
+                token += 1;
+                position += 1;
+                continue;
+            }
+            _ => {}
+        }
+
+        match backtrack {
+            Some((after_any, start)) => {
+                token = after_any;
+                position = start + 1;
+                backtrack = Some((after_any, start + 1));
+            }
+            None => return false,
+        }
+    }
+
+    pattern[token..].iter().all(|token| *token == Token::Any)
+}
+
+#[derive(Debug, Clone)]
+enum LikePattern {
+    Compiled(Vec<Token>),
+    Dynamic(Box<dyn Expression>),
+}
+
+#[derive(Debug, Clone)]
+struct LikeFn {
+    value: Box<dyn Expression>,
+    pattern: LikePattern,
+    escape: char,
+    case_sensitive: Option<Box<dyn Expression>>,
+}
+
+impl Expression for LikeFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?;
+        let string = value.try_bytes_utf8_lossy()?;
+
+        let case_sensitive = match &self.case_sensitive {
+            Some(expr) => expr.resolve(ctx)?.try_boolean()?,
+            None => true,
+        };
+
+        let is_match = match &self.pattern {
+            LikePattern::Compiled(pattern) => is_match(pattern, &string, case_sensitive),
+            LikePattern::Dynamic(pattern) => {
+                let pattern = pattern.resolve(ctx)?;
+                let pattern = pattern.try_bytes_utf8_lossy()?;
+                let pattern = compile_pattern(&pattern, self.escape).map_err(|e| e.to_string())?;
+
+                is_match(&pattern, &string, case_sensitive)
+            }
+        };
+
+        Ok(is_match.into())
+    }
+
+    fn type_def(&self, _: &state::Compiler) -> TypeDef {
+        TypeDef::new()
+            .boolean()
+            .with_fallibility(matches!(self.pattern, LikePattern::Dynamic(_)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    test_function![
+        like => Like;
+
+        percent {
+            args: func_args![value: "GET /api/users", pattern: "GET /api/%"],
+            want: Ok(true),
+            tdef: TypeDef::new().infallible().boolean(),
+        }
+
+        percent_matches_nothing {
+            args: func_args![value: "GET /api/", pattern: "GET /api/%"],
+            want: Ok(true),
+            tdef: TypeDef::new().infallible().boolean(),
+        }
+
+        percent_backtracks {
+            args: func_args![value: "abcbcd", pattern: "a%bcd"],
+            want: Ok(true),
+            tdef: TypeDef::new().infallible().boolean(),
+        }
+
+        percent_mismatch {
+            args: func_args![value: "POST /api/users", pattern: "GET %"],
+            want: Ok(false),
+            tdef: TypeDef::new().infallible().boolean(),
+        }
+
+        underscore {
+            args: func_args![value: "HTTP/1.1", pattern: "HTTP/_._"],
+            want: Ok(true),
+            tdef: TypeDef::new().infallible().boolean(),
+        }
+
+        underscore_is_one_character {
+            args: func_args![value: "HTTP/1.10", pattern: "HTTP/_._"],
+            want: Ok(false),
+            tdef: TypeDef::new().infallible().boolean(),
+        }
+
+        underscore_unicode {
+            args: func_args![value: "café", pattern: "caf_"],
+            want: Ok(true),
+            tdef: TypeDef::new().infallible().boolean(),
+        }
+
+        whole_value {
+            args: func_args![value: "error: disk full", pattern: "error"],
+            want: Ok(false),
+            tdef: TypeDef::new().infallible().boolean(),
+        }
+
+        escaped_percent {
+            args: func_args![value: "50% off", pattern: r#"50\%%"#],
+            want: Ok(true),
+            tdef: TypeDef::new().infallible().boolean(),
+        }
+
+        escaped_percent_is_literal {
+            args: func_args![value: "500 off", pattern: r#"50\%%"#],
+            want: Ok(false),
+            tdef: TypeDef::new().infallible().boolean(),
+        }
+
+        custom_escape {
+            args: func_args![value: "file_1", pattern: "file!__", escape: "!"],
+            want: Ok(true),
+            tdef: TypeDef::new().infallible().boolean(),
+        }
+
+        custom_escape_is_literal {
+            args: func_args![value: "fileX1", pattern: "file!__", escape: "!"],
+            want: Ok(false),
+            tdef: TypeDef::new().infallible().boolean(),
+        }
+
+        case_sensitive {
+            args: func_args![value: "ERROR: disk full", pattern: "error:%"],
+            want: Ok(false),
+            tdef: TypeDef::new().infallible().boolean(),
+        }
+
+        case_insensitive {
+            args: func_args![value: "ERROR: disk full", pattern: "error:%", case_sensitive: false],
+            want: Ok(true),
+            tdef: TypeDef::new().infallible().boolean(),
+        }
+
+        invalid_pattern {
+            args: func_args![value: "50%", pattern: "50!", escape: "!"],
+            want: Err(r#"invalid pattern "50!": ends with the escape character "!""#),
+            tdef: TypeDef::new().infallible().boolean(),
+        }
+
+        invalid_escape {
+            args: func_args![value: "50%", pattern: "50%", escape: "!!"],
+            want: Err(r#"invalid escape "!!": must be a single character"#),
+            tdef: TypeDef::new().infallible().boolean(),
+        }
+    ];
+
+    #[test]
+    fn dynamic_pattern() {
+        let mut target = value!({});
+        let mut runtime_state = vrl::state::Runtime::default();
+        let mut ctx = Context::new(&mut target, &mut runtime_state);
+
+        let func = LikeFn {
+            value: expr!("GET /api/users"),
+            pattern: LikePattern::Dynamic(expr!("GET %")),
+            escape: '\\',
+            case_sensitive: None,
+        };
+        assert_eq!(func.resolve(&mut ctx), Ok(true.into()));
+        assert!(func.type_def(&state::Compiler::default()).is_fallible());
+
+        let func = LikeFn {
+            value: expr!("50%"),
+            pattern: LikePattern::Dynamic(expr!(r#"50\"#)),
+            escape: '\\',
+            case_sensitive: None,
+        };
+        assert_eq!(
+            func.resolve(&mut ctx).map_err(|e| e.to_string()),
+            Err(r#"invalid pattern "50\": ends with the escape character "\""#.to_owned())
+        );
+    }
+}
@@ -92,6 +92,10 @@ impl Expression for LogFn {
             _ => info!(message = %value, internal_log_rate_secs = rate_limit_secs),
         }
 
+        if let Some(hook) = ctx.state().log_hook() {
+            hook.log(&String::from_utf8_lossy(&self.level), &value);
+        }
+
         Ok(Value::Null)
     }
 
@@ -115,4 +119,40 @@ mod tests {
             tdef: TypeDef::new().infallible().null(),
         }
     ];
+
+    #[derive(Debug, Default)]
+    struct Logs(std::sync::Arc<std::sync::Mutex<Vec<(String, Value)>>>);
+
+    impl vrl::hook::LogHook for Logs {
+        fn log(&self, level: &str, value: &Value) {
+            self.0
+                .lock()
+                .unwrap()
+                .push((level.to_owned(), value.clone()));
+        }
+    }
+
+    #[test]
+    fn hook_receives_messages() {
+        let logs = Logs::default();
+        let messages = std::sync::Arc::clone(&logs.0);
+        let state = vrl::state::Runtime::default().with_log_hook(logs);
+
+        let source = r#"log("foo")
+log({ "bar": 1 }, level: "warn", rate_limit_secs: 60)
+log({ "bar": 1 }, level: "warn", rate_limit_secs: 60)"#;
+        let program = vrl::compile(source, &[Box::new(Log)]).unwrap();
+        vrl::Runtime::new(state)
+            .resolve(&mut value!({}), &program)
+            .unwrap();
+
+        assert_eq!(
+            *messages.lock().unwrap(),
+            vec![
+                ("info".to_owned(), value!("foo")),
+                ("warn".to_owned(), value!({ "bar": 1 })),
+                ("warn".to_owned(), value!({ "bar": 1 })),
+            ]
+        );
+    }
 }
@@ -0,0 +1,136 @@
+use crate::util;
+use vrl::prelude::*;
+
+#[derive(Clone, Copy, Debug)]
+pub struct LuhnCheck;
+
+impl Function for LuhnCheck {
+    fn identifier(&self) -> &'static str {
+        "luhn_check"
+    }
+
+    fn is_pure(&self) -> bool {
+        true
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[Parameter {
+            keyword: "value",
+            kind: kind::BYTES,
+            required: true,
+        }]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "valid",
+                source: r#"luhn_check!("4111 1111 1111 1111")"#,
+                result: Ok("true"),
+            },
+            Example {
+                title: "invalid",
+                source: r#"luhn_check!("4111 1111 1111 1112")"#,
+                result: Ok("false"),
+            },
+        ]
+    }
+
+    fn compile(&self, mut arguments: ArgumentList) -> Compiled {
+        let value = arguments.required("value");
+
+        Ok(Box::new(LuhnCheckFn { value }))
+    }
+}
+
+#[derive(Debug, Clone)]
+struct LuhnCheckFn {
+    value: Box<dyn Expression>,
+}
+
+impl Expression for LuhnCheckFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let bytes = self.value.resolve(ctx)?.try_bytes()?;
+        let digits = util::card_digits(&String::from_utf8_lossy(&bytes))?;
+
+        Ok(luhn(&digits).into())
+    }
+
+    fn type_def(&self, _: &state::Compiler) -> TypeDef {
+        TypeDef::new().fallible().boolean()
+    }
+}
+
+/// Validates the checksum of a string of ASCII digits, by doubling every
+/// second digit from the right and checking that the sum of the resulting
+/// digits is a multiple of ten.
+fn luhn(digits: &str) -> bool {
+    let sum: u32 = digits
+        .bytes()
+        .rev()
+        .map(|b| u32::from(b - b'0'))
+        .enumerate()
+        .map(|(i, d)| {
+            if i % 2 == 0 {
+                d
+            } else if d * 2 > 9 {
+                d * 2 - 9
+            } else {
+                d * 2
+            }
+        })
+        .sum();
+
+    sum % 10 == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    test_function![
+        luhn_check => LuhnCheck;
+
+        valid {
+            args: func_args![value: "79927398713"],
+            want: Ok(true),
+            tdef: TypeDef::new().fallible().boolean(),
+        }
+
+        invalid {
+            args: func_args![value: "79927398710"],
+            want: Ok(false),
+            tdef: TypeDef::new().fallible().boolean(),
+        }
+
+        valid_formatted {
+            args: func_args![value: "3782-822463-10005"],
+            want: Ok(true),
+            tdef: TypeDef::new().fallible().boolean(),
+        }
+
+        grouped {
+            args: func_args![value: "4111 1111 1111 1111"],
+            want: Ok(true),
+            tdef: TypeDef::new().fallible().boolean(),
+        }
+
+        single_digit {
+            args: func_args![value: "0"],
+            want: Ok(true),
+            tdef: TypeDef::new().fallible().boolean(),
+        }
+
+        non_numeric {
+            args: func_args![value: "4111-1111-abcd-1111"],
+            want: Err("value must only contain digits, spaces and dashes"),
+            tdef: TypeDef::new().fallible().boolean(),
+        }
+
+        empty {
+            args: func_args![value: ""],
+            want: Err("value must only contain digits, spaces and dashes"),
+            tdef: TypeDef::new().fallible().boolean(),
+        }
+    ];
+}
@@ -0,0 +1,118 @@
+use crate::color_util::Color;
+use vrl::prelude::*;
+
+#[derive(Clone, Copy, Debug)]
+pub struct Luminance;
+
+impl Function for Luminance {
+    fn identifier(&self) -> &'static str {
+        "luminance"
+    }
+
+    fn is_pure(&self) -> bool {
+        true
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[Parameter {
+            keyword: "color",
+            kind: kind::BYTES | kind::OBJECT,
+            required: true,
+        }]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "string",
+                source: r#"luminance!("red")"#,
+                result: Ok("0.2126"),
+            },
+            Example {
+                title: "object",
+                source: r#"luminance!({ "r": 255, "g": 255, "b": 255 })"#,
+                result: Ok("1.0"),
+            },
+        ]
+    }
+
+    fn compile(&self, mut arguments: ArgumentList) -> Compiled {
+        let color = arguments.required("color");
+
+        Ok(Box::new(LuminanceFn { color }))
+    }
+}
+
+#[derive(Debug, Clone)]
+struct LuminanceFn {
+    color: Box<dyn Expression>,
+}
+
+impl Expression for LuminanceFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let color = Color::from_value(&self.color.resolve(ctx)?)?;
+
+        Ok(color.luminance().into())
+    }
+
+    fn type_def(&self, _: &state::Compiler) -> TypeDef {
+        TypeDef::new().fallible().float()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    test_function![
+        luminance => Luminance;
+
+        white {
+            args: func_args![color: "#fff"],
+            want: Ok(1.0),
+            tdef: TypeDef::new().fallible().float(),
+        }
+
+        black {
+            args: func_args![color: "black"],
+            want: Ok(0.0),
+            tdef: TypeDef::new().fallible().float(),
+        }
+
+        red {
+            args: func_args![color: "rgb(255, 0, 0)"],
+            want: Ok(0.2126),
+            tdef: TypeDef::new().fallible().float(),
+        }
+
+        green_object {
+            args: func_args![color: value!({ "r": 0, "g": 255, "b": 0 })],
+            want: Ok(0.7152),
+            tdef: TypeDef::new().fallible().float(),
+        }
+
+        opacity_ignored {
+            args: func_args![color: value!({ "r": 0, "g": 0, "b": 255, "a": 0.5 })],
+            want: Ok(0.0722),
+            tdef: TypeDef::new().fallible().float(),
+        }
+
+        invalid_string {
+            args: func_args![color: "blurple"],
+            want: Err(r#"unable to parse color "blurple""#),
+            tdef: TypeDef::new().fallible().float(),
+        }
+
+        invalid_object {
+            args: func_args![color: value!({ "r": 256, "g": 0, "b": 0 })],
+            want: Err(r#"invalid color: "r" must be an integer between 0 and 255"#),
+            tdef: TypeDef::new().fallible().float(),
+        }
+
+        invalid_opacity {
+            args: func_args![color: value!({ "r": 0, "g": 0, "b": 0, "a": 2 })],
+            want: Err(r#"invalid color: "a" must be a number between 0 and 1"#),
+            tdef: TypeDef::new().fallible().float(),
+        }
+    ];
+}
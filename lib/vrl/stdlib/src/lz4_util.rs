@@ -0,0 +1,203 @@
+//! An encoder and decoder for the
+//! [LZ4 frame format](https://github.com/lz4/lz4/blob/dev/doc/lz4_Frame_format.md),
+//! on top of the block compression of `lz4_flex`.
+//!
+//! Frames are encoded with independent blocks of at most 64 KiB, and without
+//! block checksums. Any frame is decoded, including ones with linked blocks,
+//! block checksums, or a content size, but not ones that depend on a
+//! dictionary.
+
+use std::hash::Hasher;
+use twox_hash::XxHash32;
+
+const MAGIC_NUMBER: [u8; 4] = [0x04, 0x22, 0x4d, 0x18];
+
+// The flags of the frame descriptor.
+const VERSION: u8 = 0b0100_0000;
+#[cfg(feature = "decode_lz4")]
+const VERSION_MASK: u8 = 0b1100_0000;
+#[cfg(feature = "encode_lz4")]
+const INDEPENDENT_BLOCKS: u8 = 0b0010_0000;
+#[cfg(feature = "decode_lz4")]
+const BLOCK_CHECKSUMS: u8 = 0b0001_0000;
+#[cfg(feature = "decode_lz4")]
+const CONTENT_SIZE: u8 = 0b0000_1000;
+const CONTENT_CHECKSUM: u8 = 0b0000_0100;
+#[cfg(feature = "decode_lz4")]
+const RESERVED: u8 = 0b0000_0010;
+#[cfg(feature = "decode_lz4")]
+const DICTIONARY_ID: u8 = 0b0000_0001;
+
+/// The block descriptor of frames with blocks of at most 64 KiB.
+#[cfg(feature = "encode_lz4")]
+const BLOCK_MAX_SIZE_64KB: u8 = 4 << 4;
+
+/// The bit of the size of a block set if the block is stored uncompressed.
+const UNCOMPRESSED: u32 = 1 << 31;
+
+fn checksum(data: &[u8]) -> u32 {
+    let mut hasher = XxHash32::with_seed(0);
+    hasher.write(data);
+    hasher.finish() as u32
+}
+
+/// The checksum of the frame descriptor, which is the second byte of the hash
+/// of the descriptor.
+fn header_checksum(descriptor: &[u8]) -> u8 {
+    (checksum(descriptor) >> 8) as u8
+}
+
+#[cfg(feature = "encode_lz4")]
+pub(crate) fn encode(input: &[u8], content_checksum: bool) -> Vec<u8> {
+    let mut flags = VERSION | INDEPENDENT_BLOCKS;
+    if content_checksum {
+        flags |= CONTENT_CHECKSUM;
+    }
+
+    let mut output = MAGIC_NUMBER.to_vec();
+    let descriptor = [flags, BLOCK_MAX_SIZE_64KB];
+    output.extend_from_slice(&descriptor);
+    output.push(header_checksum(&descriptor));
+
+    // Blocks which don't get smaller when compressed are stored as they are.
+    for block in input.chunks(64 * 1024) {
+        let compressed = lz4_flex::compress(block);
+
+        if compressed.len() < block.len() {
+            output.extend_from_slice(&(compressed.len() as u32).to_le_bytes());
+            output.extend_from_slice(&compressed);
+        } else {
+            output.extend_from_slice(&(block.len() as u32 | UNCOMPRESSED).to_le_bytes());
+            output.extend_from_slice(block);
+        }
+    }
+
+    // The end mark is an empty block.
+    output.extend_from_slice(&[0; 4]);
+
+    if content_checksum {
+        output.extend_from_slice(&checksum(input).to_le_bytes());
+    }
+
+    output
+}
+
+#[cfg(feature = "decode_lz4")]
+pub(crate) fn decode(input: &[u8]) -> Result<Vec<u8>, &'static str> {
+    let mut reader = Reader(input);
+
+    if reader.take(4)? != MAGIC_NUMBER {
+        return Err("not an LZ4 frame");
+    }
+
+    let flags = reader.byte()?;
+    if flags & VERSION_MASK != VERSION {
+        return Err("unsupported frame version");
+    }
+    if flags & RESERVED != 0 {
+        return Err("reserved bit set");
+    }
+    if flags & DICTIONARY_ID != 0 {
+        return Err("frames depending on a dictionary are unsupported");
+    }
+
+    let block_descriptor = reader.byte()?;
+    let block_max_size = match (block_descriptor >> 4) & 0b111 {
+        4 => 64 * 1024,
+        5 => 256 * 1024,
+        6 => 1024 * 1024,
+        7 => 4 * 1024 * 1024,
+        _ => return Err("invalid block maximum size"),
+    };
+    if block_descriptor & 0b1000_1111 != 0 {
+        return Err("reserved bit set");
+    }
+
+    let content_size = if flags & CONTENT_SIZE != 0 {
+        Some(u64::from_le_bytes(reader.array()?))
+    } else {
+        None
+    };
+
+    // The header checksum covers the descriptor, from the flags up to the
+    // content size.
+    let descriptor = &input[4..input.len() - reader.0.len()];
+    if reader.byte()? != header_checksum(descriptor) {
+        return Err("invalid header checksum");
+    }
+
+    let mut output = vec![];
+
+    loop {
+        let size = u32::from_le_bytes(reader.array()?);
+        if size == 0 {
+            break;
+        }
+
+        let uncompressed = size & UNCOMPRESSED != 0;
+        let size = (size & !UNCOMPRESSED) as usize;
+        if size > block_max_size {
+            return Err("block larger than the maximum size");
+        }
+
+        let block = reader.take(size)?;
+        if flags & BLOCK_CHECKSUMS != 0 && u32::from_le_bytes(reader.array()?) != checksum(block) {
+            return Err("invalid block checksum");
+        }
+
+        // Linked blocks can refer to the data of the blocks before them, which
+        // are decompressed to the same vector.
+        let start = output.len();
+        if uncompressed {
+            output.extend_from_slice(block);
+        } else {
+            lz4_flex::block::decompress::decompress_into(block, &mut output)
+                .map_err(|_| "invalid block")?;
+        }
+
+        if output.len() - start > block_max_size {
+            return Err("block larger than the maximum size");
+        }
+    }
+
+    if flags & CONTENT_CHECKSUM != 0 && u32::from_le_bytes(reader.array()?) != checksum(&output) {
+        return Err("invalid content checksum");
+    }
+
+    if content_size.map_or(false, |size| size != output.len() as u64) {
+        return Err("invalid content size");
+    }
+
+    if !reader.0.is_empty() {
+        return Err("unexpected data after the frame");
+    }
+
+    Ok(output)
+}
+
+#[cfg(feature = "decode_lz4")]
+struct Reader<'a>(&'a [u8]);
+
+#[cfg(feature = "decode_lz4")]
+impl<'a> Reader<'a> {
+    fn take(&mut self, len: usize) -> Result<&'a [u8], &'static str> {
+        if self.0.len() < len {
+            return Err("unexpected end of frame");
+        }
+
+        let (taken, rest) = self.0.split_at(len);
+        self.0 = rest;
+        Ok(taken)
+    }
+
+    fn byte(&mut self) -> Result<u8, &'static str> {
+        Ok(self.take(1)?[0])
+    }
+
+    fn array<T: Default + AsMut<[u8]>>(&mut self) -> Result<T, &'static str> {
+        let mut array = T::default();
+        let len = array.as_mut().len();
+        array.as_mut().copy_from_slice(self.take(len)?);
+        Ok(array)
+    }
+}
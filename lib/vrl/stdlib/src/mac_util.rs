@@ -0,0 +1,88 @@
+//! Parsing and formatting of MAC addresses.
+
+#[cfg(any(feature = "format_mac_address", feature = "parse_mac_address"))]
+use std::str::FromStr;
+
+/// The ways of writing a MAC address.
+#[cfg(any(feature = "format_mac_address", feature = "parse_mac_address"))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Style {
+    /// Pairs of digits separated by colons, `00:1b:63:84:45:e6`, the canonical
+    /// form.
+    Colon,
+
+    /// Pairs of digits separated by dashes, `00-1b-63-84-45-e6`.
+    Dash,
+
+    /// Groups of four digits separated by dots, `001b.6384.45e6`, as Cisco
+    /// devices write them.
+    Cisco,
+}
+
+#[cfg(any(feature = "format_mac_address", feature = "parse_mac_address"))]
+impl FromStr for Style {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "colon" => Ok(Self::Colon),
+            "dash" => Ok(Self::Dash),
+            "cisco" => Ok(Self::Cisco),
+            _ => Err(format!(
+                r#"unknown style "{}", expected "colon", "dash" or "cisco""#,
+                s
+            )),
+        }
+    }
+}
+
+/// Parses a MAC address written in any of the [`Style`]s, or as twelve digits
+/// without separators. Hexadecimal digits can be in either case.
+pub(crate) fn parse(address: &str) -> Result<[u8; 6], String> {
+    let invalid = || format!("invalid MAC address {:?}", address);
+
+    let (groups, len): (Vec<_>, _) = if address.contains(':') {
+        (address.split(':').collect(), 2)
+    } else if address.contains('-') {
+        (address.split('-').collect(), 2)
+    } else if address.contains('.') {
+        (address.split('.').collect(), 4)
+    } else {
+        (vec![address], 12)
+    };
+
+    if groups.len() * len != 12
+        || groups
+            .iter()
+            .any(|group| group.len() != len || !group.bytes().all(|b| b.is_ascii_hexdigit()))
+    {
+        return Err(invalid());
+    }
+
+    let digits = groups.concat();
+    let mut octets = [0; 6];
+    for (i, octet) in octets.iter_mut().enumerate() {
+        *octet = u8::from_str_radix(&digits[i * 2..i * 2 + 2], 16).map_err(|_| invalid())?;
+    }
+
+    Ok(octets)
+}
+
+/// Formats the MAC address in the style, with lowercase digits.
+#[cfg(any(feature = "format_mac_address", feature = "parse_mac_address"))]
+pub(crate) fn format(octets: &[u8; 6], style: Style) -> String {
+    let hex = octets
+        .iter()
+        .map(|octet| format!("{:02x}", octet))
+        .collect::<Vec<_>>();
+
+    match style {
+        Style::Colon => hex.join(":"),
+        Style::Dash => hex.join("-"),
+        Style::Cisco => hex
+            .chunks(2)
+            .map(|pair| pair.concat())
+            .collect::<Vec<_>>()
+            .join("."),
+    }
+}
@@ -0,0 +1,255 @@
+use crate::mac_util;
+use lazy_static::lazy_static;
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
+use vrl::{
+    diagnostic::{Label, Span},
+    prelude::*,
+};
+
+/// Vendors by the organizationally unique identifier (OUI) of their MAC
+/// addresses, the first three octets.
+type Database = HashMap<[u8; 3], String>;
+
+lazy_static! {
+    /// The bundled database, a selection of well-known vendors, parsed once.
+    static ref BUNDLED: Arc<Database> = Arc::new(parse_database(include_str!("../data/oui.txt")));
+}
+
+#[derive(Debug)]
+pub enum Error {
+    Database { path: String, error: std::io::Error },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Database { path, error } => {
+                write!(f, "unable to read OUI database {:?}: {}", path, error)
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl DiagnosticError for Error {
+    fn code(&self) -> usize {
+        109
+    }
+
+    fn labels(&self) -> Vec<Label> {
+        vec![Label::primary(self.to_string(), Span::default())]
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct MacVendor;
+
+impl Function for MacVendor {
+    fn identifier(&self) -> &'static str {
+        "mac_vendor"
+    }
+
+    fn is_pure(&self) -> bool {
+        true
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[
+            Parameter {
+                keyword: "value",
+                kind: kind::BYTES,
+                required: true,
+            },
+            Parameter {
+                keyword: "database",
+                kind: kind::BYTES,
+                required: false,
+            },
+        ]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "known vendor",
+                source: r#"mac_vendor!("00:1b:63:84:45:e6")"#,
+                result: Ok("Apple, Inc."),
+            },
+            Example {
+                title: "locally administered",
+                source: r#"mac_vendor!("02:42:ac:11:00:02")"#,
+                result: Ok("null"),
+            },
+        ]
+    }
+
+    fn compile(&self, mut arguments: ArgumentList) -> Compiled {
+        let value = arguments.required("value");
+
+        // Supplied databases are read once, when the program is compiled.
+        let database = match arguments.optional_literal("database")? {
+            Some(path) => {
+                let path = path
+                    .to_value()
+                    .try_bytes_utf8_lossy()
+                    .expect("database not bytes")
+                    .into_owned();
+
+                let text = std::fs::read_to_string(&path).map_err(|error| {
+                    Box::new(Error::Database { path, error }) as Box<dyn DiagnosticError>
+                })?;
+
+                Arc::new(parse_database(&text))
+            }
+            None => BUNDLED.clone(),
+        };
+
+        Ok(Box::new(MacVendorFn { value, database }))
+    }
+}
+
+/// Parses a database in the format of the `oui.txt` file of the IEEE
+/// registry, in which each vendor has a line such as
+/// `00-1B-63   (hex)\t\tApple, Inc.`. Lines without an OUI are skipped.
+fn parse_database(text: &str) -> Database {
+    text.lines()
+        .filter_map(|line| {
+            let mut parts = line.trim().splitn(2, char::is_whitespace);
+            let oui = parts.next()?.replace(|c| c == '-' || c == ':', "");
+            let rest = parts.next()?.trim_start();
+            let vendor = rest
+                .strip_prefix("(hex)")
+                .or_else(|| rest.strip_prefix("(base 16)"))?
+                .trim();
+
+            if oui.len() != 6 || vendor.is_empty() {
+                return None;
+            }
+
+            let mut octets = [0; 3];
+            for (i, octet) in octets.iter_mut().enumerate() {
+                *octet = u8::from_str_radix(oui.get(i * 2..i * 2 + 2)?, 16).ok()?;
+            }
+
+            Some((octets, vendor.to_owned()))
+        })
+        .collect()
+}
+
+#[derive(Debug, Clone)]
+struct MacVendorFn {
+    value: Box<dyn Expression>,
+    database: Arc<Database>,
+}
+
+impl Expression for MacVendorFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?.try_bytes()?;
+        let octets = mac_util::parse(String::from_utf8_lossy(&value).trim())?;
+
+        // Locally administered addresses aren't assigned by the IEEE, their
+        // first octets aren't an OUI.
+        if octets[0] & 0x02 != 0 {
+            return Ok(Value::Null);
+        }
+
+        Ok(self
+            .database
+            .get(&[octets[0], octets[1], octets[2]])
+            .cloned()
+            .into())
+    }
+
+    fn type_def(&self, _: &state::Compiler) -> TypeDef {
+        TypeDef::new().fallible().bytes().add_null()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Writes a database to a temporary file, returning its path.
+    fn database(name: &str, text: &str) -> String {
+        let path = std::env::temp_dir().join(format!("vrl-mac-vendor-{}.txt", name));
+        std::fs::write(&path, text).unwrap();
+
+        path.to_string_lossy().into_owned()
+    }
+
+    test_function![
+        mac_vendor => MacVendor;
+
+        known_vendor {
+            args: func_args![value: "00:1B:63:84:45:E6"],
+            want: Ok("Apple, Inc."),
+            tdef: TypeDef::new().fallible().bytes().add_null(),
+        }
+
+        known_vendor_cisco_style {
+            args: func_args![value: "0050.5612.3456"],
+            want: Ok("VMware, Inc."),
+            tdef: TypeDef::new().fallible().bytes().add_null(),
+        }
+
+        unknown_vendor {
+            args: func_args![value: "00:ab:cd:00:00:01"],
+            want: Ok(Value::Null),
+            tdef: TypeDef::new().fallible().bytes().add_null(),
+        }
+
+        locally_administered {
+            args: func_args![value: "02:42:ac:11:00:02"],
+            want: Ok(Value::Null),
+            tdef: TypeDef::new().fallible().bytes().add_null(),
+        }
+
+        invalid_address {
+            args: func_args![value: "00:1b:63"],
+            want: Err(r#"invalid MAC address "00:1b:63""#),
+            tdef: TypeDef::new().fallible().bytes().add_null(),
+        }
+
+        supplied_database {
+            args: func_args![
+                value: "00-AB-CD-00-00-01",
+                database: database("supplied", "00-AB-CD   (hex)\t\tExample Corp\n00ABCD     (base 16)\t\tExample Corp\n\n  Somewhere\n"),
+            ],
+            want: Ok("Example Corp"),
+            tdef: TypeDef::new().fallible().bytes().add_null(),
+        }
+
+        supplied_database_replaces_bundled {
+            args: func_args![
+                value: "00:1b:63:84:45:e6",
+                database: database("replaces", "00-AB-CD   (hex)\t\tExample Corp\n"),
+            ],
+            want: Ok(Value::Null),
+            tdef: TypeDef::new().fallible().bytes().add_null(),
+        }
+    ];
+
+    #[test]
+    fn missing_database() {
+        let args = func_args![value: "00:1b:63:84:45:e6", database: "/nonexistent/oui.txt"];
+
+        match MacVendor.compile(args.into()) {
+            Err(error) => assert!(error
+                .message()
+                .starts_with(r#"unable to read OUI database "/nonexistent/oui.txt": "#)),
+            Ok(_) => panic!("expected an error"),
+        }
+    }
+
+    #[test]
+    fn bundled_database() {
+        assert_eq!(
+            BUNDLED.get(&[0x08, 0x00, 0x27]).map(String::as_str),
+            Some("PCS Systemtechnik GmbH")
+        );
+        assert!(BUNDLED.len() > 20);
+    }
+}
@@ -0,0 +1,174 @@
+use vrl::prelude::*;
+
+#[derive(Clone, Copy, Debug)]
+pub struct Mask;
+
+impl Function for Mask {
+    fn identifier(&self) -> &'static str {
+        "mask"
+    }
+
+    fn is_pure(&self) -> bool {
+        true
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[
+            Parameter {
+                keyword: "value",
+                kind: kind::BYTES,
+                required: true,
+            },
+            Parameter {
+                keyword: "keep_start",
+                kind: kind::INTEGER,
+                required: false,
+            },
+            Parameter {
+                keyword: "keep_end",
+                kind: kind::INTEGER,
+                required: false,
+            },
+            Parameter {
+                keyword: "mask_char",
+                kind: kind::BYTES,
+                required: false,
+            },
+        ]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "card number",
+                source: r#"mask!("4111111111111111", keep_start: 4)"#,
+                result: Ok("4111********1111"),
+            },
+            Example {
+                title: "custom mask character",
+                source: r##"mask!("secret", keep_end: 0, mask_char: "#")"##,
+                result: Ok("######"),
+            },
+        ]
+    }
+
+    fn compile(&self, mut arguments: ArgumentList) -> Compiled {
+        let value = arguments.required("value");
+        let keep_start = arguments.optional("keep_start").unwrap_or(expr!(0));
+        let keep_end = arguments.optional("keep_end").unwrap_or(expr!(4));
+        let mask_char = arguments.optional("mask_char").unwrap_or(expr!("*"));
+
+        Ok(Box::new(MaskFn {
+            value,
+            keep_start,
+            keep_end,
+            mask_char,
+        }))
+    }
+}
+
+#[derive(Debug, Clone)]
+struct MaskFn {
+    value: Box<dyn Expression>,
+    keep_start: Box<dyn Expression>,
+    keep_end: Box<dyn Expression>,
+    mask_char: Box<dyn Expression>,
+}
+
+impl Expression for MaskFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?;
+        let value = value.try_bytes_utf8_lossy()?;
+        let keep_start = self.keep_start.resolve(ctx)?.try_integer()?;
+        let keep_end = self.keep_end.resolve(ctx)?.try_integer()?;
+        let mask_char = self.mask_char.resolve(ctx)?;
+        let mask_char = mask_char.try_bytes_utf8_lossy()?;
+
+        if keep_start < 0 || keep_end < 0 {
+            return Err("keep_start and keep_end must not be negative".into());
+        }
+
+        let mut chars = mask_char.chars();
+        let mask_char = match (chars.next(), chars.next()) {
+            (Some(c), None) => c,
+            _ => return Err("mask_char must be a single character".into()),
+        };
+
+        // Values too short to mask anything are returned as is, rather than
+        // revealing more than asked by masking fewer characters.
+        let len = value.chars().count();
+        let (keep_start, keep_end) = (keep_start as usize, keep_end as usize);
+        if len <= keep_start.saturating_add(keep_end) {
+            return Ok(value.into_owned().into());
+        }
+
+        let masked = value
+            .chars()
+            .enumerate()
+            .map(|(i, c)| {
+                if i < keep_start || i >= len - keep_end {
+                    c
+                } else {
+                    mask_char
+                }
+            })
+            .collect::<String>();
+
+        Ok(masked.into())
+    }
+
+    fn type_def(&self, _: &state::Compiler) -> TypeDef {
+        TypeDef::new().fallible().bytes()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    test_function![
+        mask => Mask;
+
+        card_number {
+            args: func_args![value: "4111111111111111", keep_start: 4],
+            want: Ok("4111********1111"),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+
+        defaults {
+            args: func_args![value: "4111111111111111"],
+            want: Ok("************1111"),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+
+        short {
+            args: func_args![value: "1234", keep_start: 2, keep_end: 2],
+            want: Ok("1234"),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+
+        custom_mask_char {
+            args: func_args![value: "jane.doe@example.com", keep_start: 1, keep_end: 12, mask_char: "•"],
+            want: Ok("j•••••••@example.com"),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+
+        mask_everything {
+            args: func_args![value: "secret", keep_end: 0, mask_char: "#"],
+            want: Ok("######"),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+
+        invalid_mask_char {
+            args: func_args![value: "secret", mask_char: "**"],
+            want: Err("mask_char must be a single character"),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+
+        negative_keep {
+            args: func_args![value: "secret", keep_start: -1],
+            want: Err("keep_start and keep_end must not be negative"),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+    ];
+}
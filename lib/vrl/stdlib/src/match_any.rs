@@ -0,0 +1,186 @@
+use crate::util;
+use regex::RegexSet;
+use vrl::prelude::*;
+
+#[derive(Clone, Copy, Debug)]
+pub struct MatchAny;
+
+impl Function for MatchAny {
+    fn identifier(&self) -> &'static str {
+        "match_any"
+    }
+
+    fn is_pure(&self) -> bool {
+        true
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[
+            Parameter {
+                keyword: "value",
+                kind: kind::BYTES,
+                required: true,
+            },
+            Parameter {
+                keyword: "patterns",
+                kind: kind::ARRAY,
+                required: true,
+            },
+        ]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "match",
+                source: r#"match_any("foobar", [r'^foo', r'baz$'])"#,
+                result: Ok("true"),
+            },
+            Example {
+                title: "mismatch",
+                source: r#"match_any("bazqux", [r'^foo', r'baz$'])"#,
+                result: Ok("false"),
+            },
+        ]
+    }
+
+    fn compile(&self, mut arguments: ArgumentList) -> Compiled {
+        let value = arguments.required("value");
+        let patterns = arguments.required("patterns");
+
+        // A literal array is compiled once, here, so that invalid patterns are
+        // reported at compile-time.
+        let patterns = match patterns.as_value() {
+            Some(array) => {
+                let sources = array
+                    .try_array()
+                    .and_then(|array| {
+                        array
+                            .into_iter()
+                            .map(util::pattern_source)
+                            .collect::<std::result::Result<Vec<_>, _>>()
+                    })
+                    .map_err(|e| Box::new(e) as Box<dyn DiagnosticError>)?;
+
+                let set = util::regex_set(&sources)
+                    .map_err(|e| Box::new(e) as Box<dyn DiagnosticError>)?;
+
+                Patterns::Compiled(set)
+            }
+            None => Patterns::Dynamic(patterns),
+        };
+
+        Ok(Box::new(MatchAnyFn { value, patterns }))
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Patterns {
+    Compiled(RegexSet),
+    Dynamic(Box<dyn Expression>),
+}
+
+#[derive(Debug, Clone)]
+struct MatchAnyFn {
+    value: Box<dyn Expression>,
+    patterns: Patterns,
+}
+
+impl Expression for MatchAnyFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?;
+        let string = value.try_bytes_utf8_lossy()?;
+
+        let is_match = match &self.patterns {
+            Patterns::Compiled(set) => set.is_match(&string),
+            Patterns::Dynamic(patterns) => {
+                let sources = patterns
+                    .resolve(ctx)?
+                    .try_array()?
+                    .into_iter()
+                    .map(util::pattern_source)
+                    .collect::<std::result::Result<Vec<_>, _>>()?;
+
+                util::regex_set(&sources)
+                    .map_err(|e| e.to_string())?
+                    .is_match(&string)
+            }
+        };
+
+        Ok(is_match.into())
+    }
+
+    fn type_def(&self, _: &state::Compiler) -> TypeDef {
+        TypeDef::new()
+            .boolean()
+            .with_fallibility(matches!(self.patterns, Patterns::Dynamic(_)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    test_function![
+        match_any => MatchAny;
+
+        matches {
+            args: func_args![value: "foobar", patterns: vec![Value::from(regex::Regex::new("^fo+").unwrap()), Value::from(regex::Regex::new("ba+z$").unwrap())]],
+            want: Ok(true),
+            tdef: TypeDef::new().infallible().boolean(),
+        }
+
+        matches_string_pattern {
+            args: func_args![value: "bazqux", patterns: vec!["^foo", "qux$"]],
+            want: Ok(true),
+            tdef: TypeDef::new().infallible().boolean(),
+        }
+
+        no_match {
+            args: func_args![value: "quxbaz", patterns: vec!["^foo", "qux$"]],
+            want: Ok(false),
+            tdef: TypeDef::new().infallible().boolean(),
+        }
+
+        empty_patterns {
+            args: func_args![value: "foobar", patterns: Vec::<Value>::new()],
+            want: Ok(false),
+            tdef: TypeDef::new().infallible().boolean(),
+        }
+
+        invalid_pattern {
+            args: func_args![value: "foobar", patterns: vec!["^foo", "(bar"]],
+            want: Err(r#"invalid pattern "(bar" at index 1"#),
+            tdef: TypeDef::new().infallible().boolean(),
+        }
+
+        invalid_pattern_type {
+            args: func_args![value: "foobar", patterns: vec![Value::from("^foo"), Value::from(1)]],
+            want: Err(r#"expected "string" or "regex", got "integer""#),
+            tdef: TypeDef::new().infallible().boolean(),
+        }
+    ];
+
+    #[test]
+    fn dynamic_patterns() {
+        let mut target = value!({});
+        let mut runtime_state = vrl::state::Runtime::default();
+        let mut ctx = Context::new(&mut target, &mut runtime_state);
+
+        let func = MatchAnyFn {
+            value: expr!("foobar"),
+            patterns: Patterns::Dynamic(expr!(["^foo"])),
+        };
+        assert_eq!(func.resolve(&mut ctx), Ok(true.into()));
+        assert!(func.type_def(&state::Compiler::default()).is_fallible());
+
+        let func = MatchAnyFn {
+            value: expr!("foobar"),
+            patterns: Patterns::Dynamic(expr!(["(bar"])),
+        };
+        assert_eq!(
+            func.resolve(&mut ctx).map_err(|e| e.to_string()),
+            Err(r#"invalid pattern "(bar" at index 0"#.to_owned())
+        );
+    }
+}
@@ -0,0 +1,239 @@
+use crate::schema_util;
+use vrl::prelude::*;
+
+#[derive(Clone, Copy, Debug)]
+pub struct MergeSchemas;
+
+impl Function for MergeSchemas {
+    fn identifier(&self) -> &'static str {
+        "merge_schemas"
+    }
+
+    fn is_pure(&self) -> bool {
+        true
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[
+            Parameter {
+                keyword: "a",
+                kind: kind::OBJECT,
+                required: true,
+            },
+            Parameter {
+                keyword: "b",
+                kind: kind::OBJECT,
+                required: true,
+            },
+        ]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "widen types",
+                source: r#"merge_schemas!({ "type": "integer" }, { "type": "number" })"#,
+                result: Ok(r#"{ "type": "number" }"#),
+            },
+            Example {
+                title: "inferred schemas",
+                source: r#"merge_schemas!(infer_schema({ "id": 1, "name": "foo" }), infer_schema({ "id": "2" }))"#,
+                result: Ok(
+                    r#"{ "type": "object", "properties": { "id": { "type": ["integer", "string"] }, "name": { "type": "string" } }, "required": ["id"] }"#,
+                ),
+            },
+            Example {
+                title: "invalid schema",
+                source: r#"merge_schemas!({ "type": "string" }, {})"#,
+                result: Err(
+                    r#"function call error for "merge_schemas" at (0:40): invalid schema: "type" must be a string or an array of strings"#,
+                ),
+            },
+        ]
+    }
+
+    fn compile(&self, mut arguments: ArgumentList) -> Compiled {
+        let a = arguments.required("a");
+        let b = arguments.required("b");
+
+        Ok(Box::new(MergeSchemasFn { a, b }))
+    }
+}
+
+#[derive(Debug, Clone)]
+struct MergeSchemasFn {
+    a: Box<dyn Expression>,
+    b: Box<dyn Expression>,
+}
+
+impl Expression for MergeSchemasFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let a = self.a.resolve(ctx)?.try_object()?;
+        let b = self.b.resolve(ctx)?.try_object()?;
+
+        Ok(schema_util::merge(&a, &b)?.into())
+    }
+
+    fn type_def(&self, _: &state::Compiler) -> TypeDef {
+        TypeDef::new()
+            .fallible()
+            .object::<(), Kind>(map! { (): Kind::all() })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    test_function![
+        merge_schemas => MergeSchemas;
+
+        disjoint_keys {
+            args: func_args![
+                a: value!({
+                    "type": "object",
+                    "properties": { "foo": { "type": "string" } },
+                    "required": ["foo"]
+                }),
+                b: value!({
+                    "type": "object",
+                    "properties": { "bar": { "type": "boolean" } },
+                    "required": ["bar"]
+                }),
+            ],
+            want: Ok(value!({
+                "type": "object",
+                "properties": {
+                    "bar": { "type": "boolean" },
+                    "foo": { "type": "string" }
+                },
+                "required": []
+            })),
+            tdef: TypeDef::new().fallible().object::<(), Kind>(map! { (): Kind::all() }),
+        }
+
+        overlapping_keys {
+            args: func_args![
+                a: value!({
+                    "type": "object",
+                    "properties": {
+                        "foo": { "type": "string" },
+                        "bar": {
+                            "type": "object",
+                            "properties": { "baz": { "type": "integer" } },
+                            "required": ["baz"]
+                        }
+                    },
+                    "required": ["bar", "foo"]
+                }),
+                b: value!({
+                    "type": "object",
+                    "properties": {
+                        "bar": {
+                            "type": "object",
+                            "properties": { "qux": { "type": "null" } },
+                            "required": ["qux"]
+                        }
+                    },
+                    "required": ["bar"]
+                }),
+            ],
+            want: Ok(value!({
+                "type": "object",
+                "properties": {
+                    "bar": {
+                        "type": "object",
+                        "properties": {
+                            "baz": { "type": "integer" },
+                            "qux": { "type": "null" }
+                        },
+                        "required": []
+                    },
+                    "foo": { "type": "string" }
+                },
+                "required": ["bar"]
+            })),
+            tdef: TypeDef::new().fallible().object::<(), Kind>(map! { (): Kind::all() }),
+        }
+
+        differing_types {
+            args: func_args![
+                a: value!({
+                    "type": "object",
+                    "properties": {
+                        "count": { "type": "integer" },
+                        "id": { "type": "string" }
+                    },
+                    "required": ["count", "id"]
+                }),
+                b: value!({
+                    "type": "object",
+                    "properties": {
+                        "count": { "type": "number" },
+                        "id": { "type": ["integer", "null"] }
+                    },
+                    "required": ["count", "id"]
+                }),
+            ],
+            want: Ok(value!({
+                "type": "object",
+                "properties": {
+                    "count": { "type": "number" },
+                    "id": { "type": ["integer", "null", "string"] }
+                },
+                "required": ["count", "id"]
+            })),
+            tdef: TypeDef::new().fallible().object::<(), Kind>(map! { (): Kind::all() }),
+        }
+
+        object_or_null {
+            args: func_args![
+                a: value!({ "type": "null" }),
+                b: value!({
+                    "type": "object",
+                    "properties": { "foo": { "type": "string" } },
+                    "required": ["foo"]
+                }),
+            ],
+            want: Ok(value!({
+                "type": ["null", "object"],
+                "properties": { "foo": { "type": "string" } },
+                "required": ["foo"]
+            })),
+            tdef: TypeDef::new().fallible().object::<(), Kind>(map! { (): Kind::all() }),
+        }
+
+        array_items {
+            args: func_args![
+                a: value!({ "type": "array", "items": { "type": "string", "format": "date-time" } }),
+                b: value!({ "type": "array" }),
+            ],
+            want: Ok(value!({ "type": "array", "items": { "type": "string", "format": "date-time" } })),
+            tdef: TypeDef::new().fallible().object::<(), Kind>(map! { (): Kind::all() }),
+        }
+
+        differing_formats {
+            args: func_args![
+                a: value!({ "type": "string", "format": "date-time" }),
+                b: value!({ "type": "string" }),
+            ],
+            want: Ok(value!({ "type": "string" })),
+            tdef: TypeDef::new().fallible().object::<(), Kind>(map! { (): Kind::all() }),
+        }
+
+        missing_type {
+            args: func_args![a: value!({ "type": "string" }), b: value!({})],
+            want: Err(r#"invalid schema: "type" must be a string or an array of strings"#),
+            tdef: TypeDef::new().fallible().object::<(), Kind>(map! { (): Kind::all() }),
+        }
+
+        invalid_required {
+            args: func_args![
+                a: value!({ "type": "object", "required": "foo" }),
+                b: value!({ "type": "object" }),
+            ],
+            want: Err(r#"invalid schema: "required" must be an array of strings"#),
+            tdef: TypeDef::new().fallible().object::<(), Kind>(map! { (): Kind::all() }),
+        }
+    ];
+}
@@ -0,0 +1,315 @@
+use crate::util;
+use vrl::prelude::*;
+
+#[derive(Clone, Copy, Debug)]
+pub struct Metaphone;
+
+impl Function for Metaphone {
+    fn identifier(&self) -> &'static str {
+        "metaphone"
+    }
+
+    fn is_pure(&self) -> bool {
+        true
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[
+            Parameter {
+                keyword: "value",
+                kind: kind::BYTES,
+                required: true,
+            },
+            Parameter {
+                keyword: "ignore_invalid",
+                kind: kind::BOOLEAN,
+                required: false,
+            },
+        ]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "silent letters",
+                source: r#"metaphone!("Knight")"#,
+                result: Ok("NT"),
+            },
+            Example {
+                title: "ignore invalid",
+                source: r#"metaphone!("Smith-Jones", ignore_invalid: true)"#,
+                result: Ok("SM0JNS"),
+            },
+        ]
+    }
+
+    fn compile(&self, mut arguments: ArgumentList) -> Compiled {
+        let value = arguments.required("value");
+        let ignore_invalid = arguments.optional("ignore_invalid").unwrap_or(expr!(false));
+
+        Ok(Box::new(MetaphoneFn {
+            value,
+            ignore_invalid,
+        }))
+    }
+}
+
+#[derive(Debug, Clone)]
+struct MetaphoneFn {
+    value: Box<dyn Expression>,
+    ignore_invalid: Box<dyn Expression>,
+}
+
+impl Expression for MetaphoneFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?;
+        let ignore_invalid = self.ignore_invalid.resolve(ctx)?.try_boolean()?;
+        let letters = util::phonetic_letters(&value.try_bytes_utf8_lossy()?, ignore_invalid)?;
+
+        Ok(metaphone(&letters).into())
+    }
+
+    fn type_def(&self, _: &state::Compiler) -> TypeDef {
+        TypeDef::new().fallible().bytes()
+    }
+}
+
+/// Returns the Metaphone code of the uppercase letters, following the rules of
+/// the original algorithm, without limiting the length of the code.
+///
+/// `0` stands for the "th" sound, and `X` for the "sh" sound.
+fn metaphone(letters: &[u8]) -> String {
+    let at = |i: usize| letters.get(i).copied();
+    let is_vowel = |i: usize| at(i).map_or(false, |c| b"AEIOU".contains(&c));
+    let is_front_vowel = |i: usize| at(i).map_or(false, |c| b"EIY".contains(&c));
+    let matches_at = |i: usize, s: &[u8]| letters[i..].starts_with(s);
+
+    let mut code = String::new();
+
+    // Some initial letters are handled up front, as they're either silent, or
+    // pronounced differently than they would be elsewhere.
+    let mut i = match (at(0), at(1)) {
+        (Some(b'A'), Some(b'E')) => {
+            code.push('E');
+            2
+        }
+        (Some(b'G'), Some(b'N')) | (Some(b'K'), Some(b'N')) | (Some(b'P'), Some(b'N')) => {
+            code.push('N');
+            2
+        }
+        (Some(b'W'), Some(b'R')) => {
+            code.push('R');
+            2
+        }
+        (Some(b'W'), Some(b'H')) => {
+            code.push('W');
+            2
+        }
+        (Some(b'X'), _) => {
+            code.push('S');
+            1
+        }
+        (Some(letter), _) if is_vowel(0) => {
+            code.push(letter as char);
+            1
+        }
+        _ => 0,
+    };
+
+    while let Some(letter) = at(i) {
+        // Doubled letters are coded once, except for `C`.
+        if i > 0 && at(i - 1) == Some(letter) && letter != b'C' {
+            i += 1;
+            continue;
+        }
+
+        let previous = if i > 0 { at(i - 1) } else { None };
+        let is_last = i + 1 == letters.len();
+
+        match letter {
+            b'B' => {
+                // Silent in a final "MB".
+                if !(is_last && previous == Some(b'M')) {
+                    code.push('B');
+                }
+            }
+            b'C' => {
+                if previous == Some(b'S') && is_front_vowel(i + 1) {
+                    // Silent in "SCE", "SCI" and "SCY".
+                } else if matches_at(i, b"CIA") {
+                    code.push('X');
+                } else if is_front_vowel(i + 1) {
+                    code.push('S');
+                } else if previous == Some(b'S') && at(i + 1) == Some(b'H') {
+                    code.push('K');
+                } else if at(i + 1) == Some(b'H') {
+                    // A leading "CH" before a consonant is hard, as in "Christ".
+                    if i == 0 && letters.len() >= 3 && !is_vowel(2) {
+                        code.push('K');
+                    } else {
+                        code.push('X');
+                    }
+                } else {
+                    code.push('K');
+                }
+            }
+            b'D' => {
+                if at(i + 1) == Some(b'G') && is_front_vowel(i + 2) {
+                    code.push('J');
+                    i += 2;
+                } else {
+                    code.push('T');
+                }
+            }
+            b'G' => {
+                // Silent in a "GH" that isn't final or followed by a vowel, as in
+                // "night", and before an "N", as in "sign" and "signed".
+                let silent = (at(i + 1) == Some(b'H') && !is_last && !is_vowel(i + 2))
+                    || (i > 0 && at(i + 1) == Some(b'N'));
+
+                if !silent {
+                    if is_front_vowel(i + 1) && previous != Some(b'G') {
+                        code.push('J');
+                    } else {
+                        code.push('K');
+                    }
+                }
+            }
+            b'H' => {
+                let after_modifier = previous.map_or(false, |c| b"CSPTG".contains(&c));
+
+                if !is_last && !after_modifier && is_vowel(i + 1) {
+                    code.push('H');
+                }
+            }
+            b'K' => {
+                if previous != Some(b'C') {
+                    code.push('K');
+                }
+            }
+            b'P' => {
+                if at(i + 1) == Some(b'H') {
+                    code.push('F');
+                } else {
+                    code.push('P');
+                }
+            }
+            b'Q' => code.push('K'),
+            b'S' => {
+                if matches_at(i, b"SH") || matches_at(i, b"SIO") || matches_at(i, b"SIA") {
+                    code.push('X');
+                } else {
+                    code.push('S');
+                }
+            }
+            b'T' => {
+                if matches_at(i, b"TIA") || matches_at(i, b"TIO") {
+                    code.push('X');
+                } else if matches_at(i, b"TCH") {
+                    // Silent, the "CH" is coded instead.
+                } else if at(i + 1) == Some(b'H') {
+                    code.push('0');
+                } else {
+                    code.push('T');
+                }
+            }
+            b'V' => code.push('F'),
+            b'W' | b'Y' => {
+                if is_vowel(i + 1) {
+                    code.push(letter as char);
+                }
+            }
+            b'X' => code.push_str("KS"),
+            b'Z' => code.push('S'),
+            b'F' | b'J' | b'L' | b'M' | b'N' | b'R' => code.push(letter as char),
+            // Vowels are only coded as the first letter, which is handled
+            // above.
+            _ => {}
+        }
+
+        i += 1;
+    }
+
+    code
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    test_function![
+        metaphone => Metaphone;
+
+        knight {
+            args: func_args![value: "Knight"],
+            want: Ok("NT"),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+
+        thumb {
+            args: func_args![value: "thumb"],
+            want: Ok("0M"),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+
+        phone {
+            args: func_args![value: "Phone"],
+            want: Ok("FN"),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+
+        judge {
+            args: func_args![value: "Judge"],
+            want: Ok("JJ"),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+
+        church {
+            args: func_args![value: "Church"],
+            want: Ok("XRX"),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+
+        christ {
+            args: func_args![value: "Christ"],
+            want: Ok("KRST"),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+
+        xavier {
+            args: func_args![value: "Xavier"],
+            want: Ok("SFR"),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+
+        wright {
+            args: func_args![value: "Wright"],
+            want: Ok("RT"),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+
+        leading_vowel {
+            args: func_args![value: "Aaron"],
+            want: Ok("ARN"),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+
+        empty {
+            args: func_args![value: ""],
+            want: Ok(""),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+
+        invalid_character {
+            args: func_args![value: "Zoë"],
+            want: Err(r#"invalid character "ë" in "Zoë""#),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+
+        ignore_invalid {
+            args: func_args![value: "Smith-Jones", ignore_invalid: true],
+            want: Ok("SM0JNS"),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+    ];
+}
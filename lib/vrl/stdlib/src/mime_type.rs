@@ -0,0 +1,124 @@
+use crate::mime_util;
+use vrl::prelude::*;
+
+#[derive(Clone, Copy, Debug)]
+pub struct MimeType;
+
+impl Function for MimeType {
+    fn identifier(&self) -> &'static str {
+        "mime_type"
+    }
+
+    fn is_pure(&self) -> bool {
+        true
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[Parameter {
+            keyword: "value",
+            kind: kind::BYTES,
+            required: true,
+        }]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "extension",
+                source: r#"mime_type("json")"#,
+                result: Ok("application/json"),
+            },
+            Example {
+                title: "file name",
+                source: r#"mime_type("/backups/site.tar.gz")"#,
+                result: Ok("application/x-compressed-tar"),
+            },
+            Example {
+                title: "unknown",
+                source: r#"mime_type("data.unknown")"#,
+                result: Ok("null"),
+            },
+        ]
+    }
+
+    fn compile(&self, mut arguments: ArgumentList) -> Compiled {
+        let value = arguments.required("value");
+
+        Ok(Box::new(MimeTypeFn { value }))
+    }
+}
+
+#[derive(Debug, Clone)]
+struct MimeTypeFn {
+    value: Box<dyn Expression>,
+}
+
+impl Expression for MimeTypeFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?;
+        let value = value.try_bytes_utf8_lossy()?;
+
+        Ok(mime_util::media_type(&value).into())
+    }
+
+    fn type_def(&self, _: &state::Compiler) -> TypeDef {
+        TypeDef::new().infallible().bytes().add_null()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    test_function![
+        mime_type => MimeType;
+
+        extension {
+            args: func_args![value: "png"],
+            want: Ok("image/png"),
+            tdef: TypeDef::new().infallible().bytes().add_null(),
+        }
+
+        extension_with_dot {
+            args: func_args![value: ".html"],
+            want: Ok("text/html"),
+            tdef: TypeDef::new().infallible().bytes().add_null(),
+        }
+
+        file_name {
+            args: func_args![value: "report.PDF"],
+            want: Ok("application/pdf"),
+            tdef: TypeDef::new().infallible().bytes().add_null(),
+        }
+
+        path {
+            args: func_args![value: "/srv/www/app.min.js"],
+            want: Ok("application/javascript"),
+            tdef: TypeDef::new().infallible().bytes().add_null(),
+        }
+
+        compound_extension {
+            args: func_args![value: "site.tar.gz"],
+            want: Ok("application/x-compressed-tar"),
+            tdef: TypeDef::new().infallible().bytes().add_null(),
+        }
+
+        simple_extension_of_compound {
+            args: func_args![value: "site.log.gz"],
+            want: Ok("application/gzip"),
+            tdef: TypeDef::new().infallible().bytes().add_null(),
+        }
+
+        unknown_extension {
+            args: func_args![value: "data.unknown"],
+            want: Ok(Value::Null),
+            tdef: TypeDef::new().infallible().bytes().add_null(),
+        }
+
+        directory_extension {
+            args: func_args![value: "/etc/conf.d/app"],
+            want: Ok(Value::Null),
+            tdef: TypeDef::new().infallible().bytes().add_null(),
+        }
+    ];
+}
@@ -0,0 +1,85 @@
+//! The bundled table of media types and their file extensions.
+
+use lazy_static::lazy_static;
+use std::collections::HashMap;
+
+lazy_static! {
+    static ref TABLE: Table = Table::parse(include_str!("../data/mime.types"));
+}
+
+struct Table {
+    /// Media types by extension.
+    types: HashMap<&'static str, &'static str>,
+
+    /// The extension each media type is written with, by media type.
+    extensions: HashMap<&'static str, &'static str>,
+}
+
+impl Table {
+    /// Parses a table in the format of the `mime.types` file of the Apache
+    /// HTTP Server, in which each line is a media type followed by its
+    /// extensions.
+    fn parse(text: &'static str) -> Self {
+        let mut types = HashMap::new();
+        let mut extensions = HashMap::new();
+
+        let lines = text
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with('#'));
+
+        for line in lines {
+            let mut fields = line.split_whitespace();
+            let media_type = match fields.next() {
+                Some(media_type) => media_type,
+                None => continue,
+            };
+
+            for extension in fields {
+                types.entry(extension).or_insert(media_type);
+                extensions.entry(media_type).or_insert(extension);
+            }
+        }
+
+        Self { types, extensions }
+    }
+}
+
+/// Returns the media type of the file extension, or of the file name or path,
+/// by its longest known extension, so that `archive.tar.gz` is a compressed
+/// tarball rather than a gzip file.
+#[cfg(feature = "mime_type")]
+pub(crate) fn media_type(value: &str) -> Option<&'static str> {
+    let name = value.rsplit('/').next().unwrap_or_default();
+    let name = name.trim_start_matches('.').to_lowercase();
+
+    std::iter::once(name.as_str())
+        .chain(name.match_indices('.').map(|(i, _)| &name[i + 1..]))
+        .find_map(|extension| TABLE.types.get(extension).copied())
+}
+
+/// Returns the file extension, without its leading dot, of the media type.
+/// Parameters of the media type, such as `charset`, are ignored.
+#[cfg(feature = "file_extension")]
+pub(crate) fn extension(media_type: &str) -> Option<&'static str> {
+    let media_type = media_type
+        .splitn(2, ';')
+        .next()
+        .unwrap_or_default()
+        .trim()
+        .to_lowercase();
+
+    TABLE.extensions.get(media_type.as_str()).copied()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bundled_table() {
+        assert_eq!(TABLE.types.get("jpeg"), Some(&"image/jpeg"));
+        assert_eq!(TABLE.extensions.get("image/jpeg"), Some(&"jpg"));
+        assert!(TABLE.types.len() > 50);
+    }
+}
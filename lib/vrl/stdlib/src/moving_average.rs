@@ -0,0 +1,131 @@
+use crate::util;
+use vrl::prelude::*;
+
+#[derive(Clone, Copy, Debug)]
+pub struct MovingAverage;
+
+impl Function for MovingAverage {
+    fn identifier(&self) -> &'static str {
+        "moving_average"
+    }
+
+    fn is_pure(&self) -> bool {
+        true
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[
+            Parameter {
+                keyword: "value",
+                kind: kind::ARRAY,
+                required: true,
+            },
+            Parameter {
+                keyword: "window",
+                kind: kind::INTEGER,
+                required: true,
+            },
+        ]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[Example {
+            title: "window of two",
+            source: r#"moving_average!([1, 2, 3, 4], 2)"#,
+            result: Ok("[1.5, 2.5, 3.5]"),
+        }]
+    }
+
+    fn compile(&self, mut arguments: ArgumentList) -> Compiled {
+        let value = arguments.required("value");
+        let window = arguments.required("window");
+
+        Ok(Box::new(MovingAverageFn { value, window }))
+    }
+}
+
+#[derive(Debug, Clone)]
+struct MovingAverageFn {
+    value: Box<dyn Expression>,
+    window: Box<dyn Expression>,
+}
+
+impl Expression for MovingAverageFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let numbers = util::float_array(self.value.resolve(ctx)?)?;
+        let window = self.window.resolve(ctx)?.try_integer()?;
+
+        if window < 1 || window as usize > numbers.len() {
+            return Err(format!(
+                "window must be between 1 and the length of the array ({}), got {}",
+                numbers.len(),
+                window
+            )
+            .into());
+        }
+
+        // Each mean covers a full window, the first one starting at the first
+        // element, so there are `len - window + 1` of them.
+        let averages = numbers
+            .windows(window as usize)
+            .map(|window| (window.iter().sum::<f64>() / window.len() as f64).into())
+            .collect::<Vec<Value>>();
+
+        Ok(averages.into())
+    }
+
+    fn type_def(&self, _: &state::Compiler) -> TypeDef {
+        TypeDef::new().fallible().array_mapped::<(), Kind>(map! {
+            (): Kind::Float,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    test_function![
+        moving_average => MovingAverage;
+
+        simple {
+            args: func_args![value: value!([1, 2, 3, 4, 5.5]), window: 3],
+            want: Ok(value!([2.0, 3.0, 4.166666666666667])),
+            tdef: TypeDef::new().fallible().array_mapped::<(), Kind>(map! {
+                (): Kind::Float,
+            }),
+        }
+
+        window_of_one {
+            args: func_args![value: value!([1, 2]), window: 1],
+            want: Ok(value!([1.0, 2.0])),
+            tdef: TypeDef::new().fallible().array_mapped::<(), Kind>(map! {
+                (): Kind::Float,
+            }),
+        }
+
+        window_of_array_length {
+            args: func_args![value: value!([1, 2, 6]), window: 3],
+            want: Ok(value!([3.0])),
+            tdef: TypeDef::new().fallible().array_mapped::<(), Kind>(map! {
+                (): Kind::Float,
+            }),
+        }
+
+        window_too_large {
+            args: func_args![value: value!([1, 2]), window: 3],
+            want: Err("window must be between 1 and the length of the array (2), got 3"),
+            tdef: TypeDef::new().fallible().array_mapped::<(), Kind>(map! {
+                (): Kind::Float,
+            }),
+        }
+
+        window_zero {
+            args: func_args![value: value!([1, 2]), window: 0],
+            want: Err("window must be between 1 and the length of the array (2), got 0"),
+            tdef: TypeDef::new().fallible().array_mapped::<(), Kind>(map! {
+                (): Kind::Float,
+            }),
+        }
+    ];
+}
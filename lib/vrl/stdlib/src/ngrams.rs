@@ -0,0 +1,143 @@
+use vrl::prelude::*;
+
+#[derive(Clone, Copy, Debug)]
+pub struct Ngrams;
+
+impl Function for Ngrams {
+    fn identifier(&self) -> &'static str {
+        "ngrams"
+    }
+
+    fn is_pure(&self) -> bool {
+        true
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[
+            Parameter {
+                keyword: "value",
+                kind: kind::ARRAY | kind::BYTES,
+                required: true,
+            },
+            Parameter {
+                keyword: "n",
+                kind: kind::INTEGER,
+                required: true,
+            },
+        ]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "word bigrams",
+                source: r#"ngrams!(["the", "quick", "fox"], 2)"#,
+                result: Ok(r#"[["the", "quick"], ["quick", "fox"]]"#),
+            },
+            Example {
+                title: "character trigrams",
+                source: r#"ngrams!("vector", 3)"#,
+                result: Ok(r#"["vec", "ect", "cto", "tor"]"#),
+            },
+        ]
+    }
+
+    fn compile(&self, mut arguments: ArgumentList) -> Compiled {
+        let value = arguments.required("value");
+        let n = arguments.required("n");
+
+        Ok(Box::new(NgramsFn { value, n }))
+    }
+}
+
+#[derive(Debug, Clone)]
+struct NgramsFn {
+    value: Box<dyn Expression>,
+    n: Box<dyn Expression>,
+}
+
+impl Expression for NgramsFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?;
+        let n = self.n.resolve(ctx)?.try_integer()?;
+
+        if n < 1 {
+            return Err(format!("n must be at least 1, got {}", n).into());
+        }
+
+        let ngrams = match value {
+            Value::Array(array) => array
+                .windows(n as usize)
+                .map(|window| window.to_vec().into())
+                .collect::<Vec<Value>>(),
+            Value::Bytes(_) => {
+                let chars = value.try_bytes_utf8_lossy()?.chars().collect::<Vec<_>>();
+
+                chars
+                    .windows(n as usize)
+                    .map(|window| window.iter().collect::<String>().into())
+                    .collect::<Vec<Value>>()
+            }
+            value => {
+                return Err(value::Error::Expected {
+                    got: value.kind(),
+                    expected: Kind::Array | Kind::Bytes,
+                }
+                .into())
+            }
+        };
+
+        Ok(ngrams.into())
+    }
+
+    fn type_def(&self, _: &state::Compiler) -> TypeDef {
+        TypeDef::new()
+            .fallible()
+            .array_mapped::<(), Kind>(map! { (): Kind::Array | Kind::Bytes })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    test_function![
+        ngrams => Ngrams;
+
+        bigrams {
+            args: func_args![value: value!(["the", "quick", "brown", "fox"]), n: 2],
+            want: Ok(value!([["the", "quick"], ["quick", "brown"], ["brown", "fox"]])),
+            tdef: TypeDef::new().fallible().array_mapped::<(), Kind>(map! { (): Kind::Array | Kind::Bytes }),
+        }
+
+        unigrams {
+            args: func_args![value: value!([1, true]), n: 1],
+            want: Ok(value!([[1], [true]])),
+            tdef: TypeDef::new().fallible().array_mapped::<(), Kind>(map! { (): Kind::Array | Kind::Bytes }),
+        }
+
+        n_larger_than_array {
+            args: func_args![value: value!(["foo"]), n: 2],
+            want: Ok(value!([])),
+            tdef: TypeDef::new().fallible().array_mapped::<(), Kind>(map! { (): Kind::Array | Kind::Bytes }),
+        }
+
+        characters {
+            args: func_args![value: "Köln", n: 2],
+            want: Ok(value!(["Kö", "öl", "ln"])),
+            tdef: TypeDef::new().fallible().array_mapped::<(), Kind>(map! { (): Kind::Array | Kind::Bytes }),
+        }
+
+        empty_string {
+            args: func_args![value: "", n: 2],
+            want: Ok(value!([])),
+            tdef: TypeDef::new().fallible().array_mapped::<(), Kind>(map! { (): Kind::Array | Kind::Bytes }),
+        }
+
+        invalid_n {
+            args: func_args![value: value!([]), n: 0],
+            want: Err("n must be at least 1, got 0"),
+            tdef: TypeDef::new().fallible().array_mapped::<(), Kind>(map! { (): Kind::Array | Kind::Bytes }),
+        }
+    ];
+}
@@ -0,0 +1,151 @@
+use crate::util;
+use vrl::prelude::*;
+
+#[derive(Clone, Copy, Debug)]
+pub struct NormalizeEmail;
+
+impl Function for NormalizeEmail {
+    fn identifier(&self) -> &'static str {
+        "normalize_email"
+    }
+
+    fn is_pure(&self) -> bool {
+        true
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[
+            Parameter {
+                keyword: "value",
+                kind: kind::BYTES,
+                required: true,
+            },
+            Parameter {
+                keyword: "strip_dots",
+                kind: kind::BOOLEAN,
+                required: false,
+            },
+            Parameter {
+                keyword: "strip_tags",
+                kind: kind::BOOLEAN,
+                required: false,
+            },
+        ]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "lowercase domain",
+                source: r#"normalize_email!("First.Last+news@Gmail.COM")"#,
+                result: Ok("First.Last+news@gmail.com"),
+            },
+            Example {
+                title: "strip dots and tags",
+                source: r#"normalize_email!("First.Last+news@Gmail.COM", strip_dots: true, strip_tags: true)"#,
+                result: Ok("FirstLast@gmail.com"),
+            },
+        ]
+    }
+
+    fn compile(&self, mut arguments: ArgumentList) -> Compiled {
+        let value = arguments.required("value");
+        let strip_dots = arguments.optional("strip_dots").unwrap_or(expr!(false));
+        let strip_tags = arguments.optional("strip_tags").unwrap_or(expr!(false));
+
+        Ok(Box::new(NormalizeEmailFn {
+            value,
+            strip_dots,
+            strip_tags,
+        }))
+    }
+}
+
+#[derive(Debug, Clone)]
+struct NormalizeEmailFn {
+    value: Box<dyn Expression>,
+    strip_dots: Box<dyn Expression>,
+    strip_tags: Box<dyn Expression>,
+}
+
+impl Expression for NormalizeEmailFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?;
+        let value = value.try_bytes_utf8_lossy()?;
+        let (local, domain) = util::parse_email(&value)?;
+
+        let strip_dots = self.strip_dots.resolve(ctx)?.try_boolean()?;
+        let strip_tags = self.strip_tags.resolve(ctx)?.try_boolean()?;
+
+        let local = if strip_tags {
+            local.splitn(2, '+').next().unwrap_or_default()
+        } else {
+            local
+        };
+
+        let local = if strip_dots {
+            local.replace('.', "")
+        } else {
+            local.to_owned()
+        };
+
+        if local.is_empty() {
+            return Err(format!(r#"invalid email address "{}""#, value).into());
+        }
+
+        // Converting the domain to its ASCII form also lowercases it.
+        let domain = idna::domain_to_ascii(domain)
+            .map_err(|_| format!(r#"invalid email domain "{}""#, domain))?;
+
+        Ok(format!("{}@{}", local, domain).into())
+    }
+
+    fn type_def(&self, _: &state::Compiler) -> TypeDef {
+        TypeDef::new().fallible().bytes()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    test_function![
+        normalize_email => NormalizeEmail;
+
+        lowercase_domain {
+            args: func_args![value: "First.Last+news@Gmail.COM"],
+            want: Ok("First.Last+news@gmail.com"),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+
+        gmail_dots_and_tag {
+            args: func_args![value: "first.last+news@gmail.com", strip_dots: true, strip_tags: true],
+            want: Ok("firstlast@gmail.com"),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+
+        tag_only {
+            args: func_args![value: "first.last+news@gmail.com", strip_tags: true],
+            want: Ok("first.last@gmail.com"),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+
+        unicode_domain {
+            args: func_args![value: "info@Bücher.de"],
+            want: Ok("info@xn--bcher-kva.de"),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+
+        only_tag {
+            args: func_args![value: "+news@gmail.com", strip_tags: true],
+            want: Err(r#"invalid email address "+news@gmail.com""#),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+
+        invalid {
+            args: func_args![value: "not an email"],
+            want: Err(r#"invalid email address "not an email""#),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+    ];
+}
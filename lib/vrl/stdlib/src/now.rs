@@ -1,4 +1,3 @@
-use chrono::Utc;
 use vrl::prelude::*;
 
 #[derive(Clone, Copy, Debug)]
@@ -26,11 +25,30 @@ impl Function for Now {
 struct NowFn;
 
 impl Expression for NowFn {
-    fn resolve(&self, _: &mut Context) -> Resolved {
-        Ok(Utc::now().into())
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        Ok(ctx.state().clock().now().into())
     }
 
     fn type_def(&self, _: &state::Compiler) -> TypeDef {
         TypeDef::new().timestamp()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{TimeZone, Utc};
+    use vrl::clock::FixedClock;
+
+    #[test]
+    fn fixed_clock() {
+        let now = Utc.ymd(2021, 2, 3).and_hms_milli(4, 5, 6, 789);
+
+        let mut target = value!({});
+        let mut runtime_state = vrl::state::Runtime::default().with_clock(FixedClock(now));
+        let mut ctx = Context::new(&mut target, &mut runtime_state);
+
+        assert_eq!(NowFn.resolve(&mut ctx), Ok(now.into()));
+        assert_eq!(NowFn.resolve(&mut ctx), Ok(now.into()));
+    }
+}
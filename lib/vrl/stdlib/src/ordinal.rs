@@ -0,0 +1,247 @@
+use vrl::prelude::*;
+
+#[derive(Clone, Copy, Debug)]
+pub struct Ordinal;
+
+impl Function for Ordinal {
+    fn identifier(&self) -> &'static str {
+        "ordinal"
+    }
+
+    fn is_pure(&self) -> bool {
+        true
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[Parameter {
+            keyword: "value",
+            kind: kind::INTEGER,
+            required: true,
+        }]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "first",
+                source: r#"ordinal(1)"#,
+                result: Ok("1st"),
+            },
+            Example {
+                title: "teens",
+                source: r#"ordinal(112)"#,
+                result: Ok("112th"),
+            },
+        ]
+    }
+
+    fn compile(&self, mut arguments: ArgumentList) -> Compiled {
+        let value = arguments.required("value");
+
+        Ok(Box::new(OrdinalFn { value }))
+    }
+}
+
+#[derive(Debug, Clone)]
+struct OrdinalFn {
+    value: Box<dyn Expression>,
+}
+
+impl Expression for OrdinalFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?.try_integer()?;
+
+        Ok(format!("{}{}", value, suffix(value)).into())
+    }
+
+    fn type_def(&self, _: &state::Compiler) -> TypeDef {
+        TypeDef::new().infallible().bytes()
+    }
+}
+
+/// The English ordinal suffix of the number, numbers ending in 11, 12 and 13
+/// being the exceptions to the suffix of their last digit.
+fn suffix(number: i64) -> &'static str {
+    // The remainder is taken first, as the absolute value of `i64::MIN`
+    // overflows.
+    let last_digits = (number % 100).abs();
+
+    match (last_digits % 10, last_digits) {
+        (_, 11..=13) => "th",
+        (1, _) => "st",
+        (2, _) => "nd",
+        (3, _) => "rd",
+        _ => "th",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    /// The ordinal function, counting the calls resolved.
+    #[derive(Debug)]
+    struct CountingOrdinal(Arc<AtomicUsize>);
+
+    impl Function for CountingOrdinal {
+        fn identifier(&self) -> &'static str {
+            Ordinal.identifier()
+        }
+
+        fn is_pure(&self) -> bool {
+            Ordinal.is_pure()
+        }
+
+        fn parameters(&self) -> &'static [Parameter] {
+            Ordinal.parameters()
+        }
+
+        fn examples(&self) -> &'static [Example] {
+            &[]
+        }
+
+        fn compile(&self, arguments: ArgumentList) -> Compiled {
+            let expr = Ordinal.compile(arguments)?;
+
+            Ok(Box::new(CountingFn(expr, self.0.clone())))
+        }
+    }
+
+    #[derive(Debug, Clone)]
+    struct CountingFn(Box<dyn Expression>, Arc<AtomicUsize>);
+
+    impl Expression for CountingFn {
+        fn resolve(&self, ctx: &mut Context) -> Resolved {
+            self.1.fetch_add(1, Ordering::Relaxed);
+            self.0.resolve(ctx)
+        }
+
+        fn type_def(&self, state: &state::Compiler) -> TypeDef {
+            self.0.type_def(state)
+        }
+    }
+
+    #[test]
+    fn shared_calls() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let fns: Vec<Box<dyn Function>> = vec![Box::new(CountingOrdinal(calls.clone()))];
+        let mut state = state::Compiler::default().with_optimization(vrl::Optimization::Full);
+
+        let program =
+            vrl::compile_with_state("n = 2\n.a = ordinal(n)\n.b = ordinal(n)", &fns, &mut state)
+                .unwrap();
+
+        let mut target = value!({ "n": 2 });
+        vrl::Runtime::default()
+            .resolve(&mut target, &program)
+            .unwrap();
+
+        assert_eq!(target, value!({ "n": 2, "a": "2nd", "b": "2nd" }));
+        assert_eq!(calls.load(Ordering::Relaxed), 1);
+    }
+
+    #[test]
+    fn constant_folding() {
+        let fns: Vec<Box<dyn Function>> = vec![Box::new(Ordinal)];
+        let mut state = state::Compiler::default().with_optimization(vrl::Optimization::Full);
+
+        let program = vrl::compile_with_state("ordinal(2)", &fns, &mut state).unwrap();
+        assert_eq!(program[0].as_value(), Some(value!("2nd")));
+    }
+
+    test_function![
+        ordinal => Ordinal;
+
+        first {
+            args: func_args![value: 1],
+            want: Ok("1st"),
+            tdef: TypeDef::new().infallible().bytes(),
+        }
+
+        second {
+            args: func_args![value: 2],
+            want: Ok("2nd"),
+            tdef: TypeDef::new().infallible().bytes(),
+        }
+
+        third {
+            args: func_args![value: 3],
+            want: Ok("3rd"),
+            tdef: TypeDef::new().infallible().bytes(),
+        }
+
+        fourth {
+            args: func_args![value: 4],
+            want: Ok("4th"),
+            tdef: TypeDef::new().infallible().bytes(),
+        }
+
+        zero {
+            args: func_args![value: 0],
+            want: Ok("0th"),
+            tdef: TypeDef::new().infallible().bytes(),
+        }
+
+        eleventh {
+            args: func_args![value: 11],
+            want: Ok("11th"),
+            tdef: TypeDef::new().infallible().bytes(),
+        }
+
+        twelfth {
+            args: func_args![value: 12],
+            want: Ok("12th"),
+            tdef: TypeDef::new().infallible().bytes(),
+        }
+
+        thirteenth {
+            args: func_args![value: 13],
+            want: Ok("13th"),
+            tdef: TypeDef::new().infallible().bytes(),
+        }
+
+        twenty_first {
+            args: func_args![value: 21],
+            want: Ok("21st"),
+            tdef: TypeDef::new().infallible().bytes(),
+        }
+
+        twenty_second {
+            args: func_args![value: 22],
+            want: Ok("22nd"),
+            tdef: TypeDef::new().infallible().bytes(),
+        }
+
+        twenty_third {
+            args: func_args![value: 23],
+            want: Ok("23rd"),
+            tdef: TypeDef::new().infallible().bytes(),
+        }
+
+        hundred_and_eleventh {
+            args: func_args![value: 111],
+            want: Ok("111th"),
+            tdef: TypeDef::new().infallible().bytes(),
+        }
+
+        hundred_and_first {
+            args: func_args![value: 101],
+            want: Ok("101st"),
+            tdef: TypeDef::new().infallible().bytes(),
+        }
+
+        negative {
+            args: func_args![value: -22],
+            want: Ok("-22nd"),
+            tdef: TypeDef::new().infallible().bytes(),
+        }
+
+        minimum {
+            args: func_args![value: i64::MIN],
+            want: Ok("-9223372036854775808th"),
+            tdef: TypeDef::new().infallible().bytes(),
+        }
+    ];
+}
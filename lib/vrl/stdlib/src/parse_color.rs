@@ -0,0 +1,153 @@
+use crate::color_util;
+use std::collections::BTreeMap;
+use vrl::prelude::*;
+
+#[derive(Clone, Copy, Debug)]
+pub struct ParseColor;
+
+impl Function for ParseColor {
+    fn identifier(&self) -> &'static str {
+        "parse_color"
+    }
+
+    fn is_pure(&self) -> bool {
+        true
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[Parameter {
+            keyword: "value",
+            kind: kind::BYTES,
+            required: true,
+        }]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "hexadecimal",
+                source: r##"parse_color!("#f0f8ff80")"##,
+                result: Ok(r#"{ "r": 240, "g": 248, "b": 255, "a": 0.5019607843137255 }"#),
+            },
+            Example {
+                title: "rgb",
+                source: r#"parse_color!("rgba(255, 0, 0, 0.5)")"#,
+                result: Ok(r#"{ "r": 255, "g": 0, "b": 0, "a": 0.5 }"#),
+            },
+            Example {
+                title: "named",
+                source: r#"parse_color!("rebeccapurple")"#,
+                result: Ok(r#"{ "r": 102, "g": 51, "b": 153, "a": 1.0 }"#),
+            },
+            Example {
+                title: "invalid",
+                source: r#"parse_color!("blurple")"#,
+                result: Err(
+                    r#"function call error for "parse_color" at (0:23): unable to parse color "blurple""#,
+                ),
+            },
+        ]
+    }
+
+    fn compile(&self, mut arguments: ArgumentList) -> Compiled {
+        let value = arguments.required("value");
+
+        Ok(Box::new(ParseColorFn { value }))
+    }
+}
+
+#[derive(Debug, Clone)]
+struct ParseColorFn {
+    value: Box<dyn Expression>,
+}
+
+impl Expression for ParseColorFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?;
+        let color = color_util::parse(&value.try_bytes_utf8_lossy()?)?;
+
+        Ok(color.into_value())
+    }
+
+    fn type_def(&self, _: &state::Compiler) -> TypeDef {
+        TypeDef::new().fallible().object(type_def())
+    }
+}
+
+fn type_def() -> BTreeMap<&'static str, TypeDef> {
+    map! {
+        "r": Kind::Integer,
+        "g": Kind::Integer,
+        "b": Kind::Integer,
+        "a": Kind::Float,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    test_function![
+        parse_color => ParseColor;
+
+        hex {
+            args: func_args![value: "#3366CC"],
+            want: Ok(value!({ "r": 51, "g": 102, "b": 204, "a": 1.0 })),
+            tdef: TypeDef::new().fallible().object(type_def()),
+        }
+
+        short_hex {
+            args: func_args![value: "#36c"],
+            want: Ok(value!({ "r": 51, "g": 102, "b": 204, "a": 1.0 })),
+            tdef: TypeDef::new().fallible().object(type_def()),
+        }
+
+        hex_alpha {
+            args: func_args![value: "#3366cc00"],
+            want: Ok(value!({ "r": 51, "g": 102, "b": 204, "a": 0.0 })),
+            tdef: TypeDef::new().fallible().object(type_def()),
+        }
+
+        rgb {
+            args: func_args![value: "rgb(51, 102, 204)"],
+            want: Ok(value!({ "r": 51, "g": 102, "b": 204, "a": 1.0 })),
+            tdef: TypeDef::new().fallible().object(type_def()),
+        }
+
+        rgba {
+            args: func_args![value: "rgba(51,102,204,0.25)"],
+            want: Ok(value!({ "r": 51, "g": 102, "b": 204, "a": 0.25 })),
+            tdef: TypeDef::new().fallible().object(type_def()),
+        }
+
+        named {
+            args: func_args![value: "AliceBlue"],
+            want: Ok(value!({ "r": 240, "g": 248, "b": 255, "a": 1.0 })),
+            tdef: TypeDef::new().fallible().object(type_def()),
+        }
+
+        transparent {
+            args: func_args![value: "transparent"],
+            want: Ok(value!({ "r": 0, "g": 0, "b": 0, "a": 0.0 })),
+            tdef: TypeDef::new().fallible().object(type_def()),
+        }
+
+        unknown_name {
+            args: func_args![value: "blurple"],
+            want: Err(r#"unable to parse color "blurple""#),
+            tdef: TypeDef::new().fallible().object(type_def()),
+        }
+
+        invalid_hex {
+            args: func_args![value: "#12345"],
+            want: Err(r##"unable to parse color "#12345""##),
+            tdef: TypeDef::new().fallible().object(type_def()),
+        }
+
+        invalid_rgb {
+            args: func_args![value: "rgb(256, 0, 0)"],
+            want: Err(r#"unable to parse color "rgb(256, 0, 0)""#),
+            tdef: TypeDef::new().fallible().object(type_def()),
+        }
+    ];
+}
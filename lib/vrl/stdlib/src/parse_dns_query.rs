@@ -0,0 +1,455 @@
+use std::collections::BTreeMap;
+use std::net::{Ipv4Addr, Ipv6Addr};
+use vrl::prelude::*;
+
+#[derive(Clone, Copy, Debug)]
+pub struct ParseDnsQuery;
+
+impl Function for ParseDnsQuery {
+    fn identifier(&self) -> &'static str {
+        "parse_dns_query"
+    }
+
+    fn is_pure(&self) -> bool {
+        true
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[Parameter {
+            keyword: "value",
+            kind: kind::BYTES,
+            required: true,
+        }]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[Example {
+            title: "A record response",
+            source: r#"parse_dns_query!(decode_base64!("EjSBgAABAAEAAAAAB2V4YW1wbGUDY29tAAABAAHADAABAAEAAA4QAARduNgi"))"#,
+            result: Ok(
+                r#"{ "answers": [{ "class": "IN", "data": "93.184.216.34", "name": "example.com", "ttl": 3600, "type": "A" }], "flags": { "aa": false, "ad": false, "cd": false, "qr": true, "ra": true, "rd": true, "tc": false }, "id": 4660, "opcode": 0, "questions": [{ "class": "IN", "name": "example.com", "type": "A" }], "rcode": "NOERROR" }"#,
+            ),
+        }]
+    }
+
+    fn compile(&self, mut arguments: ArgumentList) -> Compiled {
+        let value = arguments.required("value");
+
+        Ok(Box::new(ParseDnsQueryFn { value }))
+    }
+}
+
+#[derive(Debug, Clone)]
+struct ParseDnsQueryFn {
+    value: Box<dyn Expression>,
+}
+
+impl Expression for ParseDnsQueryFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let bytes = self.value.resolve(ctx)?.try_bytes()?;
+
+        Ok(parse_message(&bytes).map_err(|err| format!("unable to parse DNS message: {}", err))?)
+    }
+
+    fn type_def(&self, _: &state::Compiler) -> TypeDef {
+        TypeDef::new().fallible().object(type_def())
+    }
+}
+
+fn type_def() -> BTreeMap<&'static str, TypeDef> {
+    map! {
+        "id": Kind::Integer,
+        "opcode": Kind::Integer,
+        "rcode": Kind::Bytes,
+        "flags": TypeDef::new().object::<&str, Kind>(map! {
+            "qr": Kind::Boolean,
+            "aa": Kind::Boolean,
+            "tc": Kind::Boolean,
+            "rd": Kind::Boolean,
+            "ra": Kind::Boolean,
+            "ad": Kind::Boolean,
+            "cd": Kind::Boolean,
+        }),
+        "questions": TypeDef::new().array_mapped::<(), TypeDef>(map! {
+            (): TypeDef::new().object::<&str, Kind>(map! {
+                "name": Kind::Bytes,
+                "type": Kind::Bytes,
+                "class": Kind::Bytes,
+            }),
+        }),
+        "answers": TypeDef::new().array_mapped::<(), TypeDef>(map! {
+            (): TypeDef::new().object::<&str, Kind>(map! {
+                "name": Kind::Bytes,
+                "type": Kind::Bytes,
+                "class": Kind::Bytes,
+                "ttl": Kind::Integer,
+                "data": Kind::Bytes | Kind::Object | Kind::Array,
+            }),
+        }),
+    }
+}
+
+/// Parses a DNS message in the wire format of RFC 1035, section 4. The
+/// authority and additional sections aren't parsed.
+fn parse_message(message: &[u8]) -> std::result::Result<Value, String> {
+    let mut reader = Reader {
+        message,
+        position: 0,
+    };
+
+    let id = reader.u16()?;
+    let flags = reader.u16()?;
+    let question_count = reader.u16()?;
+    let answer_count = reader.u16()?;
+    reader.u16()?;
+    reader.u16()?;
+
+    let questions = (0..question_count)
+        .map(|_| {
+            let mut question = BTreeMap::new();
+            question.insert("name".to_owned(), Value::from(reader.name()?));
+            question.insert("type".to_owned(), Value::from(type_name(reader.u16()?)));
+            question.insert("class".to_owned(), Value::from(class_name(reader.u16()?)));
+
+            Ok(question.into())
+        })
+        .collect::<std::result::Result<Vec<Value>, String>>()?;
+
+    let answers = (0..answer_count)
+        .map(|_| reader.record())
+        .collect::<std::result::Result<Vec<Value>, String>>()?;
+
+    let flag = |mask: u16| Value::from(flags & mask != 0);
+
+    let mut header_flags = BTreeMap::new();
+    header_flags.insert("qr".to_owned(), flag(0x8000));
+    header_flags.insert("aa".to_owned(), flag(0x0400));
+    header_flags.insert("tc".to_owned(), flag(0x0200));
+    header_flags.insert("rd".to_owned(), flag(0x0100));
+    header_flags.insert("ra".to_owned(), flag(0x0080));
+    header_flags.insert("ad".to_owned(), flag(0x0020));
+    header_flags.insert("cd".to_owned(), flag(0x0010));
+
+    let mut result = BTreeMap::new();
+    result.insert("id".to_owned(), Value::from(i64::from(id)));
+    result.insert(
+        "opcode".to_owned(),
+        Value::from(i64::from((flags >> 11) & 0xf)),
+    );
+    result.insert("rcode".to_owned(), Value::from(rcode_name(flags & 0xf)));
+    result.insert("flags".to_owned(), Value::from(header_flags));
+    result.insert("questions".to_owned(), Value::from(questions));
+    result.insert("answers".to_owned(), Value::from(answers));
+
+    Ok(result.into())
+}
+
+fn type_name(code: u16) -> String {
+    match code {
+        1 => "A".to_owned(),
+        2 => "NS".to_owned(),
+        5 => "CNAME".to_owned(),
+        6 => "SOA".to_owned(),
+        12 => "PTR".to_owned(),
+        15 => "MX".to_owned(),
+        16 => "TXT".to_owned(),
+        28 => "AAAA".to_owned(),
+        33 => "SRV".to_owned(),
+        255 => "ANY".to_owned(),
+        // The notation of RFC 3597 for unknown types.
+        code => format!("TYPE{}", code),
+    }
+}
+
+fn class_name(code: u16) -> String {
+    match code {
+        1 => "IN".to_owned(),
+        3 => "CH".to_owned(),
+        4 => "HS".to_owned(),
+        255 => "ANY".to_owned(),
+        code => format!("CLASS{}", code),
+    }
+}
+
+fn rcode_name(code: u16) -> String {
+    match code {
+        0 => "NOERROR".to_owned(),
+        1 => "FORMERR".to_owned(),
+        2 => "SERVFAIL".to_owned(),
+        3 => "NXDOMAIN".to_owned(),
+        4 => "NOTIMP".to_owned(),
+        5 => "REFUSED".to_owned(),
+        code => format!("RCODE{}", code),
+    }
+}
+
+struct Reader<'a> {
+    message: &'a [u8],
+    position: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn bytes(&mut self, len: usize) -> std::result::Result<&'a [u8], String> {
+        let bytes = self
+            .message
+            .get(self.position..self.position + len)
+            .ok_or("truncated message")?;
+        self.position += len;
+
+        Ok(bytes)
+    }
+
+    fn u16(&mut self) -> std::result::Result<u16, String> {
+        let bytes = self.bytes(2)?;
+
+        Ok(u16::from_be_bytes([bytes[0], bytes[1]]))
+    }
+
+    fn u32(&mut self) -> std::result::Result<u32, String> {
+        let bytes = self.bytes(4)?;
+
+        Ok(u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+    }
+
+    /// Reads a domain name, following the compression pointers. The root
+    /// domain is `.`, other names have no trailing dot.
+    fn name(&mut self) -> std::result::Result<String, String> {
+        let mut labels = vec![];
+        let mut position = self.position;
+        let mut end = None;
+        let mut limit = None;
+
+        loop {
+            let len = *self.message.get(position).ok_or("truncated message")?;
+
+            match len & 0xc0 {
+                0x00 if len == 0 => {
+                    position += 1;
+                    break;
+                }
+                0x00 => {
+                    let start = position + 1;
+                    let label = self
+                        .message
+                        .get(start..start + usize::from(len))
+                        .ok_or("truncated message")?;
+                    labels.push(String::from_utf8_lossy(label));
+                    position = start + usize::from(len);
+                }
+                0xc0 => {
+                    let low = *self.message.get(position + 1).ok_or("truncated message")?;
+                    let pointer = (usize::from(len & 0x3f) << 8) | usize::from(low);
+
+                    // Only pointers to prior names are allowed, and each one
+                    // has to point before the one followed last, which rules
+                    // out loops.
+                    if pointer >= limit.unwrap_or(position) {
+                        return Err(format!("invalid compression pointer {}", pointer));
+                    }
+
+                    end.get_or_insert(position + 2);
+                    limit = Some(pointer);
+                    position = pointer;
+                }
+                _ => return Err(format!("invalid label length {:#04x}", len)),
+            }
+        }
+
+        self.position = end.unwrap_or(position);
+
+        if labels.is_empty() {
+            return Ok(".".to_owned());
+        }
+
+        Ok(labels.join("."))
+    }
+
+    fn record(&mut self) -> std::result::Result<Value, String> {
+        let name = self.name()?;
+        let kind = self.u16()?;
+        let class = self.u16()?;
+        let ttl = self.u32()?;
+        let len = usize::from(self.u16()?);
+
+        let start = self.position;
+        let data = self.bytes(len)?;
+
+        // Names in the data may point anywhere in the message, so they're read
+        // from the message rather than from the data alone.
+        let mut data_reader = Reader {
+            message: &self.message[..start + len],
+            position: start,
+        };
+
+        let data = match kind {
+            1 if len == 4 => Ipv4Addr::new(data[0], data[1], data[2], data[3])
+                .to_string()
+                .into(),
+            28 if len == 16 => {
+                let mut octets = [0; 16];
+                octets.copy_from_slice(data);
+                Ipv6Addr::from(octets).to_string().into()
+            }
+            1 | 28 => return Err(format!("invalid {} record data", type_name(kind))),
+            2 | 5 | 12 => data_reader.name()?.into(),
+            15 => {
+                let mut exchange = BTreeMap::new();
+                exchange.insert(
+                    "preference".to_owned(),
+                    Value::from(i64::from(data_reader.u16()?)),
+                );
+                exchange.insert("exchange".to_owned(), Value::from(data_reader.name()?));
+                exchange.into()
+            }
+            16 => {
+                let mut strings = vec![];
+                while data_reader.position < start + len {
+                    let len = data_reader.bytes(1)?[0];
+                    strings.push(Value::from(Bytes::copy_from_slice(
+                        data_reader.bytes(usize::from(len))?,
+                    )));
+                }
+                strings.into()
+            }
+            _ => Bytes::copy_from_slice(data).into(),
+        };
+
+        let mut record = BTreeMap::new();
+        record.insert("name".to_owned(), Value::from(name));
+        record.insert("type".to_owned(), Value::from(type_name(kind)));
+        record.insert("class".to_owned(), Value::from(class_name(class)));
+        record.insert("ttl".to_owned(), Value::from(i64::from(ttl)));
+        record.insert("data".to_owned(), data);
+
+        Ok(record.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A response to an `A` query for `example.com`.
+    const A_RESPONSE: &[u8] = b"\x12\x34\x81\x80\x00\x01\x00\x01\x00\x00\x00\x00\
+        \x07example\x03com\x00\x00\x01\x00\x01\
+        \xc0\x0c\x00\x01\x00\x01\x00\x00\x0e\x10\x00\x04\x5d\xb8\xd8\x22";
+
+    /// A response to an `A` query for `www.example.com`, an alias of
+    /// `example.com`.
+    const CNAME_RESPONSE: &[u8] = b"\x00\x01\x81\x80\x00\x01\x00\x02\x00\x00\x00\x00\
+        \x03www\x07example\x03com\x00\x00\x01\x00\x01\
+        \xc0\x0c\x00\x05\x00\x01\x00\x00\x01\x2c\x00\x02\xc0\x10\
+        \xc0\x10\x00\x01\x00\x01\x00\x00\x01\x2c\x00\x04\x01\x02\x03\x04";
+
+    /// An authoritative response to an `ANY` query for `example.com`.
+    const ANY_RESPONSE: &[u8] = b"\xab\xcd\x85\x80\x00\x01\x00\x03\x00\x00\x00\x00\
+        \x07example\x03com\x00\x00\xff\x00\x01\
+        \xc0\x0c\x00\x0f\x00\x01\x00\x00\x0e\x10\x00\x09\x00\x0a\x04mail\xc0\x0c\
+        \xc0\x0c\x00\x10\x00\x01\x00\x00\x0e\x10\x00\x0f\x0bv=spf1 -all\x02hi\
+        \xc0\x0c\x00\x1c\x00\x01\x00\x00\x0e\x10\x00\x10\
+        \x20\x01\x0d\xb8\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x01";
+
+    /// A query for the `MX` records of `example.com`, with an `AD` bit.
+    const QUERY: &[u8] = b"\xbe\xef\x01\x20\x00\x01\x00\x00\x00\x00\x00\x00\
+        \x07example\x03com\x00\x00\x0f\x00\x01";
+
+    test_function![
+        parse_dns_query => ParseDnsQuery;
+
+        a_record_response {
+            args: func_args![value: Bytes::from_static(A_RESPONSE)],
+            want: Ok(value!({
+                "id": 4660,
+                "opcode": 0,
+                "rcode": "NOERROR",
+                "flags": { "qr": true, "aa": false, "tc": false, "rd": true, "ra": true, "ad": false, "cd": false },
+                "questions": [{ "name": "example.com", "type": "A", "class": "IN" }],
+                "answers": [{ "name": "example.com", "type": "A", "class": "IN", "ttl": 3600, "data": "93.184.216.34" }],
+            })),
+            tdef: TypeDef::new().fallible().object(type_def()),
+        }
+
+        cname_response {
+            args: func_args![value: Bytes::from_static(CNAME_RESPONSE)],
+            want: Ok(value!({
+                "id": 1,
+                "opcode": 0,
+                "rcode": "NOERROR",
+                "flags": { "qr": true, "aa": false, "tc": false, "rd": true, "ra": true, "ad": false, "cd": false },
+                "questions": [{ "name": "www.example.com", "type": "A", "class": "IN" }],
+                "answers": [
+                    { "name": "www.example.com", "type": "CNAME", "class": "IN", "ttl": 300, "data": "example.com" },
+                    { "name": "example.com", "type": "A", "class": "IN", "ttl": 300, "data": "1.2.3.4" },
+                ],
+            })),
+            tdef: TypeDef::new().fallible().object(type_def()),
+        }
+
+        multi_answer_response {
+            args: func_args![value: Bytes::from_static(ANY_RESPONSE)],
+            want: Ok(value!({
+                "id": 43981,
+                "opcode": 0,
+                "rcode": "NOERROR",
+                "flags": { "qr": true, "aa": true, "tc": false, "rd": true, "ra": true, "ad": false, "cd": false },
+                "questions": [{ "name": "example.com", "type": "ANY", "class": "IN" }],
+                "answers": [
+                    { "name": "example.com", "type": "MX", "class": "IN", "ttl": 3600, "data": { "preference": 10, "exchange": "mail.example.com" } },
+                    { "name": "example.com", "type": "TXT", "class": "IN", "ttl": 3600, "data": ["v=spf1 -all", "hi"] },
+                    { "name": "example.com", "type": "AAAA", "class": "IN", "ttl": 3600, "data": "2001:db8::1" },
+                ],
+            })),
+            tdef: TypeDef::new().fallible().object(type_def()),
+        }
+
+        query {
+            args: func_args![value: Bytes::from_static(QUERY)],
+            want: Ok(value!({
+                "id": 48879,
+                "opcode": 0,
+                "rcode": "NOERROR",
+                "flags": { "qr": false, "aa": false, "tc": false, "rd": true, "ra": false, "ad": true, "cd": false },
+                "questions": [{ "name": "example.com", "type": "MX", "class": "IN" }],
+                "answers": [],
+            })),
+            tdef: TypeDef::new().fallible().object(type_def()),
+        }
+
+        nxdomain {
+            args: func_args![value: Bytes::from_static(b"\x00\x02\x81\x83\x00\x01\x00\x00\x00\x00\x00\x00\x00\x00\x02\x00\x01")],
+            want: Ok(value!({
+                "id": 2,
+                "opcode": 0,
+                "rcode": "NXDOMAIN",
+                "flags": { "qr": true, "aa": false, "tc": false, "rd": true, "ra": true, "ad": false, "cd": false },
+                "questions": [{ "name": ".", "type": "NS", "class": "IN" }],
+                "answers": [],
+            })),
+            tdef: TypeDef::new().fallible().object(type_def()),
+        }
+
+        truncated_record {
+            args: func_args![value: Bytes::copy_from_slice(&A_RESPONSE[..A_RESPONSE.len() - 2])],
+            want: Err("unable to parse DNS message: truncated message"),
+            tdef: TypeDef::new().fallible().object(type_def()),
+        }
+
+        truncated_header {
+            args: func_args![value: Bytes::from_static(b"\x12\x34\x81")],
+            want: Err("unable to parse DNS message: truncated message"),
+            tdef: TypeDef::new().fallible().object(type_def()),
+        }
+
+        compression_loop {
+            args: func_args![value: Bytes::from_static(b"\x00\x01\x00\x00\x00\x01\x00\x00\x00\x00\x00\x00\xc0\x0c\x00\x01\x00\x01")],
+            want: Err("unable to parse DNS message: invalid compression pointer 12"),
+            tdef: TypeDef::new().fallible().object(type_def()),
+        }
+
+        compression_loop_through_label {
+            args: func_args![value: Bytes::from_static(b"\x00\x01\x00\x00\x00\x01\x00\x00\x00\x00\x00\x00\x01a\xc0\x0c\x00\x01\x00\x01")],
+            want: Err("unable to parse DNS message: invalid compression pointer 12"),
+            tdef: TypeDef::new().fallible().object(type_def()),
+        }
+    ];
+}
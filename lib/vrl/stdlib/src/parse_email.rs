@@ -0,0 +1,108 @@
+use crate::util;
+use std::collections::BTreeMap;
+use std::iter::FromIterator;
+use vrl::prelude::*;
+
+#[derive(Clone, Copy, Debug)]
+pub struct ParseEmail;
+
+impl Function for ParseEmail {
+    fn identifier(&self) -> &'static str {
+        "parse_email"
+    }
+
+    fn is_pure(&self) -> bool {
+        true
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[Parameter {
+            keyword: "value",
+            kind: kind::BYTES,
+            required: true,
+        }]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[Example {
+            title: "email address",
+            source: r#"parse_email!("vector@timber.io")"#,
+            result: Ok(r#"{ "domain": "timber.io", "local": "vector" }"#),
+        }]
+    }
+
+    fn compile(&self, mut arguments: ArgumentList) -> Compiled {
+        let value = arguments.required("value");
+
+        Ok(Box::new(ParseEmailFn { value }))
+    }
+}
+
+#[derive(Debug, Clone)]
+struct ParseEmailFn {
+    value: Box<dyn Expression>,
+}
+
+impl Expression for ParseEmailFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?;
+        let value = value.try_bytes_utf8_lossy()?;
+        let (local, domain) = util::parse_email(&value)?;
+
+        Ok(Value::from_iter(
+            vec![("local", local), ("domain", domain)]
+                .into_iter()
+                .map(|(k, v)| (k.to_owned(), v.to_owned().into())),
+        ))
+    }
+
+    fn type_def(&self, _: &state::Compiler) -> TypeDef {
+        TypeDef::new().fallible().object(type_def())
+    }
+}
+
+fn type_def() -> BTreeMap<&'static str, TypeDef> {
+    map! {
+        "local": Kind::Bytes,
+        "domain": Kind::Bytes,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    test_function![
+        parse_email => ParseEmail;
+
+        simple {
+            args: func_args![value: "vector@timber.io"],
+            want: Ok(value!({ "local": "vector", "domain": "timber.io" })),
+            tdef: TypeDef::new().fallible().object(type_def()),
+        }
+
+        plus_tag {
+            args: func_args![value: "first.last+news@Gmail.com"],
+            want: Ok(value!({ "local": "first.last+news", "domain": "Gmail.com" })),
+            tdef: TypeDef::new().fallible().object(type_def()),
+        }
+
+        quoted_at {
+            args: func_args![value: r#""a@b"@example.com"#],
+            want: Ok(value!({ "local": r#""a@b""#, "domain": "example.com" })),
+            tdef: TypeDef::new().fallible().object(type_def()),
+        }
+
+        missing_at {
+            args: func_args![value: "vector.timber.io"],
+            want: Err(r#"invalid email address "vector.timber.io""#),
+            tdef: TypeDef::new().fallible().object(type_def()),
+        }
+
+        missing_local {
+            args: func_args![value: "@timber.io"],
+            want: Err(r#"invalid email address "@timber.io""#),
+            tdef: TypeDef::new().fallible().object(type_def()),
+        }
+    ];
+}
@@ -0,0 +1,176 @@
+use crate::fixed_width_util;
+use std::collections::BTreeMap;
+use vrl::prelude::*;
+
+#[derive(Clone, Copy, Debug)]
+pub struct ParseFixedWidth;
+
+impl Function for ParseFixedWidth {
+    fn identifier(&self) -> &'static str {
+        "parse_fixed_width"
+    }
+
+    fn is_pure(&self) -> bool {
+        true
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[
+            Parameter {
+                keyword: "value",
+                kind: kind::BYTES,
+                required: true,
+            },
+            Parameter {
+                keyword: "spec",
+                kind: kind::ARRAY,
+                required: true,
+            },
+        ]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "start and length",
+                source: r#"parse_fixed_width!("00042JOHN     SMITH", [{ "name": "id", "start": 0, "length": 5 }, { "name": "last", "start": 14, "length": 5 }])"#,
+                result: Ok(r#"{ "id": "00042", "last": "SMITH" }"#),
+            },
+            Example {
+                title: "consecutive widths",
+                source: r#"parse_fixed_width!("00042JOHN     SMITH", [{ "name": "id", "width": 5 }, { "name": "first", "width": 9 }, { "name": "last", "width": 5 }])"#,
+                result: Ok(r#"{ "first": "JOHN", "id": "00042", "last": "SMITH" }"#),
+            },
+        ]
+    }
+
+    fn compile(&self, mut arguments: ArgumentList) -> Compiled {
+        let value = arguments.required("value");
+        let spec = arguments.required("spec");
+
+        Ok(Box::new(ParseFixedWidthFn { value, spec }))
+    }
+}
+
+#[derive(Debug, Clone)]
+struct ParseFixedWidthFn {
+    value: Box<dyn Expression>,
+    spec: Box<dyn Expression>,
+}
+
+impl Expression for ParseFixedWidthFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?;
+        let chars = value.try_bytes_utf8_lossy()?.chars().collect::<Vec<_>>();
+        let spec = fixed_width_util::fixed_width_spec(self.spec.resolve(ctx)?)?;
+
+        let fields = spec
+            .into_iter()
+            .map(|field| {
+                // Columns starting past the end of the line are null, those
+                // running past it are cut short.
+                let value = if field.start >= chars.len() {
+                    Value::Null
+                } else {
+                    let end = chars.len().min(field.start.saturating_add(field.length));
+                    let column = chars[field.start..end].iter().collect::<String>();
+
+                    column.trim().into()
+                };
+
+                (field.name, value)
+            })
+            .collect::<BTreeMap<_, _>>();
+
+        Ok(fields.into())
+    }
+
+    fn type_def(&self, _: &state::Compiler) -> TypeDef {
+        TypeDef::new()
+            .fallible()
+            .object::<(), Kind>(map! { (): Kind::Bytes | Kind::Null })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    test_function![
+        parse_fixed_width => ParseFixedWidth;
+
+        trailing_padding {
+            args: func_args![
+                value: "00042JOHN     SMITH     ",
+                spec: value!([
+                    { "name": "id", "start": 0, "length": 5 },
+                    { "name": "first", "start": 5, "length": 9 },
+                    { "name": "last", "start": 14, "length": 10 },
+                ]),
+            ],
+            want: Ok(value!({ "id": "00042", "first": "JOHN", "last": "SMITH" })),
+            tdef: TypeDef::new().fallible().object::<(), Kind>(map! { (): Kind::Bytes | Kind::Null }),
+        }
+
+        consecutive_widths {
+            args: func_args![
+                value: "ABC12  X",
+                spec: value!([
+                    { "name": "code", "width": 3 },
+                    { "name": "amount", "width": 4 },
+                    { "name": "flag", "width": 1 },
+                ]),
+            ],
+            want: Ok(value!({ "code": "ABC", "amount": "12", "flag": "X" })),
+            tdef: TypeDef::new().fallible().object::<(), Kind>(map! { (): Kind::Bytes | Kind::Null }),
+        }
+
+        overrun {
+            args: func_args![
+                value: "00042JOH",
+                spec: value!([
+                    { "name": "id", "start": 0, "length": 5 },
+                    { "name": "first", "start": 5, "length": 9 },
+                    { "name": "last", "start": 14, "length": 10 },
+                ]),
+            ],
+            want: Ok(value!({ "id": "00042", "first": "JOH", "last": null })),
+            tdef: TypeDef::new().fallible().object::<(), Kind>(map! { (): Kind::Bytes | Kind::Null }),
+        }
+
+        trimming {
+            args: func_args![
+                value: "  left|right  | both ",
+                spec: value!([
+                    { "name": "a", "start": 0, "length": 6 },
+                    { "name": "b", "start": 7, "length": 7 },
+                    { "name": "c", "start": 15, "length": 6 },
+                    { "name": "separator", "start": 14, "length": 1 },
+                ]),
+            ],
+            want: Ok(value!({ "a": "left", "b": "right", "c": "both", "separator": "|" })),
+            tdef: TypeDef::new().fallible().object::<(), Kind>(map! { (): Kind::Bytes | Kind::Null }),
+        }
+
+        multibyte {
+            args: func_args![
+                value: "Zoë  Köln",
+                spec: value!([{ "name": "name", "width": 5 }, { "name": "city", "width": 4 }]),
+            ],
+            want: Ok(value!({ "name": "Zoë", "city": "Köln" })),
+            tdef: TypeDef::new().fallible().object::<(), Kind>(map! { (): Kind::Bytes | Kind::Null }),
+        }
+
+        missing_length {
+            args: func_args![value: "foo", spec: value!([{ "name": "foo", "start": 0 }])],
+            want: Err(r#"invalid field 0 in spec: missing "length" or "width""#),
+            tdef: TypeDef::new().fallible().object::<(), Kind>(map! { (): Kind::Bytes | Kind::Null }),
+        }
+
+        invalid_start {
+            args: func_args![value: "foo", spec: value!([{ "name": "foo", "width": 1 }, { "name": "bar", "start": (-1), "width": 1 }])],
+            want: Err(r#"invalid field 1 in spec: "start" must be a non-negative integer"#),
+            tdef: TypeDef::new().fallible().object::<(), Kind>(map! { (): Kind::Bytes | Kind::Null }),
+        }
+    ];
+}
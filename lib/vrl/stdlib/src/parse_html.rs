@@ -0,0 +1,284 @@
+use crate::html_util::{self, HtmlToken};
+use std::str::FromStr;
+use vrl::prelude::*;
+
+#[derive(Clone, Copy, Debug)]
+pub struct ParseHtml;
+
+impl Function for ParseHtml {
+    fn identifier(&self) -> &'static str {
+        "parse_html"
+    }
+
+    fn is_pure(&self) -> bool {
+        true
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[
+            Parameter {
+                keyword: "value",
+                kind: kind::BYTES,
+                required: true,
+            },
+            Parameter {
+                keyword: "selector",
+                kind: kind::BYTES,
+                required: true,
+            },
+        ]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "class selector",
+                source: r#"parse_html!(s'<p class="note">a &amp; b</p><p>c</p>', ".note")"#,
+                result: Ok(r#"["a & b"]"#),
+            },
+            Example {
+                title: "tag selector",
+                source: r#"parse_html!("<ul><li>one</li><li>two</li></ul>", "li")"#,
+                result: Ok(r#"["one", "two"]"#),
+            },
+        ]
+    }
+
+    fn compile(&self, mut arguments: ArgumentList) -> Compiled {
+        let value = arguments.required("value");
+        let selector = arguments.required("selector");
+
+        Ok(Box::new(ParseHtmlFn { value, selector }))
+    }
+}
+
+#[derive(Debug, Clone)]
+struct ParseHtmlFn {
+    value: Box<dyn Expression>,
+    selector: Box<dyn Expression>,
+}
+
+impl Expression for ParseHtmlFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let bytes = self.value.resolve(ctx)?.try_bytes()?;
+        let input = String::from_utf8_lossy(&bytes);
+
+        let selector = self.selector.resolve(ctx)?.try_bytes()?;
+        let selector = Selector::from_str(&String::from_utf8_lossy(&selector))?;
+
+        Ok(select(&input, &selector).into())
+    }
+
+    fn type_def(&self, _: &state::Compiler) -> TypeDef {
+        TypeDef::new()
+            .fallible()
+            .array_mapped::<(), Kind>(map! { (): Kind::Bytes })
+    }
+}
+
+/// A compound CSS selector, such as `div`, `.note`, `#main`, or `p.note.big`.
+#[derive(Debug, Default, PartialEq)]
+struct Selector {
+    tag: Option<String>,
+    id: Option<String>,
+    classes: Vec<String>,
+}
+
+impl Selector {
+    fn matches(&self, name: &str, attributes: &[(String, String)]) -> bool {
+        let attribute = |key: &str| {
+            attributes
+                .iter()
+                .find(|(k, _)| k == key)
+                .map(|(_, v)| v.as_str())
+        };
+
+        if self.tag.as_deref().map_or(false, |tag| tag != name) {
+            return false;
+        }
+
+        if self.id.is_some() && attribute("id") != self.id.as_deref() {
+            return false;
+        }
+
+        let classes = attribute("class").unwrap_or_default();
+        self.classes
+            .iter()
+            .all(|class| classes.split_whitespace().any(|c| c == class))
+    }
+}
+
+impl FromStr for Selector {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let invalid = || format!(r#"invalid selector "{}""#, s);
+        let is_ident = |c: char| c.is_alphanumeric() || c == '-' || c == '_';
+
+        let mut selector = Selector::default();
+        let mut rest = s.trim();
+
+        let tag_len = rest
+            .find(|c: char| !is_ident(c))
+            .unwrap_or_else(|| rest.len());
+        if tag_len > 0 {
+            selector.tag = Some(rest[..tag_len].to_ascii_lowercase());
+        }
+        rest = &rest[tag_len..];
+
+        while let Some(prefix) = rest.chars().next() {
+            rest = &rest[prefix.len_utf8()..];
+
+            let len = rest
+                .find(|c: char| !is_ident(c))
+                .unwrap_or_else(|| rest.len());
+            let ident = rest[..len].to_owned();
+
+            match prefix {
+                _ if ident.is_empty() => return Err(invalid()),
+                '.' => selector.classes.push(ident),
+                '#' if selector.id.is_none() => selector.id = Some(ident),
+                _ => return Err(invalid()),
+            }
+
+            rest = &rest[len..];
+        }
+
+        if selector == Selector::default() {
+            return Err(invalid());
+        }
+
+        Ok(selector)
+    }
+}
+
+/// Returns the text content of all elements matching the selector, in
+/// document order.
+fn select(input: &str, selector: &Selector) -> Vec<Value> {
+    // Void elements never have content, nor a closing tag.
+    const VOID: &[&str] = &[
+        "area", "base", "br", "col", "embed", "hr", "img", "input", "link", "meta", "param",
+        "source", "track", "wbr",
+    ];
+
+    let mut matches: Vec<String> = vec![];
+
+    // The open elements, along with the index of their match, if they matched
+    // the selector.
+    let mut stack: Vec<(String, Option<usize>)> = vec![];
+
+    for token in html_util::tokenize_html(input) {
+        match token {
+            HtmlToken::Start {
+                name,
+                attributes,
+                self_closing,
+            } => {
+                let index = if selector.matches(&name, &attributes) {
+                    matches.push(String::new());
+                    Some(matches.len() - 1)
+                } else {
+                    None
+                };
+
+                if !self_closing && !VOID.contains(&name.as_str()) {
+                    stack.push((name, index));
+                }
+            }
+            HtmlToken::End(name) => {
+                // Unbalanced closing tags are ignored, and any elements left
+                // open inside the closed element are closed along with it.
+                if let Some(pos) = stack.iter().rposition(|(open, _)| open == &name) {
+                    stack.truncate(pos);
+                }
+            }
+            HtmlToken::Text(text) => {
+                let text = html_util::decode_html_entities(text);
+
+                for index in stack.iter().filter_map(|(_, index)| *index) {
+                    matches[index].push_str(&text);
+                }
+            }
+        }
+    }
+
+    matches.into_iter().map(Into::into).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_selector() {
+        assert_eq!(
+            Selector::from_str("P.note.big"),
+            Ok(Selector {
+                tag: Some("p".to_owned()),
+                id: None,
+                classes: vec!["note".to_owned(), "big".to_owned()],
+            })
+        );
+        assert_eq!(
+            Selector::from_str("#main"),
+            Ok(Selector {
+                tag: None,
+                id: Some("main".to_owned()),
+                classes: vec![],
+            })
+        );
+
+        for invalid in &["", ".", "div p", "a > b", "#a#b", "[href]", "p."] {
+            assert_eq!(
+                Selector::from_str(invalid),
+                Err(format!(r#"invalid selector "{}""#, invalid))
+            );
+        }
+    }
+
+    test_function![
+        parse_html => ParseHtml;
+
+        class_selector {
+            args: func_args![
+                value: r#"<div class="post"><p class="title big">Fish &amp; <b>chips</b></p><p class="body">Tasty</p><p class="title">Again</p></div>"#,
+                selector: ".title",
+            ],
+            want: Ok(value!(["Fish & chips", "Again"])),
+            tdef: TypeDef::new().fallible().array_mapped::<(), Kind>(map! { (): Kind::Bytes }),
+        }
+
+        compound_selector {
+            args: func_args![
+                value: r#"<span class="title">no</span><p class="title big">yes</p><p class="title">no</p>"#,
+                selector: "p.title.big",
+            ],
+            want: Ok(value!(["yes"])),
+            tdef: TypeDef::new().fallible().array_mapped::<(), Kind>(map! { (): Kind::Bytes }),
+        }
+
+        id_selector {
+            args: func_args![value: r#"<div id=main>one<div id="other">two</div></div>"#, selector: "#main"],
+            want: Ok(value!(["onetwo"])),
+            tdef: TypeDef::new().fallible().array_mapped::<(), Kind>(map! { (): Kind::Bytes }),
+        }
+
+        nested_matches {
+            args: func_args![value: "<div>a<div>b</div><br>c</div>", selector: "div"],
+            want: Ok(value!(["abc", "b"])),
+            tdef: TypeDef::new().fallible().array_mapped::<(), Kind>(map! { (): Kind::Bytes }),
+        }
+
+        no_matches {
+            args: func_args![value: "<p>text</p>", selector: "li"],
+            want: Ok(value!([])),
+            tdef: TypeDef::new().fallible().array_mapped::<(), Kind>(map! { (): Kind::Bytes }),
+        }
+
+        invalid_selector {
+            args: func_args![value: "<p>text</p>", selector: "ul > li"],
+            want: Err(r#"invalid selector "ul > li""#),
+            tdef: TypeDef::new().fallible().array_mapped::<(), Kind>(map! { (): Kind::Bytes }),
+        }
+    ];
+}
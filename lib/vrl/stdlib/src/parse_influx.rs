@@ -0,0 +1,381 @@
+use chrono::{TimeZone, Utc};
+use std::collections::BTreeMap;
+use std::convert::TryFrom;
+use vrl::prelude::*;
+
+/// The characters escaped with a backslash in measurements.
+const MEASUREMENT_ESCAPES: &[u8] = b", ";
+
+/// The characters escaped with a backslash in tag keys, tag values and field
+/// keys.
+const KEY_ESCAPES: &[u8] = b",= ";
+
+#[derive(Clone, Copy, Debug)]
+pub struct ParseInflux;
+
+impl Function for ParseInflux {
+    fn identifier(&self) -> &'static str {
+        "parse_influx"
+    }
+
+    fn is_pure(&self) -> bool {
+        true
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[Parameter {
+            keyword: "value",
+            kind: kind::BYTES,
+            required: true,
+        }]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "line",
+                source: r#"parse_influx!("cpu,host=a usage=0.5,cores=4i 1615000000000000000")"#,
+                result: Ok(
+                    r#"{ "fields": { "cores": 4, "usage": 0.5 }, "measurement": "cpu", "tags": { "host": "a" }, "timestamp": t'2021-03-06T03:06:40Z' }"#,
+                ),
+            },
+            Example {
+                title: "without timestamp",
+                source: r#"parse_influx!("weather temperature=21.5")"#,
+                result: Ok(
+                    r#"{ "fields": { "temperature": 21.5 }, "measurement": "weather", "tags": {}, "timestamp": null }"#,
+                ),
+            },
+        ]
+    }
+
+    fn compile(&self, mut arguments: ArgumentList) -> Compiled {
+        let value = arguments.required("value");
+
+        Ok(Box::new(ParseInfluxFn { value }))
+    }
+}
+
+#[derive(Debug, Clone)]
+struct ParseInfluxFn {
+    value: Box<dyn Expression>,
+}
+
+impl Expression for ParseInfluxFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let bytes = self.value.resolve(ctx)?.try_bytes()?;
+        let line = String::from_utf8_lossy(&bytes);
+        let line = line.trim_end_matches(|c| c == '\n' || c == '\r');
+
+        Ok(parse_line(line)
+            .map_err(|err| format!("unable to parse influx line protocol: {}", err))?)
+    }
+
+    fn type_def(&self, _: &state::Compiler) -> TypeDef {
+        TypeDef::new().fallible().object(type_def())
+    }
+}
+
+fn type_def() -> BTreeMap<&'static str, TypeDef> {
+    map! {
+        "measurement": Kind::Bytes,
+        "tags": TypeDef::new().object::<(), Kind>(map! {
+            (): Kind::Bytes,
+        }),
+        "fields": TypeDef::new().object::<(), Kind>(map! {
+            (): Kind::Bytes | Kind::Integer | Kind::Float | Kind::Boolean,
+        }),
+        "timestamp": Kind::Timestamp | Kind::Null,
+    }
+}
+
+fn parse_line(line: &str) -> std::result::Result<Value, String> {
+    let mut parser = Parser {
+        input: line,
+        position: 0,
+    };
+
+    let measurement = parser.token(b", ", MEASUREMENT_ESCAPES);
+    if measurement.is_empty() {
+        return Err("missing measurement".to_owned());
+    }
+
+    let mut tags = BTreeMap::new();
+    while parser.eat(b',') {
+        let key = parser.token(b"=, ", KEY_ESCAPES);
+        let value = if parser.eat(b'=') {
+            parser.token(b", ", KEY_ESCAPES)
+        } else {
+            String::new()
+        };
+
+        if key.is_empty() || value.is_empty() {
+            return Err(format!("invalid tag {:?}", key));
+        }
+
+        tags.insert(key, Value::from(value));
+    }
+
+    if !parser.eat(b' ') {
+        return Err("missing fields".to_owned());
+    }
+
+    let mut fields = BTreeMap::new();
+    loop {
+        let key = parser.token(b"=, ", KEY_ESCAPES);
+        if key.is_empty() || !parser.eat(b'=') {
+            return Err(format!("invalid field {:?}", key));
+        }
+
+        let value = if parser.eat(b'"') {
+            parser.string()?.into()
+        } else {
+            let value = parser.token(b", ", b"");
+            field_value(&value)
+                .ok_or_else(|| format!("invalid value {:?} of field {:?}", value, key))?
+        };
+
+        fields.insert(key, value);
+
+        if !parser.eat(b',') {
+            break;
+        }
+    }
+
+    let timestamp = if parser.eat(b' ') {
+        let timestamp = parser.token(b" ", b"");
+        parse_timestamp(&timestamp).ok_or_else(|| format!("invalid timestamp {:?}", timestamp))?
+    } else {
+        Value::Null
+    };
+
+    if let Some(rest) = parser.rest() {
+        return Err(format!("unexpected {:?}", rest));
+    }
+
+    let mut line = BTreeMap::new();
+    line.insert("measurement".to_owned(), Value::from(measurement));
+    line.insert("tags".to_owned(), Value::from(tags));
+    line.insert("fields".to_owned(), Value::from(fields));
+    line.insert("timestamp".to_owned(), timestamp);
+
+    Ok(line.into())
+}
+
+/// Parses an unquoted field value: an integer suffixed with `i` or `u`, a
+/// boolean, or a float.
+fn field_value(value: &str) -> Option<Value> {
+    if let Some(integer) = value.strip_suffix('i') {
+        return integer.parse::<i64>().ok().map(Into::into);
+    }
+
+    if let Some(unsigned) = value.strip_suffix('u') {
+        let unsigned = unsigned.parse::<u64>().ok()?;
+        return i64::try_from(unsigned).ok().map(Into::into);
+    }
+
+    match value {
+        "t" | "T" | "true" | "True" | "TRUE" => Some(true.into()),
+        "f" | "F" | "false" | "False" | "FALSE" => Some(false.into()),
+        _ => value
+            .parse::<f64>()
+            .ok()
+            .filter(|float| float.is_finite())
+            .map(Into::into),
+    }
+}
+
+/// Parses a timestamp in nanoseconds since the Unix epoch.
+fn parse_timestamp(timestamp: &str) -> Option<Value> {
+    let nanos = timestamp.parse::<i64>().ok()?;
+
+    Utc.timestamp_opt(
+        nanos.div_euclid(1_000_000_000),
+        nanos.rem_euclid(1_000_000_000) as u32,
+    )
+    .single()
+    .map(Into::into)
+}
+
+struct Parser<'a> {
+    input: &'a str,
+    position: usize,
+}
+
+impl<'a> Parser<'a> {
+    /// Consumes the next byte if it's the given one.
+    fn eat(&mut self, byte: u8) -> bool {
+        let matches = self.input.as_bytes().get(self.position) == Some(&byte);
+        if matches {
+            self.position += 1;
+        }
+
+        matches
+    }
+
+    /// Reads up to the first unescaped delimiter, or the end of the input,
+    /// removing the backslashes escaping the `escapes` characters. Other
+    /// backslashes are kept as is.
+    fn token(&mut self, delimiters: &[u8], escapes: &[u8]) -> String {
+        let bytes = self.input.as_bytes();
+        let mut token = String::new();
+        let mut start = self.position;
+
+        while let Some(byte) = bytes.get(self.position) {
+            let escaped = bytes.get(self.position + 1);
+
+            if *byte == b'\\' && escaped.map_or(false, |escaped| escapes.contains(escaped)) {
+                token.push_str(&self.input[start..self.position]);
+                start = self.position + 1;
+                self.position += 2;
+            } else if delimiters.contains(byte) {
+                break;
+            } else {
+                self.position += 1;
+            }
+        }
+
+        token.push_str(&self.input[start..self.position]);
+        token
+    }
+
+    /// Reads a string field value, after its opening quote.
+    fn string(&mut self) -> std::result::Result<String, String> {
+        let bytes = self.input.as_bytes();
+        let mut string = String::new();
+        let mut start = self.position;
+
+        while let Some(byte) = bytes.get(self.position) {
+            match byte {
+                b'\\' if matches!(bytes.get(self.position + 1), Some(b'"') | Some(b'\\')) => {
+                    string.push_str(&self.input[start..self.position]);
+                    start = self.position + 1;
+                    self.position += 2;
+                }
+                b'"' => {
+                    string.push_str(&self.input[start..self.position]);
+                    self.position += 1;
+                    return Ok(string);
+                }
+                _ => self.position += 1,
+            }
+        }
+
+        Err("unterminated string".to_owned())
+    }
+
+    fn rest(&self) -> Option<&'a str> {
+        Some(&self.input[self.position..]).filter(|rest| !rest.is_empty())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    test_function![
+        parse_influx => ParseInflux;
+
+        line {
+            args: func_args![value: "cpu,host=a,region=eu usage=0.5,cores=4i,up=t 1615000000000000000"],
+            want: Ok(value!({
+                "measurement": "cpu",
+                "tags": { "host": "a", "region": "eu" },
+                "fields": { "usage": 0.5, "cores": 4, "up": true },
+                "timestamp": (Utc.timestamp(1_615_000_000, 0)),
+            })),
+            tdef: TypeDef::new().fallible().object(type_def()),
+        }
+
+        without_timestamp {
+            args: func_args![value: "weather temperature=21.5\n"],
+            want: Ok(value!({
+                "measurement": "weather",
+                "tags": {},
+                "fields": { "temperature": 21.5 },
+                "timestamp": null,
+            })),
+            tdef: TypeDef::new().fallible().object(type_def()),
+        }
+
+        integers_and_floats {
+            args: func_args![value: "m a=1,b=1i,c=-2i,d=3u,e=-1.5e3,f=1.0"],
+            want: Ok(value!({
+                "measurement": "m",
+                "tags": {},
+                "fields": { "a": 1.0, "b": 1, "c": (-2), "d": 3, "e": (-1500.0), "f": 1.0 },
+                "timestamp": null,
+            })),
+            tdef: TypeDef::new().fallible().object(type_def()),
+        }
+
+        escaping {
+            args: func_args![value: r#"my\ measure\,ment,tag\ key\,\==tag\ value\,\= field\ key\=="string \"quoted\" \\ value",path="C:\dir" 1"#],
+            want: Ok(value!({
+                "measurement": "my measure,ment",
+                "tags": { "tag key,=": "tag value,=" },
+                "fields": { "field key=": r#"string "quoted" \ value"#, "path": r#"C:\dir"# },
+                "timestamp": (Utc.timestamp(0, 1)),
+            })),
+            tdef: TypeDef::new().fallible().object(type_def()),
+        }
+
+        string_with_delimiters {
+            args: func_args![value: r#"m msg="a, b=c d""#],
+            want: Ok(value!({
+                "measurement": "m",
+                "tags": {},
+                "fields": { "msg": "a, b=c d" },
+                "timestamp": null,
+            })),
+            tdef: TypeDef::new().fallible().object(type_def()),
+        }
+
+        negative_timestamp {
+            args: func_args![value: "m a=1 -1"],
+            want: Ok(value!({
+                "measurement": "m",
+                "tags": {},
+                "fields": { "a": 1.0 },
+                "timestamp": (Utc.timestamp(-1, 999_999_999)),
+            })),
+            tdef: TypeDef::new().fallible().object(type_def()),
+        }
+
+        missing_fields {
+            args: func_args![value: "cpu,host=a"],
+            want: Err("unable to parse influx line protocol: missing fields"),
+            tdef: TypeDef::new().fallible().object(type_def()),
+        }
+
+        invalid_tag {
+            args: func_args![value: "cpu,host usage=1"],
+            want: Err(r#"unable to parse influx line protocol: invalid tag "host""#),
+            tdef: TypeDef::new().fallible().object(type_def()),
+        }
+
+        invalid_field_value {
+            args: func_args![value: "cpu usage=high"],
+            want: Err(r#"unable to parse influx line protocol: invalid value "high" of field "usage""#),
+            tdef: TypeDef::new().fallible().object(type_def()),
+        }
+
+        unterminated_string {
+            args: func_args![value: r#"cpu msg="oops"#],
+            want: Err("unable to parse influx line protocol: unterminated string"),
+            tdef: TypeDef::new().fallible().object(type_def()),
+        }
+
+        invalid_timestamp {
+            args: func_args![value: "cpu usage=1 soon"],
+            want: Err(r#"unable to parse influx line protocol: invalid timestamp "soon""#),
+            tdef: TypeDef::new().fallible().object(type_def()),
+        }
+
+        trailing_data {
+            args: func_args![value: "cpu usage=1 1 2"],
+            want: Err(r#"unable to parse influx line protocol: unexpected " 2""#),
+            tdef: TypeDef::new().fallible().object(type_def()),
+        }
+    ];
+}
@@ -0,0 +1,256 @@
+use lazy_static::lazy_static;
+use regex::Regex;
+use rust_decimal::{prelude::ToPrimitive, Decimal};
+use std::collections::HashMap;
+use std::str::FromStr;
+use vrl::prelude::*;
+
+lazy_static! {
+    static ref RE: Regex = Regex::new(
+        r"(?x)                                    # x: ignore whitespace + comments
+            \A
+            P
+            (?:(?P<years>[0-9]+(?:[.,][0-9]+)?)Y)?
+            (?:(?P<months>[0-9]+(?:[.,][0-9]+)?)M)?
+            (?:(?P<weeks>[0-9]+(?:[.,][0-9]+)?)W)?
+            (?:(?P<days>[0-9]+(?:[.,][0-9]+)?)D)?
+            (?:T                                  # time components
+                (?:(?P<hours>[0-9]+(?:[.,][0-9]+)?)H)?
+                (?:(?P<minutes>[0-9]+(?:[.,][0-9]+)?)M)?
+                (?:(?P<seconds>[0-9]+(?:[.,][0-9]+)?)S)?
+            )?
+            \z"
+    )
+    .unwrap();
+    static ref UNITS: HashMap<String, Decimal> = vec![
+        ("ns", Decimal::new(1, 9)),
+        ("us", Decimal::new(1, 6)),
+        ("µs", Decimal::new(1, 6)),
+        ("ms", Decimal::new(1, 3)),
+        ("cs", Decimal::new(1, 2)),
+        ("ds", Decimal::new(1, 1)),
+        ("s", Decimal::new(1, 0)),
+        ("m", Decimal::new(60, 0)),
+        ("h", Decimal::new(3_600, 0)),
+        ("d", Decimal::new(86_400, 0)),
+    ]
+    .into_iter()
+    .map(|(k, v)| (k.to_owned(), v))
+    .collect();
+}
+
+/// The length in seconds of each component of a duration. Years and months
+/// don't have a fixed length, they're approximated as 365 and 30 days.
+const COMPONENTS: &[(&str, i64)] = &[
+    ("years", 365 * 86_400),
+    ("months", 30 * 86_400),
+    ("weeks", 7 * 86_400),
+    ("days", 86_400),
+    ("hours", 3_600),
+    ("minutes", 60),
+    ("seconds", 1),
+];
+
+#[derive(Clone, Copy, Debug)]
+pub struct ParseIso8601Duration;
+
+impl Function for ParseIso8601Duration {
+    fn identifier(&self) -> &'static str {
+        "parse_iso8601_duration"
+    }
+
+    fn is_pure(&self) -> bool {
+        true
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "seconds",
+                source: r#"parse_iso8601_duration!("P1DT2H")"#,
+                result: Ok("93600.0"),
+            },
+            Example {
+                title: "minutes",
+                source: r#"parse_iso8601_duration!("PT1H30M", unit: "m")"#,
+                result: Ok("90.0"),
+            },
+        ]
+    }
+
+    fn compile(&self, mut arguments: ArgumentList) -> Compiled {
+        let value = arguments.required("value");
+        let unit = arguments.optional("unit").unwrap_or(expr!("s"));
+
+        Ok(Box::new(ParseIso8601DurationFn { value, unit }))
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[
+            Parameter {
+                keyword: "value",
+                kind: kind::BYTES,
+                required: true,
+            },
+            Parameter {
+                keyword: "unit",
+                kind: kind::BYTES,
+                required: false,
+            },
+        ]
+    }
+}
+
+#[derive(Debug, Clone)]
+struct ParseIso8601DurationFn {
+    value: Box<dyn Expression>,
+    unit: Box<dyn Expression>,
+}
+
+impl Expression for ParseIso8601DurationFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let bytes = self.value.resolve(ctx)?.try_bytes()?;
+        let value = String::from_utf8_lossy(&bytes);
+
+        let conversion_factor = {
+            let bytes = self.unit.resolve(ctx)?.try_bytes()?;
+            let string = String::from_utf8_lossy(&bytes);
+
+            UNITS
+                .get(string.as_ref())
+                .ok_or(format!("unknown unit format: '{}'", string))?
+        };
+
+        let seconds = parse_seconds(&value)
+            .ok_or(format!("unable to parse ISO 8601 duration: '{}'", value))?;
+
+        let number = seconds / conversion_factor;
+        let number = number
+            .to_f64()
+            .ok_or(format!("unable to format duration: '{}'", number))?;
+
+        Ok(number.into())
+    }
+
+    fn type_def(&self, _: &state::Compiler) -> TypeDef {
+        TypeDef::new().fallible().float()
+    }
+}
+
+/// Returns the total number of seconds of the duration, or `None` if it isn't
+/// a valid ISO 8601 duration.
+fn parse_seconds(value: &str) -> Option<Decimal> {
+    // At least one component is required, both in the duration and after the
+    // time designator.
+    if value == "P" || value.ends_with('T') {
+        return None;
+    }
+
+    let captures = RE.captures(value)?;
+
+    COMPONENTS
+        .iter()
+        .try_fold(
+            Decimal::new(0, 0),
+            |total, (name, seconds)| match captures.name(name) {
+                Some(number) => {
+                    let number = Decimal::from_str(&number.as_str().replace(',', ".")).ok()?;
+                    total.checked_add(number.checked_mul(Decimal::new(*seconds, 0))?)
+                }
+                None => Some(total),
+            },
+        )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    test_function![
+        parse_iso8601_duration => ParseIso8601Duration;
+
+        minutes {
+            args: func_args![value: "PT30M"],
+            want: Ok(1800.0),
+            tdef: TypeDef::new().fallible().float(),
+        }
+
+        minutes_in_minutes {
+            args: func_args![value: "PT30M", unit: "m"],
+            want: Ok(30.0),
+            tdef: TypeDef::new().fallible().float(),
+        }
+
+        year {
+            args: func_args![value: "P1Y", unit: "d"],
+            want: Ok(365.0),
+            tdef: TypeDef::new().fallible().float(),
+        }
+
+        month {
+            args: func_args![value: "P1M", unit: "d"],
+            want: Ok(30.0),
+            tdef: TypeDef::new().fallible().float(),
+        }
+
+        date_and_time {
+            args: func_args![value: "P1DT2H"],
+            want: Ok(93600.0),
+            tdef: TypeDef::new().fallible().float(),
+        }
+
+        all_components {
+            args: func_args![value: "P1Y2M3W4DT5H6M7S", unit: "s"],
+            want: Ok(38_898_367.0),
+            tdef: TypeDef::new().fallible().float(),
+        }
+
+        fractional_seconds {
+            args: func_args![value: "PT1.5S", unit: "ms"],
+            want: Ok(1500.0),
+            tdef: TypeDef::new().fallible().float(),
+        }
+
+        fractional_with_comma {
+            args: func_args![value: "PT0,5H", unit: "m"],
+            want: Ok(30.0),
+            tdef: TypeDef::new().fallible().float(),
+        }
+
+        error_invalid {
+            args: func_args![value: "30 minutes"],
+            want: Err("unable to parse ISO 8601 duration: '30 minutes'"),
+            tdef: TypeDef::new().fallible().float(),
+        }
+
+        error_out_of_order {
+            args: func_args![value: "PT30M1H"],
+            want: Err("unable to parse ISO 8601 duration: 'PT30M1H'"),
+            tdef: TypeDef::new().fallible().float(),
+        }
+
+        error_minutes_without_time_designator {
+            args: func_args![value: "P1M30S"],
+            want: Err("unable to parse ISO 8601 duration: 'P1M30S'"),
+            tdef: TypeDef::new().fallible().float(),
+        }
+
+        error_empty {
+            args: func_args![value: "P"],
+            want: Err("unable to parse ISO 8601 duration: 'P'"),
+            tdef: TypeDef::new().fallible().float(),
+        }
+
+        error_empty_time {
+            args: func_args![value: "P1DT"],
+            want: Err("unable to parse ISO 8601 duration: 'P1DT'"),
+            tdef: TypeDef::new().fallible().float(),
+        }
+
+        error_unit {
+            args: func_args![value: "PT1S", unit: "w"],
+            want: Err("unknown unit format: 'w'"),
+            tdef: TypeDef::new().fallible().float(),
+        }
+    ];
+}
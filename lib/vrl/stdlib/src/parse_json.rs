@@ -8,6 +8,10 @@ impl Function for ParseJson {
         "parse_json"
     }
 
+    fn is_pure(&self) -> bool {
+        true
+    }
+
     fn summary(&self) -> &'static str {
         "parse a string to a JSON type"
     }
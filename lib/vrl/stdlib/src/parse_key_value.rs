@@ -20,6 +20,10 @@ impl Function for ParseKeyValue {
         "parse_key_value"
     }
 
+    fn is_pure(&self) -> bool {
+        true
+    }
+
     fn parameters(&self) -> &'static [Parameter] {
         &[
             Parameter {
@@ -0,0 +1,119 @@
+use crate::mac_util::{self, Style};
+use vrl::prelude::*;
+
+#[derive(Clone, Copy, Debug)]
+pub struct ParseMacAddress;
+
+impl Function for ParseMacAddress {
+    fn identifier(&self) -> &'static str {
+        "parse_mac_address"
+    }
+
+    fn is_pure(&self) -> bool {
+        true
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[Parameter {
+            keyword: "value",
+            kind: kind::BYTES,
+            required: true,
+        }]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "dashes",
+                source: r#"parse_mac_address!("00-1B-63-84-45-E6")"#,
+                result: Ok("00:1b:63:84:45:e6"),
+            },
+            Example {
+                title: "cisco",
+                source: r#"parse_mac_address!("001b.6384.45e6")"#,
+                result: Ok("00:1b:63:84:45:e6"),
+            },
+        ]
+    }
+
+    fn compile(&self, mut arguments: ArgumentList) -> Compiled {
+        let value = arguments.required("value");
+
+        Ok(Box::new(ParseMacAddressFn { value }))
+    }
+}
+
+#[derive(Debug, Clone)]
+struct ParseMacAddressFn {
+    value: Box<dyn Expression>,
+}
+
+impl Expression for ParseMacAddressFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?.try_bytes()?;
+        let octets = mac_util::parse(String::from_utf8_lossy(&value).trim())?;
+
+        Ok(mac_util::format(&octets, Style::Colon).into())
+    }
+
+    fn type_def(&self, _: &state::Compiler) -> TypeDef {
+        TypeDef::new().fallible().bytes()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    test_function![
+        parse_mac_address => ParseMacAddress;
+
+        colon {
+            args: func_args![value: "00:1b:63:84:45:e6"],
+            want: Ok("00:1b:63:84:45:e6"),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+
+        uppercase_colon {
+            args: func_args![value: "00:1B:63:84:45:E6"],
+            want: Ok("00:1b:63:84:45:e6"),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+
+        dash {
+            args: func_args![value: "00-1B-63-84-45-E6"],
+            want: Ok("00:1b:63:84:45:e6"),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+
+        cisco {
+            args: func_args![value: "001b.6384.45e6"],
+            want: Ok("00:1b:63:84:45:e6"),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+
+        bare {
+            args: func_args![value: " 001B638445E6 "],
+            want: Ok("00:1b:63:84:45:e6"),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+
+        too_short {
+            args: func_args![value: "00:1b:63:84:45"],
+            want: Err(r#"invalid MAC address "00:1b:63:84:45""#),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+
+        mixed_separators {
+            args: func_args![value: "00:1b-63:84:45:e6"],
+            want: Err(r#"invalid MAC address "00:1b-63:84:45:e6""#),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+
+        not_hexadecimal {
+            args: func_args![value: "00:1b:63:84:45:g6"],
+            want: Err(r#"invalid MAC address "00:1b:63:84:45:g6""#),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+    ];
+}
@@ -0,0 +1,239 @@
+use pulldown_cmark::{CodeBlockKind, Event, Parser, Tag};
+use std::collections::BTreeMap;
+use std::iter::FromIterator;
+use vrl::prelude::*;
+
+#[derive(Clone, Copy, Debug)]
+pub struct ParseMarkdown;
+
+impl Function for ParseMarkdown {
+    fn identifier(&self) -> &'static str {
+        "parse_markdown"
+    }
+
+    fn is_pure(&self) -> bool {
+        true
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[Parameter {
+            keyword: "value",
+            kind: kind::BYTES,
+            required: true,
+        }]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[Example {
+            title: "document",
+            source: r##"parse_markdown!("# Vector\nA [fast](https://vector.dev) pipeline.")"##,
+            result: Ok(indoc! {r#"{
+                "code_blocks": [],
+                "headings": [{ "level": 1, "text": "Vector" }],
+                "links": [{ "text": "fast", "title": null, "url": "https://vector.dev" }],
+                "paragraphs": ["A fast pipeline."]
+            }"#}),
+        }]
+    }
+
+    fn compile(&self, mut arguments: ArgumentList) -> Compiled {
+        let value = arguments.required("value");
+
+        Ok(Box::new(ParseMarkdownFn { value }))
+    }
+}
+
+#[derive(Debug, Clone)]
+struct ParseMarkdownFn {
+    value: Box<dyn Expression>,
+}
+
+impl Expression for ParseMarkdownFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let bytes = self.value.resolve(ctx)?.try_bytes()?;
+        let input =
+            std::str::from_utf8(&bytes).map_err(|e| format!("unable to parse markdown: {}", e))?;
+
+        Ok(parse(input))
+    }
+
+    fn type_def(&self, _: &state::Compiler) -> TypeDef {
+        TypeDef::new().fallible().object(type_def())
+    }
+}
+
+/// Extracts the headings, paragraphs, code blocks and links from a CommonMark
+/// document.
+///
+/// Parsing is lenient, any input produces a result, even if it contains none
+/// of the supported elements.
+fn parse(input: &str) -> Value {
+    let mut headings = vec![];
+    let mut paragraphs: Vec<Value> = vec![];
+    let mut code_blocks = vec![];
+    let mut links = vec![];
+
+    // The text of the block currently being parsed, and of the link within
+    // that block, if any.
+    let mut text = String::new();
+    let mut link: Option<(String, String, String)> = None;
+
+    for event in Parser::new(input) {
+        match event {
+            Event::Start(Tag::Heading(_))
+            | Event::Start(Tag::Paragraph)
+            | Event::Start(Tag::CodeBlock(_)) => text.clear(),
+            Event::End(Tag::Heading(level)) => headings.push(object(vec![
+                ("level", (level as i64).into()),
+                ("text", std::mem::take(&mut text).into()),
+            ])),
+            Event::End(Tag::Paragraph) => paragraphs.push(std::mem::take(&mut text).into()),
+            Event::End(Tag::CodeBlock(kind)) => {
+                let language = match kind {
+                    CodeBlockKind::Fenced(info) => info
+                        .split_whitespace()
+                        .next()
+                        .map(|language| language.to_owned().into()),
+                    CodeBlockKind::Indented => None,
+                };
+
+                code_blocks.push(object(vec![
+                    ("language", language.unwrap_or(Value::Null)),
+                    ("code", std::mem::take(&mut text).into()),
+                ]));
+            }
+            Event::Start(Tag::Link(_, url, title)) => {
+                link = Some((url.to_string(), title.to_string(), String::new()))
+            }
+            Event::End(Tag::Link(..)) => {
+                if let Some((url, title, text)) = link.take() {
+                    let title = if title.is_empty() {
+                        Value::Null
+                    } else {
+                        title.into()
+                    };
+
+                    links.push(object(vec![
+                        ("text", text.into()),
+                        ("url", url.into()),
+                        ("title", title),
+                    ]));
+                }
+            }
+            Event::Text(string) | Event::Code(string) => {
+                text.push_str(&string);
+                if let Some((_, _, text)) = link.as_mut() {
+                    text.push_str(&string);
+                }
+            }
+            Event::SoftBreak => text.push(' '),
+            Event::HardBreak => text.push('\n'),
+            _ => {}
+        }
+    }
+
+    object(vec![
+        ("headings", headings.into()),
+        ("paragraphs", paragraphs.into()),
+        ("code_blocks", code_blocks.into()),
+        ("links", links.into()),
+    ])
+}
+
+fn object(fields: Vec<(&str, Value)>) -> Value {
+    Value::from_iter(fields.into_iter().map(|(k, v)| (k.to_owned(), v)))
+}
+
+fn type_def() -> BTreeMap<&'static str, TypeDef> {
+    map! {
+        "headings": TypeDef::new().array_mapped::<(), Kind>(map! {
+            (): Kind::Object,
+        }),
+        "paragraphs": TypeDef::new().array_mapped::<(), Kind>(map! {
+            (): Kind::Bytes,
+        }),
+        "code_blocks": TypeDef::new().array_mapped::<(), Kind>(map! {
+            (): Kind::Object,
+        }),
+        "links": TypeDef::new().array_mapped::<(), Kind>(map! {
+            (): Kind::Object,
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    test_function![
+        parse_markdown => ParseMarkdown;
+
+        document {
+            args: func_args![value: indoc! {r#"
+                # Vector
+
+                A [lightweight](https://vector.dev "Vector") and `fast` tool.
+
+                ## Installation
+
+                Run the [installer](https://sh.vector.dev).
+
+                ```sh
+                curl https://sh.vector.dev | sh
+                ```
+
+                ### Linux
+
+                    indented code
+            "#}],
+            want: Ok(value!({
+                "headings": [
+                    { "level": 1, "text": "Vector" },
+                    { "level": 2, "text": "Installation" },
+                    { "level": 3, "text": "Linux" },
+                ],
+                "paragraphs": [
+                    "A lightweight and fast tool.",
+                    "Run the installer.",
+                ],
+                "code_blocks": [
+                    { "language": "sh", "code": "curl https://sh.vector.dev | sh\n" },
+                    { "language": null, "code": "indented code\n" },
+                ],
+                "links": [
+                    { "text": "lightweight", "url": "https://vector.dev", "title": "Vector" },
+                    { "text": "installer", "url": "https://sh.vector.dev", "title": null },
+                ],
+            })),
+            tdef: TypeDef::new().fallible().object(type_def()),
+        }
+
+        lenient {
+            args: func_args![value: "# unterminated [link(\n```\nunterminated fence\n"],
+            want: Ok(value!({
+                "headings": [{ "level": 1, "text": "unterminated [link(" }],
+                "paragraphs": [],
+                "code_blocks": [{ "language": null, "code": "unterminated fence\n" }],
+                "links": [],
+            })),
+            tdef: TypeDef::new().fallible().object(type_def()),
+        }
+
+        empty {
+            args: func_args![value: ""],
+            want: Ok(value!({
+                "headings": [],
+                "paragraphs": [],
+                "code_blocks": [],
+                "links": [],
+            })),
+            tdef: TypeDef::new().fallible().object(type_def()),
+        }
+
+        invalid_utf8 {
+            args: func_args![value: Bytes::from_static(b"# \xff")],
+            want: Err("unable to parse markdown: invalid utf-8 sequence of 1 bytes from index 2"),
+            tdef: TypeDef::new().fallible().object(type_def()),
+        }
+    ];
+}
@@ -0,0 +1,202 @@
+use vrl::prelude::*;
+
+#[derive(Clone, Copy, Debug)]
+pub struct ParseNumberArray;
+
+impl Function for ParseNumberArray {
+    fn identifier(&self) -> &'static str {
+        "parse_number_array"
+    }
+
+    fn is_pure(&self) -> bool {
+        true
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[
+            Parameter {
+                keyword: "value",
+                kind: kind::BYTES,
+                required: true,
+            },
+            Parameter {
+                keyword: "delimiter",
+                kind: kind::BYTES,
+                required: false,
+            },
+            Parameter {
+                keyword: "skip_invalid",
+                kind: kind::BOOLEAN,
+                required: false,
+            },
+        ]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "mixed numbers",
+                source: r#"parse_number_array!("1, 2.5, -3")"#,
+                result: Ok("[1, 2.5, -3]"),
+            },
+            Example {
+                title: "custom delimiter",
+                source: r#"parse_number_array!("1;2;3", delimiter: ";")"#,
+                result: Ok("[1, 2, 3]"),
+            },
+            Example {
+                title: "skip invalid",
+                source: r#"parse_number_array!("1,n/a,3", skip_invalid: true)"#,
+                result: Ok("[1, 3]"),
+            },
+        ]
+    }
+
+    fn compile(&self, mut arguments: ArgumentList) -> Compiled {
+        let value = arguments.required("value");
+        let delimiter = arguments.optional("delimiter").unwrap_or(expr!(","));
+        let skip_invalid = arguments.optional("skip_invalid").unwrap_or(expr!(false));
+
+        Ok(Box::new(ParseNumberArrayFn {
+            value,
+            delimiter,
+            skip_invalid,
+        }))
+    }
+}
+
+#[derive(Debug, Clone)]
+struct ParseNumberArrayFn {
+    value: Box<dyn Expression>,
+    delimiter: Box<dyn Expression>,
+    skip_invalid: Box<dyn Expression>,
+}
+
+impl Expression for ParseNumberArrayFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?;
+        let value = value.try_bytes_utf8_lossy()?;
+        let delimiter = self.delimiter.resolve(ctx)?;
+        let delimiter = delimiter.try_bytes_utf8_lossy()?;
+        let skip_invalid = self.skip_invalid.resolve(ctx)?.try_boolean()?;
+
+        if delimiter.is_empty() {
+            return Err("delimiter must not be empty".into());
+        }
+
+        if value.trim().is_empty() {
+            return Ok(Value::Array(vec![]));
+        }
+
+        let mut numbers = vec![];
+        for (index, element) in value.split(delimiter.as_ref()).enumerate() {
+            match parse_number(element.trim()) {
+                Some(number) => numbers.push(number),
+                None if skip_invalid => {}
+                None => {
+                    return Err(format!(
+                        r#"invalid number "{}" at index {}"#,
+                        element.trim(),
+                        index
+                    )
+                    .into())
+                }
+            }
+        }
+
+        Ok(numbers.into())
+    }
+
+    fn type_def(&self, _: &state::Compiler) -> TypeDef {
+        TypeDef::new().fallible().array_mapped::<(), Kind>(map! {
+            (): Kind::Integer | Kind::Float,
+        })
+    }
+}
+
+/// Parses an integer if possible, so that the type of each element is
+/// preserved, or a float otherwise.
+fn parse_number(element: &str) -> Option<Value> {
+    if let Ok(integer) = element.parse::<i64>() {
+        return Some(integer.into());
+    }
+
+    element
+        .parse::<f64>()
+        .ok()
+        .filter(|float| !float.is_nan())
+        .map(Into::into)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    test_function![
+        parse_number_array => ParseNumberArray;
+
+        clean {
+            args: func_args![value: "1,2.5, -3 ,1e3"],
+            want: Ok(value!([1, 2.5, (-3), 1000.0])),
+            tdef: TypeDef::new().fallible().array_mapped::<(), Kind>(map! {
+                (): Kind::Integer | Kind::Float,
+            }),
+        }
+
+        empty {
+            args: func_args![value: ""],
+            want: Ok(value!([])),
+            tdef: TypeDef::new().fallible().array_mapped::<(), Kind>(map! {
+                (): Kind::Integer | Kind::Float,
+            }),
+        }
+
+        invalid_element {
+            args: func_args![value: "1,two,3"],
+            want: Err(r#"invalid number "two" at index 1"#),
+            tdef: TypeDef::new().fallible().array_mapped::<(), Kind>(map! {
+                (): Kind::Integer | Kind::Float,
+            }),
+        }
+
+        empty_element {
+            args: func_args![value: "1,,3"],
+            want: Err(r#"invalid number "" at index 1"#),
+            tdef: TypeDef::new().fallible().array_mapped::<(), Kind>(map! {
+                (): Kind::Integer | Kind::Float,
+            }),
+        }
+
+        skip_invalid {
+            args: func_args![value: "1,two,,3.5,NaN", skip_invalid: true],
+            want: Ok(value!([1, 3.5])),
+            tdef: TypeDef::new().fallible().array_mapped::<(), Kind>(map! {
+                (): Kind::Integer | Kind::Float,
+            }),
+        }
+
+        custom_delimiter {
+            args: func_args![value: "1 | 2 | 3.0", delimiter: "|"],
+            want: Ok(value!([1, 2, 3.0])),
+            tdef: TypeDef::new().fallible().array_mapped::<(), Kind>(map! {
+                (): Kind::Integer | Kind::Float,
+            }),
+        }
+
+        multi_character_delimiter {
+            args: func_args![value: "1::-2::3", delimiter: "::"],
+            want: Ok(value!([1, (-2), 3])),
+            tdef: TypeDef::new().fallible().array_mapped::<(), Kind>(map! {
+                (): Kind::Integer | Kind::Float,
+            }),
+        }
+
+        empty_delimiter {
+            args: func_args![value: "1,2", delimiter: ""],
+            want: Err("delimiter must not be empty"),
+            tdef: TypeDef::new().fallible().array_mapped::<(), Kind>(map! {
+                (): Kind::Integer | Kind::Float,
+            }),
+        }
+    ];
+}
@@ -0,0 +1,574 @@
+use std::collections::BTreeMap;
+use std::convert::TryFrom;
+use vrl::prelude::*;
+
+#[derive(Clone, Copy, Debug)]
+pub struct ParseParquetSchema;
+
+impl Function for ParseParquetSchema {
+    fn identifier(&self) -> &'static str {
+        "parse_parquet_schema"
+    }
+
+    fn is_pure(&self) -> bool {
+        true
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[Parameter {
+            keyword: "value",
+            kind: kind::BYTES,
+            required: true,
+        }]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[Example {
+            title: "single column",
+            source: r#"parse_parquet_schema!(decode_base64!("UEFSMRUCGSxIBnNjaGVtYRUCABUEJQAYAmlkABYAGQwAHQAAAFBBUjE="))"#,
+            result: Ok(
+                r#"{ "columns": [{ "converted_type": null, "name": "id", "repetition": "REQUIRED", "type": "INT64" }], "created_by": null, "num_rows": 0, "version": 1 }"#,
+            ),
+        }]
+    }
+
+    fn compile(&self, mut arguments: ArgumentList) -> Compiled {
+        let value = arguments.required("value");
+
+        Ok(Box::new(ParseParquetSchemaFn { value }))
+    }
+}
+
+#[derive(Debug, Clone)]
+struct ParseParquetSchemaFn {
+    value: Box<dyn Expression>,
+}
+
+impl Expression for ParseParquetSchemaFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let bytes = self.value.resolve(ctx)?.try_bytes()?;
+        let metadata =
+            parse(&bytes).map_err(|err| format!("unable to parse Parquet metadata: {}", err))?;
+
+        let columns = metadata
+            .columns
+            .into_iter()
+            .map(|column| {
+                let mut map = BTreeMap::new();
+                map.insert("name".to_owned(), column.name.into());
+                map.insert("type".to_owned(), column.kind.into());
+                map.insert("repetition".to_owned(), column.repetition.into());
+                map.insert("converted_type".to_owned(), column.converted_type.into());
+
+                Value::from(map)
+            })
+            .collect::<Vec<_>>();
+
+        let mut map = BTreeMap::new();
+        map.insert("version".to_owned(), metadata.version.into());
+        map.insert("num_rows".to_owned(), metadata.num_rows.into());
+        map.insert("created_by".to_owned(), metadata.created_by.into());
+        map.insert("columns".to_owned(), columns.into());
+
+        Ok(map.into())
+    }
+
+    fn type_def(&self, _: &state::Compiler) -> TypeDef {
+        TypeDef::new().fallible().object(type_def())
+    }
+}
+
+fn type_def() -> BTreeMap<&'static str, TypeDef> {
+    map! {
+        "version": Kind::Integer,
+        "num_rows": Kind::Integer,
+        "created_by": Kind::Bytes | Kind::Null,
+        "columns": TypeDef::new().array_mapped::<(), TypeDef>(map! {
+            (): TypeDef::new().object::<&str, Kind>(map! {
+                "name": Kind::Bytes,
+                "type": Kind::Bytes,
+                "repetition": Kind::Bytes | Kind::Null,
+                "converted_type": Kind::Bytes | Kind::Null,
+            }),
+        }),
+    }
+}
+
+/// The magic number at the start and end of Parquet files.
+const MAGIC: &[u8] = b"PAR1";
+
+/// The names of the physical types, repetitions and converted types of
+/// columns, indexed by their values in the Thrift definition of the format.
+const TYPES: &[&str] = &[
+    "BOOLEAN",
+    "INT32",
+    "INT64",
+    "INT96",
+    "FLOAT",
+    "DOUBLE",
+    "BYTE_ARRAY",
+    "FIXED_LEN_BYTE_ARRAY",
+];
+const REPETITIONS: &[&str] = &["REQUIRED", "OPTIONAL", "REPEATED"];
+const CONVERTED_TYPES: &[&str] = &[
+    "UTF8",
+    "MAP",
+    "MAP_KEY_VALUE",
+    "LIST",
+    "ENUM",
+    "DECIMAL",
+    "DATE",
+    "TIME_MILLIS",
+    "TIME_MICROS",
+    "TIMESTAMP_MILLIS",
+    "TIMESTAMP_MICROS",
+    "UINT_8",
+    "UINT_16",
+    "UINT_32",
+    "UINT_64",
+    "INT_8",
+    "INT_16",
+    "INT_32",
+    "INT_64",
+    "JSON",
+    "BSON",
+    "INTERVAL",
+];
+
+/// The types of values in the Thrift compact protocol.
+const BOOLEAN_TRUE: u8 = 1;
+const BOOLEAN_FALSE: u8 = 2;
+const BYTE: u8 = 3;
+const I16: u8 = 4;
+const I32: u8 = 5;
+const I64: u8 = 6;
+const DOUBLE: u8 = 7;
+const BINARY: u8 = 8;
+const LIST: u8 = 9;
+const SET: u8 = 10;
+const MAP: u8 = 11;
+const STRUCT: u8 = 12;
+
+/// How deeply values can be nested before the metadata is rejected.
+const MAX_DEPTH: usize = 64;
+
+struct Metadata {
+    version: i64,
+    num_rows: i64,
+    created_by: Option<String>,
+    columns: Vec<Column>,
+}
+
+struct Column {
+    name: String,
+    kind: &'static str,
+    repetition: Option<&'static str>,
+    converted_type: Option<&'static str>,
+}
+
+#[derive(Default)]
+struct SchemaElement {
+    kind: Option<i64>,
+    repetition: Option<i64>,
+    name: Option<String>,
+    num_children: Option<i64>,
+    converted_type: Option<i64>,
+}
+
+/// Parses the metadata in the footer of the Parquet file, which is encoded
+/// with the Thrift compact protocol. The row groups aren't read.
+fn parse(file: &[u8]) -> std::result::Result<Metadata, String> {
+    if file.len() < 12 || !file.starts_with(MAGIC) || !file.ends_with(MAGIC) {
+        return Err("not a Parquet file".to_owned());
+    }
+
+    let footer = &file[file.len() - 8..];
+    let len = u32::from_le_bytes([footer[0], footer[1], footer[2], footer[3]]) as usize;
+    let start = (file.len() - 8)
+        .checked_sub(len)
+        .filter(|start| *start >= MAGIC.len())
+        .ok_or("invalid metadata length")?;
+
+    let mut reader = Reader::new(&file[start..file.len() - 8]);
+    let mut version = None;
+    let mut num_rows = None;
+    let mut created_by = None;
+    let mut schema = vec![];
+
+    let mut last = 0;
+    while let Some((id, kind)) = reader.field(&mut last)? {
+        match (id, kind) {
+            (1, I32) => version = Some(reader.int()?),
+            (2, LIST) => {
+                for _ in 0..reader.list(STRUCT)? {
+                    schema.push(schema_element(&mut reader)?);
+                }
+            }
+            (3, I64) => num_rows = Some(reader.int()?),
+            (6, BINARY) => created_by = Some(reader.string()?),
+            (_, kind) => reader.skip(kind, 0)?,
+        }
+    }
+
+    Ok(Metadata {
+        version: version.ok_or("missing version")?,
+        num_rows: num_rows.ok_or("missing number of rows")?,
+        created_by,
+        columns: columns(schema)?,
+    })
+}
+
+fn schema_element(reader: &mut Reader<'_>) -> std::result::Result<SchemaElement, String> {
+    let mut element = SchemaElement::default();
+
+    let mut last = 0;
+    while let Some((id, kind)) = reader.field(&mut last)? {
+        match (id, kind) {
+            (1, I32) => element.kind = Some(reader.int()?),
+            (3, I32) => element.repetition = Some(reader.int()?),
+            (4, BINARY) => element.name = Some(reader.string()?),
+            (5, I32) => element.num_children = Some(reader.int()?),
+            (6, I32) => element.converted_type = Some(reader.int()?),
+            (_, kind) => reader.skip(kind, 0)?,
+        }
+    }
+
+    Ok(element)
+}
+
+/// Flattens the schema, a tree stored depth-first whose root is the message
+/// itself, into its leaf columns, named by their path from the root.
+fn columns(schema: Vec<SchemaElement>) -> std::result::Result<Vec<Column>, String> {
+    let mut elements = schema.into_iter();
+    let root = elements.next().ok_or("empty schema")?;
+
+    // The number of children left to read of each group being read, and the
+    // names of the groups below the root.
+    let mut remaining = vec![root.num_children.unwrap_or(0)];
+    let mut path: Vec<String> = vec![];
+    let mut columns = vec![];
+
+    for element in elements {
+        while remaining.len() > 1 && remaining.last() == Some(&0) {
+            remaining.pop();
+            path.pop();
+        }
+
+        match remaining.last_mut() {
+            Some(count) if *count > 0 => *count -= 1,
+            _ => return Err("schema has more elements than its root holds".to_owned()),
+        }
+
+        let name = element.name.ok_or("schema element without a name")?;
+        let repetition = element
+            .repetition
+            .map(|repetition| lookup(REPETITIONS, repetition, "repetition"))
+            .transpose()?;
+
+        match element.num_children {
+            Some(children) if children > 0 => {
+                remaining.push(children);
+                path.push(name);
+            }
+            _ => {
+                let kind = element
+                    .kind
+                    .ok_or_else(|| format!("column {:?} without a type", name))?;
+
+                path.push(name);
+                columns.push(Column {
+                    name: path.join("."),
+                    kind: lookup(TYPES, kind, "type")?,
+                    repetition,
+                    converted_type: element
+                        .converted_type
+                        .map(|converted_type| {
+                            lookup(CONVERTED_TYPES, converted_type, "converted type")
+                        })
+                        .transpose()?,
+                });
+                path.pop();
+            }
+        }
+    }
+
+    if remaining.iter().any(|count| *count > 0) {
+        return Err("schema has fewer elements than its groups hold".to_owned());
+    }
+
+    Ok(columns)
+}
+
+fn lookup(
+    names: &[&'static str],
+    value: i64,
+    what: &str,
+) -> std::result::Result<&'static str, String> {
+    usize::try_from(value)
+        .ok()
+        .and_then(|i| names.get(i))
+        .copied()
+        .ok_or_else(|| format!("unknown {} {}", what, value))
+}
+
+/// Reads values encoded with the Thrift compact protocol.
+struct Reader<'a> {
+    input: &'a [u8],
+    position: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn new(input: &'a [u8]) -> Self {
+        Self { input, position: 0 }
+    }
+
+    fn bytes(&mut self, len: usize) -> std::result::Result<&'a [u8], String> {
+        let bytes = self
+            .input
+            .get(self.position..self.position.saturating_add(len))
+            .ok_or("truncated metadata")?;
+        self.position += len;
+
+        Ok(bytes)
+    }
+
+    fn byte(&mut self) -> std::result::Result<u8, String> {
+        Ok(self.bytes(1)?[0])
+    }
+
+    fn varint(&mut self) -> std::result::Result<u64, String> {
+        let mut value = 0;
+        for shift in (0..64).step_by(7) {
+            let byte = self.byte()?;
+            value |= u64::from(byte & 0x7f) << shift;
+
+            if byte & 0x80 == 0 {
+                return Ok(value);
+            }
+        }
+
+        Err("invalid integer".to_owned())
+    }
+
+    /// Reads a zigzag-encoded integer, of any width.
+    fn int(&mut self) -> std::result::Result<i64, String> {
+        let value = self.varint()?;
+
+        Ok((value >> 1) as i64 ^ -((value & 1) as i64))
+    }
+
+    fn binary(&mut self) -> std::result::Result<&'a [u8], String> {
+        let len = self.varint()? as usize;
+
+        self.bytes(len)
+    }
+
+    fn string(&mut self) -> std::result::Result<String, String> {
+        Ok(String::from_utf8_lossy(self.binary()?).into_owned())
+    }
+
+    /// Reads the header of the next field of a struct, returning its id and
+    /// type, or `None` at the end of the struct. Ids are encoded as deltas
+    /// from the id of the last field.
+    fn field(&mut self, last: &mut i64) -> std::result::Result<Option<(i64, u8)>, String> {
+        let header = self.byte()?;
+        if header == 0 {
+            return Ok(None);
+        }
+
+        let delta = i64::from(header >> 4);
+        let id = if delta == 0 {
+            self.int()?
+        } else {
+            *last + delta
+        };
+        *last = id;
+
+        Ok(Some((id, header & 0x0f)))
+    }
+
+    /// Reads the header of a list, returning its length, and failing if its
+    /// elements aren't of the type.
+    fn list(&mut self, kind: u8) -> std::result::Result<usize, String> {
+        let (len, element) = self.collection()?;
+
+        if element != kind {
+            return Err(format!("expected list of type {}, got {}", kind, element));
+        }
+
+        Ok(len)
+    }
+
+    fn collection(&mut self) -> std::result::Result<(usize, u8), String> {
+        let header = self.byte()?;
+        let len = match header >> 4 {
+            15 => self.varint()? as usize,
+            len => usize::from(len),
+        };
+
+        Ok((len, header & 0x0f))
+    }
+
+    /// Skips the value of a field of the type. The values of boolean fields
+    /// are part of their header.
+    fn skip(&mut self, kind: u8, depth: usize) -> std::result::Result<(), String> {
+        if depth > MAX_DEPTH {
+            return Err("metadata nested too deeply".to_owned());
+        }
+
+        match kind {
+            BOOLEAN_TRUE | BOOLEAN_FALSE => {}
+            BYTE => {
+                self.byte()?;
+            }
+            I16 | I32 | I64 => {
+                self.varint()?;
+            }
+            DOUBLE => {
+                self.bytes(8)?;
+            }
+            BINARY => {
+                self.binary()?;
+            }
+            LIST | SET => {
+                let (len, element) = self.collection()?;
+                for _ in 0..len {
+                    self.skip_element(element, depth + 1)?;
+                }
+            }
+            MAP => {
+                let len = self.varint()? as usize;
+                if len > 0 {
+                    let kinds = self.byte()?;
+                    for _ in 0..len {
+                        self.skip_element(kinds >> 4, depth + 1)?;
+                        self.skip_element(kinds & 0x0f, depth + 1)?;
+                    }
+                }
+            }
+            STRUCT => {
+                let mut last = 0;
+                while let Some((_, kind)) = self.field(&mut last)? {
+                    self.skip(kind, depth + 1)?;
+                }
+            }
+            kind => return Err(format!("unknown type {}", kind)),
+        }
+
+        Ok(())
+    }
+
+    /// Skips an element of a collection of the type. Unlike fields, boolean
+    /// elements take a byte of their own.
+    fn skip_element(&mut self, kind: u8, depth: usize) -> std::result::Result<(), String> {
+        match kind {
+            BOOLEAN_TRUE | BOOLEAN_FALSE => self.byte().map(|_| ()),
+            kind => self.skip(kind, depth),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A Parquet file of two rows, with a required `id` column, an optional
+    /// `name` column and an `address` group with a `city` column.
+    const FILE: &[u8] = b"PAR1\x15\x00\x15\x20\x15\x20\x2c\x15\x04\x15\x00\x15\
+        \x06\x15\x06\x00\x00\x01\x00\x00\x00\x00\x00\x00\x00\x02\x00\x00\
+        \x00\x00\x00\x00\x00\x15\x00\x15\x28\x15\x28\x2c\x15\x04\x15\x00\
+        \x15\x06\x15\x06\x00\x00\x02\x00\x00\x00\x04\x01\x03\x00\x00\x00\
+        ada\x03\x00\x00\x00bob\x15\x00\x15\x2c\x15\x2c\
+        \x2c\x15\x04\x15\x00\x15\x06\x15\x06\x00\x00\x02\x00\x00\x00\x04\
+        \x02\x04\x00\x00\x00oslo\x04\x00\x00\x00rom\
+        e\x15\x02\x19\x5cH\x06schema\x15\x06\x00\
+        \x15\x04\x25\x00\x18\x02id\x00\x15\x0c\x25\x02\x18\x04n\
+        ame\x25\x00\x005\x02\x18\x07addres\
+        s\x15\x02\x00\x15\x0c\x25\x02\x18\x04city\x25\x00\
+        \x00\x16\x04\x19\x1c\x19\x3c\x26\x08\x1c\x15\x04\x19\x25\x00\x06\
+        \x19\x18\x02id\x15\x00\x16\x04\x16B\x16B\x26\x08\x00\
+        \x00\x26J\x1c\x15\x0c\x19\x25\x00\x06\x19\x18\x04nam\
+        e\x15\x00\x16\x04\x16J\x16J\x26J\x00\x00\x26\x94\x01\
+        \x1c\x15\x0c\x19\x25\x00\x06\x19\x28\x07addres\
+        s\x04city\x15\x00\x16\x04\x16N\x16N\x26\x94\
+        \x01\x00\x00\x16\xda\x01\x16\x04\x00\x19\x1c\x18\x06wri\
+        ter\x18\x06vector\x00\x18\x10vr\
+        l\x20stdlib\x20tests\x00\xce\
+        \x00\x00\x00PAR1";
+
+    /// The footer of `FILE`, starting at its metadata.
+    const FOOTER: usize = 113;
+
+    fn with_footer(metadata: &[u8]) -> Bytes {
+        let mut file = MAGIC.to_vec();
+        file.extend_from_slice(metadata);
+        file.extend_from_slice(&(metadata.len() as u32).to_le_bytes());
+        file.extend_from_slice(MAGIC);
+
+        file.into()
+    }
+
+    test_function![
+        parse_parquet_schema => ParseParquetSchema;
+
+        file {
+            args: func_args![value: Bytes::from_static(FILE)],
+            want: Ok(value!({
+                "version": 1,
+                "num_rows": 2,
+                "created_by": "vrl stdlib tests",
+                "columns": [
+                    { "name": "id", "type": "INT64", "repetition": "REQUIRED", "converted_type": null },
+                    { "name": "name", "type": "BYTE_ARRAY", "repetition": "OPTIONAL", "converted_type": "UTF8" },
+                    { "name": "address.city", "type": "BYTE_ARRAY", "repetition": "OPTIONAL", "converted_type": "UTF8" },
+                ],
+            })),
+            tdef: TypeDef::new().fallible().object(type_def()),
+        }
+
+        metadata_only {
+            args: func_args![value: with_footer(&FILE[FOOTER..FILE.len() - 8])],
+            want: Ok(value!({
+                "version": 1,
+                "num_rows": 2,
+                "created_by": "vrl stdlib tests",
+                "columns": [
+                    { "name": "id", "type": "INT64", "repetition": "REQUIRED", "converted_type": null },
+                    { "name": "name", "type": "BYTE_ARRAY", "repetition": "OPTIONAL", "converted_type": "UTF8" },
+                    { "name": "address.city", "type": "BYTE_ARRAY", "repetition": "OPTIONAL", "converted_type": "UTF8" },
+                ],
+            })),
+            tdef: TypeDef::new().fallible().object(type_def()),
+        }
+
+        not_parquet {
+            args: func_args![value: "hello world"],
+            want: Err("unable to parse Parquet metadata: not a Parquet file"),
+            tdef: TypeDef::new().fallible().object(type_def()),
+        }
+
+        truncated_file {
+            args: func_args![value: Bytes::from_static(&FILE[..FILE.len() - 1])],
+            want: Err("unable to parse Parquet metadata: not a Parquet file"),
+            tdef: TypeDef::new().fallible().object(type_def()),
+        }
+
+        invalid_metadata_length {
+            args: func_args![value: Bytes::from_static(b"PAR1\xff\xff\x00\x00PAR1")],
+            want: Err("unable to parse Parquet metadata: invalid metadata length"),
+            tdef: TypeDef::new().fallible().object(type_def()),
+        }
+
+        truncated_metadata {
+            args: func_args![value: with_footer(&FILE[FOOTER..FILE.len() - 20])],
+            want: Err("unable to parse Parquet metadata: truncated metadata"),
+            tdef: TypeDef::new().fallible().object(type_def()),
+        }
+
+        nested_too_deeply {
+            args: func_args![value: with_footer(&[&[0x1c; 100][..], &[0]].concat())],
+            want: Err("unable to parse Parquet metadata: metadata nested too deeply"),
+            tdef: TypeDef::new().fallible().object(type_def()),
+        }
+    ];
+}
@@ -0,0 +1,368 @@
+use std::collections::BTreeMap;
+use vrl::prelude::*;
+
+#[derive(Clone, Copy, Debug)]
+pub struct ParsePhpSerialized;
+
+impl Function for ParsePhpSerialized {
+    fn identifier(&self) -> &'static str {
+        "parse_php_serialized"
+    }
+
+    fn is_pure(&self) -> bool {
+        true
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[Parameter {
+            keyword: "value",
+            kind: kind::BYTES,
+            required: true,
+        }]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "associative array",
+                source: r#"parse_php_serialized!(s'a:2:{s:4:"name";s:6:"vector";s:5:"stars";i:9001;}')"#,
+                result: Ok(r#"{ "name": "vector", "stars": 9001 }"#),
+            },
+            Example {
+                title: "list",
+                source: r#"parse_php_serialized!(s'a:2:{i:0;b:1;i:1;N;}')"#,
+                result: Ok("[true, null]"),
+            },
+        ]
+    }
+
+    fn compile(&self, mut arguments: ArgumentList) -> Compiled {
+        let value = arguments.required("value");
+
+        Ok(Box::new(ParsePhpSerializedFn { value }))
+    }
+}
+
+#[derive(Debug, Clone)]
+struct ParsePhpSerializedFn {
+    value: Box<dyn Expression>,
+}
+
+impl Expression for ParsePhpSerializedFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let bytes = self.value.resolve(ctx)?.try_bytes()?;
+
+        parse(&bytes).map_err(|e| format!("unable to parse PHP serialized value: {}", e).into())
+    }
+
+    fn type_def(&self, _: &state::Compiler) -> TypeDef {
+        TypeDef::new().fallible().unknown()
+    }
+}
+
+/// How deeply arrays and objects can be nested in the parsed value.
+const MAX_DEPTH: usize = 128;
+
+fn parse(input: &[u8]) -> std::result::Result<Value, String> {
+    let mut parser = Parser {
+        input,
+        pos: 0,
+        depth: 0,
+    };
+    let value = parser.parse_value()?;
+
+    if parser.pos != input.len() {
+        return Err(parser.error("unexpected trailing characters"));
+    }
+
+    Ok(value)
+}
+
+/// A parser for the format produced by PHP's `serialize` function.
+///
+/// String lengths in this format are counted in bytes, which is why the
+/// parser operates on raw bytes rather than on characters.
+struct Parser<'a> {
+    input: &'a [u8],
+    pos: usize,
+    depth: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn error(&self, message: &str) -> String {
+        format!("{} at position {}", message, self.pos)
+    }
+
+    fn expect(&mut self, byte: u8) -> std::result::Result<(), String> {
+        if self.input.get(self.pos) == Some(&byte) {
+            self.pos += 1;
+            Ok(())
+        } else {
+            Err(self.error(&format!(r#"expected "{}""#, byte as char)))
+        }
+    }
+
+    /// Returns everything up to the next `delimiter`, and advances past it.
+    fn take_until(&mut self, delimiter: u8) -> std::result::Result<&'a str, String> {
+        let start = self.pos;
+        let len = self.input[start..]
+            .iter()
+            .position(|&b| b == delimiter)
+            .ok_or_else(|| self.error(&format!(r#"expected "{}""#, delimiter as char)))?;
+
+        self.pos += len + 1;
+
+        std::str::from_utf8(&self.input[start..start + len])
+            .map_err(|_| format!("invalid UTF-8 at position {}", start))
+    }
+
+    /// Parses the `:<value>;` part of booleans, integers and floats.
+    fn take_scalar(&mut self) -> std::result::Result<&'a str, String> {
+        self.expect(b':')?;
+        self.take_until(b';')
+    }
+
+    fn take_length(&mut self) -> std::result::Result<usize, String> {
+        let start = self.pos;
+        let length = self.take_until(b':')?;
+
+        length
+            .parse()
+            .map_err(|_| format!(r#"invalid length "{}" at position {}"#, length, start))
+    }
+
+    fn parse_value(&mut self) -> std::result::Result<Value, String> {
+        let start = self.pos;
+        let kind = *self
+            .input
+            .get(self.pos)
+            .ok_or_else(|| self.error("unexpected end of input"))?;
+        self.pos += 1;
+
+        match kind {
+            b'N' => {
+                self.expect(b';')?;
+                Ok(Value::Null)
+            }
+            b'b' => match self.take_scalar()? {
+                "0" => Ok(false.into()),
+                "1" => Ok(true.into()),
+                v => Err(format!(r#"invalid boolean "{}" at position {}"#, v, start)),
+            },
+            b'i' => {
+                let v = self.take_scalar()?;
+                v.parse::<i64>()
+                    .map(Into::into)
+                    .map_err(|_| format!(r#"invalid integer "{}" at position {}"#, v, start))
+            }
+            b'd' => {
+                let v = self.take_scalar()?;
+                match v {
+                    "INF" => Ok(f64::INFINITY.into()),
+                    "-INF" => Ok(f64::NEG_INFINITY.into()),
+                    _ => v
+                        .parse::<f64>()
+                        .ok()
+                        .filter(|v| !v.is_nan())
+                        .map(Into::into)
+                        .ok_or_else(|| format!(r#"invalid float "{}" at position {}"#, v, start)),
+                }
+            }
+            b's' => {
+                self.expect(b':')?;
+                let string = self.parse_string()?;
+                self.expect(b';')?;
+                Ok(string.into())
+            }
+            b'a' => {
+                self.expect(b':')?;
+                self.parse_array()
+            }
+            b'O' => {
+                // The class name is dropped, only the properties are kept.
+                self.expect(b':')?;
+                self.parse_string()?;
+                self.expect(b':')?;
+                self.parse_entries().map(|entries| {
+                    entries
+                        .into_iter()
+                        .map(|(key, value)| (property_name(&key).to_owned(), value))
+                        .collect::<BTreeMap<_, _>>()
+                        .into()
+                })
+            }
+            _ => Err(format!(
+                r#"unsupported type "{}" at position {}"#,
+                kind as char, start
+            )),
+        }
+    }
+
+    /// Parses a `<length>:"<bytes>"` string, without the trailing delimiter.
+    fn parse_string(&mut self) -> std::result::Result<Bytes, String> {
+        let length = self.take_length()?;
+        self.expect(b'"')?;
+
+        let start = self.pos;
+        let end = start + length;
+        if end > self.input.len() {
+            return Err(self.error("unexpected end of input"));
+        }
+        self.pos = end;
+        self.expect(b'"')?;
+
+        Ok(Bytes::copy_from_slice(&self.input[start..end]))
+    }
+
+    /// Parses an array, returning a list if its keys are the sequential
+    /// integers starting at zero, or an object otherwise.
+    fn parse_array(&mut self) -> std::result::Result<Value, String> {
+        let entries = self.parse_entries()?;
+
+        let is_list = entries
+            .iter()
+            .enumerate()
+            .all(|(i, (key, _))| key == &i.to_string());
+
+        if is_list {
+            Ok(entries
+                .into_iter()
+                .map(|(_, value)| value)
+                .collect::<Vec<_>>()
+                .into())
+        } else {
+            Ok(entries.into_iter().collect::<BTreeMap<_, _>>().into())
+        }
+    }
+
+    /// Parses the `<count>:{<key><value>...}` part of arrays and objects.
+    fn parse_entries(&mut self) -> std::result::Result<Vec<(String, Value)>, String> {
+        let count = self.take_length()?;
+        self.expect(b'{')?;
+
+        self.depth += 1;
+        if self.depth > MAX_DEPTH {
+            return Err(self.error("value nested too deeply"));
+        }
+
+        let mut entries = Vec::with_capacity(count.min(1024));
+        for _ in 0..count {
+            let start = self.pos;
+            let key = match self.parse_value()? {
+                Value::Integer(key) => key.to_string(),
+                Value::Bytes(key) => String::from_utf8_lossy(&key).into_owned(),
+                _ => return Err(format!("invalid array key at position {}", start)),
+            };
+
+            entries.push((key, self.parse_value()?));
+        }
+
+        self.expect(b'}')?;
+        self.depth -= 1;
+
+        Ok(entries)
+    }
+}
+
+/// Strips the `\0Class\0` and `\0*\0` prefixes PHP adds to the names of
+/// private and protected properties.
+fn property_name(key: &str) -> &str {
+    key.rsplit('\0').next().unwrap_or(key)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_scalars() {
+        assert_eq!(parse(b"N;"), Ok(Value::Null));
+        assert_eq!(parse(b"b:1;"), Ok(true.into()));
+        assert_eq!(parse(b"i:-12;"), Ok((-12).into()));
+        assert_eq!(parse(b"d:0.5;"), Ok(0.5.into()));
+        assert_eq!(parse(br#"s:4:"a";b";"#), Ok(r#"a";b"#.into()));
+    }
+
+    #[test]
+    fn test_parse_multibyte_string() {
+        assert_eq!(
+            parse("s:6:\"h\u{e9}h\u{e9}\";".as_bytes()),
+            Ok("h\u{e9}h\u{e9}".into())
+        );
+    }
+
+    #[test]
+    fn test_parse_object() {
+        assert_eq!(
+            parse(b"O:4:\"User\":3:{s:4:\"name\";s:5:\"alice\";s:8:\"\0User\0id\";i:7;s:7:\"\0*\0role\";s:5:\"admin\";}"),
+            Ok(value!({ "name": "alice", "id": 7, "role": "admin" }))
+        );
+    }
+
+    #[test]
+    fn test_parse_deeply_nested() {
+        let nested = "a:1:{i:0;".repeat(MAX_DEPTH) + "N;" + &"}".repeat(MAX_DEPTH);
+        assert!(parse(nested.as_bytes()).is_ok());
+
+        let too_deep = "a:1:{i:0;".repeat(5000);
+        assert_eq!(
+            parse(too_deep.as_bytes()),
+            Err(format!(
+                "value nested too deeply at position {}",
+                9 * MAX_DEPTH + 5
+            ))
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            parse(br#"s:10:"short";"#),
+            Err("unexpected end of input at position 6".to_owned())
+        );
+        assert_eq!(
+            parse(b"i:abc;"),
+            Err(r#"invalid integer "abc" at position 0"#.to_owned())
+        );
+        assert_eq!(
+            parse(b"a:2:{i:0;i:1;}"),
+            Err(r#"unsupported type "}" at position 13"#.to_owned())
+        );
+        assert_eq!(
+            parse(b"x:1;"),
+            Err(r#"unsupported type "x" at position 0"#.to_owned())
+        );
+        assert_eq!(
+            parse(b"N;N;"),
+            Err("unexpected trailing characters at position 2".to_owned())
+        );
+    }
+
+    test_function![
+        parse_php_serialized => ParsePhpSerialized;
+
+        associative_array {
+            args: func_args![value: r#"a:5:{s:4:"name";s:6:"vector";s:5:"stars";i:9001;s:5:"ratio";d:0.75;s:6:"active";b:1;s:4:"tags";a:2:{i:0;s:4:"fast";i:1;N;}}"#],
+            want: Ok(value!({
+                "name": "vector",
+                "stars": 9001,
+                "ratio": 0.75,
+                "active": true,
+                "tags": ["fast", null],
+            })),
+            tdef: TypeDef::new().fallible().unknown(),
+        }
+
+        sparse_array {
+            args: func_args![value: "a:2:{i:0;s:1:\"a\";i:2;s:1:\"b\";}"],
+            want: Ok(value!({ "0": "a", "2": "b" })),
+            tdef: TypeDef::new().fallible().unknown(),
+        }
+
+        malformed {
+            args: func_args![value: r#"a:1:{s:3:"foo";}"#],
+            want: Err(r#"unable to parse PHP serialized value: unsupported type "}" at position 15"#),
+            tdef: TypeDef::new().fallible().unknown(),
+        }
+    ];
+}
@@ -0,0 +1,525 @@
+use chrono::{TimeZone, Utc};
+use std::collections::BTreeMap;
+use vrl::prelude::*;
+
+/// The metric types of the `# TYPE` comments.
+const TYPES: &[&str] = &["counter", "gauge", "histogram", "summary", "untyped"];
+
+#[derive(Clone, Copy, Debug)]
+pub struct ParsePrometheus;
+
+impl Function for ParsePrometheus {
+    fn identifier(&self) -> &'static str {
+        "parse_prometheus"
+    }
+
+    fn is_pure(&self) -> bool {
+        true
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[Parameter {
+            keyword: "value",
+            kind: kind::BYTES,
+            required: true,
+        }]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "counter",
+                source: r##"parse_prometheus!("# HELP http_requests_total The number of requests.\n# TYPE http_requests_total counter\nhttp_requests_total{code=\"200\"} 1027 1395066363000")"##,
+                result: Ok(
+                    r#"[{ "help": "The number of requests.", "labels": { "code": "200" }, "name": "http_requests_total", "timestamp": t'2014-03-17T14:26:03Z', "type": "counter", "value": 1027.0 }]"#,
+                ),
+            },
+            Example {
+                title: "without metadata",
+                source: r#"parse_prometheus!("temperature 21.5")"#,
+                result: Ok(
+                    r#"[{ "help": null, "labels": {}, "name": "temperature", "timestamp": null, "type": "untyped", "value": 21.5 }]"#,
+                ),
+            },
+        ]
+    }
+
+    fn compile(&self, mut arguments: ArgumentList) -> Compiled {
+        let value = arguments.required("value");
+
+        Ok(Box::new(ParsePrometheusFn { value }))
+    }
+}
+
+#[derive(Debug, Clone)]
+struct ParsePrometheusFn {
+    value: Box<dyn Expression>,
+}
+
+impl Expression for ParsePrometheusFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let bytes = self.value.resolve(ctx)?.try_bytes()?;
+        let text = String::from_utf8_lossy(&bytes);
+
+        Ok(parse_text(&text)
+            .map_err(|err| format!("unable to parse prometheus metrics: {}", err))?)
+    }
+
+    fn type_def(&self, _: &state::Compiler) -> TypeDef {
+        TypeDef::new()
+            .fallible()
+            .array_mapped::<(), TypeDef>(map! { (): TypeDef::new().object(type_def()) })
+    }
+}
+
+fn type_def() -> BTreeMap<&'static str, TypeDef> {
+    map! {
+        "name": Kind::Bytes,
+        "labels": TypeDef::new().object::<(), Kind>(map! {
+            (): Kind::Bytes,
+        }),
+        "value": Kind::Float | Kind::Null,
+        "timestamp": Kind::Timestamp | Kind::Null,
+        "type": Kind::Bytes,
+        "help": Kind::Bytes | Kind::Null,
+    }
+}
+
+/// The `# TYPE` and `# HELP` metadata of a metric family.
+#[derive(Default)]
+struct Metadata {
+    kind: Option<String>,
+    help: Option<String>,
+}
+
+fn parse_text(text: &str) -> std::result::Result<Value, String> {
+    let lines = text
+        .lines()
+        .map(str::trim)
+        .enumerate()
+        .filter(|(_, line)| !line.is_empty());
+
+    // The metadata applies to all samples of its family, wherever it appears
+    // in the text, so it's collected before the samples are parsed.
+    let mut metadata = BTreeMap::<&str, Metadata>::new();
+    let mut samples = vec![];
+
+    for (i, line) in lines {
+        let comment = match line.strip_prefix('#') {
+            Some(comment) => comment,
+            None => {
+                samples.push((i, line));
+                continue;
+            }
+        };
+
+        let mut parts = comment.trim_start().splitn(3, |c| c == ' ' || c == '\t');
+        let (keyword, name) = match (parts.next(), parts.next()) {
+            (Some(keyword), Some(name)) if !name.is_empty() => (keyword, name),
+            _ => continue,
+        };
+        let rest = parts.next().unwrap_or("").trim();
+
+        match keyword {
+            "TYPE" if TYPES.contains(&rest) => {
+                metadata.entry(name).or_default().kind = Some(rest.to_owned());
+            }
+            "TYPE" => {
+                return Err(format!(
+                    "line {}: invalid type {:?} of metric {:?}",
+                    i + 1,
+                    rest,
+                    name
+                ))
+            }
+            "HELP" => metadata.entry(name).or_default().help = Some(unescape_help(rest)),
+            _ => {}
+        }
+    }
+
+    samples
+        .into_iter()
+        .map(|(i, line)| {
+            let sample = parse_sample(line).map_err(|err| format!("line {}: {}", i + 1, err))?;
+            let (kind, help) = match family(&metadata, &sample.name) {
+                Some(Metadata { kind, help }) => (kind.clone(), help.clone()),
+                None => (None, None),
+            };
+
+            let mut value = BTreeMap::new();
+            value.insert("name".to_owned(), Value::from(sample.name));
+            value.insert("labels".to_owned(), Value::from(sample.labels));
+            value.insert("value".to_owned(), sample.value);
+            value.insert("timestamp".to_owned(), sample.timestamp);
+            value.insert(
+                "type".to_owned(),
+                kind.unwrap_or_else(|| "untyped".to_owned()).into(),
+            );
+            value.insert("help".to_owned(), help.into());
+
+            Ok(value.into())
+        })
+        .collect::<std::result::Result<Vec<Value>, String>>()
+        .map(Into::into)
+}
+
+/// Returns the metadata of the family of the sample: the metric of the same
+/// name or, for the `_bucket`, `_sum` and `_count` samples of histograms and
+/// summaries, the metric without the suffix.
+fn family<'a>(metadata: &'a BTreeMap<&str, Metadata>, name: &str) -> Option<&'a Metadata> {
+    if let Some(metadata) = metadata.get(name) {
+        return Some(metadata);
+    }
+
+    let (family, types) = if let Some(family) = name.strip_suffix("_bucket") {
+        (family, &["histogram"][..])
+    } else if let Some(family) = name
+        .strip_suffix("_sum")
+        .or_else(|| name.strip_suffix("_count"))
+    {
+        (family, &["histogram", "summary"][..])
+    } else {
+        return None;
+    };
+
+    metadata.get(family).filter(|metadata| {
+        metadata
+            .kind
+            .as_deref()
+            .map_or(false, |kind| types.contains(&kind))
+    })
+}
+
+fn unescape_help(help: &str) -> String {
+    let mut unescaped = String::with_capacity(help.len());
+    let mut chars = help.chars();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => match chars.next() {
+                Some('n') => unescaped.push('\n'),
+                Some('\\') => unescaped.push('\\'),
+                Some(c) => {
+                    unescaped.push('\\');
+                    unescaped.push(c);
+                }
+                None => unescaped.push('\\'),
+            },
+            c => unescaped.push(c),
+        }
+    }
+
+    unescaped
+}
+
+struct Sample {
+    name: String,
+    labels: BTreeMap<String, Value>,
+    value: Value,
+    timestamp: Value,
+}
+
+/// Parses a sample, such as `name{label="value"} 1 1395066363000`.
+fn parse_sample(line: &str) -> std::result::Result<Sample, String> {
+    let name_end = line
+        .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_' || c == ':'))
+        .unwrap_or_else(|| line.len());
+    let (name, rest) = line.split_at(name_end);
+    if name.is_empty() || name.starts_with(|c: char| c.is_ascii_digit()) {
+        return Err(format!("invalid metric name in {:?}", line));
+    }
+
+    let mut rest = rest.trim_start();
+    let mut labels = BTreeMap::new();
+    if let Some(after) = rest.strip_prefix('{') {
+        rest = parse_labels(after, &mut labels)?;
+    }
+
+    let mut parts = rest.split_whitespace();
+    let value = match parts.next() {
+        Some(value) => {
+            parse_value(value).ok_or_else(|| format!("invalid value {:?} of {:?}", value, name))?
+        }
+        None => return Err(format!("missing value of {:?}", name)),
+    };
+
+    let timestamp = match parts.next() {
+        Some(timestamp) => parse_timestamp(timestamp)
+            .ok_or_else(|| format!("invalid timestamp {:?} of {:?}", timestamp, name))?,
+        None => Value::Null,
+    };
+
+    if let Some(part) = parts.next() {
+        return Err(format!("unexpected {:?}", part));
+    }
+
+    Ok(Sample {
+        name: name.to_owned(),
+        labels,
+        value,
+        timestamp,
+    })
+}
+
+/// Parses the labels after the opening brace, returning what follows the
+/// closing one.
+fn parse_labels<'a>(
+    mut input: &'a str,
+    labels: &mut BTreeMap<String, Value>,
+) -> std::result::Result<&'a str, String> {
+    loop {
+        input = input.trim_start();
+        if let Some(rest) = input.strip_prefix('}') {
+            return Ok(rest);
+        }
+
+        let name_end = input
+            .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+            .unwrap_or_else(|| input.len());
+        let (name, rest) = input.split_at(name_end);
+        if name.is_empty() {
+            return Err(format!("invalid label in {:?}", input));
+        }
+
+        let rest = rest.trim_start();
+        let rest = match rest.strip_prefix('=') {
+            Some(rest) => rest.trim_start(),
+            None => return Err(format!("missing value of label {:?}", name)),
+        };
+        let rest = match rest.strip_prefix('"') {
+            Some(rest) => rest,
+            None => return Err(format!("unquoted value of label {:?}", name)),
+        };
+
+        let (value, rest) =
+            label_value(rest).ok_or_else(|| format!("unterminated value of label {:?}", name))?;
+        labels.insert(name.to_owned(), value.into());
+
+        input = rest.trim_start();
+        match input.strip_prefix(',') {
+            Some(rest) => input = rest,
+            None if input.starts_with('}') => {}
+            None => return Err(format!("expected \",\" or \"}}\" after label {:?}", name)),
+        }
+    }
+}
+
+/// Reads a label value after its opening quote, returning the unescaped value
+/// and what follows the closing quote.
+fn label_value(input: &str) -> Option<(String, &str)> {
+    let mut value = String::new();
+    let mut chars = input.char_indices();
+
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '"' => return Some((value, &input[i + 1..])),
+            '\\' => match chars.next() {
+                Some((_, 'n')) => value.push('\n'),
+                Some((_, '"')) => value.push('"'),
+                Some((_, '\\')) => value.push('\\'),
+                Some((_, c)) => {
+                    value.push('\\');
+                    value.push(c);
+                }
+                None => return None,
+            },
+            c => value.push(c),
+        }
+    }
+
+    None
+}
+
+/// Parses a sample value, which is a float, `+Inf`, `-Inf` or `NaN`. `NaN`
+/// isn't a valid float, and is `null` instead.
+fn parse_value(value: &str) -> Option<Value> {
+    let float = match value {
+        "+Inf" | "Inf" => f64::INFINITY,
+        "-Inf" => f64::NEG_INFINITY,
+        "NaN" => return Some(Value::Null),
+        value => value
+            .parse::<f64>()
+            .ok()
+            .filter(|float| float.is_finite())?,
+    };
+
+    Some(float.into())
+}
+
+/// Parses a timestamp in milliseconds since the Unix epoch.
+fn parse_timestamp(timestamp: &str) -> Option<Value> {
+    let millis = timestamp.parse::<i64>().ok()?;
+
+    Utc.timestamp_opt(
+        millis.div_euclid(1000),
+        millis.rem_euclid(1000) as u32 * 1_000_000,
+    )
+    .single()
+    .map(Into::into)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    test_function![
+        parse_prometheus => ParsePrometheus;
+
+        counter_with_labels {
+            args: func_args![value: indoc! {r#"
+                # HELP http_requests_total The total number of HTTP requests.
+                # TYPE http_requests_total counter
+                http_requests_total{method="post",code="200"} 1027 1395066363000
+                http_requests_total{method="post", code="400", } 3 1395066363000
+            "#}],
+            want: Ok(value!([
+                {
+                    "name": "http_requests_total",
+                    "labels": { "method": "post", "code": "200" },
+                    "value": 1027.0,
+                    "timestamp": (Utc.timestamp(1_395_066_363, 0)),
+                    "type": "counter",
+                    "help": "The total number of HTTP requests.",
+                },
+                {
+                    "name": "http_requests_total",
+                    "labels": { "method": "post", "code": "400" },
+                    "value": 3.0,
+                    "timestamp": (Utc.timestamp(1_395_066_363, 0)),
+                    "type": "counter",
+                    "help": "The total number of HTTP requests.",
+                },
+            ])),
+            tdef: TypeDef::new().fallible().array_mapped::<(), TypeDef>(map! { (): TypeDef::new().object(type_def()) }),
+        }
+
+        histogram_with_buckets {
+            args: func_args![value: indoc! {r#"
+                # HELP request_duration_seconds The request latencies.
+                # TYPE request_duration_seconds histogram
+                request_duration_seconds_bucket{le="0.5"} 24054
+                request_duration_seconds_bucket{le="+Inf"} 144320
+                request_duration_seconds_sum 53423
+                request_duration_seconds_count 144320
+            "#}],
+            want: Ok(value!([
+                {
+                    "name": "request_duration_seconds_bucket",
+                    "labels": { "le": "0.5" },
+                    "value": 24054.0,
+                    "timestamp": null,
+                    "type": "histogram",
+                    "help": "The request latencies.",
+                },
+                {
+                    "name": "request_duration_seconds_bucket",
+                    "labels": { "le": "+Inf" },
+                    "value": 144320.0,
+                    "timestamp": null,
+                    "type": "histogram",
+                    "help": "The request latencies.",
+                },
+                {
+                    "name": "request_duration_seconds_sum",
+                    "labels": {},
+                    "value": 53423.0,
+                    "timestamp": null,
+                    "type": "histogram",
+                    "help": "The request latencies.",
+                },
+                {
+                    "name": "request_duration_seconds_count",
+                    "labels": {},
+                    "value": 144320.0,
+                    "timestamp": null,
+                    "type": "histogram",
+                    "help": "The request latencies.",
+                },
+            ])),
+            tdef: TypeDef::new().fallible().array_mapped::<(), TypeDef>(map! { (): TypeDef::new().object(type_def()) }),
+        }
+
+        without_metadata {
+            args: func_args![value: indoc! {r#"
+                # A comment.
+                temperature{room="kitchen"} 21.5
+                uptime_count 3
+            "#}],
+            want: Ok(value!([
+                {
+                    "name": "temperature",
+                    "labels": { "room": "kitchen" },
+                    "value": 21.5,
+                    "timestamp": null,
+                    "type": "untyped",
+                    "help": null,
+                },
+                {
+                    "name": "uptime_count",
+                    "labels": {},
+                    "value": 3.0,
+                    "timestamp": null,
+                    "type": "untyped",
+                    "help": null,
+                },
+            ])),
+            tdef: TypeDef::new().fallible().array_mapped::<(), TypeDef>(map! { (): TypeDef::new().object(type_def()) }),
+        }
+
+        escaping {
+            args: func_args![value: indoc! {r#"
+                # HELP msdos_file_access_time_seconds Access time\nof \\ files.
+                msdos_file_access_time_seconds{path="C:\\DIR\\FILE.TXT",error="Cannot find file:\n\"FILE.TXT\""} 1.458255915e9
+            "#}],
+            want: Ok(value!([{
+                "name": "msdos_file_access_time_seconds",
+                "labels": { "path": r#"C:\DIR\FILE.TXT"#, "error": "Cannot find file:\n\"FILE.TXT\"" },
+                "value": 1_458_255_915.0,
+                "timestamp": null,
+                "type": "untyped",
+                "help": "Access time\nof \\ files.",
+            }])),
+            tdef: TypeDef::new().fallible().array_mapped::<(), TypeDef>(map! { (): TypeDef::new().object(type_def()) }),
+        }
+
+        special_values {
+            args: func_args![value: "a -Inf\nb NaN\nc 1e-3 -1"],
+            want: Ok(value!([
+                { "name": "a", "labels": {}, "value": (f64::NEG_INFINITY), "timestamp": null, "type": "untyped", "help": null },
+                { "name": "b", "labels": {}, "value": null, "timestamp": null, "type": "untyped", "help": null },
+                { "name": "c", "labels": {}, "value": 0.001, "timestamp": (Utc.timestamp_millis(-1)), "type": "untyped", "help": null },
+            ])),
+            tdef: TypeDef::new().fallible().array_mapped::<(), TypeDef>(map! { (): TypeDef::new().object(type_def()) }),
+        }
+
+        empty {
+            args: func_args![value: "\n# TYPE unused gauge\n"],
+            want: Ok(value!([])),
+            tdef: TypeDef::new().fallible().array_mapped::<(), TypeDef>(map! { (): TypeDef::new().object(type_def()) }),
+        }
+
+        invalid_type {
+            args: func_args![value: "# TYPE foo meter\nfoo 1"],
+            want: Err(r#"unable to parse prometheus metrics: line 1: invalid type "meter" of metric "foo""#),
+            tdef: TypeDef::new().fallible().array_mapped::<(), TypeDef>(map! { (): TypeDef::new().object(type_def()) }),
+        }
+
+        invalid_value {
+            args: func_args![value: "foo 1\nbar high"],
+            want: Err(r#"unable to parse prometheus metrics: line 2: invalid value "high" of "bar""#),
+            tdef: TypeDef::new().fallible().array_mapped::<(), TypeDef>(map! { (): TypeDef::new().object(type_def()) }),
+        }
+
+        unterminated_label {
+            args: func_args![value: r#"foo{a="b} 1"#],
+            want: Err(r#"unable to parse prometheus metrics: line 1: unterminated value of label "a""#),
+            tdef: TypeDef::new().fallible().array_mapped::<(), TypeDef>(map! { (): TypeDef::new().object(type_def()) }),
+        }
+
+        missing_value {
+            args: func_args![value: r#"foo{a="b"}"#],
+            want: Err(r#"unable to parse prometheus metrics: line 1: missing value of "foo""#),
+            tdef: TypeDef::new().fallible().array_mapped::<(), TypeDef>(map! { (): TypeDef::new().object(type_def()) }),
+        }
+    ];
+}
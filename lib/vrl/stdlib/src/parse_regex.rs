@@ -11,6 +11,10 @@ impl Function for ParseRegex {
         "parse_regex"
     }
 
+    fn is_pure(&self) -> bool {
+        true
+    }
+
     fn parameters(&self) -> &'static [Parameter] {
         &[
             Parameter {
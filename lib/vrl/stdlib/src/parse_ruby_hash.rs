@@ -0,0 +1,501 @@
+use std::collections::BTreeMap;
+use vrl::prelude::*;
+
+#[derive(Clone, Copy, Debug)]
+pub struct ParseRubyHash;
+
+impl Function for ParseRubyHash {
+    fn identifier(&self) -> &'static str {
+        "parse_ruby_hash"
+    }
+
+    fn is_pure(&self) -> bool {
+        true
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[Parameter {
+            keyword: "value",
+            kind: kind::BYTES,
+            required: true,
+        }]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "symbol keys",
+                source: r#"parse_ruby_hash!(s'{:user=>"alice", :roles=>[:admin, :dev], :age=>42}')"#,
+                result: Ok(r#"{ "age": 42, "roles": ["admin", "dev"], "user": "alice" }"#),
+            },
+            Example {
+                title: "shorthand keys",
+                source: r#"parse_ruby_hash!(s'{status: "ok", retry: nil}')"#,
+                result: Ok(r#"{ "retry": null, "status": "ok" }"#),
+            },
+        ]
+    }
+
+    fn compile(&self, mut arguments: ArgumentList) -> Compiled {
+        let value = arguments.required("value");
+
+        Ok(Box::new(ParseRubyHashFn { value }))
+    }
+}
+
+#[derive(Debug, Clone)]
+struct ParseRubyHashFn {
+    value: Box<dyn Expression>,
+}
+
+impl Expression for ParseRubyHashFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let bytes = self.value.resolve(ctx)?.try_bytes()?;
+        let input = String::from_utf8_lossy(&bytes);
+
+        parse(&input).map_err(|e| format!("unable to parse Ruby hash: {}", e).into())
+    }
+
+    fn type_def(&self, _: &state::Compiler) -> TypeDef {
+        TypeDef::new()
+            .fallible()
+            .object::<(), Kind>(map! { (): Kind::all() })
+    }
+}
+
+/// How deeply hashes and arrays can be nested in the parsed value.
+const MAX_DEPTH: usize = 128;
+
+fn parse(input: &str) -> std::result::Result<Value, String> {
+    let mut parser = Parser {
+        input,
+        pos: 0,
+        depth: 0,
+    };
+
+    parser.skip_whitespace();
+    if parser.peek() != Some('{') {
+        return Err(parser.error("expected hash"));
+    }
+
+    let value = parser.parse_hash()?;
+
+    parser.skip_whitespace();
+    if parser.peek().is_some() {
+        return Err(parser.error("unexpected trailing characters"));
+    }
+
+    Ok(value)
+}
+
+/// A recursive descent parser for the output of Ruby's `Hash#inspect`.
+///
+/// Both the `:key=>value` and the `key: value` syntax are supported. Symbols
+/// are converted to strings, and `nil` is converted to `null`.
+struct Parser<'a> {
+    input: &'a str,
+    pos: usize,
+    depth: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn rest(&self) -> &'a str {
+        &self.input[self.pos..]
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.rest().chars().next()
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.pos += c.len_utf8();
+        Some(c)
+    }
+
+    fn eat(&mut self, s: &str) -> bool {
+        if self.rest().starts_with(s) {
+            self.pos += s.len();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, s: &str) -> std::result::Result<(), String> {
+        if self.eat(s) {
+            Ok(())
+        } else {
+            Err(self.error(&format!(r#"expected "{}""#, s)))
+        }
+    }
+
+    fn error(&self, message: &str) -> String {
+        format!("{} at position {}", message, self.pos)
+    }
+
+    /// Enters a hash or an array, failing if that nests them too deeply.
+    fn enter(&mut self) -> std::result::Result<(), String> {
+        self.depth += 1;
+        if self.depth > MAX_DEPTH {
+            return Err(self.error("value nested too deeply"));
+        }
+
+        Ok(())
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.peek().map_or(false, char::is_whitespace) {
+            self.bump();
+        }
+    }
+
+    fn take_while(&mut self, f: impl Fn(char) -> bool) -> &'a str {
+        let start = self.pos;
+        while self.peek().map_or(false, &f) {
+            self.bump();
+        }
+
+        &self.input[start..self.pos]
+    }
+
+    fn parse_value(&mut self) -> std::result::Result<Value, String> {
+        self.skip_whitespace();
+
+        match self.peek() {
+            Some('{') => self.parse_hash(),
+            Some('[') => self.parse_array(),
+            Some('"') => self.parse_double_quoted().map(Into::into),
+            Some('\'') => self.parse_single_quoted().map(Into::into),
+            Some(':') => self.parse_symbol().map(Into::into),
+            Some(c) if c == '-' || c.is_ascii_digit() => self.parse_number(),
+            Some(_) => match self.take_while(is_word_char) {
+                "nil" => Ok(Value::Null),
+                "true" => Ok(true.into()),
+                "false" => Ok(false.into()),
+                _ => Err(self.error("unexpected character")),
+            },
+            None => Err(self.error("unexpected end of input")),
+        }
+    }
+
+    fn parse_hash(&mut self) -> std::result::Result<Value, String> {
+        self.expect("{")?;
+        self.enter()?;
+
+        let mut map = BTreeMap::new();
+
+        self.skip_whitespace();
+        if self.eat("}") {
+            self.depth -= 1;
+            return Ok(map.into());
+        }
+
+        loop {
+            let key = self.parse_key()?;
+            let value = self.parse_value()?;
+            map.insert(key, value);
+
+            self.skip_whitespace();
+            if self.eat("}") {
+                self.depth -= 1;
+                return Ok(map.into());
+            }
+            self.expect(",")?;
+        }
+    }
+
+    /// Parses a hash key, including the `=>` or `:` that separates it from
+    /// its value.
+    fn parse_key(&mut self) -> std::result::Result<String, String> {
+        self.skip_whitespace();
+
+        let key = match self.peek() {
+            Some('"') => self.parse_double_quoted()?,
+            Some('\'') => self.parse_single_quoted()?,
+            Some(':') => {
+                let key = self.parse_symbol()?;
+                self.skip_whitespace();
+                self.expect("=>")?;
+                return Ok(key);
+            }
+            Some(c) if c == '-' || c.is_ascii_digit() => self.parse_number()?.to_string(),
+            Some(c) if is_word_char(c) => self.take_while(is_symbol_char).to_owned(),
+            Some(_) => return Err(self.error("unexpected character")),
+            None => return Err(self.error("unexpected end of input")),
+        };
+
+        self.skip_whitespace();
+        if !self.eat("=>") {
+            self.expect(":")?;
+        }
+
+        Ok(key)
+    }
+
+    fn parse_array(&mut self) -> std::result::Result<Value, String> {
+        self.expect("[")?;
+        self.enter()?;
+
+        let mut array = vec![];
+
+        self.skip_whitespace();
+        if self.eat("]") {
+            self.depth -= 1;
+            return Ok(array.into());
+        }
+
+        loop {
+            array.push(self.parse_value()?);
+
+            self.skip_whitespace();
+            if self.eat("]") {
+                self.depth -= 1;
+                return Ok(array.into());
+            }
+            self.expect(",")?;
+        }
+    }
+
+    fn parse_symbol(&mut self) -> std::result::Result<String, String> {
+        self.expect(":")?;
+
+        match self.peek() {
+            Some('"') => self.parse_double_quoted(),
+            Some(c) if is_word_char(c) => Ok(self.take_while(is_symbol_char).to_owned()),
+            _ => Err(self.error("invalid symbol")),
+        }
+    }
+
+    fn parse_number(&mut self) -> std::result::Result<Value, String> {
+        let start = self.pos;
+
+        self.eat("-");
+        self.take_while(|c| c.is_ascii_digit());
+
+        let mut float = false;
+        if self.rest().starts_with('.')
+            && self.rest()[1..].starts_with(|c: char| c.is_ascii_digit())
+        {
+            float = true;
+            self.bump();
+            self.take_while(|c| c.is_ascii_digit());
+        }
+        if self.eat("e") || self.eat("E") {
+            float = true;
+            if !self.eat("+") {
+                self.eat("-");
+            }
+            self.take_while(|c| c.is_ascii_digit());
+        }
+
+        let number = &self.input[start..self.pos];
+        let value = if float {
+            number.parse::<f64>().map(Into::into).ok()
+        } else {
+            number.parse::<i64>().map(Into::into).ok()
+        };
+
+        value.ok_or_else(|| format!(r#"invalid number "{}" at position {}"#, number, start))
+    }
+
+    fn parse_single_quoted(&mut self) -> std::result::Result<String, String> {
+        self.expect("'")?;
+
+        let mut string = String::new();
+        loop {
+            match self.bump() {
+                Some('\'') => return Ok(string),
+                Some('\\') => match self.bump() {
+                    Some(c @ '\'') | Some(c @ '\\') => string.push(c),
+                    Some(c) => {
+                        string.push('\\');
+                        string.push(c);
+                    }
+                    None => break,
+                },
+                Some(c) => string.push(c),
+                None => break,
+            }
+        }
+
+        Err(self.error("unterminated string"))
+    }
+
+    fn parse_double_quoted(&mut self) -> std::result::Result<String, String> {
+        self.expect("\"")?;
+
+        let mut string = String::new();
+        loop {
+            match self.bump() {
+                Some('"') => return Ok(string),
+                Some('\\') => match self.bump() {
+                    Some('n') => string.push('\n'),
+                    Some('t') => string.push('\t'),
+                    Some('r') => string.push('\r'),
+                    Some('0') => string.push('\0'),
+                    Some('s') => string.push(' '),
+                    Some('e') => string.push('\x1b'),
+                    Some('u') => string.push(self.parse_unicode_escape()?),
+                    Some(c) => string.push(c),
+                    None => break,
+                },
+                Some(c) => string.push(c),
+                None => break,
+            }
+        }
+
+        Err(self.error("unterminated string"))
+    }
+
+    /// Parses the `XXXX` or `{X...}` part of a `\u` escape sequence.
+    fn parse_unicode_escape(&mut self) -> std::result::Result<char, String> {
+        let hex = if self.eat("{") {
+            let hex = self.take_while(|c| c.is_ascii_hexdigit());
+            self.expect("}")?;
+            hex
+        } else {
+            let start = self.pos;
+            for _ in 0..4 {
+                if self.peek().map_or(false, |c| c.is_ascii_hexdigit()) {
+                    self.bump();
+                }
+            }
+            &self.input[start..self.pos]
+        };
+
+        u32::from_str_radix(hex, 16)
+            .ok()
+            .and_then(std::char::from_u32)
+            .ok_or_else(|| self.error("invalid unicode escape"))
+    }
+}
+
+fn is_word_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+fn is_symbol_char(c: char) -> bool {
+    is_word_char(c) || c == '?' || c == '!'
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_scalars() {
+        assert_eq!(
+            parse(r#"{:a=>1, :b=>-2.5, :c=>1.0e+20, :d=>nil, :e=>true, :f=>false}"#),
+            Ok(value!({
+                "a": 1,
+                "b": (-2.5),
+                "c": 1.0e20,
+                "d": null,
+                "e": true,
+                "f": false,
+            }))
+        );
+    }
+
+    #[test]
+    fn test_parse_string_escapes() {
+        assert_eq!(
+            parse(r#"{"msg"=>"say \"hi\"\né\u{1F600}", 'raw'=>'it\'s \n'}"#),
+            Ok(value!({
+                "msg": "say \"hi\"\n\u{e9}\u{1F600}",
+                "raw": "it's \\n",
+            }))
+        );
+    }
+
+    #[test]
+    fn test_parse_key_styles() {
+        assert_eq!(
+            parse(r#"{:sym=>1, "str"=>2, 3=>3, bare: 4, "quoted sym": 5, :"odd key"=>6, :ok?=>7}"#),
+            Ok(value!({
+                "sym": 1,
+                "str": 2,
+                "3": 3,
+                "bare": 4,
+                "quoted sym": 5,
+                "odd key": 6,
+                "ok?": 7,
+            }))
+        );
+    }
+
+    #[test]
+    fn test_parse_deeply_nested() {
+        let nested = r#"{"a"=>"#.repeat(MAX_DEPTH - 1) + "{}" + &"}".repeat(MAX_DEPTH - 1);
+        assert!(parse(&nested).is_ok());
+
+        let too_deep = r#"{"a"=>"#.repeat(5000);
+        assert_eq!(
+            parse(&too_deep),
+            Err(format!(
+                "value nested too deeply at position {}",
+                6 * MAX_DEPTH + 1
+            ))
+        );
+
+        let arrays = "{:a=>".to_owned() + &"[".repeat(5000);
+        assert_eq!(
+            parse(&arrays),
+            Err(format!(
+                "value nested too deeply at position {}",
+                5 + MAX_DEPTH
+            ))
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            parse("[1, 2]"),
+            Err("expected hash at position 0".to_owned())
+        );
+        assert_eq!(
+            parse(r#"{:a=>"foo"#),
+            Err("unterminated string at position 9".to_owned())
+        );
+        assert_eq!(
+            parse("{:a=>1 :b=>2}"),
+            Err(r#"expected "," at position 7"#.to_owned())
+        );
+        assert_eq!(
+            parse("{:a=>#<Object>}"),
+            Err("unexpected character at position 5".to_owned())
+        );
+        assert_eq!(
+            parse("{:a=>1} extra"),
+            Err("unexpected trailing characters at position 8".to_owned())
+        );
+    }
+
+    test_function![
+        parse_ruby_hash => ParseRubyHash;
+
+        symbol_keys {
+            args: func_args![value: r#"{:name=>"vector", :tags=>[:fast, "reliable", [1, 2]], :meta=>{:stars=>9001, :license=>nil}}"#],
+            want: Ok(value!({
+                "name": "vector",
+                "tags": ["fast", "reliable", [1, 2]],
+                "meta": { "stars": 9001, "license": null },
+            })),
+            tdef: TypeDef::new().fallible().object::<(), Kind>(map! { (): Kind::all() }),
+        }
+
+        empty {
+            args: func_args![value: " {} "],
+            want: Ok(value!({})),
+            tdef: TypeDef::new().fallible().object::<(), Kind>(map! { (): Kind::all() }),
+        }
+
+        malformed {
+            args: func_args![value: "{:a=>[1, 2}"],
+            want: Err(r#"unable to parse Ruby hash: expected "," at position 10"#),
+            tdef: TypeDef::new().fallible().object::<(), Kind>(map! { (): Kind::all() }),
+        }
+    ];
+}
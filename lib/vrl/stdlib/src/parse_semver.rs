@@ -0,0 +1,146 @@
+use crate::util;
+use std::collections::BTreeMap;
+use std::iter::FromIterator;
+use vrl::prelude::*;
+
+#[derive(Clone, Copy, Debug)]
+pub struct ParseSemver;
+
+impl Function for ParseSemver {
+    fn identifier(&self) -> &'static str {
+        "parse_semver"
+    }
+
+    fn is_pure(&self) -> bool {
+        true
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[Parameter {
+            keyword: "value",
+            kind: kind::BYTES,
+            required: true,
+        }]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "release",
+                source: r#"parse_semver!("1.2.3")"#,
+                result: Ok(
+                    r#"{ "build": null, "major": 1, "minor": 2, "patch": 3, "prerelease": null }"#,
+                ),
+            },
+            Example {
+                title: "prerelease with build metadata",
+                source: r#"parse_semver!("2.0.0-rc.1+build.5")"#,
+                result: Ok(
+                    r#"{ "build": "build.5", "major": 2, "minor": 0, "patch": 0, "prerelease": "rc.1" }"#,
+                ),
+            },
+        ]
+    }
+
+    fn compile(&self, mut arguments: ArgumentList) -> Compiled {
+        let value = arguments.required("value");
+
+        Ok(Box::new(ParseSemverFn { value }))
+    }
+}
+
+#[derive(Debug, Clone)]
+struct ParseSemverFn {
+    value: Box<dyn Expression>,
+}
+
+impl Expression for ParseSemverFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let bytes = self.value.resolve(ctx)?.try_bytes()?;
+        let version = util::parse_semver(&String::from_utf8_lossy(&bytes))?;
+
+        let join = |identifiers: &[semver::Identifier]| -> Value {
+            if identifiers.is_empty() {
+                return Value::Null;
+            }
+
+            identifiers
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join(".")
+                .into()
+        };
+
+        let mut map = BTreeMap::<&str, Value>::new();
+
+        map.insert("major", (version.major as i64).into());
+        map.insert("minor", (version.minor as i64).into());
+        map.insert("patch", (version.patch as i64).into());
+        map.insert("prerelease", join(&version.pre));
+        map.insert("build", join(&version.build));
+
+        Ok(Value::from_iter(
+            map.into_iter().map(|(k, v)| (k.to_owned(), v)),
+        ))
+    }
+
+    fn type_def(&self, _: &state::Compiler) -> TypeDef {
+        TypeDef::new().fallible().object(type_def())
+    }
+}
+
+fn type_def() -> BTreeMap<&'static str, TypeDef> {
+    map! {
+        "major": Kind::Integer,
+        "minor": Kind::Integer,
+        "patch": Kind::Integer,
+        "prerelease": Kind::Bytes | Kind::Null,
+        "build": Kind::Bytes | Kind::Null,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    test_function![
+        parse_semver => ParseSemver;
+
+        release {
+            args: func_args![value: "1.22.333"],
+            want: Ok(value!({
+                "major": 1,
+                "minor": 22,
+                "patch": 333,
+                "prerelease": null,
+                "build": null,
+            })),
+            tdef: TypeDef::new().fallible().object(type_def()),
+        }
+
+        prerelease_and_build {
+            args: func_args![value: "0.12.0-alpha.1+20210301.sha-5114f85"],
+            want: Ok(value!({
+                "major": 0,
+                "minor": 12,
+                "patch": 0,
+                "prerelease": "alpha.1",
+                "build": "20210301.sha-5114f85",
+            })),
+            tdef: TypeDef::new().fallible().object(type_def()),
+        }
+
+        missing_patch {
+            args: func_args![value: "1.2"],
+            want: Err(r#"invalid semantic version "1.2""#),
+            tdef: TypeDef::new().fallible().object(type_def()),
+        }
+
+        garbage {
+            args: func_args![value: "not.a.version"],
+            want: Err(r#"invalid semantic version "not.a.version""#),
+            tdef: TypeDef::new().fallible().object(type_def()),
+        }
+    ];
+}
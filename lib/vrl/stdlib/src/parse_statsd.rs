@@ -0,0 +1,287 @@
+use std::collections::BTreeMap;
+use vrl::prelude::*;
+
+#[derive(Clone, Copy, Debug)]
+pub struct ParseStatsd;
+
+impl Function for ParseStatsd {
+    fn identifier(&self) -> &'static str {
+        "parse_statsd"
+    }
+
+    fn is_pure(&self) -> bool {
+        true
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[Parameter {
+            keyword: "value",
+            kind: kind::BYTES,
+            required: true,
+        }]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "counter",
+                source: r#"parse_statsd!("page.views:1|c|@0.5|#env:prod,canary")"#,
+                result: Ok(
+                    r#"{ "metric": "page.views", "sample_rate": 0.5, "tags": { "canary": "true", "env": "prod" }, "type": "counter", "value": 1.0 }"#,
+                ),
+            },
+            Example {
+                title: "set",
+                source: r#"parse_statsd!("users.unique:alice|s")"#,
+                result: Ok(
+                    r#"{ "metric": "users.unique", "sample_rate": 1.0, "tags": {}, "type": "set", "value": "alice" }"#,
+                ),
+            },
+        ]
+    }
+
+    fn compile(&self, mut arguments: ArgumentList) -> Compiled {
+        let value = arguments.required("value");
+
+        Ok(Box::new(ParseStatsdFn { value }))
+    }
+}
+
+#[derive(Debug, Clone)]
+struct ParseStatsdFn {
+    value: Box<dyn Expression>,
+}
+
+impl Expression for ParseStatsdFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let bytes = self.value.resolve(ctx)?.try_bytes()?;
+        let line = String::from_utf8_lossy(&bytes);
+
+        Ok(parse_line(line.trim()).map_err(|err| format!("unable to parse statsd: {}", err))?)
+    }
+
+    fn type_def(&self, _: &state::Compiler) -> TypeDef {
+        TypeDef::new().fallible().object(type_def())
+    }
+}
+
+fn type_def() -> BTreeMap<&'static str, TypeDef> {
+    map! {
+        "metric": Kind::Bytes,
+        "value": Kind::Float | Kind::Bytes,
+        "type": Kind::Bytes,
+        "sample_rate": Kind::Float,
+        "tags": TypeDef::new().object::<(), Kind>(map! {
+            (): Kind::Bytes,
+        }),
+    }
+}
+
+/// Parses a line such as `page.views:1|c|@0.5|#tag:value`.
+///
+/// See <https://docs.datadoghq.com/developers/dogstatsd/datagram_shell/#the-dogstatsd-protocol>.
+fn parse_line(line: &str) -> std::result::Result<Value, String> {
+    let malformed = || format!("expected \"<metric>:<value>|<type>\", got {:?}", line);
+
+    let mut key_and_body = line.splitn(2, ':');
+    let (metric, body) = match (key_and_body.next(), key_and_body.next()) {
+        (Some(metric), Some(body)) if !metric.is_empty() => (metric, body),
+        _ => return Err(malformed()),
+    };
+
+    let mut parts = body.split('|');
+    let (value, kind) = match (parts.next(), parts.next()) {
+        (Some(value), Some(kind)) if !value.is_empty() => (value, kind),
+        _ => return Err(malformed()),
+    };
+
+    let kind = match kind {
+        "c" => "counter",
+        "g" => "gauge",
+        "ms" => "timer",
+        "h" => "histogram",
+        "d" => "distribution",
+        "s" => "set",
+        kind => return Err(format!("unknown metric type {:?}", kind)),
+    };
+
+    // The members of sets are arbitrary strings, other metrics are numbers.
+    let value = if kind == "set" {
+        Value::from(value)
+    } else {
+        value
+            .parse::<f64>()
+            .ok()
+            .filter(|value| value.is_finite())
+            .ok_or_else(|| format!("invalid value {:?}", value))?
+            .into()
+    };
+
+    let mut sample_rate = 1.0;
+    let mut tags = BTreeMap::new();
+
+    for part in parts {
+        if let Some(rate) = part.strip_prefix('@') {
+            sample_rate = rate
+                .parse::<f64>()
+                .ok()
+                .filter(|rate| *rate > 0.0 && *rate <= 1.0)
+                .ok_or_else(|| format!("invalid sample rate {:?}", part))?;
+        } else if let Some(list) = part.strip_prefix('#') {
+            for tag in list.split(',').filter(|tag| !tag.is_empty()) {
+                // As with Telegraf, tags without a value are `"true"`.
+                let mut key_and_value = tag.splitn(2, ':');
+                let key = key_and_value.next().unwrap_or_default();
+                let value = key_and_value.next().unwrap_or("true");
+
+                tags.insert(key.to_owned(), Value::from(value));
+            }
+        }
+
+        // Other DogStatsD extensions, such as container IDs, are ignored.
+    }
+
+    let mut result = BTreeMap::new();
+    result.insert("metric".to_owned(), Value::from(metric));
+    result.insert("value".to_owned(), value);
+    result.insert("type".to_owned(), Value::from(kind));
+    result.insert("sample_rate".to_owned(), Value::from(sample_rate));
+    result.insert("tags".to_owned(), Value::from(tags));
+
+    Ok(result.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    test_function![
+        parse_statsd => ParseStatsd;
+
+        counter {
+            args: func_args![value: "page.views:1|c"],
+            want: Ok(value!({
+                "metric": "page.views",
+                "value": 1.0,
+                "type": "counter",
+                "sample_rate": 1.0,
+                "tags": {},
+            })),
+            tdef: TypeDef::new().fallible().object(type_def()),
+        }
+
+        gauge {
+            args: func_args![value: "fuel.level:-0.5|g\n"],
+            want: Ok(value!({
+                "metric": "fuel.level",
+                "value": (-0.5),
+                "type": "gauge",
+                "sample_rate": 1.0,
+                "tags": {},
+            })),
+            tdef: TypeDef::new().fallible().object(type_def()),
+        }
+
+        timer {
+            args: func_args![value: "request.duration:320|ms"],
+            want: Ok(value!({
+                "metric": "request.duration",
+                "value": 320.0,
+                "type": "timer",
+                "sample_rate": 1.0,
+                "tags": {},
+            })),
+            tdef: TypeDef::new().fallible().object(type_def()),
+        }
+
+        distribution {
+            args: func_args![value: "song.length:240|d"],
+            want: Ok(value!({
+                "metric": "song.length",
+                "value": 240.0,
+                "type": "distribution",
+                "sample_rate": 1.0,
+                "tags": {},
+            })),
+            tdef: TypeDef::new().fallible().object(type_def()),
+        }
+
+        set {
+            args: func_args![value: "users.unique:alice|s"],
+            want: Ok(value!({
+                "metric": "users.unique",
+                "value": "alice",
+                "type": "set",
+                "sample_rate": 1.0,
+                "tags": {},
+            })),
+            tdef: TypeDef::new().fallible().object(type_def()),
+        }
+
+        sample_rate {
+            args: func_args![value: "page.views:3|c|@0.1"],
+            want: Ok(value!({
+                "metric": "page.views",
+                "value": 3.0,
+                "type": "counter",
+                "sample_rate": 0.1,
+                "tags": {},
+            })),
+            tdef: TypeDef::new().fallible().object(type_def()),
+        }
+
+        tags {
+            args: func_args![value: "page.views:1|c|@0.5|#env:prod,canary,url:http://a"],
+            want: Ok(value!({
+                "metric": "page.views",
+                "value": 1.0,
+                "type": "counter",
+                "sample_rate": 0.5,
+                "tags": { "env": "prod", "canary": "true", "url": "http://a" },
+            })),
+            tdef: TypeDef::new().fallible().object(type_def()),
+        }
+
+        tags_without_sample_rate {
+            args: func_args![value: "page.views:1|h|#env:prod|c:abc123"],
+            want: Ok(value!({
+                "metric": "page.views",
+                "value": 1.0,
+                "type": "histogram",
+                "sample_rate": 1.0,
+                "tags": { "env": "prod" },
+            })),
+            tdef: TypeDef::new().fallible().object(type_def()),
+        }
+
+        malformed {
+            args: func_args![value: "page.views"],
+            want: Err(r#"unable to parse statsd: expected "<metric>:<value>|<type>", got "page.views""#),
+            tdef: TypeDef::new().fallible().object(type_def()),
+        }
+
+        missing_type {
+            args: func_args![value: "page.views:1"],
+            want: Err(r#"unable to parse statsd: expected "<metric>:<value>|<type>", got "page.views:1""#),
+            tdef: TypeDef::new().fallible().object(type_def()),
+        }
+
+        unknown_type {
+            args: func_args![value: "page.views:1|x"],
+            want: Err(r#"unable to parse statsd: unknown metric type "x""#),
+            tdef: TypeDef::new().fallible().object(type_def()),
+        }
+
+        invalid_value {
+            args: func_args![value: "page.views:one|c"],
+            want: Err(r#"unable to parse statsd: invalid value "one""#),
+            tdef: TypeDef::new().fallible().object(type_def()),
+        }
+
+        invalid_sample_rate {
+            args: func_args![value: "page.views:1|c|@2"],
+            want: Err(r#"unable to parse statsd: invalid sample rate "@2""#),
+            tdef: TypeDef::new().fallible().object(type_def()),
+        }
+    ];
+}
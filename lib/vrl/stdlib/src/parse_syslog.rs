@@ -57,7 +57,9 @@ impl Expression for ParseSyslogFn {
         let value = self.value.resolve(ctx)?;
         let message = value.try_bytes_utf8_lossy()?;
 
-        let parsed = syslog_loose::parse_message_with_year_exact(&message, resolve_year)?;
+        let now = ctx.state().clock().now();
+        let parsed =
+            syslog_loose::parse_message_with_year_exact(&message, |date| resolve_year(date, now))?;
 
         Ok(message_to_value(parsed))
     }
@@ -70,8 +72,7 @@ impl Expression for ParseSyslogFn {
 /// Function used to resolve the year for syslog messages that don't include the
 /// year. If the current month is January, and the syslog message is for
 /// December, it will take the previous year. Otherwise, take the current year.
-fn resolve_year((month, _date, _hour, _min, _sec): IncompleteDate) -> i32 {
-    let now = Utc::now();
+fn resolve_year((month, _date, _hour, _min, _sec): IncompleteDate, now: DateTime<Utc>) -> i32 {
     if now.month() == 1 && month == 12 {
         now.year() - 1
     } else {
@@ -152,6 +153,16 @@ mod tests {
     use chrono::prelude::*;
     use shared::btreemap;
 
+    #[test]
+    fn resolve_year_from_clock() {
+        let january = Utc.ymd(2021, 1, 5).and_hms(0, 0, 0);
+        assert_eq!(resolve_year((12, 31, 23, 59, 59), january), 2020);
+        assert_eq!(resolve_year((1, 4, 23, 59, 59), january), 2021);
+
+        let june = Utc.ymd(2021, 6, 5).and_hms(0, 0, 0);
+        assert_eq!(resolve_year((12, 31, 23, 59, 59), june), 2021);
+    }
+
     test_function![
         parse_syslog => ParseSyslog;
 
@@ -11,6 +11,10 @@ impl Function for ParseUrl {
         "parse_url"
     }
 
+    fn is_pure(&self) -> bool {
+        true
+    }
+
     fn parameters(&self) -> &'static [Parameter] {
         &[Parameter {
             keyword: "value",
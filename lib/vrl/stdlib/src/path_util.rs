@@ -0,0 +1,54 @@
+//! Platform-neutral manipulation of paths.
+//!
+//! Paths are separated by forward slashes. Windows paths are also separated
+//! by backslashes, and can start with a drive, such as `C:`.
+
+pub(crate) fn is_separator(c: char, windows: bool) -> bool {
+    c == '/' || (windows && c == '\\')
+}
+
+/// Splits the path into its root, the drive and the leading separators, and
+/// the rest of the path.
+#[cfg(any(feature = "basename", feature = "dirname", feature = "extname"))]
+fn split_root(path: &str, windows: bool) -> (&str, &str) {
+    let bytes = path.as_bytes();
+    let drive = if windows && bytes.len() >= 2 && bytes[0].is_ascii_alphabetic() && bytes[1] == b':'
+    {
+        2
+    } else {
+        0
+    };
+
+    let rest = path[drive..].trim_start_matches(|c| is_separator(c, windows));
+
+    path.split_at(path.len() - rest.len())
+}
+
+/// Returns the last component of the path, ignoring trailing separators.
+#[cfg(any(feature = "basename", feature = "extname"))]
+pub(crate) fn basename(path: &str, windows: bool) -> &str {
+    let (_, rest) = split_root(path, windows);
+    let rest = rest.trim_end_matches(|c| is_separator(c, windows));
+
+    match rest.rfind(|c| is_separator(c, windows)) {
+        Some(i) => &rest[i + 1..],
+        None => rest,
+    }
+}
+
+/// Returns the path without its last component, ignoring trailing
+/// separators. The directory of a relative path without separators is `.`.
+#[cfg(feature = "dirname")]
+pub(crate) fn dirname(path: &str, windows: bool) -> &str {
+    let (root, rest) = split_root(path, windows);
+    let rest = rest.trim_end_matches(|c| is_separator(c, windows));
+
+    match rest.rfind(|c| is_separator(c, windows)) {
+        Some(i) => {
+            let parent = rest[..i].trim_end_matches(|c| is_separator(c, windows));
+            &path[..root.len() + parent.len()]
+        }
+        None if root.is_empty() => ".",
+        None => root,
+    }
+}
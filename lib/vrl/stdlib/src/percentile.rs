@@ -0,0 +1,125 @@
+use crate::util;
+use std::convert::TryFrom;
+use vrl::prelude::*;
+
+#[derive(Clone, Copy, Debug)]
+pub struct Percentile;
+
+impl Function for Percentile {
+    fn identifier(&self) -> &'static str {
+        "percentile"
+    }
+
+    fn is_pure(&self) -> bool {
+        true
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[
+            Parameter {
+                keyword: "value",
+                kind: kind::ARRAY,
+                required: true,
+            },
+            Parameter {
+                keyword: "p",
+                kind: kind::INTEGER | kind::FLOAT,
+                required: true,
+            },
+        ]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[Example {
+            title: "p90",
+            source: r#"percentile!([1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11], 90)"#,
+            result: Ok("10.0"),
+        }]
+    }
+
+    fn compile(&self, mut arguments: ArgumentList) -> Compiled {
+        let value = arguments.required("value");
+        let p = arguments.required("p");
+
+        Ok(Box::new(PercentileFn { value, p }))
+    }
+}
+
+#[derive(Debug, Clone)]
+struct PercentileFn {
+    value: Box<dyn Expression>,
+    p: Box<dyn Expression>,
+}
+
+impl Expression for PercentileFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?;
+        let p = f64::try_from(&self.p.resolve(ctx)?)?;
+
+        if !(0.0..=100.0).contains(&p) {
+            return Err(format!("percentile must be between 0 and 100, got {}", p).into());
+        }
+
+        Ok(util::quantile(value, p / 100.0)?.into())
+    }
+
+    fn type_def(&self, _: &state::Compiler) -> TypeDef {
+        TypeDef::new().fallible().float()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // The integers 1 through 101, so that percentile `p` is `p + 1`.
+    fn distribution() -> Value {
+        (1..=101).map(Value::from).collect::<Vec<_>>().into()
+    }
+
+    test_function![
+        percentile => Percentile;
+
+        p50 {
+            args: func_args![value: distribution(), p: 50],
+            want: Ok(51.0),
+            tdef: TypeDef::new().fallible().float(),
+        }
+
+        p90 {
+            args: func_args![value: distribution(), p: 90],
+            want: Ok(91.0),
+            tdef: TypeDef::new().fallible().float(),
+        }
+
+        p99 {
+            args: func_args![value: distribution(), p: 99],
+            want: Ok(100.0),
+            tdef: TypeDef::new().fallible().float(),
+        }
+
+        interpolated {
+            args: func_args![value: distribution(), p: 99.5],
+            want: Ok(100.5),
+            tdef: TypeDef::new().fallible().float(),
+        }
+
+        unsorted {
+            args: func_args![value: value!([40, 10, 30, 20]), p: 50],
+            want: Ok(25.0),
+            tdef: TypeDef::new().fallible().float(),
+        }
+
+        empty {
+            args: func_args![value: value!([]), p: 50],
+            want: Err("array must not be empty"),
+            tdef: TypeDef::new().fallible().float(),
+        }
+
+        out_of_range {
+            args: func_args![value: distribution(), p: -1],
+            want: Err("percentile must be between 0 and 100, got -1"),
+            tdef: TypeDef::new().fallible().float(),
+        }
+    ];
+}
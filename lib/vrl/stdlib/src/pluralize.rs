@@ -0,0 +1,206 @@
+use vrl::prelude::*;
+
+#[derive(Clone, Copy, Debug)]
+pub struct Pluralize;
+
+impl Function for Pluralize {
+    fn identifier(&self) -> &'static str {
+        "pluralize"
+    }
+
+    fn is_pure(&self) -> bool {
+        true
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[
+            Parameter {
+                keyword: "value",
+                kind: kind::BYTES,
+                required: true,
+            },
+            Parameter {
+                keyword: "count",
+                kind: kind::INTEGER | kind::FLOAT,
+                required: true,
+            },
+            Parameter {
+                keyword: "plural",
+                kind: kind::BYTES,
+                required: false,
+            },
+        ]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "plural",
+                source: r#"pluralize("item", 3)"#,
+                result: Ok("items"),
+            },
+            Example {
+                title: "singular",
+                source: r#"pluralize("item", 1)"#,
+                result: Ok("item"),
+            },
+            Example {
+                title: "irregular",
+                source: r#"pluralize("person", 2, plural: "people")"#,
+                result: Ok("people"),
+            },
+        ]
+    }
+
+    fn compile(&self, mut arguments: ArgumentList) -> Compiled {
+        let value = arguments.required("value");
+        let count = arguments.required("count");
+        let plural = arguments.optional("plural");
+
+        Ok(Box::new(PluralizeFn {
+            value,
+            count,
+            plural,
+        }))
+    }
+}
+
+#[derive(Debug, Clone)]
+struct PluralizeFn {
+    value: Box<dyn Expression>,
+    count: Box<dyn Expression>,
+    plural: Option<Box<dyn Expression>>,
+}
+
+impl Expression for PluralizeFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?;
+        let word = value.try_bytes_utf8_lossy()?;
+
+        let singular = match self.count.resolve(ctx)? {
+            Value::Integer(v) => v == 1 || v == -1,
+            #[allow(clippy::float_cmp)]
+            Value::Float(v) => v.abs() == 1.0,
+            value => {
+                return Err(value::Error::Expected {
+                    got: value.kind(),
+                    expected: Kind::Integer | Kind::Float,
+                }
+                .into())
+            }
+        };
+
+        if singular {
+            return Ok(word.into_owned().into());
+        }
+
+        match &self.plural {
+            Some(plural) => Ok(plural.resolve(ctx)?.try_bytes()?.into()),
+            None => Ok(plural_of(&word).into()),
+        }
+    }
+
+    fn type_def(&self, _: &state::Compiler) -> TypeDef {
+        TypeDef::new().infallible().bytes()
+    }
+}
+
+/// The plural of the word, following the regular English rules. The suffix
+/// is uppercase if the word ends with an uppercase letter.
+fn plural_of(word: &str) -> String {
+    let lowercase = word.to_lowercase();
+    let ends_with_consonant_y = lowercase.ends_with('y')
+        && !lowercase[..lowercase.len() - 1].ends_with(|c| "aeiou".contains(c));
+
+    let (stem, suffix) = if ends_with_consonant_y && word.len() > 1 {
+        (&word[..word.len() - 1], "ies")
+    } else if ["s", "x", "z", "ch", "sh"]
+        .iter()
+        .any(|ending| lowercase.ends_with(ending))
+    {
+        (word, "es")
+    } else {
+        (word, "s")
+    };
+
+    if word.chars().last().map_or(false, char::is_uppercase) {
+        format!("{}{}", stem, suffix.to_uppercase())
+    } else {
+        format!("{}{}", stem, suffix)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    test_function![
+        pluralize => Pluralize;
+
+        regular {
+            args: func_args![value: "item", count: 2],
+            want: Ok("items"),
+            tdef: TypeDef::new().infallible().bytes(),
+        }
+
+        one {
+            args: func_args![value: "item", count: 1],
+            want: Ok("item"),
+            tdef: TypeDef::new().infallible().bytes(),
+        }
+
+        zero {
+            args: func_args![value: "item", count: 0],
+            want: Ok("items"),
+            tdef: TypeDef::new().infallible().bytes(),
+        }
+
+        fraction {
+            args: func_args![value: "mile", count: 1.5],
+            want: Ok("miles"),
+            tdef: TypeDef::new().infallible().bytes(),
+        }
+
+        one_as_float {
+            args: func_args![value: "mile", count: 1.0],
+            want: Ok("mile"),
+            tdef: TypeDef::new().infallible().bytes(),
+        }
+
+        sibilant {
+            args: func_args![value: "match", count: 2],
+            want: Ok("matches"),
+            tdef: TypeDef::new().infallible().bytes(),
+        }
+
+        consonant_y {
+            args: func_args![value: "city", count: 2],
+            want: Ok("cities"),
+            tdef: TypeDef::new().infallible().bytes(),
+        }
+
+        vowel_y {
+            args: func_args![value: "day", count: 2],
+            want: Ok("days"),
+            tdef: TypeDef::new().infallible().bytes(),
+        }
+
+        uppercase {
+            args: func_args![value: "BOX", count: 2],
+            want: Ok("BOXES"),
+            tdef: TypeDef::new().infallible().bytes(),
+        }
+
+        irregular {
+            args: func_args![value: "person", count: 2, plural: "people"],
+            want: Ok("people"),
+            tdef: TypeDef::new().infallible().bytes(),
+        }
+
+        irregular_one {
+            args: func_args![value: "person", count: 1, plural: "people"],
+            want: Ok("person"),
+            tdef: TypeDef::new().infallible().bytes(),
+        }
+    ];
+}
@@ -0,0 +1,110 @@
+use crate::util;
+use vrl::prelude::*;
+
+#[derive(Clone, Copy, Debug)]
+pub struct PublicSuffix;
+
+impl Function for PublicSuffix {
+    fn identifier(&self) -> &'static str {
+        "public_suffix"
+    }
+
+    fn is_pure(&self) -> bool {
+        true
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[Parameter {
+            keyword: "value",
+            kind: kind::BYTES,
+            required: true,
+        }]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "multi-label suffix",
+                source: r#"public_suffix!("a.b.example.co.uk")"#,
+                result: Ok("co.uk"),
+            },
+            Example {
+                title: "single-label suffix",
+                source: r#"public_suffix!("vector.dev")"#,
+                result: Ok("dev"),
+            },
+        ]
+    }
+
+    fn compile(&self, mut arguments: ArgumentList) -> Compiled {
+        let value = arguments.required("value");
+
+        Ok(Box::new(PublicSuffixFn { value }))
+    }
+}
+
+#[derive(Debug, Clone)]
+struct PublicSuffixFn {
+    value: Box<dyn Expression>,
+}
+
+impl Expression for PublicSuffixFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?;
+        let domain = util::parse_domain(&value.try_bytes_utf8_lossy()?)?;
+
+        let suffix = psl::suffix_str(&domain)
+            .ok_or_else(|| format!(r#"unable to determine public suffix of "{}""#, domain))?;
+
+        Ok(suffix.to_owned().into())
+    }
+
+    fn type_def(&self, _: &state::Compiler) -> TypeDef {
+        TypeDef::new().fallible().bytes()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    test_function![
+        public_suffix => PublicSuffix;
+
+        multi_label {
+            args: func_args![value: "a.b.example.co.uk"],
+            want: Ok("co.uk"),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+
+        single_label {
+            args: func_args![value: "www.example.com"],
+            want: Ok("com"),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+
+        suffix_only {
+            args: func_args![value: "co.uk"],
+            want: Ok("co.uk"),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+
+        uppercase_trailing_dot {
+            args: func_args![value: "WWW.Example.CO.UK."],
+            want: Ok("co.uk"),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+
+        ip_address {
+            args: func_args![value: "192.168.0.1"],
+            want: Err(r#"invalid domain "192.168.0.1""#),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+
+        not_a_domain {
+            args: func_args![value: "not a domain"],
+            want: Err(r#"invalid domain "not a domain""#),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+    ];
+}
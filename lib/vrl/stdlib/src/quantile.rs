@@ -0,0 +1,133 @@
+use crate::util;
+use std::convert::TryFrom;
+use vrl::prelude::*;
+
+#[derive(Clone, Copy, Debug)]
+pub struct Quantile;
+
+impl Function for Quantile {
+    fn identifier(&self) -> &'static str {
+        "quantile"
+    }
+
+    fn is_pure(&self) -> bool {
+        true
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[
+            Parameter {
+                keyword: "value",
+                kind: kind::ARRAY,
+                required: true,
+            },
+            Parameter {
+                keyword: "q",
+                kind: kind::INTEGER | kind::FLOAT,
+                required: true,
+            },
+        ]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "median",
+                source: r#"quantile!([3, 1, 4, 2], 0.5)"#,
+                result: Ok("2.5"),
+            },
+            Example {
+                title: "maximum",
+                source: r#"quantile!([3, 1, 4, 2], 1)"#,
+                result: Ok("4.0"),
+            },
+        ]
+    }
+
+    fn compile(&self, mut arguments: ArgumentList) -> Compiled {
+        let value = arguments.required("value");
+        let q = arguments.required("q");
+
+        Ok(Box::new(QuantileFn { value, q }))
+    }
+}
+
+#[derive(Debug, Clone)]
+struct QuantileFn {
+    value: Box<dyn Expression>,
+    q: Box<dyn Expression>,
+}
+
+impl Expression for QuantileFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?;
+        let q = f64::try_from(&self.q.resolve(ctx)?)?;
+
+        if !(0.0..=1.0).contains(&q) {
+            return Err(format!("quantile must be between 0 and 1, got {}", q).into());
+        }
+
+        Ok(util::quantile(value, q)?.into())
+    }
+
+    fn type_def(&self, _: &state::Compiler) -> TypeDef {
+        TypeDef::new().fallible().float()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    test_function![
+        quantile => Quantile;
+
+        median_odd {
+            args: func_args![value: value!([5, 1, 3]), q: 0.5],
+            want: Ok(3.0),
+            tdef: TypeDef::new().fallible().float(),
+        }
+
+        median_interpolated {
+            args: func_args![value: value!([1, 2, 3, 4]), q: 0.5],
+            want: Ok(2.5),
+            tdef: TypeDef::new().fallible().float(),
+        }
+
+        interpolated_between_ranks {
+            args: func_args![value: value!([10, 20.0]), q: 0.25],
+            want: Ok(12.5),
+            tdef: TypeDef::new().fallible().float(),
+        }
+
+        bounds {
+            args: func_args![value: value!([2, (-1), 7]), q: 0],
+            want: Ok(-1.0),
+            tdef: TypeDef::new().fallible().float(),
+        }
+
+        single {
+            args: func_args![value: value!([42]), q: 0.9],
+            want: Ok(42.0),
+            tdef: TypeDef::new().fallible().float(),
+        }
+
+        empty {
+            args: func_args![value: value!([]), q: 0.5],
+            want: Err("array must not be empty"),
+            tdef: TypeDef::new().fallible().float(),
+        }
+
+        out_of_range {
+            args: func_args![value: value!([1, 2]), q: 1.5],
+            want: Err("quantile must be between 0 and 1, got 1.5"),
+            tdef: TypeDef::new().fallible().float(),
+        }
+
+        not_a_number {
+            args: func_args![value: value!([1, "2"]), q: 0.5],
+            want: Err(r#"expected array of numbers, got "string" at index 1"#),
+            tdef: TypeDef::new().fallible().float(),
+        }
+    ];
+}
@@ -0,0 +1,122 @@
+use rand::Rng;
+use vrl::prelude::*;
+
+#[derive(Clone, Copy, Debug)]
+pub struct RandomInt;
+
+impl Function for RandomInt {
+    fn identifier(&self) -> &'static str {
+        "random_int"
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[
+            Parameter {
+                keyword: "min",
+                kind: kind::INTEGER,
+                required: true,
+            },
+            Parameter {
+                keyword: "max",
+                kind: kind::INTEGER,
+                required: true,
+            },
+        ]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "in range",
+                source: r#"random_int!(0, 10) >= 0"#,
+                result: Ok("true"),
+            },
+            Example {
+                title: "empty range",
+                source: r#"random_int!(10, 10)"#,
+                result: Err(
+                    r#"function call error for "random_int" at (0:19): max must be greater than min"#,
+                ),
+            },
+        ]
+    }
+
+    fn compile(&self, mut arguments: ArgumentList) -> Compiled {
+        let min = arguments.required("min");
+        let max = arguments.required("max");
+
+        Ok(Box::new(RandomIntFn { min, max }))
+    }
+}
+
+#[derive(Debug, Clone)]
+struct RandomIntFn {
+    min: Box<dyn Expression>,
+    max: Box<dyn Expression>,
+}
+
+impl Expression for RandomIntFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let min = self.min.resolve(ctx)?.try_integer()?;
+        let max = self.max.resolve(ctx)?.try_integer()?;
+
+        if max <= min {
+            return Err("max must be greater than min".into());
+        }
+
+        Ok(ctx.state_mut().rng().gen_range(min..max).into())
+    }
+
+    fn type_def(&self, _: &state::Compiler) -> TypeDef {
+        TypeDef::new().fallible().integer()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sequence(seed: u64) -> Vec<Resolved> {
+        let mut target = value!({});
+        let mut runtime_state = vrl::state::Runtime::default().with_seed(seed);
+        let mut ctx = Context::new(&mut target, &mut runtime_state);
+
+        let func = RandomIntFn {
+            min: expr!(-100),
+            max: expr!(100),
+        };
+
+        (0..32).map(|_| func.resolve(&mut ctx)).collect()
+    }
+
+    #[test]
+    fn seeded() {
+        let sequence = sequence(42);
+
+        // In range, and in theory not all 32 values are the same.
+        assert!(sequence.iter().all(|n| match n {
+            Ok(Value::Integer(n)) => (-100..100).contains(n),
+            _ => false,
+        }));
+        assert!(sequence.iter().any(|n| n != &sequence[0]));
+
+        assert_eq!(sequence, self::sequence(42));
+        assert_ne!(sequence, self::sequence(43));
+    }
+
+    test_function![
+        random_int => RandomInt;
+
+        single_value {
+            args: func_args![min: 7, max: 8],
+            want: Ok(7),
+            tdef: TypeDef::new().fallible().integer(),
+        }
+
+        empty_range {
+            args: func_args![min: 8, max: 8],
+            want: Err("max must be greater than min"),
+            tdef: TypeDef::new().fallible().integer(),
+        }
+    ];
+}
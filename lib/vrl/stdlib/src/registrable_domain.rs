@@ -0,0 +1,110 @@
+use crate::util;
+use vrl::prelude::*;
+
+#[derive(Clone, Copy, Debug)]
+pub struct RegistrableDomain;
+
+impl Function for RegistrableDomain {
+    fn identifier(&self) -> &'static str {
+        "registrable_domain"
+    }
+
+    fn is_pure(&self) -> bool {
+        true
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[Parameter {
+            keyword: "value",
+            kind: kind::BYTES,
+            required: true,
+        }]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "multi-label suffix",
+                source: r#"registrable_domain!("a.b.example.co.uk")"#,
+                result: Ok("example.co.uk"),
+            },
+            Example {
+                title: "single-label suffix",
+                source: r#"registrable_domain!("www.vector.dev")"#,
+                result: Ok("vector.dev"),
+            },
+        ]
+    }
+
+    fn compile(&self, mut arguments: ArgumentList) -> Compiled {
+        let value = arguments.required("value");
+
+        Ok(Box::new(RegistrableDomainFn { value }))
+    }
+}
+
+#[derive(Debug, Clone)]
+struct RegistrableDomainFn {
+    value: Box<dyn Expression>,
+}
+
+impl Expression for RegistrableDomainFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?;
+        let domain = util::parse_domain(&value.try_bytes_utf8_lossy()?)?;
+
+        let registrable = psl::domain_str(&domain)
+            .ok_or_else(|| format!(r#"unable to determine registrable domain of "{}""#, domain))?;
+
+        Ok(registrable.to_owned().into())
+    }
+
+    fn type_def(&self, _: &state::Compiler) -> TypeDef {
+        TypeDef::new().fallible().bytes()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    test_function![
+        registrable_domain => RegistrableDomain;
+
+        multi_label {
+            args: func_args![value: "a.b.example.co.uk"],
+            want: Ok("example.co.uk"),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+
+        single_label {
+            args: func_args![value: "www.example.com"],
+            want: Ok("example.com"),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+
+        already_registrable {
+            args: func_args![value: "example.com"],
+            want: Ok("example.com"),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+
+        suffix_only {
+            args: func_args![value: "co.uk"],
+            want: Err(r#"unable to determine registrable domain of "co.uk""#),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+
+        empty_label {
+            args: func_args![value: "www..example.com"],
+            want: Err(r#"invalid domain "www..example.com""#),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+
+        not_a_domain {
+            args: func_args![value: "https://example.com/"],
+            want: Err(r#"invalid domain "https://example.com/""#),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+    ];
+}
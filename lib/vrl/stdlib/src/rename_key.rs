@@ -0,0 +1,149 @@
+use crate::util;
+use vrl::prelude::*;
+
+#[derive(Clone, Copy, Debug)]
+pub struct RenameKey;
+
+impl Function for RenameKey {
+    fn identifier(&self) -> &'static str {
+        "rename_key"
+    }
+
+    fn is_pure(&self) -> bool {
+        true
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[
+            Parameter {
+                keyword: "value",
+                kind: kind::OBJECT,
+                required: true,
+            },
+            Parameter {
+                keyword: "from",
+                kind: kind::BYTES,
+                required: true,
+            },
+            Parameter {
+                keyword: "to",
+                kind: kind::BYTES,
+                required: true,
+            },
+            Parameter {
+                keyword: "overwrite",
+                kind: kind::BOOLEAN,
+                required: false,
+            },
+        ]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "rename field",
+                source: r#"rename_key!({ "foo": 1 }, "foo", "bar")"#,
+                result: Ok(r#"{ "bar": 1 }"#),
+            },
+            Example {
+                title: "nested path",
+                source: r#"rename_key!({ "foo": { "bar": 1 } }, "foo.bar", "baz")"#,
+                result: Ok(r#"{ "baz": 1, "foo": {} }"#),
+            },
+            Example {
+                title: "collision",
+                source: r#"rename_key!({ "foo": 1, "bar": 2 }, "foo", "bar", overwrite: false)"#,
+                result: Err(
+                    r#"function call error for "rename_key" at (0:67): path ".bar" already exists"#,
+                ),
+            },
+        ]
+    }
+
+    fn compile(&self, mut arguments: ArgumentList) -> Compiled {
+        let value = arguments.required("value");
+        let from = arguments.required("from");
+        let to = arguments.required("to");
+        let overwrite = arguments
+            .optional("overwrite")
+            .unwrap_or_else(|| expr!(true));
+
+        Ok(Box::new(RenameKeyFn {
+            value,
+            from,
+            to,
+            overwrite,
+        }))
+    }
+}
+
+#[derive(Debug, Clone)]
+struct RenameKeyFn {
+    value: Box<dyn Expression>,
+    from: Box<dyn Expression>,
+    to: Box<dyn Expression>,
+    overwrite: Box<dyn Expression>,
+}
+
+impl Expression for RenameKeyFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let mut value: Value = self.value.resolve(ctx)?.try_object()?.into();
+
+        let from = self.from.resolve(ctx)?;
+        let from = util::parse_path(&from.try_bytes_utf8_lossy()?)?;
+
+        let to = self.to.resolve(ctx)?;
+        let to = util::parse_path(&to.try_bytes_utf8_lossy()?)?;
+
+        let overwrite = self.overwrite.resolve(ctx)?.try_boolean()?;
+
+        util::rename_paths(&mut value, &[(from, to)], overwrite)?;
+
+        Ok(value)
+    }
+
+    fn type_def(&self, _: &state::Compiler) -> TypeDef {
+        TypeDef::new()
+            .fallible()
+            .object::<(), Kind>(map! { (): Kind::all() })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    test_function![
+        rename_key => RenameKey;
+
+        single {
+            args: func_args![value: value!({ "foo": 1, "baz": 2 }), from: "foo", to: "bar"],
+            want: Ok(value!({ "bar": 1, "baz": 2 })),
+            tdef: TypeDef::new().fallible().object::<(), Kind>(map! { (): Kind::all() }),
+        }
+
+        missing {
+            args: func_args![value: value!({ "foo": 1 }), from: "qux", to: "bar"],
+            want: Ok(value!({ "foo": 1 })),
+            tdef: TypeDef::new().fallible().object::<(), Kind>(map! { (): Kind::all() }),
+        }
+
+        nested {
+            args: func_args![value: value!({ "foo": { "bar": 1 } }), from: "foo.bar", to: "baz.qux"],
+            want: Ok(value!({ "foo": {}, "baz": { "qux": 1 } })),
+            tdef: TypeDef::new().fallible().object::<(), Kind>(map! { (): Kind::all() }),
+        }
+
+        collision_overwrite {
+            args: func_args![value: value!({ "foo": 1, "bar": 2 }), from: "foo", to: "bar"],
+            want: Ok(value!({ "bar": 1 })),
+            tdef: TypeDef::new().fallible().object::<(), Kind>(map! { (): Kind::all() }),
+        }
+
+        collision_error {
+            args: func_args![value: value!({ "foo": 1, "bar": 2 }), from: "foo", to: "bar", overwrite: false],
+            want: Err(r#"path ".bar" already exists"#),
+            tdef: TypeDef::new().fallible().object::<(), Kind>(map! { (): Kind::all() }),
+        }
+    ];
+}
@@ -0,0 +1,172 @@
+use crate::util;
+use vrl::prelude::*;
+
+#[derive(Clone, Copy, Debug)]
+pub struct RenameKeys;
+
+impl Function for RenameKeys {
+    fn identifier(&self) -> &'static str {
+        "rename_keys"
+    }
+
+    fn is_pure(&self) -> bool {
+        true
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[
+            Parameter {
+                keyword: "value",
+                kind: kind::OBJECT,
+                required: true,
+            },
+            Parameter {
+                keyword: "mapping",
+                kind: kind::OBJECT,
+                required: true,
+            },
+            Parameter {
+                keyword: "overwrite",
+                kind: kind::BOOLEAN,
+                required: false,
+            },
+        ]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "rename fields",
+                source: r#"rename_keys!({ "foo": 1, "bar": 2 }, { "foo": "baz", "bar": "qux" })"#,
+                result: Ok(r#"{ "baz": 1, "qux": 2 }"#),
+            },
+            Example {
+                title: "swap fields",
+                source: r#"rename_keys!({ "foo": 1, "bar": 2 }, { "foo": "bar", "bar": "foo" })"#,
+                result: Ok(r#"{ "bar": 1, "foo": 2 }"#),
+            },
+            Example {
+                title: "collision",
+                source: r#"rename_keys!({ "foo": 1, "bar": 2 }, { "foo": "bar" }, overwrite: false)"#,
+                result: Err(
+                    r#"function call error for "rename_keys" at (0:72): path ".bar" already exists"#,
+                ),
+            },
+        ]
+    }
+
+    fn compile(&self, mut arguments: ArgumentList) -> Compiled {
+        let value = arguments.required("value");
+        let mapping = arguments.required("mapping");
+        let overwrite = arguments
+            .optional("overwrite")
+            .unwrap_or_else(|| expr!(true));
+
+        Ok(Box::new(RenameKeysFn {
+            value,
+            mapping,
+            overwrite,
+        }))
+    }
+}
+
+#[derive(Debug, Clone)]
+struct RenameKeysFn {
+    value: Box<dyn Expression>,
+    mapping: Box<dyn Expression>,
+    overwrite: Box<dyn Expression>,
+}
+
+impl Expression for RenameKeysFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let mut value: Value = self.value.resolve(ctx)?.try_object()?.into();
+        let overwrite = self.overwrite.resolve(ctx)?.try_boolean()?;
+
+        let renames = self
+            .mapping
+            .resolve(ctx)?
+            .try_object()?
+            .into_iter()
+            .map(|(from, to)| {
+                let from = util::parse_path(&from)?;
+                let to = util::parse_path(&String::from_utf8_lossy(&to.try_bytes()?))?;
+
+                Ok((from, to))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        util::rename_paths(&mut value, &renames, overwrite)?;
+
+        Ok(value)
+    }
+
+    fn type_def(&self, _: &state::Compiler) -> TypeDef {
+        TypeDef::new()
+            .fallible()
+            .object::<(), Kind>(map! { (): Kind::all() })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    test_function![
+        rename_keys => RenameKeys;
+
+        bulk {
+            args: func_args![
+                value: value!({ "foo": 1, "bar": 2, "baz": 3 }),
+                mapping: value!({ "foo": "new_foo", "bar": "new_bar" }),
+            ],
+            want: Ok(value!({ "new_foo": 1, "new_bar": 2, "baz": 3 })),
+            tdef: TypeDef::new().fallible().object::<(), Kind>(map! { (): Kind::all() }),
+        }
+
+        swap {
+            args: func_args![
+                value: value!({ "foo": 1, "bar": 2 }),
+                mapping: value!({ "foo": "bar", "bar": "foo" }),
+            ],
+            want: Ok(value!({ "foo": 2, "bar": 1 })),
+            tdef: TypeDef::new().fallible().object::<(), Kind>(map! { (): Kind::all() }),
+        }
+
+        nested {
+            args: func_args![
+                value: value!({ "foo": { "bar": 1 }, "baz": 2 }),
+                mapping: value!({ "foo.bar": "qux", "baz": "foo.baz" }),
+            ],
+            want: Ok(value!({ "foo": { "baz": 2 }, "qux": 1 })),
+            tdef: TypeDef::new().fallible().object::<(), Kind>(map! { (): Kind::all() }),
+        }
+
+        collision_overwrite {
+            args: func_args![
+                value: value!({ "foo": 1, "bar": 2 }),
+                mapping: value!({ "foo": "bar" }),
+            ],
+            want: Ok(value!({ "bar": 1 })),
+            tdef: TypeDef::new().fallible().object::<(), Kind>(map! { (): Kind::all() }),
+        }
+
+        collision_error {
+            args: func_args![
+                value: value!({ "foo": 1, "bar": 2 }),
+                mapping: value!({ "foo": "bar" }),
+                overwrite: false,
+            ],
+            want: Err(r#"path ".bar" already exists"#),
+            tdef: TypeDef::new().fallible().object::<(), Kind>(map! { (): Kind::all() }),
+        }
+
+        invalid_mapping {
+            args: func_args![
+                value: value!({ "foo": 1 }),
+                mapping: value!({ "foo": 2 }),
+            ],
+            want: Err(r#"expected "string", got "integer""#),
+            tdef: TypeDef::new().fallible().object::<(), Kind>(map! { (): Kind::all() }),
+        }
+    ];
+}
@@ -0,0 +1,184 @@
+use crate::template_util;
+use vrl::prelude::*;
+
+#[derive(Clone, Copy, Debug)]
+pub struct Render;
+
+impl Function for Render {
+    fn identifier(&self) -> &'static str {
+        "render"
+    }
+
+    fn is_pure(&self) -> bool {
+        true
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[
+            Parameter {
+                keyword: "template",
+                kind: kind::BYTES | kind::OBJECT,
+                required: true,
+            },
+            Parameter {
+                keyword: "variables",
+                kind: kind::OBJECT,
+                required: true,
+            },
+        ]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "compiled template",
+                source: r#"render!(compile_template!("{{ method }} {{ path }}"), { "method": "GET", "path": "/" })"#,
+                result: Ok("GET /"),
+            },
+            Example {
+                title: "string template",
+                source: r#"render!("Hello, {{ user.name }}!", { "user": { "name": "foo" } })"#,
+                result: Ok("Hello, foo!"),
+            },
+            Example {
+                title: "missing variable",
+                source: r#"render!("[{{ level }}]", {})"#,
+                result: Ok("[]"),
+            },
+        ]
+    }
+
+    fn compile(&self, mut arguments: ArgumentList) -> Compiled {
+        let template = arguments.required("template");
+        let variables = arguments.required("variables");
+
+        Ok(Box::new(RenderFn {
+            template,
+            variables,
+        }))
+    }
+}
+
+#[derive(Debug, Clone)]
+struct RenderFn {
+    template: Box<dyn Expression>,
+    variables: Box<dyn Expression>,
+}
+
+impl Expression for RenderFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let template = self.template.resolve(ctx)?;
+        let variables = self.variables.resolve(ctx)?;
+
+        // Compiled templates are rendered as they are, strings are compiled
+        // first.
+        let rendered = match template {
+            Value::Object(compiled) => match compiled.get("parts") {
+                Some(Value::Array(parts)) => template_util::render(parts, &variables)?,
+                _ => return Err("invalid compiled template".into()),
+            },
+            template => {
+                let parts = template_util::parse(&template.try_bytes_utf8_lossy()?)?;
+                template_util::render(&parts, &variables)?
+            }
+        };
+
+        Ok(rendered.into())
+    }
+
+    fn type_def(&self, _: &state::Compiler) -> TypeDef {
+        TypeDef::new().fallible().bytes()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{TimeZone, Utc};
+
+    test_function![
+        render => Render;
+
+        string_template {
+            args: func_args![
+                template: "{{ user.name }} has {{ user.emails.1 }} and {{ count }} more",
+                variables: value!({ "user": { "name": "foo", "emails": ["a@example.com", "b@example.com"] }, "count": 2 }),
+            ],
+            want: Ok("foo has b@example.com and 2 more"),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+
+        compiled_template {
+            args: func_args![
+                template: value!({ "template": "{{ a }}-{{ b }}", "parts": [["a"], "-", ["b"]] }),
+                variables: value!({ "a": 1, "b": true }),
+            ],
+            want: Ok("1-true"),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+
+        missing_and_null {
+            args: func_args![
+                template: "[{{ missing }}][{{ nothing }}][{{ count.0 }}]",
+                variables: value!({ "nothing": null, "count": 1 }),
+            ],
+            want: Ok("[][][]"),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+
+        structured_values {
+            args: func_args![
+                template: "{{ tags }} {{ user }}",
+                variables: value!({ "tags": ["a", "b"], "user": { "id": 1 } }),
+            ],
+            want: Ok(r#"["a","b"] {"id":1}"#),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+
+        timestamp {
+            args: func_args![
+                template: "at {{ timestamp }}",
+                variables: map!["timestamp": Value::from(Utc.ymd(2021, 2, 3).and_hms(4, 5, 6))],
+            ],
+            want: Ok("at 2021-02-03T04:05:06Z"),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+
+        invalid_string_template {
+            args: func_args![template: "{{ name", variables: value!({ "name": "foo" })],
+            want: Err("unable to compile template: unclosed placeholder at position 0"),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+
+        invalid_compiled_template {
+            args: func_args![template: value!({ "parts": [1] }), variables: value!({})],
+            want: Err("invalid compiled template"),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+    ];
+
+    #[test]
+    fn compiled_renders_identically() {
+        let parts = template_util::parse("{{ method }} {{ path }} {{ status }}").unwrap();
+        let compiled = Value::from(map!["parts": Value::from(parts)]);
+        let variables = value!({ "method": "GET", "path": "/", "status": 200 });
+
+        let mut target = value!({});
+        let mut runtime_state = vrl::state::Runtime::default();
+        let mut ctx = Context::new(&mut target, &mut runtime_state);
+
+        let func = RenderFn {
+            template: compiled.into_expression(),
+            variables: variables.clone().into_expression(),
+        };
+        let string = RenderFn {
+            template: expr!("{{ method }} {{ path }} {{ status }}"),
+            variables: variables.into_expression(),
+        };
+
+        for _ in 0..3 {
+            assert_eq!(func.resolve(&mut ctx), Ok("GET / 200".into()));
+        }
+        assert_eq!(func.resolve(&mut ctx), string.resolve(&mut ctx));
+    }
+}
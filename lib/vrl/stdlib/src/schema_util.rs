@@ -0,0 +1,194 @@
+//! Inference and merging of schemas describing the structure of values, in
+//! a subset of [JSON Schema](https://json-schema.org).
+//!
+//! Schemas are objects with a `type`, either the name of a type or an array
+//! of names. Objects have `properties`, the schemas of their fields, and the
+//! `required` fields they always have, arrays have the schema of their
+//! `items`, and timestamps are strings with a `date-time` `format`.
+
+use std::collections::{BTreeMap, BTreeSet};
+use vrl::Value;
+
+type Schema = BTreeMap<String, Value>;
+
+/// Infers the schema of the value. The items of arrays are described by the
+/// merged schema of all of them.
+#[cfg(feature = "infer_schema")]
+pub(crate) fn infer(value: &Value) -> Schema {
+    let mut schema = Schema::new();
+
+    let kind = match value {
+        Value::Null => "null",
+        Value::Boolean(_) => "boolean",
+        Value::Integer(_) => "integer",
+        Value::Float(_) => "number",
+        Value::Bytes(_) | Value::Regex(_) => "string",
+        Value::Timestamp(_) => {
+            schema.insert("format".to_owned(), "date-time".into());
+            "string"
+        }
+        Value::Object(object) => {
+            let properties = object
+                .iter()
+                .map(|(key, value)| (key.clone(), Value::from(infer(value))))
+                .collect::<BTreeMap<_, _>>();
+            let required = object.keys().cloned().map(Value::from).collect::<Vec<_>>();
+
+            schema.insert("properties".to_owned(), properties.into());
+            schema.insert("required".to_owned(), required.into());
+            "object"
+        }
+        Value::Array(array) => {
+            let mut items = array.iter().map(infer);
+
+            if let Some(first) = items.next() {
+                // Inferred schemas are always valid.
+                let merged = items
+                    .try_fold(first, |merged, items| merge(&merged, &items))
+                    .expect("valid schemas");
+                schema.insert("items".to_owned(), merged.into());
+            }
+
+            "array"
+        }
+    };
+
+    schema.insert("type".to_owned(), kind.into());
+    schema
+}
+
+/// Merges the schemas into one that accepts the values of either. Types are
+/// widened, the properties of objects are merged, and only the fields that
+/// both require stay required.
+pub(crate) fn merge(a: &Schema, b: &Schema) -> Result<Schema, String> {
+    let a_types = types(a)?;
+    let b_types = types(b)?;
+
+    let mut types = a_types.union(&b_types).cloned().collect::<BTreeSet<_>>();
+    if types.contains("number") {
+        types.remove("integer");
+    }
+
+    let mut schema = Schema::new();
+    schema.insert(
+        "type".to_owned(),
+        match types.len() {
+            1 => types.into_iter().next().expect("one type").into(),
+            _ => types
+                .into_iter()
+                .map(Value::from)
+                .collect::<Vec<_>>()
+                .into(),
+        },
+    );
+
+    let properties = match (properties(a)?, properties(b)?) {
+        (Some(a), Some(b)) => {
+            let mut properties = a.clone();
+            for (key, b) in b {
+                let merged = match properties.get(key) {
+                    Some(a) => merge(schema_of(a)?, schema_of(b)?)?.into(),
+                    None => b.clone(),
+                };
+                properties.insert(key.clone(), merged);
+            }
+
+            Some(properties)
+        }
+        (properties, None) | (None, properties) => properties.cloned(),
+    };
+    if let Some(properties) = properties {
+        schema.insert("properties".to_owned(), properties.into());
+    }
+
+    // Fields are only required of objects, so when a single schema is of
+    // objects, its required fields are kept.
+    let required = match (a_types.contains("object"), b_types.contains("object")) {
+        (true, true) => {
+            let b = required(b)?;
+            Some(
+                required(a)?
+                    .into_iter()
+                    .filter(|field| b.contains(field))
+                    .collect::<Vec<_>>(),
+            )
+        }
+        (true, false) => Some(required(a)?),
+        (false, true) => Some(required(b)?),
+        (false, false) => None,
+    };
+    if let Some(required) = required {
+        let required = required.into_iter().map(Value::from).collect::<Vec<_>>();
+        schema.insert("required".to_owned(), required.into());
+    }
+
+    let items: Option<Value> = match (a.get("items"), b.get("items")) {
+        (Some(a), Some(b)) => Some(merge(schema_of(a)?, schema_of(b)?)?.into()),
+        (items, None) | (None, items) => items.cloned(),
+    };
+    if let Some(items) = items {
+        schema.insert("items".to_owned(), items);
+    }
+
+    // Like required fields, formats are only of strings.
+    let format = match (a_types.contains("string"), b_types.contains("string")) {
+        (true, true) if a.get("format") == b.get("format") => a.get("format"),
+        (true, false) => a.get("format"),
+        (false, true) => b.get("format"),
+        _ => None,
+    };
+    if let Some(format) = format {
+        schema.insert("format".to_owned(), format.clone());
+    }
+
+    Ok(schema)
+}
+
+fn schema_of(value: &Value) -> Result<&Schema, String> {
+    match value {
+        Value::Object(schema) => Ok(schema),
+        _ => Err("invalid schema: expected an object".to_owned()),
+    }
+}
+
+fn types(schema: &Schema) -> Result<BTreeSet<String>, String> {
+    let invalid = || r#"invalid schema: "type" must be a string or an array of strings"#.to_owned();
+
+    match schema.get("type") {
+        Some(Value::Bytes(kind)) => Ok(vec![String::from_utf8_lossy(kind).into_owned()]
+            .into_iter()
+            .collect()),
+        Some(Value::Array(kinds)) if !kinds.is_empty() => kinds
+            .iter()
+            .map(|kind| match kind {
+                Value::Bytes(kind) => Ok(String::from_utf8_lossy(kind).into_owned()),
+                _ => Err(invalid()),
+            })
+            .collect(),
+        _ => Err(invalid()),
+    }
+}
+
+fn properties(schema: &Schema) -> Result<Option<&BTreeMap<String, Value>>, String> {
+    match schema.get("properties") {
+        Some(Value::Object(properties)) => Ok(Some(properties)),
+        Some(_) => Err(r#"invalid schema: "properties" must be an object"#.to_owned()),
+        None => Ok(None),
+    }
+}
+
+fn required(schema: &Schema) -> Result<Vec<String>, String> {
+    let invalid = || r#"invalid schema: "required" must be an array of strings"#.to_owned();
+
+    match schema.get("required") {
+        Some(Value::Array(fields)) => fields
+            .iter()
+            .map(|field| match field {
+                Value::Bytes(field) => Ok(String::from_utf8_lossy(field).into_owned()),
+                _ => Err(invalid()),
+            })
+            .collect(),
+        Some(_) => Err(invalid()),
+        None => Ok(vec![]),
+    }
+}
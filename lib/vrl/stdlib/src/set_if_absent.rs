@@ -0,0 +1,135 @@
+use crate::util;
+use vrl::prelude::*;
+
+#[derive(Clone, Copy, Debug)]
+pub struct SetIfAbsent;
+
+impl Function for SetIfAbsent {
+    fn identifier(&self) -> &'static str {
+        "set_if_absent"
+    }
+
+    fn is_pure(&self) -> bool {
+        true
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[
+            Parameter {
+                keyword: "value",
+                kind: kind::OBJECT,
+                required: true,
+            },
+            Parameter {
+                keyword: "path",
+                kind: kind::BYTES,
+                required: true,
+            },
+            Parameter {
+                keyword: "new",
+                kind: kind::ANY,
+                required: true,
+            },
+        ]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "absent field",
+                source: r#"set_if_absent!({ "foo": 1 }, "bar", 2)"#,
+                result: Ok(r#"{ "bar": 2, "foo": 1 }"#),
+            },
+            Example {
+                title: "present field",
+                source: r#"set_if_absent!({ "foo": 1 }, "foo", 2)"#,
+                result: Ok(r#"{ "foo": 1 }"#),
+            },
+            Example {
+                title: "nested path",
+                source: r#"set_if_absent!({}, "foo.bar", true)"#,
+                result: Ok(r#"{ "foo": { "bar": true } }"#),
+            },
+        ]
+    }
+
+    fn compile(&self, mut arguments: ArgumentList) -> Compiled {
+        let value = arguments.required("value");
+        let path = arguments.required("path");
+        let new = arguments.required("new");
+
+        Ok(Box::new(SetIfAbsentFn { value, path, new }))
+    }
+}
+
+#[derive(Debug, Clone)]
+struct SetIfAbsentFn {
+    value: Box<dyn Expression>,
+    path: Box<dyn Expression>,
+    new: Box<dyn Expression>,
+}
+
+impl Expression for SetIfAbsentFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let mut value: Value = self.value.resolve(ctx)?.try_object()?.into();
+        let path = self.path.resolve(ctx)?;
+        let path = util::parse_path(&path.try_bytes_utf8_lossy()?)?;
+
+        if value.get_by_path(&path).is_none() {
+            value.insert_by_path(&path, self.new.resolve(ctx)?);
+        }
+
+        Ok(value)
+    }
+
+    fn type_def(&self, _: &state::Compiler) -> TypeDef {
+        TypeDef::new()
+            .fallible()
+            .object::<(), Kind>(map! { (): Kind::all() })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    test_function![
+        set_if_absent => SetIfAbsent;
+
+        absent {
+            args: func_args![value: value!({ "foo": 1 }), path: "bar", new: 2],
+            want: Ok(value!({ "foo": 1, "bar": 2 })),
+            tdef: TypeDef::new().fallible().object::<(), Kind>(map! { (): Kind::all() }),
+        }
+
+        present {
+            args: func_args![value: value!({ "foo": 1 }), path: "foo", new: 2],
+            want: Ok(value!({ "foo": 1 })),
+            tdef: TypeDef::new().fallible().object::<(), Kind>(map! { (): Kind::all() }),
+        }
+
+        present_null {
+            args: func_args![value: value!({ "foo": null }), path: "foo", new: 2],
+            want: Ok(value!({ "foo": null })),
+            tdef: TypeDef::new().fallible().object::<(), Kind>(map! { (): Kind::all() }),
+        }
+
+        deep_path {
+            args: func_args![value: value!({ "foo": { "baz": true } }), path: "foo.bar.qux", new: "x"],
+            want: Ok(value!({ "foo": { "bar": { "qux": "x" }, "baz": true } })),
+            tdef: TypeDef::new().fallible().object::<(), Kind>(map! { (): Kind::all() }),
+        }
+
+        deep_path_present {
+            args: func_args![value: value!({ "foo": { "bar": [1, 2] } }), path: "foo.bar[1]", new: 3],
+            want: Ok(value!({ "foo": { "bar": [1, 2] } })),
+            tdef: TypeDef::new().fallible().object::<(), Kind>(map! { (): Kind::all() }),
+        }
+
+        invalid_path {
+            args: func_args![value: value!({}), path: "foo..bar", new: 1],
+            want: Err(r#"invalid path "foo..bar""#),
+            tdef: TypeDef::new().fallible().object::<(), Kind>(map! { (): Kind::all() }),
+        }
+    ];
+}
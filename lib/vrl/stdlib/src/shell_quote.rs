@@ -0,0 +1,115 @@
+use vrl::prelude::*;
+
+#[derive(Clone, Copy, Debug)]
+pub struct ShellQuote;
+
+impl Function for ShellQuote {
+    fn identifier(&self) -> &'static str {
+        "shell_quote"
+    }
+
+    fn is_pure(&self) -> bool {
+        true
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[Parameter {
+            keyword: "value",
+            kind: kind::BYTES,
+            required: true,
+        }]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "safe",
+                source: r#"shell_quote("/var/log/app.log")"#,
+                result: Ok("/var/log/app.log"),
+            },
+            Example {
+                title: "quoted",
+                source: r#"shell_quote("it's here")"#,
+                result: Ok(r#"s''it'\''s here''"#),
+            },
+        ]
+    }
+
+    fn compile(&self, mut arguments: ArgumentList) -> Compiled {
+        let value = arguments.required("value");
+
+        Ok(Box::new(ShellQuoteFn { value }))
+    }
+}
+
+#[derive(Debug, Clone)]
+struct ShellQuoteFn {
+    value: Box<dyn Expression>,
+}
+
+impl Expression for ShellQuoteFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let bytes = self.value.resolve(ctx)?.try_bytes()?;
+        let input = String::from_utf8_lossy(&bytes);
+
+        Ok(quote(&input).into())
+    }
+
+    fn type_def(&self, _: &state::Compiler) -> TypeDef {
+        TypeDef::new().infallible().bytes()
+    }
+}
+
+/// Quotes the string so a POSIX shell interprets it as a single word.
+///
+/// Strings consisting only of characters without special meaning are returned
+/// as-is, all others are wrapped in single quotes, in which only the single
+/// quote itself needs escaping.
+fn quote(input: &str) -> String {
+    let is_safe = |c: char| c.is_ascii_alphanumeric() || "_-+=%@:,./".contains(c);
+
+    if !input.is_empty() && input.chars().all(is_safe) {
+        return input.to_owned();
+    }
+
+    format!("'{}'", input.replace('\'', r#"'\''"#))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    test_function![
+        shell_quote => ShellQuote;
+
+        safe {
+            args: func_args![value: "--output=/tmp/a.log"],
+            want: Ok("--output=/tmp/a.log"),
+            tdef: TypeDef::new().infallible().bytes(),
+        }
+
+        empty {
+            args: func_args![value: ""],
+            want: Ok("''"),
+            tdef: TypeDef::new().infallible().bytes(),
+        }
+
+        spaces {
+            args: func_args![value: "hello world"],
+            want: Ok("'hello world'"),
+            tdef: TypeDef::new().infallible().bytes(),
+        }
+
+        quotes {
+            args: func_args![value: r#"say "it's" now"#],
+            want: Ok(r#"'say "it'\''s" now'"#),
+            tdef: TypeDef::new().infallible().bytes(),
+        }
+
+        metacharacters {
+            args: func_args![value: "$(rm -rf /); `id` | *"],
+            want: Ok("'$(rm -rf /); `id` | *'"),
+            tdef: TypeDef::new().infallible().bytes(),
+        }
+    ];
+}
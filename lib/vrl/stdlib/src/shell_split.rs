@@ -0,0 +1,118 @@
+use vrl::prelude::*;
+
+#[derive(Clone, Copy, Debug)]
+pub struct ShellSplit;
+
+impl Function for ShellSplit {
+    fn identifier(&self) -> &'static str {
+        "shell_split"
+    }
+
+    fn is_pure(&self) -> bool {
+        true
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[Parameter {
+            keyword: "value",
+            kind: kind::BYTES,
+            required: true,
+        }]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "quotes and escapes",
+                source: r#"shell_split!(s'grep -i "connection refused" /var/log/app\ 1.log')"#,
+                result: Ok(r#"["grep", "-i", "connection refused", "/var/log/app 1.log"]"#),
+            },
+            Example {
+                title: "unterminated quote",
+                source: r#"shell_split!(s'echo "foo')"#,
+                result: Err(
+                    r#"function call error for "shell_split" at (0:26): unable to split value: unterminated quote or trailing escape"#,
+                ),
+            },
+        ]
+    }
+
+    fn compile(&self, mut arguments: ArgumentList) -> Compiled {
+        let value = arguments.required("value");
+
+        Ok(Box::new(ShellSplitFn { value }))
+    }
+}
+
+#[derive(Debug, Clone)]
+struct ShellSplitFn {
+    value: Box<dyn Expression>,
+}
+
+impl Expression for ShellSplitFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let bytes = self.value.resolve(ctx)?.try_bytes()?;
+        let input = String::from_utf8_lossy(&bytes);
+
+        shlex::split(&input)
+            .map(|words| {
+                words
+                    .into_iter()
+                    .map(Value::from)
+                    .collect::<Vec<_>>()
+                    .into()
+            })
+            .ok_or_else(|| "unable to split value: unterminated quote or trailing escape".into())
+    }
+
+    fn type_def(&self, _: &state::Compiler) -> TypeDef {
+        TypeDef::new()
+            .fallible()
+            .array_mapped::<(), Kind>(map! { (): Kind::Bytes })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    test_function![
+        shell_split => ShellSplit;
+
+        quotes_and_escapes {
+            args: func_args![value: r#"a "b c" d\ e"#],
+            want: Ok(value!(["a", "b c", "d e"])),
+            tdef: TypeDef::new().fallible().array_mapped::<(), Kind>(map! { (): Kind::Bytes }),
+        }
+
+        single_quotes {
+            args: func_args![value: r#"echo 'it'\''s' "a \"b\"""#],
+            want: Ok(value!(["echo", "it's", r#"a "b""#])),
+            tdef: TypeDef::new().fallible().array_mapped::<(), Kind>(map! { (): Kind::Bytes }),
+        }
+
+        whitespace {
+            args: func_args![value: "  a\t\tb\n c  "],
+            want: Ok(value!(["a", "b", "c"])),
+            tdef: TypeDef::new().fallible().array_mapped::<(), Kind>(map! { (): Kind::Bytes }),
+        }
+
+        empty {
+            args: func_args![value: ""],
+            want: Ok(value!([])),
+            tdef: TypeDef::new().fallible().array_mapped::<(), Kind>(map! { (): Kind::Bytes }),
+        }
+
+        unterminated_quote {
+            args: func_args![value: "a 'b c"],
+            want: Err("unable to split value: unterminated quote or trailing escape"),
+            tdef: TypeDef::new().fallible().array_mapped::<(), Kind>(map! { (): Kind::Bytes }),
+        }
+
+        trailing_escape {
+            args: func_args![value: r#"a b\"#],
+            want: Err("unable to split value: unterminated quote or trailing escape"),
+            tdef: TypeDef::new().fallible().array_mapped::<(), Kind>(map! { (): Kind::Bytes }),
+        }
+    ];
+}
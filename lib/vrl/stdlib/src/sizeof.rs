@@ -0,0 +1,108 @@
+use vrl::prelude::*;
+
+#[derive(Clone, Copy, Debug)]
+pub struct Sizeof;
+
+impl Function for Sizeof {
+    fn identifier(&self) -> &'static str {
+        "sizeof"
+    }
+
+    fn is_pure(&self) -> bool {
+        true
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[Parameter {
+            keyword: "value",
+            kind: kind::ANY,
+            required: true,
+        }]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "string",
+                source: r#"sizeof("foobar") > sizeof("foo")"#,
+                result: Ok("true"),
+            },
+            Example {
+                title: "object",
+                source: r#"sizeof({ "foo": [1, 2, 3] }) > sizeof({})"#,
+                result: Ok("true"),
+            },
+        ]
+    }
+
+    fn compile(&self, mut arguments: ArgumentList) -> Compiled {
+        let value = arguments.required("value");
+
+        Ok(Box::new(SizeofFn { value }))
+    }
+}
+
+#[derive(Clone, Debug)]
+struct SizeofFn {
+    value: Box<dyn Expression>,
+}
+
+impl Expression for SizeofFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?;
+
+        Ok((value.size_estimate() as i64).into())
+    }
+
+    fn type_def(&self, _: &state::Compiler) -> TypeDef {
+        TypeDef::new().infallible().integer()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    test_function![
+        sizeof => Sizeof;
+
+        null {
+            args: func_args![value: value!(null)],
+            want: Ok(std::mem::size_of::<Value>() as i64),
+            tdef: TypeDef::new().infallible().integer(),
+        }
+
+        empty_object {
+            args: func_args![value: value!({})],
+            want: Ok(std::mem::size_of::<Value>() as i64),
+            tdef: TypeDef::new().infallible().integer(),
+        }
+
+        string {
+            args: func_args![value: "foobar"],
+            want: Ok(std::mem::size_of::<Value>() as i64 + 6),
+            tdef: TypeDef::new().infallible().integer(),
+        }
+
+        nested {
+            args: func_args![value: value!({ "foo": [1, "bar"] })],
+            want: Ok(value!({ "foo": [1, "bar"] }).size_estimate() as i64),
+            tdef: TypeDef::new().infallible().integer(),
+        }
+    ];
+
+    #[test]
+    fn grows_with_structure() {
+        let sizes = [
+            value!({}),
+            value!({ "foo": "bar" }),
+            value!({ "foo": "bar", "baz": [1, 2, 3] }),
+            value!({ "foo": "bar", "baz": [1, 2, 3], "qux": { "quux": "a longer string" } }),
+        ]
+        .iter()
+        .map(Value::size_estimate)
+        .collect::<Vec<_>>();
+
+        assert!(sizes.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+}
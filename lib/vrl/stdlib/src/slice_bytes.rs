@@ -0,0 +1,157 @@
+use vrl::prelude::*;
+
+#[derive(Clone, Copy, Debug)]
+pub struct SliceBytes;
+
+impl Function for SliceBytes {
+    fn identifier(&self) -> &'static str {
+        "slice_bytes"
+    }
+
+    fn is_pure(&self) -> bool {
+        true
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[
+            Parameter {
+                keyword: "value",
+                kind: kind::BYTES,
+                required: true,
+            },
+            Parameter {
+                keyword: "start",
+                kind: kind::INTEGER,
+                required: true,
+            },
+            Parameter {
+                keyword: "end",
+                kind: kind::INTEGER,
+                required: false,
+            },
+        ]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "start..end",
+                source: r#"slice_bytes("foobar", 2, 4)"#,
+                result: Ok("ob"),
+            },
+            Example {
+                title: "negative start",
+                source: r#"slice_bytes("foobar", -3)"#,
+                result: Ok("bar"),
+            },
+        ]
+    }
+
+    fn compile(&self, mut arguments: ArgumentList) -> Compiled {
+        let value = arguments.required("value");
+        let start = arguments.required("start");
+        let end = arguments.optional("end");
+
+        Ok(Box::new(SliceBytesFn { value, start, end }))
+    }
+}
+
+#[derive(Debug, Clone)]
+struct SliceBytesFn {
+    value: Box<dyn Expression>,
+    start: Box<dyn Expression>,
+    end: Option<Box<dyn Expression>>,
+}
+
+impl Expression for SliceBytesFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?.try_bytes()?;
+        let start = self.start.resolve(ctx)?.try_integer()?;
+        let end = match &self.end {
+            Some(expr) => Some(expr.resolve(ctx)?.try_integer()?),
+            None => None,
+        };
+
+        // Negative indices count from the end, and indices out of range are
+        // clamped, rather than failing as with `slice`.
+        let len = value.len() as i64;
+        let index = |index: i64| {
+            let index = match index {
+                index if index < 0 => (index + len).max(0),
+                index => index.min(len),
+            };
+
+            index as usize
+        };
+
+        let start = index(start);
+        let end = end.map_or(value.len(), index);
+
+        if end <= start {
+            return Ok(Bytes::new().into());
+        }
+
+        Ok(value.slice(start..end).into())
+    }
+
+    fn type_def(&self, _: &state::Compiler) -> TypeDef {
+        TypeDef::new().infallible().bytes()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    test_function![
+        slice_bytes => SliceBytes;
+
+        start {
+            args: func_args![value: "foobar", start: 3],
+            want: Ok("bar"),
+            tdef: TypeDef::new().infallible().bytes(),
+        }
+
+        start_end {
+            args: func_args![value: "foobar", start: 2, end: 4],
+            want: Ok("ob"),
+            tdef: TypeDef::new().infallible().bytes(),
+        }
+
+        negative_start {
+            args: func_args![value: "foobar", start: -3],
+            want: Ok("bar"),
+            tdef: TypeDef::new().infallible().bytes(),
+        }
+
+        negative_end {
+            args: func_args![value: "foobar", start: 1, end: -1],
+            want: Ok("ooba"),
+            tdef: TypeDef::new().infallible().bytes(),
+        }
+
+        negative_start_end {
+            args: func_args![value: "foobar", start: -4, end: -2],
+            want: Ok("ob"),
+            tdef: TypeDef::new().infallible().bytes(),
+        }
+
+        out_of_range {
+            args: func_args![value: "foobar", start: -10, end: 10],
+            want: Ok("foobar"),
+            tdef: TypeDef::new().infallible().bytes(),
+        }
+
+        end_before_start {
+            args: func_args![value: "foobar", start: 4, end: 2],
+            want: Ok(""),
+            tdef: TypeDef::new().infallible().bytes(),
+        }
+
+        binary {
+            args: func_args![value: Bytes::from_static(b"\x00\xff\xfe\x01"), start: 1, end: 3],
+            want: Ok(Bytes::from_static(b"\xff\xfe")),
+            tdef: TypeDef::new().infallible().bytes(),
+        }
+    ];
+}
@@ -0,0 +1,155 @@
+use crate::util;
+use vrl::prelude::*;
+
+#[derive(Clone, Copy, Debug)]
+pub struct SnakeCaseKeys;
+
+impl Function for SnakeCaseKeys {
+    fn identifier(&self) -> &'static str {
+        "snake_case_keys"
+    }
+
+    fn is_pure(&self) -> bool {
+        true
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[
+            Parameter {
+                keyword: "value",
+                kind: kind::OBJECT | kind::ARRAY,
+                required: true,
+            },
+            Parameter {
+                keyword: "overwrite",
+                kind: kind::BOOLEAN,
+                required: false,
+            },
+        ]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "nested object",
+                source: r#"snake_case_keys!({ "userId": 1, "HTTPServer": { "maxConnections": 2 } })"#,
+                result: Ok(r#"{ "http_server": { "max_connections": 2 }, "user_id": 1 }"#),
+            },
+            Example {
+                title: "collision",
+                source: r#"snake_case_keys!({ "userId": 1, "user_id": 2 }, overwrite: false)"#,
+                result: Err(
+                    r#"function call error for "snake_case_keys" at (0:65): keys "userId" and "user_id" both convert to "user_id""#,
+                ),
+            },
+        ]
+    }
+
+    fn compile(&self, mut arguments: ArgumentList) -> Compiled {
+        let value = arguments.required("value");
+        let overwrite = arguments
+            .optional("overwrite")
+            .unwrap_or_else(|| expr!(true));
+
+        Ok(Box::new(SnakeCaseKeysFn { value, overwrite }))
+    }
+}
+
+#[derive(Debug, Clone)]
+struct SnakeCaseKeysFn {
+    value: Box<dyn Expression>,
+    overwrite: Box<dyn Expression>,
+}
+
+impl Expression for SnakeCaseKeysFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?;
+        let overwrite = self.overwrite.resolve(ctx)?.try_boolean()?;
+
+        Ok(util::convert_keys(value, &snake_case, overwrite)?)
+    }
+
+    fn type_def(&self, state: &state::Compiler) -> TypeDef {
+        let td = self.value.type_def(state);
+
+        if td.is_array() {
+            TypeDef::new()
+                .fallible()
+                .array_mapped::<(), Kind>(map! { (): Kind::all() })
+        } else {
+            TypeDef::new()
+                .fallible()
+                .object::<(), Kind>(map! { (): Kind::all() })
+        }
+    }
+}
+
+/// Converts the key to `snake_case`, leaving keys without any word untouched.
+fn snake_case(key: &str) -> String {
+    let words = util::identifier_words(key);
+
+    if words.is_empty() {
+        return key.to_owned();
+    }
+
+    words
+        .iter()
+        .map(|word| word.to_lowercase())
+        .collect::<Vec<_>>()
+        .join("_")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    test_function![
+        snake_case_keys => SnakeCaseKeys;
+
+        flat {
+            args: func_args![value: value!({
+                "userId": 1,
+                "UserName": 2,
+                "HTTPServer": 3,
+                "user-agent": 4,
+                "already_snake": 5,
+                "version2Count": 6,
+                "_": 7,
+            })],
+            want: Ok(value!({
+                "user_id": 1,
+                "user_name": 2,
+                "http_server": 3,
+                "user_agent": 4,
+                "already_snake": 5,
+                "version2_count": 6,
+                "_": 7,
+            })),
+            tdef: TypeDef::new().fallible().object::<(), Kind>(map! { (): Kind::all() }),
+        }
+
+        deeply_nested {
+            args: func_args![value: value!({ "outerKey": { "middleKey": { "innerKey": "camelCase" } } })],
+            want: Ok(value!({ "outer_key": { "middle_key": { "inner_key": "camelCase" } } })),
+            tdef: TypeDef::new().fallible().object::<(), Kind>(map! { (): Kind::all() }),
+        }
+
+        array_of_objects {
+            args: func_args![value: value!([{ "firstName": "a" }, [{ "lastName": "b" }], "otherValue"])],
+            want: Ok(value!([{ "first_name": "a" }, [{ "last_name": "b" }], "otherValue"])),
+            tdef: TypeDef::new().fallible().array_mapped::<(), Kind>(map! { (): Kind::all() }),
+        }
+
+        collision_overwrite {
+            args: func_args![value: value!({ "user_id": 1, "userId": 2 })],
+            want: Ok(value!({ "user_id": 1 })),
+            tdef: TypeDef::new().fallible().object::<(), Kind>(map! { (): Kind::all() }),
+        }
+
+        collision_error {
+            args: func_args![value: value!({ "user_id": 1, "userId": 2 }), overwrite: false],
+            want: Err(r#"keys "userId" and "user_id" both convert to "user_id""#),
+            tdef: TypeDef::new().fallible().object::<(), Kind>(map! { (): Kind::all() }),
+        }
+    ];
+}
@@ -0,0 +1,190 @@
+use crate::util;
+use vrl::prelude::*;
+
+#[derive(Clone, Copy, Debug)]
+pub struct Soundex;
+
+impl Function for Soundex {
+    fn identifier(&self) -> &'static str {
+        "soundex"
+    }
+
+    fn is_pure(&self) -> bool {
+        true
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[
+            Parameter {
+                keyword: "value",
+                kind: kind::BYTES,
+                required: true,
+            },
+            Parameter {
+                keyword: "ignore_invalid",
+                kind: kind::BOOLEAN,
+                required: false,
+            },
+        ]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "name",
+                source: r#"soundex!("Robert")"#,
+                result: Ok("R163"),
+            },
+            Example {
+                title: "ignore invalid",
+                source: r#"soundex!("O'Brien", ignore_invalid: true)"#,
+                result: Ok("O165"),
+            },
+        ]
+    }
+
+    fn compile(&self, mut arguments: ArgumentList) -> Compiled {
+        let value = arguments.required("value");
+        let ignore_invalid = arguments.optional("ignore_invalid").unwrap_or(expr!(false));
+
+        Ok(Box::new(SoundexFn {
+            value,
+            ignore_invalid,
+        }))
+    }
+}
+
+#[derive(Debug, Clone)]
+struct SoundexFn {
+    value: Box<dyn Expression>,
+    ignore_invalid: Box<dyn Expression>,
+}
+
+impl Expression for SoundexFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?;
+        let ignore_invalid = self.ignore_invalid.resolve(ctx)?.try_boolean()?;
+        let letters = util::phonetic_letters(&value.try_bytes_utf8_lossy()?, ignore_invalid)?;
+
+        Ok(soundex(&letters).into())
+    }
+
+    fn type_def(&self, _: &state::Compiler) -> TypeDef {
+        TypeDef::new().fallible().bytes()
+    }
+}
+
+/// Returns the American Soundex code of the uppercase letters, or an empty
+/// string if there are none.
+fn soundex(letters: &[u8]) -> String {
+    let (first, rest) = match letters.split_first() {
+        Some(split) => split,
+        None => return String::new(),
+    };
+
+    let mut code = String::with_capacity(4);
+    code.push(*first as char);
+
+    let mut last = digit(*first);
+    for &letter in rest {
+        if code.len() == 4 {
+            break;
+        }
+
+        match digit(letter) {
+            // `H` and `W` neither get a digit, nor separate the letters around
+            // them, so the same digit on both sides is only coded once.
+            None => {}
+            Some(digit) => {
+                if digit != '0' && Some(digit) != last {
+                    code.push(digit);
+                }
+
+                last = Some(digit);
+            }
+        }
+    }
+
+    while code.len() < 4 {
+        code.push('0');
+    }
+
+    code
+}
+
+/// Vowels get a `0`, which isn't coded but separates the letters around them.
+fn digit(letter: u8) -> Option<char> {
+    match letter {
+        b'A' | b'E' | b'I' | b'O' | b'U' | b'Y' => Some('0'),
+        b'B' | b'F' | b'P' | b'V' => Some('1'),
+        b'C' | b'G' | b'J' | b'K' | b'Q' | b'S' | b'X' | b'Z' => Some('2'),
+        b'D' | b'T' => Some('3'),
+        b'L' => Some('4'),
+        b'M' | b'N' => Some('5'),
+        b'R' => Some('6'),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    test_function![
+        soundex => Soundex;
+
+        robert {
+            args: func_args![value: "Robert"],
+            want: Ok("R163"),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+
+        rupert {
+            args: func_args![value: "rupert"],
+            want: Ok("R163"),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+
+        separated_by_h_or_w {
+            args: func_args![value: "Ashcraft"],
+            want: Ok("A261"),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+
+        separated_by_vowel {
+            args: func_args![value: "Tymczak"],
+            want: Ok("T522"),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+
+        same_digit_as_first_letter {
+            args: func_args![value: "Pfister"],
+            want: Ok("P236"),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+
+        padded {
+            args: func_args![value: "Lee"],
+            want: Ok("L000"),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+
+        empty {
+            args: func_args![value: ""],
+            want: Ok(""),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+
+        invalid_character {
+            args: func_args![value: "O'Brien"],
+            want: Err(r#"invalid character "'" in "O'Brien""#),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+
+        ignore_invalid {
+            args: func_args![value: "O'Brien", ignore_invalid: true],
+            want: Ok("O165"),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+    ];
+}
@@ -0,0 +1,590 @@
+use chrono::SecondsFormat;
+use std::fmt;
+use vrl::{
+    diagnostic::{Label, Span},
+    prelude::*,
+};
+
+#[derive(Debug)]
+pub enum Error {
+    Incomplete {
+        format: String,
+        position: usize,
+    },
+    UnknownConversion {
+        format: String,
+        conversion: String,
+        position: usize,
+    },
+    UnsupportedPrecision {
+        format: String,
+        conversion: String,
+        position: usize,
+    },
+    TooLarge {
+        format: String,
+        conversion: String,
+        position: usize,
+    },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Incomplete { format, position } => write!(
+                f,
+                r#"invalid format "{}": incomplete conversion at position {}"#,
+                format, position
+            ),
+            Error::UnknownConversion {
+                format,
+                conversion,
+                position,
+            } => write!(
+                f,
+                r#"invalid format "{}": unknown conversion "{}" at position {}"#,
+                format, conversion, position
+            ),
+            Error::UnsupportedPrecision {
+                format,
+                conversion,
+                position,
+            } => write!(
+                f,
+                r#"invalid format "{}": conversion "{}" at position {} doesn't support a precision"#,
+                format, conversion, position
+            ),
+            Error::TooLarge {
+                format,
+                conversion,
+                position,
+            } => write!(
+                f,
+                r#"invalid format "{}": conversion "{}" at position {} has a width or precision larger than {}"#,
+                format, conversion, position, MAX_WIDTH
+            ),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl DiagnosticError for Error {
+    fn code(&self) -> usize {
+        109
+    }
+
+    fn labels(&self) -> Vec<Label> {
+        vec![Label::primary(self.to_string(), Span::default())]
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Sprintf;
+
+impl Function for Sprintf {
+    fn identifier(&self) -> &'static str {
+        "sprintf"
+    }
+
+    fn is_pure(&self) -> bool {
+        true
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[
+            Parameter {
+                keyword: "format",
+                kind: kind::BYTES,
+                required: true,
+            },
+            Parameter {
+                keyword: "args",
+                kind: kind::ARRAY,
+                required: true,
+            },
+        ]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "float precision",
+                source: r#"sprintf!("took %.2fms", [12.3456])"#,
+                result: Ok("took 12.35ms"),
+            },
+            Example {
+                title: "width",
+                source: r#"sprintf!("[%-5s|%5d|%05d]", ["ab", 42, -42])"#,
+                result: Ok("[ab   |   42|-0042]"),
+            },
+            Example {
+                title: "hexadecimal",
+                source: r#"sprintf!("0x%08x", [48879])"#,
+                result: Ok("0x0000beef"),
+            },
+            Example {
+                title: "too few arguments",
+                source: r#"sprintf!("%s: %d%%", ["cpu"])"#,
+                result: Err(
+                    r#"function call error for "sprintf" at (0:29): expected 2 arguments, got 1"#,
+                ),
+            },
+        ]
+    }
+
+    fn compile(&self, mut arguments: ArgumentList) -> Compiled {
+        let format = arguments.required("format");
+        let args = arguments.required("args");
+
+        // A literal format is parsed once, here, so that an invalid one is
+        // reported at compile-time.
+        let format = match format.as_value() {
+            Some(format) => {
+                let format = format
+                    .try_bytes_utf8_lossy()
+                    .map_err(|e| Box::new(e) as Box<dyn DiagnosticError>)?;
+                let pieces =
+                    parse_format(&format).map_err(|e| Box::new(e) as Box<dyn DiagnosticError>)?;
+
+                SprintfFormat::Parsed(pieces)
+            }
+            None => SprintfFormat::Dynamic(format),
+        };
+
+        Ok(Box::new(SprintfFn { format, args }))
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Piece {
+    Literal(String),
+    Conversion(Conversion),
+}
+
+/// A `%[flags][width][.precision]kind` conversion.
+#[derive(Debug, Clone)]
+struct Conversion {
+    /// The `-` flag, to pad on the right.
+    left: bool,
+
+    /// The `0` flag, to pad numbers with zeros after their sign.
+    zero: bool,
+    width: usize,
+    precision: Option<usize>,
+    kind: ConversionKind,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum ConversionKind {
+    Decimal,
+    Float,
+    Hex,
+    String,
+}
+
+impl ConversionKind {
+    fn as_str(self) -> &'static str {
+        match self {
+            ConversionKind::Decimal => "%d",
+            ConversionKind::Float => "%f",
+            ConversionKind::Hex => "%x",
+            ConversionKind::String => "%s",
+        }
+    }
+}
+
+/// The largest width or precision of a conversion, so that a format can't
+/// make the output arbitrarily large.
+const MAX_WIDTH: usize = 1024;
+
+fn parse_format(format: &str) -> std::result::Result<Vec<Piece>, Error> {
+    let mut pieces = vec![];
+    let mut literal = String::new();
+    let mut chars = format.char_indices().peekable();
+
+    while let Some((position, c)) = chars.next() {
+        if c != '%' {
+            literal.push(c);
+            continue;
+        }
+
+        if let Some((_, '%')) = chars.peek() {
+            chars.next();
+            literal.push('%');
+            continue;
+        }
+
+        let mut left = false;
+        let mut zero = false;
+        while let Some(&(_, c)) = chars.peek() {
+            match c {
+                '-' => left = true,
+                '0' => zero = true,
+                _ => break,
+            }
+            chars.next();
+        }
+
+        let width = parse_number(&mut chars).unwrap_or(0);
+        let precision = match chars.peek() {
+            Some((_, '.')) => {
+                chars.next();
+                Some(parse_number(&mut chars).unwrap_or(0))
+            }
+            _ => None,
+        };
+
+        let (end, kind) = match chars.next() {
+            Some((end, 'd')) => (end, ConversionKind::Decimal),
+            Some((end, 'f')) => (end, ConversionKind::Float),
+            Some((end, 'x')) => (end, ConversionKind::Hex),
+            Some((end, 's')) => (end, ConversionKind::String),
+            Some((end, c)) => {
+                return Err(Error::UnknownConversion {
+                    format: format.to_owned(),
+                    conversion: format[position..end + c.len_utf8()].to_owned(),
+                    position,
+                })
+            }
+            None => {
+                return Err(Error::Incomplete {
+                    format: format.to_owned(),
+                    position,
+                })
+            }
+        };
+
+        if precision.is_some() && matches!(kind, ConversionKind::Decimal | ConversionKind::Hex) {
+            return Err(Error::UnsupportedPrecision {
+                format: format.to_owned(),
+                conversion: format[position..=end].to_owned(),
+                position,
+            });
+        }
+
+        if width > MAX_WIDTH || precision.map_or(false, |precision| precision > MAX_WIDTH) {
+            return Err(Error::TooLarge {
+                format: format.to_owned(),
+                conversion: format[position..=end].to_owned(),
+                position,
+            });
+        }
+
+        if !literal.is_empty() {
+            pieces.push(Piece::Literal(std::mem::take(&mut literal)));
+        }
+        pieces.push(Piece::Conversion(Conversion {
+            left,
+            zero,
+            width,
+            precision,
+            kind,
+        }));
+    }
+
+    if !literal.is_empty() {
+        pieces.push(Piece::Literal(literal));
+    }
+
+    Ok(pieces)
+}
+
+fn parse_number(chars: &mut std::iter::Peekable<std::str::CharIndices<'_>>) -> Option<usize> {
+    let mut number = None;
+    while let Some(digit) = chars.peek().and_then(|(_, c)| c.to_digit(10)) {
+        chars.next();
+        number = Some(
+            number
+                .unwrap_or(0usize)
+                .saturating_mul(10)
+                .saturating_add(digit as usize),
+        );
+    }
+
+    number
+}
+
+fn render(pieces: &[Piece], args: &[Value]) -> std::result::Result<String, String> {
+    let conversions = pieces
+        .iter()
+        .filter(|piece| matches!(piece, Piece::Conversion(_)))
+        .count();
+    if conversions != args.len() {
+        return Err(format!(
+            "expected {} argument{}, got {}",
+            conversions,
+            if conversions == 1 { "" } else { "s" },
+            args.len()
+        ));
+    }
+
+    let mut formatted = String::new();
+    let mut args = args.iter().enumerate();
+
+    for piece in pieces {
+        let conversion = match piece {
+            Piece::Literal(literal) => {
+                formatted.push_str(literal);
+                continue;
+            }
+            Piece::Conversion(conversion) => conversion,
+        };
+
+        let (index, arg) = args.next().expect("arguments counted");
+        let invalid = |expected: &str| {
+            format!(
+                r#"argument at index {} of "{}" must be {}, got {}"#,
+                index,
+                conversion.kind.as_str(),
+                expected,
+                arg.kind()
+            )
+        };
+
+        let string = match (conversion.kind, arg) {
+            (ConversionKind::Decimal, Value::Integer(integer)) => integer.to_string(),
+            (ConversionKind::Decimal, _) => return Err(invalid("an integer")),
+            (ConversionKind::Hex, Value::Integer(integer)) => format!("{:x}", integer),
+            (ConversionKind::Hex, _) => return Err(invalid("an integer")),
+            (ConversionKind::Float, Value::Float(float)) => {
+                format!(
+                    "{:.*}",
+                    conversion.precision.unwrap_or(6),
+                    float.into_inner()
+                )
+            }
+            (ConversionKind::Float, Value::Integer(integer)) => {
+                format!("{:.*}", conversion.precision.unwrap_or(6), *integer as f64)
+            }
+            (ConversionKind::Float, _) => return Err(invalid("a float or an integer")),
+            (ConversionKind::String, arg) => {
+                let string = match arg {
+                    Value::Bytes(bytes) => String::from_utf8_lossy(bytes).into_owned(),
+                    Value::Integer(integer) => integer.to_string(),
+                    Value::Float(float) => float.to_string(),
+                    Value::Boolean(boolean) => boolean.to_string(),
+                    Value::Timestamp(timestamp) => {
+                        timestamp.to_rfc3339_opts(SecondsFormat::AutoSi, true)
+                    }
+                    Value::Null => String::new(),
+                    _ => return Err(invalid("coercible into a string")),
+                };
+
+                // As in C, the precision of a string is its maximum length.
+                match conversion.precision {
+                    Some(precision) => string.chars().take(precision).collect(),
+                    None => string,
+                }
+            }
+        };
+
+        pad(&mut formatted, &string, conversion);
+    }
+
+    Ok(formatted)
+}
+
+fn pad(formatted: &mut String, string: &str, conversion: &Conversion) {
+    let padding = conversion.width.saturating_sub(string.chars().count());
+
+    if conversion.left {
+        formatted.push_str(string);
+        formatted.extend(std::iter::repeat(' ').take(padding));
+    } else if conversion.zero && conversion.kind != ConversionKind::String {
+        let digits = string.trim_start_matches('-');
+        formatted.push_str(&string[..string.len() - digits.len()]);
+        formatted.extend(std::iter::repeat('0').take(padding));
+        formatted.push_str(digits);
+    } else {
+        formatted.extend(std::iter::repeat(' ').take(padding));
+        formatted.push_str(string);
+    }
+}
+
+#[derive(Debug, Clone)]
+enum SprintfFormat {
+    Parsed(Vec<Piece>),
+    Dynamic(Box<dyn Expression>),
+}
+
+#[derive(Debug, Clone)]
+struct SprintfFn {
+    format: SprintfFormat,
+    args: Box<dyn Expression>,
+}
+
+impl Expression for SprintfFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let args = self.args.resolve(ctx)?.try_array()?;
+
+        let formatted = match &self.format {
+            SprintfFormat::Parsed(pieces) => render(pieces, &args)?,
+            SprintfFormat::Dynamic(expr) => {
+                let format_string = expr.resolve(ctx)?;
+                let format_string = format_string.try_bytes_utf8_lossy()?;
+                let pieces = parse_format(&format_string).map_err(|e| e.to_string())?;
+
+                render(&pieces, &args)?
+            }
+        };
+
+        Ok(formatted.into())
+    }
+
+    fn type_def(&self, _: &state::Compiler) -> TypeDef {
+        // The number and types of the arguments are only known at runtime.
+        TypeDef::new().fallible().bytes()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    test_function![
+        sprintf => Sprintf;
+
+        string_and_decimal {
+            args: func_args![format: "%s returned %d", args: value!(["GET /", 200])],
+            want: Ok("GET / returned 200"),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+
+        float_default_precision {
+            args: func_args![format: "%f", args: value!([1.5])],
+            want: Ok("1.500000"),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+
+        float_precision {
+            args: func_args![format: "%.2f", args: value!([1.23456])],
+            want: Ok("1.23"),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+
+        float_width_and_precision {
+            args: func_args![format: "[%8.3f|%-8.1f|%08.2f]", args: value!([1.23456, 2.26, (-1.5)])],
+            want: Ok("[   1.235|2.3     |-0001.50]"),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+
+        float_from_integer {
+            args: func_args![format: "%.1f", args: value!([3])],
+            want: Ok("3.0"),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+
+        decimal_width {
+            args: func_args![format: "[%5d|%-5d|%05d]", args: value!([42, 42, (-42)])],
+            want: Ok("[   42|42   |-0042]"),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+
+        hex {
+            args: func_args![format: "%x %04x", args: value!([255, 10])],
+            want: Ok("ff 000a"),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+
+        string_width_and_precision {
+            args: func_args![format: "[%6s|%.3s|%s]", args: value!(["ab", "abcdef", null])],
+            want: Ok("[    ab|abc|]"),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+
+        string_coerced {
+            args: func_args![format: "%s %s %s", args: value!([1, 2.5, true])],
+            want: Ok("1 2.5 true"),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+
+        percent {
+            args: func_args![format: "%d%% of %s", args: value!([50, "disk"])],
+            want: Ok("50% of disk"),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+
+        no_conversions {
+            args: func_args![format: "100%%", args: value!([])],
+            want: Ok("100%"),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+
+        too_few_arguments {
+            args: func_args![format: "%s: %d", args: value!(["cpu"])],
+            want: Err("expected 2 arguments, got 1"),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+
+        too_many_arguments {
+            args: func_args![format: "%d", args: value!([1, 2])],
+            want: Err("expected 1 argument, got 2"),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+
+        invalid_argument {
+            args: func_args![format: "%s: %d", args: value!(["cpu", 1.5])],
+            want: Err(r#"argument at index 1 of "%d" must be an integer, got "float""#),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+
+        unknown_conversion {
+            args: func_args![format: "%5q", args: value!([1])],
+            want: Err(r#"invalid format "%5q": unknown conversion "%5q" at position 0"#),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+
+        incomplete_conversion {
+            args: func_args![format: "50%", args: value!([])],
+            want: Err(r#"invalid format "50%": incomplete conversion at position 2"#),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+
+        unsupported_precision {
+            args: func_args![format: "%.2d", args: value!([1])],
+            want: Err(r#"invalid format "%.2d": conversion "%.2d" at position 0 doesn't support a precision"#),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+
+        maximum_width {
+            args: func_args![format: "%1024d", args: value!([1])],
+            want: Ok(format!("{:>1024}", 1)),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+
+        huge_width {
+            args: func_args![format: "%99999999999999d", args: value!([1])],
+            want: Err(r#"invalid format "%99999999999999d": conversion "%99999999999999d" at position 0 has a width or precision larger than 1024"#),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+
+        huge_precision {
+            args: func_args![format: "%.99999999999999999999f", args: value!([1.5])],
+            want: Err(r#"invalid format "%.99999999999999999999f": conversion "%.99999999999999999999f" at position 0 has a width or precision larger than 1024"#),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+    ];
+
+    #[test]
+    fn dynamic_format() {
+        let mut target = value!({});
+        let mut runtime_state = vrl::state::Runtime::default();
+        let mut ctx = Context::new(&mut target, &mut runtime_state);
+
+        let func = SprintfFn {
+            format: SprintfFormat::Dynamic(expr!("%s=%d")),
+            args: expr!(["status", 200]),
+        };
+        assert_eq!(func.resolve(&mut ctx), Ok("status=200".into()));
+
+        let func = SprintfFn {
+            format: SprintfFormat::Dynamic(expr!("%")),
+            args: expr!([]),
+        };
+        assert_eq!(
+            func.resolve(&mut ctx).map_err(|e| e.to_string()),
+            Err(r#"invalid format "%": incomplete conversion at position 0"#.to_owned())
+        );
+    }
+}
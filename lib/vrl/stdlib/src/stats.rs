@@ -0,0 +1,183 @@
+use std::collections::BTreeMap;
+use std::convert::TryFrom;
+use vrl::prelude::*;
+
+#[derive(Clone, Copy, Debug)]
+pub struct Stats;
+
+impl Function for Stats {
+    fn identifier(&self) -> &'static str {
+        "stats"
+    }
+
+    fn is_pure(&self) -> bool {
+        true
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[
+            Parameter {
+                keyword: "value",
+                kind: kind::ARRAY,
+                required: true,
+            },
+            Parameter {
+                keyword: "skip_non_numeric",
+                kind: kind::BOOLEAN,
+                required: false,
+            },
+        ]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "summary",
+                source: r#"stats!([3, 1, 4, 1, 5])"#,
+                result: Ok(r#"{ "count": 5, "max": 5, "mean": 2.8, "min": 1, "sum": 14 }"#),
+            },
+            Example {
+                title: "skip non-numeric",
+                source: r#"stats!([3, "n/a", 1.5], skip_non_numeric: true)"#,
+                result: Ok(r#"{ "count": 2, "max": 3, "mean": 2.25, "min": 1.5, "sum": 4.5 }"#),
+            },
+        ]
+    }
+
+    fn compile(&self, mut arguments: ArgumentList) -> Compiled {
+        let value = arguments.required("value");
+        let skip_non_numeric = arguments
+            .optional("skip_non_numeric")
+            .unwrap_or(expr!(false));
+
+        Ok(Box::new(StatsFn {
+            value,
+            skip_non_numeric,
+        }))
+    }
+}
+
+#[derive(Debug, Clone)]
+struct StatsFn {
+    value: Box<dyn Expression>,
+    skip_non_numeric: Box<dyn Expression>,
+}
+
+impl Expression for StatsFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let array = self.value.resolve(ctx)?.try_array()?;
+        let skip_non_numeric = self.skip_non_numeric.resolve(ctx)?.try_boolean()?;
+
+        let mut count: i64 = 0;
+        let mut sum = Value::Integer(0);
+        let mut min: Option<(f64, Value)> = None;
+        let mut max: Option<(f64, Value)> = None;
+
+        // The aggregates are all computed in a single pass over the array.
+        for (index, value) in array.into_iter().enumerate() {
+            if !value.is_integer() && !value.is_float() {
+                if skip_non_numeric {
+                    continue;
+                }
+
+                return Err(format!(
+                    "expected array of numbers, got {} at index {}",
+                    value.kind(),
+                    index
+                )
+                .into());
+            }
+
+            let number = f64::try_from(&value)?;
+            count += 1;
+
+            if min.as_ref().map_or(true, |(min, _)| number < *min) {
+                min = Some((number, value.clone()));
+            }
+            if max.as_ref().map_or(true, |(max, _)| number > *max) {
+                max = Some((number, value.clone()));
+            }
+
+            sum = sum.try_add(value)?;
+        }
+
+        let mean = match count {
+            0 => Value::Null,
+            count => (f64::try_from(&sum)? / count as f64).into(),
+        };
+
+        let mut stats = BTreeMap::new();
+        stats.insert("count".to_owned(), Value::from(count));
+        stats.insert("sum".to_owned(), sum);
+        stats.insert("min".to_owned(), min.map_or(Value::Null, |(_, min)| min));
+        stats.insert("max".to_owned(), max.map_or(Value::Null, |(_, max)| max));
+        stats.insert("mean".to_owned(), mean);
+
+        Ok(stats.into())
+    }
+
+    fn type_def(&self, _: &state::Compiler) -> TypeDef {
+        TypeDef::new().fallible().object(type_def())
+    }
+}
+
+fn type_def() -> BTreeMap<&'static str, TypeDef> {
+    map! {
+        "count": Kind::Integer,
+        "sum": Kind::Integer | Kind::Float,
+        "min": Kind::Integer | Kind::Float | Kind::Null,
+        "max": Kind::Integer | Kind::Float | Kind::Null,
+        "mean": Kind::Float | Kind::Null,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    test_function![
+        stats => Stats;
+
+        integers {
+            args: func_args![value: value!([3, 1, 4, 1, 5])],
+            want: Ok(value!({ "count": 5, "sum": 14, "min": 1, "max": 5, "mean": 2.8 })),
+            tdef: TypeDef::new().fallible().object(type_def()),
+        }
+
+        mixed {
+            args: func_args![value: value!([2.5, (-1), 0.5, 4])],
+            want: Ok(value!({ "count": 4, "sum": 6.0, "min": (-1), "max": 4, "mean": 1.5 })),
+            tdef: TypeDef::new().fallible().object(type_def()),
+        }
+
+        single {
+            args: func_args![value: value!([7])],
+            want: Ok(value!({ "count": 1, "sum": 7, "min": 7, "max": 7, "mean": 7.0 })),
+            tdef: TypeDef::new().fallible().object(type_def()),
+        }
+
+        empty {
+            args: func_args![value: value!([])],
+            want: Ok(value!({ "count": 0, "sum": 0, "min": null, "max": null, "mean": null })),
+            tdef: TypeDef::new().fallible().object(type_def()),
+        }
+
+        not_a_number {
+            args: func_args![value: value!([1, "2", 3])],
+            want: Err(r#"expected array of numbers, got "string" at index 1"#),
+            tdef: TypeDef::new().fallible().object(type_def()),
+        }
+
+        skip_non_numeric {
+            args: func_args![value: value!([3, "n/a", null, 1.5, [1]]), skip_non_numeric: true],
+            want: Ok(value!({ "count": 2, "sum": 4.5, "min": 1.5, "max": 3, "mean": 2.25 })),
+            tdef: TypeDef::new().fallible().object(type_def()),
+        }
+
+        skip_all {
+            args: func_args![value: value!(["a", "b"]), skip_non_numeric: true],
+            want: Ok(value!({ "count": 0, "sum": 0, "min": null, "max": null, "mean": null })),
+            tdef: TypeDef::new().fallible().object(type_def()),
+        }
+    ];
+}
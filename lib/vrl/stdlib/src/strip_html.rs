@@ -0,0 +1,104 @@
+use crate::html_util::{self, HtmlToken};
+use vrl::prelude::*;
+
+#[derive(Clone, Copy, Debug)]
+pub struct StripHtml;
+
+impl Function for StripHtml {
+    fn identifier(&self) -> &'static str {
+        "strip_html"
+    }
+
+    fn is_pure(&self) -> bool {
+        true
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[Parameter {
+            keyword: "value",
+            kind: kind::BYTES,
+            required: true,
+        }]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[Example {
+            title: "strip tags",
+            source: r#"strip_html("<p>Fish &amp; <b>chips</b></p>")"#,
+            result: Ok(r#""Fish & chips""#),
+        }]
+    }
+
+    fn compile(&self, mut arguments: ArgumentList) -> Compiled {
+        let value = arguments.required("value");
+
+        Ok(Box::new(StripHtmlFn { value }))
+    }
+}
+
+#[derive(Debug, Clone)]
+struct StripHtmlFn {
+    value: Box<dyn Expression>,
+}
+
+impl Expression for StripHtmlFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let bytes = self.value.resolve(ctx)?.try_bytes()?;
+        let input = String::from_utf8_lossy(&bytes);
+
+        Ok(strip_html(&input).into())
+    }
+
+    fn type_def(&self, _: &state::Compiler) -> TypeDef {
+        TypeDef::new().infallible().bytes()
+    }
+}
+
+fn strip_html(input: &str) -> String {
+    html_util::tokenize_html(input)
+        .into_iter()
+        .filter_map(|token| match token {
+            HtmlToken::Text(text) => Some(html_util::decode_html_entities(text)),
+            _ => None,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    test_function![
+        strip_html => StripHtml;
+
+        plain_text {
+            args: func_args![value: "foo bar"],
+            want: Ok("foo bar"),
+            tdef: TypeDef::new().infallible().bytes(),
+        }
+
+        entities {
+            args: func_args![value: "Fish &amp; chips &lt;3 &#169; &#x1F41F; &bogus; & more"],
+            want: Ok("Fish & chips <3 \u{a9} \u{1F41F} &bogus; & more"),
+            tdef: TypeDef::new().infallible().bytes(),
+        }
+
+        nested_tags {
+            args: func_args![value: r#"<div class="post"><p>Hello, <a href="/x?a=1&amp;b=2"><b>big</b> world</a>!</p><br/></div>"#],
+            want: Ok("Hello, big world!"),
+            tdef: TypeDef::new().infallible().bytes(),
+        }
+
+        comments_and_scripts {
+            args: func_args![value: "<!DOCTYPE html><!-- hidden --><style>p > a { color: red }</style>shown<script>if (a < b) { alert('<p>') }</SCRIPT>"],
+            want: Ok("shown"),
+            tdef: TypeDef::new().infallible().bytes(),
+        }
+
+        stray_brackets {
+            args: func_args![value: "1 < 2 and <3 but <b>bold</b> > all"],
+            want: Ok("1 < 2 and <3 but bold > all"),
+            tdef: TypeDef::new().infallible().bytes(),
+        }
+    ];
+}
@@ -8,6 +8,10 @@ impl Function for StripWhitespace {
         "strip_whitespace"
     }
 
+    fn is_pure(&self) -> bool {
+        true
+    }
+
     fn parameters(&self) -> &'static [Parameter] {
         &[Parameter {
             keyword: "value",
@@ -0,0 +1,138 @@
+//! Templates, in which `{{ path }}` placeholders are replaced by the values of
+//! variables.
+//!
+//! Paths are field names separated by dots, numbers indexing arrays, as in
+//! `{{ user.emails.0 }}`. A backslash before `{{` makes it literal.
+//!
+//! A compiled template is an object, so that it can be kept in a variable or
+//! a field like any other value. Its `parts` are the literal strings of the
+//! template and, between them, the paths of the placeholders as arrays of
+//! segments, so that rendering it only looks up the variables.
+
+use chrono::SecondsFormat;
+use std::collections::BTreeMap;
+use vrl::Value;
+
+/// Compiles the template into its object.
+#[cfg(feature = "compile_template")]
+pub(crate) fn compile(template: &str) -> Result<Value, String> {
+    let parts = parse(template)?;
+
+    let mut compiled = BTreeMap::new();
+    compiled.insert("template".to_owned(), template.into());
+    compiled.insert("parts".to_owned(), parts.into());
+
+    Ok(compiled.into())
+}
+
+pub(crate) fn parse(template: &str) -> Result<Vec<Value>, String> {
+    let mut parts = vec![];
+    let mut literal = String::new();
+    let mut rest = template;
+
+    while let Some(start) = rest.find("{{") {
+        if rest[..start].ends_with('\\') {
+            literal.push_str(&rest[..start - 1]);
+            literal.push_str("{{");
+            rest = &rest[start + 2..];
+            continue;
+        }
+
+        literal.push_str(&rest[..start]);
+
+        let position = template.len() - rest.len() + start;
+        let end = rest[start..].find("}}").ok_or_else(|| {
+            format!(
+                "unable to compile template: unclosed placeholder at position {}",
+                position
+            )
+        })?;
+        let placeholder = &rest[start..start + end + 2];
+
+        let segments = placeholder[2..placeholder.len() - 2]
+            .trim()
+            .split('.')
+            .map(|segment| {
+                if segment.is_empty()
+                    || segment
+                        .chars()
+                        .any(|c| c.is_whitespace() || c == '{' || c == '}')
+                {
+                    Err(format!(
+                        r#"unable to compile template: invalid placeholder "{}" at position {}"#,
+                        placeholder, position
+                    ))
+                } else {
+                    Ok(Value::from(segment))
+                }
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        if !literal.is_empty() {
+            parts.push(std::mem::take(&mut literal).into());
+        }
+        parts.push(segments.into());
+        rest = &rest[start + end + 2..];
+    }
+
+    literal.push_str(rest);
+    if !literal.is_empty() {
+        parts.push(literal.into());
+    }
+
+    Ok(parts)
+}
+
+/// Renders the parts of a template with the variables. Missing variables and
+/// `null` render as empty strings.
+pub(crate) fn render(parts: &[Value], variables: &Value) -> Result<String, String> {
+    let invalid = || "invalid compiled template".to_owned();
+    let mut rendered = String::new();
+
+    for part in parts {
+        match part {
+            Value::Bytes(literal) => rendered.push_str(&String::from_utf8_lossy(literal)),
+            Value::Array(segments) => {
+                let mut value = Some(variables);
+                for segment in segments {
+                    let segment = match segment {
+                        Value::Bytes(segment) => String::from_utf8_lossy(segment),
+                        _ => return Err(invalid()),
+                    };
+
+                    value = match value {
+                        Some(Value::Object(object)) => object.get(segment.as_ref()),
+                        Some(Value::Array(array)) => segment
+                            .parse::<usize>()
+                            .ok()
+                            .and_then(|index| array.get(index)),
+                        _ => None,
+                    };
+                }
+
+                if let Some(value) = value {
+                    push_value(&mut rendered, value);
+                }
+            }
+            _ => return Err(invalid()),
+        }
+    }
+
+    Ok(rendered)
+}
+
+/// Renders the value as `to_string` does, objects, arrays and regular
+/// expressions as JSON.
+fn push_value(rendered: &mut String, value: &Value) {
+    match value {
+        Value::Bytes(bytes) => rendered.push_str(&String::from_utf8_lossy(bytes)),
+        Value::Integer(integer) => rendered.push_str(&integer.to_string()),
+        Value::Float(float) => rendered.push_str(&float.to_string()),
+        Value::Boolean(boolean) => rendered.push_str(&boolean.to_string()),
+        Value::Timestamp(timestamp) => {
+            rendered.push_str(&timestamp.to_rfc3339_opts(SecondsFormat::AutoSi, true))
+        }
+        Value::Null => {}
+        value => rendered.push_str(&value.to_json_str()),
+    }
+}
@@ -0,0 +1,207 @@
+use chrono::{DateTime, Utc};
+use vrl::prelude::*;
+
+/// The units of the description, with their length in seconds, from the
+/// largest. Months and years are approximated as 30 and 365 days.
+const UNITS: &[(&str, i64)] = &[
+    ("year", 365 * 24 * 3600),
+    ("month", 30 * 24 * 3600),
+    ("day", 24 * 3600),
+    ("hour", 3600),
+    ("minute", 60),
+];
+
+#[derive(Clone, Copy, Debug)]
+pub struct TimeAgo;
+
+impl Function for TimeAgo {
+    fn identifier(&self) -> &'static str {
+        "time_ago"
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[
+            Parameter {
+                keyword: "value",
+                kind: kind::TIMESTAMP,
+                required: true,
+            },
+            Parameter {
+                keyword: "now",
+                kind: kind::TIMESTAMP,
+                required: false,
+            },
+        ]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "past",
+                source: r#"time_ago(t'2021-02-03T04:02:00Z', now: t'2021-02-03T04:05:06Z')"#,
+                result: Ok("3 minutes ago"),
+            },
+            Example {
+                title: "future",
+                source: r#"time_ago(t'2021-02-05T04:05:06Z', now: t'2021-02-03T04:05:06Z')"#,
+                result: Ok("in 2 days"),
+            },
+        ]
+    }
+
+    fn compile(&self, mut arguments: ArgumentList) -> Compiled {
+        let value = arguments.required("value");
+        let now = arguments.optional("now");
+
+        Ok(Box::new(TimeAgoFn { value, now }))
+    }
+}
+
+#[derive(Debug, Clone)]
+struct TimeAgoFn {
+    value: Box<dyn Expression>,
+    now: Option<Box<dyn Expression>>,
+}
+
+impl Expression for TimeAgoFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?.try_timestamp()?;
+        let now = match &self.now {
+            Some(now) => now.resolve(ctx)?.try_timestamp()?,
+            None => ctx.state().clock().now(),
+        };
+
+        Ok(time_ago(value, now).into())
+    }
+
+    fn type_def(&self, _: &state::Compiler) -> TypeDef {
+        TypeDef::new().infallible().bytes()
+    }
+}
+
+/// Describes the time relative to `now`, in the largest unit it's at least
+/// one of, rounded down.
+fn time_ago(value: DateTime<Utc>, now: DateTime<Utc>) -> String {
+    let seconds = (now - value).num_seconds();
+
+    let (unit, length) = match UNITS.iter().find(|(_, length)| seconds.abs() >= *length) {
+        Some(unit) => *unit,
+        None => return "just now".to_owned(),
+    };
+
+    let count = seconds.abs() / length;
+    let plural = if count == 1 { "" } else { "s" };
+
+    if seconds > 0 {
+        format!("{} {}{} ago", count, unit, plural)
+    } else {
+        format!("in {} {}{}", count, unit, plural)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Duration, TimeZone};
+    use vrl::clock::FixedClock;
+
+    fn now() -> DateTime<Utc> {
+        Utc.ymd(2021, 2, 3).and_hms(4, 5, 6)
+    }
+
+    test_function![
+        time_ago => TimeAgo;
+
+        just_now {
+            args: func_args![value: now() - Duration::seconds(59), now: now()],
+            want: Ok("just now"),
+            tdef: TypeDef::new().infallible().bytes(),
+        }
+
+        same_time {
+            args: func_args![value: now(), now: now()],
+            want: Ok("just now"),
+            tdef: TypeDef::new().infallible().bytes(),
+        }
+
+        just_now_future {
+            args: func_args![value: now() + Duration::seconds(30), now: now()],
+            want: Ok("just now"),
+            tdef: TypeDef::new().infallible().bytes(),
+        }
+
+        one_minute {
+            args: func_args![value: now() - Duration::seconds(60), now: now()],
+            want: Ok("1 minute ago"),
+            tdef: TypeDef::new().infallible().bytes(),
+        }
+
+        minutes {
+            args: func_args![value: now() - Duration::seconds(3599), now: now()],
+            want: Ok("59 minutes ago"),
+            tdef: TypeDef::new().infallible().bytes(),
+        }
+
+        one_hour {
+            args: func_args![value: now() - Duration::hours(1), now: now()],
+            want: Ok("1 hour ago"),
+            tdef: TypeDef::new().infallible().bytes(),
+        }
+
+        hours {
+            args: func_args![value: now() - Duration::seconds(24 * 3600 - 1), now: now()],
+            want: Ok("23 hours ago"),
+            tdef: TypeDef::new().infallible().bytes(),
+        }
+
+        days {
+            args: func_args![value: now() - Duration::days(29), now: now()],
+            want: Ok("29 days ago"),
+            tdef: TypeDef::new().infallible().bytes(),
+        }
+
+        months {
+            args: func_args![value: now() - Duration::days(364), now: now()],
+            want: Ok("12 months ago"),
+            tdef: TypeDef::new().infallible().bytes(),
+        }
+
+        years {
+            args: func_args![value: now() - Duration::days(3 * 365), now: now()],
+            want: Ok("3 years ago"),
+            tdef: TypeDef::new().infallible().bytes(),
+        }
+
+        future_minutes {
+            args: func_args![value: now() + Duration::minutes(3), now: now()],
+            want: Ok("in 3 minutes"),
+            tdef: TypeDef::new().infallible().bytes(),
+        }
+
+        future_day {
+            args: func_args![value: now() + Duration::hours(47), now: now()],
+            want: Ok("in 1 day"),
+            tdef: TypeDef::new().infallible().bytes(),
+        }
+
+        future_year {
+            args: func_args![value: now() + Duration::days(400), now: now()],
+            want: Ok("in 1 year"),
+            tdef: TypeDef::new().infallible().bytes(),
+        }
+    ];
+
+    #[test]
+    fn runtime_clock() {
+        let mut target = value!({});
+        let mut runtime_state = vrl::state::Runtime::default().with_clock(FixedClock(now()));
+        let mut ctx = Context::new(&mut target, &mut runtime_state);
+
+        let function = TimeAgoFn {
+            value: expr!((now() - Duration::days(2))),
+            now: None,
+        };
+
+        assert_eq!(function.resolve(&mut ctx), Ok("2 days ago".into()));
+    }
+}
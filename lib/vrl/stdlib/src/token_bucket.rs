@@ -0,0 +1,268 @@
+use std::collections::BTreeMap;
+use std::convert::TryFrom;
+use vrl::prelude::*;
+
+#[derive(Clone, Copy, Debug)]
+pub struct TokenBucket;
+
+impl Function for TokenBucket {
+    fn identifier(&self) -> &'static str {
+        "token_bucket"
+    }
+
+    fn is_pure(&self) -> bool {
+        true
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[
+            Parameter {
+                keyword: "state",
+                kind: kind::OBJECT | kind::NULL,
+                required: true,
+            },
+            Parameter {
+                keyword: "capacity",
+                kind: kind::INTEGER | kind::FLOAT,
+                required: true,
+            },
+            Parameter {
+                keyword: "refill_rate",
+                kind: kind::INTEGER | kind::FLOAT,
+                required: true,
+            },
+            Parameter {
+                keyword: "now",
+                kind: kind::TIMESTAMP,
+                required: true,
+            },
+        ]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "initial state",
+                source: r#"token_bucket!(null, 10, 1, t'2021-03-05T12:00:00Z')"#,
+                result: Ok(
+                    r#"{ "allowed": true, "state": { "last_refill": t'2021-03-05T12:00:00Z', "tokens": 9.0 } }"#,
+                ),
+            },
+            Example {
+                title: "refill",
+                source: r#"token_bucket!({ "tokens": 0.0, "last_refill": t'2021-03-05T12:00:00Z' }, 10, 2, t'2021-03-05T12:00:02Z')"#,
+                result: Ok(
+                    r#"{ "allowed": true, "state": { "last_refill": t'2021-03-05T12:00:02Z', "tokens": 3.0 } }"#,
+                ),
+            },
+        ]
+    }
+
+    fn compile(&self, mut arguments: ArgumentList) -> Compiled {
+        let state = arguments.required("state");
+        let capacity = arguments.required("capacity");
+        let refill_rate = arguments.required("refill_rate");
+        let now = arguments.required("now");
+
+        Ok(Box::new(TokenBucketFn {
+            state,
+            capacity,
+            refill_rate,
+            now,
+        }))
+    }
+}
+
+#[derive(Debug, Clone)]
+struct TokenBucketFn {
+    state: Box<dyn Expression>,
+    capacity: Box<dyn Expression>,
+    refill_rate: Box<dyn Expression>,
+    now: Box<dyn Expression>,
+}
+
+impl Expression for TokenBucketFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let state = self.state.resolve(ctx)?;
+        let capacity = f64::try_from(&self.capacity.resolve(ctx)?)?;
+        let refill_rate = f64::try_from(&self.refill_rate.resolve(ctx)?)?;
+        let now = self.now.resolve(ctx)?.try_timestamp()?;
+
+        if capacity <= 0.0 {
+            return Err(format!("capacity must be greater than 0, got {}", capacity).into());
+        }
+
+        if refill_rate < 0.0 {
+            return Err(format!("refill_rate must be at least 0, got {}", refill_rate).into());
+        }
+
+        // A missing or empty state is a new bucket, which starts full.
+        let tokens = match state {
+            Value::Null => capacity,
+            Value::Object(state) if state.is_empty() => capacity,
+            Value::Object(state) => {
+                let tokens = state
+                    .get("tokens")
+                    .ok_or(r#"invalid bucket state, missing "tokens""#)?;
+                let last_refill = state
+                    .get("last_refill")
+                    .ok_or(r#"invalid bucket state, missing "last_refill""#)?
+                    .clone()
+                    .try_timestamp()?;
+
+                // Time going backwards doesn't refill the bucket.
+                let elapsed = (now - last_refill)
+                    .to_std()
+                    .map_or(0.0, |elapsed| elapsed.as_secs_f64());
+
+                (f64::try_from(tokens)? + elapsed * refill_rate).min(capacity)
+            }
+            value => {
+                return Err(value::Error::Expected {
+                    got: value.kind(),
+                    expected: Kind::Object | Kind::Null,
+                }
+                .into())
+            }
+        };
+
+        let allowed = tokens >= 1.0;
+        let tokens = if allowed { tokens - 1.0 } else { tokens };
+
+        let mut state = BTreeMap::new();
+        state.insert("tokens".to_owned(), Value::from(tokens));
+        state.insert("last_refill".to_owned(), Value::from(now));
+
+        let mut result = BTreeMap::new();
+        result.insert("allowed".to_owned(), Value::from(allowed));
+        result.insert("state".to_owned(), Value::from(state));
+
+        Ok(result.into())
+    }
+
+    fn type_def(&self, _: &state::Compiler) -> TypeDef {
+        TypeDef::new().fallible().object(type_def())
+    }
+}
+
+fn type_def() -> BTreeMap<&'static str, TypeDef> {
+    map! {
+        "allowed": Kind::Boolean,
+        "state": TypeDef::new().object::<&'static str, Kind>(map! {
+            "tokens": Kind::Float,
+            "last_refill": Kind::Timestamp,
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{DateTime, Duration, TimeZone, Utc};
+
+    fn now() -> DateTime<Utc> {
+        Utc.ymd(2021, 3, 5).and_hms(12, 0, 0)
+    }
+
+    test_function![
+        token_bucket => TokenBucket;
+
+        initial_null_state {
+            args: func_args![state: value!(null), capacity: 10, refill_rate: 1, now: now()],
+            want: Ok(value!({
+                "allowed": true,
+                "state": { "tokens": 9.0, "last_refill": (now()) },
+            })),
+            tdef: TypeDef::new().fallible().object(type_def()),
+        }
+
+        initial_empty_state {
+            args: func_args![state: value!({}), capacity: 10, refill_rate: 1, now: now()],
+            want: Ok(value!({
+                "allowed": true,
+                "state": { "tokens": 9.0, "last_refill": (now()) },
+            })),
+            tdef: TypeDef::new().fallible().object(type_def()),
+        }
+
+        refill {
+            args: func_args![
+                state: value!({ "tokens": 0.0, "last_refill": (now()) }),
+                capacity: 10,
+                refill_rate: 2,
+                now: now() + Duration::milliseconds(2500),
+            ],
+            want: Ok(value!({
+                "allowed": true,
+                "state": { "tokens": 4.0, "last_refill": (now() + Duration::milliseconds(2500)) },
+            })),
+            tdef: TypeDef::new().fallible().object(type_def()),
+        }
+
+        refill_up_to_capacity {
+            args: func_args![
+                state: value!({ "tokens": 8, "last_refill": (now()) }),
+                capacity: 10,
+                refill_rate: 0.5,
+                now: now() + Duration::hours(1),
+            ],
+            want: Ok(value!({
+                "allowed": true,
+                "state": { "tokens": 9.0, "last_refill": (now() + Duration::hours(1)) },
+            })),
+            tdef: TypeDef::new().fallible().object(type_def()),
+        }
+
+        exhausted {
+            args: func_args![
+                state: value!({ "tokens": 0.25, "last_refill": (now()) }),
+                capacity: 10,
+                refill_rate: 0.5,
+                now: now() + Duration::seconds(1),
+            ],
+            want: Ok(value!({
+                "allowed": false,
+                "state": { "tokens": 0.75, "last_refill": (now() + Duration::seconds(1)) },
+            })),
+            tdef: TypeDef::new().fallible().object(type_def()),
+        }
+
+        clock_going_backwards {
+            args: func_args![
+                state: value!({ "tokens": 0.0, "last_refill": (now()) }),
+                capacity: 10,
+                refill_rate: 1,
+                now: now() - Duration::seconds(5),
+            ],
+            want: Ok(value!({
+                "allowed": false,
+                "state": { "tokens": 0.0, "last_refill": (now() - Duration::seconds(5)) },
+            })),
+            tdef: TypeDef::new().fallible().object(type_def()),
+        }
+
+        invalid_capacity {
+            args: func_args![state: value!(null), capacity: 0, refill_rate: 1, now: now()],
+            want: Err("capacity must be greater than 0, got 0"),
+            tdef: TypeDef::new().fallible().object(type_def()),
+        }
+
+        invalid_refill_rate {
+            args: func_args![state: value!(null), capacity: 10, refill_rate: -1, now: now()],
+            want: Err("refill_rate must be at least 0, got -1"),
+            tdef: TypeDef::new().fallible().object(type_def()),
+        }
+
+        invalid_state {
+            args: func_args![state: value!({ "tokens": 1.0 }), capacity: 10, refill_rate: 1, now: now()],
+            want: Err(r#"invalid bucket state, missing "last_refill""#),
+            tdef: TypeDef::new().fallible().object(type_def()),
+        }
+
+        invalid_state_kind {
+            args: func_args![state: "full", capacity: 10, refill_rate: 1, now: now()],
+            want: Err(r#"expected "object" or "null", got "string""#),
+            tdef: TypeDef::new().fallible().object(type_def()),
+        }
+    ];
+}
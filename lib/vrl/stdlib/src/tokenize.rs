@@ -0,0 +1,157 @@
+use vrl::prelude::*;
+
+#[derive(Clone, Copy, Debug)]
+pub struct Tokenize;
+
+impl Function for Tokenize {
+    fn identifier(&self) -> &'static str {
+        "tokenize"
+    }
+
+    fn is_pure(&self) -> bool {
+        true
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[
+            Parameter {
+                keyword: "value",
+                kind: kind::BYTES,
+                required: true,
+            },
+            Parameter {
+                keyword: "lowercase",
+                kind: kind::BOOLEAN,
+                required: false,
+            },
+            Parameter {
+                keyword: "stopwords",
+                kind: kind::ARRAY,
+                required: false,
+            },
+        ]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "words",
+                source: r#"tokenize!("The quick, brown fox!")"#,
+                result: Ok(r#"["The", "quick", "brown", "fox"]"#),
+            },
+            Example {
+                title: "lowercase without stopwords",
+                source: r#"tokenize!("The quick, brown fox!", lowercase: true, stopwords: ["the"])"#,
+                result: Ok(r#"["quick", "brown", "fox"]"#),
+            },
+        ]
+    }
+
+    fn compile(&self, mut arguments: ArgumentList) -> Compiled {
+        let value = arguments.required("value");
+        let lowercase = arguments.optional("lowercase").unwrap_or(expr!(false));
+        let stopwords = arguments.optional("stopwords").unwrap_or(expr!([]));
+
+        Ok(Box::new(TokenizeFn {
+            value,
+            lowercase,
+            stopwords,
+        }))
+    }
+}
+
+#[derive(Debug, Clone)]
+struct TokenizeFn {
+    value: Box<dyn Expression>,
+    lowercase: Box<dyn Expression>,
+    stopwords: Box<dyn Expression>,
+}
+
+impl Expression for TokenizeFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?;
+        let value = value.try_bytes_utf8_lossy()?;
+        let lowercase = self.lowercase.resolve(ctx)?.try_boolean()?;
+
+        // Stopwords are matched regardless of case.
+        let stopwords = self
+            .stopwords
+            .resolve(ctx)?
+            .try_array()?
+            .into_iter()
+            .map(|word| Ok(String::from_utf8_lossy(&word.try_bytes()?).to_lowercase()))
+            .collect::<std::result::Result<Vec<_>, ExpressionError>>()?;
+
+        let tokens = value
+            .split(|c: char| !c.is_alphanumeric())
+            .filter(|token| !token.is_empty())
+            .filter(|token| !stopwords.contains(&token.to_lowercase()))
+            .map(|token| {
+                if lowercase {
+                    token.to_lowercase().into()
+                } else {
+                    token.into()
+                }
+            })
+            .collect::<Vec<Value>>();
+
+        Ok(tokens.into())
+    }
+
+    fn type_def(&self, _: &state::Compiler) -> TypeDef {
+        TypeDef::new()
+            .fallible()
+            .array_mapped::<(), Kind>(map! { (): Kind::Bytes })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    test_function![
+        tokenize => Tokenize;
+
+        words {
+            args: func_args![value: "Hello,  World! It's 2021-05-01."],
+            want: Ok(value!(["Hello", "World", "It", "s", "2021", "05", "01"])),
+            tdef: TypeDef::new().fallible().array_mapped::<(), Kind>(map! { (): Kind::Bytes }),
+        }
+
+        unicode {
+            args: func_args![value: "Grüße aus Köln"],
+            want: Ok(value!(["Grüße", "aus", "Köln"])),
+            tdef: TypeDef::new().fallible().array_mapped::<(), Kind>(map! { (): Kind::Bytes }),
+        }
+
+        lowercase {
+            args: func_args![value: "The Quick FOX", lowercase: true],
+            want: Ok(value!(["the", "quick", "fox"])),
+            tdef: TypeDef::new().fallible().array_mapped::<(), Kind>(map! { (): Kind::Bytes }),
+        }
+
+        stopwords {
+            args: func_args![value: "The fox and THE hound", stopwords: value!(["the", "And"])],
+            want: Ok(value!(["fox", "hound"])),
+            tdef: TypeDef::new().fallible().array_mapped::<(), Kind>(map! { (): Kind::Bytes }),
+        }
+
+        invalid_stopword {
+            args: func_args![value: "foo", stopwords: value!([1])],
+            want: Err(r#"expected "string", got "integer""#),
+            tdef: TypeDef::new().fallible().array_mapped::<(), Kind>(map! { (): Kind::Bytes }),
+        }
+
+        empty {
+            args: func_args![value: ""],
+            want: Ok(value!([])),
+            tdef: TypeDef::new().fallible().array_mapped::<(), Kind>(map! { (): Kind::Bytes }),
+        }
+
+        punctuation_only {
+            args: func_args![value: " ,.!? "],
+            want: Ok(value!([])),
+            tdef: TypeDef::new().fallible().array_mapped::<(), Kind>(map! { (): Kind::Bytes }),
+        }
+    ];
+}
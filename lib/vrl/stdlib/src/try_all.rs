@@ -0,0 +1,202 @@
+use vrl::prelude::*;
+
+#[derive(Clone, Copy, Debug)]
+pub struct TryAll;
+
+impl Function for TryAll {
+    fn identifier(&self) -> &'static str {
+        "try_all"
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[Parameter {
+            keyword: "expressions",
+            kind: kind::ARRAY,
+            required: true,
+        }]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "first success",
+                source: r#"try_all!([parse_json("{"), to_int("12"), to_int("13")])"#,
+                result: Ok("12"),
+            },
+            Example {
+                title: "all failed",
+                source: r#"try_all!([to_int("foo"), parse_json("{")])"#,
+                result: Err(
+                    r#"function call error for "try_all" at (0:42): all 2 attempts failed: 1. function call error for "to_int" at (10:23): Invalid integer "foo": invalid digit found in string; 2. function call error for "parse_json" at (25:40): unable to parse json: EOF while parsing an object at line 1 column 1"#,
+                ),
+            },
+        ]
+    }
+
+    fn compile(&self, mut arguments: ArgumentList) -> Compiled {
+        let expressions = arguments.required_array("expressions")?;
+
+        Ok(Box::new(TryAllFn { expressions }))
+    }
+
+    fn handles_errors(&self) -> bool {
+        true
+    }
+}
+
+#[derive(Debug, Clone)]
+struct TryAllFn {
+    expressions: Vec<expression::Expr>,
+}
+
+impl Expression for TryAllFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        if self.expressions.is_empty() {
+            return Err("no expressions to try".into());
+        }
+
+        let mut errors = Vec::with_capacity(self.expressions.len());
+
+        for expression in &self.expressions {
+            match expression.resolve(ctx) {
+                Ok(value) => return Ok(value),
+                Err(err) => errors.push(err),
+            }
+        }
+
+        let message = errors
+            .iter()
+            .enumerate()
+            .map(|(i, err)| format!("{}. {}", i + 1, err.message))
+            .collect::<Vec<_>>()
+            .join("; ");
+
+        // The errors of the attempts are kept in the payload, for the embedder
+        // of the runtime to inspect.
+        let attempts = errors
+            .into_iter()
+            .map(|err| value!({ "error": (err.message), "code": (err.code as i64) }))
+            .collect::<Vec<_>>();
+
+        Err(ExpressionError {
+            message: format!("all {} attempts failed: {}", attempts.len(), message),
+            payload: Some(value!({ "attempts": (Value::Array(attempts)) })),
+            ..Default::default()
+        })
+    }
+
+    fn type_def(&self, state: &state::Compiler) -> TypeDef {
+        let mut type_def: Option<TypeDef> = None;
+
+        // The expressions after the first infallible one are never resolved.
+        for expression in &self.expressions {
+            let expression = expression.type_def(state);
+            let fallible = expression.is_fallible();
+
+            type_def = Some(match type_def {
+                Some(type_def) => type_def.merge(expression),
+                None => expression,
+            });
+
+            if !fallible {
+                return type_def.expect("merged").infallible();
+            }
+        }
+
+        type_def.unwrap_or_else(|| TypeDef::new().null()).fallible()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    test_function![
+        try_all => TryAll;
+
+        first {
+            args: func_args![expressions: value!([1, "foo"])],
+            want: Ok(1),
+            tdef: TypeDef::new().infallible().integer(),
+        }
+
+        empty {
+            args: func_args![expressions: value!([])],
+            want: Err("no expressions to try"),
+            tdef: TypeDef::new().fallible().null(),
+        }
+    ];
+
+    fn resolve(source: &str) -> vrl::RuntimeResult {
+        let program = vrl::compile(source, &crate::all()).unwrap();
+        let mut target = value!({});
+
+        vrl::Runtime::default().resolve(&mut target, &program)
+    }
+
+    #[test]
+    fn first_success() {
+        assert_eq!(
+            resolve(r#"try_all!([parse_json("{"), to_int("12"), to_int("13")])"#),
+            Ok(value!(12))
+        );
+        assert_eq!(
+            resolve(r#"try_all!([parse_json("[1]"), to_int("12")])"#),
+            Ok(value!([1]))
+        );
+    }
+
+    #[test]
+    fn stops_at_first_success() {
+        assert_eq!(
+            resolve(r#"try_all!([to_int("foo"), to_int("12"), abort("never")])"#),
+            Ok(value!(12))
+        );
+    }
+
+    #[test]
+    fn all_failed() {
+        let err =
+            resolve(r#"try_all!([to_int("foo"), parse_json("{"), abort("no")])"#).unwrap_err();
+
+        assert_eq!(
+            err.message(),
+            concat!(
+                r#"function call error for "try_all" at (0:55): all 3 attempts failed: "#,
+                r#"1. function call error for "to_int" at (10:23): Invalid integer "foo": invalid digit found in string; "#,
+                r#"2. function call error for "parse_json" at (25:40): unable to parse json: EOF while parsing an object at line 1 column 1; "#,
+                r#"3. function call error for "abort" at (42:53): no"#,
+            )
+        );
+        assert_eq!(err.code(), 705);
+        assert_eq!(
+            err.payload(),
+            Some(&value!({
+                "attempts": [
+                    {
+                        "error": r#"function call error for "to_int" at (10:23): Invalid integer "foo": invalid digit found in string"#,
+                        "code": 705,
+                    },
+                    {
+                        "error": r#"function call error for "parse_json" at (25:40): unable to parse json: EOF while parsing an object at line 1 column 1"#,
+                        "code": 705,
+                    },
+                    {
+                        "error": r#"function call error for "abort" at (42:53): no"#,
+                        "code": 700,
+                    },
+                ],
+            }))
+        );
+    }
+
+    #[test]
+    fn fallibility() {
+        // The call only fails if all of the expressions can.
+        let compile = |source| vrl::compile(source, &crate::all());
+
+        assert!(compile(r#".a = try_all([to_int(.a), "foo"])"#).is_ok());
+        assert!(compile(r#".a = try_all([to_int(.a), to_int(.b)])"#).is_err());
+        assert!(compile(r#".a = try_all!([to_int(.a), to_int(.b)])"#).is_ok());
+    }
+}
@@ -0,0 +1,184 @@
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use vrl::prelude::*;
+
+/// The upper bound of the `bits` option, which keeps the filter at 2 MiB.
+const MAX_BITS: i64 = 1 << 24;
+
+#[derive(Clone, Copy, Debug)]
+pub struct UniqueBloom;
+
+impl Function for UniqueBloom {
+    fn identifier(&self) -> &'static str {
+        "unique_bloom"
+    }
+
+    fn is_pure(&self) -> bool {
+        true
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[
+            Parameter {
+                keyword: "value",
+                kind: kind::ARRAY,
+                required: true,
+            },
+            Parameter {
+                keyword: "bits",
+                kind: kind::INTEGER,
+                required: false,
+            },
+            Parameter {
+                keyword: "hashes",
+                kind: kind::INTEGER,
+                required: false,
+            },
+        ]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[Example {
+            title: "remove duplicates",
+            source: r#"unique_bloom!(["foo", "bar", "foo", 1, 1])"#,
+            result: Ok(r#"["foo", "bar", 1]"#),
+        }]
+    }
+
+    fn compile(&self, mut arguments: ArgumentList) -> Compiled {
+        let value = arguments.required("value");
+        let bits = arguments.optional("bits").unwrap_or(expr!(8192));
+        let hashes = arguments.optional("hashes").unwrap_or(expr!(4));
+
+        Ok(Box::new(UniqueBloomFn {
+            value,
+            bits,
+            hashes,
+        }))
+    }
+}
+
+#[derive(Debug, Clone)]
+struct UniqueBloomFn {
+    value: Box<dyn Expression>,
+    bits: Box<dyn Expression>,
+    hashes: Box<dyn Expression>,
+}
+
+impl Expression for UniqueBloomFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let array = self.value.resolve(ctx)?.try_array()?;
+        let bits = self.bits.resolve(ctx)?.try_integer()?;
+        let hashes = self.hashes.resolve(ctx)?.try_integer()?;
+
+        if !(1..=MAX_BITS).contains(&bits) {
+            return Err(format!("bits must be between 1 and {}, got {}", MAX_BITS, bits).into());
+        }
+
+        if !(1..=64).contains(&hashes) {
+            return Err(format!("hashes must be between 1 and 64, got {}", hashes).into());
+        }
+
+        let mut filter = BloomFilter::new(bits as usize, hashes as u64);
+        let unique = array
+            .into_iter()
+            .filter(|value| !filter.insert(&value.canonicalize(false)))
+            .collect::<Vec<_>>();
+
+        Ok(unique.into())
+    }
+
+    fn type_def(&self, _: &state::Compiler) -> TypeDef {
+        TypeDef::new()
+            .fallible()
+            .array_mapped::<(), Kind>(map! { (): Kind::all() })
+    }
+}
+
+/// A bloom filter of `len` bits, setting `hashes` bits for each item.
+///
+/// The bits of an item are derived from a single 64-bit hash, split in two
+/// halves that are combined as `h1 + i * h2`.
+struct BloomFilter {
+    bits: Vec<u64>,
+    len: u64,
+    hashes: u64,
+}
+
+impl BloomFilter {
+    fn new(len: usize, hashes: u64) -> Self {
+        Self {
+            bits: vec![0; (len + 63) / 64],
+            len: len as u64,
+            hashes,
+        }
+    }
+
+    /// Inserts the item, returning whether it was (probably) inserted before.
+    fn insert(&mut self, item: &str) -> bool {
+        let mut hasher = DefaultHasher::new();
+        item.hash(&mut hasher);
+        let hash = hasher.finish();
+
+        let h1 = hash & 0xffff_ffff;
+        let h2 = (hash >> 32) | 1;
+
+        let mut present = true;
+        for i in 0..self.hashes {
+            let bit = h1.wrapping_add(i.wrapping_mul(h2)) % self.len;
+            let (word, mask) = ((bit / 64) as usize, 1 << (bit % 64));
+
+            present &= self.bits[word] & mask != 0;
+            self.bits[word] |= mask;
+        }
+
+        present
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    test_function![
+        unique_bloom => UniqueBloom;
+
+        exact_duplicates {
+            args: func_args![value: value!([1, "foo", 1, "foo", { "bar": true }, { "bar": true }, null, null])],
+            want: Ok(value!([1, "foo", { "bar": true }, null])),
+            tdef: TypeDef::new().fallible().array_mapped::<(), Kind>(map! { (): Kind::all() }),
+        }
+
+        distinct_kinds {
+            args: func_args![value: value!([1, 1.0, "1", [1]])],
+            want: Ok(value!([1, 1.0, "1", [1]])),
+            tdef: TypeDef::new().fallible().array_mapped::<(), Kind>(map! { (): Kind::all() }),
+        }
+
+        empty {
+            args: func_args![value: value!([])],
+            want: Ok(value!([])),
+            tdef: TypeDef::new().fallible().array_mapped::<(), Kind>(map! { (): Kind::all() }),
+        }
+
+        // A single bit is set by the first item, after which every item looks
+        // like a probable duplicate.
+        false_positives {
+            args: func_args![value: value!(["foo", "bar", "baz"]), bits: 1],
+            want: Ok(value!(["foo"])),
+            tdef: TypeDef::new().fallible().array_mapped::<(), Kind>(map! { (): Kind::all() }),
+        }
+
+        invalid_bits {
+            args: func_args![value: value!([]), bits: 0],
+            want: Err("bits must be between 1 and 16777216, got 0"),
+            tdef: TypeDef::new().fallible().array_mapped::<(), Kind>(map! { (): Kind::all() }),
+        }
+
+        invalid_hashes {
+            args: func_args![value: value!([]), hashes: 0],
+            want: Err("hashes must be between 1 and 64, got 0"),
+            tdef: TypeDef::new().fallible().array_mapped::<(), Kind>(map! { (): Kind::all() }),
+        }
+    ];
+}
@@ -8,6 +8,10 @@ impl Function for Upcase {
         "upcase"
     }
 
+    fn is_pure(&self) -> bool {
+        true
+    }
+
     fn examples(&self) -> &'static [Example] {
         &[Example {
             title: "upcase",
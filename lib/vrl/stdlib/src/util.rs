@@ -1,4 +1,9 @@
-#[cfg(any(feature = "parse_regex", feature = "parse_regex_all"))]
+#[cfg(any(
+    feature = "camel_case_keys",
+    feature = "parse_regex",
+    feature = "parse_regex_all",
+    feature = "snake_case_keys"
+))]
 use std::collections::BTreeMap;
 use std::str::FromStr;
 use vrl::{value::Kind, Value};
@@ -117,3 +122,412 @@ impl FromStr for Base64Charset {
         }
     }
 }
+
+/// Parses a path provided at runtime (e.g. `"foo.bar[0]"`) into a [`vrl::Path`].
+#[cfg(any(
+    feature = "rename_key",
+    feature = "rename_keys",
+    feature = "set_if_absent"
+))]
+pub(crate) fn parse_path(path: &str) -> std::result::Result<vrl::Path, String> {
+    vrl::Path::from_str(path).map_err(|_| format!(r#"invalid path "{}""#, path))
+}
+
+/// Moves the values found at each `from` path to their `to` path.
+///
+/// All values are removed before any of them is re-inserted, so renames can
+/// safely swap keys. If `overwrite` is `false`, an error is returned if a value
+/// already exists at one of the `to` paths.
+#[cfg(any(feature = "rename_key", feature = "rename_keys"))]
+pub(crate) fn rename_paths(
+    value: &mut Value,
+    renames: &[(vrl::Path, vrl::Path)],
+    overwrite: bool,
+) -> std::result::Result<(), String> {
+    let mut moved = Vec::with_capacity(renames.len());
+
+    for (from, to) in renames {
+        if let Some(item) = value.get_by_path(from).cloned() {
+            value.remove_by_path(from, false);
+            moved.push((to, item));
+        }
+    }
+
+    if !overwrite {
+        if let Some((to, _)) = moved.iter().find(|(to, _)| value.get_by_path(to).is_some()) {
+            return Err(format!(r#"path "{}" already exists"#, to));
+        }
+    }
+
+    for (to, item) in moved {
+        value.insert_by_path(to, item);
+    }
+
+    Ok(())
+}
+
+/// Parses a [semantic version](https://semver.org), such as `1.2.3-rc.1+build.5`.
+#[cfg(any(feature = "compare_semver", feature = "parse_semver"))]
+pub(crate) fn parse_semver(version: &str) -> std::result::Result<semver::Version, String> {
+    semver::Version::parse(version)
+        .map_err(|_| format!(r#"invalid semantic version "{}""#, version))
+}
+
+/// Returns the digits of a card number, ignoring the spaces and dashes
+/// commonly used to group them.
+#[cfg(any(feature = "card_brand", feature = "luhn_check"))]
+pub(crate) fn card_digits(value: &str) -> std::result::Result<String, String> {
+    let digits = value
+        .chars()
+        .filter(|&c| c != ' ' && c != '-')
+        .collect::<String>();
+
+    if digits.is_empty() || !digits.chars().all(|c| c.is_ascii_digit()) {
+        return Err("value must only contain digits, spaces and dashes".to_owned());
+    }
+
+    Ok(digits)
+}
+
+/// Validates and normalizes a domain name for a Public Suffix List lookup.
+///
+/// The domain is lowercased and a trailing dot is removed. IP addresses and
+/// names with empty labels or characters other than letters, digits, `-` and
+/// `_` are rejected.
+#[cfg(any(feature = "public_suffix", feature = "registrable_domain"))]
+pub(crate) fn parse_domain(value: &str) -> std::result::Result<String, String> {
+    let domain = value.strip_suffix('.').unwrap_or(value).to_lowercase();
+
+    let is_valid = !domain.is_empty()
+        && domain.parse::<std::net::IpAddr>().is_err()
+        && domain.split('.').all(|label| {
+            !label.is_empty()
+                && label
+                    .chars()
+                    .all(|c| c.is_alphanumeric() || c == '-' || c == '_')
+        });
+
+    if !is_valid {
+        return Err(format!(r#"invalid domain "{}""#, value));
+    }
+
+    Ok(domain)
+}
+
+/// Splits an email address into its local part and domain.
+///
+/// The address is split at its last `@`, as the local part may itself contain
+/// one when quoted. Both parts must be non-empty and free of whitespace.
+#[cfg(any(feature = "normalize_email", feature = "parse_email"))]
+pub(crate) fn parse_email(value: &str) -> std::result::Result<(&str, &str), String> {
+    let mut parts = value.rsplitn(2, '@');
+    let domain = parts.next().unwrap_or_default();
+    let local = parts.next().unwrap_or_default();
+
+    let is_valid = |part: &str| !part.is_empty() && !part.chars().any(char::is_whitespace);
+
+    if !is_valid(local) || !is_valid(domain) || domain.starts_with('.') || domain.ends_with('.') {
+        return Err(format!(r#"invalid email address "{}""#, value));
+    }
+
+    Ok((local, domain))
+}
+
+/// An error returned when compiling the patterns of a regex set function.
+#[cfg(any(feature = "classify", feature = "match_any"))]
+#[derive(Debug)]
+pub(crate) enum RegexSetError {
+    InvalidPattern {
+        index: usize,
+        pattern: String,
+        error: regex::Error,
+    },
+    InvalidLabeledPattern {
+        label: String,
+        pattern: String,
+        error: regex::Error,
+    },
+    TooLarge(regex::Error),
+}
+
+#[cfg(any(feature = "classify", feature = "match_any"))]
+impl RegexSetError {
+    /// Reports an invalid pattern by the label it's stored under, instead of
+    /// by its index.
+    #[cfg(feature = "classify")]
+    pub(crate) fn with_labels(self, labels: &[String]) -> Self {
+        match self {
+            RegexSetError::InvalidPattern {
+                index,
+                pattern,
+                error,
+            } => RegexSetError::InvalidLabeledPattern {
+                label: labels[index].clone(),
+                pattern,
+                error,
+            },
+            error => error,
+        }
+    }
+}
+
+#[cfg(any(feature = "classify", feature = "match_any"))]
+impl std::fmt::Display for RegexSetError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RegexSetError::InvalidPattern { index, pattern, .. } => {
+                write!(f, r#"invalid pattern "{}" at index {}"#, pattern, index)
+            }
+            RegexSetError::InvalidLabeledPattern { label, pattern, .. } => {
+                write!(f, r#"invalid pattern "{}" for label "{}""#, pattern, label)
+            }
+            RegexSetError::TooLarge(_) => f.write_str("patterns exceed the regex size limit"),
+        }
+    }
+}
+
+#[cfg(any(feature = "classify", feature = "match_any"))]
+impl std::error::Error for RegexSetError {}
+
+#[cfg(any(feature = "classify", feature = "match_any"))]
+impl vrl::diagnostic::DiagnosticError for RegexSetError {
+    fn code(&self) -> usize {
+        101
+    }
+
+    fn labels(&self) -> Vec<vrl::diagnostic::Label> {
+        let error = match self {
+            RegexSetError::InvalidPattern { error, .. }
+            | RegexSetError::InvalidLabeledPattern { error, .. }
+            | RegexSetError::TooLarge(error) => error,
+        };
+
+        vec![vrl::diagnostic::Label::primary(
+            error.to_string(),
+            vrl::diagnostic::Span::default(),
+        )]
+    }
+}
+
+/// Returns the source of a pattern, which is either a regex or a string.
+#[cfg(any(feature = "classify", feature = "match_any"))]
+pub(crate) fn pattern_source(value: Value) -> std::result::Result<String, vrl::value::Error> {
+    match value {
+        Value::Regex(regex) => Ok(regex.as_str().to_owned()),
+        Value::Bytes(bytes) => Ok(String::from_utf8_lossy(&bytes).into_owned()),
+        value => Err(vrl::value::Error::Expected {
+            got: value.kind(),
+            expected: Kind::Regex | Kind::Bytes,
+        }),
+    }
+}
+
+/// Compiles the given patterns into a single [`regex::RegexSet`], so that a
+/// value can be matched against all of them in a single pass.
+#[cfg(any(feature = "classify", feature = "match_any"))]
+pub(crate) fn regex_set(
+    patterns: &[String],
+) -> std::result::Result<regex::RegexSet, RegexSetError> {
+    // Compiling each pattern on its own first tells us which one is invalid.
+    for (index, pattern) in patterns.iter().enumerate() {
+        regex::Regex::new(pattern).map_err(|error| RegexSetError::InvalidPattern {
+            index,
+            pattern: pattern.to_owned(),
+            error,
+        })?;
+    }
+
+    regex::RegexSet::new(patterns).map_err(RegexSetError::TooLarge)
+}
+
+/// Returns the elements of an array of numbers, failing if any of them isn't
+/// an integer or a float.
+#[cfg(any(
+    feature = "dot_product",
+    feature = "ewma",
+    feature = "moving_average",
+    feature = "percentile",
+    feature = "quantile",
+    feature = "vector_add",
+    feature = "vector_scale"
+))]
+pub(crate) fn number_array(value: Value) -> std::result::Result<Vec<Value>, String> {
+    let array = value.try_array().map_err(|err| err.to_string())?;
+
+    if let Some((index, value)) = array
+        .iter()
+        .enumerate()
+        .find(|(_, value)| !value.is_integer() && !value.is_float())
+    {
+        return Err(format!(
+            "expected array of numbers, got {} at index {}",
+            value.kind(),
+            index
+        ));
+    }
+
+    Ok(array)
+}
+
+/// Pairs up the elements of two arrays of numbers, which must be of the same
+/// length.
+#[cfg(any(feature = "dot_product", feature = "vector_add"))]
+pub(crate) fn number_array_pairs(
+    a: Value,
+    b: Value,
+) -> std::result::Result<Vec<(Value, Value)>, String> {
+    let a = number_array(a)?;
+    let b = number_array(b)?;
+
+    if a.len() != b.len() {
+        return Err(format!(
+            "arrays must have the same length, got {} and {}",
+            a.len(),
+            b.len()
+        ));
+    }
+
+    Ok(a.into_iter().zip(b).collect())
+}
+
+/// Returns the elements of an array of numbers as floats.
+#[cfg(any(
+    feature = "ewma",
+    feature = "moving_average",
+    feature = "percentile",
+    feature = "quantile"
+))]
+pub(crate) fn float_array(value: Value) -> std::result::Result<Vec<f64>, String> {
+    Ok(number_array(value)?
+        .iter()
+        .filter_map(|value| std::convert::TryFrom::try_from(value).ok())
+        .collect())
+}
+
+/// Returns the value at quantile `q` of an array of numbers, interpolating
+/// linearly between the two closest ranks.
+///
+/// `q` has to be between `0.0` and `1.0`, which is checked by the caller.
+#[cfg(any(feature = "percentile", feature = "quantile"))]
+pub(crate) fn quantile(value: Value, q: f64) -> std::result::Result<f64, String> {
+    let mut numbers = float_array(value)?;
+
+    if numbers.is_empty() {
+        return Err("array must not be empty".to_owned());
+    }
+
+    // Floats in values are never NaN, so they're totally ordered.
+    numbers.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+
+    let rank = q * (numbers.len() - 1) as f64;
+    let lower = numbers[rank.floor() as usize];
+    let upper = numbers[rank.ceil() as usize];
+
+    Ok(lower + (upper - lower) * rank.fract())
+}
+
+/// Returns the uppercased ASCII letters of a word to phonetically encode.
+///
+/// Any other character is an error, unless `ignore_invalid` is set, in which
+/// case it's left out instead.
+#[cfg(any(feature = "metaphone", feature = "soundex"))]
+pub(crate) fn phonetic_letters(
+    value: &str,
+    ignore_invalid: bool,
+) -> std::result::Result<Vec<u8>, String> {
+    let mut letters = Vec::with_capacity(value.len());
+
+    for c in value.chars() {
+        if c.is_ascii_alphabetic() {
+            letters.push(c.to_ascii_uppercase() as u8);
+        } else if !ignore_invalid {
+            return Err(format!(r#"invalid character "{}" in "{}""#, c, value));
+        }
+    }
+
+    Ok(letters)
+}
+
+/// Splits an identifier into its words, at `_`, `-`, `.` and whitespace
+/// separators, and at case boundaries, so that `"user_id"` splits into
+/// `["user", "id"]` and `"userID"` into `["user", "ID"]`.
+///
+/// A run of uppercase letters is kept as one word, except for its last letter
+/// when a lowercase letter follows, so that `"HTTPServer"` splits into
+/// `["HTTP", "Server"]`.
+#[cfg(any(feature = "camel_case_keys", feature = "snake_case_keys"))]
+pub(crate) fn identifier_words(identifier: &str) -> Vec<String> {
+    let chars = identifier.chars().collect::<Vec<_>>();
+    let mut words = vec![];
+    let mut word = String::new();
+
+    for (i, &c) in chars.iter().enumerate() {
+        if c == '_' || c == '-' || c == '.' || c.is_whitespace() {
+            if !word.is_empty() {
+                words.push(std::mem::take(&mut word));
+            }
+            continue;
+        }
+
+        if c.is_uppercase() && !word.is_empty() {
+            let previous = chars[i - 1];
+            let next_is_lower = chars.get(i + 1).map_or(false, |c| c.is_lowercase());
+
+            if !previous.is_uppercase() || next_is_lower {
+                words.push(std::mem::take(&mut word));
+            }
+        }
+
+        word.push(c);
+    }
+
+    if !word.is_empty() {
+        words.push(word);
+    }
+
+    words
+}
+
+/// Recursively renames the keys of every object within the value, including
+/// the objects nested in arrays, using the given conversion.
+///
+/// When several keys of an object convert to the same key, the value of the
+/// key that sorts last is kept if `overwrite` is `true`, and an error is
+/// returned otherwise.
+#[cfg(any(feature = "camel_case_keys", feature = "snake_case_keys"))]
+pub(crate) fn convert_keys(
+    value: Value,
+    convert: &impl Fn(&str) -> String,
+    overwrite: bool,
+) -> std::result::Result<Value, String> {
+    match value {
+        Value::Object(object) => {
+            let mut converted = BTreeMap::new();
+            let mut sources = BTreeMap::new();
+
+            for (key, value) in object {
+                let new_key = convert(&key);
+                let value = convert_keys(value, convert, overwrite)?;
+
+                if !overwrite {
+                    if let Some(source) = sources.insert(new_key.clone(), key.clone()) {
+                        return Err(format!(
+                            r#"keys "{}" and "{}" both convert to "{}""#,
+                            source, key, new_key
+                        ));
+                    }
+                }
+
+                converted.insert(new_key, value);
+            }
+
+            Ok(converted.into())
+        }
+        Value::Array(array) => array
+            .into_iter()
+            .map(|value| convert_keys(value, convert, overwrite))
+            .collect::<std::result::Result<Vec<_>, _>>()
+            .map(Into::into),
+        value => Ok(value),
+    }
+}
@@ -26,9 +26,17 @@ impl Function for UuidV4 {
 struct UuidV4Fn;
 
 impl Expression for UuidV4Fn {
-    fn resolve(&self, _: &mut Context) -> Resolved {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let mut bytes = [0; 16];
+        ctx.state_mut().rng().fill_bytes(&mut bytes);
+
+        let uuid = uuid::Builder::from_bytes(bytes)
+            .set_variant(uuid::Variant::RFC4122)
+            .set_version(uuid::Version::Random)
+            .build();
+
         let mut buf = [0; 36];
-        let uuid = uuid::Uuid::new_v4().to_hyphenated().encode_lower(&mut buf);
+        let uuid = uuid.to_hyphenated().encode_lower(&mut buf);
 
         Ok(Bytes::copy_from_slice(uuid.as_bytes()).into())
     }
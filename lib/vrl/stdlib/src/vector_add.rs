@@ -0,0 +1,104 @@
+use crate::util;
+use vrl::prelude::*;
+
+#[derive(Clone, Copy, Debug)]
+pub struct VectorAdd;
+
+impl Function for VectorAdd {
+    fn identifier(&self) -> &'static str {
+        "vector_add"
+    }
+
+    fn is_pure(&self) -> bool {
+        true
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[
+            Parameter {
+                keyword: "a",
+                kind: kind::ARRAY,
+                required: true,
+            },
+            Parameter {
+                keyword: "b",
+                kind: kind::ARRAY,
+                required: true,
+            },
+        ]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[Example {
+            title: "add",
+            source: r#"vector_add!([1, 2.5], [3, 4])"#,
+            result: Ok("[4, 6.5]"),
+        }]
+    }
+
+    fn compile(&self, mut arguments: ArgumentList) -> Compiled {
+        let a = arguments.required("a");
+        let b = arguments.required("b");
+
+        Ok(Box::new(VectorAddFn { a, b }))
+    }
+}
+
+#[derive(Debug, Clone)]
+struct VectorAddFn {
+    a: Box<dyn Expression>,
+    b: Box<dyn Expression>,
+}
+
+impl Expression for VectorAddFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let a = self.a.resolve(ctx)?;
+        let b = self.b.resolve(ctx)?;
+
+        util::number_array_pairs(a, b)?
+            .into_iter()
+            .map(|(a, b)| a.try_add(b))
+            .collect::<std::result::Result<Vec<_>, _>>()
+            .map(Into::into)
+            .map_err(Into::into)
+    }
+
+    fn type_def(&self, _: &state::Compiler) -> TypeDef {
+        TypeDef::new().fallible().array_mapped::<(), Kind>(map! {
+            (): Kind::Integer | Kind::Float,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    test_function![
+        vector_add => VectorAdd;
+
+        add {
+            args: func_args![a: value!([1, 2.5, (-3)]), b: value!([3, 4, 1])],
+            want: Ok(value!([4, 6.5, (-2)])),
+            tdef: TypeDef::new().fallible().array_mapped::<(), Kind>(map! {
+                (): Kind::Integer | Kind::Float,
+            }),
+        }
+
+        length_mismatch {
+            args: func_args![a: value!([1]), b: value!([])],
+            want: Err("arrays must have the same length, got 1 and 0"),
+            tdef: TypeDef::new().fallible().array_mapped::<(), Kind>(map! {
+                (): Kind::Integer | Kind::Float,
+            }),
+        }
+
+        not_a_number {
+            args: func_args![a: value!([1]), b: value!([null])],
+            want: Err(r#"expected array of numbers, got "null" at index 0"#),
+            tdef: TypeDef::new().fallible().array_mapped::<(), Kind>(map! {
+                (): Kind::Integer | Kind::Float,
+            }),
+        }
+    ];
+}
@@ -0,0 +1,120 @@
+use crate::util;
+use vrl::prelude::*;
+
+#[derive(Clone, Copy, Debug)]
+pub struct VectorScale;
+
+impl Function for VectorScale {
+    fn identifier(&self) -> &'static str {
+        "vector_scale"
+    }
+
+    fn is_pure(&self) -> bool {
+        true
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[
+            Parameter {
+                keyword: "value",
+                kind: kind::ARRAY,
+                required: true,
+            },
+            Parameter {
+                keyword: "factor",
+                kind: kind::INTEGER | kind::FLOAT,
+                required: true,
+            },
+        ]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[Example {
+            title: "scale",
+            source: r#"vector_scale!([1, 2.5], 2)"#,
+            result: Ok("[2, 5.0]"),
+        }]
+    }
+
+    fn compile(&self, mut arguments: ArgumentList) -> Compiled {
+        let value = arguments.required("value");
+        let factor = arguments.required("factor");
+
+        Ok(Box::new(VectorScaleFn { value, factor }))
+    }
+}
+
+#[derive(Debug, Clone)]
+struct VectorScaleFn {
+    value: Box<dyn Expression>,
+    factor: Box<dyn Expression>,
+}
+
+impl Expression for VectorScaleFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = util::number_array(self.value.resolve(ctx)?)?;
+        let factor = self.factor.resolve(ctx)?;
+
+        if !factor.is_integer() && !factor.is_float() {
+            return Err(value::Error::Expected {
+                got: factor.kind(),
+                expected: Kind::Integer | Kind::Float,
+            }
+            .into());
+        }
+
+        value
+            .into_iter()
+            .map(|element| element.try_mul(factor.clone()))
+            .collect::<std::result::Result<Vec<_>, _>>()
+            .map(Into::into)
+            .map_err(Into::into)
+    }
+
+    fn type_def(&self, _: &state::Compiler) -> TypeDef {
+        TypeDef::new().fallible().array_mapped::<(), Kind>(map! {
+            (): Kind::Integer | Kind::Float,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    test_function![
+        vector_scale => VectorScale;
+
+        integer {
+            args: func_args![value: value!([1, 2.5, 0]), factor: 2],
+            want: Ok(value!([2, 5.0, 0])),
+            tdef: TypeDef::new().fallible().array_mapped::<(), Kind>(map! {
+                (): Kind::Integer | Kind::Float,
+            }),
+        }
+
+        negative {
+            args: func_args![value: value!([1, (-2), 3.5]), factor: -1.5],
+            want: Ok(value!([(-1.5), 3.0, (-5.25)])),
+            tdef: TypeDef::new().fallible().array_mapped::<(), Kind>(map! {
+                (): Kind::Integer | Kind::Float,
+            }),
+        }
+
+        empty {
+            args: func_args![value: value!([]), factor: 3],
+            want: Ok(value!([])),
+            tdef: TypeDef::new().fallible().array_mapped::<(), Kind>(map! {
+                (): Kind::Integer | Kind::Float,
+            }),
+        }
+
+        not_a_number {
+            args: func_args![value: value!([true]), factor: 1],
+            want: Err(r#"expected array of numbers, got "boolean" at index 0"#),
+            tdef: TypeDef::new().fallible().array_mapped::<(), Kind>(map! {
+                (): Kind::Integer | Kind::Float,
+            }),
+        }
+    ];
+}
@@ -0,0 +1,199 @@
+use vrl::prelude::*;
+
+#[derive(Clone, Copy, Debug)]
+pub struct Windows;
+
+impl Function for Windows {
+    fn identifier(&self) -> &'static str {
+        "windows"
+    }
+
+    fn is_pure(&self) -> bool {
+        true
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[
+            Parameter {
+                keyword: "value",
+                kind: kind::ARRAY,
+                required: true,
+            },
+            Parameter {
+                keyword: "size",
+                kind: kind::INTEGER,
+                required: true,
+            },
+            Parameter {
+                keyword: "step",
+                kind: kind::INTEGER,
+                required: false,
+            },
+            Parameter {
+                keyword: "partial",
+                kind: kind::BOOLEAN,
+                required: false,
+            },
+        ]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "sliding",
+                source: r#"windows!([1, 2, 3, 4], 2)"#,
+                result: Ok("[[1, 2], [2, 3], [3, 4]]"),
+            },
+            Example {
+                title: "step",
+                source: r#"windows!([1, 2, 3, 4, 5], 2, step: 2)"#,
+                result: Ok("[[1, 2], [3, 4]]"),
+            },
+            Example {
+                title: "partial",
+                source: r#"windows!([1, 2, 3, 4, 5], 2, step: 2, partial: true)"#,
+                result: Ok("[[1, 2], [3, 4], [5]]"),
+            },
+        ]
+    }
+
+    fn compile(&self, mut arguments: ArgumentList) -> Compiled {
+        let value = arguments.required("value");
+        let size = arguments.required("size");
+        let step = arguments.optional("step").unwrap_or(expr!(1));
+        let partial = arguments.optional("partial").unwrap_or(expr!(false));
+
+        Ok(Box::new(WindowsFn {
+            value,
+            size,
+            step,
+            partial,
+        }))
+    }
+}
+
+#[derive(Debug, Clone)]
+struct WindowsFn {
+    value: Box<dyn Expression>,
+    size: Box<dyn Expression>,
+    step: Box<dyn Expression>,
+    partial: Box<dyn Expression>,
+}
+
+impl Expression for WindowsFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let array = self.value.resolve(ctx)?.try_array()?;
+
+        let size = self.size.resolve(ctx)?.try_integer()?;
+        if size < 1 {
+            return Err(format!("size must be at least 1, got {}", size).into());
+        }
+
+        let step = self.step.resolve(ctx)?.try_integer()?;
+        if step < 1 {
+            return Err(format!("step must be at least 1, got {}", step).into());
+        }
+
+        let partial = self.partial.resolve(ctx)?.try_boolean()?;
+
+        Ok(windows(&array, size as usize, step as usize, partial).into())
+    }
+
+    fn type_def(&self, _: &state::Compiler) -> TypeDef {
+        TypeDef::new()
+            .fallible()
+            .array_mapped::<(), Kind>(map! { (): Kind::Array })
+    }
+}
+
+/// The windows starting every `step` elements, up to the last one that is
+/// `size` elements long, or that isn't empty if `partial` is set.
+fn windows(array: &[Value], size: usize, step: usize, partial: bool) -> Vec<Value> {
+    let end = if partial {
+        array.len()
+    } else {
+        (array.len() + 1).saturating_sub(size)
+    };
+
+    (0..end)
+        .step_by(step)
+        .map(|start| {
+            Value::from(array[start..array.len().min(start.saturating_add(size))].to_vec())
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    test_function![
+        windows => Windows;
+
+        default_step {
+            args: func_args![value: value!([1, 2, 3, 4]), size: 2],
+            want: Ok(value!([[1, 2], [2, 3], [3, 4]])),
+            tdef: TypeDef::new().fallible().array_mapped::<(), Kind>(map! { (): Kind::Array }),
+        }
+
+        size_of_array {
+            args: func_args![value: value!([1, 2, 3]), size: 3],
+            want: Ok(value!([[1, 2, 3]])),
+            tdef: TypeDef::new().fallible().array_mapped::<(), Kind>(map! { (): Kind::Array }),
+        }
+
+        step {
+            args: func_args![value: value!([1, 2, 3, 4, 5, 6]), size: 2, step: 3],
+            want: Ok(value!([[1, 2], [4, 5]])),
+            tdef: TypeDef::new().fallible().array_mapped::<(), Kind>(map! { (): Kind::Array }),
+        }
+
+        step_drops_partial {
+            args: func_args![value: value!([1, 2, 3, 4, 5]), size: 2, step: 2],
+            want: Ok(value!([[1, 2], [3, 4]])),
+            tdef: TypeDef::new().fallible().array_mapped::<(), Kind>(map! { (): Kind::Array }),
+        }
+
+        partial {
+            args: func_args![value: value!([1, 2, 3, 4, 5]), size: 2, step: 2, partial: true],
+            want: Ok(value!([[1, 2], [3, 4], [5]])),
+            tdef: TypeDef::new().fallible().array_mapped::<(), Kind>(map! { (): Kind::Array }),
+        }
+
+        partial_default_step {
+            args: func_args![value: value!([1, 2, 3]), size: 2, partial: true],
+            want: Ok(value!([[1, 2], [2, 3], [3]])),
+            tdef: TypeDef::new().fallible().array_mapped::<(), Kind>(map! { (): Kind::Array }),
+        }
+
+        size_larger_than_array {
+            args: func_args![value: value!([1, 2]), size: 3],
+            want: Ok(value!([])),
+            tdef: TypeDef::new().fallible().array_mapped::<(), Kind>(map! { (): Kind::Array }),
+        }
+
+        size_larger_than_array_partial {
+            args: func_args![value: value!([1, 2]), size: 3, partial: true],
+            want: Ok(value!([[1, 2], [2]])),
+            tdef: TypeDef::new().fallible().array_mapped::<(), Kind>(map! { (): Kind::Array }),
+        }
+
+        empty {
+            args: func_args![value: value!([]), size: 1, partial: true],
+            want: Ok(value!([])),
+            tdef: TypeDef::new().fallible().array_mapped::<(), Kind>(map! { (): Kind::Array }),
+        }
+
+        zero_size {
+            args: func_args![value: value!([1, 2]), size: 0],
+            want: Err("size must be at least 1, got 0"),
+            tdef: TypeDef::new().fallible().array_mapped::<(), Kind>(map! { (): Kind::Array }),
+        }
+
+        zero_step {
+            args: func_args![value: value!([1, 2]), size: 1, step: 0],
+            want: Err("step must be at least 1, got 0"),
+            tdef: TypeDef::new().fallible().array_mapped::<(), Kind>(map! { (): Kind::Array }),
+        }
+    ];
+}
@@ -0,0 +1,119 @@
+use vrl::prelude::*;
+
+#[derive(Clone, Copy, Debug)]
+pub struct XorBytes;
+
+impl Function for XorBytes {
+    fn identifier(&self) -> &'static str {
+        "xor_bytes"
+    }
+
+    fn is_pure(&self) -> bool {
+        true
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[
+            Parameter {
+                keyword: "value",
+                kind: kind::BYTES,
+                required: true,
+            },
+            Parameter {
+                keyword: "key",
+                kind: kind::BYTES,
+                required: true,
+            },
+        ]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[Example {
+            title: "repeating key",
+            source: r#"encode_base64(xor_bytes!("secret", "key"))"#,
+            result: Ok("GAAaGQAN"),
+        }]
+    }
+
+    fn compile(&self, mut arguments: ArgumentList) -> Compiled {
+        let value = arguments.required("value");
+        let key = arguments.required("key");
+
+        Ok(Box::new(XorBytesFn { value, key }))
+    }
+}
+
+#[derive(Debug, Clone)]
+struct XorBytesFn {
+    value: Box<dyn Expression>,
+    key: Box<dyn Expression>,
+}
+
+impl Expression for XorBytesFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?.try_bytes()?;
+        let key = self.key.resolve(ctx)?.try_bytes()?;
+
+        // The shorter of the two is repeated as a key over the longer one.
+        let (long, short) = if value.len() >= key.len() {
+            (value, key)
+        } else {
+            (key, value)
+        };
+
+        if short.is_empty() {
+            return Err("unable to XOR with an empty byte sequence".into());
+        }
+
+        let xored = long
+            .iter()
+            .zip(short.iter().cycle())
+            .map(|(a, b)| a ^ b)
+            .collect::<Vec<_>>();
+
+        Ok(Bytes::from(xored).into())
+    }
+
+    fn type_def(&self, _: &state::Compiler) -> TypeDef {
+        TypeDef::new().fallible().bytes()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    test_function![
+        xor_bytes => XorBytes;
+
+        repeating_key {
+            args: func_args![value: "secret", key: "key"],
+            want: Ok(Bytes::from_static(b"\x18\x00\x1a\x19\x00\x0d")),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+
+        reversible {
+            args: func_args![value: Bytes::from_static(b"\x18\x00\x1a\x19\x00\x0d"), key: "key"],
+            want: Ok("secret"),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+
+        shorter_value {
+            args: func_args![value: "key", key: "secret"],
+            want: Ok(Bytes::from_static(b"\x18\x00\x1a\x19\x00\x0d")),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+
+        same_length {
+            args: func_args![value: Bytes::from_static(b"\xff\x0f"), key: Bytes::from_static(b"\x0f\xff")],
+            want: Ok(Bytes::from_static(b"\xf0\xf0")),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+
+        empty_key {
+            args: func_args![value: "secret", key: ""],
+            want: Err("unable to XOR with an empty byte sequence"),
+            tdef: TypeDef::new().fallible().bytes(),
+        }
+    ];
+}